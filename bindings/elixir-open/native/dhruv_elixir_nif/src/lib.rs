@@ -3,7 +3,9 @@ use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
 use dhruv_config::{ConfigResolver, DefaultsMode, load_with_discovery};
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query, StateVector};
+use dhruv_core::{
+    Body, Correction, Engine, EngineConfig, Frame, Observer, Query, SpkLoadMode, StateVector,
+};
 use dhruv_frames::{
     PrecessionModel, ReferencePlane, cartesian_state_to_spherical_state, cartesian_to_spherical,
     nutation_iau2000b,
@@ -141,6 +143,7 @@ struct EngineConfigInput {
     lsk_path: String,
     cache_capacity: Option<usize>,
     strict_validation: Option<bool>,
+    spk_load_mode: Option<EnumInput>,
     time_policy: Option<TimePolicyInput>,
 }
 
@@ -655,6 +658,8 @@ const DELTA_T_MODEL_VARIANTS: [DeltaTModel; 2] = [
     DeltaTModel::Smh2016WithPre720Quadratic,
 ];
 
+const SPK_LOAD_MODE_VARIANTS: [SpkLoadMode; 2] = [SpkLoadMode::InMemory, SpkLoadMode::OnDemand];
+
 const FUTURE_DELTA_T_TRANSITION_VARIANTS: [FutureDeltaTTransition; 2] = [
     FutureDeltaTTransition::LegacyTtUtcBlend,
     FutureDeltaTTransition::BridgeFromModernEndpoint,
@@ -769,6 +774,17 @@ fn parse_delta_t_model(input: &EnumInput) -> Result<DeltaTModel, Value> {
     }
 }
 
+fn parse_spk_load_mode(input: &EnumInput) -> Result<SpkLoadMode, Value> {
+    match input {
+        EnumInput::Int(index) => SPK_LOAD_MODE_VARIANTS
+            .get(*index as usize)
+            .copied()
+            .ok_or_else(|| error_payload("invalid_request", "unknown spk_load_mode")),
+        EnumInput::Str(value) => parse_named(value, &SPK_LOAD_MODE_VARIANTS)
+            .ok_or_else(|| error_payload("invalid_request", "unknown spk_load_mode")),
+    }
+}
+
 fn parse_future_delta_t_transition(input: &EnumInput) -> Result<FutureDeltaTTransition, Value> {
     match input {
         EnumInput::Int(index) => FUTURE_DELTA_T_TRANSITION_VARIANTS
@@ -832,6 +848,7 @@ fn parse_time_policy_input(input: Option<TimePolicyInput>) -> Result<TimeConvers
                     .map(parse_smh_future_family)
                     .transpose()?
                     .unwrap_or_default(),
+                ..Default::default()
             }))
         }
     }
@@ -887,6 +904,18 @@ fn time_warning_json(warning: &TimeWarning) -> Value {
             "delta_t_segment": debug_name(*segment),
             "used_dut1_seconds": assumed_dut1_seconds
         }),
+        TimeWarning::UtcDriftTableUsed {
+            utc_seconds,
+            tai_minus_utc_seconds,
+        } => json!({
+            "kind": "utc_drift_table_used",
+            "utc_seconds": utc_seconds,
+            "tai_minus_utc_seconds": tai_minus_utc_seconds
+        }),
+        TimeWarning::CivilTimeAsUt1 { utc_seconds } => json!({
+            "kind": "civil_time_as_ut1",
+            "utc_seconds": utc_seconds
+        }),
     }
 }
 
@@ -894,7 +923,8 @@ fn time_diagnostics_json(diagnostics: &TimeDiagnostics) -> Value {
     json!({
         "source": match diagnostics.source {
             TtUtcSource::LskDeltaAt => "lsk_delta_at",
-            TtUtcSource::DeltaTModel => "delta_t_model"
+            TtUtcSource::DeltaTModel => "delta_t_model",
+            TtUtcSource::UtcDriftTable => "utc_drift_table"
         },
         "tt_minus_utc_s": diagnostics.tt_minus_utc_s,
         "warnings": diagnostics.warnings.iter().map(time_warning_json).collect::<Vec<_>>()
@@ -980,19 +1010,16 @@ fn equatorial_position_json(position: EquatorialPosition) -> Value {
 
 fn parse_body(input: &EnumInput) -> Result<Body, Value> {
     match input {
-        EnumInput::Int(code) => Body::from_code(*code as i32)
-            .ok_or_else(|| error_payload("invalid_request", "unknown body code")),
+        EnumInput::Int(code) => Ok(Body::naif(*code as i32)),
         EnumInput::Str(value) => parse_named(value, &BODY_VARIANTS)
+            .or_else(|| Body::from_name(value))
             .ok_or_else(|| error_payload("invalid_request", "unknown body name")),
     }
 }
 
 fn parse_observer(input: &EnumInput) -> Result<Observer, Value> {
     match input {
-        EnumInput::Int(0) => Ok(Observer::SolarSystemBarycenter),
-        EnumInput::Int(code) => Body::from_code(*code as i32)
-            .map(Observer::Body)
-            .ok_or_else(|| error_payload("invalid_request", "unknown observer code")),
+        EnumInput::Int(code) => Ok(Observer::naif(*code as i32)),
         EnumInput::Str(value) => {
             if value.eq_ignore_ascii_case("solar_system_barycenter")
                 || value.eq_ignore_ascii_case("ssb")
@@ -1000,7 +1027,8 @@ fn parse_observer(input: &EnumInput) -> Result<Observer, Value> {
                 Ok(Observer::SolarSystemBarycenter)
             } else {
                 parse_named(value, &BODY_VARIANTS)
-                    .map(Observer::Body)
+                    .or_else(|| Body::from_name(value))
+                    .map(|body| Observer::naif(body.code()))
                     .ok_or_else(|| error_payload("invalid_request", "unknown observer"))
             }
         }
@@ -1956,7 +1984,7 @@ fn to_full_kundali_config(
             }
         }
         if let Some(dasha_config) = input.dasha_config.as_ref() {
-            if let Some(snapshot_utc) = dasha_config.snapshot_utc.clone() {
+            if let Some(snapshot_utc) = dasha_config.snapshot_utc {
                 config.dasha_config.snapshot_time = Some(dhruv_search::DashaSnapshotTime::Utc(
                     parse_utc(snapshot_utc)?,
                 ));
@@ -2091,7 +2119,7 @@ fn utc_to_jd_tdb(engine: &Engine, utc: &UtcTime) -> f64 {
 fn search_at_jd_tdb(engine: &Engine, request: &SearchRequest) -> Result<f64, Value> {
     match (&request.at_jd_tdb, &request.at_utc) {
         (Some(jd), None) => Ok(*jd),
-        (None, Some(utc)) => Ok(utc_to_jd_tdb(engine, &parse_utc(utc.clone())?)),
+        (None, Some(utc)) => Ok(utc_to_jd_tdb(engine, &parse_utc(*utc)?)),
         (Some(_), Some(_)) => Err(error_payload(
             "invalid_request",
             "provide only one of at_jd_tdb or at_utc",
@@ -2112,8 +2140,8 @@ fn search_range_jd_tdb(engine: &Engine, request: &SearchRequest) -> Result<(f64,
     ) {
         (Some(start), Some(end), None, None) => Ok((*start, *end)),
         (None, None, Some(start), Some(end)) => Ok((
-            utc_to_jd_tdb(engine, &parse_utc(start.clone())?),
-            utc_to_jd_tdb(engine, &parse_utc(end.clone())?),
+            utc_to_jd_tdb(engine, &parse_utc(*start)?),
+            utc_to_jd_tdb(engine, &parse_utc(*end)?),
         )),
         (Some(_), Some(_), Some(_), Some(_)) => Err(error_payload(
             "invalid_request",
@@ -2263,7 +2291,7 @@ fn query_epoch_tdb_jd(state: &EngineState, request: &QueryInput) -> Result<f64,
     match (request.epoch_tdb_jd, request.utc.as_ref()) {
         (Some(epoch_tdb_jd), None) => Ok(epoch_tdb_jd),
         (None, Some(utc_input)) => {
-            let utc = parse_utc(utc_input.clone())?;
+            let utc = parse_utc(*utc_input)?;
             let jd_utc = dhruv_time::calendar_to_jd(
                 utc.year,
                 utc.month,
@@ -2339,6 +2367,7 @@ fn bhava_result_json(result: BhavaResult) -> Value {
     })
 }
 
+#[allow(clippy::needless_range_loop)]
 fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> BhavaResult {
     let lagna = lagna_deg.rem_euclid(360.0);
     let lagna_rashi = (lagna / 30.0).floor() as u8;
@@ -3108,6 +3137,7 @@ fn handle_ephemeris(resource: &ResourceArc<EngineResource>, request: QueryInput)
             observer: parse_observer(&request.observer)?,
             frame: parse_frame(request.frame.as_ref().unwrap_or(&EnumInput::Int(0)))?,
             epoch_tdb_jd: query_epoch_tdb_jd(state, &request)?,
+            correction: Correction::Geometric,
         };
         engine
             .query(query)
@@ -3212,6 +3242,7 @@ fn handle_vedic(resource: &ResourceArc<EngineResource>, request: VedicRequest) -
                         .as_ref()
                         .and_then(|config| config.use_nutation)
                         .unwrap_or(false),
+                    nutation_model: dhruv_frames::DEFAULT_NUTATION_MODEL,
                     delta_psi_arcsec: 0.0,
                 };
                 ayanamsha(&op)
@@ -3395,7 +3426,7 @@ fn handle_panchang(resource: &ResourceArc<EngineResource>, request: PanchangRequ
             .as_ref()
             .ok_or_else(|| error_payload("missing_eop", "panchang requires loaded EOP data"))?;
         apply_time_policy(state);
-        let utc = request.utc.clone().map(parse_utc).transpose()?;
+        let utc = request.utc.map(parse_utc).transpose()?;
         let location = parse_location(request.location.unwrap_or(GeoLocationInput {
             latitude_deg: 0.0,
             longitude_deg: 0.0,
@@ -4102,7 +4133,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
         let raw_inputs = parse_dasha_inputs(request.inputs.as_ref())?;
         let birth_jd = match (request.birth_jd, request.birth_utc.as_ref()) {
             (Some(jd), _) => jd,
-            (None, Some(utc)) if raw_inputs.is_some() => utc_to_jd_utc(&parse_utc(utc.clone())?),
+            (None, Some(utc)) if raw_inputs.is_some() => utc_to_jd_utc(&parse_utc(*utc)?),
             (None, Some(_)) => 0.0,
             (None, None) => {
                 return Err(error_payload(
@@ -4156,7 +4187,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
                 if let Some(inputs) = raw_inputs.as_ref() {
                     let query_jd = match (request.query_jd, request.query_utc.as_ref()) {
                         (Some(jd), _) => jd,
-                        (None, Some(utc)) => utc_to_jd_utc(&parse_utc(utc.clone())?),
+                        (None, Some(utc)) => utc_to_jd_utc(&parse_utc(*utc)?),
                         (None, None) => {
                             return Err(error_payload(
                                 "invalid_request",
@@ -4503,17 +4534,25 @@ fn engine_new<'a>(env: Env<'a>, config: Term<'a>) -> Result<Term<'a>, rustler::E
     let config = decode_term::<EngineConfigInput>(config)?;
     let time_policy =
         parse_time_policy_input(config.time_policy).map_err(|_| rustler::Error::BadArg)?;
+    let spk_load_mode = config
+        .spk_load_mode
+        .as_ref()
+        .map(parse_spk_load_mode)
+        .transpose()
+        .map_err(|_| rustler::Error::BadArg)?
+        .unwrap_or_default();
     let engine_config = EngineConfig {
         spk_paths: config.spk_paths.into_iter().map(PathBuf::from).collect(),
         lsk_path: PathBuf::from(config.lsk_path),
         cache_capacity: config.cache_capacity.unwrap_or(256),
         strict_validation: config.strict_validation.unwrap_or(true),
+        spk_load_mode,
     };
     let engine = match Engine::new(engine_config) {
         Ok(engine) => engine,
         Err(err) => {
             let error_term =
-                rustler::serde::to_term(env, &error_payload("engine_error", err.to_string()))?;
+                rustler::serde::to_term(env, error_payload("engine_error", err.to_string()))?;
             return Ok((atoms::error(), error_term).encode(env));
         }
     };
@@ -4604,6 +4643,7 @@ fn engine_list_spks<'a>(
                 json!({
                     "path": info.path,
                     "segment_count": info.segment_count,
+                    "internal_name": info.internal_name,
                     "generation": info.generation
                 })
             })
@@ -5276,13 +5316,17 @@ mod tests {
             parse_named("mixed_parashara", &CHARAKARAKA_SCHEME_VARIANTS),
             Some(CharakarakaScheme::MixedParashara)
         );
+        assert_eq!(
+            parse_named("on_demand", &SPK_LOAD_MODE_VARIANTS),
+            Some(SpkLoadMode::OnDemand)
+        );
     }
 
     #[test]
     fn resource_mutation_updates_state() {
         let dummy = dummy_state();
         assert!(matches!(dummy.time_policy, TimeConversionPolicy::StrictLsk));
-        assert!(dummy.tara_catalog.len() > 0);
+        assert!(!dummy.tara_catalog.is_empty());
     }
 
     #[test]
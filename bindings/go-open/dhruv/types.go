@@ -2,7 +2,7 @@ package dhruv
 
 import "ctara-dhruv-core/bindings/go-open/internal/cabi"
 
-const ExpectedAPIVersion = 70
+const ExpectedAPIVersion = 77
 
 const (
 	PathCapacity          = cabi.PathCapacity
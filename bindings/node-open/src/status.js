@@ -1,6 +1,6 @@
 'use strict';
 
-const EXPECTED_API_VERSION = 70;
+const EXPECTED_API_VERSION = 77;
 
 const STATUS = {
   OK: 0,
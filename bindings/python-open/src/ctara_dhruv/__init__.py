@@ -77,13 +77,16 @@ from ctara_dhruv.types import (
     SMH_FUTURE_FAMILY_STEPHENSON_2016,
     TIME_POLICY_HYBRID_DELTA_T,
     TIME_POLICY_STRICT_LSK,
+    TIME_WARNING_CIVIL_TIME_AS_UT1,
     TIME_WARNING_DELTA_T_MODEL_USED,
     TIME_WARNING_EOP_FUTURE_FROZEN,
     TIME_WARNING_EOP_PRE_RANGE_FALLBACK,
     TIME_WARNING_LSK_FUTURE_FROZEN,
     TIME_WARNING_LSK_PRE_RANGE_FALLBACK,
+    TIME_WARNING_UTC_DRIFT_TABLE_USED,
     TT_UTC_SOURCE_DELTA_T_MODEL,
     TT_UTC_SOURCE_LSK_DELTA_AT,
+    TT_UTC_SOURCE_UTC_DRIFT_TABLE,
     QueryRequest,
     QueryResult,
     StateVector,
@@ -186,10 +189,11 @@ __all__ = [
     "SMH_FUTURE_FAMILY_ADDENDUM_2020_PIECEWISE", "SMH_FUTURE_FAMILY_CONSTANT_C_MINUS20",
     "SMH_FUTURE_FAMILY_CONSTANT_C_MINUS17P52", "SMH_FUTURE_FAMILY_CONSTANT_C_MINUS15P32",
     "SMH_FUTURE_FAMILY_STEPHENSON_1997", "SMH_FUTURE_FAMILY_STEPHENSON_2016",
-    "TT_UTC_SOURCE_LSK_DELTA_AT", "TT_UTC_SOURCE_DELTA_T_MODEL",
+    "TT_UTC_SOURCE_LSK_DELTA_AT", "TT_UTC_SOURCE_DELTA_T_MODEL", "TT_UTC_SOURCE_UTC_DRIFT_TABLE",
     "TIME_WARNING_LSK_FUTURE_FROZEN", "TIME_WARNING_LSK_PRE_RANGE_FALLBACK",
     "TIME_WARNING_EOP_FUTURE_FROZEN", "TIME_WARNING_EOP_PRE_RANGE_FALLBACK",
-    "TIME_WARNING_DELTA_T_MODEL_USED",
+    "TIME_WARNING_DELTA_T_MODEL_USED", "TIME_WARNING_UTC_DRIFT_TABLE_USED",
+    "TIME_WARNING_CIVIL_TIME_AS_UT1",
     "QUERY_OUTPUT_CARTESIAN", "QUERY_OUTPUT_SPHERICAL", "QUERY_OUTPUT_BOTH",
     # Types
     "QueryRequest", "QueryResult",
@@ -41,7 +41,7 @@ extern "C" {
  * =================================================================== */
 
 /* API version */
-#define DHRUV_API_VERSION       70
+#define DHRUV_API_VERSION       77
 #define DHRUV_PATH_CAPACITY     512
 #define DHRUV_MAX_SPK_PATHS     8
 #define DHRUV_MAX_AMSHA_VARIATIONS 16
@@ -80,10 +80,20 @@ typedef int32_t DhruvStatus;
 #define DHRUV_PRECESSION_MODEL_IAU2006     2
 #define DHRUV_PRECESSION_MODEL_VONDRAK2011 3
 
+/* Nutation model selector */
+#define DHRUV_NUTATION_MODEL_IAU2000B 0
+#define DHRUV_NUTATION_MODEL_IAU1980  1
+
 /* Graha longitude selector */
 #define DHRUV_GRAHA_LONGITUDE_KIND_SIDEREAL 0
 #define DHRUV_GRAHA_LONGITUDE_KIND_TROPICAL 1
 
+/* Light-time / aberration correction selector */
+#define DHRUV_CORRECTION_GEOMETRIC              0
+#define DHRUV_CORRECTION_LIGHT_TIME             1
+#define DHRUV_CORRECTION_LIGHT_TIME_ABERRATION  2
+#define DHRUV_CORRECTION_APPARENT               3
+
 /* Query time selector */
 #define DHRUV_QUERY_TIME_JD_TDB 0
 #define DHRUV_QUERY_TIME_UTC    1
@@ -91,6 +101,7 @@ typedef int32_t DhruvStatus;
 /* Search time selector */
 #define DHRUV_SEARCH_TIME_JD_TDB 0
 #define DHRUV_SEARCH_TIME_UTC    1
+#define DHRUV_SEARCH_TIME_EPOCH  2
 
 /* Query output selector */
 #define DHRUV_QUERY_OUTPUT_CARTESIAN 0
@@ -120,6 +131,7 @@ typedef int32_t DhruvStatus;
 /* TT-UTC diagnostic source */
 #define DHRUV_TT_UTC_SOURCE_LSK_DELTA_AT  0
 #define DHRUV_TT_UTC_SOURCE_DELTA_T_MODEL 1
+#define DHRUV_TT_UTC_SOURCE_UTC_DRIFT_TABLE 2
 
 /* Time warning kinds */
 #define DHRUV_TIME_WARNING_LSK_FUTURE_FROZEN      0
@@ -127,6 +139,13 @@ typedef int32_t DhruvStatus;
 #define DHRUV_TIME_WARNING_EOP_FUTURE_FROZEN      2
 #define DHRUV_TIME_WARNING_EOP_PRE_RANGE_FALLBACK 3
 #define DHRUV_TIME_WARNING_DELTA_T_MODEL_USED     4
+#define DHRUV_TIME_WARNING_UTC_DRIFT_TABLE_USED   5
+#define DHRUV_TIME_WARNING_CIVIL_TIME_AS_UT1      6
+
+/* Local civil time disambiguation (dhruv_local_time_to_utc) */
+#define DHRUV_LOCAL_DISAMBIGUATION_REJECT  0
+#define DHRUV_LOCAL_DISAMBIGUATION_EARLIER 1
+#define DHRUV_LOCAL_DISAMBIGUATION_LATER   2
 
 /* Delta-T segment codes */
 #define DHRUV_DELTA_T_SEGMENT_PRE_MINUS720_QUADRATIC  0
@@ -325,6 +344,7 @@ typedef int32_t DhruvStatus;
 #define DHRUV_MAX_AMSHA_REQUESTS      40
 #define DHRUV_MAX_DASHA_SYSTEMS       23
 #define DHRUV_MAX_CHARAKARAKA_ENTRIES 8
+#define DHRUV_KP_SUB_COUNT            249
 
 /* Charakaraka schemes */
 #define DHRUV_CHARAKARAKA_SCHEME_EIGHT             0
@@ -404,8 +424,19 @@ typedef struct {
     int32_t observer;
     int32_t frame;
     double  epoch_tdb_jd;
+    int32_t correction;  /* DHRUV_CORRECTION_* */
 } DhruvQuery;
 
+/* Two-part JD TDB (jd_tdb_day + jd_tdb_frac, any split). */
+typedef struct {
+    int32_t target;
+    int32_t observer;
+    int32_t frame;
+    double  jd_tdb_day;
+    double  jd_tdb_frac;
+    int32_t correction;  /* DHRUV_CORRECTION_* */
+} DhruvEpochQuery;
+
 typedef struct {
     double position_km[3];
     double velocity_km_s[3];
@@ -455,6 +486,13 @@ typedef struct {
     double   second;
 } DhruvUtcTime;
 
+/* Two-part TDB epoch: seconds past J2000.0 = whole_seconds + fraction.
+ * Outputs keep fraction in [0, 1); inputs accept any split. */
+typedef struct {
+    double whole_seconds;
+    double fraction;
+} DhruvEpoch;
+
 typedef struct {
     int32_t kind;
     double  utc_seconds;
@@ -494,6 +532,7 @@ typedef struct {
     double       epoch_tdb_jd;
     DhruvUtcTime utc;
     int32_t      output_mode;
+    int32_t      correction;  /* DHRUV_CORRECTION_* */
 } DhruvQueryRequest;
 
 typedef struct {
@@ -506,6 +545,8 @@ typedef struct {
     uint8_t use_refraction;
     int32_t sun_limb;
     uint8_t altitude_correction;
+    double sun_radius_km;          /* 0 = IAU nominal */
+    double earth_mean_radius_km;   /* 0 = IAU nominal */
 } DhruvRiseSetConfig;
 
 typedef struct {
@@ -617,12 +658,16 @@ typedef struct {
     DhruvUtcTime at_utc;
     DhruvUtcTime start_utc;
     DhruvUtcTime end_utc;
+    DhruvEpoch   at_epoch;
+    DhruvEpoch   start_epoch;
+    DhruvEpoch   end_epoch;
     DhruvConjunctionConfig config;
 } DhruvConjunctionSearchRequest;
 
 typedef struct {
     double  jd_tdb;
     DhruvUtcTime utc;
+    DhruvEpoch epoch;
     double  actual_separation_deg;
     double  body1_longitude_deg;
     double  body2_longitude_deg;
@@ -649,6 +694,9 @@ typedef struct {
     DhruvUtcTime at_utc;
     DhruvUtcTime start_utc;
     DhruvUtcTime end_utc;
+    DhruvEpoch   at_epoch;
+    DhruvEpoch   start_epoch;
+    DhruvEpoch   end_epoch;
     DhruvGrahanConfig config;
 } DhruvGrahanSearchRequest;
 
@@ -658,18 +706,25 @@ typedef struct {
     double  penumbral_magnitude;
     double  greatest_grahan_jd;
     DhruvUtcTime greatest_grahan_utc;
+    DhruvEpoch   greatest_grahan_epoch;
     double  p1_jd;
     DhruvUtcTime p1_utc;
+    DhruvEpoch   p1_epoch;
     double  u1_jd;
     DhruvUtcTime u1_utc;
+    DhruvEpoch   u1_epoch;
     double  u2_jd;
     DhruvUtcTime u2_utc;
+    DhruvEpoch   u2_epoch;
     double  u3_jd;
     DhruvUtcTime u3_utc;
+    DhruvEpoch   u3_epoch;
     double  u4_jd;
     DhruvUtcTime u4_utc;
+    DhruvEpoch   u4_epoch;
     double  p4_jd;
     DhruvUtcTime p4_utc;
+    DhruvEpoch   p4_epoch;
     double  moon_ecliptic_lat_deg;
     double  angular_separation_deg;
 } DhruvChandraGrahanResult;
@@ -679,14 +734,19 @@ typedef struct {
     double  magnitude;
     double  greatest_grahan_jd;
     DhruvUtcTime greatest_grahan_utc;
+    DhruvEpoch   greatest_grahan_epoch;
     double  c1_jd;
     DhruvUtcTime c1_utc;
+    DhruvEpoch   c1_epoch;
     double  c2_jd;
     DhruvUtcTime c2_utc;
+    DhruvEpoch   c2_epoch;
     double  c3_jd;
     DhruvUtcTime c3_utc;
+    DhruvEpoch   c3_epoch;
     double  c4_jd;
     DhruvUtcTime c4_utc;
+    DhruvEpoch   c4_epoch;
     double  moon_ecliptic_lat_deg;
     double  angular_separation_deg;
 } DhruvSuryaGrahanResult;
@@ -711,6 +771,9 @@ typedef struct {
     DhruvUtcTime at_utc;
     DhruvUtcTime start_utc;
     DhruvUtcTime end_utc;
+    DhruvEpoch   at_epoch;
+    DhruvEpoch   start_epoch;
+    DhruvEpoch   end_epoch;
     DhruvStationaryConfig config;
 } DhruvMotionSearchRequest;
 
@@ -738,6 +801,7 @@ typedef struct {
 typedef struct {
     int32_t  ayanamsha_system;
     uint8_t  use_nutation;
+    int32_t  nutation_model;
     int32_t  reference_plane;
     double   step_size_days;
     uint32_t max_iterations;
@@ -746,6 +810,7 @@ typedef struct {
 
 typedef struct {
     DhruvUtcTime utc;
+    DhruvEpoch epoch;
     int32_t rashi_index;
     double  sun_sidereal_longitude_deg;
     double  sun_tropical_longitude_deg;
@@ -762,6 +827,9 @@ typedef struct {
     DhruvUtcTime at_utc;
     DhruvUtcTime start_utc;
     DhruvUtcTime end_utc;
+    DhruvEpoch   at_epoch;
+    DhruvEpoch   start_epoch;
+    DhruvEpoch   end_epoch;
     DhruvSankrantiConfig config;
 } DhruvSankrantiSearchRequest;
 
@@ -775,10 +843,14 @@ typedef struct {
     DhruvUtcTime at_utc;
     DhruvUtcTime start_utc;
     DhruvUtcTime end_utc;
+    DhruvEpoch   at_epoch;
+    DhruvEpoch   start_epoch;
+    DhruvEpoch   end_epoch;
 } DhruvLunarPhaseSearchRequest;
 
 typedef struct {
     DhruvUtcTime utc;
+    DhruvEpoch epoch;
     int32_t phase;
     double  moon_longitude_deg;
     double  sun_longitude_deg;
@@ -848,6 +920,43 @@ typedef struct {
     DhruvUtcTime end;
 } DhruvGhatikaInfo;
 
+/* --- Daily muhurta periods --- */
+
+typedef struct {
+    DhruvUtcTime start;
+    DhruvUtcTime end;
+} DhruvMuhurtaWindow;
+
+typedef struct {
+    uint8_t      index;          /* 0-14 within the day or the night */
+    uint8_t      is_night;
+    uint8_t      is_durmuhurta;
+    DhruvUtcTime start;
+    DhruvUtcTime end;
+} DhruvMuhurtaSlot;
+
+typedef struct {
+    uint8_t      choghadiya_index; /* 0=Udveg,1=Char,2=Labh,3=Amrit,4=Kaal,5=Shubh,6=Rog */
+    uint8_t      slot;             /* 0-7 within the day or the night */
+    uint8_t      is_night;
+    DhruvUtcTime start;
+    DhruvUtcTime end;
+} DhruvChoghadiyaSlot;
+
+typedef struct {
+    int32_t             vaar_index;
+    DhruvUtcTime        sunrise;
+    DhruvUtcTime        sunset;
+    DhruvUtcTime        next_sunrise;
+    DhruvMuhurtaWindow  rahu_kalam;
+    DhruvMuhurtaWindow  yamaganda;
+    DhruvMuhurtaWindow  gulika_kalam;
+    DhruvMuhurtaWindow  abhijit;
+    DhruvMuhurtaWindow  brahma_muhurta;
+    DhruvMuhurtaSlot    muhurtas[30];     /* 15 day, then 15 night */
+    DhruvChoghadiyaSlot choghadiyas[16];  /* 8 day, then 8 night */
+} DhruvMuhurtaInfo;
+
 typedef struct {
     int32_t      nakshatra_index;
     int32_t      pada;
@@ -1171,7 +1280,9 @@ typedef struct {
     int32_t ayanamsha_system;
     uint8_t use_nutation;
     int32_t precession_model;
+    int32_t nutation_model;
     int32_t reference_plane;
+    int32_t correction;  /* DHRUV_CORRECTION_* */
 } DhruvGrahaLongitudesConfig;
 
 typedef struct {
@@ -1274,6 +1385,86 @@ typedef struct {
     DhruvCharakarakaEntry entries[8];
 } DhruvCharakarakaResult;
 
+/* --- Krishnamurti Paddhati (KP) --- */
+
+/* All lord fields are graha indices (0=Sun..8=Ketu). */
+typedef struct {
+    uint8_t sign_lord;
+    uint8_t star_lord;
+    uint8_t sub_lord;
+    uint8_t sub_sub_lord;
+} DhruvKpLords;
+
+typedef struct {
+    uint8_t      graha_index;
+    uint8_t      bhava_number;       /* 1-based KP house */
+    uint16_t     sub_number;         /* 1..249 */
+    double       sidereal_longitude;
+    DhruvKpLords lords;
+} DhruvKpGrahaEntry;
+
+typedef struct {
+    uint8_t      bhava_number;
+    uint16_t     sub_number;         /* 1..249 */
+    double       sidereal_longitude;
+    DhruvKpLords lords;
+} DhruvKpCuspEntry;
+
+typedef struct {
+    uint8_t bhava_number;
+    uint8_t level_a_count;           /* in star of occupants */
+    uint8_t level_a[9];
+    uint8_t level_b_count;           /* occupants */
+    uint8_t level_b[9];
+    uint8_t level_c_count;           /* in star of cusp lord */
+    uint8_t level_c[9];
+    uint8_t level_d_count;           /* cusp lord */
+    uint8_t level_d[9];
+} DhruvKpHouseSignificators;
+
+typedef struct {
+    uint8_t      day_lord;
+    DhruvKpLords lagna;
+    DhruvKpLords moon;
+    uint8_t      ruling_count;
+    uint8_t      ruling[9];          /* strongest first */
+} DhruvKpRulingPlanets;
+
+typedef struct {
+    double                    ayanamsha_deg;
+    DhruvKpGrahaEntry         grahas[9];
+    DhruvKpCuspEntry          cusps[12];
+    DhruvKpHouseSignificators significators[12];
+    DhruvKpRulingPlanets      ruling_planets;
+} DhruvKpChart;
+
+/* --- Kundali milan (Ashtakoota / Dashakoota) --- */
+
+/* Koota dosha cancellation codes */
+#define DHRUV_MILAN_CANCELLATION_NONE                      0
+#define DHRUV_MILAN_CANCELLATION_SAME_RASHI_DIFF_NAKSHATRA 1
+#define DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_RASHI 2
+#define DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_PADA  3
+#define DHRUV_MILAN_CANCELLATION_SAME_RASHI_LORD           4
+#define DHRUV_MILAN_CANCELLATION_FRIENDLY_RASHI_LORDS      5
+
+typedef struct {
+    uint8_t koota_index;             /* 0=Varna..7=Nadi */
+    uint8_t dosha;
+    uint8_t cancellation;            /* DHRUV_MILAN_CANCELLATION_* */
+    double  points;
+    double  max_points;
+} DhruvKootaScore;
+
+typedef struct {
+    DhruvKootaScore kootas[8];
+    double          total;           /* out of 36 */
+    double          adjusted_total;  /* cancelled doshas at full points */
+    uint8_t         poruthams[10];   /* Dina..Vedha, 1 = matched */
+    uint8_t         porutham_matched_count;
+    uint8_t         critical_failure; /* Rajju or Vedha failed */
+} DhruvMilanResult;
+
 /* --- Shadbala & Vimsopaka --- */
 
 typedef struct {
@@ -1650,6 +1841,10 @@ DhruvStatus dhruv_engine_query(
     const DhruvEngineHandle *engine,
     const DhruvQuery *query,
     DhruvStateVector *out);
+DhruvStatus dhruv_engine_query_epoch(
+    const DhruvEngineHandle *engine,
+    const DhruvEpochQuery *query,
+    DhruvStateVector *out);
 DhruvStatus dhruv_engine_query_request(
     const DhruvEngineHandle *engine,
     const DhruvQueryRequest *request,
@@ -1703,6 +1898,11 @@ DhruvStatus dhruv_nutation_iau2000b(double jd_tdb, double *dpsi, double *deps);
 DhruvStatus dhruv_jd_tdb_to_utc(
     const DhruvLskHandle *lsk, double jd_tdb,
     DhruvUtcTime *out);
+/* local_iso_utf8: "YYYY-MM-DDThh:mm:ss" plus Z, +hh:mm, [Zone],
+ * +hh:mm[Zone] or [LMT+lon]; out_utc_offset_seconds may be NULL. */
+DhruvStatus dhruv_local_time_to_utc(
+    const char *local_iso_utf8, int32_t disambiguation,
+    DhruvUtcTime *out_utc, int32_t *out_utc_offset_seconds);
 DhruvStatus dhruv_riseset_result_to_utc(
     const DhruvLskHandle *lsk,
     const DhruvRiseSetResult *result,
@@ -1710,6 +1910,7 @@ DhruvStatus dhruv_riseset_result_to_utc(
 
 /* --- Rise/set --- */
 DhruvRiseSetConfig dhruv_riseset_config_default(void);
+DhruvStatus dhruv_riseset_config_load_tpc(const char *tpc_path, DhruvRiseSetConfig *config);
 DhruvStatus dhruv_compute_rise_set(
     const DhruvEngineHandle *engine,
     const DhruvLskHandle *lsk,
@@ -2025,6 +2226,13 @@ DhruvStatus dhruv_ghatika_for_date(
     const DhruvGeoLocation *location,
     const DhruvRiseSetConfig *riseset_config,
     DhruvGhatikaInfo *out);
+DhruvStatus dhruv_muhurta_for_date(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvUtcTime *utc,
+    const DhruvGeoLocation *location,
+    const DhruvRiseSetConfig *riseset_config,
+    DhruvMuhurtaInfo *out);
 
 /* --- Unified panchang --- */
 DhruvStatus dhruv_panchang_compute_ex(
@@ -2040,6 +2248,8 @@ const char *dhruv_karana_name(uint32_t index);
 const char *dhruv_yoga_name(uint32_t index);
 const char *dhruv_vaar_name(uint32_t index);
 const char *dhruv_hora_name(uint32_t index);
+const char *dhruv_choghadiya_name(uint32_t index);
+const char *dhruv_muhurta_name(uint8_t is_night, uint32_t index);
 
 /* --- Panchang composable intermediates --- */
 DhruvStatus dhruv_elongation_at(
@@ -2477,6 +2687,35 @@ DhruvStatus dhruv_charakaraka_for_date(
     uint8_t scheme,
     DhruvCharakarakaResult *out);
 
+/* --- Krishnamurti Paddhati (KP) --- */
+DhruvStatus dhruv_kp_lords(
+    double sidereal_lon_deg,
+    DhruvKpLords *out);
+DhruvStatus dhruv_kp_chart_for_date(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvUtcTime *utc,
+    const DhruvGeoLocation *location,
+    const DhruvBhavaConfig *bhava_config,
+    const DhruvRiseSetConfig *riseset_config,
+    uint32_t ayanamsha_system,
+    uint8_t use_nutation,
+    DhruvKpChart *out);
+
+/* --- Kundali milan --- */
+DhruvStatus dhruv_kundali_milan(
+    double groom_moon_deg,
+    double bride_moon_deg,
+    DhruvMilanResult *out);
+DhruvStatus dhruv_kundali_milan_for_dates(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvUtcTime *groom_utc,
+    const DhruvUtcTime *bride_utc,
+    uint32_t ayanamsha_system,
+    uint8_t use_nutation,
+    DhruvMilanResult *out);
+
 /* --- Shadbala --- */
 DhruvStatus dhruv_shadbala_for_date(
     const DhruvEngineHandle *engine,
@@ -45,12 +45,15 @@ SMH_FUTURE_FAMILY_STEPHENSON_2016 = 5
 
 TT_UTC_SOURCE_LSK_DELTA_AT = 0
 TT_UTC_SOURCE_DELTA_T_MODEL = 1
+TT_UTC_SOURCE_UTC_DRIFT_TABLE = 2
 
 TIME_WARNING_LSK_FUTURE_FROZEN = 0
 TIME_WARNING_LSK_PRE_RANGE_FALLBACK = 1
 TIME_WARNING_EOP_FUTURE_FROZEN = 2
 TIME_WARNING_EOP_PRE_RANGE_FALLBACK = 3
 TIME_WARNING_DELTA_T_MODEL_USED = 4
+TIME_WARNING_UTC_DRIFT_TABLE_USED = 5
+TIME_WARNING_CIVIL_TIME_AS_UT1 = 6
 
 
 @dataclass(frozen=True)
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Engine, EngineConfig, SpkLoadMode};
 use dhruv_search::{SankrantiConfig, next_purnima, panchang_for_date};
 use dhruv_time::{EopKernel, UtcTime};
 use dhruv_vedic_base::{AyanamshaSystem, GeoLocation, RiseSetConfig};
@@ -30,6 +30,7 @@ fn load_context() -> Option<CliBenchContext> {
         lsk_path: lsk,
         cache_capacity: 256,
         strict_validation: true,
+        spk_load_mode: SpkLoadMode::InMemory,
     })
     .expect("should load engine");
 
@@ -5,10 +5,13 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use clap::{Parser, Subcommand, ValueEnum};
 use dhruv_config::{ConfigResolver, DefaultsMode, EngineConfigPatch, load_with_discovery};
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query};
+use dhruv_core::{
+    Body, BodyRadii, Correction, Engine, EngineConfig, Frame, Observer, PckKernel, Query,
+    TextKernel,
+};
 use dhruv_frames::{
-    PrecessionModel, ReferencePlane, cartesian_to_spherical, icrf_to_ecliptic, nutation_iau2000b,
-    precess_ecliptic_j2000_to_date,
+    DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel, ReferencePlane,
+    cartesian_to_spherical, nutation_with_model,
 };
 use dhruv_search::conjunction_types::{ConjunctionConfig, ConjunctionEvent};
 use dhruv_search::grahan_types::GrahanConfig;
@@ -22,20 +25,22 @@ use dhruv_search::{
 };
 use dhruv_tara::{EarthState, TaraAccuracy, TaraCatalog, TaraConfig, TaraId};
 use dhruv_time::{
-    DeltaTModel, EopKernel, FutureDeltaTTransition, LeapSecondKernel, SmhFutureParabolaFamily,
-    TimeConversionOptions, TimeConversionPolicy, TimeWarning, UtcTime, calendar_to_jd,
+    DeltaTModel, Disambiguation, EopKernel, FutureDeltaTTransition, LeapSecondKernel, LocalTime,
+    LocalTimeResolution, ResolvedLocalTime, SmhFutureParabolaFamily, TdbModel,
+    TimeConversionOptions, TimeConversionPolicy, TimeWarning, TimeZone, UtcTime, calendar_to_jd,
     jd_to_calendar, jd_to_tdb_seconds, tdb_seconds_to_jd,
 };
 use dhruv_vedic_base::bhava_types::SayanadiGhatikaRounding;
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig, RiseSetResult};
 use dhruv_vedic_base::{
     ALL_GRAHAS, AyanamshaSystem, Graha, GulikaMaandiPlanet, LunarNode, NodeDignityPolicy, NodeMode,
-    Rashi, TimeUpagrahaConfig, TimeUpagrahaPoint, ayanamsha_deg, ayanamsha_deg_with_catalog,
-    ayanamsha_mean_deg_with_catalog, ayanamsha_true_deg, deg_to_dms, jd_tdb_to_centuries,
-    nakshatra_from_longitude, nakshatra_from_tropical, nakshatra28_from_longitude,
-    nakshatra28_from_tropical, rashi_from_longitude, rashi_from_tropical,
+    Rashi, TimeUpagrahaConfig, TimeUpagrahaPoint, ayanamsha_deg,
+    ayanamsha_deg_with_catalog_and_models, ayanamsha_mean_deg_with_catalog, ayanamsha_true_deg,
+    deg_to_dms, jd_tdb_to_centuries, nakshatra_from_longitude, nakshatra_from_tropical,
+    nakshatra28_from_longitude, nakshatra28_from_tropical, rashi_from_longitude,
+    rashi_from_tropical,
 };
-use dhruv_vedic_base::{BhavaConfig, ChandraBeneficRule};
+use dhruv_vedic_base::{BhavaConfig, BhavaSystem, ChandraBeneficRule};
 use dhruv_vedic_ops::{
     NodeBackend, NodeOperation, PANCHANG_INCLUDE_ALL, PANCHANG_INCLUDE_ALL_CALENDAR,
     PANCHANG_INCLUDE_ALL_CORE, PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_GHATIKA,
@@ -44,6 +49,7 @@ use dhruv_vedic_ops::{
     PANCHANG_INCLUDE_VARSHA, PANCHANG_INCLUDE_YOGA, PanchangOperation, TaraOperation,
     TaraOutputKind, TaraResult,
 };
+use jpl_kernel::{FrameRotation, KernelError, SpkKernel};
 
 #[derive(Parser)]
 #[command(name = "dhruv", about = "Dhruv ephemeris CLI")]
@@ -73,6 +79,10 @@ struct Cli {
     /// Values: legacy-tt-utc-blend, bridge-modern-endpoint.
     #[arg(long, global = true, default_value = "legacy-tt-utc-blend")]
     future_delta_t_transition: String,
+    /// TT->TDB model for hybrid-deltat policy: naif or
+    /// fairhead-bretagnon-truncated (alias fairhead-bretagnon)
+    #[arg(long, global = true, default_value = "naif")]
+    tdb_model: String,
     /// For hybrid-deltat policy: do not freeze DUT1 after EOP coverage.
     /// By default DUT1 is frozen to last known EOP value.
     #[arg(long, global = true, default_value_t = false)]
@@ -107,6 +117,18 @@ struct Cli {
     command: Commands,
 }
 
+#[derive(clap::Args)]
+struct KernelInfoArgs {
+    /// Path to a binary SPK or PCK kernel
+    kernel: PathBuf,
+    /// Print the DAF comment area
+    #[arg(long)]
+    comments: bool,
+    /// List every segment descriptor
+    #[arg(long)]
+    segments: bool,
+}
+
 #[derive(clap::Args)]
 struct RashiTropicalArgs {
     /// Tropical ecliptic longitude in degrees
@@ -306,6 +328,31 @@ struct HoraArgs {
     eop: PathBuf,
 }
 
+#[derive(clap::Args)]
+struct MuhurtaArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
 #[derive(clap::Args)]
 struct GhatikaArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -838,6 +885,7 @@ impl From<SayanadiGhatikaRoundingArg> for SayanadiGhatikaRounding {
     }
 }
 
+#[allow(clippy::needless_range_loop)]
 fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> dhruv_vedic_base::BhavaResult {
     let lagna = lagna_deg.rem_euclid(360.0);
     let lagna_rashi = (lagna / 30.0).floor() as u8;
@@ -1194,6 +1242,9 @@ struct AyanamshaComputeArgs {
     mode: String,
     #[arg(long)]
     nutation: bool,
+    /// Nutation model for `--nutation`: iau2000b or iau1980
+    #[arg(long, default_value = "iau2000b")]
+    nutation_model: String,
     /// Delta-psi (arcsec) used by `--mode true`
     #[arg(long, default_value_t = 0.0)]
     delta_psi_arcsec: f64,
@@ -1222,6 +1273,10 @@ struct SunriseArgs {
     lsk: Option<PathBuf>,
     #[arg(long)]
     eop: PathBuf,
+    /// Text PCK supplying the solar radius (semidiameter) and Earth
+    /// mean radius (horizon dip) (default: IAU nominal)
+    #[arg(long)]
+    tpc: Option<PathBuf>,
 }
 
 #[derive(clap::Args)]
@@ -1396,6 +1451,10 @@ struct GrahanOpArgs {
     /// Exclude peak-detail fields in results
     #[arg(long, default_value_t = false)]
     no_peak_details: bool,
+    /// Text PCK supplying Sun/Earth/Moon radii for the grahan shadow and
+    /// disk geometry (default: IAU nominal)
+    #[arg(long)]
+    tpc: Option<PathBuf>,
     #[arg(long)]
     bsp: Option<PathBuf>,
     #[arg(long)]
@@ -1478,6 +1537,98 @@ struct SearchSuryaGrahanArgs {
     lsk: Option<PathBuf>,
 }
 
+#[derive(clap::Args)]
+struct SuryaGrahanLocalArgs {
+    /// Find the next solar eclipse after this UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Apply standard atmospheric refraction to Sun altitudes
+    #[arg(long)]
+    refraction: bool,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all); UT1 = UTC without it
+    #[arg(long)]
+    eop: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct ChandraGrahanLocalArgs {
+    /// Find the next lunar eclipse after this UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Apply standard atmospheric refraction to Moon altitudes
+    #[arg(long)]
+    refraction: bool,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all); UT1 = UTC without it
+    #[arg(long)]
+    eop: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct ChandraGrahanMapArgs {
+    /// Find the next lunar eclipse after this UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Grid spacing in degrees (default 10)
+    #[arg(long, default_value = "10")]
+    step_deg: f64,
+    /// Write the grid as CSV (latitude,longitude,visibility) to this file
+    #[arg(long)]
+    csv: Option<PathBuf>,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all); UT1 = UTC without it
+    #[arg(long)]
+    eop: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct SuryaGrahanPathArgs {
+    /// Find the next solar eclipse after this UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Path sampling step in minutes (default 1)
+    #[arg(long, default_value = "1")]
+    step_minutes: f64,
+    /// Write the central line and limits as GeoJSON to this file
+    #[arg(long)]
+    geojson: Option<PathBuf>,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all); UT1 = UTC without it
+    #[arg(long)]
+    eop: Option<PathBuf>,
+}
+
 #[derive(clap::Args)]
 struct NextStationaryArgs {
     #[arg(long)]
@@ -1586,12 +1737,40 @@ struct PositionArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
     #[arg(long)]
     date: String,
-    /// NAIF body code for target (e.g. 10=Sun, 301=Moon, 499=Mars)
+    /// Target: NAIF ID or name (e.g. 10, Moon, Ceres, "Jupiter barycenter")
     #[arg(long)]
-    target: i32,
-    /// NAIF body code for observer (0=SSB, 399=Earth)
+    target: String,
+    /// Observer: NAIF ID or name (0/SSB, 399/Earth, 3/EMB)
     #[arg(long, default_value = "399")]
-    observer: i32,
+    observer: String,
+    /// Correction: geometric, light-time, light-time-aberration or apparent
+    #[arg(long, default_value = "geometric")]
+    correction: String,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct LunarLibrationArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Binary lunar PCK (e.g. moon_pa_de440_200625.bpc)
+    #[arg(long)]
+    bpc: PathBuf,
+    /// Body-fixed frame ID in the PCK (31008 = MOON_PA_DE440, 31006 = MOON_PA_DE421)
+    #[arg(long, default_value = "31008")]
+    frame: i32,
+    /// Frame kernel defining a TK frame on top of the PA frame
+    #[arg(long, requires = "me_frame")]
+    fk: Option<PathBuf>,
+    /// TK frame name from --fk to report in (e.g. MOON_ME_DE440_ME421)
+    #[arg(long, requires = "fk")]
+    me_frame: Option<String>,
     /// Path to SPK kernel
     #[arg(long)]
     bsp: Option<PathBuf>,
@@ -1605,18 +1784,21 @@ struct SiderealLongitudeArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
     #[arg(long)]
     date: String,
-    /// NAIF body code for target
+    /// Target: NAIF ID or name
     #[arg(long)]
-    target: i32,
-    /// NAIF body code for observer (default 399=Earth)
+    target: String,
+    /// Observer: NAIF ID or name (default 399=Earth)
     #[arg(long, default_value = "399")]
-    observer: i32,
+    observer: String,
     /// Ayanamsha system code (0-19, default 0=Lahiri)
     #[arg(long, default_value = "0")]
     ayanamsha: i32,
     /// Apply nutation correction
     #[arg(long)]
     nutation: bool,
+    /// Correction: geometric, light-time, light-time-aberration or apparent
+    #[arg(long, default_value = "geometric")]
+    correction: String,
     /// Path to SPK kernel
     #[arg(long)]
     bsp: Option<PathBuf>,
@@ -1753,6 +1935,10 @@ struct VedicDaySunrisesArgs {
     lsk: Option<PathBuf>,
     #[arg(long)]
     eop: PathBuf,
+    /// Text PCK supplying the solar radius (semidiameter) and Earth
+    /// mean radius (horizon dip) (default: IAU nominal)
+    #[arg(long)]
+    tpc: Option<PathBuf>,
 }
 
 #[derive(clap::Args)]
@@ -2010,6 +2196,139 @@ struct AvasthaArgs {
     bhava_behavior: BhavaBehaviorArgs,
 }
 
+#[derive(clap::Args)]
+struct KpArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Ayanamsha system code (0-19, default 2=KP)
+    #[arg(long, default_value = "2")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct YogasArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct DoshasArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Use the North Indian Mangal dosha bhavas (1,4,7,8,12) instead of 1,2,4,7,8,12
+    #[arg(long)]
+    north: bool,
+    /// Report only full Kaal Sarpa (no graha outside the nodal axis)
+    #[arg(long)]
+    no_partial_kaal_sarpa: bool,
+    /// Gandanta half-width in degrees (default one pada, 3.333)
+    #[arg(long)]
+    gandanta_orb: Option<f64>,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct MilanArgs {
+    /// Groom birth UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long, conflicts_with_all = ["groom_moon", "bride_moon"])]
+    groom_date: Option<String>,
+    /// Bride birth UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long, conflicts_with_all = ["groom_moon", "bride_moon"])]
+    bride_date: Option<String>,
+    /// Precomputed groom Moon sidereal longitude in degrees (skips kernels)
+    #[arg(long)]
+    groom_moon: Option<f64>,
+    /// Precomputed bride Moon sidereal longitude in degrees (skips kernels)
+    #[arg(long)]
+    bride_moon: Option<f64>,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all); required with birth dates
+    #[arg(long)]
+    eop: Option<PathBuf>,
+}
+
 #[derive(clap::Args)]
 struct CharakarakaArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -2334,106 +2653,553 @@ struct TaraPrimitiveArgs {
     observer_sun_distance_au: Option<f64>,
 }
 
-#[derive(Subcommand)]
-enum Commands {
-    /// Show effective resolved configuration (debug utility)
-    ConfigShowEffective,
-    /// Rashi from sidereal longitude
-    Rashi {
-        /// Sidereal ecliptic longitude in degrees
-        lon: f64,
-    },
-    /// Nakshatra from sidereal longitude
-    Nakshatra {
-        /// Sidereal ecliptic longitude in degrees
-        lon: f64,
-        /// Scheme: 27 (default) or 28
-        #[arg(long, default_value = "27")]
-        scheme: u32,
-    },
-    /// Rashi from tropical longitude + ayanamsha
-    RashiTropical(RashiTropicalArgs),
-    /// Nakshatra from tropical longitude + ayanamsha
-    NakshatraTropical(NakshatraTropicalArgs),
-    /// Convert degrees to DMS
-    Dms {
-        /// Angle in decimal degrees
-        deg: f64,
-    },
-    /// Find next Purnima (full moon)
-    NextPurnima {
-        /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
-        #[arg(long)]
-        date: String,
-        /// Path to SPK kernel (de442s.bsp)
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        /// Path to leap second kernel (naif0012.tls)
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
-    /// Find next Amavasya (new moon)
-    NextAmavasya {
-        /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
-        #[arg(long)]
-        date: String,
-        /// Path to SPK kernel
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        /// Path to leap second kernel
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
-    /// Find next Sankranti (Sun entering a rashi)
-    NextSankranti(NextSankrantiArgs),
-    /// Determine the Masa (lunar month) for a date
-    Masa(MasaArgs),
-    /// Determine the Ayana (Uttarayana/Dakshinayana) for a date
-    Ayana(AyanaArgs),
-    /// Determine the Varsha (60-year samvatsara cycle) for a date
-    Varsha(VarshaArgs),
-    /// Determine the Tithi (lunar day) for a date
-    Tithi {
-        /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
-        #[arg(long)]
-        date: String,
-        /// Path to SPK kernel
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        /// Path to leap second kernel
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
-    /// Determine the Karana (half-tithi) for a date
-    Karana {
-        /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
-        #[arg(long)]
-        date: String,
-        /// Path to SPK kernel
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        /// Path to leap second kernel
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
-    /// Determine the Yoga (luni-solar yoga) for a date
-    Yoga(YogaArgs),
-    /// Determine the Moon's Nakshatra (27-scheme) with start/end times for a date
-    MoonNakshatra(MoonNakshatraArgs),
-    /// Determine the Vaar (Vedic weekday) for a date and location
-    Vaar(VaarArgs),
-    /// Determine the Hora (planetary hour) for a date and location
-    Hora(HoraArgs),
-    /// Determine the Ghatika (1-60) for a date and location
-    Ghatika(GhatikaArgs),
-    /// Compute all 16 sphutas for a date and location
-    Sphutas(SphutasArgs),
-    /// Compute all 8 special lagnas for a date and location
-    SpecialLagnas(SpecialLagnasArgs),
-    /// Compute all 12 arudha padas for a date and location
-    ArudhaPadas(ArudhaPadasArgs),
-    /// Combined panchang: tithi, karana, yoga, vaar, hora, ghatika
-    Panchang(PanchangArgs),
+#[derive(clap::Args)]
+struct RashiArgs {
+    /// Sidereal ecliptic longitude in degrees
+    lon: f64,
+}
+
+#[derive(clap::Args)]
+struct NakshatraArgs {
+    /// Sidereal ecliptic longitude in degrees
+    lon: f64,
+    /// Scheme: 27 (default) or 28
+    #[arg(long, default_value = "27")]
+    scheme: u32,
+}
+
+#[derive(clap::Args)]
+struct LocalToUtcArgs {
+    /// Local datetime with zone: YYYY-MM-DDThh:mm:ss followed by +hh:mm,
+    /// [Area/City], -hh:mm[Area/City] or [LMT+lon]
+    date: String,
+    /// Pick a repeated or skipped reading: earlier or later
+    #[arg(long)]
+    disambiguate: Option<String>,
+    /// Use Local Mean Time of this longitude (deg, east positive) before
+    /// the zone adopted standard time
+    #[arg(long)]
+    lmt_longitude: Option<f64>,
+}
+
+#[derive(clap::Args)]
+struct DmsArgs {
+    /// Angle in decimal degrees
+    deg: f64,
+}
+
+#[derive(clap::Args)]
+struct NextPurnimaArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Path to SPK kernel (de442s.bsp)
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel (naif0012.tls)
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct NextAmavasyaArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct TithiArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct KaranaArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct PrevPurnimaArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct PrevAmavasyaArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct NutationComputeArgs {
+    #[arg(long)]
+    date: String,
+    /// Nutation model: iau2000b or iau1980
+    #[arg(long, default_value = "iau2000b")]
+    nutation_model: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct NextChandraGrahanArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct PrevChandraGrahanArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct NextSuryaGrahanArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct PrevSuryaGrahanArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct BhriguBinduArgs {
+    /// Rahu sidereal longitude in degrees
+    #[arg(long)]
+    rahu: f64,
+    /// Moon sidereal longitude in degrees
+    #[arg(long)]
+    moon: f64,
+}
+
+#[derive(clap::Args)]
+struct PranaSphutaArgs {
+    #[arg(long)]
+    lagna: f64,
+    #[arg(long)]
+    moon: f64,
+}
+
+#[derive(clap::Args)]
+struct DehaSphutaArgs {
+    #[arg(long)]
+    moon: f64,
+    #[arg(long)]
+    lagna: f64,
+}
+
+#[derive(clap::Args)]
+struct MrityuSphutaArgs {
+    #[arg(long)]
+    eighth_lord: f64,
+    #[arg(long)]
+    lagna: f64,
+}
+
+#[derive(clap::Args)]
+struct TithiSphutaArgs {
+    #[arg(long)]
+    moon: f64,
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    lagna: f64,
+}
+
+#[derive(clap::Args)]
+struct YogaSphutaArgs {
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    moon: f64,
+}
+
+#[derive(clap::Args)]
+struct YogaSphutaNormalizedArgs {
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    moon: f64,
+}
+
+#[derive(clap::Args)]
+struct RahuTithiSphutaArgs {
+    #[arg(long)]
+    rahu: f64,
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    lagna: f64,
+}
+
+#[derive(clap::Args)]
+struct BeejaSphutaArgs {
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    venus: f64,
+    #[arg(long)]
+    jupiter: f64,
+}
+
+#[derive(clap::Args)]
+struct TriSphutaArgs {
+    #[arg(long)]
+    lagna: f64,
+    #[arg(long)]
+    moon: f64,
+    #[arg(long)]
+    gulika: f64,
+}
+
+#[derive(clap::Args)]
+struct ChatusSphutaArgs {
+    #[arg(long)]
+    trisphuta: f64,
+    #[arg(long)]
+    sun: f64,
+}
+
+#[derive(clap::Args)]
+struct PanchaSphutaArgs {
+    #[arg(long)]
+    chatussphuta: f64,
+    #[arg(long)]
+    rahu: f64,
+}
+
+#[derive(clap::Args)]
+struct AvayogaSphutaArgs {
+    #[arg(long)]
+    sun: f64,
+    #[arg(long)]
+    moon: f64,
+}
+
+#[derive(clap::Args)]
+struct KundaArgs {
+    #[arg(long)]
+    lagna: f64,
+    #[arg(long)]
+    moon: f64,
+    #[arg(long)]
+    mars: f64,
+}
+
+#[derive(clap::Args)]
+struct BhavaLagnaArgs {
+    #[arg(long)]
+    sun_lon: f64,
+    #[arg(long)]
+    ghatikas: f64,
+}
+
+#[derive(clap::Args)]
+struct HoraLagnaArgs {
+    #[arg(long)]
+    sun_lon: f64,
+    #[arg(long)]
+    ghatikas: f64,
+}
+
+#[derive(clap::Args)]
+struct GhatiLagnaArgs {
+    #[arg(long)]
+    sun_lon: f64,
+    #[arg(long)]
+    ghatikas: f64,
+}
+
+#[derive(clap::Args)]
+struct VighatiLagnaArgs {
+    #[arg(long)]
+    lagna_lon: f64,
+    #[arg(long)]
+    vighatikas: f64,
+}
+
+#[derive(clap::Args)]
+struct VarnadaLagnaArgs {
+    #[arg(long)]
+    lagna_lon: f64,
+    #[arg(long)]
+    hora_lagna_lon: f64,
+}
+
+#[derive(clap::Args)]
+struct SreeLagnaArgs {
+    #[arg(long)]
+    moon_lon: f64,
+    #[arg(long)]
+    lagna_lon: f64,
+}
+
+#[derive(clap::Args)]
+struct PranapadaLagnaArgs {
+    #[arg(long)]
+    sun_lon: f64,
+    #[arg(long)]
+    ghatikas: f64,
+}
+
+#[derive(clap::Args)]
+struct InduLagnaArgs {
+    #[arg(long)]
+    moon_lon: f64,
+    /// Graha index of lagna lord (0-8: Sun..Ketu)
+    #[arg(long)]
+    lagna_lord: u8,
+    /// Graha index of Moon's 9th lord (0-8)
+    #[arg(long)]
+    moon_9th_lord: u8,
+}
+
+#[derive(clap::Args)]
+struct TithiFromElongationArgs {
+    /// Elongation (Moon_lon - Sun_lon) mod 360 in degrees
+    #[arg(long)]
+    elongation: f64,
+}
+
+#[derive(clap::Args)]
+struct KaranaFromElongationArgs {
+    #[arg(long)]
+    elongation: f64,
+}
+
+#[derive(clap::Args)]
+struct YogaFromSumArgs {
+    /// Sidereal sum (Sun_sid + Moon_sid) mod 360
+    #[arg(long)]
+    sum: f64,
+}
+
+#[derive(clap::Args)]
+struct VaarFromJdArgs {
+    /// Julian Date
+    #[arg(long)]
+    jd: f64,
+}
+
+#[derive(clap::Args)]
+struct MasaFromRashiArgs {
+    /// Rashi index (0=Mesha .. 11=Meena)
+    #[arg(long)]
+    rashi: u8,
+}
+
+#[derive(clap::Args)]
+struct AyanaFromLonArgs {
+    /// Sidereal longitude in degrees
+    #[arg(long)]
+    lon: f64,
+}
+
+#[derive(clap::Args)]
+struct SamvatsaraComputeArgs {
+    /// CE year
+    #[arg(long)]
+    year: i32,
+}
+
+#[derive(clap::Args)]
+struct NthRashiFromArgs {
+    /// Starting rashi index (0-11)
+    #[arg(long)]
+    rashi: u8,
+    /// Offset in signs
+    #[arg(long)]
+    offset: u8,
+}
+
+#[derive(clap::Args)]
+struct RashiLordArgs {
+    /// Rashi index (0-11)
+    #[arg(long)]
+    rashi: u8,
+}
+
+#[derive(clap::Args)]
+struct Normalize360Args {
+    /// Angle in degrees
+    #[arg(long)]
+    deg: f64,
+}
+
+#[derive(clap::Args)]
+struct ArudhaPadaComputeArgs {
+    /// Bhava cusp longitude in degrees
+    #[arg(long)]
+    cusp_lon: f64,
+    /// Lord longitude in degrees
+    #[arg(long)]
+    lord_lon: f64,
+}
+
+#[derive(clap::Args)]
+struct SunBasedUpagrahasArgs {
+    /// Sun's sidereal longitude in degrees
+    #[arg(long)]
+    sun_lon: f64,
+}
+
+#[derive(clap::Args)]
+struct ElongationAtArgs {
+    #[arg(long)]
+    date: String,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct CalculateAshtakavargaArgs {
+    /// Comma-separated rashi indices for Sun,Moon,Mars,Mercury,Jupiter,Venus,Saturn (0-11)
+    #[arg(long)]
+    graha_rashis: String,
+    /// Lagna rashi index (0-11)
+    #[arg(long)]
+    lagna_rashi: u8,
+}
+
+#[derive(clap::Args)]
+struct GrahaDrishtiComputeArgs {
+    /// Graha index (0=Sun, 1=Moon, ..., 8=Ketu)
+    #[arg(long)]
+    graha: u8,
+    /// Source longitude in degrees
+    #[arg(long)]
+    source: f64,
+    /// Target longitude in degrees
+    #[arg(long)]
+    target: f64,
+}
+
+#[derive(clap::Args)]
+struct GrahaDrishtiMatrixComputeArgs {
+    /// Comma-separated sidereal longitudes for all 9 grahas
+    #[arg(long)]
+    longitudes: String,
+}
+
+#[derive(clap::Args)]
+struct TaraListArgs {
+    /// Path to star catalog JSON
+    #[arg(long)]
+    catalog: PathBuf,
+    /// Filter by category: yogatara, rashi, special, galactic (optional)
+    #[arg(long)]
+    category: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Show effective resolved configuration (debug utility)
+    ConfigShowEffective,
+    /// Rashi from sidereal longitude
+    Rashi(RashiArgs),
+    /// Nakshatra from sidereal longitude
+    Nakshatra(NakshatraArgs),
+    /// Rashi from tropical longitude + ayanamsha
+    RashiTropical(RashiTropicalArgs),
+    /// Nakshatra from tropical longitude + ayanamsha
+    NakshatraTropical(NakshatraTropicalArgs),
+    /// Inspect a binary SPK/PCK kernel: metadata, segments, coverage gaps
+    KernelInfo(KernelInfoArgs),
+    /// Resolve a local civil time to UTC, reporting DST gaps and repeats
+    LocalToUtc(LocalToUtcArgs),
+    /// List zones in the embedded tz database
+    TzList,
+    /// Convert degrees to DMS
+    Dms(DmsArgs),
+    /// Find next Purnima (full moon)
+    NextPurnima(NextPurnimaArgs),
+    /// Find next Amavasya (new moon)
+    NextAmavasya(NextAmavasyaArgs),
+    /// Find next Sankranti (Sun entering a rashi)
+    NextSankranti(NextSankrantiArgs),
+    /// Determine the Masa (lunar month) for a date
+    Masa(MasaArgs),
+    /// Determine the Ayana (Uttarayana/Dakshinayana) for a date
+    Ayana(AyanaArgs),
+    /// Determine the Varsha (60-year samvatsara cycle) for a date
+    Varsha(VarshaArgs),
+    /// Determine the Tithi (lunar day) for a date
+    Tithi(TithiArgs),
+    /// Determine the Karana (half-tithi) for a date
+    Karana(KaranaArgs),
+    /// Determine the Yoga (luni-solar yoga) for a date
+    Yoga(YogaArgs),
+    /// Determine the Moon's Nakshatra (27-scheme) with start/end times for a date
+    MoonNakshatra(MoonNakshatraArgs),
+    /// Determine the Vaar (Vedic weekday) for a date and location
+    Vaar(VaarArgs),
+    /// Determine the Hora (planetary hour) for a date and location
+    Hora(HoraArgs),
+    /// Determine the Ghatika (1-60) for a date and location
+    Ghatika(GhatikaArgs),
+    /// Daily muhurta periods: Rahu Kalam, Yamaganda, Gulika, Abhijit, Brahma, Durmuhurta, Choghadiya
+    Muhurta(MuhurtaArgs),
+    /// Compute all 16 sphutas for a date and location
+    Sphutas(SphutasArgs),
+    /// Compute all 8 special lagnas for a date and location
+    SpecialLagnas(SpecialLagnasArgs),
+    /// Compute all 12 arudha padas for a date and location
+    ArudhaPadas(ArudhaPadasArgs),
+    /// Combined panchang: tithi, karana, yoga, vaar, hora, ghatika
+    Panchang(PanchangArgs),
     /// Compute Ashtakavarga (BAV + SAV) for a date and location
     Ashtakavarga(AshtakavargaArgs),
     /// Compute all 11 upagrahas for a date and location
@@ -2447,23 +3213,9 @@ enum Commands {
     /// Compute full kundali in one call (shared intermediates across sections)
     Kundali(KundaliArgs),
     /// Find previous Purnima (full moon)
-    PrevPurnima {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    PrevPurnima(PrevPurnimaArgs),
     /// Find previous Amavasya (new moon)
-    PrevAmavasya {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    PrevAmavasya(PrevAmavasyaArgs),
     /// Find previous Sankranti
     PrevSankranti(PrevSankrantiArgs),
     /// Search Purnimas in a date range
@@ -2479,14 +3231,7 @@ enum Commands {
     /// Compute ayanamsha for a date
     AyanamshaCompute(AyanamshaComputeArgs),
     /// Compute nutation (dpsi, deps) for a date
-    NutationCompute {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    NutationCompute(NutationComputeArgs),
     /// Compute sunrise/sunset and twilight events
     Sunrise(SunriseArgs),
     /// Compute bhava (house) cusps
@@ -2510,45 +3255,25 @@ enum Commands {
     /// Unified sankranti operation (`--mode next|prev|range [--rashi 0..11]`)
     Sankranti(SankrantiOpArgs),
     /// Find next lunar eclipse
-    NextChandraGrahan {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    NextChandraGrahan(NextChandraGrahanArgs),
     /// Find previous lunar eclipse
-    PrevChandraGrahan {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    PrevChandraGrahan(PrevChandraGrahanArgs),
     /// Search lunar eclipses in a date range
     SearchChandraGrahan(SearchChandraGrahanArgs),
+    /// Moon altitude at each contact and visible window of the next lunar eclipse
+    ChandraGrahanLocal(ChandraGrahanLocalArgs),
+    /// World visibility grid of the next lunar eclipse
+    ChandraGrahanMap(ChandraGrahanMapArgs),
     /// Find next solar eclipse
-    NextSuryaGrahan {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    NextSuryaGrahan(NextSuryaGrahanArgs),
     /// Find previous solar eclipse
-    PrevSuryaGrahan {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    PrevSuryaGrahan(PrevSuryaGrahanArgs),
     /// Search solar eclipses in a date range
     SearchSuryaGrahan(SearchSuryaGrahanArgs),
+    /// Local circumstances of the next solar eclipse at a location
+    SuryaGrahanLocal(SuryaGrahanLocalArgs),
+    /// Besselian elements and ground path of the next solar eclipse
+    SuryaGrahanPath(SuryaGrahanPathArgs),
     /// Unified motion operation (`--kind stationary|max-speed --mode next|prev|range`)
     Motion(MotionOpArgs),
     /// Find next stationary point of a planet
@@ -2565,6 +3290,8 @@ enum Commands {
     SearchMaxSpeed(SearchMaxSpeedArgs),
     /// Query spherical position of a body (lon, lat, distance)
     Position(PositionArgs),
+    /// Lunar libration (sub-Earth point) and pole from a binary lunar PCK
+    LunarLibration(LunarLibrationArgs),
     /// Sidereal longitude of a body
     SiderealLongitude(SiderealLongitudeArgs),
     /// Sidereal longitudes of all 9 grahas
@@ -2576,264 +3303,85 @@ enum Commands {
     // Individual Sphuta Formulas (pure math)
     // -------------------------------------------------------------------
     /// Compute Bhrigu Bindu = midpoint(Rahu, Moon)
-    BhriguBindu {
-        /// Rahu sidereal longitude in degrees
-        #[arg(long)]
-        rahu: f64,
-        /// Moon sidereal longitude in degrees
-        #[arg(long)]
-        moon: f64,
-    },
+    BhriguBindu(BhriguBinduArgs),
     /// Compute Prana Sphuta = Lagna + Moon
-    PranaSphuta {
-        #[arg(long)]
-        lagna: f64,
-        #[arg(long)]
-        moon: f64,
-    },
+    PranaSphuta(PranaSphutaArgs),
     /// Compute Deha Sphuta = Moon + Lagna
-    DehaSphuta {
-        #[arg(long)]
-        moon: f64,
-        #[arg(long)]
-        lagna: f64,
-    },
+    DehaSphuta(DehaSphutaArgs),
     /// Compute Mrityu Sphuta = 8th lord + Lagna
-    MrityuSphuta {
-        #[arg(long)]
-        eighth_lord: f64,
-        #[arg(long)]
-        lagna: f64,
-    },
+    MrityuSphuta(MrityuSphutaArgs),
     /// Compute Tithi Sphuta = (Moon - Sun) + Lagna
-    TithiSphuta {
-        #[arg(long)]
-        moon: f64,
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        lagna: f64,
-    },
+    TithiSphuta(TithiSphutaArgs),
     /// Compute Yoga Sphuta = Sun + Moon (raw sum)
-    YogaSphuta {
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        moon: f64,
-    },
+    YogaSphuta(YogaSphutaArgs),
     /// Compute Yoga Sphuta Normalized = (Sun + Moon) mod 360
-    YogaSphutaNormalized {
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        moon: f64,
-    },
+    YogaSphutaNormalized(YogaSphutaNormalizedArgs),
     /// Compute Rahu Tithi Sphuta = (Rahu - Sun) + Lagna
-    RahuTithiSphuta {
-        #[arg(long)]
-        rahu: f64,
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        lagna: f64,
-    },
+    RahuTithiSphuta(RahuTithiSphutaArgs),
     /// Compute Kshetra Sphuta from Venus, Moon, Mars, Jupiter, Lagna
     KshetraSphuta(KshetraSphutaArgs),
     /// Compute Beeja Sphuta from Sun, Venus, Jupiter
-    BeejaSphuta {
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        venus: f64,
-        #[arg(long)]
-        jupiter: f64,
-    },
+    BeejaSphuta(BeejaSphutaArgs),
     /// Compute TriSphuta = Lagna + Moon + Gulika
-    TriSphuta {
-        #[arg(long)]
-        lagna: f64,
-        #[arg(long)]
-        moon: f64,
-        #[arg(long)]
-        gulika: f64,
-    },
+    TriSphuta(TriSphutaArgs),
     /// Compute ChatusSphuta = TriSphuta + Sun
-    ChatusSphuta {
-        #[arg(long)]
-        trisphuta: f64,
-        #[arg(long)]
-        sun: f64,
-    },
+    ChatusSphuta(ChatusSphutaArgs),
     /// Compute PanchaSphuta = ChatusSphuta + Rahu
-    PanchaSphuta {
-        #[arg(long)]
-        chatussphuta: f64,
-        #[arg(long)]
-        rahu: f64,
-    },
+    PanchaSphuta(PanchaSphutaArgs),
     /// Compute Sookshma TriSphuta = Lagna + Moon + Gulika + Sun
     SookshmaTrisphuta(SookshmaTrisphutaArgs),
     /// Compute Avayoga Sphuta
-    AvayogaSphuta {
-        #[arg(long)]
-        sun: f64,
-        #[arg(long)]
-        moon: f64,
-    },
+    AvayogaSphuta(AvayogaSphutaArgs),
     /// Compute Kunda = Lagna + Moon + Mars
-    Kunda {
-        #[arg(long)]
-        lagna: f64,
-        #[arg(long)]
-        moon: f64,
-        #[arg(long)]
-        mars: f64,
-    },
+    Kunda(KundaArgs),
 
     // -------------------------------------------------------------------
     // Individual Special Lagna Formulas (pure math)
     // -------------------------------------------------------------------
     /// Compute Bhava Lagna from Sun longitude and ghatikas
-    BhavaLagna {
-        #[arg(long)]
-        sun_lon: f64,
-        #[arg(long)]
-        ghatikas: f64,
-    },
+    BhavaLagna(BhavaLagnaArgs),
     /// Compute Hora Lagna from Sun longitude and ghatikas
-    HoraLagna {
-        #[arg(long)]
-        sun_lon: f64,
-        #[arg(long)]
-        ghatikas: f64,
-    },
+    HoraLagna(HoraLagnaArgs),
     /// Compute Ghati Lagna from Sun longitude and ghatikas
-    GhatiLagna {
-        #[arg(long)]
-        sun_lon: f64,
-        #[arg(long)]
-        ghatikas: f64,
-    },
+    GhatiLagna(GhatiLagnaArgs),
     /// Compute Vighati Lagna from Lagna longitude and vighatikas
-    VighatiLagna {
-        #[arg(long)]
-        lagna_lon: f64,
-        #[arg(long)]
-        vighatikas: f64,
-    },
+    VighatiLagna(VighatiLagnaArgs),
     /// Compute Varnada Lagna from Lagna and Hora Lagna longitudes
-    VarnadaLagna {
-        #[arg(long)]
-        lagna_lon: f64,
-        #[arg(long)]
-        hora_lagna_lon: f64,
-    },
+    VarnadaLagna(VarnadaLagnaArgs),
     /// Compute Sree Lagna from Moon and Lagna longitudes
-    SreeLagna {
-        #[arg(long)]
-        moon_lon: f64,
-        #[arg(long)]
-        lagna_lon: f64,
-    },
+    SreeLagna(SreeLagnaArgs),
     /// Compute Pranapada Lagna from Sun longitude and ghatikas
-    PranapadaLagna {
-        #[arg(long)]
-        sun_lon: f64,
-        #[arg(long)]
-        ghatikas: f64,
-    },
+    PranapadaLagna(PranapadaLagnaArgs),
     /// Compute Indu Lagna from Moon longitude and graha lord indices
-    InduLagna {
-        #[arg(long)]
-        moon_lon: f64,
-        /// Graha index of lagna lord (0-8: Sun..Ketu)
-        #[arg(long)]
-        lagna_lord: u8,
-        /// Graha index of Moon's 9th lord (0-8)
-        #[arg(long)]
-        moon_9th_lord: u8,
-    },
+    InduLagna(InduLagnaArgs),
 
     // -------------------------------------------------------------------
     // Utility Primitives
     // -------------------------------------------------------------------
     /// Determine Tithi from Moon-Sun elongation (degrees)
-    TithiFromElongation {
-        /// Elongation (Moon_lon - Sun_lon) mod 360 in degrees
-        #[arg(long)]
-        elongation: f64,
-    },
+    TithiFromElongation(TithiFromElongationArgs),
     /// Determine Karana from Moon-Sun elongation (degrees)
-    KaranaFromElongation {
-        #[arg(long)]
-        elongation: f64,
-    },
+    KaranaFromElongation(KaranaFromElongationArgs),
     /// Determine Yoga from sidereal sum (Sun + Moon) degrees
-    YogaFromSum {
-        /// Sidereal sum (Sun_sid + Moon_sid) mod 360
-        #[arg(long)]
-        sum: f64,
-    },
+    YogaFromSum(YogaFromSumArgs),
     /// Determine Vaar (weekday) from Julian Date
-    VaarFromJd {
-        /// Julian Date
-        #[arg(long)]
-        jd: f64,
-    },
+    VaarFromJd(VaarFromJdArgs),
     /// Determine Masa from rashi index (0-11)
-    MasaFromRashi {
-        /// Rashi index (0=Mesha .. 11=Meena)
-        #[arg(long)]
-        rashi: u8,
-    },
+    MasaFromRashi(MasaFromRashiArgs),
     /// Determine Ayana from sidereal longitude
-    AyanaFromLon {
-        /// Sidereal longitude in degrees
-        #[arg(long)]
-        lon: f64,
-    },
+    AyanaFromLon(AyanaFromLonArgs),
     /// Determine Samvatsara from a year
-    SamvatsaraCompute {
-        /// CE year
-        #[arg(long)]
-        year: i32,
-    },
+    SamvatsaraCompute(SamvatsaraComputeArgs),
     /// Compute the rashi index that is N signs from a starting rashi
-    NthRashiFrom {
-        /// Starting rashi index (0-11)
-        #[arg(long)]
-        rashi: u8,
-        /// Offset in signs
-        #[arg(long)]
-        offset: u8,
-    },
+    NthRashiFrom(NthRashiFromArgs),
     /// Determine the rashi lord for a rashi index
-    RashiLord {
-        /// Rashi index (0-11)
-        #[arg(long)]
-        rashi: u8,
-    },
+    RashiLord(RashiLordArgs),
     /// Normalize angle to [0, 360)
-    Normalize360 {
-        /// Angle in degrees
-        #[arg(long)]
-        deg: f64,
-    },
+    Normalize360(Normalize360Args),
     /// Compute a single arudha pada from bhava cusp and lord longitudes
-    ArudhaPadaCompute {
-        /// Bhava cusp longitude in degrees
-        #[arg(long)]
-        cusp_lon: f64,
-        /// Lord longitude in degrees
-        #[arg(long)]
-        lord_lon: f64,
-    },
+    ArudhaPadaCompute(ArudhaPadaComputeArgs),
     /// Compute 5 sun-based upagrahas from Sun's sidereal longitude
-    SunBasedUpagrahas {
-        /// Sun's sidereal longitude in degrees
-        #[arg(long)]
-        sun_lon: f64,
-    },
+    SunBasedUpagrahas(SunBasedUpagrahasArgs),
     /// Low-level graha relationship, dignity, and combustion helpers
     GrahaHelper(GrahaHelperArgs),
     /// Low-level time and frame utility helpers
@@ -2845,14 +3393,7 @@ enum Commands {
     // Panchang Intermediates (engine required)
     // -------------------------------------------------------------------
     /// Compute Moon-Sun elongation at a date
-    ElongationAt {
-        #[arg(long)]
-        date: String,
-        #[arg(long)]
-        bsp: Option<PathBuf>,
-        #[arg(long)]
-        lsk: Option<PathBuf>,
-    },
+    ElongationAt(ElongationAtArgs),
     /// Compute sidereal sum (Moon + Sun) at a date
     SiderealSumAt(SiderealSumAtArgs),
     /// Query body ecliptic longitude and latitude
@@ -2872,32 +3413,11 @@ enum Commands {
     // Low-level Ashtakavarga / Drishti
     // -------------------------------------------------------------------
     /// Compute full Ashtakavarga from rashi positions
-    CalculateAshtakavarga {
-        /// Comma-separated rashi indices for Sun,Moon,Mars,Mercury,Jupiter,Venus,Saturn (0-11)
-        #[arg(long)]
-        graha_rashis: String,
-        /// Lagna rashi index (0-11)
-        #[arg(long)]
-        lagna_rashi: u8,
-    },
+    CalculateAshtakavarga(CalculateAshtakavargaArgs),
     /// Compute graha drishti between two points
-    GrahaDrishtiCompute {
-        /// Graha index (0=Sun, 1=Moon, ..., 8=Ketu)
-        #[arg(long)]
-        graha: u8,
-        /// Source longitude in degrees
-        #[arg(long)]
-        source: f64,
-        /// Target longitude in degrees
-        #[arg(long)]
-        target: f64,
-    },
+    GrahaDrishtiCompute(GrahaDrishtiComputeArgs),
     /// Compute full 9×9 graha drishti matrix from longitudes
-    GrahaDrishtiMatrixCompute {
-        /// Comma-separated sidereal longitudes for all 9 grahas
-        #[arg(long)]
-        longitudes: String,
-    },
+    GrahaDrishtiMatrixCompute(GrahaDrishtiMatrixComputeArgs),
     /// Compute Shadbala (six-fold planetary strength) for a date and location
     Shadbala(ShadbalaArgs),
     /// Compute Bhava Bala (house strength) for a date and location
@@ -2908,6 +3428,14 @@ enum Commands {
     Vimsopaka(VimsopakaArgs),
     /// Compute Chara Karaka assignments for a date
     Charakaraka(CharakarakaArgs),
+    /// Compute a Krishnamurti Paddhati chart (star/sub/sub-sub lords, significators, ruling planets)
+    Kp(KpArgs),
+    /// Compute Ashtakoota (36 guna) and Dashakoota (10 porutham) marriage compatibility
+    Milan(MilanArgs),
+    /// Detect classical graha yogas (Mahapurusha, Raja, Dhana, Chandra/Surya, ...) for a date and location
+    Yogas(YogasArgs),
+    /// Detect Mangal, Kaal Sarpa, Pitru and Gandanta doshas with cancellation reasons
+    Doshas(DoshasArgs),
     /// Transform a sidereal longitude through amsha (divisional chart) mappings
     Amsha(AmshaArgs),
     /// List supported variation codes and names for one or more amshas
@@ -2919,14 +3447,7 @@ enum Commands {
     /// Compute Dasha (planetary period) hierarchy or snapshot
     Dasha(DashaArgs),
     /// List all fixed stars in a catalog
-    TaraList {
-        /// Path to star catalog JSON
-        #[arg(long)]
-        catalog: PathBuf,
-        /// Filter by category: yogatara, rashi, special, galactic (optional)
-        #[arg(long)]
-        category: Option<String>,
-    },
+    TaraList(TaraListArgs),
     /// Compute fixed star position (equatorial, ecliptic, or sidereal)
     TaraPosition(TaraPositionArgs),
 }
@@ -2938,24 +3459,12 @@ fn aya_system_from_code(code: i32) -> Option<AyanamshaSystem> {
 }
 
 fn parse_utc(s: &str) -> Result<UtcTime, String> {
-    // Parse "YYYY-MM-DDThh:mm:ssZ" or "YYYY-MM-DDThh:mm:ss"
-    let s = s.trim_end_matches('Z');
-    let parts: Vec<&str> = s.split('T').collect();
-    if parts.len() != 2 {
-        return Err(format!("expected YYYY-MM-DDThh:mm:ssZ, got {s}"));
-    }
-    let date_parts: Vec<&str> = parts[0].split('-').collect();
-    let time_parts: Vec<&str> = parts[1].split(':').collect();
-    if date_parts.len() != 3 || time_parts.len() != 3 {
-        return Err(format!("invalid date/time format: {s}"));
-    }
-    let year: i32 = date_parts[0].parse().map_err(|e| format!("{e}"))?;
-    let month: u32 = date_parts[1].parse().map_err(|e| format!("{e}"))?;
-    let day: u32 = date_parts[2].parse().map_err(|e| format!("{e}"))?;
-    let hour: u32 = time_parts[0].parse().map_err(|e| format!("{e}"))?;
-    let minute: u32 = time_parts[1].parse().map_err(|e| format!("{e}"))?;
-    let second: f64 = time_parts[2].parse().map_err(|e| format!("{e}"))?;
-    UtcTime::try_new(year, month, day, hour, minute, second, None).map_err(|e| e.to_string())
+    // Parse "YYYY-MM-DDThh:mm:ssZ" or "YYYY-MM-DDThh:mm:ss" (UTC), or a
+    // local time with "+05:30", "[Asia/Kolkata]", "-04:00[America/New_York]"
+    // or "[LMT+77.209]". Repeated or skipped local readings are rejected.
+    LocalTime::parse_with_default_zone(s, &TimeZone::utc())
+        .and_then(|local| local.to_utc())
+        .map_err(|e| e.to_string())
 }
 
 fn parse_defaults_mode(s: &str) -> DefaultsMode {
@@ -2983,6 +3492,7 @@ fn resolve_engine_config_for_cli(
             lsk_path: lsk.as_ref().map(|p| p.to_string_lossy().to_string()),
             cache_capacity: Some(256),
             strict_validation: Some(true),
+            spk_load_mode: None,
         };
         return resolver
             .resolve_engine(Some(patch))
@@ -3017,6 +3527,17 @@ fn load_engine(bsp: &Option<PathBuf>, lsk: &Option<PathBuf>) -> Engine {
     engine
 }
 
+fn load_body_radii(tpc: &Option<PathBuf>) -> BodyRadii {
+    let Some(path) = tpc else {
+        return BodyRadii::default();
+    };
+    let kernel = TextKernel::load(path).unwrap_or_else(|e| {
+        eprintln!("Failed to load text PCK: {e}");
+        std::process::exit(1);
+    });
+    BodyRadii::from_text_kernel(&kernel)
+}
+
 fn require_aya_system(code: i32) -> AyanamshaSystem {
     aya_system_from_code(code).unwrap_or_else(|| {
         eprintln!("Invalid ayanamsha code: {code} (0-19)");
@@ -3024,6 +3545,21 @@ fn require_aya_system(code: i32) -> AyanamshaSystem {
     })
 }
 
+fn parse_correction(s: &str) -> Correction {
+    match s.to_lowercase().as_str() {
+        "geometric" | "none" => Correction::Geometric,
+        "light-time" | "lt" => Correction::LightTime,
+        "light-time-aberration" | "lt+s" => Correction::LightTimeAberration,
+        "apparent" => Correction::Apparent,
+        _ => {
+            eprintln!(
+                "Invalid correction: {s} (geometric, light-time, light-time-aberration, apparent)"
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
 fn parse_precession_model(s: &str) -> PrecessionModel {
     match s {
         "vondrak2011" | "vondrak" => PrecessionModel::Vondrak2011,
@@ -3039,6 +3575,17 @@ fn parse_precession_model(s: &str) -> PrecessionModel {
     }
 }
 
+fn parse_nutation_model(s: &str) -> NutationModel {
+    match s.to_lowercase().as_str() {
+        "iau2000b" | "2000b" => NutationModel::Iau2000B,
+        "iau1980" | "1980" => NutationModel::Iau1980,
+        _ => {
+            eprintln!("Invalid nutation model: {s} (iau2000b, iau1980)");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn parse_reference_plane_arg(s: &str, default_plane: ReferencePlane) -> ReferencePlane {
     match s {
         "default" => default_plane,
@@ -3125,13 +3672,17 @@ fn require_body(code: i32) -> Body {
     })
 }
 
-fn require_observer(code: i32) -> Observer {
-    Observer::from_code(code).unwrap_or_else(|| {
-        eprintln!("Invalid observer code: {code}");
+fn require_body_spec(spec: &str) -> Body {
+    Body::from_name(spec).unwrap_or_else(|| {
+        eprintln!("Unknown body: {spec} (use a NAIF ID or name)");
         std::process::exit(1);
     })
 }
 
+fn require_observer_spec(spec: &str) -> Observer {
+    Observer::naif(require_body_spec(spec).code())
+}
+
 fn parse_panchang_include_mask(raw: &str) -> Result<u32, String> {
     let mut mask = 0_u32;
     for token in raw.split(',').map(str::trim).filter(|t| !t.is_empty()) {
@@ -3173,6 +3724,8 @@ fn utc_to_jd_utc(utc: &UtcTime) -> f64 {
 static CLI_WARNED_LSK_PRE: AtomicBool = AtomicBool::new(false);
 static CLI_WARNED_LSK_FUTURE: AtomicBool = AtomicBool::new(false);
 static CLI_WARNED_DELTA_T: AtomicBool = AtomicBool::new(false);
+static CLI_WARNED_UTC_DRIFT: AtomicBool = AtomicBool::new(false);
+static CLI_WARNED_CIVIL_UT1: AtomicBool = AtomicBool::new(false);
 static CLI_WARNED_STALE_LSK: AtomicBool = AtomicBool::new(false);
 static CLI_WARNED_STALE_EOP: AtomicBool = AtomicBool::new(false);
 static STALE_LSK_THRESHOLD_DAYS: OnceLock<Option<f64>> = OnceLock::new();
@@ -3205,6 +3758,90 @@ fn jd_utc_to_iso_string(jd: f64) -> String {
     format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:09.6}Z")
 }
 
+fn tdb_seconds_to_iso_string(s: f64) -> String {
+    let iso = jd_utc_to_iso_string(tdb_seconds_to_jd(s));
+    format!("{} TDB", iso.trim_end_matches('Z'))
+}
+
+fn print_kernel_info(args: &KernelInfoArgs) -> Result<(), KernelError> {
+    let comments = match SpkKernel::open(&args.kernel) {
+        Ok(kernel) => {
+            println!("File type:     {}", kernel.file_id());
+            println!("Internal name: {}", kernel.internal_name());
+            println!("Segments:      {}", kernel.segments().len());
+            if args.segments {
+                println!();
+                for (i, seg) in kernel.segments().iter().enumerate() {
+                    println!(
+                        "  [{i}] target {} center {} frame {} type {} {} .. {}  {}",
+                        seg.target,
+                        seg.center,
+                        seg.frame,
+                        seg.data_type,
+                        tdb_seconds_to_iso_string(seg.start_epoch),
+                        tdb_seconds_to_iso_string(seg.end_epoch),
+                        seg.name
+                    );
+                }
+            }
+            println!();
+            println!("Coverage:");
+            for cov in kernel.coverage() {
+                println!(
+                    "  target {} wrt {} ({} segment(s)){}",
+                    cov.target,
+                    cov.center,
+                    cov.segment_count,
+                    if cov.is_contiguous() { "" } else { "  GAPS" }
+                );
+                for w in &cov.windows {
+                    println!(
+                        "    {} .. {}",
+                        tdb_seconds_to_iso_string(w.start_epoch),
+                        tdb_seconds_to_iso_string(w.end_epoch)
+                    );
+                }
+                for gap in &cov.gaps {
+                    println!(
+                        "    gap {} .. {}",
+                        tdb_seconds_to_iso_string(gap.start_epoch),
+                        tdb_seconds_to_iso_string(gap.end_epoch)
+                    );
+                }
+            }
+            kernel.comments()?
+        }
+        Err(KernelError::BadFileId(_)) => {
+            let kernel = PckKernel::open(&args.kernel)?;
+            println!("File type:     DAF/PCK");
+            println!("Internal name: {}", kernel.internal_name());
+            println!("Segments:      {}", kernel.segments().len());
+            if args.segments {
+                println!();
+                for (i, seg) in kernel.segments().iter().enumerate() {
+                    println!(
+                        "  [{i}] body frame {} wrt {} type {} {} .. {}  {}",
+                        seg.body_frame,
+                        seg.reference_frame,
+                        seg.data_type,
+                        tdb_seconds_to_iso_string(seg.start_epoch),
+                        tdb_seconds_to_iso_string(seg.end_epoch),
+                        seg.name
+                    );
+                }
+            }
+            kernel.comments()?
+        }
+        Err(e) => return Err(e),
+    };
+    if args.comments {
+        println!();
+        println!("Comments:");
+        println!("{comments}");
+    }
+    Ok(())
+}
+
 fn maybe_warn_stale_lsk(lsk: &LeapSecondKernel) {
     let Some(Some(threshold_days)) = STALE_LSK_THRESHOLD_DAYS.get().copied() else {
         return;
@@ -3240,6 +3877,7 @@ fn maybe_warn_stale_eop(eop: &EopKernel) {
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::field_reassign_with_default)]
 fn parse_time_policy(
     s: &str,
     delta_t_model: DeltaTModel,
@@ -3249,6 +3887,7 @@ fn parse_time_policy(
     no_warn_on_fallback: bool,
     pre_range_dut1: Option<f64>,
     future_transition_years: Option<f64>,
+    tdb_model: TdbModel,
 ) -> TimeConversionPolicy {
     match s {
         "strict-lsk" => TimeConversionPolicy::StrictLsk,
@@ -3265,6 +3904,7 @@ fn parse_time_policy(
             if let Some(v) = future_transition_years {
                 opts.future_transition_years = v;
             }
+            opts.tdb_model = tdb_model;
             TimeConversionPolicy::HybridDeltaT(opts)
         }
         _ => {
@@ -3298,6 +3938,19 @@ fn parse_delta_t_model(s: &str) -> DeltaTModel {
     }
 }
 
+fn parse_tdb_model(s: &str) -> TdbModel {
+    match s.to_lowercase().as_str() {
+        "naif" | "one-term" => TdbModel::NaifOneTerm,
+        "fairhead-bretagnon-truncated" | "fairhead-bretagnon" | "fb" => {
+            TdbModel::FairheadBretagnonTruncated
+        }
+        _ => {
+            eprintln!("Invalid TDB model: {s} (naif, fairhead-bretagnon-truncated)");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn parse_smh_future_family(s: &str) -> SmhFutureParabolaFamily {
     match s.to_lowercase().as_str() {
         "addendum2020" | "smh2020" | "piecewise" => SmhFutureParabolaFamily::Addendum2020Piecewise,
@@ -3323,20 +3976,30 @@ fn parse_smh_future_family(s: &str) -> SmhFutureParabolaFamily {
 
 fn emit_cli_time_warning_once(warning: &TimeWarning) {
     match warning {
-        TimeWarning::LskPreRangeFallback { .. } => {
-            if !CLI_WARNED_LSK_PRE.swap(true, Ordering::Relaxed) {
-                eprintln!("Warning: {warning}");
-            }
+        TimeWarning::LskPreRangeFallback { .. }
+            if !CLI_WARNED_LSK_PRE.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
         }
-        TimeWarning::LskFutureFrozen { .. } => {
-            if !CLI_WARNED_LSK_FUTURE.swap(true, Ordering::Relaxed) {
-                eprintln!("Warning: {warning}");
-            }
+        TimeWarning::LskFutureFrozen { .. }
+            if !CLI_WARNED_LSK_FUTURE.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
         }
-        TimeWarning::DeltaTModelUsed { .. } => {
-            if !CLI_WARNED_DELTA_T.swap(true, Ordering::Relaxed) {
-                eprintln!("Warning: {warning}");
-            }
+        TimeWarning::DeltaTModelUsed { .. }
+            if !CLI_WARNED_DELTA_T.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
+        }
+        TimeWarning::UtcDriftTableUsed { .. }
+            if !CLI_WARNED_UTC_DRIFT.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
+        }
+        TimeWarning::CivilTimeAsUt1 { .. }
+            if !CLI_WARNED_CIVIL_UT1.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
         }
         _ => {}
     }
@@ -3689,6 +4352,7 @@ fn main() {
         cli.no_warn_on_fallback,
         cli.pre_range_dut1,
         cli.future_transition_years,
+        parse_tdb_model(&cli.tdb_model),
     );
     dhruv_search::set_time_conversion_policy(time_policy);
 
@@ -3727,7 +4391,7 @@ fn main() {
             println!("drishti={drishti:#?}");
             println!("full_kundali={full_kundali:#?}");
         }
-        Commands::Rashi { lon } => {
+        Commands::Rashi(RashiArgs { lon }) => {
             let info = rashi_from_longitude(lon);
             let dms = info.dms;
             println!(
@@ -3740,7 +4404,7 @@ fn main() {
             );
         }
 
-        Commands::Nakshatra { lon, scheme } => match scheme {
+        Commands::Nakshatra(NakshatraArgs { lon, scheme }) => match scheme {
             27 => {
                 let info = nakshatra_from_longitude(lon);
                 println!(
@@ -3821,12 +4485,78 @@ fn main() {
             }
         }
 
-        Commands::Dms { deg } => {
+        Commands::KernelInfo(args) => {
+            if let Err(e) = print_kernel_info(&args) {
+                eprintln!("Failed to read {}: {e}", args.kernel.display());
+                std::process::exit(1);
+            }
+        }
+
+        Commands::LocalToUtc(LocalToUtcArgs {
+            date,
+            disambiguate,
+            lmt_longitude,
+        }) => {
+            let mut local = LocalTime::parse_with_default_zone(&date, &TimeZone::utc())
+                .unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                });
+            if let Some(lon) = lmt_longitude {
+                local.zone = local.zone.with_local_mean_time(lon);
+            }
+            match disambiguate.as_deref() {
+                None => {}
+                Some("earlier") => local.disambiguation = Disambiguation::Earlier,
+                Some("later") => local.disambiguation = Disambiguation::Later,
+                Some(other) => {
+                    eprintln!("Invalid --disambiguate: {other} (earlier|later)");
+                    std::process::exit(1);
+                }
+            }
+            let resolution = local.resolve().unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let describe = |r: &ResolvedLocalTime| format!("{} ({})", r.utc, r.local_time_type);
+            println!("Local: {local}");
+            match &resolution {
+                LocalTimeResolution::Unique(_) => {}
+                LocalTimeResolution::Ambiguous { earlier, later } => {
+                    println!("Repeated reading (clocks set back):");
+                    println!("  earlier: {}", describe(earlier));
+                    println!("  later:   {}", describe(later));
+                }
+                LocalTimeResolution::Gap { earlier, later } => {
+                    println!("Skipped reading (clocks set forward):");
+                    println!("  earlier: {}", describe(earlier));
+                    println!("  later:   {}", describe(later));
+                }
+            }
+            match local.resolve_with_type() {
+                Ok(r) => println!("UTC:   {}", describe(&r)),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::TzList => {
+            for name in dhruv_time::available_time_zones() {
+                println!("{name}");
+            }
+            if let Some(version) = dhruv_time::tzdata_version() {
+                eprintln!("tzdata {version}");
+            }
+        }
+
+        Commands::Dms(DmsArgs { deg }) => {
             let d = deg_to_dms(deg);
             println!("{} deg {} min {:.2} sec", d.degrees, d.minutes, d.seconds);
         }
 
-        Commands::NextPurnima { date, bsp, lsk } => {
+        Commands::NextPurnima(NextPurnimaArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -3857,7 +4587,7 @@ fn main() {
             }
         }
 
-        Commands::NextAmavasya { date, bsp, lsk } => {
+        Commands::NextAmavasya(NextAmavasyaArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -3991,7 +4721,7 @@ fn main() {
             }
         }
 
-        Commands::Tithi { date, bsp, lsk } => {
+        Commands::Tithi(TithiArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -4015,7 +4745,7 @@ fn main() {
             }
         }
 
-        Commands::Karana { date, bsp, lsk } => {
+        Commands::Karana(KaranaArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -4133,6 +4863,25 @@ fn main() {
             }
         }
 
+        Commands::Muhurta(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let rs_config = RiseSetConfig::default();
+            match dhruv_search::muhurta_for_date(&engine, &eop_kernel, &utc, &location, &rs_config)
+            {
+                Ok(info) => print_muhurta(&info),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::Ghatika(args) => {
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -5074,7 +5823,7 @@ fn main() {
             });
         }
 
-        Commands::PrevPurnima { date, bsp, lsk } => {
+        Commands::PrevPurnima(PrevPurnimaArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -5105,7 +5854,7 @@ fn main() {
             }
         }
 
-        Commands::PrevAmavasya { date, bsp, lsk } => {
+        Commands::PrevAmavasya(PrevAmavasyaArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -5389,7 +6138,14 @@ fn main() {
             let aya = match args.mode.as_str() {
                 "mean" => ayanamsha_mean_deg_with_catalog(system, t, cat.as_ref()),
                 "true" => ayanamsha_true_deg(system, t, args.delta_psi_arcsec),
-                "unified" => ayanamsha_deg_with_catalog(system, t, args.nutation, cat.as_ref()),
+                "unified" => ayanamsha_deg_with_catalog_and_models(
+                    system,
+                    t,
+                    args.nutation,
+                    cat.as_ref(),
+                    DEFAULT_PRECESSION_MODEL,
+                    parse_nutation_model(&args.nutation_model),
+                ),
                 _ => {
                     eprintln!("Invalid mode: {} (unified|mean|true)", args.mode);
                     std::process::exit(1);
@@ -5418,7 +6174,12 @@ fn main() {
             );
         }
 
-        Commands::NutationCompute { date, bsp, lsk } => {
+        Commands::NutationCompute(NutationComputeArgs {
+            date,
+            nutation_model,
+            bsp,
+            lsk,
+        }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -5426,8 +6187,9 @@ fn main() {
             let engine = load_engine(&bsp, &lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let t = jd_tdb_to_centuries(jd_tdb);
-            let (dpsi, deps) = nutation_iau2000b(t);
-            println!("Nutation at {}:", date);
+            let model = parse_nutation_model(&nutation_model);
+            let (dpsi, deps) = nutation_with_model(t, model);
+            println!("Nutation at {} ({:?}):", date, model);
             println!("  dpsi (longitude): {:.6} arcsec", dpsi);
             println!("  deps (obliquity): {:.6} arcsec", deps);
         }
@@ -5440,7 +6202,10 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
+            let rs_config = RiseSetConfig {
+                radii: load_body_radii(&args.tpc),
+                ..RiseSetConfig::default()
+            };
             let jd_utc = utc_to_jd_utc(&utc);
             let jd_noon = dhruv_vedic_base::approximate_local_noon_jd(
                 dhruv_vedic_base::utc_day_start_jd(jd_utc),
@@ -5855,6 +6620,7 @@ fn main() {
             let config = GrahanConfig {
                 include_penumbral: !args.no_penumbral,
                 include_peak_details: !args.no_peak_details,
+                radii: load_body_radii(&args.tpc),
             };
             let query = match args.mode.as_str() {
                 "next" => {
@@ -6191,7 +6957,7 @@ fn main() {
             }
         }
 
-        Commands::NextChandraGrahan { date, bsp, lsk } => {
+        Commands::NextChandraGrahan(NextChandraGrahanArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -6201,6 +6967,7 @@ fn main() {
             let config = GrahanConfig {
                 include_penumbral: true,
                 include_peak_details: true,
+                ..Default::default()
             };
             let op = GrahanOperation {
                 kind: GrahanKind::Chandra,
@@ -6223,7 +6990,7 @@ fn main() {
             }
         }
 
-        Commands::PrevChandraGrahan { date, bsp, lsk } => {
+        Commands::PrevChandraGrahan(PrevChandraGrahanArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -6233,6 +7000,7 @@ fn main() {
             let config = GrahanConfig {
                 include_penumbral: true,
                 include_peak_details: true,
+                ..Default::default()
             };
             let op = GrahanOperation {
                 kind: GrahanKind::Chandra,
@@ -6270,6 +7038,7 @@ fn main() {
             let config = GrahanConfig {
                 include_penumbral: true,
                 include_peak_details: true,
+                ..Default::default()
             };
             let op = GrahanOperation {
                 kind: GrahanKind::Chandra,
@@ -6297,7 +7066,7 @@ fn main() {
             }
         }
 
-        Commands::NextSuryaGrahan { date, bsp, lsk } => {
+        Commands::NextSuryaGrahan(NextSuryaGrahanArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -6307,6 +7076,7 @@ fn main() {
             let config = GrahanConfig {
                 include_penumbral: true,
                 include_peak_details: true,
+                ..Default::default()
             };
             let op = GrahanOperation {
                 kind: GrahanKind::Surya,
@@ -6328,81 +7098,269 @@ fn main() {
                 }
             }
         }
-
-        Commands::PrevSuryaGrahan { date, bsp, lsk } => {
-            let utc = parse_utc(&date).unwrap_or_else(|e| {
+
+        Commands::PrevSuryaGrahan(PrevSuryaGrahanArgs { date, bsp, lsk }) => {
+            let utc = parse_utc(&date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&bsp, &lsk);
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let config = GrahanConfig {
+                include_penumbral: true,
+                include_peak_details: true,
+                ..Default::default()
+            };
+            let op = GrahanOperation {
+                kind: GrahanKind::Surya,
+                config,
+                query: GrahanQuery::Prev { at_jd_tdb: jd_tdb },
+            };
+            match dhruv_search::grahan(&engine, &op) {
+                Ok(GrahanResult::SuryaSingle(Some(ev))) => {
+                    print_surya_grahan("Previous Surya Grahan", &ev)
+                }
+                Ok(GrahanResult::SuryaSingle(None)) => println!("No solar eclipse found"),
+                Ok(_) => {
+                    eprintln!("Error: unexpected search result shape");
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::SearchSuryaGrahan(args) => {
+            let s = parse_utc(&args.start).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let e = parse_utc(&args.end).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let jd_start = utc_to_jd_tdb_with_policy(&s, engine.lsk(), time_policy);
+            let jd_end = utc_to_jd_tdb_with_policy(&e, engine.lsk(), time_policy);
+            let config = GrahanConfig {
+                include_penumbral: true,
+                include_peak_details: true,
+                ..Default::default()
+            };
+            let op = GrahanOperation {
+                kind: GrahanKind::Surya,
+                config,
+                query: GrahanQuery::Range {
+                    start_jd_tdb: jd_start,
+                    end_jd_tdb: jd_end,
+                },
+            };
+            match dhruv_search::grahan(&engine, &op) {
+                Ok(GrahanResult::SuryaMany(events)) => {
+                    println!("Found {} solar eclipses:", events.len());
+                    for ev in &events {
+                        print_surya_grahan("  Surya Grahan", ev);
+                    }
+                }
+                Ok(_) => {
+                    eprintln!("Error: unexpected search result shape");
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::ChandraGrahanLocal(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop = args.eop.as_deref().map(load_eop);
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let config = GrahanConfig::default();
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let options = dhruv_core::TopocentricOptions {
+                refraction: args.refraction,
+                ..Default::default()
+            };
+            let grahan = match dhruv_search::next_chandra_grahan(&engine, jd_tdb, &config) {
+                Ok(Some(ev)) => ev,
+                Ok(None) => {
+                    println!("No lunar eclipse found");
+                    return;
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            };
+            print_chandra_grahan("Next Chandra Grahan", &grahan);
+            match dhruv_search::chandra_grahan_local_circumstances(
+                &engine,
+                &grahan,
+                &location,
+                eop.as_ref(),
+                &options,
+                &config,
+            ) {
+                Ok(local) => print_chandra_grahan_local(&local),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::ChandraGrahanMap(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop = args.eop.as_deref().map(load_eop);
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let config = GrahanConfig::default();
+            let grahan = match dhruv_search::next_chandra_grahan(&engine, jd_tdb, &config) {
+                Ok(Some(ev)) => ev,
+                Ok(None) => {
+                    println!("No lunar eclipse found");
+                    return;
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            };
+            print_chandra_grahan("Next Chandra Grahan", &grahan);
+            let grid = dhruv_search::chandra_grahan_visibility_grid(
+                &engine,
+                &grahan,
+                args.step_deg,
+                eop.as_ref(),
+                &dhruv_core::TopocentricOptions::default(),
+                &config,
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+            let count = |v: dhruv_search::GrahanVisibility| {
+                grid.cells.iter().filter(|c| c.visibility == v).count()
+            };
+            println!(
+                "Grid {}°: visible {}  partly visible {}  not visible {}",
+                grid.step_deg,
+                count(dhruv_search::GrahanVisibility::Visible),
+                count(dhruv_search::GrahanVisibility::PartlyVisible),
+                count(dhruv_search::GrahanVisibility::NotVisible)
+            );
+            if let Some(out) = &args.csv {
+                std::fs::write(out, chandra_grahan_grid_csv(&grid)).unwrap_or_else(|e| {
+                    eprintln!("Failed to write {}: {e}", out.display());
+                    std::process::exit(1);
+                });
+                println!("Wrote {}", out.display());
+            }
+        }
+        Commands::SuryaGrahanPath(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
             });
-            let engine = load_engine(&bsp, &lsk);
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop = args.eop.as_deref().map(load_eop);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
-            let config = GrahanConfig {
-                include_penumbral: true,
-                include_peak_details: true,
+            let config = dhruv_search::SuryaGrahanPathConfig {
+                step_minutes: args.step_minutes,
+                ..Default::default()
             };
-            let op = GrahanOperation {
-                kind: GrahanKind::Surya,
-                config,
-                query: GrahanQuery::Prev { at_jd_tdb: jd_tdb },
-            };
-            match dhruv_search::grahan(&engine, &op) {
-                Ok(GrahanResult::SuryaSingle(Some(ev))) => {
-                    print_surya_grahan("Previous Surya Grahan", &ev)
-                }
-                Ok(GrahanResult::SuryaSingle(None)) => println!("No solar eclipse found"),
-                Ok(_) => {
-                    eprintln!("Error: unexpected search result shape");
-                    std::process::exit(1);
-                }
-                Err(e) => {
+            let grahan =
+                match dhruv_search::next_surya_grahan(&engine, jd_tdb, &GrahanConfig::default()) {
+                    Ok(Some(ev)) => ev,
+                    Ok(None) => {
+                        println!("No solar eclipse found");
+                        return;
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    }
+                };
+            print_surya_grahan("Next Surya Grahan", &grahan);
+            let path = dhruv_search::surya_grahan_path(&engine, &grahan, eop.as_ref(), &config)
+                .unwrap_or_else(|e| {
                     eprintln!("Error: {e}");
                     std::process::exit(1);
-                }
+                });
+            print_besselian_elements(&path.elements);
+            let count = |line: &dhruv_search::SuryaGrahanPathLine| -> usize {
+                line.iter().map(Vec::len).sum()
+            };
+            println!(
+                "Path points: central {}  umbral N/S {}/{}  penumbral N/S {}/{}  sunrise/sunset {}/{}",
+                count(&path.central_line),
+                count(&path.umbral_north_limit),
+                count(&path.umbral_south_limit),
+                count(&path.penumbral_north_limit),
+                count(&path.penumbral_south_limit),
+                count(&path.sunrise_limit),
+                count(&path.sunset_limit)
+            );
+            if let Some(out) = &args.geojson {
+                std::fs::write(out, surya_grahan_path_geojson(&path)).unwrap_or_else(|e| {
+                    eprintln!("Failed to write {}: {e}", out.display());
+                    std::process::exit(1);
+                });
+                println!("Wrote {}", out.display());
             }
         }
-
-        Commands::SearchSuryaGrahan(args) => {
-            let s = parse_utc(&args.start).unwrap_or_else(|e| {
-                eprintln!("{e}");
-                std::process::exit(1);
-            });
-            let e = parse_utc(&args.end).unwrap_or_else(|e| {
+        Commands::SuryaGrahanLocal(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
             });
             let engine = load_engine(&args.bsp, &args.lsk);
-            let jd_start = utc_to_jd_tdb_with_policy(&s, engine.lsk(), time_policy);
-            let jd_end = utc_to_jd_tdb_with_policy(&e, engine.lsk(), time_policy);
-            let config = GrahanConfig {
-                include_penumbral: true,
-                include_peak_details: true,
-            };
-            let op = GrahanOperation {
-                kind: GrahanKind::Surya,
-                config,
-                query: GrahanQuery::Range {
-                    start_jd_tdb: jd_start,
-                    end_jd_tdb: jd_end,
-                },
+            let eop = args.eop.as_deref().map(load_eop);
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let config = GrahanConfig::default();
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let options = dhruv_core::TopocentricOptions {
+                refraction: args.refraction,
+                ..Default::default()
             };
-            match dhruv_search::grahan(&engine, &op) {
-                Ok(GrahanResult::SuryaMany(events)) => {
-                    println!("Found {} solar eclipses:", events.len());
-                    for ev in &events {
-                        print_surya_grahan("  Surya Grahan", ev);
-                    }
+            let grahan = match dhruv_search::next_surya_grahan(&engine, jd_tdb, &config) {
+                Ok(Some(ev)) => ev,
+                Ok(None) => {
+                    println!("No solar eclipse found");
+                    return;
                 }
-                Ok(_) => {
-                    eprintln!("Error: unexpected search result shape");
+                Err(e) => {
+                    eprintln!("Error: {e}");
                     std::process::exit(1);
                 }
+            };
+            print_surya_grahan("Next Surya Grahan (geocentric)", &grahan);
+            match dhruv_search::surya_grahan_local_circumstances(
+                &engine,
+                &grahan,
+                &location,
+                eop.as_ref(),
+                &options,
+                &config,
+            ) {
+                Ok(Some(local)) => print_surya_grahan_local(&local),
+                Ok(None) => println!("Not seen from this location"),
                 Err(e) => {
                     eprintln!("Error: {e}");
                     std::process::exit(1);
                 }
             }
         }
-
         Commands::Motion(args) => {
             let kind = match args.kind.as_str() {
                 "stationary" => MotionKind::Stationary,
@@ -6728,8 +7686,9 @@ fn main() {
                 eprintln!("{e}");
                 std::process::exit(1);
             });
-            let t = require_body(args.target);
-            let obs = require_observer(args.observer);
+            let t = require_body_spec(&args.target);
+            let obs = require_observer_spec(&args.observer);
+            let correction = parse_correction(&args.correction);
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
 
@@ -6738,17 +7697,15 @@ fn main() {
                 let q = Query {
                     target: t,
                     observer: obs,
-                    frame: Frame::IcrfJ2000,
+                    frame: Frame::MeanEclipticOfDate(DEFAULT_PRECESSION_MODEL),
                     epoch_tdb_jd: jd,
+                    correction,
                 };
                 let sv = engine.query(q).unwrap_or_else(|e| {
                     eprintln!("Error: {e}");
                     std::process::exit(1);
                 });
-                let ecl_j2000 = icrf_to_ecliptic(&sv.position_km);
-                let tc = (jd - 2_451_545.0) / 36525.0;
-                let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, tc);
-                cartesian_to_spherical(&ecl_date)
+                cartesian_to_spherical(&sv.position_km)
             };
 
             let sph = ecl_sph(jd_tdb);
@@ -6768,30 +7725,104 @@ fn main() {
             println!("  Distance speed: {:.6} km/s", dist_speed);
         }
 
+        Commands::LunarLibration(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let pck = PckKernel::open(&args.bpc).unwrap_or_else(|e| {
+                eprintln!("Failed to load lunar PCK: {e}");
+                std::process::exit(1);
+            });
+            // An optional TK frame (e.g. mean Earth / polar axis) is a fixed
+            // offset from the PCK frame it names as relative.
+            let (body_frame, offset) = match (&args.fk, &args.me_frame) {
+                (Some(fk), Some(name)) => {
+                    let kernel = TextKernel::load(fk).unwrap_or_else(|e| {
+                        eprintln!("Failed to load frame kernel: {e}");
+                        std::process::exit(1);
+                    });
+                    let tk = kernel
+                        .frame_id(name)
+                        .and_then(|id| kernel.tk_frame(id).transpose())
+                        .unwrap_or_else(|| {
+                            eprintln!("No TK frame named {name} in frame kernel");
+                            std::process::exit(1);
+                        })
+                        .unwrap_or_else(|e| {
+                            eprintln!("Invalid TK frame {name}: {e}");
+                            std::process::exit(1);
+                        });
+                    let relative = kernel.frame_id(&tk.relative).unwrap_or_else(|| {
+                        eprintln!("Unknown relative frame {}", tk.relative);
+                        std::process::exit(1);
+                    });
+                    (relative, Some(FrameRotation::fixed(tk.rotation)))
+                }
+                _ => (args.frame, None),
+            };
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let query = Query {
+                target: Body::Earth,
+                observer: Observer::Body(Body::Moon),
+                frame: Frame::IcrfJ2000,
+                epoch_tdb_jd: jd_tdb,
+                correction: Correction::LightTime,
+            };
+            let state = engine.query(query).unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+            let rotation = pck
+                .rotation_from_j2000(body_frame, jd_to_tdb_seconds(jd_tdb))
+                .map(|pa| match &offset {
+                    Some(tk) => tk.after(&pa),
+                    None => pa,
+                })
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                });
+            let [lon, lat] = rotation.lon_lat(&state.position_km);
+            let orientation = rotation.orientation();
+            println!("Libration in longitude: {:.6}°", lon.to_degrees());
+            println!("Libration in latitude:  {:.6}°", lat.to_degrees());
+            println!(
+                "Pole RA / Dec (J2000):  {:.6}° / {:.6}°",
+                orientation.pole_ra_rad.to_degrees().rem_euclid(360.0),
+                orientation.pole_dec_rad.to_degrees()
+            );
+            println!(
+                "Prime meridian W:       {:.6}°",
+                orientation.prime_meridian_rad.to_degrees()
+            );
+        }
+
         Commands::SiderealLongitude(args) => {
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
             });
-            let t = require_body(args.target);
-            let obs = require_observer(args.observer);
+            let t = require_body_spec(&args.target);
+            let obs = require_observer_spec(&args.observer);
             let system = require_aya_system(args.ayanamsha);
+            let correction = parse_correction(&args.correction);
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let query = Query {
                 target: t,
                 observer: obs,
-                frame: Frame::IcrfJ2000,
+                frame: Frame::MeanEclipticOfDate(DEFAULT_PRECESSION_MODEL),
                 epoch_tdb_jd: jd_tdb,
+                correction,
             };
             let state = engine.query(query).unwrap_or_else(|e| {
                 eprintln!("Error: {e}");
                 std::process::exit(1);
             });
             let tc = jd_tdb_to_centuries(jd_tdb);
-            let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-            let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, tc);
-            let tropical_lon = cartesian_to_spherical(&ecl_date).lon_deg;
+            let tropical_lon = cartesian_to_spherical(&state.position_km).lon_deg;
             let aya = ayanamsha_deg(system, tc, args.nutation);
             let sid = (tropical_lon - aya).rem_euclid(360.0);
             println!("Tropical longitude: {:.6}°", tropical_lon);
@@ -6947,41 +7978,41 @@ fn main() {
         // -----------------------------------------------------------
         // Individual Sphuta Formulas (pure math)
         // -----------------------------------------------------------
-        Commands::BhriguBindu { rahu, moon } => {
+        Commands::BhriguBindu(BhriguBinduArgs { rahu, moon }) => {
             println!("{:.6}°", dhruv_vedic_base::bhrigu_bindu(rahu, moon));
         }
 
-        Commands::PranaSphuta { lagna, moon } => {
+        Commands::PranaSphuta(PranaSphutaArgs { lagna, moon }) => {
             println!("{:.6}°", dhruv_vedic_base::prana_sphuta(lagna, moon));
         }
 
-        Commands::DehaSphuta { moon, lagna } => {
+        Commands::DehaSphuta(DehaSphutaArgs { moon, lagna }) => {
             println!("{:.6}°", dhruv_vedic_base::deha_sphuta(moon, lagna));
         }
 
-        Commands::MrityuSphuta { eighth_lord, lagna } => {
+        Commands::MrityuSphuta(MrityuSphutaArgs { eighth_lord, lagna }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::mrityu_sphuta(eighth_lord, lagna)
             );
         }
 
-        Commands::TithiSphuta { moon, sun, lagna } => {
+        Commands::TithiSphuta(TithiSphutaArgs { moon, sun, lagna }) => {
             println!("{:.6}°", dhruv_vedic_base::tithi_sphuta(moon, sun, lagna));
         }
 
-        Commands::YogaSphuta { sun, moon } => {
+        Commands::YogaSphuta(YogaSphutaArgs { sun, moon }) => {
             println!("{:.6}°", dhruv_vedic_base::yoga_sphuta(sun, moon));
         }
 
-        Commands::YogaSphutaNormalized { sun, moon } => {
+        Commands::YogaSphutaNormalized(YogaSphutaNormalizedArgs { sun, moon }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::yoga_sphuta_normalized(sun, moon)
             );
         }
 
-        Commands::RahuTithiSphuta { rahu, sun, lagna } => {
+        Commands::RahuTithiSphuta(RahuTithiSphutaArgs { rahu, sun, lagna }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::rahu_tithi_sphuta(rahu, sun, lagna)
@@ -7001,30 +8032,30 @@ fn main() {
             );
         }
 
-        Commands::BeejaSphuta {
+        Commands::BeejaSphuta(BeejaSphutaArgs {
             sun,
             venus,
             jupiter,
-        } => {
+        }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::beeja_sphuta(sun, venus, jupiter)
             );
         }
 
-        Commands::TriSphuta {
+        Commands::TriSphuta(TriSphutaArgs {
             lagna,
             moon,
             gulika,
-        } => {
+        }) => {
             println!("{:.6}°", dhruv_vedic_base::trisphuta(lagna, moon, gulika));
         }
 
-        Commands::ChatusSphuta { trisphuta, sun } => {
+        Commands::ChatusSphuta(ChatusSphutaArgs { trisphuta, sun }) => {
             println!("{:.6}°", dhruv_vedic_base::chatussphuta(trisphuta, sun));
         }
 
-        Commands::PanchaSphuta { chatussphuta, rahu } => {
+        Commands::PanchaSphuta(PanchaSphutaArgs { chatussphuta, rahu }) => {
             println!("{:.6}°", dhruv_vedic_base::panchasphuta(chatussphuta, rahu));
         }
 
@@ -7035,68 +8066,68 @@ fn main() {
             );
         }
 
-        Commands::AvayogaSphuta { sun, moon } => {
+        Commands::AvayogaSphuta(AvayogaSphutaArgs { sun, moon }) => {
             println!("{:.6}°", dhruv_vedic_base::avayoga_sphuta(sun, moon));
         }
 
-        Commands::Kunda { lagna, moon, mars } => {
+        Commands::Kunda(KundaArgs { lagna, moon, mars }) => {
             println!("{:.6}°", dhruv_vedic_base::kunda(lagna, moon, mars));
         }
 
         // -----------------------------------------------------------
         // Individual Special Lagna Formulas (pure math)
         // -----------------------------------------------------------
-        Commands::BhavaLagna { sun_lon, ghatikas } => {
+        Commands::BhavaLagna(BhavaLagnaArgs { sun_lon, ghatikas }) => {
             println!("{:.6}°", dhruv_vedic_base::bhava_lagna(sun_lon, ghatikas));
         }
 
-        Commands::HoraLagna { sun_lon, ghatikas } => {
+        Commands::HoraLagna(HoraLagnaArgs { sun_lon, ghatikas }) => {
             println!("{:.6}°", dhruv_vedic_base::hora_lagna(sun_lon, ghatikas));
         }
 
-        Commands::GhatiLagna { sun_lon, ghatikas } => {
+        Commands::GhatiLagna(GhatiLagnaArgs { sun_lon, ghatikas }) => {
             println!("{:.6}°", dhruv_vedic_base::ghati_lagna(sun_lon, ghatikas));
         }
 
-        Commands::VighatiLagna {
+        Commands::VighatiLagna(VighatiLagnaArgs {
             lagna_lon,
             vighatikas,
-        } => {
+        }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::vighati_lagna(lagna_lon, vighatikas)
             );
         }
 
-        Commands::VarnadaLagna {
+        Commands::VarnadaLagna(VarnadaLagnaArgs {
             lagna_lon,
             hora_lagna_lon,
-        } => {
+        }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::varnada_lagna(lagna_lon, hora_lagna_lon)
             );
         }
 
-        Commands::SreeLagna {
+        Commands::SreeLagna(SreeLagnaArgs {
             moon_lon,
             lagna_lon,
-        } => {
+        }) => {
             println!("{:.6}°", dhruv_vedic_base::sree_lagna(moon_lon, lagna_lon));
         }
 
-        Commands::PranapadaLagna { sun_lon, ghatikas } => {
+        Commands::PranapadaLagna(PranapadaLagnaArgs { sun_lon, ghatikas }) => {
             println!(
                 "{:.6}°",
                 dhruv_vedic_base::pranapada_lagna(sun_lon, ghatikas)
             );
         }
 
-        Commands::InduLagna {
+        Commands::InduLagna(InduLagnaArgs {
             moon_lon,
             lagna_lord,
             moon_9th_lord,
-        } => {
+        }) => {
             let ll = require_graha(lagna_lord);
             let m9l = require_graha(moon_9th_lord);
             println!("{:.6}°", dhruv_vedic_base::indu_lagna(moon_lon, ll, m9l));
@@ -7105,7 +8136,7 @@ fn main() {
         // -----------------------------------------------------------
         // Utility Primitives
         // -----------------------------------------------------------
-        Commands::TithiFromElongation { elongation } => {
+        Commands::TithiFromElongation(TithiFromElongationArgs { elongation }) => {
             let pos = dhruv_vedic_base::tithi_from_elongation(elongation);
             println!(
                 "{} ({} {}) - {:.6}° into tithi",
@@ -7116,7 +8147,7 @@ fn main() {
             );
         }
 
-        Commands::KaranaFromElongation { elongation } => {
+        Commands::KaranaFromElongation(KaranaFromElongationArgs { elongation }) => {
             let pos = dhruv_vedic_base::karana_from_elongation(elongation);
             println!(
                 "{} (index {}) - {:.6}° into karana",
@@ -7126,7 +8157,7 @@ fn main() {
             );
         }
 
-        Commands::YogaFromSum { sum } => {
+        Commands::YogaFromSum(YogaFromSumArgs { sum }) => {
             let pos = dhruv_vedic_base::yoga_from_sum(sum);
             println!(
                 "{} (index {}) - {:.6}° into yoga",
@@ -7136,22 +8167,22 @@ fn main() {
             );
         }
 
-        Commands::VaarFromJd { jd } => {
+        Commands::VaarFromJd(VaarFromJdArgs { jd }) => {
             let vaar = dhruv_vedic_base::vaar_from_jd(jd);
             println!("{}", vaar.name());
         }
 
-        Commands::MasaFromRashi { rashi } => {
+        Commands::MasaFromRashi(MasaFromRashiArgs { rashi }) => {
             let masa = dhruv_vedic_base::masa_from_rashi_index(rashi);
             println!("{}", masa.name());
         }
 
-        Commands::AyanaFromLon { lon } => {
+        Commands::AyanaFromLon(AyanaFromLonArgs { lon }) => {
             let ayana = dhruv_vedic_base::ayana_from_sidereal_longitude(lon);
             println!("{}", ayana.name());
         }
 
-        Commands::SamvatsaraCompute { year } => {
+        Commands::SamvatsaraCompute(SamvatsaraComputeArgs { year }) => {
             let (samvatsara, cycle_index) = dhruv_vedic_base::samvatsara_from_year(year);
             println!(
                 "{} (index {} in 60-year cycle)",
@@ -7160,31 +8191,33 @@ fn main() {
             );
         }
 
-        Commands::NthRashiFrom { rashi, offset } => {
+        Commands::NthRashiFrom(NthRashiFromArgs { rashi, offset }) => {
             let result = dhruv_vedic_base::nth_rashi_from(rashi, offset);
             let ri = rashi_from_index(result);
             println!("{} (index {})", ri.name(), result);
         }
 
-        Commands::RashiLord { rashi } => match dhruv_vedic_base::rashi_lord_by_index(rashi) {
-            Some(graha) => println!("{}", graha.name()),
-            None => {
-                eprintln!("Invalid rashi index: {rashi} (0-11)");
-                std::process::exit(1);
+        Commands::RashiLord(RashiLordArgs { rashi }) => {
+            match dhruv_vedic_base::rashi_lord_by_index(rashi) {
+                Some(graha) => println!("{}", graha.name()),
+                None => {
+                    eprintln!("Invalid rashi index: {rashi} (0-11)");
+                    std::process::exit(1);
+                }
             }
-        },
+        }
 
-        Commands::Normalize360 { deg } => {
+        Commands::Normalize360(Normalize360Args { deg }) => {
             println!("{:.6}°", dhruv_vedic_base::normalize_360(deg));
         }
 
-        Commands::ArudhaPadaCompute { cusp_lon, lord_lon } => {
+        Commands::ArudhaPadaCompute(ArudhaPadaComputeArgs { cusp_lon, lord_lon }) => {
             let (lon, rashi_idx) = dhruv_vedic_base::arudha_pada(cusp_lon, lord_lon);
             let ri = rashi_from_index(rashi_idx);
             println!("{:.6}° ({})", lon, ri.name());
         }
 
-        Commands::SunBasedUpagrahas { sun_lon } => {
+        Commands::SunBasedUpagrahas(SunBasedUpagrahasArgs { sun_lon }) => {
             let upa = dhruv_vedic_base::sun_based_upagrahas(sun_lon);
             println!("Dhooma:      {:.6}°", upa.dhooma);
             println!("Vyatipata:   {:.6}°", upa.vyatipata);
@@ -7727,7 +8760,7 @@ fn main() {
         // -----------------------------------------------------------
         // Panchang Intermediates (engine required)
         // -----------------------------------------------------------
-        Commands::ElongationAt { date, bsp, lsk } => {
+        Commands::ElongationAt(ElongationAtArgs { date, bsp, lsk }) => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
                 std::process::exit(1);
@@ -7790,7 +8823,10 @@ fn main() {
                 longitude_deg: args.lon,
                 altitude_m: args.alt,
             };
-            let rs_config = RiseSetConfig::default();
+            let rs_config = RiseSetConfig {
+                radii: load_body_radii(&args.tpc),
+                ..RiseSetConfig::default()
+            };
             match dhruv_search::vedic_day_sunrises(
                 &engine,
                 &eop_kernel,
@@ -7914,10 +8950,10 @@ fn main() {
         // -----------------------------------------------------------
         // Low-level Ashtakavarga / Drishti
         // -----------------------------------------------------------
-        Commands::CalculateAshtakavarga {
+        Commands::CalculateAshtakavarga(CalculateAshtakavargaArgs {
             graha_rashis,
             lagna_rashi,
-        } => {
+        }) => {
             let rashis = parse_graha_rashis(&graha_rashis);
             let result = dhruv_vedic_base::calculate_ashtakavarga(&rashis, lagna_rashi);
             let graha_names = [
@@ -7950,11 +8986,11 @@ fn main() {
             println!("  Ekadhipatya: {:?}", result.sav.after_ekadhipatya);
         }
 
-        Commands::GrahaDrishtiCompute {
+        Commands::GrahaDrishtiCompute(GrahaDrishtiComputeArgs {
             graha,
             source,
             target,
-        } => {
+        }) => {
             let g = require_graha(graha);
             let entry = dhruv_vedic_base::graha_drishti(g, source, target);
             println!(
@@ -7963,7 +8999,7 @@ fn main() {
             );
         }
 
-        Commands::GrahaDrishtiMatrixCompute { longitudes } => {
+        Commands::GrahaDrishtiMatrixCompute(GrahaDrishtiMatrixComputeArgs { longitudes }) => {
             let lons = parse_longitudes_9(&longitudes);
             let matrix = dhruv_vedic_base::graha_drishti_matrix(&lons);
             let names = [
@@ -8076,7 +9112,171 @@ fn main() {
                 }
             }
         }
-        Commands::Bhavabala(args) => {
+        Commands::Bhavabala(args) => {
+            let system = require_aya_system(args.ayanamsha);
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
+            let rs_config = RiseSetConfig::default();
+            let aya_config = SankrantiConfig::new(system, args.nutation);
+
+            if let Some(bhava_number) = args.bhava {
+                let entry = dhruv_search::bhavabala_for_bhava(
+                    &engine,
+                    &eop_kernel,
+                    &utc,
+                    &location,
+                    &bhava_config,
+                    &rs_config,
+                    &aya_config,
+                    bhava_number,
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                });
+                println!("Bhava Bala for Bhava {} on {}\n", bhava_number, args.date);
+                print_bhavabala_entry(&entry);
+            } else {
+                let result = dhruv_search::bhavabala_for_date(
+                    &engine,
+                    &eop_kernel,
+                    &utc,
+                    &location,
+                    &bhava_config,
+                    &rs_config,
+                    &aya_config,
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                });
+
+                println!(
+                    "Bhava Bala for {} at {:.6}°N, {:.6}°E\n",
+                    args.date, args.lat, args.lon
+                );
+                println!(
+                    "{:<6} {:<10} {:<8} {:>11} {:>8} {:>8} {:>8} {:>8} {:>8}",
+                    "Bhava",
+                    "Rashi",
+                    "Lord",
+                    "Bhavadhip",
+                    "Dig",
+                    "Drishti",
+                    "Occup",
+                    "Rise",
+                    "Total"
+                );
+                println!("{}", "-".repeat(86));
+                for entry in &result.entries {
+                    println!(
+                        "{:<6} {:<10} {:<8} {:>11.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2}",
+                        entry.bhava_number,
+                        dhruv_vedic_base::ALL_RASHIS[entry.rashi_index as usize].name(),
+                        entry.lord.name(),
+                        entry.bhavadhipati,
+                        entry.dig,
+                        entry.drishti,
+                        entry.occupation_bonus,
+                        entry.rising_bonus,
+                        entry.total_virupas,
+                    );
+                }
+            }
+        }
+        Commands::Kp(args) => {
+            let system = require_aya_system(args.ayanamsha);
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let bhava_config = BhavaConfig {
+                system: BhavaSystem::KP,
+                ..BhavaConfig::default()
+            };
+            let rs_config = RiseSetConfig::default();
+            let aya_config = SankrantiConfig::new(system, args.nutation);
+
+            let chart = dhruv_search::kp_chart_for_date(
+                &engine,
+                &eop_kernel,
+                &utc,
+                &location,
+                &bhava_config,
+                &rs_config,
+                &aya_config,
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+
+            println!(
+                "KP chart ({:?}, ayanamsha={:.6}°) for {} at {:.6}°N, {:.6}°E\n",
+                system, chart.ayanamsha_deg, args.date, args.lat, args.lon
+            );
+            print_kp_chart(&chart);
+        }
+        Commands::Yogas(args) => {
+            let system = require_aya_system(args.ayanamsha);
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let config = dhruv_search::FullKundaliConfig {
+                include_graha_positions: true,
+                graha_positions_config: dhruv_search::GrahaPositionsConfig {
+                    include_lagna: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            let yogas = dhruv_search::full_kundali_for_date(
+                &engine,
+                &eop_kernel,
+                &utc,
+                &location,
+                &BhavaConfig::default(),
+                &RiseSetConfig::default(),
+                &aya_config,
+                &config,
+            )
+            .and_then(|kundali| {
+                dhruv_search::yogas_from_kundali(&kundali, dhruv_search::CLASSICAL_YOGA_RULES)
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+
+            println!(
+                "Graha yogas ({:?}) for {} at {:.6}°N, {:.6}°E\n",
+                system, args.date, args.lat, args.lon
+            );
+            if yogas.is_empty() {
+                println!("No yogas detected");
+            }
+            for y in &yogas {
+                println!("{:<16} {:?}", y.name, y.category);
+                println!("  {}", y.detail);
+                println!("  rule: {}", y.rule);
+            }
+        }
+        Commands::Doshas(args) => {
             let system = require_aya_system(args.ayanamsha);
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -8085,74 +9285,89 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
-            let rs_config = RiseSetConfig::default();
             let aya_config = SankrantiConfig::new(system, args.nutation);
+            let mut config = dhruv_search::DoshaConfig {
+                include_partial_kaal_sarpa: !args.no_partial_kaal_sarpa,
+                ..Default::default()
+            };
+            if args.north {
+                config.mangal_bhavas = dhruv_vedic_base::MANGAL_DOSHA_BHAVAS_NORTH.to_vec();
+            }
+            if let Some(orb) = args.gandanta_orb {
+                config.gandanta_orb_deg = orb;
+            }
 
-            if let Some(bhava_number) = args.bhava {
-                let entry = dhruv_search::bhavabala_for_bhava(
-                    &engine,
-                    &eop_kernel,
-                    &utc,
-                    &location,
-                    &bhava_config,
-                    &rs_config,
-                    &aya_config,
-                    bhava_number,
-                )
-                .unwrap_or_else(|e| {
-                    eprintln!("Error: {e}");
-                    std::process::exit(1);
-                });
-                println!("Bhava Bala for Bhava {} on {}\n", bhava_number, args.date);
-                print_bhavabala_entry(&entry);
-            } else {
-                let result = dhruv_search::bhavabala_for_date(
-                    &engine,
-                    &eop_kernel,
-                    &utc,
-                    &location,
-                    &bhava_config,
-                    &rs_config,
-                    &aya_config,
-                )
-                .unwrap_or_else(|e| {
-                    eprintln!("Error: {e}");
-                    std::process::exit(1);
-                });
+            let result = dhruv_search::doshas_for_date(
+                &engine,
+                &eop_kernel,
+                &utc,
+                &location,
+                &aya_config,
+                &config,
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
 
-                println!(
-                    "Bhava Bala for {} at {:.6}°N, {:.6}°E\n",
-                    args.date, args.lat, args.lon
-                );
-                println!(
-                    "{:<6} {:<10} {:<8} {:>11} {:>8} {:>8} {:>8} {:>8} {:>8}",
-                    "Bhava",
-                    "Rashi",
-                    "Lord",
-                    "Bhavadhip",
-                    "Dig",
-                    "Drishti",
-                    "Occup",
-                    "Rise",
-                    "Total"
-                );
-                println!("{}", "-".repeat(86));
-                for entry in &result.entries {
+            println!(
+                "Doshas ({:?}) for {} at {:.6}°N, {:.6}°E\n",
+                system, args.date, args.lat, args.lon
+            );
+            print_doshas(&result);
+        }
+        Commands::Milan(args) => {
+            let system = require_aya_system(args.ayanamsha);
+            let result = match (args.groom_moon, args.bride_moon) {
+                (Some(groom_moon), Some(bride_moon)) => {
                     println!(
-                        "{:<6} {:<10} {:<8} {:>11.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2}",
-                        entry.bhava_number,
-                        dhruv_vedic_base::ALL_RASHIS[entry.rashi_index as usize].name(),
-                        entry.lord.name(),
-                        entry.bhavadhipati,
-                        entry.dig,
-                        entry.drishti,
-                        entry.occupation_bonus,
-                        entry.rising_bonus,
-                        entry.total_virupas,
+                        "Kundali milan for groom Moon {}, bride Moon {}\n",
+                        format_rashi_dms(groom_moon),
+                        format_rashi_dms(bride_moon)
                     );
+                    dhruv_vedic_base::kundali_milan(groom_moon, bride_moon)
                 }
-            }
+                _ => {
+                    let (Some(groom_date), Some(bride_date)) = (&args.groom_date, &args.bride_date)
+                    else {
+                        eprintln!(
+                            "Provide --groom-date and --bride-date, or --groom-moon and --bride-moon"
+                        );
+                        std::process::exit(1);
+                    };
+                    let Some(eop_path) = &args.eop else {
+                        eprintln!("--eop is required with --groom-date/--bride-date");
+                        std::process::exit(1);
+                    };
+                    let parse = |date: &str| {
+                        parse_utc(date).unwrap_or_else(|e| {
+                            eprintln!("{e}");
+                            std::process::exit(1);
+                        })
+                    };
+                    let groom_utc = parse(groom_date);
+                    let bride_utc = parse(bride_date);
+                    let engine = load_engine(&args.bsp, &args.lsk);
+                    let eop_kernel = load_eop(eop_path);
+                    let aya_config = SankrantiConfig::new(system, args.nutation);
+                    println!(
+                        "Kundali milan ({:?}) for groom {}, bride {}\n",
+                        system, groom_date, bride_date
+                    );
+                    dhruv_search::kundali_milan_for_dates(
+                        &engine,
+                        &eop_kernel,
+                        &groom_utc,
+                        &bride_utc,
+                        &aya_config,
+                    )
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    })
+                }
+            };
+            print_milan(&result);
         }
         Commands::Charakaraka(args) => {
             let system = require_aya_system(args.ayanamsha);
@@ -9071,7 +10286,7 @@ fn main() {
                 }
             }
         }
-        Commands::TaraList { catalog, category } => {
+        Commands::TaraList(TaraListArgs { catalog, category }) => {
             let cat = TaraCatalog::load(&catalog).unwrap_or_else(|e| {
                 eprintln!("Failed to load catalog: {e}");
                 std::process::exit(1);
@@ -9147,6 +10362,7 @@ fn main() {
                     TaraAccuracy::Astrometric
                 },
                 apply_parallax: args.parallax,
+                ..TaraConfig::default()
             };
 
             // Get Earth state if needed
@@ -9157,6 +10373,7 @@ fn main() {
                     observer: Observer::SolarSystemBarycenter,
                     frame: Frame::IcrfJ2000,
                     epoch_tdb_jd: jd_tdb,
+                    correction: Correction::Geometric,
                 };
                 let state = engine.query(q).unwrap_or_else(|e| {
                     eprintln!("Failed to query Earth state: {e}");
@@ -9372,6 +10589,231 @@ fn parse_dasha_entity_spec(spec: &str) -> dhruv_vedic_base::dasha::DashaEntity {
     }
 }
 
+fn kp_lords_row(lords: &dhruv_search::KpLords) -> String {
+    format!(
+        "{:<8} {:<8} {:<8} {:<8}",
+        lords.sign_lord.name(),
+        lords.star_lord.name(),
+        lords.sub_lord.name(),
+        lords.sub_sub_lord.name()
+    )
+}
+
+fn kp_graha_list(grahas: &[Graha]) -> String {
+    if grahas.is_empty() {
+        return "-".to_string();
+    }
+    grahas
+        .iter()
+        .map(|g| g.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_kp_chart(chart: &dhruv_search::KpChart) {
+    println!(
+        "{:<8} {:<26} {:>5} {:>4} {:<8} {:<8} {:<8} {:<8}",
+        "Graha", "Longitude", "Bhava", "Sub", "Sign", "Star", "Sub", "SubSub"
+    );
+    println!("{}", "-".repeat(84));
+    for e in &chart.grahas {
+        println!(
+            "{:<8} {:<26} {:>5} {:>4} {}",
+            e.graha.name(),
+            format_rashi_dms(e.sidereal_longitude),
+            e.bhava_number,
+            e.sub_number,
+            kp_lords_row(&e.lords)
+        );
+    }
+
+    println!(
+        "\n{:<8} {:<26} {:>4} {:<8} {:<8} {:<8} {:<8}",
+        "Cusp", "Longitude", "Sub", "Sign", "Star", "Sub", "SubSub"
+    );
+    println!("{}", "-".repeat(78));
+    for c in &chart.cusps {
+        println!(
+            "{:<8} {:<26} {:>4} {}",
+            c.bhava_number,
+            format_rashi_dms(c.sidereal_longitude),
+            c.sub_number,
+            kp_lords_row(&c.lords)
+        );
+    }
+
+    println!(
+        "\nSignificators (A: in star of occupants, B: occupants, C: in star of lord, D: lord)"
+    );
+    for h in &chart.significators {
+        println!(
+            "  {:>2}  A: {}  B: {}  C: {}  D: {}",
+            h.bhava_number,
+            kp_graha_list(&h.level_a),
+            kp_graha_list(&h.level_b),
+            kp_graha_list(&h.level_c),
+            kp_graha_list(&h.level_d)
+        );
+    }
+
+    let rp = &chart.ruling_planets;
+    println!("\nRuling planets");
+    println!("  Day lord: {}", rp.day_lord.name());
+    println!("  Lagna:    {}", kp_lords_row(&rp.lagna));
+    println!("  Moon:     {}", kp_lords_row(&rp.moon));
+    println!("  Ruling:   {}", kp_graha_list(&rp.ruling));
+}
+
+fn print_muhurta(info: &dhruv_search::MuhurtaInfo) {
+    println!("Vaar: {}", info.vaar.name());
+    println!("  Sunrise:      {}", info.sunrise);
+    println!("  Sunset:       {}", info.sunset);
+    println!("  Next sunrise: {}", info.next_sunrise);
+    println!();
+    let periods = [
+        ("Rahu Kalam", &info.rahu_kalam),
+        ("Yamaganda", &info.yamaganda),
+        ("Gulika Kalam", &info.gulika_kalam),
+        ("Abhijit", &info.abhijit),
+        ("Brahma muhurta", &info.brahma_muhurta),
+    ];
+    for (name, w) in periods {
+        println!("{:<15} {} - {}", name, w.start, w.end);
+    }
+
+    println!("\nMuhurtas");
+    for m in &info.muhurtas {
+        println!(
+            "  {:<5} {:>2}  {:<14} {} - {}{}",
+            if m.is_night { "night" } else { "day" },
+            m.index + 1,
+            m.name,
+            m.start,
+            m.end,
+            if m.is_durmuhurta { "  Durmuhurta" } else { "" }
+        );
+    }
+
+    println!("\nChoghadiya");
+    for c in &info.choghadiyas {
+        println!(
+            "  {:<5} {}  {:<6} {:?}  {} - {}",
+            if c.is_night { "night" } else { "day" },
+            c.slot + 1,
+            c.choghadiya.name(),
+            c.choghadiya.nature(),
+            c.start,
+            c.end
+        );
+    }
+}
+
+fn print_doshas(result: &dhruv_vedic_base::DoshaResult) {
+    use dhruv_vedic_base::{GandantaPoint, PitruDoshaReason};
+
+    let mangal = &result.mangal;
+    println!("Mangal dosha");
+    if !mangal.is_present() {
+        println!("  not present");
+    }
+    for p in &mangal.placements {
+        println!("  Mangal in bhava {} from {:?}", p.bhava, p.reference);
+    }
+    for c in &mangal.cancellations {
+        println!("  cancelled: {c:?}");
+    }
+    if mangal.is_present() {
+        println!(
+            "  effective: {}",
+            if mangal.is_effective() { "yes" } else { "no" }
+        );
+    }
+
+    println!("\nKaal Sarpa");
+    match &result.kaal_sarpa {
+        None => println!("  not present"),
+        Some(ks) => {
+            println!(
+                "  {} ({:?}, {:?}), Rahu in bhava {}",
+                ks.name, ks.kind, ks.direction, ks.rahu_bhava
+            );
+            for g in &ks.outside {
+                println!("  outside the axis: {}", g.name());
+            }
+        }
+    }
+
+    println!("\nPitru dosha");
+    if result.pitru.is_empty() {
+        println!("  not present");
+    }
+    for reason in &result.pitru {
+        match reason {
+            PitruDoshaReason::SuryaWithNode { node } => {
+                println!("  Surya conjunct {}", node.name())
+            }
+            PitruDoshaReason::SuryaWithShani => println!("  Surya conjunct Shani"),
+            PitruDoshaReason::NodeInNinthBhava { node } => {
+                println!("  {} in the 9th bhava", node.name())
+            }
+            PitruDoshaReason::NinthLordWithNode { lord, node } => {
+                println!("  9th lord {} conjunct {}", lord.name(), node.name())
+            }
+        }
+    }
+
+    println!("\nGandanta");
+    if result.gandanta.is_empty() {
+        println!("  none");
+    }
+    for p in &result.gandanta {
+        let point = match p.point {
+            GandantaPoint::Lagna => "Lagna",
+            GandantaPoint::Graha(g) => g.name(),
+        };
+        println!("  {:<8} {:?} {:+.4}°", point, p.junction, p.offset_deg);
+    }
+}
+
+fn print_milan(result: &dhruv_vedic_base::MilanResult) {
+    let ak = &result.ashtakoota;
+    println!("Ashtakoota");
+    println!("{:<14} {:>6} {:>4}  Dosha", "Koota", "Points", "Max");
+    println!("{}", "-".repeat(60));
+    for k in &ak.kootas {
+        let dosha = match (k.dosha, k.cancellation) {
+            (false, _) => String::new(),
+            (true, None) => "yes".to_string(),
+            (true, Some(c)) => format!("cancelled ({c:?})"),
+        };
+        println!(
+            "{:<14} {:>6.1} {:>4.0}  {}",
+            k.koota.name(),
+            k.points,
+            k.max_points,
+            dosha
+        );
+    }
+    println!(
+        "Total: {:.1} / 36 (adjusted for cancellations: {:.1})",
+        ak.total, ak.adjusted_total
+    );
+
+    let dk = &result.dashakoota;
+    println!("\nDashakoota");
+    for p in &dk.poruthams {
+        println!(
+            "  {:<14} {}",
+            p.porutham.name(),
+            if p.matched { "match" } else { "no match" }
+        );
+    }
+    println!("Matched: {} / 10", dk.matched_count);
+    if dk.critical_failure {
+        println!("Rajju or Vedha failed");
+    }
+}
+
 fn parse_charakaraka_scheme(s: &str) -> dhruv_vedic_base::CharakarakaScheme {
     match s.to_ascii_lowercase().replace('_', "-").as_str() {
         "eight" | "8" | "8-chara" | "8-charakaraka" | "jaimini-8" => {
@@ -9761,15 +11203,15 @@ fn print_chandra_grahan(label: &str, ev: &dhruv_search::grahan_types::ChandraGra
         ev.greatest_grahan_utc, ev.greatest_grahan_jd
     );
     println!("  P1: UTC {}  JD TDB {:.6}", ev.p1_utc, ev.p1_jd);
-    if let Some(u1) = ev.u1_jd {
-        if let Some(u1_utc) = ev.u1_utc {
-            println!("  U1: UTC {}  JD TDB {:.6}", u1_utc, u1);
-        }
+    if let Some(u1) = ev.u1_jd
+        && let Some(u1_utc) = ev.u1_utc
+    {
+        println!("  U1: UTC {}  JD TDB {:.6}", u1_utc, u1);
     }
-    if let Some(u2) = ev.u2_jd {
-        if let Some(u2_utc) = ev.u2_utc {
-            println!("  U2: UTC {}  JD TDB {:.6}", u2_utc, u2);
-        }
+    if let Some(u2) = ev.u2_jd
+        && let Some(u2_utc) = ev.u2_utc
+    {
+        println!("  U2: UTC {}  JD TDB {:.6}", u2_utc, u2);
     }
 }
 
@@ -9779,24 +11221,171 @@ fn print_surya_grahan(label: &str, ev: &dhruv_search::grahan_types::SuryaGrahan)
         "  Greatest: UTC {}  JD TDB {:.6}",
         ev.greatest_grahan_utc, ev.greatest_grahan_jd
     );
-    if let Some(c1) = ev.c1_jd {
-        if let Some(c1_utc) = ev.c1_utc {
-            println!("  C1: UTC {}  JD TDB {:.6}", c1_utc, c1);
-        }
+    if let Some(c1) = ev.c1_jd
+        && let Some(c1_utc) = ev.c1_utc
+    {
+        println!("  C1: UTC {}  JD TDB {:.6}", c1_utc, c1);
+    }
+    if let Some(c2) = ev.c2_jd
+        && let Some(c2_utc) = ev.c2_utc
+    {
+        println!("  C2: UTC {}  JD TDB {:.6}", c2_utc, c2);
+    }
+    if let Some(c3) = ev.c3_jd
+        && let Some(c3_utc) = ev.c3_utc
+    {
+        println!("  C3: UTC {}  JD TDB {:.6}", c3_utc, c3);
+    }
+    if let Some(c4) = ev.c4_jd
+        && let Some(c4_utc) = ev.c4_utc
+    {
+        println!("  C4: UTC {}  JD TDB {:.6}", c4_utc, c4);
     }
-    if let Some(c2) = ev.c2_jd {
-        if let Some(c2_utc) = ev.c2_utc {
-            println!("  C2: UTC {}  JD TDB {:.6}", c2_utc, c2);
+}
+
+fn print_chandra_grahan_local(local: &dhruv_search::ChandraGrahanLocal) {
+    println!(
+        "Local ({:.4}, {:.4}): {:?}",
+        local.location.latitude_deg, local.location.longitude_deg, local.visibility
+    );
+    let contacts = [
+        ("P1", Some(local.p1)),
+        ("U1", local.u1),
+        ("U2", local.u2),
+        ("Max", Some(local.greatest_grahan)),
+        ("U3", local.u3),
+        ("U4", local.u4),
+        ("P4", Some(local.p4)),
+    ];
+    for (label, contact) in contacts {
+        if let Some(c) = contact {
+            println!(
+                "  {label}: UTC {}  Moon alt {:.2}°  az {:.2}°{}",
+                c.utc,
+                c.moon_altitude_deg,
+                c.moon_azimuth_deg,
+                if c.above_horizon {
+                    ""
+                } else {
+                    "  (below horizon)"
+                }
+            );
         }
     }
-    if let Some(c3) = ev.c3_jd {
-        if let Some(c3_utc) = ev.c3_utc {
-            println!("  C3: UTC {}  JD TDB {:.6}", c3_utc, c3);
+    let windows = [
+        ("Penumbral", local.penumbral_window),
+        ("Umbral", local.umbral_window),
+        ("Total", local.total_window),
+    ];
+    for (label, window) in windows {
+        match window {
+            Some(w) => println!("  {label} visible: UTC {} – {}", w.start_utc, w.end_utc),
+            None => println!("  {label} visible: no"),
         }
     }
-    if let Some(c4) = ev.c4_jd {
-        if let Some(c4_utc) = ev.c4_utc {
-            println!("  C4: UTC {}  JD TDB {:.6}", c4_utc, c4);
+}
+
+/// CSV rows `latitude_deg,longitude_deg,visibility` with a header line.
+fn chandra_grahan_grid_csv(grid: &dhruv_search::ChandraGrahanVisibilityGrid) -> String {
+    let mut out = String::from("latitude_deg,longitude_deg,visibility\n");
+    for cell in &grid.cells {
+        let visibility = match cell.visibility {
+            dhruv_search::GrahanVisibility::Visible => "visible",
+            dhruv_search::GrahanVisibility::PartlyVisible => "partly_visible",
+            dhruv_search::GrahanVisibility::NotVisible => "not_visible",
+        };
+        out.push_str(&format!(
+            "{},{},{visibility}\n",
+            cell.latitude_deg, cell.longitude_deg
+        ));
+    }
+    out
+}
+
+fn print_besselian_elements(el: &dhruv_search::BesselianElements) {
+    println!("Besselian elements at JD TDB {:.6}:", el.jd_tdb);
+    println!("  x  = {:>10.6}  x'  = {:>10.6}/h", el.x, el.x_rate);
+    println!("  y  = {:>10.6}  y'  = {:>10.6}/h", el.y, el.y_rate);
+    println!(
+        "  d  = {:>10.6}°  d'  = {:>10.6}°/h",
+        el.d_deg, el.d_rate_deg
+    );
+    println!(
+        "  μ  = {:>10.6}°  μ'  = {:>10.6}°/h",
+        el.mu_deg, el.mu_rate_deg
+    );
+    println!("  l1 = {:>10.6}  l1' = {:>10.6}/h", el.l1, el.l1_rate);
+    println!("  l2 = {:>10.6}  l2' = {:>10.6}/h", el.l2, el.l2_rate);
+    println!("  tan f1 = {:.7}  tan f2 = {:.7}", el.tan_f1, el.tan_f2);
+}
+
+/// GeoJSON FeatureCollection with one MultiLineString per path curve.
+fn surya_grahan_path_geojson(path: &dhruv_search::SuryaGrahanPath) -> String {
+    let curves = [
+        ("central_line", &path.central_line),
+        ("umbral_north_limit", &path.umbral_north_limit),
+        ("umbral_south_limit", &path.umbral_south_limit),
+        ("penumbral_north_limit", &path.penumbral_north_limit),
+        ("penumbral_south_limit", &path.penumbral_south_limit),
+        ("sunrise_limit", &path.sunrise_limit),
+        ("sunset_limit", &path.sunset_limit),
+    ];
+    let features: Vec<String> = curves
+        .iter()
+        .map(|(name, line)| {
+            let polylines: Vec<String> = line
+                .iter()
+                .map(|points| {
+                    let coords: Vec<String> = points
+                        .iter()
+                        .map(|p| format!("[{:.5},{:.5}]", p.longitude_deg, p.latitude_deg))
+                        .collect();
+                    format!("[{}]", coords.join(","))
+                })
+                .collect();
+            format!(
+                "{{\"type\":\"Feature\",\"properties\":{{\"name\":\"{name}\"}},\"geometry\":{{\"type\":\"MultiLineString\",\"coordinates\":[{}]}}}}",
+                polylines.join(",")
+            )
+        })
+        .collect();
+    format!(
+        "{{\"type\":\"FeatureCollection\",\"features\":[{}]}}\n",
+        features.join(",")
+    )
+}
+
+fn print_surya_grahan_local(local: &dhruv_search::SuryaGrahanLocal) {
+    println!(
+        "Local ({:.4}, {:.4}): {:?}  mag: {:.4}  obscuration: {:.4}  visible: {}",
+        local.location.latitude_deg,
+        local.location.longitude_deg,
+        local.grahan_type,
+        local.magnitude,
+        local.obscuration,
+        local.visible
+    );
+    let contacts = [
+        ("C1", local.c1),
+        ("C2", local.c2),
+        ("Max", Some(local.greatest_grahan)),
+        ("C3", local.c3),
+        ("C4", local.c4),
+    ];
+    for (label, contact) in contacts {
+        if let Some(c) = contact {
+            println!(
+                "  {label}: UTC {}  JD TDB {:.6}  Sun alt {:.2}°  az {:.2}°{}",
+                c.utc,
+                c.jd,
+                c.sun_altitude_deg,
+                c.sun_azimuth_deg,
+                if c.above_horizon {
+                    ""
+                } else {
+                    "  (below horizon)"
+                }
+            );
         }
     }
 }
@@ -10056,6 +11645,7 @@ fn shodasavarga_amsha_selection() -> dhruv_search::AmshaSelectionConfig {
     sel
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_kundali_config(
     resolved: &ResolvedKundaliFlags,
     dasha_systems: Option<&str>,
@@ -10915,6 +12505,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_surya_grahan_path_geojson_layout() {
+        let epoch = dhruv_time::Epoch::from_jd_tdb(2_460_409.25);
+        let point = |lon: f64| dhruv_search::SuryaGrahanPathPoint {
+            jd_tdb: epoch.as_jd_tdb(),
+            epoch,
+            latitude_deg: 32.5,
+            longitude_deg: lon,
+        };
+        let elements = dhruv_search::BesselianElements {
+            jd_tdb: epoch.as_jd_tdb(),
+            epoch,
+            x: 0.0,
+            y: 0.0,
+            d_deg: 0.0,
+            mu_deg: 0.0,
+            l1: 0.5,
+            l2: -0.01,
+            tan_f1: 0.0047,
+            tan_f2: 0.0047,
+            x_rate: 0.5,
+            y_rate: 0.3,
+            d_rate_deg: 0.0,
+            mu_rate_deg: 15.0,
+            l1_rate: 0.0,
+            l2_rate: 0.0,
+        };
+        let path = dhruv_search::SuryaGrahanPath {
+            elements,
+            central_line: vec![vec![point(-97.0), point(-96.0)], vec![point(10.0)]],
+            umbral_north_limit: Vec::new(),
+            umbral_south_limit: Vec::new(),
+            penumbral_north_limit: Vec::new(),
+            penumbral_south_limit: Vec::new(),
+            sunrise_limit: Vec::new(),
+            sunset_limit: Vec::new(),
+        };
+        let json = surya_grahan_path_geojson(&path);
+        assert!(json.starts_with("{\"type\":\"FeatureCollection\""));
+        assert_eq!(json.matches("\"type\":\"Feature\",").count(), 7);
+        assert!(json.contains(
+            "\"coordinates\":[[[-97.00000,32.50000],[-96.00000,32.50000]],[[10.00000,32.50000]]]"
+        ));
+        assert!(json.contains("\"name\":\"umbral_north_limit\"},\"geometry\":{\"type\":\"MultiLineString\",\"coordinates\":[]}"));
+    }
+
     #[test]
     fn test_bhava_config_from_cli_special_bhavabala_default_and_opt_out() {
         let default_cfg = bhava_config_from_cli(&default_bhava_behavior_args());
@@ -11011,6 +12647,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_shodasavarga_amsha_selection() {
         let sel = shodasavarga_amsha_selection();
         assert_eq!(sel.count, 16);
@@ -11350,6 +12987,7 @@ mod tests {
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn make_bhava_result() -> dhruv_vedic_base::BhavaResult {
         let mut bhavas = [dhruv_vedic_base::Bhava {
             number: 1,
@@ -11547,10 +13185,47 @@ mod tests {
             "--eop",
             "x",
         ];
-        let result = Cli::try_parse_from(&args);
+        let result = Cli::try_parse_from(args);
         assert!(result.is_ok(), "--tropical alone should parse successfully");
     }
 
+    #[test]
+    fn milan_rejects_mixed_dates_and_moons() {
+        let args = [
+            "dhruv",
+            "milan",
+            "--groom-date",
+            "2000-01-01T00:00:00Z",
+            "--groom-moon",
+            "10",
+            "--bride-moon",
+            "20",
+        ];
+        assert!(Cli::try_parse_from(args).is_err());
+        let args = ["dhruv", "milan", "--groom-moon", "10", "--bride-moon", "20"];
+        assert!(Cli::try_parse_from(args).is_ok());
+    }
+
+    #[test]
+    fn doshas_parses_house_set_and_orb() {
+        let args = [
+            "dhruv",
+            "doshas",
+            "--date",
+            "2000-01-01T00:00:00Z",
+            "--lat",
+            "28.6",
+            "--lon",
+            "77.2",
+            "--north",
+            "--gandanta-orb",
+            "1.5",
+            "--eop",
+            "x",
+        ];
+        assert!(Cli::try_parse_from(args).is_ok());
+    }
+
     #[test]
     fn delta_t_model_parser_accepts_supported_values() {
         assert_eq!(
@@ -11578,6 +13253,7 @@ mod tests {
             true,
             Some(0.25),
             Some(25.0),
+            TdbModel::FairheadBretagnonTruncated,
         );
         match out {
             TimeConversionPolicy::HybridDeltaT(opts) => {
@@ -11593,6 +13269,7 @@ mod tests {
                 );
                 assert_eq!(opts.pre_range_dut1, 0.25);
                 assert_eq!(opts.future_transition_years, 25.0);
+                assert_eq!(opts.tdb_model, TdbModel::FairheadBretagnonTruncated);
             }
             TimeConversionPolicy::StrictLsk => panic!("expected hybrid policy"),
         }
@@ -240,3 +240,143 @@ fn cli_amsha_variations_lists_catalogs() {
     assert!(stdout.contains("1   cancer-leo-only"));
     assert!(stdout.contains("Navamsha (D9):"));
 }
+
+/// Minimal little-endian DAF/SPK with one constant-position Type 2 record
+/// per (start, end) window for target 10 wrt 0.
+fn synthetic_spk(windows: &[(f64, f64)]) -> Vec<u8> {
+    let mut file = vec![0u8; 3 * 1024];
+    file[0..8].copy_from_slice(b"DAF/SPK ");
+    file[8..12].copy_from_slice(&2i32.to_le_bytes());
+    file[12..16].copy_from_slice(&6i32.to_le_bytes());
+    file[16..76].copy_from_slice(&[b' '; 60]);
+    file[16..25].copy_from_slice(b"CLI SPK 1");
+    file[76..80].copy_from_slice(&2i32.to_le_bytes());
+    file[80..84].copy_from_slice(&2i32.to_le_bytes());
+    file[88..96].copy_from_slice(b"LTL-IEEE");
+    file[1024 + 16..1024 + 24].copy_from_slice(&(windows.len() as f64).to_le_bytes());
+
+    let mut next_addr = 3 * 128 + 1;
+    for (i, &(start, end)) in windows.iter().enumerate() {
+        let radius = (end - start) / 2.0;
+        let words = [
+            start + radius,
+            radius,
+            1.0,
+            2.0,
+            3.0,
+            start,
+            2.0 * radius,
+            5.0,
+            1.0,
+        ];
+        let off = 1024 + 24 + i * 5 * 8;
+        file[off..off + 8].copy_from_slice(&start.to_le_bytes());
+        file[off + 8..off + 16].copy_from_slice(&end.to_le_bytes());
+        let ints = [
+            10,
+            0,
+            1,
+            2,
+            next_addr as i32,
+            (next_addr + words.len() - 1) as i32,
+        ];
+        for (j, v) in ints.iter().enumerate() {
+            file[off + 16 + j * 4..off + 20 + j * 4].copy_from_slice(&v.to_le_bytes());
+        }
+        next_addr += words.len();
+        for w in words {
+            file.extend_from_slice(&w.to_le_bytes());
+        }
+    }
+    file
+}
+
+/// Minimal little-endian DAF/PCK with one constant-angle Type 2 segment for
+/// body frame 3000 wrt J2000 over [-1e5, 1e5] s.
+fn synthetic_pck() -> Vec<u8> {
+    let mut file = vec![0u8; 3 * 1024];
+    file[0..8].copy_from_slice(b"DAF/PCK ");
+    file[8..12].copy_from_slice(&2i32.to_le_bytes());
+    file[12..16].copy_from_slice(&5i32.to_le_bytes());
+    file[16..76].copy_from_slice(&[b' '; 60]);
+    file[16..25].copy_from_slice(b"CLI PCK 1");
+    file[76..80].copy_from_slice(&2i32.to_le_bytes());
+    file[80..84].copy_from_slice(&2i32.to_le_bytes());
+    file[88..96].copy_from_slice(b"LTL-IEEE");
+    file[1024 + 16..1024 + 24].copy_from_slice(&1.0f64.to_le_bytes());
+
+    let words = [
+        0.0f64, 1.0e5, 0.1, 0.0, 1.3, 0.0, 1.0, 0.0, -1.0e5, 2.0e5, 8.0, 1.0,
+    ];
+    let off = 1024 + 24;
+    file[off..off + 8].copy_from_slice(&(-1.0e5f64).to_le_bytes());
+    file[off + 8..off + 16].copy_from_slice(&1.0e5f64.to_le_bytes());
+    let first = 3 * 128 + 1;
+    let ints = [3000, 1, 2, first as i32, (first + words.len() - 1) as i32];
+    for (j, v) in ints.iter().enumerate() {
+        file[off + 16 + j * 4..off + 20 + j * 4].copy_from_slice(&v.to_le_bytes());
+    }
+    for w in words {
+        file.extend_from_slice(&w.to_le_bytes());
+    }
+    file
+}
+
+#[test]
+fn cli_kernel_info_lists_pck_segments_only_on_request() {
+    let path =
+        std::env::temp_dir().join(format!("dhruv_cli_kernel_info_{}.bpc", std::process::id()));
+    std::fs::write(&path, synthetic_pck()).unwrap();
+    let run = |extra: &[&str]| {
+        let mut args = vec!["--no-config", "kernel-info", path.to_str().unwrap()];
+        args.extend_from_slice(extra);
+        let output = run_cli(&args);
+        assert_success(&output, "kernel-info");
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    let brief = run(&[]);
+    let listed = run(&["--segments"]);
+    std::fs::remove_file(&path).unwrap();
+
+    for stdout in [&brief, &listed] {
+        assert!(stdout.contains("File type:     DAF/PCK"), "{stdout}");
+        assert!(stdout.contains("Internal name: CLI PCK 1"), "{stdout}");
+        assert!(stdout.contains("Segments:      1"), "{stdout}");
+    }
+    assert!(!brief.contains("body frame"), "{brief}");
+    assert!(
+        listed.contains("[0] body frame 3000 wrt 1 type 2"),
+        "{listed}"
+    );
+}
+
+#[test]
+fn cli_kernel_info_reports_metadata_and_gaps() {
+    let path =
+        std::env::temp_dir().join(format!("dhruv_cli_kernel_info_{}.bsp", std::process::id()));
+    std::fs::write(
+        &path,
+        synthetic_spk(&[(0.0, 86_400.0), (172_800.0, 259_200.0)]),
+    )
+    .unwrap();
+    let output = run_cli(&[
+        "--no-config",
+        "kernel-info",
+        path.to_str().unwrap(),
+        "--segments",
+    ]);
+    std::fs::remove_file(&path).unwrap();
+    assert_success(&output, "kernel-info");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Internal name: CLI SPK 1"), "{stdout}");
+    assert!(stdout.contains("Segments:      2"), "{stdout}");
+    assert!(
+        stdout.contains("target 10 wrt 0 (2 segment(s))  GAPS"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("gap 2000-01-02T12:00:00.000000 TDB"),
+        "{stdout}"
+    );
+}
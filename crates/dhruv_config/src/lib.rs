@@ -10,8 +10,8 @@ use std::fmt::{Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use dhruv_core::{Body, EngineConfig};
-use dhruv_frames::{PrecessionModel, ReferencePlane};
+use dhruv_core::{Body, EngineConfig, SpkLoadMode};
+use dhruv_frames::{NutationModel, PrecessionModel, ReferencePlane};
 use dhruv_search::{
     AmshaSelectionConfig, BindusConfig, ConjunctionConfig, DashaSelectionConfig, DashaSnapshotTime,
     DrishtiConfig, FullKundaliConfig, GrahaPositionsConfig, GrahanConfig, SankrantiConfig,
@@ -118,6 +118,7 @@ pub struct CommonConfigPatch {
     pub ayanamsha_system: Option<EnumInput>,
     pub use_nutation: Option<bool>,
     pub precession_model: Option<EnumInput>,
+    pub nutation_model: Option<EnumInput>,
     pub reference_plane: Option<EnumInput>,
     pub step_size_days: Option<f64>,
     pub max_iterations: Option<u32>,
@@ -160,6 +161,7 @@ pub struct EngineConfigPatch {
     pub lsk_path: Option<String>,
     pub cache_capacity: Option<usize>,
     pub strict_validation: Option<bool>,
+    pub spk_load_mode: Option<EnumInput>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -193,6 +195,7 @@ pub struct SankrantiConfigPatch {
     pub ayanamsha_system: Option<EnumInput>,
     pub use_nutation: Option<bool>,
     pub precession_model: Option<EnumInput>,
+    pub nutation_model: Option<EnumInput>,
     pub reference_plane: Option<EnumInput>,
     pub step_size_days: Option<f64>,
     pub max_iterations: Option<u32>,
@@ -426,11 +429,23 @@ impl ConfigResolver {
             "engine.strict_validation",
         )?;
 
+        // Optional in every defaults mode: whole-file loading is the
+        // long-standing behaviour.
+        let (load_mode_input, load_mode_source) = choose_enum(
+            explicit.spk_load_mode,
+            op.spk_load_mode,
+            None,
+            Some(EnumInput::Str("in-memory".to_string())),
+            "engine.spk_load_mode",
+        )?;
+        let spk_load_mode = parse_spk_load_mode(&load_mode_input, "engine.spk_load_mode")?;
+
         let mut source = BTreeMap::new();
         source.insert("spk_paths".to_string(), spk_source);
         source.insert("lsk_path".to_string(), lsk_source);
         source.insert("cache_capacity".to_string(), cache_source);
         source.insert("strict_validation".to_string(), strict_source);
+        source.insert("spk_load_mode".to_string(), load_mode_source);
 
         Ok(EffectiveConfig {
             value: EngineConfig {
@@ -438,6 +453,7 @@ impl ConfigResolver {
                 lsk_path: PathBuf::from(lsk_path),
                 cache_capacity,
                 strict_validation,
+                spk_load_mode,
             },
             source_by_field: source,
         })
@@ -528,6 +544,7 @@ impl ConfigResolver {
             value: GrahanConfig {
                 include_penumbral,
                 include_peak_details,
+                ..Default::default()
             },
             source_by_field: source,
         })
@@ -622,6 +639,15 @@ impl ConfigResolver {
         )?;
         let precession_model = parse_precession_model(&model_input, "sankranti.precession_model")?;
 
+        let (nutation_input, nutation_source) = choose_enum(
+            explicit.nutation_model,
+            op.nutation_model.clone(),
+            self.file.common.nutation_model.clone(),
+            recommended_enum(self.defaults_mode, EnumInput::Str("iau2000b".to_string())),
+            "sankranti.nutation_model",
+        )?;
+        let nutation_model = parse_nutation_model(&nutation_input, "sankranti.nutation_model")?;
+
         let (plane_input, plane_source) = choose_enum(
             explicit.reference_plane,
             op.reference_plane.clone(),
@@ -668,6 +694,7 @@ impl ConfigResolver {
             ayanamsha_system,
             use_nutation,
             precession_model,
+            nutation_model,
             reference_plane,
             step_size_days,
             max_iterations,
@@ -678,6 +705,7 @@ impl ConfigResolver {
         source.insert("ayanamsha_system".to_string(), system_source);
         source.insert("use_nutation".to_string(), nut_source);
         source.insert("precession_model".to_string(), model_source);
+        source.insert("nutation_model".to_string(), nutation_source);
         source.insert("reference_plane".to_string(), plane_source);
         source.insert("step_size_days".to_string(), step_source);
         source.insert("max_iterations".to_string(), iter_source);
@@ -731,6 +759,7 @@ impl ConfigResolver {
                 use_refraction,
                 sun_limb,
                 altitude_correction,
+                ..Default::default()
             },
             source_by_field: source,
         })
@@ -878,6 +907,7 @@ impl ConfigResolver {
             value: TaraConfig {
                 accuracy,
                 apply_parallax,
+                ..TaraConfig::default()
             },
             source_by_field: source,
         })
@@ -1457,6 +1487,31 @@ fn parse_precession_model(
     }
 }
 
+fn parse_nutation_model(
+    input: &EnumInput,
+    field: &'static str,
+) -> Result<NutationModel, ConfigError> {
+    match input.as_lower().replace('_', "-").as_str() {
+        "0" | "iau2000b" | "iau-2000b" => Ok(NutationModel::Iau2000B),
+        "1" | "iau1980" | "iau-1980" => Ok(NutationModel::Iau1980),
+        other => Err(ConfigError::InvalidEnumValue {
+            field,
+            value: other.to_string(),
+        }),
+    }
+}
+
+fn parse_spk_load_mode(input: &EnumInput, field: &'static str) -> Result<SpkLoadMode, ConfigError> {
+    match input.as_lower().replace('_', "-").as_str() {
+        "0" | "in-memory" | "memory" => Ok(SpkLoadMode::InMemory),
+        "1" | "on-demand" | "lazy" => Ok(SpkLoadMode::OnDemand),
+        other => Err(ConfigError::InvalidEnumValue {
+            field,
+            value: other.to_string(),
+        }),
+    }
+}
+
 fn parse_reference_plane(
     input: &EnumInput,
     field: &'static str,
@@ -1502,7 +1557,7 @@ fn parse_bhava_system(
                 })
         }
         EnumInput::Str(s) => {
-            let key = s.to_ascii_lowercase().replace('-', "").replace('_', "");
+            let key = s.to_ascii_lowercase().replace(['-', '_'], "");
             ALL_BHAVA_SYSTEMS
                 .iter()
                 .copied()
@@ -1935,6 +1990,26 @@ max_iterations = 55
         assert_eq!(eff.value.max_iterations, 55);
     }
 
+    #[test]
+    fn resolve_sankranti_nutation_model_layers() {
+        let text = r#"
+version = 1
+[common]
+nutation_model = "iau2000b"
+[operations.sankranti]
+nutation_model = "iau-1980"
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_sankranti(None).unwrap();
+        assert_eq!(eff.value.nutation_model, NutationModel::Iau1980);
+
+        let file: DhruvConfigFile = toml::from_str("version = 1").unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_sankranti(None).unwrap();
+        assert_eq!(eff.value.nutation_model, NutationModel::Iau2000B);
+    }
+
     #[test]
     fn resolve_bhava_sayanadi_rounding_defaults_floor_and_accepts_ceil() {
         let file: DhruvConfigFile = toml::from_str(
@@ -2002,4 +2077,32 @@ include_special_bhavabala_rules = false
         let err = resolver.resolve_engine(None).unwrap_err();
         assert!(matches!(err, ConfigError::MissingRequired(_)));
     }
+    #[test]
+    fn resolve_engine_spk_load_mode_defaults_in_memory() {
+        let file: DhruvConfigFile = toml::from_str(
+            r#"
+version = 1
+[common.engine]
+spk_paths = ["de442s.bsp"]
+lsk_path = "naif0012.tls"
+cache_capacity = 64
+strict_validation = true
+"#,
+        )
+        .unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::None);
+        let eff = resolver.resolve_engine(None).unwrap();
+        assert_eq!(eff.value.spk_load_mode, SpkLoadMode::InMemory);
+
+        let explicit = EngineConfigPatch {
+            spk_load_mode: Some(EnumInput::Str("on_demand".to_string())),
+            ..EngineConfigPatch::default()
+        };
+        let eff = resolver.resolve_engine(Some(explicit)).unwrap();
+        assert_eq!(eff.value.spk_load_mode, SpkLoadMode::OnDemand);
+        assert_eq!(
+            eff.source_by_field.get("spk_load_mode"),
+            Some(&ConfigSource::Explicit)
+        );
+    }
 }
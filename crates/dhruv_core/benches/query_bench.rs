@@ -2,7 +2,7 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, EngineConfig, Frame, Observer, Query, SpkLoadMode};
 
 fn load_engine() -> Option<Engine> {
     let base = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../kernels/data");
@@ -18,6 +18,7 @@ fn load_engine() -> Option<Engine> {
             lsk_path: lsk,
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         })
         .expect("should load engine"),
     )
@@ -38,6 +39,7 @@ fn single_query_benchmarks(c: &mut Criterion) {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: epoch,
+            correction: Correction::Geometric,
         };
         b.iter(|| engine.query(query).unwrap());
     });
@@ -48,6 +50,7 @@ fn single_query_benchmarks(c: &mut Criterion) {
             observer: Observer::Body(Body::Earth),
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: epoch,
+            correction: Correction::Geometric,
         };
         b.iter(|| engine.query(query).unwrap());
     });
@@ -58,6 +61,7 @@ fn single_query_benchmarks(c: &mut Criterion) {
             observer: Observer::Body(Body::Earth),
             frame: Frame::EclipticJ2000,
             epoch_tdb_jd: epoch,
+            correction: Correction::Geometric,
         };
         b.iter(|| engine.query(query).unwrap());
     });
@@ -93,6 +97,7 @@ fn batch_benchmark(c: &mut Criterion) {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: epoch,
+            correction: Correction::Geometric,
         })
         .collect();
 
@@ -112,6 +117,7 @@ fn concurrent_scaling_benchmarks(c: &mut Criterion) {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
 
     let mut group = c.benchmark_group("concurrent_scaling");
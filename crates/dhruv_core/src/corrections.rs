@@ -0,0 +1,252 @@
+//! Observer-relative corrections applied to geometric SSB states.
+//!
+//! Light-time iteration, solar gravitational deflection (finite-distance
+//! form of the PPN light-bending formula) and first-order stellar
+//! aberration. All vectors are ICRF/J2000, km and km/s.
+//!
+//! Sources: IERS Conventions (2010) §5; Explanatory Supplement to the
+//! Astronomical Almanac (3rd ed.) §7.2. Implementation is original.
+
+/// Speed of light in km/s (IAU 2009, exact).
+pub const C_KM_S: f64 = 299_792.458;
+
+/// Schwarzschild term 2GM☉/c² in km.
+pub const SUN_2GM_OVER_C2_KM: f64 = 2.953_250_077;
+
+/// Maximum number of light-time iterations.
+pub(crate) const LIGHT_TIME_MAX_ITERATIONS: usize = 5;
+
+/// Light-time convergence threshold in seconds.
+pub(crate) const LIGHT_TIME_TOLERANCE_S: f64 = 1e-9;
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: &[f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn sub(a: &[f64], b: &[f64]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// One-way light time in seconds from an SSB target state to an SSB observer.
+pub(crate) fn light_time_s(target_ssb: &[f64; 6], observer_ssb: &[f64; 6]) -> f64 {
+    norm(&sub(target_ssb, observer_ssb)) / C_KM_S
+}
+
+/// Observer-relative state of a target evaluated at the retarded epoch.
+///
+/// `target_ssb` is the target at `t - τ` and `observer_ssb` the observer at
+/// `t`. The velocity accounts for the rate of change of τ itself:
+/// `dτ/dt = r̂·(v_t − v_o) / (c + r̂·v_t)`, so the target velocity is scaled by
+/// `1 − dτ/dt`.
+pub(crate) fn light_time_state(
+    target_ssb: &[f64; 6],
+    observer_ssb: &[f64; 6],
+) -> ([f64; 3], [f64; 3]) {
+    let position = sub(target_ssb, observer_ssb);
+    let target_vel = [target_ssb[3], target_ssb[4], target_ssb[5]];
+    let observer_vel = [observer_ssb[3], observer_ssb[4], observer_ssb[5]];
+    let distance = norm(&position);
+    if distance == 0.0 {
+        return (position, sub(&target_vel, &observer_vel));
+    }
+
+    let unit = [
+        position[0] / distance,
+        position[1] / distance,
+        position[2] / distance,
+    ];
+    let rel_vel = sub(&target_vel, &observer_vel);
+    let dtau_dt = dot(&unit, &rel_vel) / (C_KM_S + dot(&unit, &target_vel));
+    let scale = 1.0 - dtau_dt;
+    let velocity = [
+        target_vel[0] * scale - observer_vel[0],
+        target_vel[1] * scale - observer_vel[1],
+        target_vel[2] * scale - observer_vel[2],
+    ];
+    (position, velocity)
+}
+
+/// Apply solar gravitational light deflection to an observer-relative
+/// position.
+///
+/// * `position` — target relative to observer (light-time corrected)
+/// * `target_from_sun` — target relative to the Sun at the retarded epoch
+/// * `observer_from_sun` — observer relative to the Sun at the observation epoch
+///
+/// Uses the finite-distance form `p₁ = p + (2GM/c²E)[(p·q)e − (e·p)q]/(1 + q·e)`
+/// with unit vectors `p` (observer→target), `q` (Sun→target), `e`
+/// (Sun→observer). Distance is preserved. Returns the input unchanged when
+/// any of the vectors is degenerate.
+pub(crate) fn apply_solar_deflection(
+    position: &[f64; 3],
+    target_from_sun: &[f64; 3],
+    observer_from_sun: &[f64; 3],
+) -> [f64; 3] {
+    let distance = norm(position);
+    let q_len = norm(target_from_sun);
+    let e_len = norm(observer_from_sun);
+    if distance == 0.0 || q_len == 0.0 || e_len == 0.0 {
+        return *position;
+    }
+
+    let p = [
+        position[0] / distance,
+        position[1] / distance,
+        position[2] / distance,
+    ];
+    let q = [
+        target_from_sun[0] / q_len,
+        target_from_sun[1] / q_len,
+        target_from_sun[2] / q_len,
+    ];
+    let e = [
+        observer_from_sun[0] / e_len,
+        observer_from_sun[1] / e_len,
+        observer_from_sun[2] / e_len,
+    ];
+
+    let one_plus_qe = 1.0 + dot(&q, &e);
+    // Target directly behind the Sun: formula is singular.
+    if one_plus_qe <= f64::EPSILON {
+        return *position;
+    }
+
+    let factor = SUN_2GM_OVER_C2_KM / e_len / one_plus_qe;
+    let pq = dot(&p, &q);
+    let ep = dot(&e, &p);
+    let mut p1 = [
+        p[0] + factor * (pq * e[0] - ep * q[0]),
+        p[1] + factor * (pq * e[1] - ep * q[1]),
+        p[2] + factor * (pq * e[2] - ep * q[2]),
+    ];
+    let len = norm(&p1);
+    for v in &mut p1 {
+        *v *= distance / len;
+    }
+    p1
+}
+
+/// Apply first-order annual/diurnal aberration for an observer moving with
+/// `observer_velocity_km_s` relative to the SSB.
+///
+/// `u' = u + v/c − (u·v/c)u`, renormalised; distance is preserved.
+pub(crate) fn apply_aberration(position: &[f64; 3], observer_velocity_km_s: &[f64; 3]) -> [f64; 3] {
+    let distance = norm(position);
+    if distance == 0.0 {
+        return *position;
+    }
+
+    let u = [
+        position[0] / distance,
+        position[1] / distance,
+        position[2] / distance,
+    ];
+    let beta = [
+        observer_velocity_km_s[0] / C_KM_S,
+        observer_velocity_km_s[1] / C_KM_S,
+        observer_velocity_km_s[2] / C_KM_S,
+    ];
+    let ub = dot(&u, &beta);
+    let mut shifted = [
+        u[0] + beta[0] - ub * u[0],
+        u[1] + beta[1] - ub * u[1],
+        u[2] + beta[2] - ub * u[2],
+    ];
+    let len = norm(&shifted);
+    for v in &mut shifted {
+        *v *= distance / len;
+    }
+    shifted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AU_KM: f64 = 149_597_870.7;
+    const RAD_TO_ARCSEC: f64 = 206_264.806_247_096_36;
+
+    fn angle(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+        let cross = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        norm(&cross).atan2(dot(a, b))
+    }
+
+    #[test]
+    fn light_time_for_one_au() {
+        let target = [AU_KM, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let observer = [0.0; 6];
+        let lt = light_time_s(&target, &observer);
+        assert!((lt - 499.004_784).abs() < 1e-5, "lt = {lt}");
+    }
+
+    #[test]
+    fn light_time_velocity_static_target() {
+        // Stationary target: velocity is just the negated observer velocity.
+        let target = [AU_KM, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let observer = [0.0, 0.0, 0.0, 0.0, 30.0, 0.0];
+        let (_, vel) = light_time_state(&target, &observer);
+        assert_eq!(vel, [0.0, -30.0, 0.0]);
+    }
+
+    #[test]
+    fn light_time_velocity_radial_recession() {
+        // Target receding radially at v: apparent rate is v·c/(c + v).
+        let v = 1000.0;
+        let target = [AU_KM, 0.0, 0.0, v, 0.0, 0.0];
+        let observer = [0.0; 6];
+        let (_, vel) = light_time_state(&target, &observer);
+        let expected = v * C_KM_S / (C_KM_S + v);
+        assert!((vel[0] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn aberration_magnitude_perpendicular() {
+        // Earth orbital speed perpendicular to line of sight: ~20.5".
+        let pos = [AU_KM, 0.0, 0.0];
+        let vel = [0.0, 29.78, 0.0];
+        let out = apply_aberration(&pos, &vel);
+        let shift = angle(&pos, &out) * RAD_TO_ARCSEC;
+        assert!((shift - 20.49).abs() < 0.05, "shift = {shift}\"");
+        assert!((norm(&out) - AU_KM).abs() < 1e-3);
+        // Shifted towards the direction of motion.
+        assert!(out[1] > 0.0);
+    }
+
+    #[test]
+    fn aberration_zero_along_velocity() {
+        let pos = [AU_KM, 0.0, 0.0];
+        let vel = [29.78, 0.0, 0.0];
+        let out = apply_aberration(&pos, &vel);
+        assert!(angle(&pos, &out) < 1e-15);
+    }
+
+    #[test]
+    fn deflection_at_ninety_degrees() {
+        // Distant target 90° from the Sun seen from 1 AU: ~4.07 mas.
+        let observer_from_sun = [AU_KM, 0.0, 0.0];
+        let target_from_sun = [AU_KM, 1.0e12, 0.0];
+        let position = sub(&target_from_sun, &observer_from_sun);
+        let out = apply_solar_deflection(&position, &target_from_sun, &observer_from_sun);
+        let shift_mas = angle(&position, &out) * RAD_TO_ARCSEC * 1000.0;
+        assert!((shift_mas - 4.07).abs() < 0.05, "shift = {shift_mas} mas");
+        // Deflected away from the Sun.
+        assert!(out[0] > position[0]);
+        assert!((norm(&out) - norm(&position)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn deflection_degenerate_inputs_unchanged() {
+        let p = [1.0, 2.0, 3.0];
+        assert_eq!(apply_solar_deflection(&p, &[0.0; 3], &[1.0, 0.0, 0.0]), p);
+        assert_eq!(apply_solar_deflection(&p, &[1.0, 0.0, 0.0], &[0.0; 3]), p);
+        assert_eq!(apply_aberration(&[0.0; 3], &[30.0, 0.0, 0.0]), [0.0; 3]);
+    }
+}
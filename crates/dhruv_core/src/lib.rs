@@ -12,16 +12,50 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use dhruv_time::{self, LeapSecondKernel};
+use dhruv_frames::{DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL};
+use dhruv_time::{self, EopKernel, LeapSecondKernel};
 use jpl_kernel::{KernelError, SpkEvaluation, SpkKernel};
+use record_cache::RecordCache;
+
+mod corrections;
+mod naif_names;
+mod radii;
+mod record_cache;
+pub mod topocentric;
+
+pub use corrections::{C_KM_S, SUN_2GM_OVER_C2_KM};
+pub use dhruv_frames::{FrameOfDate, NutationModel, PoleParameters, PrecessionModel};
+pub use dhruv_time::Epoch;
+pub use jpl_kernel::{PckKernel, TextKernel};
+pub use radii::BodyRadii;
+pub use topocentric::{
+    GeoLocation, HorizontalCoords, TopocentricEpochQuery, TopocentricOptions, TopocentricQuery,
+    TopocentricState,
+};
 
 /// Engine configuration used at startup time.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EngineConfig {
     pub spk_paths: Vec<PathBuf>,
     pub lsk_path: PathBuf,
+    /// Maximum number of decoded Chebyshev records kept in the
+    /// cross-query cache.
     pub cache_capacity: usize,
     pub strict_validation: bool,
+    /// How SPK files are read; applies to `replace_spk_paths` as well.
+    pub spk_load_mode: SpkLoadMode,
+}
+
+/// How SPK kernel files are brought into memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpkLoadMode {
+    /// Read each file fully into memory when it is loaded.
+    #[default]
+    InMemory,
+    /// Read only the DAF headers at load time and fetch segment data from
+    /// the open file as queries need it. Suited to very large kernels
+    /// (DE441) where loading the whole file per process is too costly.
+    OnDemand,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -29,6 +63,7 @@ struct SpkIdentity {
     path: PathBuf,
     len: u64,
     modified_unix_nanos: Option<i128>,
+    load_mode: SpkLoadMode,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +72,7 @@ struct LoadedSpk {
     path: PathBuf,
     kernel: Arc<SpkKernel>,
     segment_count: usize,
+    internal_name: String,
 }
 
 #[derive(Debug)]
@@ -50,6 +86,8 @@ struct SpkSet {
 pub struct LoadedSpkInfo {
     pub path: PathBuf,
     pub segment_count: usize,
+    /// DAF internal file name (`IFNAME`), trimmed.
+    pub internal_name: String,
     pub generation: u64,
 }
 
@@ -75,6 +113,7 @@ impl EngineConfig {
             lsk_path,
             cache_capacity,
             strict_validation,
+            spk_load_mode: SpkLoadMode::InMemory,
         }
     }
 
@@ -101,12 +140,17 @@ impl EngineConfig {
     }
 }
 
-/// Primary bodies supported by the core query contract.
+/// Bodies supported by the core query contract.
 ///
 /// These are physical bodies that exist as SPK segments in the kernel file.
 /// Computed points (e.g. lunar nodes) are NOT included here — they belong
 /// in downstream crates like `dhruv_vedic_base` via the `DerivedComputation` trait.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// The named variants cover the primary bodies; [`Body::Naif`] reaches any
+/// other NAIF ID present in the loaded kernels (barycenters, satellites,
+/// asteroids, spacecraft). Equality and hashing compare NAIF codes, so
+/// `Body::Naif(399) == Body::Earth`.
+#[derive(Debug, Clone, Copy)]
 pub enum Body {
     Sun,
     Mercury,
@@ -119,9 +163,36 @@ pub enum Body {
     Uranus,
     Neptune,
     Pluto,
+    /// Any other NAIF ID. Prefer [`Body::naif`], which maps the codes of
+    /// the named variants to those variants.
+    Naif(i32),
+}
+
+impl PartialEq for Body {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Body {}
+
+impl std::hash::Hash for Body {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
 }
 
 impl Body {
+    pub const MERCURY_BARYCENTER: Self = Self::Naif(1);
+    pub const VENUS_BARYCENTER: Self = Self::Naif(2);
+    pub const EARTH_MOON_BARYCENTER: Self = Self::Naif(3);
+    pub const MARS_BARYCENTER: Self = Self::Naif(4);
+    pub const JUPITER_BARYCENTER: Self = Self::Naif(5);
+    pub const SATURN_BARYCENTER: Self = Self::Naif(6);
+    pub const URANUS_BARYCENTER: Self = Self::Naif(7);
+    pub const NEPTUNE_BARYCENTER: Self = Self::Naif(8);
+    pub const PLUTO_BARYCENTER: Self = Self::Naif(9);
+
     /// NAIF-style body code.
     pub const fn code(self) -> i32 {
         match self {
@@ -136,10 +207,14 @@ impl Body {
             Self::Uranus => 799,
             Self::Neptune => 899,
             Self::Pluto => 999,
+            Self::Naif(code) => code,
         }
     }
 
-    /// Convert a NAIF-style body code into a [`Body`].
+    /// Convert the code of a named body into a [`Body`].
+    ///
+    /// Returns `None` for codes without a named variant; use [`Body::naif`]
+    /// to accept any NAIF ID.
     pub const fn from_code(code: i32) -> Option<Self> {
         match code {
             10 => Some(Self::Sun),
@@ -156,12 +231,45 @@ impl Body {
             _ => None,
         }
     }
+
+    /// Body for any NAIF ID, using the named variant where one exists.
+    pub const fn naif(code: i32) -> Self {
+        match Self::from_code(code) {
+            Some(body) => body,
+            None => Self::Naif(code),
+        }
+    }
+
+    /// Resolve a body name (e.g. `"Ceres"`, `"Jupiter barycenter"`, `"EMB"`)
+    /// or a decimal NAIF ID.
+    ///
+    /// Names compare case-insensitively; blanks and underscores are
+    /// interchangeable.
+    pub fn from_name(name: &str) -> Option<Self> {
+        naif_names::code_for_name(name)
+            .or_else(|| name.trim().parse().ok())
+            .map(Self::naif)
+    }
+
+    /// Like [`Body::from_name`], but names defined in a text kernel
+    /// (`NAIF_BODY_NAME` / `NAIF_BODY_CODE`) take precedence.
+    pub fn from_name_in(name: &str, kernel: &TextKernel) -> Option<Self> {
+        kernel
+            .body_code(name)
+            .map(Self::naif)
+            .or_else(|| Self::from_name(name))
+    }
+
+    /// Canonical NAIF name of the body, if it is in the built-in table.
+    pub fn name(self) -> Option<&'static str> {
+        naif_names::name_for_code(self.code())
+    }
 }
 
 /// Observer used to evaluate relative state vectors.
 ///
-/// Topocentric observers (geographic lat/lon/alt) are a higher-level concept
-/// built on top of `Body(Earth)` queries — they do not belong in this enum.
+/// Topocentric observers (geographic lat/lon/alt) are built on top of
+/// `Body(Earth)` queries; see [`Engine::query_topocentric`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Observer {
     SolarSystemBarycenter,
@@ -177,7 +285,18 @@ impl Observer {
         }
     }
 
+    /// Observer for any NAIF ID; `0` is the solar system barycenter.
+    pub const fn naif(code: i32) -> Self {
+        if code == 0 {
+            Self::SolarSystemBarycenter
+        } else {
+            Self::Body(Body::naif(code))
+        }
+    }
+
     /// Convert a compact observer code into an [`Observer`].
+    ///
+    /// Accepts `0` and the codes of named bodies; see [`Observer::naif`].
     pub const fn from_code(code: i32) -> Option<Self> {
         if code == 0 {
             return Some(Self::SolarSystemBarycenter);
@@ -190,29 +309,146 @@ impl Observer {
 }
 
 /// Output reference frame.
+///
+/// The `*OfDate` variants are parameterised by the precession model used to
+/// carry the J2000 ecliptic to the epoch of the query; true frames also
+/// carry the nutation model.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Frame {
     IcrfJ2000,
     EclipticJ2000,
+    /// Mean equator and mean equinox of date.
+    MeanEquatorOfDate(PrecessionModel),
+    /// True equator and true equinox of date.
+    TrueEquatorOfDate(PrecessionModel, NutationModel),
+    /// Ecliptic of date with the mean equinox.
+    MeanEclipticOfDate(PrecessionModel),
+    /// Ecliptic of date with the true equinox.
+    TrueEclipticOfDate(PrecessionModel, NutationModel),
 }
 
 impl Frame {
     /// Compact frame code for FFI interoperability.
+    ///
+    /// The precession and nutation models of the `*OfDate` variants are not
+    /// encoded.
     pub const fn code(self) -> i32 {
         match self {
             Self::IcrfJ2000 => 0,
             Self::EclipticJ2000 => 1,
+            Self::MeanEquatorOfDate(_) => 2,
+            Self::TrueEquatorOfDate(..) => 3,
+            Self::MeanEclipticOfDate(_) => 4,
+            Self::TrueEclipticOfDate(..) => 5,
         }
     }
 
     /// Convert a compact frame code into a [`Frame`].
+    ///
+    /// Frames of date use [`DEFAULT_PRECESSION_MODEL`] and
+    /// [`DEFAULT_NUTATION_MODEL`]; see [`Frame::from_code_with_models`].
     pub const fn from_code(code: i32) -> Option<Self> {
+        Self::from_code_with_model(code, DEFAULT_PRECESSION_MODEL)
+    }
+
+    /// Convert a compact frame code into a [`Frame`], using `model` for
+    /// frames of date and [`DEFAULT_NUTATION_MODEL`] for true frames.
+    pub const fn from_code_with_model(code: i32, model: PrecessionModel) -> Option<Self> {
+        Self::from_code_with_models(code, model, DEFAULT_NUTATION_MODEL)
+    }
+
+    /// Convert a compact frame code into a [`Frame`], using `model` for
+    /// frames of date and `nutation` for true frames.
+    pub const fn from_code_with_models(
+        code: i32,
+        model: PrecessionModel,
+        nutation: NutationModel,
+    ) -> Option<Self> {
         match code {
             0 => Some(Self::IcrfJ2000),
             1 => Some(Self::EclipticJ2000),
+            2 => Some(Self::MeanEquatorOfDate(model)),
+            3 => Some(Self::TrueEquatorOfDate(model, nutation)),
+            4 => Some(Self::MeanEclipticOfDate(model)),
+            5 => Some(Self::TrueEclipticOfDate(model, nutation)),
             _ => None,
         }
     }
+
+    /// Frame of date with its precession and nutation models, or `None` for
+    /// J2000 frames. Mean frames report [`DEFAULT_NUTATION_MODEL`], which
+    /// they do not use.
+    pub const fn of_date(self) -> Option<(FrameOfDate, PrecessionModel, NutationModel)> {
+        let mean = DEFAULT_NUTATION_MODEL;
+        match self {
+            Self::IcrfJ2000 | Self::EclipticJ2000 => None,
+            Self::MeanEquatorOfDate(model) => Some((FrameOfDate::MeanEquator, model, mean)),
+            Self::TrueEquatorOfDate(model, nutation) => {
+                Some((FrameOfDate::TrueEquator, model, nutation))
+            }
+            Self::MeanEclipticOfDate(model) => Some((FrameOfDate::MeanEcliptic, model, mean)),
+            Self::TrueEclipticOfDate(model, nutation) => {
+                Some((FrameOfDate::TrueEcliptic, model, nutation))
+            }
+        }
+    }
+}
+
+/// Observer-relative corrections applied to a query result.
+///
+/// Each level includes the ones before it. Corrections are applied in the
+/// ICRF before any frame rotation. Aberration and deflection change the
+/// direction of the position vector only; the reported velocity is the
+/// light-time corrected velocity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Correction {
+    /// Instantaneous geometric state (no corrections).
+    #[default]
+    Geometric,
+    /// Target evaluated at the light-time retarded epoch.
+    LightTime,
+    /// Light time plus stellar aberration from the observer's SSB velocity.
+    LightTimeAberration,
+    /// Light time, solar gravitational deflection and stellar aberration.
+    Apparent,
+}
+
+impl Correction {
+    /// Compact correction code for FFI interoperability.
+    pub const fn code(self) -> i32 {
+        match self {
+            Self::Geometric => 0,
+            Self::LightTime => 1,
+            Self::LightTimeAberration => 2,
+            Self::Apparent => 3,
+        }
+    }
+
+    /// Convert a compact correction code into a [`Correction`].
+    pub const fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Geometric),
+            1 => Some(Self::LightTime),
+            2 => Some(Self::LightTimeAberration),
+            3 => Some(Self::Apparent),
+            _ => None,
+        }
+    }
+
+    /// Whether the target is evaluated at the retarded epoch.
+    pub const fn light_time(self) -> bool {
+        !matches!(self, Self::Geometric)
+    }
+
+    /// Whether stellar aberration is applied.
+    pub const fn aberration(self) -> bool {
+        matches!(self, Self::LightTimeAberration | Self::Apparent)
+    }
+
+    /// Whether solar gravitational deflection is applied.
+    pub const fn deflection(self) -> bool {
+        matches!(self, Self::Apparent)
+    }
 }
 
 /// Single ephemeris request.
@@ -222,6 +458,46 @@ pub struct Query {
     pub observer: Observer,
     pub frame: Frame,
     pub epoch_tdb_jd: f64,
+    pub correction: Correction,
+}
+
+/// Ephemeris request at an [`Epoch`] rather than a single-`f64` Julian
+/// Date, which only resolves ~40 µs.
+///
+/// [`Engine::query`] accepts either form; a [`Query`] converts losslessly
+/// from its JD.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpochQuery {
+    pub target: Body,
+    pub observer: Observer,
+    pub frame: Frame,
+    pub epoch: Epoch,
+    pub correction: Correction,
+}
+
+impl From<Query> for EpochQuery {
+    fn from(query: Query) -> Self {
+        Self {
+            target: query.target,
+            observer: query.observer,
+            frame: query.frame,
+            epoch: Epoch::from_jd_tdb(query.epoch_tdb_jd),
+            correction: query.correction,
+        }
+    }
+}
+
+impl EpochQuery {
+    /// The same request with the epoch rounded to a single-`f64` JD.
+    pub fn to_query(&self) -> Query {
+        Query {
+            target: self.target,
+            observer: self.observer,
+            frame: self.frame,
+            epoch_tdb_jd: self.epoch.as_jd_tdb(),
+            correction: self.correction,
+        }
+    }
 }
 
 /// Cartesian state vector output.
@@ -240,7 +516,15 @@ pub enum EngineError {
     KernelLoad(String),
     TimeConversion(String),
     UnsupportedQuery(&'static str),
-    EpochOutOfRange { epoch_tdb_jd: f64 },
+    EpochOutOfRange {
+        epoch_tdb_jd: f64,
+    },
+    /// No chain of loaded segments connects `body` to the solar system
+    /// barycenter; `missing` is the first code without a segment.
+    UnresolvedBody {
+        body: i32,
+        missing: i32,
+    },
     Internal(String),
 }
 
@@ -255,6 +539,11 @@ impl Display for EngineError {
             Self::EpochOutOfRange { epoch_tdb_jd } => {
                 write!(f, "epoch out of range: {epoch_tdb_jd}")
             }
+            Self::UnresolvedBody { body, missing } => write!(
+                f,
+                "cannot resolve body {body} to the solar system barycenter: \
+                 no loaded segment has target {missing}"
+            ),
             Self::Internal(msg) => write!(f, "internal error: {msg}"),
         }
     }
@@ -262,6 +551,25 @@ impl Display for EngineError {
 
 impl Error for EngineError {}
 
+/// Rotate an ICRF/J2000 state into `frame` at `epoch_tdb_jd`.
+fn rotate_to_frame(mut state: StateVector, frame: Frame, epoch_tdb_jd: f64) -> StateVector {
+    if frame == Frame::EclipticJ2000 {
+        state.position_km = dhruv_frames::icrf_to_ecliptic(&state.position_km);
+        state.velocity_km_s = dhruv_frames::icrf_to_ecliptic(&state.velocity_km_s);
+    } else if let Some((frame, model, nutation)) = frame.of_date() {
+        let t = (epoch_tdb_jd - 2_451_545.0) / 36525.0;
+        (state.position_km, state.velocity_km_s) = dhruv_frames::icrf_state_to_frame_of_date(
+            &state.position_km,
+            &state.velocity_km_s,
+            t,
+            frame,
+            model,
+            nutation,
+        );
+    }
+    state
+}
+
 /// Output shape for extension-trait computations.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DerivedValue {
@@ -284,10 +592,16 @@ pub trait DerivedComputation: Send + Sync {
 }
 
 /// Telemetry from a query or batch of queries.
+///
+/// `evaluations` and `cache_hits` count per-request memoization of segment
+/// evaluations; the `record_cache_*` counters report the engine-wide cache
+/// of decoded Chebyshev records.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct QueryStats {
     pub evaluations: u32,
     pub cache_hits: u32,
+    pub record_cache_hits: u32,
+    pub record_cache_misses: u32,
 }
 
 /// Per-request memoization context.
@@ -301,6 +615,8 @@ struct ComputationContext {
     cache: HashMap<(i32, i32, u64), SpkEvaluation>,
     evaluations: u32,
     cache_hits: u32,
+    record_cache_hits: u32,
+    record_cache_misses: u32,
 }
 
 impl ComputationContext {
@@ -309,6 +625,8 @@ impl ComputationContext {
             cache: HashMap::with_capacity(8),
             evaluations: 0,
             cache_hits: 0,
+            record_cache_hits: 0,
+            record_cache_misses: 0,
         }
     }
 
@@ -316,6 +634,8 @@ impl ComputationContext {
         QueryStats {
             evaluations: self.evaluations,
             cache_hits: self.cache_hits,
+            record_cache_hits: self.record_cache_hits,
+            record_cache_misses: self.record_cache_misses,
         }
     }
 }
@@ -327,7 +647,7 @@ fn system_time_to_unix_nanos(time: SystemTime) -> Option<i128> {
     }
 }
 
-fn spk_identity(path: &Path) -> Result<SpkIdentity, EngineError> {
+fn spk_identity(path: &Path, load_mode: SpkLoadMode) -> Result<SpkIdentity, EngineError> {
     let canonical = fs::canonicalize(path).map_err(|e| {
         EngineError::KernelLoad(format!("failed to canonicalize {}: {e}", path.display()))
     })?;
@@ -342,16 +662,30 @@ fn spk_identity(path: &Path) -> Result<SpkIdentity, EngineError> {
         path: canonical,
         len: metadata.len(),
         modified_unix_nanos,
+        load_mode,
     })
 }
 
+/// Map a segment-chain failure for `body` to an engine error.
+fn chain_error(body: i32, error: KernelError) -> EngineError {
+    match error {
+        KernelError::SegmentNotFound { target, .. } => EngineError::UnresolvedBody {
+            body,
+            missing: target,
+        },
+        other => EngineError::Internal(other.to_string()),
+    }
+}
+
 fn loaded_spk_from_kernel(identity: SpkIdentity, kernel: Arc<SpkKernel>) -> LoadedSpk {
     let segment_count = kernel.segments().len();
+    let internal_name = kernel.internal_name().to_string();
     LoadedSpk {
         path: identity.path.clone(),
         identity,
         kernel,
         segment_count,
+        internal_name,
     }
 }
 
@@ -361,25 +695,25 @@ fn spk_infos_from_set(set: &SpkSet) -> Vec<LoadedSpkInfo> {
         .map(|entry| LoadedSpkInfo {
             path: entry.path.clone(),
             segment_count: entry.segment_count,
+            internal_name: entry.internal_name.clone(),
             generation: set.generation,
         })
         .collect()
 }
 
+/// Weak handles to previously loaded kernels, keyed by file identity.
+type KernelCache = HashMap<SpkIdentity, Weak<SpkKernel>>;
+
+/// Loaded set, refreshed kernel cache, loaded count, reused count.
+type LoadedSpkSet = (Arc<SpkSet>, KernelCache, usize, usize);
+
 fn load_spk_set(
     paths: &[PathBuf],
+    load_mode: SpkLoadMode,
     generation: u64,
     current: Option<&Arc<SpkSet>>,
-    cache: Option<&HashMap<SpkIdentity, Weak<SpkKernel>>>,
-) -> Result<
-    (
-        Arc<SpkSet>,
-        HashMap<SpkIdentity, Weak<SpkKernel>>,
-        usize,
-        usize,
-    ),
-    EngineError,
-> {
+    cache: Option<&KernelCache>,
+) -> Result<LoadedSpkSet, EngineError> {
     let mut entries = Vec::with_capacity(paths.len());
     let mut loaded_count = 0usize;
     let mut reused_count = 0usize;
@@ -387,7 +721,7 @@ fn load_spk_set(
     let mut local_kernels: HashMap<SpkIdentity, Arc<SpkKernel>> = HashMap::new();
 
     for path in paths {
-        let identity = spk_identity(path)?;
+        let identity = spk_identity(path, load_mode)?;
         let mut kernel = current
             .and_then(|set| {
                 set.entries
@@ -401,10 +735,14 @@ fn load_spk_set(
         if kernel.is_some() {
             reused_count += 1;
         } else {
-            let loaded = Arc::new(SpkKernel::load(&identity.path).map_err(|e| {
+            let result = match load_mode {
+                SpkLoadMode::InMemory => SpkKernel::load(&identity.path),
+                SpkLoadMode::OnDemand => SpkKernel::open(&identity.path),
+            };
+            let loaded = Arc::new(result.map_err(|e| {
                 EngineError::KernelLoad(format!("{}: {e}", identity.path.display()))
             })?);
-            let after = spk_identity(&identity.path)?;
+            let after = spk_identity(&identity.path, load_mode)?;
             if after != identity {
                 return Err(EngineError::KernelLoad(format!(
                     "SPK metadata changed while loading {}",
@@ -450,7 +788,10 @@ pub struct Engine {
     config: RwLock<EngineConfig>,
     spk_set: RwLock<Arc<SpkSet>>,
     replace_lock: Mutex<()>,
-    kernel_cache: Mutex<HashMap<SpkIdentity, Weak<SpkKernel>>>,
+    kernel_cache: Mutex<KernelCache>,
+    /// Decoded Chebyshev records shared across queries, bounded by
+    /// `EngineConfig::cache_capacity`.
+    record_cache: Mutex<RecordCache>,
     lsk: LeapSecondKernel,
 }
 
@@ -479,14 +820,17 @@ impl Engine {
     /// Create a new engine, loading SPK and LSK kernels from the config paths.
     pub fn new(config: EngineConfig) -> Result<Self, EngineError> {
         config.validate()?;
-        let (spk_set, cache, _, _) = load_spk_set(&config.spk_paths, 0, None, None)?;
+        let (spk_set, cache, _, _) =
+            load_spk_set(&config.spk_paths, config.spk_load_mode, 0, None, None)?;
         let lsk = LeapSecondKernel::load(&config.lsk_path)
             .map_err(|e| EngineError::KernelLoad(e.to_string()))?;
+        let record_cache = RecordCache::new(config.cache_capacity, spk_set.generation);
         Ok(Self {
             config: RwLock::new(config),
             spk_set: RwLock::new(spk_set),
             replace_lock: Mutex::new(()),
             kernel_cache: Mutex::new(cache),
+            record_cache: Mutex::new(record_cache),
             lsk,
         })
     }
@@ -534,12 +878,18 @@ impl Engine {
             .expect("engine SPK replacement lock poisoned");
         let current = self.spk_snapshot();
         let next_generation = current.generation.saturating_add(1);
+        let load_mode = self
+            .config
+            .read()
+            .expect("engine config lock poisoned")
+            .spk_load_mode;
         let cache_guard = self
             .kernel_cache
             .lock()
             .expect("engine SPK cache lock poisoned");
         let (new_set, mut new_cache_entries, loaded_count, reused_count) = load_spk_set(
             &spk_paths,
+            load_mode,
             next_generation,
             Some(&current),
             Some(&cache_guard),
@@ -550,10 +900,16 @@ impl Engine {
             let mut set_guard = self.spk_set.write().expect("engine SPK lock poisoned");
             *set_guard = Arc::clone(&new_set);
         }
-        {
+        let cache_capacity = {
             let mut config_guard = self.config.write().expect("engine config lock poisoned");
             config_guard.spk_paths = spk_paths;
-        }
+            config_guard.cache_capacity
+        };
+        *self
+            .record_cache
+            .lock()
+            .expect("engine record cache lock poisoned") =
+            RecordCache::new(cache_capacity, new_set.generation);
         {
             let mut cache_guard = self
                 .kernel_cache
@@ -576,6 +932,15 @@ impl Engine {
         &self.lsk
     }
 
+    /// Number of decoded Chebyshev records currently held in the
+    /// cross-query cache.
+    pub fn record_cache_len(&self) -> usize {
+        self.record_cache
+            .lock()
+            .expect("engine record cache lock poisoned")
+            .len()
+    }
+
     fn spk_snapshot(&self) -> Arc<SpkSet> {
         Arc::clone(&self.spk_set.read().expect("engine SPK lock poisoned"))
     }
@@ -596,16 +961,17 @@ impl Engine {
             return Ok(*cached);
         }
 
-        for entry in &spk_set.entries {
-            match entry.kernel.evaluate(target, center, epoch_tdb_s) {
-                Ok(eval) => {
-                    ctx.evaluations += 1;
-                    ctx.cache.insert(key, eval);
-                    return Ok(eval);
-                }
+        for (slot, entry) in spk_set.entries.iter().enumerate() {
+            let segment_index = match entry.kernel.find_segment_index(target, center, epoch_tdb_s) {
+                Ok(index) => index,
                 Err(KernelError::EpochOutOfRange { .. }) => continue,
                 Err(e) => return Err(e),
-            }
+            };
+            let eval =
+                self.evaluate_segment_cached(spk_set, slot, segment_index, epoch_tdb_s, ctx)?;
+            ctx.evaluations += 1;
+            ctx.cache.insert(key, eval);
+            return Ok(eval);
         }
         Err(KernelError::EpochOutOfRange {
             target,
@@ -614,6 +980,47 @@ impl Engine {
         })
     }
 
+    /// Evaluate one segment, serving Chebyshev records from the cross-query
+    /// record cache. Other segment types are evaluated directly.
+    fn evaluate_segment_cached(
+        &self,
+        spk_set: &SpkSet,
+        slot: usize,
+        segment_index: usize,
+        epoch_tdb_s: f64,
+        ctx: &mut ComputationContext,
+    ) -> Result<SpkEvaluation, KernelError> {
+        let kernel = &spk_set.entries[slot].kernel;
+        let Some(record_index) = kernel.chebyshev_record_index(segment_index, epoch_tdb_s)? else {
+            return kernel.evaluate_segment(segment_index, epoch_tdb_s);
+        };
+
+        let key = (slot, segment_index, record_index);
+        let cached = self
+            .record_cache
+            .lock()
+            .expect("engine record cache lock poisoned")
+            .get(spk_set.generation, key);
+        let record = match cached {
+            Some(record) => {
+                ctx.record_cache_hits += 1;
+                record
+            }
+            None => {
+                ctx.record_cache_misses += 1;
+                // Decode outside the lock; a concurrent decode of the same
+                // record is harmless.
+                let record = Arc::new(kernel.read_chebyshev_record(segment_index, record_index)?);
+                self.record_cache
+                    .lock()
+                    .expect("engine record cache lock poisoned")
+                    .insert(spk_set.generation, key, Arc::clone(&record));
+                record
+            }
+        };
+        Ok(record.evaluate(epoch_tdb_s))
+    }
+
     /// Find the center body for a target across all kernels.
     fn center_for_across(&self, spk_set: &SpkSet, target: i32) -> Option<i32> {
         for entry in &spk_set.entries {
@@ -667,17 +1074,23 @@ impl Engine {
     }
 
     /// Evaluate an ephemeris query, returning a Cartesian state vector.
-    pub fn query(&self, query: Query) -> Result<StateVector, EngineError> {
+    ///
+    /// Takes a [`Query`] (JD TDB) or an [`EpochQuery`] (full [`Epoch`]
+    /// precision).
+    pub fn query(&self, query: impl Into<EpochQuery>) -> Result<StateVector, EngineError> {
         let mut ctx = ComputationContext::new();
         let spk_set = self.spk_snapshot();
-        self.query_with_ctx(&spk_set, query, &mut ctx)
+        self.query_with_ctx(&spk_set, query.into(), &mut ctx)
     }
 
     /// Evaluate a query and return telemetry alongside the result.
-    pub fn query_with_stats(&self, query: Query) -> Result<(StateVector, QueryStats), EngineError> {
+    pub fn query_with_stats(
+        &self,
+        query: impl Into<EpochQuery>,
+    ) -> Result<(StateVector, QueryStats), EngineError> {
         let mut ctx = ComputationContext::new();
         let spk_set = self.spk_snapshot();
-        let state = self.query_with_ctx(&spk_set, query, &mut ctx)?;
+        let state = self.query_with_ctx(&spk_set, query.into(), &mut ctx)?;
         Ok((state, ctx.stats()))
     }
 
@@ -685,10 +1098,12 @@ impl Engine {
     fn query_with_ctx(
         &self,
         spk_set: &SpkSet,
-        query: Query,
+        epoch_query: EpochQuery,
         ctx: &mut ComputationContext,
     ) -> Result<StateVector, EngineError> {
-        if !query.epoch_tdb_jd.is_finite() {
+        let query = epoch_query.to_query();
+        let epoch_tdb_s = epoch_query.epoch.as_tdb_seconds();
+        if !epoch_tdb_s.is_finite() {
             return Err(EngineError::InvalidQuery("epoch_tdb_jd must be finite"));
         }
         if let Observer::Body(body) = query.observer
@@ -699,43 +1114,193 @@ impl Engine {
             ));
         }
 
-        // Convert JD TDB to TDB seconds past J2000.
-        let epoch_tdb_s = dhruv_time::jd_to_tdb_seconds(query.epoch_tdb_jd);
-
-        // Resolve target to SSB across all loaded kernels.
-        let target_ssb = self
-            .resolve_to_ssb_across(spk_set, query.target.code(), epoch_tdb_s, ctx)
-            .map_err(|e| EngineError::Internal(e.to_string()))?;
-
         // Resolve observer to SSB across all loaded kernels.
         let observer_ssb = match query.observer {
             Observer::SolarSystemBarycenter => [0.0f64; 6],
             Observer::Body(body) => self
                 .resolve_to_ssb_across(spk_set, body.code(), epoch_tdb_s, ctx)
-                .map_err(|e| EngineError::Internal(e.to_string()))?,
+                .map_err(|e| chain_error(body.code(), e))?,
         };
 
-        // Subtract observer from target.
-        let mut state = StateVector {
-            position_km: [
-                target_ssb[0] - observer_ssb[0],
-                target_ssb[1] - observer_ssb[1],
-                target_ssb[2] - observer_ssb[2],
-            ],
-            velocity_km_s: [
-                target_ssb[3] - observer_ssb[3],
-                target_ssb[4] - observer_ssb[4],
-                target_ssb[5] - observer_ssb[5],
-            ],
+        let state = self.state_from_observer(spk_set, query, epoch_tdb_s, &observer_ssb, ctx)?;
+        Ok(rotate_to_frame(state, query.frame, query.epoch_tdb_jd))
+    }
+
+    /// ICRF target state relative to an observer already resolved to the
+    /// SSB.
+    fn state_from_observer(
+        &self,
+        spk_set: &SpkSet,
+        query: Query,
+        epoch_tdb_s: f64,
+        observer_ssb: &[f64; 6],
+        ctx: &mut ComputationContext,
+    ) -> Result<StateVector, EngineError> {
+        let state = if query.correction.light_time() {
+            self.corrected_state(spk_set, query, epoch_tdb_s, observer_ssb, ctx)
+                .map_err(|e| chain_error(query.target.code(), e))?
+        } else {
+            // Resolve target to SSB across all loaded kernels.
+            let target_ssb = self
+                .resolve_to_ssb_across(spk_set, query.target.code(), epoch_tdb_s, ctx)
+                .map_err(|e| chain_error(query.target.code(), e))?;
+
+            // Subtract observer from target.
+            StateVector {
+                position_km: [
+                    target_ssb[0] - observer_ssb[0],
+                    target_ssb[1] - observer_ssb[1],
+                    target_ssb[2] - observer_ssb[2],
+                ],
+                velocity_km_s: [
+                    target_ssb[3] - observer_ssb[3],
+                    target_ssb[4] - observer_ssb[4],
+                    target_ssb[5] - observer_ssb[5],
+                ],
+            }
         };
 
-        // Frame rotation via dhruv_frames.
-        if query.frame == Frame::EclipticJ2000 {
-            state.position_km = dhruv_frames::icrf_to_ecliptic(&state.position_km);
-            state.velocity_km_s = dhruv_frames::icrf_to_ecliptic(&state.velocity_km_s);
+        Ok(state)
+    }
+
+    /// Evaluate a query for an observer on the Earth's surface.
+    ///
+    /// The site is placed on the WGS84 ellipsoid and carried into GCRS with
+    /// the CIO-based Earth rotation of
+    /// [`topocentric::gcrs_to_itrs_matrix`]. UT1 comes from the loaded LSK
+    /// (TDB→UTC) plus DUT1 from `eop`; polar motion and the celestial pole
+    /// offsets dX/dY also come from `eop`. Without EOP data UT1 = UTC and
+    /// the pole is not displaced, which costs up to ~15″ in azimuth.
+    ///
+    /// Light time and aberration use the site's own SSB state, so diurnal
+    /// aberration and the topocentric light-time are included. A
+    /// [`TopocentricEpochQuery`] evaluates the ephemeris at its full
+    /// two-part epoch; the Earth rotation needs only the rounded JD.
+    pub fn query_topocentric(
+        &self,
+        query: impl Into<TopocentricEpochQuery>,
+        eop: Option<&EopKernel>,
+        options: &TopocentricOptions,
+    ) -> Result<TopocentricState, EngineError> {
+        let query = query.into();
+        let epoch_tdb_s = query.epoch.as_tdb_seconds();
+        if !epoch_tdb_s.is_finite() {
+            return Err(EngineError::InvalidQuery("epoch_tdb_jd must be finite"));
+        }
+        let epoch_tdb_jd = query.epoch.as_jd_tdb();
+        if query.target == Body::Earth {
+            return Err(EngineError::UnsupportedQuery(
+                "topocentric target cannot be the Earth",
+            ));
         }
 
-        Ok(state)
+        let jd_utc = dhruv_time::tdb_seconds_to_jd(self.lsk().tdb_to_utc(epoch_tdb_s));
+        let (jd_ut1, pole) = match eop {
+            Some(eop) => (
+                eop.utc_to_ut1_jd(jd_utc)
+                    .map_err(|e| EngineError::TimeConversion(e.to_string()))?,
+                topocentric::eop_pole_parameters(eop, jd_utc),
+            ),
+            None => (jd_utc, PoleParameters::default()),
+        };
+        let gcrs_to_itrs = topocentric::gcrs_to_itrs_matrix(epoch_tdb_jd, jd_ut1, &pole);
+        let site = topocentric::site_gcrs_state(&query.location, &gcrs_to_itrs);
+
+        let mut ctx = ComputationContext::new();
+        let spk_set = self.spk_snapshot();
+        let earth = Body::Earth.code();
+        let mut observer_ssb = self
+            .resolve_to_ssb_across(&spk_set, earth, epoch_tdb_s, &mut ctx)
+            .map_err(|e| chain_error(earth, e))?;
+        for (o, s) in observer_ssb.iter_mut().zip(site) {
+            *o += s;
+        }
+
+        let base = Query {
+            target: query.target,
+            observer: Observer::Body(Body::Earth),
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd,
+            correction: query.correction,
+        };
+        let icrf =
+            self.state_from_observer(&spk_set, base, epoch_tdb_s, &observer_ssb, &mut ctx)?;
+        let horizontal = topocentric::horizontal_from_gcrs(
+            &query.location,
+            &gcrs_to_itrs,
+            &icrf.position_km,
+            options,
+        );
+
+        Ok(TopocentricState {
+            state: rotate_to_frame(icrf, query.frame, epoch_tdb_jd),
+            horizontal,
+            jd_ut1,
+        })
+    }
+
+    /// Light-time corrected state with optional deflection and aberration.
+    ///
+    /// Iterates the retarded epoch `t - τ` until τ changes by less than a
+    /// nanosecond, then applies solar deflection (unless the Sun is the
+    /// target or observer) and aberration from the observer's SSB velocity.
+    fn corrected_state(
+        &self,
+        spk_set: &SpkSet,
+        query: Query,
+        epoch_tdb_s: f64,
+        observer_ssb: &[f64; 6],
+        ctx: &mut ComputationContext,
+    ) -> Result<StateVector, KernelError> {
+        let target = query.target.code();
+        let mut light_time = 0.0;
+        let mut target_ssb = self.resolve_to_ssb_across(spk_set, target, epoch_tdb_s, ctx)?;
+        for _ in 0..corrections::LIGHT_TIME_MAX_ITERATIONS {
+            let next = corrections::light_time_s(&target_ssb, observer_ssb);
+            let converged = (next - light_time).abs() < corrections::LIGHT_TIME_TOLERANCE_S;
+            light_time = next;
+            target_ssb =
+                self.resolve_to_ssb_across(spk_set, target, epoch_tdb_s - light_time, ctx)?;
+            if converged {
+                break;
+            }
+        }
+
+        let (mut position_km, velocity_km_s) =
+            corrections::light_time_state(&target_ssb, observer_ssb);
+
+        let observer_is_sun = query.observer == Observer::Body(Body::Sun);
+        if query.correction.deflection() && query.target != Body::Sun && !observer_is_sun {
+            let sun = Body::Sun.code();
+            let sun_at_emission =
+                self.resolve_to_ssb_across(spk_set, sun, epoch_tdb_s - light_time, ctx)?;
+            let sun_at_reception = self.resolve_to_ssb_across(spk_set, sun, epoch_tdb_s, ctx)?;
+            let target_from_sun = [
+                target_ssb[0] - sun_at_emission[0],
+                target_ssb[1] - sun_at_emission[1],
+                target_ssb[2] - sun_at_emission[2],
+            ];
+            let observer_from_sun = [
+                observer_ssb[0] - sun_at_reception[0],
+                observer_ssb[1] - sun_at_reception[1],
+                observer_ssb[2] - sun_at_reception[2],
+            ];
+            position_km = corrections::apply_solar_deflection(
+                &position_km,
+                &target_from_sun,
+                &observer_from_sun,
+            );
+        }
+
+        if query.correction.aberration() {
+            let observer_velocity = [observer_ssb[3], observer_ssb[4], observer_ssb[5]];
+            position_km = corrections::apply_aberration(&position_km, &observer_velocity);
+        }
+
+        Ok(StateVector {
+            position_km,
+            velocity_km_s,
+        })
     }
 
     /// Evaluate multiple queries, sharing memoization across queries at the
@@ -783,12 +1348,14 @@ impl Engine {
 
             let mut ctx = ComputationContext::new();
             for &(_, idx) in &indexed[group_start..group_end] {
-                results[idx] = self.query_with_ctx(&spk_set, queries[idx], &mut ctx);
+                results[idx] = self.query_with_ctx(&spk_set, queries[idx].into(), &mut ctx);
             }
 
             let group_stats = ctx.stats();
             total_stats.evaluations += group_stats.evaluations;
             total_stats.cache_hits += group_stats.cache_hits;
+            total_stats.record_cache_hits += group_stats.record_cache_hits;
+            total_stats.record_cache_misses += group_stats.record_cache_misses;
 
             group_start = group_end;
         }
@@ -824,6 +1391,7 @@ mod tests {
             lsk_path: lsk,
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         };
         assert!(matches!(
             Engine::new(config),
@@ -839,6 +1407,7 @@ mod tests {
             lsk_path: lsk,
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         };
         assert!(matches!(
             Engine::new(config),
@@ -854,6 +1423,7 @@ mod tests {
             lsk_path: PathBuf::new(),
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         };
         assert!(matches!(
             Engine::new(config),
@@ -869,6 +1439,7 @@ mod tests {
             lsk_path: lsk,
             cache_capacity: 0,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         };
         assert!(matches!(
             Engine::new(config),
@@ -885,6 +1456,54 @@ mod tests {
         assert!(config.strict_validation);
     }
 
+    #[test]
+    fn frame_codes_round_trip() {
+        for model in [PrecessionModel::Iau2006, DEFAULT_PRECESSION_MODEL] {
+            for nutation in [NutationModel::Iau1980, NutationModel::Iau2000B] {
+                for frame in [
+                    Frame::IcrfJ2000,
+                    Frame::EclipticJ2000,
+                    Frame::MeanEquatorOfDate(model),
+                    Frame::TrueEquatorOfDate(model, nutation),
+                    Frame::MeanEclipticOfDate(model),
+                    Frame::TrueEclipticOfDate(model, nutation),
+                ] {
+                    assert_eq!(
+                        Frame::from_code_with_models(frame.code(), model, nutation),
+                        Some(frame)
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            Frame::from_code_with_model(3, PrecessionModel::Iau2006),
+            Some(Frame::TrueEquatorOfDate(
+                PrecessionModel::Iau2006,
+                DEFAULT_NUTATION_MODEL
+            ))
+        );
+        assert_eq!(
+            Frame::from_code(4),
+            Some(Frame::MeanEclipticOfDate(DEFAULT_PRECESSION_MODEL))
+        );
+        assert_eq!(Frame::from_code(6), None);
+        assert_eq!(Frame::EclipticJ2000.of_date(), None);
+    }
+
+    #[test]
+    fn correction_codes_round_trip() {
+        for correction in [
+            Correction::Geometric,
+            Correction::LightTime,
+            Correction::LightTimeAberration,
+            Correction::Apparent,
+        ] {
+            assert_eq!(Correction::from_code(correction.code()), Some(correction));
+        }
+        assert_eq!(Correction::from_code(4), None);
+        assert_eq!(Correction::default(), Correction::Geometric);
+    }
+
     // Compile-time assertion: Engine must be Send + Sync.
     #[allow(dead_code)]
     const _: () = {
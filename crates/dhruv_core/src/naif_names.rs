@@ -0,0 +1,121 @@
+//! Built-in NAIF body name/ID table.
+//!
+//! Covers the solar system barycenter, the planetary barycenters, the Sun,
+//! planets, major satellites and the asteroids and centaurs commonly used
+//! in astrology. Asteroid IDs follow the `2000000 + number` convention.
+//! Kernels can add or override names through `NAIF_BODY_NAME` /
+//! `NAIF_BODY_CODE` in a text kernel (see [`crate::Body::from_name_in`]).
+
+/// (NAIF ID, canonical name, aliases). Names are upper case with single
+/// spaces; lookups normalise input the same way.
+const BODIES: &[(i32, &str, &[&str])] = &[
+    (
+        0,
+        "SOLAR SYSTEM BARYCENTER",
+        &["SSB", "SOLAR SYSTEM BARYCENTRE"],
+    ),
+    (1, "MERCURY BARYCENTER", &[]),
+    (2, "VENUS BARYCENTER", &[]),
+    (
+        3,
+        "EARTH BARYCENTER",
+        &["EMB", "EARTH MOON BARYCENTER", "EARTH-MOON BARYCENTER"],
+    ),
+    (4, "MARS BARYCENTER", &[]),
+    (5, "JUPITER BARYCENTER", &[]),
+    (6, "SATURN BARYCENTER", &[]),
+    (7, "URANUS BARYCENTER", &[]),
+    (8, "NEPTUNE BARYCENTER", &[]),
+    (9, "PLUTO BARYCENTER", &[]),
+    (10, "SUN", &[]),
+    (199, "MERCURY", &[]),
+    (299, "VENUS", &[]),
+    (399, "EARTH", &[]),
+    (301, "MOON", &[]),
+    (499, "MARS", &[]),
+    (401, "PHOBOS", &[]),
+    (402, "DEIMOS", &[]),
+    (599, "JUPITER", &[]),
+    (501, "IO", &[]),
+    (502, "EUROPA", &[]),
+    (503, "GANYMEDE", &[]),
+    (504, "CALLISTO", &[]),
+    (699, "SATURN", &[]),
+    (601, "MIMAS", &[]),
+    (602, "ENCELADUS", &[]),
+    (603, "TETHYS", &[]),
+    (604, "DIONE", &[]),
+    (605, "RHEA", &[]),
+    (606, "TITAN", &[]),
+    (607, "HYPERION", &[]),
+    (608, "IAPETUS", &[]),
+    (799, "URANUS", &[]),
+    (701, "ARIEL", &[]),
+    (702, "UMBRIEL", &[]),
+    (703, "TITANIA", &[]),
+    (704, "OBERON", &[]),
+    (705, "MIRANDA", &[]),
+    (899, "NEPTUNE", &[]),
+    (801, "TRITON", &[]),
+    (999, "PLUTO", &[]),
+    (901, "CHARON", &[]),
+    (2_000_001, "CERES", &[]),
+    (2_000_002, "PALLAS", &[]),
+    (2_000_003, "JUNO", &[]),
+    (2_000_004, "VESTA", &[]),
+    (2_002_060, "CHIRON", &[]),
+    (2_005_145, "PHOLUS", &[]),
+];
+
+/// Upper-case `name` with blanks and underscores collapsed to one space.
+pub(crate) fn normalize(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase()
+}
+
+/// NAIF ID for a built-in name or alias.
+pub(crate) fn code_for_name(name: &str) -> Option<i32> {
+    let wanted = normalize(name);
+    BODIES
+        .iter()
+        .find(|(_, canonical, aliases)| *canonical == wanted || aliases.contains(&wanted.as_str()))
+        .map(|(code, _, _)| *code)
+}
+
+/// Canonical built-in name of a NAIF ID.
+pub(crate) fn name_for_code(code: i32) -> Option<&'static str> {
+    BODIES
+        .iter()
+        .find(|(id, _, _)| *id == code)
+        .map(|(_, name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_ids_are_unique() {
+        for (i, (code, name, aliases)) in BODIES.iter().enumerate() {
+            assert_eq!(*name, normalize(name));
+            for (other_code, other_name, _) in &BODIES[i + 1..] {
+                assert_ne!(code, other_code);
+                assert_ne!(name, other_name);
+            }
+            for alias in *aliases {
+                assert_eq!(code_for_name(alias), Some(*code));
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_is_case_and_space_insensitive() {
+        assert_eq!(code_for_name("  earth_moon   barycenter "), Some(3));
+        assert_eq!(code_for_name("Chiron"), Some(2_002_060));
+        assert_eq!(code_for_name("Planet X"), None);
+        assert_eq!(name_for_code(5), Some("JUPITER BARYCENTER"));
+    }
+}
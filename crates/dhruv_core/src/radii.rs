@@ -0,0 +1,72 @@
+//! Body radii used by shadow, semidiameter and horizon-dip geometry.
+//!
+//! Defaults are the IAU 2015 nominal values; [`BodyRadii::from_text_kernel`]
+//! takes them from a text PCK (`BODYnnn_RADII`) instead.
+
+use jpl_kernel::TextKernel;
+
+/// NAIF IDs of the bodies whose radii are tracked.
+const SUN: i32 = 10;
+const EARTH: i32 = 399;
+const MOON: i32 = 301;
+
+/// Radii of the Sun, Earth and Moon in km.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyRadii {
+    /// Solar radius (IAU 2015 Resolution B3 nominal: 696 000 km).
+    pub sun_km: f64,
+    /// Earth equatorial radius, used for lunar and solar parallax.
+    pub earth_equatorial_km: f64,
+    /// Earth mean radius, used for horizon dip.
+    pub earth_mean_km: f64,
+    /// Moon mean radius.
+    pub moon_km: f64,
+}
+
+impl Default for BodyRadii {
+    fn default() -> Self {
+        Self {
+            sun_km: 696_000.0,
+            earth_equatorial_km: 6378.137,
+            earth_mean_km: 6371.0,
+            moon_km: 1737.4,
+        }
+    }
+}
+
+impl BodyRadii {
+    /// Radii from a text PCK, keeping the default for any body it omits.
+    ///
+    /// Equatorial radius is the first `RADII` value; mean radii are the
+    /// average of all three.
+    pub fn from_text_kernel(kernel: &TextKernel) -> Self {
+        let defaults = Self::default();
+        let mean = |[a, b, c]: [f64; 3]| (a + b + c) / 3.0;
+        let earth = kernel.body_radii_km(EARTH);
+        Self {
+            sun_km: kernel.body_radii_km(SUN).map_or(defaults.sun_km, mean),
+            earth_equatorial_km: earth.map_or(defaults.earth_equatorial_km, |r| r[0]),
+            earth_mean_km: earth.map_or(defaults.earth_mean_km, mean),
+            moon_km: kernel.body_radii_km(MOON).map_or(defaults.moon_km, mean),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_kernel_overrides_present_bodies() {
+        let kernel = TextKernel::parse(
+            "\\begindata\nBODY10_RADII = ( 695700 695700 695700 )\n\
+             BODY399_RADII = ( 6378.1366 6378.1366 6356.7519 )\n",
+        )
+        .unwrap();
+        let radii = BodyRadii::from_text_kernel(&kernel);
+        assert_eq!(radii.sun_km, 695_700.0);
+        assert_eq!(radii.earth_equatorial_km, 6378.1366);
+        assert!((radii.earth_mean_km - 6371.0084).abs() < 1e-4);
+        assert_eq!(radii.moon_km, BodyRadii::default().moon_km);
+    }
+}
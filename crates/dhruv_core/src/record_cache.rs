@@ -0,0 +1,166 @@
+//! Bounded LRU cache of decoded Chebyshev records shared across queries.
+//!
+//! Keys identify a record by kernel slot, segment index and record index
+//! within one SPK set generation. The cache clears itself when a query from
+//! a newer generation touches it, and ignores inserts from stale snapshots.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use jpl_kernel::ChebyshevRecordData;
+
+/// (kernel slot in the SPK set, segment index, record index).
+pub(crate) type RecordKey = (usize, usize, usize);
+
+struct Entry {
+    record: Arc<ChebyshevRecordData>,
+    last_used: u64,
+}
+
+pub(crate) struct RecordCache {
+    capacity: usize,
+    generation: u64,
+    tick: u64,
+    entries: HashMap<RecordKey, Entry>,
+    /// Recency order: last-use tick -> key. Oldest first.
+    order: BTreeMap<u64, RecordKey>,
+}
+
+impl RecordCache {
+    pub(crate) fn new(capacity: usize, generation: u64) -> Self {
+        Self {
+            capacity,
+            generation,
+            tick: 0,
+            entries: HashMap::with_capacity(capacity),
+            order: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Drop all entries and adopt `generation` if it is newer.
+    ///
+    /// Returns `false` when `generation` is older than the cache (a stale
+    /// snapshot), in which case the cache must not be used.
+    fn sync_generation(&mut self, generation: u64) -> bool {
+        if generation > self.generation {
+            self.entries.clear();
+            self.order.clear();
+            self.generation = generation;
+        }
+        generation == self.generation
+    }
+
+    fn touch(&mut self, key: RecordKey) {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.order.remove(&entry.last_used);
+            entry.last_used = self.tick;
+            self.order.insert(self.tick, key);
+        }
+    }
+
+    /// Look up a record, marking it most recently used.
+    pub(crate) fn get(
+        &mut self,
+        generation: u64,
+        key: RecordKey,
+    ) -> Option<Arc<ChebyshevRecordData>> {
+        if !self.sync_generation(generation) {
+            return None;
+        }
+        let record = Arc::clone(&self.entries.get(&key)?.record);
+        self.touch(key);
+        Some(record)
+    }
+
+    /// Insert a record, evicting the least recently used entry when full.
+    pub(crate) fn insert(
+        &mut self,
+        generation: u64,
+        key: RecordKey,
+        record: Arc<ChebyshevRecordData>,
+    ) {
+        if self.capacity == 0 || !self.sync_generation(generation) {
+            return;
+        }
+        if self.entries.contains_key(&key) {
+            self.touch(key);
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.tick += 1;
+        self.entries.insert(
+            key,
+            Entry {
+                record,
+                last_used: self.tick,
+            },
+        );
+        self.order.insert(self.tick, key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(mid: f64) -> Arc<ChebyshevRecordData> {
+        Arc::new(ChebyshevRecordData {
+            mid,
+            radius: 1.0,
+            components: 3,
+            n_coeffs: 1,
+            coefficients: vec![mid, 0.0, 0.0],
+        })
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = RecordCache::new(2, 1);
+        cache.insert(1, (0, 0, 0), record(0.0));
+        cache.insert(1, (0, 0, 1), record(1.0));
+        // Touch record 0 so record 1 becomes the eviction candidate.
+        assert!(cache.get(1, (0, 0, 0)).is_some());
+        cache.insert(1, (0, 0, 2), record(2.0));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(1, (0, 0, 1)).is_none());
+        assert_eq!(cache.get(1, (0, 0, 0)).unwrap().mid, 0.0);
+        assert_eq!(cache.get(1, (0, 0, 2)).unwrap().mid, 2.0);
+    }
+
+    #[test]
+    fn newer_generation_invalidates() {
+        let mut cache = RecordCache::new(4, 1);
+        cache.insert(1, (0, 0, 0), record(0.0));
+        assert!(cache.get(2, (0, 0, 0)).is_none());
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn stale_generation_is_ignored() {
+        let mut cache = RecordCache::new(4, 3);
+        cache.insert(2, (0, 0, 0), record(0.0));
+        assert_eq!(cache.len(), 0);
+        cache.insert(3, (0, 0, 0), record(0.0));
+        assert!(cache.get(2, (0, 0, 0)).is_none());
+        assert!(cache.get(3, (0, 0, 0)).is_some());
+    }
+
+    #[test]
+    fn reinsert_keeps_single_entry() {
+        let mut cache = RecordCache::new(2, 0);
+        cache.insert(0, (1, 2, 3), record(0.0));
+        cache.insert(0, (1, 2, 3), record(0.0));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.order.len(), 1);
+    }
+}
@@ -0,0 +1,360 @@
+//! Topocentric observers on the WGS84 ellipsoid.
+//!
+//! Provides the geodetic site type, the CIO-based celestial-to-terrestrial
+//! rotation (CIP and CIO locator, Earth Rotation Angle and polar motion, via
+//! [`dhruv_frames::celestial_to_terrestrial_matrix`]), the site's GCRS state,
+//! horizontal coordinates and an atmospheric refraction model.
+//! [`crate::Engine::query_topocentric`] ties these to ephemeris queries.
+//!
+//! Sources: NIMA TR8350.2 (WGS84 constants); IERS Conventions 2010, §5
+//! (CIO-based transformation, polar motion matrix); Sæmundsson,
+//! Sky & Telescope 72 (1986) p. 70 (refraction). Implementation is original.
+
+use dhruv_frames::{PoleParameters, celestial_to_terrestrial_matrix};
+use dhruv_time::{EopKernel, Epoch};
+
+use crate::StateVector;
+
+/// WGS84 equatorial radius in km.
+pub const WGS84_A_KM: f64 = 6378.137;
+
+/// WGS84 flattening.
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Nominal Earth rotation rate in rad/s (rate of the Earth Rotation Angle).
+pub const EARTH_ROTATION_RAD_S: f64 = 7.292_115_146_706_979e-5;
+
+/// Geographic location on Earth's surface (WGS84 geodetic).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoLocation {
+    /// Geodetic latitude in degrees, north positive. Range: [-90, 90].
+    /// **Not validated** — out-of-range values are accepted but produce
+    /// undefined astronomical results from downstream functions.
+    pub latitude_deg: f64,
+    /// Geodetic longitude in degrees, east positive. Range: [-180, 180].
+    pub longitude_deg: f64,
+    /// Height in meters. Treated as height above the WGS84 ellipsoid; mean
+    /// sea level differs from it by less than 110 m.
+    pub altitude_m: f64,
+}
+
+impl GeoLocation {
+    /// Create a new geographic location.
+    pub fn new(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Self {
+        Self {
+            latitude_deg,
+            longitude_deg,
+            altitude_m,
+        }
+    }
+
+    /// Latitude in radians.
+    pub fn latitude_rad(&self) -> f64 {
+        self.latitude_deg.to_radians()
+    }
+
+    /// Longitude in radians (east positive).
+    pub fn longitude_rad(&self) -> f64 {
+        self.longitude_deg.to_radians()
+    }
+
+    /// Earth-fixed (ITRS) Cartesian position in km.
+    pub fn itrs_position_km(&self) -> [f64; 3] {
+        let (sin_lat, cos_lat) = self.latitude_rad().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude_rad().sin_cos();
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let n = WGS84_A_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        let h = self.altitude_m / 1000.0;
+        [
+            (n + h) * cos_lat * cos_lon,
+            (n + h) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + h) * sin_lat,
+        ]
+    }
+
+    /// Rotate an ITRS vector into local east, north and up components.
+    pub fn itrs_to_enu(&self, v: &[f64; 3]) -> [f64; 3] {
+        let (sin_lat, cos_lat) = self.latitude_rad().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude_rad().sin_cos();
+        [
+            -sin_lon * v[0] + cos_lon * v[1],
+            -sin_lat * cos_lon * v[0] - sin_lat * sin_lon * v[1] + cos_lat * v[2],
+            cos_lat * cos_lon * v[0] + cos_lat * sin_lon * v[1] + sin_lat * v[2],
+        ]
+    }
+}
+
+/// Options for [`crate::Engine::query_topocentric`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopocentricOptions {
+    /// Apply atmospheric refraction to the reported altitude.
+    pub refraction: bool,
+    /// Surface pressure in hPa for the refraction model.
+    pub pressure_hpa: f64,
+    /// Surface temperature in °C for the refraction model.
+    pub temperature_c: f64,
+}
+
+impl Default for TopocentricOptions {
+    fn default() -> Self {
+        Self {
+            refraction: false,
+            pressure_hpa: 1010.0,
+            temperature_c: 10.0,
+        }
+    }
+}
+
+/// Ephemeris request for an observer on the Earth's surface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopocentricQuery {
+    pub target: crate::Body,
+    pub location: GeoLocation,
+    /// Frame of the returned state vector. Horizontal coordinates do not
+    /// depend on it.
+    pub frame: crate::Frame,
+    pub epoch_tdb_jd: f64,
+    pub correction: crate::Correction,
+}
+
+/// Topocentric request at an [`Epoch`], which keeps the light-time and
+/// ephemeris evaluation free of the ~40 µs rounding of a single-`f64` JD.
+///
+/// [`crate::Engine::query_topocentric`] accepts either form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopocentricEpochQuery {
+    pub target: crate::Body,
+    pub location: GeoLocation,
+    pub frame: crate::Frame,
+    pub epoch: Epoch,
+    pub correction: crate::Correction,
+}
+
+impl From<TopocentricQuery> for TopocentricEpochQuery {
+    fn from(query: TopocentricQuery) -> Self {
+        Self {
+            target: query.target,
+            location: query.location,
+            frame: query.frame,
+            epoch: Epoch::from_jd_tdb(query.epoch_tdb_jd),
+            correction: query.correction,
+        }
+    }
+}
+
+impl TopocentricEpochQuery {
+    /// The same request with the epoch rounded to a single-`f64` JD.
+    pub fn to_query(&self) -> TopocentricQuery {
+        TopocentricQuery {
+            target: self.target,
+            location: self.location,
+            frame: self.frame,
+            epoch_tdb_jd: self.epoch.as_jd_tdb(),
+            correction: self.correction,
+        }
+    }
+}
+
+/// Azimuth/altitude of a target as seen from a site.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalCoords {
+    /// Azimuth in degrees, measured from north through east. Range: [0, 360).
+    pub azimuth_deg: f64,
+    /// Altitude above the horizon in degrees, including refraction when
+    /// requested.
+    pub altitude_deg: f64,
+    /// Refraction added to the geometric altitude, in degrees (0 when
+    /// refraction is disabled).
+    pub refraction_deg: f64,
+    /// Topocentric distance in km.
+    pub distance_km: f64,
+}
+
+/// Result of a topocentric query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopocentricState {
+    /// Target relative to the site, in the query frame.
+    pub state: StateVector,
+    /// Horizontal coordinates of the target.
+    pub horizontal: HorizontalCoords,
+    /// UT1 Julian Date used for Earth rotation.
+    pub jd_ut1: f64,
+}
+
+/// Rotation matrix (row-major) from GCRS/ICRF to ITRS.
+///
+/// `M = W(x_p, y_p, s′) · R3(ERA) · Q(X + dX, Y + dY, s)`: the GCRS → CIRS
+/// rotation from the IAU 2006 CIP, the Earth Rotation Angle at UT1, and
+/// polar motion.
+///
+/// * `jd_tdb` — epoch for precession-nutation
+/// * `jd_ut1` — epoch for Earth rotation
+/// * `pole` — IERS pole coordinates and celestial pole offsets
+pub fn gcrs_to_itrs_matrix(jd_tdb: f64, jd_ut1: f64, pole: &PoleParameters) -> [[f64; 3]; 3] {
+    let t = (jd_tdb - dhruv_time::J2000_JD) / 36525.0;
+    celestial_to_terrestrial_matrix(t, dhruv_time::earth_rotation_angle_rad(jd_ut1), pole)
+}
+
+/// Pole coordinates and celestial pole offsets from EOP data at a UTC
+/// Julian Date.
+pub fn eop_pole_parameters(eop: &EopKernel, jd_utc: f64) -> PoleParameters {
+    let (xp_arcsec, yp_arcsec) = eop.polar_motion_arcsec(jd_utc);
+    let (dx_arcsec, dy_arcsec) = eop.celestial_pole_offsets_arcsec(jd_utc);
+    PoleParameters {
+        xp_arcsec,
+        yp_arcsec,
+        dx_arcsec,
+        dy_arcsec,
+    }
+}
+
+/// Site position (km) and velocity (km/s) in GCRS for a GCRS→ITRS matrix.
+///
+/// The velocity is the Earth's rotation `ω × r` about the pole; the slow
+/// rotation of the pole itself is neglected.
+pub fn site_gcrs_state(location: &GeoLocation, gcrs_to_itrs: &[[f64; 3]; 3]) -> [f64; 6] {
+    let r = location.itrs_position_km();
+    let v = [
+        -EARTH_ROTATION_RAD_S * r[1],
+        EARTH_ROTATION_RAD_S * r[0],
+        0.0,
+    ];
+    let p = mat_t_vec(gcrs_to_itrs, &r);
+    let pv = mat_t_vec(gcrs_to_itrs, &v);
+    [p[0], p[1], p[2], pv[0], pv[1], pv[2]]
+}
+
+/// Horizontal coordinates of a GCRS site-relative position.
+pub fn horizontal_from_gcrs(
+    location: &GeoLocation,
+    gcrs_to_itrs: &[[f64; 3]; 3],
+    position_km: &[f64; 3],
+    options: &TopocentricOptions,
+) -> HorizontalCoords {
+    let itrs = mat_vec(gcrs_to_itrs, position_km);
+    let [east, north, up] = location.itrs_to_enu(&itrs);
+    let horizontal = (east * east + north * north).sqrt();
+    let geometric_alt = up.atan2(horizontal).to_degrees();
+    let refraction = if options.refraction {
+        refraction_deg(geometric_alt, options.pressure_hpa, options.temperature_c)
+    } else {
+        0.0
+    };
+    HorizontalCoords {
+        azimuth_deg: east.atan2(north).to_degrees().rem_euclid(360.0),
+        altitude_deg: geometric_alt + refraction,
+        refraction_deg: refraction,
+        distance_km: (horizontal * horizontal + up * up).sqrt(),
+    }
+}
+
+/// Atmospheric refraction in degrees for a geometric (airless) altitude.
+///
+/// Sæmundsson's formula `R = 1.02′ / tan(h + 10.3°/(h + 5.11°))`, scaled by
+/// `(P/1010)·(283/(273 + T))`. Below −1° the value at −1° is used, where
+/// the formula stops being meaningful.
+pub fn refraction_deg(geometric_altitude_deg: f64, pressure_hpa: f64, temperature_c: f64) -> f64 {
+    let h = geometric_altitude_deg.max(-1.0);
+    let r_arcmin = 1.02 / (h + 10.3 / (h + 5.11)).to_radians().tan();
+    let scale = (pressure_hpa / 1010.0) * (283.0 / (273.0 + temperature_c));
+    r_arcmin * scale / 60.0
+}
+
+fn mat_vec(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn mat_t_vec(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [0, 1, 2].map(|j| m[0][j] * v[0] + m[1][j] * v[1] + m[2][j] * v[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhruv_frames::{
+        FrameOfDate, PrecessionModel, equation_of_equinoxes_and_true_obliquity,
+        icrf_to_frame_of_date_matrix,
+    };
+
+    fn mat_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let mut out = [[0.0; 3]; 3];
+        for (row, a_row) in out.iter_mut().zip(a) {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = a_row[0] * b[0][j] + a_row[1] * b[1][j] + a_row[2] * b[2][j];
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn geodetic_to_itrs_reference_points() {
+        let equator = GeoLocation::new(0.0, 90.0, 0.0).itrs_position_km();
+        assert!(equator[0].abs() < 1e-9);
+        assert!((equator[1] - WGS84_A_KM).abs() < 1e-9);
+
+        let pole = GeoLocation::new(90.0, 0.0, 1000.0).itrs_position_km();
+        let b = WGS84_A_KM * (1.0 - WGS84_F);
+        assert!((pole[2] - (b + 1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn enu_axes_at_site() {
+        let site = GeoLocation::new(30.0, 60.0, 0.0);
+        let up = site.itrs_to_enu(&[
+            30f64.to_radians().cos() * 60f64.to_radians().cos(),
+            30f64.to_radians().cos() * 60f64.to_radians().sin(),
+            30f64.to_radians().sin(),
+        ]);
+        assert!(up[0].abs() < 1e-12 && up[1].abs() < 1e-12);
+        assert!((up[2] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn earth_rotation_matrix_is_orthonormal_and_follows_gast() {
+        let jd = 2_460_000.5;
+        let pole = PoleParameters {
+            xp_arcsec: 0.2,
+            yp_arcsec: 0.3,
+            ..Default::default()
+        };
+        let m = gcrs_to_itrs_matrix(jd, jd, &pole);
+        let identity = mat_mul(&m, &[0, 1, 2].map(|i| [m[0][i], m[1][i], m[2][i]]));
+        for (i, row) in identity.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((v - expected).abs() < 1e-14);
+            }
+        }
+
+        // Without polar motion, the true equinox of date lies at hour angle
+        // GAST west of Greenwich (to the few-mas consistency of the IAU 2006
+        // GMST polynomial with the CIO locator).
+        let m = gcrs_to_itrs_matrix(jd, jd, &PoleParameters::default());
+        let t = (jd - dhruv_time::J2000_JD) / 36525.0;
+        let np = icrf_to_frame_of_date_matrix(
+            t,
+            FrameOfDate::TrueEquator,
+            PrecessionModel::Iau2006,
+            dhruv_frames::NutationModel::Iau2000B,
+        );
+        let equinox = [np[0][0], np[0][1], np[0][2]];
+        let itrs = mat_vec(&m, &equinox);
+        let gast = dhruv_time::gmst_rad(jd) + equation_of_equinoxes_and_true_obliquity(t).0;
+        let lon = itrs[1].atan2(itrs[0]);
+        let diff = (lon + gast).rem_euclid(std::f64::consts::TAU);
+        let diff_arcsec = diff.min(std::f64::consts::TAU - diff).to_degrees() * 3600.0;
+        assert!(diff_arcsec < 0.01, "{diff_arcsec}″");
+    }
+
+    #[test]
+    fn refraction_standard_values() {
+        // About 29′ for a geometric altitude of 0° and under 1′ at 45°.
+        let horizon = refraction_deg(0.0, 1010.0, 10.0) * 60.0;
+        assert!((horizon - 29.0).abs() < 0.5, "horizon {horizon}′");
+        let mid = refraction_deg(45.0, 1010.0, 10.0) * 60.0;
+        assert!((mid - 1.0).abs() < 0.05, "45° {mid}′");
+        assert!(refraction_deg(90.0, 1010.0, 10.0).abs() < 1e-4);
+        // Thinner, colder air scales the value.
+        assert!(refraction_deg(10.0, 500.0, 10.0) < refraction_deg(10.0, 1010.0, 10.0));
+    }
+}
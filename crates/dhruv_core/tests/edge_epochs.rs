@@ -5,7 +5,7 @@
 
 use std::path::PathBuf;
 
-use dhruv_core::{Body, Engine, EngineConfig, EngineError, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, EngineConfig, EngineError, Frame, Observer, Query};
 
 fn kernel_paths() -> (PathBuf, PathBuf) {
     let base = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../kernels/data");
@@ -30,6 +30,7 @@ fn earth_ssb_query(epoch_tdb_jd: f64) -> Query {
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd,
+        correction: Correction::Geometric,
     }
 }
 
@@ -144,6 +145,7 @@ fn query_all_bodies_at_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         };
         let result = engine.query(query);
         assert!(
@@ -245,6 +247,7 @@ fn moon_across_epoch_range() {
                 observer: Observer::Body(Body::Earth),
                 frame: Frame::IcrfJ2000,
                 epoch_tdb_jd: jd,
+                correction: Correction::Geometric,
             })
             .unwrap_or_else(|e| panic!("Moon-Earth query at JD {jd} failed: {e}"));
 
@@ -22,6 +22,7 @@ fn real_engine() -> Option<Engine> {
             lsk_path: lsk,
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         })
         .expect("should load engine"),
     )
@@ -38,6 +39,7 @@ fn query_rejects_non_finite_epoch() {
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: f64::NAN,
+        correction: Correction::Geometric,
     };
     assert!(matches!(
         engine.query(query),
@@ -56,6 +58,7 @@ fn query_rejects_same_target_observer() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     assert!(matches!(
         engine.query(query),
@@ -74,6 +77,7 @@ fn query_earth_ssb_at_j2000() {
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0, // J2000.0
+        correction: Correction::Geometric,
     };
     let state = engine.query(query).expect("should succeed");
 
@@ -100,6 +104,7 @@ fn query_mars_earth_at_2460000() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query).expect("should succeed");
 
@@ -126,6 +131,7 @@ fn query_deterministic() {
         observer: Observer::Body(Body::Sun),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::Geometric,
     };
     let first = engine.query(query).expect("should succeed");
     let second = engine.query(query).expect("should succeed");
@@ -144,12 +150,14 @@ fn query_ecliptic_frame() {
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let q_ecl = Query {
         target: Body::Earth,
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::EclipticJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let s_icrf = engine.query(q_icrf).unwrap();
     let s_ecl = engine.query(q_ecl).unwrap();
@@ -187,6 +195,7 @@ fn moon_relative_to_earth() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query).expect("should succeed");
 
@@ -214,6 +223,7 @@ fn context_avoids_redundant_evaluations() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let (state, stats) = engine.query_with_stats(query).expect("should succeed");
 
@@ -262,6 +272,7 @@ fn query_batch_matches_individual() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: epoch,
+            correction: Correction::Geometric,
         })
         .collect();
 
@@ -299,18 +310,21 @@ fn query_batch_mixed_epochs() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         },
         Query {
             target: Body::Mars,
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_460_000.5,
+            correction: Correction::Geometric,
         },
         Query {
             target: Body::Moon,
             observer: Observer::Body(Body::Earth),
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         },
     ];
     let results = engine.query_batch(&queries);
@@ -348,6 +362,7 @@ fn query_with_derived_works() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::Geometric,
     };
     let (state, value) = engine
         .query_with_derived(query, &DummyDerived)
@@ -368,6 +383,7 @@ fn multi_kernel_loads_and_queries() {
         lsk_path: lsk,
         cache_capacity: 256,
         strict_validation: true,
+        spk_load_mode: SpkLoadMode::InMemory,
     })
     .expect("should load multi-kernel engine");
 
@@ -378,6 +394,7 @@ fn multi_kernel_loads_and_queries() {
         observer: Observer::SolarSystemBarycenter,
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let state = engine
         .query(query)
@@ -450,6 +467,7 @@ fn concurrent_queries_produce_identical_results() {
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
     };
     let expected = engine.query(query).expect("baseline should succeed");
 
@@ -466,3 +484,245 @@ fn concurrent_queries_produce_identical_results() {
         assert_eq!(state, expected, "concurrent result differs from baseline");
     }
 }
+
+fn angle_arcsec(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let cross = [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ];
+    let cross_norm = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+    cross_norm.atan2(dot).to_degrees() * 3600.0
+}
+
+#[test]
+fn light_time_sun_from_earth() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let mut query = Query {
+        target: Body::Sun,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: 2_451_545.0,
+        correction: Correction::Geometric,
+    };
+    let geometric = engine.query(query).expect("geometric");
+    query.correction = Correction::LightTime;
+    let light_time = engine.query(query).expect("light time");
+
+    // Sun moves ~0.013 km/s about the SSB: 499 s of light time shifts it by
+    // only a few km, well under an arcsecond.
+    let shift = angle_arcsec(&geometric.position_km, &light_time.position_km);
+    assert!(shift < 0.05, "light-time shift = {shift}\"");
+    assert_ne!(geometric.position_km, light_time.position_km);
+}
+
+#[test]
+fn annual_aberration_of_sun_is_about_20_arcsec() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let mut query = Query {
+        target: Body::Sun,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::LightTime,
+    };
+    let light_time = engine.query(query).expect("light time");
+    query.correction = Correction::LightTimeAberration;
+    let aberrated = engine.query(query).expect("aberration");
+    query.correction = Correction::Apparent;
+    let apparent = engine.query(query).expect("apparent");
+
+    let shift = angle_arcsec(&light_time.position_km, &aberrated.position_km);
+    assert!((shift - 20.5).abs() < 0.5, "aberration = {shift}\"");
+    // No deflection for the Sun itself.
+    assert_eq!(aberrated.position_km, apparent.position_km);
+}
+
+#[test]
+fn apparent_mars_includes_deflection() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let mut query = Query {
+        target: Body::Mars,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::EclipticJ2000,
+        epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::LightTimeAberration,
+    };
+    let aberrated = engine.query(query).expect("aberration");
+    query.correction = Correction::Apparent;
+    let apparent = engine.query(query).expect("apparent");
+
+    // Deflection away from the Sun is milliarcseconds, far from the limb.
+    let shift = angle_arcsec(&aberrated.position_km, &apparent.position_km);
+    assert!(shift > 0.0 && shift < 0.1, "deflection = {shift}\"");
+}
+
+#[test]
+fn mean_ecliptic_of_date_matches_manual_rotation() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let jd = 2_460_000.5;
+    let model = dhruv_frames::PrecessionModel::Iau2006;
+    let mut query = Query {
+        target: Body::Mars,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: jd,
+        correction: Correction::Geometric,
+    };
+    let icrf = engine.query(query).expect("icrf");
+    query.frame = Frame::MeanEclipticOfDate(model);
+    let of_date = engine.query(query).expect("of date");
+
+    let t = (jd - 2_451_545.0) / 36525.0;
+    let expected = dhruv_frames::precess_ecliptic_j2000_to_date_with_model(
+        &dhruv_frames::icrf_to_ecliptic(&icrf.position_km),
+        t,
+        model,
+    );
+    assert_eq!(of_date.position_km, expected);
+}
+
+#[test]
+fn frame_of_date_velocity_matches_finite_difference() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let jd = 2_460_000.5;
+    let frame = Frame::TrueEquatorOfDate(
+        dhruv_frames::DEFAULT_PRECESSION_MODEL,
+        dhruv_frames::DEFAULT_NUTATION_MODEL,
+    );
+    let at = |epoch_tdb_jd: f64| {
+        engine
+            .query(Query {
+                target: Body::Moon,
+                observer: Observer::Body(Body::Earth),
+                frame,
+                epoch_tdb_jd,
+                correction: Correction::Geometric,
+            })
+            .expect("query")
+    };
+    let h_days = 1.0 / 1440.0;
+    let state = at(jd);
+    let ahead = at(jd + h_days);
+    let behind = at(jd - h_days);
+    for i in 0..3 {
+        let fd = (ahead.position_km[i] - behind.position_km[i]) / (2.0 * h_days * 86_400.0);
+        assert!(
+            (state.velocity_km_s[i] - fd).abs() < 1e-6,
+            "axis {i}: {} vs {fd}",
+            state.velocity_km_s[i]
+        );
+    }
+}
+
+#[test]
+fn record_cache_serves_repeat_queries() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let query = Query {
+        target: Body::Mars,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: 2_460_000.5,
+        correction: Correction::Geometric,
+    };
+    let (first, stats) = engine.query_with_stats(query).expect("first query");
+    assert!(stats.record_cache_misses > 0, "{stats:?}");
+    assert_eq!(stats.record_cache_hits, 0);
+
+    // A nearby epoch falls in the same records.
+    let mut nearby = query;
+    nearby.epoch_tdb_jd += 1.0 / 24.0;
+    let (_, stats) = engine.query_with_stats(nearby).expect("nearby query");
+    assert_eq!(stats.record_cache_misses, 0, "{stats:?}");
+    assert!(stats.record_cache_hits > 0);
+
+    let (again, _) = engine.query_with_stats(query).expect("repeat query");
+    assert_eq!(first, again);
+}
+
+#[test]
+fn record_cache_is_bounded_and_cleared_on_replace() {
+    let (spk, lsk) = kernel_paths();
+    if !spk.exists() || !lsk.exists() {
+        eprintln!("Skipping: kernel files not found");
+        return;
+    }
+    let engine = Engine::new(EngineConfig::with_single_spk(spk.clone(), lsk, 4, true))
+        .expect("should load engine");
+
+    for day in 0..40 {
+        let query = Query {
+            target: Body::Moon,
+            observer: Observer::Body(Body::Earth),
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd: 2_451_545.0 + 10.0 * day as f64,
+            correction: Correction::Geometric,
+        };
+        engine.query(query).expect("query");
+        assert!(engine.record_cache_len() <= 4);
+    }
+    assert_eq!(engine.record_cache_len(), 4);
+
+    engine
+        .replace_spk_paths(vec![spk])
+        .expect("replacement should succeed");
+    assert_eq!(engine.record_cache_len(), 0);
+}
+
+#[test]
+fn on_demand_engine_matches_in_memory() {
+    let (spk, lsk) = kernel_paths();
+    let resident = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let on_demand = Engine::new(EngineConfig {
+        spk_load_mode: SpkLoadMode::OnDemand,
+        ..EngineConfig::with_single_spk(spk.clone(), lsk, 256, true)
+    })
+    .expect("should open engine on demand");
+
+    for (target, observer) in [
+        (Body::Moon, Observer::Body(Body::Earth)),
+        (Body::Mars, Observer::SolarSystemBarycenter),
+        (Body::Sun, Observer::Body(Body::Earth)),
+    ] {
+        let query = Query {
+            target,
+            observer,
+            frame: Frame::EclipticJ2000,
+            epoch_tdb_jd: 2_460_000.5,
+            correction: Correction::Apparent,
+        };
+        assert_eq!(
+            on_demand.query(query).unwrap(),
+            resident.query(query).unwrap()
+        );
+    }
+
+    // Replacement keeps the configured mode and reuses the open kernel.
+    let report = on_demand
+        .replace_spk_paths(vec![spk])
+        .expect("replacement should succeed");
+    assert_eq!(report.reused_count, 1);
+    assert_eq!(on_demand.config().spk_load_mode, SpkLoadMode::OnDemand);
+}
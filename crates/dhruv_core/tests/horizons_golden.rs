@@ -18,7 +18,7 @@
 
 use std::path::PathBuf;
 
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query, StateVector};
+use dhruv_core::{Body, Correction, Engine, EngineConfig, Frame, Observer, Query, StateVector};
 
 // ---------------------------------------------------------------------------
 // Tolerances (per-body tier)
@@ -118,6 +118,7 @@ fn golden_earth_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -151,6 +152,7 @@ fn golden_sun_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -184,6 +186,7 @@ fn golden_mercury_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -217,6 +220,7 @@ fn golden_venus_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -250,6 +254,7 @@ fn golden_moon_earth_j2000() {
             observer: Observer::Body(Body::Earth),
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -285,6 +290,7 @@ fn golden_mars_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -319,6 +325,7 @@ fn golden_jupiter_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -353,6 +360,7 @@ fn golden_saturn_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -390,6 +398,7 @@ fn golden_earth_ssb_2460000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_460_000.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -423,6 +432,7 @@ fn golden_moon_earth_2460000() {
             observer: Observer::Body(Body::Earth),
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_460_000.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -460,6 +470,7 @@ fn golden_uranus_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -493,6 +504,7 @@ fn golden_neptune_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -526,6 +538,7 @@ fn golden_pluto_bary_ssb_j2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -564,6 +577,7 @@ fn golden_earth_ssb_perihelion_2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_547.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -598,6 +612,7 @@ fn golden_earth_ssb_aphelion_2000() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_451_729.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -632,6 +647,7 @@ fn golden_earth_ssb_1900() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_415_020.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -666,6 +682,7 @@ fn golden_earth_ssb_2050() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: 2_469_807.5,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -703,6 +720,7 @@ fn golden_earth_ssb_j2000_ecliptic() {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::EclipticJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
@@ -736,6 +754,7 @@ fn golden_moon_earth_j2000_ecliptic() {
             observer: Observer::Body(Body::Earth),
             frame: Frame::EclipticJ2000,
             epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
         })
         .expect("query should succeed");
 
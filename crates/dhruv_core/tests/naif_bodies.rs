@@ -0,0 +1,230 @@
+//! Queries of arbitrary NAIF bodies against a synthetic kernel: planetary
+//! barycenters, satellites and asteroids, plus unresolvable chains.
+
+use std::path::PathBuf;
+
+use dhruv_core::*;
+use jpl_kernel::{SegmentData, SpkWriter};
+
+const LSK: &str = r"
+\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K         = 1.657D-3
+DELTET/EB        = 1.671D-2
+DELTET/M         = ( 6.239996 1.99096871D-7 )
+DELTET/DELTA_AT  = ( 10, @1972-JAN-1
+                     37, @2017-JAN-1 )
+\begintext
+";
+
+/// Type 2 segment over ±1e6 s: position `p0 + v t`.
+fn linear(target: i32, center: i32, p0: [f64; 3], v: [f64; 3]) -> SegmentData {
+    let radius = 1.0e6;
+    let mut words = vec![0.0, radius];
+    for axis in 0..3 {
+        words.extend([p0[axis], v[axis] * radius]);
+    }
+    words.extend([-radius, 2.0 * radius, 8.0, 1.0]);
+    SegmentData {
+        target,
+        center,
+        frame: 1,
+        data_type: 2,
+        start_epoch: -radius,
+        end_epoch: radius,
+        name: String::new(),
+        words,
+    }
+}
+
+fn synthetic_engine(tag: &str) -> (Engine, [PathBuf; 2]) {
+    let dir = std::env::temp_dir();
+    let spk = dir.join(format!("dhruv_core_naif_{tag}_{}.bsp", std::process::id()));
+    let lsk = dir.join(format!("dhruv_core_naif_{tag}_{}.tls", std::process::id()));
+
+    let mut writer = SpkWriter::new("NAIF BODY TEST");
+    for segment in [
+        linear(10, 0, [1000.0, 0.0, 0.0], [0.0; 3]),
+        linear(3, 0, [1.5e8, 0.0, 0.0], [0.0, 30.0, 0.0]),
+        linear(5, 0, [7.8e8, 0.0, 0.0], [0.0, 13.0, 0.0]),
+        linear(501, 5, [4.2e5, 0.0, 0.0], [0.0, 17.0, 0.0]),
+        linear(2_000_001, 10, [4.1e8, 0.0, 0.0], [0.0, 18.0, 0.0]),
+        // Centered on Uranus barycenter, which the kernel lacks.
+        linear(2_000_002, 7, [1.0, 2.0, 3.0], [0.0; 3]),
+    ] {
+        writer.add_segment(segment).unwrap();
+    }
+    writer.write(&spk).unwrap();
+    std::fs::write(&lsk, LSK).unwrap();
+
+    let engine = Engine::new(EngineConfig::with_single_spk(
+        spk.clone(),
+        lsk.clone(),
+        64,
+        true,
+    ))
+    .unwrap();
+    (engine, [spk, lsk])
+}
+
+fn position(engine: &Engine, target: Body, observer: Observer, t_s: f64) -> [f64; 3] {
+    engine
+        .query(Query {
+            target,
+            observer,
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd: 2_451_545.0 + t_s / 86_400.0,
+            correction: Correction::Geometric,
+        })
+        .unwrap()
+        .position_km
+}
+
+#[test]
+fn barycenters_satellites_and_asteroids_resolve() {
+    let (engine, files) = synthetic_engine("resolve");
+    let t = 1000.0;
+
+    // Io from the Earth-Moon barycenter chains through Jupiter barycenter.
+    let io = position(
+        &engine,
+        Body::from_name("Io").unwrap(),
+        Observer::Body(Body::EARTH_MOON_BARYCENTER),
+        t,
+    );
+    let expected_y = (13.0 + 17.0 - 30.0) * t;
+    assert!((io[0] - (7.8e8 + 4.2e5 - 1.5e8)).abs() < 1e-6);
+    assert!((io[1] - expected_y).abs() < 1e-6);
+
+    // Ceres is centered on the Sun; an SSB observer sees the sum.
+    let ceres = position(
+        &engine,
+        Body::from_name("ceres").unwrap(),
+        Observer::naif(0),
+        t,
+    );
+    assert!((ceres[0] - (4.1e8 + 1000.0)).abs() < 1e-6);
+
+    // Jupiter (599) falls back to its barycenter when it has no segment.
+    let jupiter = position(&engine, Body::Jupiter, Observer::SolarSystemBarycenter, t);
+    let bary = position(
+        &engine,
+        Body::JUPITER_BARYCENTER,
+        Observer::SolarSystemBarycenter,
+        t,
+    );
+    assert_eq!(jupiter, bary);
+
+    for file in files {
+        std::fs::remove_file(file).unwrap();
+    }
+}
+
+#[test]
+fn unresolvable_chains_name_the_missing_body() {
+    let (engine, files) = synthetic_engine("missing");
+    let query = |target: Body, observer: Observer| {
+        engine.query(Query {
+            target,
+            observer,
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd: 2_451_545.0,
+            correction: Correction::Geometric,
+        })
+    };
+
+    assert_eq!(
+        query(Body::naif(-555), Observer::SolarSystemBarycenter),
+        Err(EngineError::UnresolvedBody {
+            body: -555,
+            missing: -555
+        })
+    );
+    assert_eq!(
+        query(Body::naif(2_000_002), Observer::SolarSystemBarycenter),
+        Err(EngineError::UnresolvedBody {
+            body: 2_000_002,
+            missing: 7
+        })
+    );
+    // Observer chains are reported the same way.
+    assert_eq!(
+        query(Body::Sun, Observer::Body(Body::Moon)),
+        Err(EngineError::UnresolvedBody {
+            body: 301,
+            missing: 301
+        })
+    );
+
+    for file in files {
+        std::fs::remove_file(file).unwrap();
+    }
+}
+
+#[test]
+fn naif_codes_normalise_to_named_variants() {
+    assert!(matches!(Body::naif(399), Body::Earth));
+    assert_eq!(Body::Naif(301), Body::Moon);
+    assert_eq!(Body::from_code(2_000_001), None);
+    assert_eq!(Body::naif(2_000_001).code(), 2_000_001);
+    assert_eq!(Body::from_name("EMB"), Some(Body::EARTH_MOON_BARYCENTER));
+    assert_eq!(Body::from_name(" 2005145 "), Some(Body::Naif(2_005_145)));
+    assert_eq!(Body::from_name("Vulcan"), None);
+    assert_eq!(Body::PLUTO_BARYCENTER.name(), Some("PLUTO BARYCENTER"));
+    assert_eq!(Observer::naif(0), Observer::SolarSystemBarycenter);
+    assert_eq!(Observer::naif(3), Observer::Body(Body::Naif(3)));
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(Body::Earth);
+    assert!(set.contains(&Body::Naif(399)));
+
+    let kernel = TextKernel::parse(
+        r"\begindata
+NAIF_BODY_NAME = 'CERES'
+NAIF_BODY_CODE = 20000001
+",
+    )
+    .unwrap();
+    assert_eq!(
+        Body::from_name_in("Ceres", &kernel),
+        Some(Body::Naif(20_000_001))
+    );
+    assert_eq!(Body::from_name_in("Io", &kernel), Some(Body::Naif(501)));
+}
+
+#[test]
+fn epoch_queries_resolve_microseconds() {
+    let (engine, files) = synthetic_engine("epoch");
+    let base = Epoch::from_tdb_seconds(9.0e5);
+    let at = |epoch: Epoch| {
+        engine
+            .query(EpochQuery {
+                target: Body::Earth,
+                observer: Observer::SolarSystemBarycenter,
+                frame: Frame::IcrfJ2000,
+                epoch,
+                correction: Correction::Geometric,
+            })
+            .unwrap()
+            .position_km
+    };
+
+    // 30 km/s over 2 µs is 6 cm, below a single-f64 JD's ~40 µs step.
+    let moved = at(base.add_seconds(2e-6))[1] - at(base)[1];
+    assert!((moved - 6e-5).abs() < 1e-6, "{moved}");
+
+    // A JD query still lands on the same state as its Epoch.
+    let query = Query {
+        target: Body::Earth,
+        observer: Observer::SolarSystemBarycenter,
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: base.as_jd_tdb(),
+        correction: Correction::Geometric,
+    };
+    let from_jd = engine.query(query).unwrap().position_km;
+    assert_eq!(from_jd, at(Epoch::from_jd_tdb(query.epoch_tdb_jd)));
+
+    for file in files {
+        std::fs::remove_file(file).unwrap();
+    }
+}
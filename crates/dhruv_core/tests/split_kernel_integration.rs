@@ -6,7 +6,9 @@
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query, StateVector};
+use dhruv_core::{
+    Body, Correction, Engine, EngineConfig, Frame, Observer, Query, SpkLoadMode, StateVector,
+};
 use dhruv_time::{SECONDS_PER_DAY, tdb_seconds_to_jd};
 use jpl_kernel::SpkKernel;
 
@@ -50,6 +52,7 @@ fn engine_for(spk_paths: Vec<PathBuf>) -> Engine {
         lsk_path: lsk,
         cache_capacity: 256,
         strict_validation: true,
+        spk_load_mode: SpkLoadMode::InMemory,
     })
     .expect("engine should load")
 }
@@ -103,6 +106,7 @@ fn query(engine: &Engine, body: Body, epoch_tdb_s: f64) -> StateVector {
             observer: Observer::SolarSystemBarycenter,
             frame: Frame::IcrfJ2000,
             epoch_tdb_jd: tdb_seconds_to_jd(epoch_tdb_s),
+            correction: Correction::Geometric,
         })
         .unwrap_or_else(|e| panic!("query failed for {body:?} at {epoch_tdb_s}: {e}"))
 }
@@ -173,6 +177,7 @@ fn split_kernels_match_parent_one_by_one() {
                 observer: Observer::SolarSystemBarycenter,
                 frame: Frame::IcrfJ2000,
                 epoch_tdb_jd: tdb_seconds_to_jd(start - SECONDS_PER_DAY),
+                correction: Correction::Geometric,
             });
             assert!(
                 before_result.is_err(),
@@ -185,6 +190,7 @@ fn split_kernels_match_parent_one_by_one() {
                 observer: Observer::SolarSystemBarycenter,
                 frame: Frame::IcrfJ2000,
                 epoch_tdb_jd: tdb_seconds_to_jd(end + SECONDS_PER_DAY),
+                correction: Correction::Geometric,
             });
             assert!(
                 after_result.is_err(),
@@ -0,0 +1,259 @@
+//! Topocentric queries against a synthetic kernel with a static Earth and
+//! targets placed along known ICRF directions.
+
+use std::path::PathBuf;
+
+use dhruv_core::topocentric::{gcrs_to_itrs_matrix, refraction_deg, site_gcrs_state};
+use dhruv_core::*;
+use dhruv_time::EopKernel;
+use jpl_kernel::{SegmentData, SpkWriter};
+
+const LSK: &str = r"
+\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K         = 1.657D-3
+DELTET/EB        = 1.671D-2
+DELTET/M         = ( 6.239996 1.99096871D-7 )
+DELTET/DELTA_AT  = ( 10, @1972-JAN-1
+                     32, @1999-JAN-1 )
+\begintext
+";
+
+const EPOCH: f64 = 2_451_545.0;
+
+/// Target far along the ICRF +Z axis (near the celestial pole).
+const POLE_TARGET: Body = Body::Naif(2_000_001);
+/// Target at lunar distance along +X.
+const NEAR_TARGET: Body = Body::Naif(2_000_002);
+
+fn fixed(target: i32, center: i32, p: [f64; 3]) -> SegmentData {
+    let radius = 1.0e6;
+    let mut words = vec![0.0, radius];
+    for value in p {
+        words.extend([value, 0.0]);
+    }
+    words.extend([-radius, 2.0 * radius, 8.0, 1.0]);
+    SegmentData {
+        target,
+        center,
+        frame: 1,
+        data_type: 2,
+        start_epoch: -radius,
+        end_epoch: radius,
+        name: String::new(),
+        words,
+    }
+}
+
+fn synthetic_engine(tag: &str) -> (Engine, [PathBuf; 2]) {
+    let dir = std::env::temp_dir();
+    let spk = dir.join(format!("dhruv_core_topo_{tag}_{}.bsp", std::process::id()));
+    let lsk = dir.join(format!("dhruv_core_topo_{tag}_{}.tls", std::process::id()));
+
+    let mut writer = SpkWriter::new("TOPOCENTRIC TEST");
+    for segment in [
+        fixed(3, 0, [0.0; 3]),
+        fixed(399, 3, [0.0; 3]),
+        fixed(2_000_001, 399, [0.0, 0.0, 1.0e9]),
+        fixed(2_000_002, 399, [384_400.0, 0.0, 0.0]),
+    ] {
+        writer.add_segment(segment).unwrap();
+    }
+    writer.write(&spk).unwrap();
+    std::fs::write(&lsk, LSK).unwrap();
+
+    let engine = Engine::new(EngineConfig::with_single_spk(
+        spk.clone(),
+        lsk.clone(),
+        64,
+        true,
+    ))
+    .unwrap();
+    (engine, [spk, lsk])
+}
+
+fn cleanup(paths: [PathBuf; 2]) {
+    for path in paths {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn topo_query(target: Body, location: GeoLocation) -> TopocentricQuery {
+    TopocentricQuery {
+        target,
+        location,
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: EPOCH,
+        correction: Correction::Geometric,
+    }
+}
+
+#[test]
+fn celestial_pole_altitude_matches_latitude() {
+    let (engine, paths) = synthetic_engine("pole");
+    let options = TopocentricOptions::default();
+
+    for latitude in [90.0, 40.0, 12.5] {
+        let site = GeoLocation::new(latitude, 77.2, 200.0);
+        let result = engine
+            .query_topocentric(topo_query(POLE_TARGET, site), None, &options)
+            .unwrap();
+        let h = result.horizontal;
+        assert!(
+            (h.altitude_deg - latitude).abs() < 0.01,
+            "lat {latitude}: alt {}",
+            h.altitude_deg
+        );
+        assert_eq!(h.refraction_deg, 0.0);
+        if latitude < 90.0 {
+            let az = h.azimuth_deg.min(360.0 - h.azimuth_deg);
+            assert!(az < 0.02, "lat {latitude}: az {}", h.azimuth_deg);
+        }
+    }
+
+    let polar = GeoLocation::new(90.0, 0.0, 0.0);
+    let result = engine
+        .query_topocentric(topo_query(NEAR_TARGET, polar), None, &options)
+        .unwrap();
+    // On the horizon, pulled just below by the site's height above the
+    // centre.
+    assert!(result.horizontal.altitude_deg.abs() < 1.0);
+    cleanup(paths);
+}
+
+#[test]
+fn topocentric_state_is_geocentric_minus_site() {
+    let (engine, paths) = synthetic_engine("parallax");
+    let site = GeoLocation::new(28.6139, 77.209, 216.0);
+    let result = engine
+        .query_topocentric(
+            topo_query(NEAR_TARGET, site),
+            None,
+            &TopocentricOptions::default(),
+        )
+        .unwrap();
+    let geocentric = engine
+        .query(Query {
+            target: NEAR_TARGET,
+            observer: Observer::Body(Body::Earth),
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd: EPOCH,
+            correction: Correction::Geometric,
+        })
+        .unwrap();
+    let matrix = gcrs_to_itrs_matrix(EPOCH, result.jd_ut1, &PoleParameters::default());
+    let site_state = site_gcrs_state(&site, &matrix);
+    for axis in 0..3 {
+        let expected = geocentric.position_km[axis] - site_state[axis];
+        assert!((result.state.position_km[axis] - expected).abs() < 1e-9);
+        let expected_v = geocentric.velocity_km_s[axis] - site_state[3 + axis];
+        assert!((result.state.velocity_km_s[axis] - expected_v).abs() < 1e-12);
+    }
+    let distance = result.state.position_km.iter().map(|v| v * v).sum::<f64>();
+    assert!((result.horizontal.distance_km - distance.sqrt()).abs() < 1e-6);
+    cleanup(paths);
+}
+
+#[test]
+fn refraction_and_eop_are_applied() {
+    let (engine, paths) = synthetic_engine("eop");
+    let site = GeoLocation::new(40.0, -3.7, 650.0);
+    let plain = engine
+        .query_topocentric(
+            topo_query(POLE_TARGET, site),
+            None,
+            &TopocentricOptions::default(),
+        )
+        .unwrap();
+
+    let refracted_options = TopocentricOptions {
+        refraction: true,
+        ..Default::default()
+    };
+    let refracted = engine
+        .query_topocentric(topo_query(POLE_TARGET, site), None, &refracted_options)
+        .unwrap();
+    let expected = refraction_deg(plain.horizontal.altitude_deg, 1010.0, 10.0);
+    assert!((refracted.horizontal.refraction_deg - expected).abs() < 1e-12);
+    assert!(
+        (refracted.horizontal.altitude_deg - plain.horizontal.altitude_deg - expected).abs()
+            < 1e-12
+    );
+
+    // finals2000A rows around J2000 with DUT1 = 0.5 s and no polar motion.
+    let finals: Vec<String> = [51543.0, 51544.0, 51545.0]
+        .iter()
+        .map(|mjd| {
+            let mut line = vec![b' '; 70];
+            line[7..15].copy_from_slice(format!("{mjd:8.2}").as_bytes());
+            line[57] = b'I';
+            line[58..68].copy_from_slice(format!("{:10.7}", 0.5).as_bytes());
+            String::from_utf8(line).unwrap()
+        })
+        .collect();
+    let eop = EopKernel::parse(&finals.join("\n")).unwrap();
+    let with_eop = engine
+        .query_topocentric(
+            topo_query(POLE_TARGET, site),
+            Some(&eop),
+            &TopocentricOptions::default(),
+        )
+        .unwrap();
+    assert!((with_eop.jd_ut1 - plain.jd_ut1 - 0.5 / 86_400.0).abs() < 1e-9);
+
+    // A 1″ x_p shift moves the celestial pole's altitude by x_p·cos λ.
+    let finals: Vec<String> = finals
+        .iter()
+        .map(|line| {
+            let mut line = line.clone();
+            line.replace_range(18..27, &format!("{:9.6}", 1.0));
+            line
+        })
+        .collect();
+    let eop = EopKernel::parse(&finals.join("\n")).unwrap();
+    let shifted = engine
+        .query_topocentric(
+            topo_query(POLE_TARGET, site),
+            Some(&eop),
+            &TopocentricOptions::default(),
+        )
+        .unwrap();
+    let delta_arcsec =
+        (shifted.horizontal.altitude_deg - with_eop.horizontal.altitude_deg) * 3600.0;
+    let expected = site.longitude_rad().cos();
+    assert!((delta_arcsec - expected).abs() < 0.01, "{delta_arcsec}″");
+    cleanup(paths);
+}
+
+#[test]
+fn earth_target_is_rejected() {
+    let (engine, paths) = synthetic_engine("earth");
+    let err = engine
+        .query_topocentric(
+            topo_query(Body::Earth, GeoLocation::new(0.0, 0.0, 0.0)),
+            None,
+            &TopocentricOptions::default(),
+        )
+        .unwrap_err();
+    assert!(matches!(err, EngineError::UnsupportedQuery(_)));
+    cleanup(paths);
+}
+
+#[test]
+fn epoch_query_matches_jd_query() {
+    let (engine, paths) = synthetic_engine("epoch");
+    let options = TopocentricOptions::default();
+    let site = GeoLocation::new(28.6, 77.2, 216.0);
+
+    let query = topo_query(NEAR_TARGET, site);
+    let epoch_query = TopocentricEpochQuery::from(query);
+    assert_eq!(epoch_query.epoch, Epoch::from_jd_tdb(EPOCH));
+    assert_eq!(epoch_query.to_query(), query);
+
+    let from_jd = engine.query_topocentric(query, None, &options).unwrap();
+    let from_epoch = engine
+        .query_topocentric(epoch_query, None, &options)
+        .unwrap();
+    assert_eq!(from_jd, from_epoch);
+    cleanup(paths);
+}
@@ -6,15 +6,15 @@ use criterion::{
 use dhruv_core::{Body, Frame, Observer};
 use dhruv_ffi_c::{
     DHRUV_AYANAMSHA_MODE_MEAN, DHRUV_AYANAMSHA_MODE_TRUE, DHRUV_AYANAMSHA_MODE_UNIFIED,
-    DHRUV_AYANAMSHA_TIME_JD_TDB, DHRUV_AYANAMSHA_TIME_UTC, DHRUV_GRAHA_LONGITUDE_KIND_TROPICAL,
-    DHRUV_PANCHANG_INCLUDE_ALL, DHRUV_PANCHANG_TIME_UTC, DHRUV_QUERY_OUTPUT_SPHERICAL,
-    DHRUV_QUERY_TIME_UTC, DHRUV_TIME_POLICY_STRICT_LSK, DhruvAyanamshaComputeRequest,
-    DhruvBhinnaAshtakavarga, DhruvDrishtiEntry, DhruvEngineConfig, DhruvGrahaDrishtiMatrix,
-    DhruvGrahaLongitudes, DhruvKaranaPosition, DhruvNakshatra28Info, DhruvNakshatraInfo,
-    DhruvPanchangComputeRequest, DhruvPanchangNakshatraInfo, DhruvPanchangOperationResult,
-    DhruvQuery, DhruvQueryRequest, DhruvQueryResult, DhruvRashiInfo, DhruvSamvatsaraResult,
-    DhruvSarvaAshtakavarga, DhruvSphericalCoords, DhruvStateVector, DhruvStatus,
-    DhruvTimeConversionOptions, DhruvTimePolicy, DhruvTithiPosition, DhruvUtcTime,
+    DHRUV_AYANAMSHA_TIME_JD_TDB, DHRUV_AYANAMSHA_TIME_UTC, DHRUV_CORRECTION_GEOMETRIC,
+    DHRUV_GRAHA_LONGITUDE_KIND_TROPICAL, DHRUV_PANCHANG_INCLUDE_ALL, DHRUV_PANCHANG_TIME_UTC,
+    DHRUV_QUERY_OUTPUT_SPHERICAL, DHRUV_QUERY_TIME_UTC, DHRUV_TIME_POLICY_STRICT_LSK,
+    DhruvAyanamshaComputeRequest, DhruvBhinnaAshtakavarga, DhruvDrishtiEntry, DhruvEngineConfig,
+    DhruvGrahaDrishtiMatrix, DhruvGrahaLongitudes, DhruvKaranaPosition, DhruvNakshatra28Info,
+    DhruvNakshatraInfo, DhruvPanchangComputeRequest, DhruvPanchangNakshatraInfo,
+    DhruvPanchangOperationResult, DhruvQuery, DhruvQueryRequest, DhruvQueryResult, DhruvRashiInfo,
+    DhruvSamvatsaraResult, DhruvSarvaAshtakavarga, DhruvSphericalCoords, DhruvStateVector,
+    DhruvStatus, DhruvTimeConversionOptions, DhruvTimePolicy, DhruvTithiPosition, DhruvUtcTime,
     DhruvUtcToTdbRequest, DhruvUtcToTdbResult, DhruvYogaPosition,
     dhruv_ayana_from_sidereal_longitude, dhruv_ayanamsha_compute_ex, dhruv_calculate_all_bav,
     dhruv_calculate_bav, dhruv_calculate_sav, dhruv_cartesian_to_spherical,
@@ -141,6 +141,7 @@ fn load_context() -> Option<FfiContext> {
         observer: Observer::Body(Body::Earth).code(),
         frame: Frame::IcrfJ2000.code(),
         epoch_tdb_jd: 2_460_000.5,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
 
     Some(FfiContext {
@@ -200,6 +201,7 @@ fn ffi_core_cabi_bench(c: &mut Criterion) {
             second: 0.0,
         },
         output_mode: DHRUV_QUERY_OUTPUT_SPHERICAL,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
 
     let mut out_state: DhruvStateVector = zeroed();
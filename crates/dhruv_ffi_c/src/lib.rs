@@ -7,23 +7,24 @@ use std::sync::{LazyLock, RwLock};
 
 use dhruv_config::{ConfigResolver, DefaultsMode, load_with_discovery};
 use dhruv_core::{
-    Body, Engine, EngineConfig, EngineError, Frame, LoadedSpkInfo, Observer, Query,
-    SpkReplaceReport, StateVector,
+    Body, BodyRadii, Correction, Engine, EngineConfig, EngineError, EpochQuery, Frame,
+    LoadedSpkInfo, Observer, Query, SpkLoadMode, SpkReplaceReport, StateVector, TextKernel,
 };
-use dhruv_frames::PrecessionModel;
+use dhruv_frames::{NutationModel, PrecessionModel};
 use dhruv_search::{
     ChandraGrahan, ChandraGrahanType, ConjunctionConfig, ConjunctionEvent, GrahaLongitudeKind,
     GrahaLongitudesConfig, GrahanConfig, LunarPhase, MaxSpeedEvent, MaxSpeedType, SankrantiConfig,
-    SearchError, StationType, StationaryConfig, StationaryEvent, SuryaGrahan, SuryaGrahanType,
-    amsha_charts_for_date, avastha_for_date, ayana_for_date, balas_for_date, bhavabala_for_date,
-    body_ecliptic_lon_lat, charakaraka_for_date, dasha_child_period_with_inputs,
-    dasha_children_with_inputs, dasha_complete_level_with_inputs, dasha_hierarchy_with_inputs,
-    dasha_level0_entity_with_inputs, dasha_level0_with_inputs, dasha_snapshot_with_inputs,
-    elongation_at, full_kundali_for_date, ghatika_for_date, ghatika_from_sunrises,
-    graha_longitudes, hora_for_date, hora_from_sunrises, karana_at, karana_for_date, masa_for_date,
-    moving_osculating_apogees_for_date, nakshatra_at, nakshatra_for_date, next_amavasya,
-    next_chandra_grahan, next_conjunction, next_max_speed, next_purnima, next_sankranti,
-    next_specific_sankranti, next_stationary, next_surya_grahan, prev_amavasya,
+    SearchError, SearchInstant, StationType, StationaryConfig, StationaryEvent, SuryaGrahan,
+    SuryaGrahanType, amsha_charts_for_date, avastha_for_date, ayana_for_date, balas_for_date,
+    bhavabala_for_date, body_ecliptic_lon_lat, charakaraka_for_date,
+    dasha_child_period_with_inputs, dasha_children_with_inputs, dasha_complete_level_with_inputs,
+    dasha_hierarchy_with_inputs, dasha_level0_entity_with_inputs, dasha_level0_with_inputs,
+    dasha_snapshot_with_inputs, elongation_at, full_kundali_for_date, ghatika_for_date,
+    ghatika_from_sunrises, graha_longitudes, hora_for_date, hora_from_sunrises, karana_at,
+    karana_for_date, kp_chart_for_date, kundali_milan_for_dates, masa_for_date,
+    moving_osculating_apogees_for_date, muhurta_for_date, nakshatra_at, nakshatra_for_date,
+    next_amavasya, next_chandra_grahan, next_conjunction, next_max_speed, next_purnima,
+    next_sankranti, next_specific_sankranti, next_stationary, next_surya_grahan, prev_amavasya,
     prev_chandra_grahan, prev_conjunction, prev_max_speed, prev_purnima, prev_sankranti,
     prev_specific_sankranti, prev_stationary, prev_surya_grahan, search_amavasyas,
     search_chandra_grahan, search_conjunctions, search_max_speed, search_purnimas,
@@ -34,9 +35,9 @@ use dhruv_search::{
 };
 use dhruv_tara::{TaraAccuracy, TaraCatalog, TaraConfig, TaraError, TaraId};
 use dhruv_time::{
-    DeltaTModel, DeltaTSegment, FutureDeltaTTransition, SmhFutureParabolaFamily,
-    TimeConversionOptions, TimeConversionPolicy, TimeDiagnostics, TimeWarning, TtUtcSource,
-    UtcTime,
+    DeltaTModel, DeltaTSegment, Disambiguation, Epoch, FutureDeltaTTransition, LocalTime,
+    SmhFutureParabolaFamily, TimeConversionOptions, TimeConversionPolicy, TimeDiagnostics,
+    TimeWarning, TimeZone, TtUtcSource, UtcTime,
 };
 use dhruv_vedic_base::dasha::RashiDashaInputs;
 use dhruv_vedic_base::{
@@ -61,7 +62,7 @@ use dhruv_vedic_ops::{
 };
 
 /// ABI version for downstream bindings.
-pub const DHRUV_API_VERSION: u32 = 70;
+pub const DHRUV_API_VERSION: u32 = 77;
 
 /// Fixed UTF-8 buffer size for path fields in C-compatible structs.
 pub const DHRUV_PATH_CAPACITY: usize = 512;
@@ -119,9 +120,17 @@ pub const DHRUV_PRECESSION_MODEL_LIESKE1977: i32 = 1;
 pub const DHRUV_PRECESSION_MODEL_IAU2006: i32 = 2;
 pub const DHRUV_PRECESSION_MODEL_VONDRAK2011: i32 = 3;
 
+pub const DHRUV_NUTATION_MODEL_IAU2000B: i32 = 0;
+pub const DHRUV_NUTATION_MODEL_IAU1980: i32 = 1;
+
 pub const DHRUV_GRAHA_LONGITUDE_KIND_SIDEREAL: i32 = 0;
 pub const DHRUV_GRAHA_LONGITUDE_KIND_TROPICAL: i32 = 1;
 
+pub const DHRUV_CORRECTION_GEOMETRIC: i32 = 0;
+pub const DHRUV_CORRECTION_LIGHT_TIME: i32 = 1;
+pub const DHRUV_CORRECTION_LIGHT_TIME_ABERRATION: i32 = 2;
+pub const DHRUV_CORRECTION_APPARENT: i32 = 3;
+
 pub const DHRUV_NAISARGIKA_FRIEND: i32 = 0;
 pub const DHRUV_NAISARGIKA_ENEMY: i32 = 1;
 pub const DHRUV_NAISARGIKA_NEUTRAL: i32 = 2;
@@ -168,6 +177,7 @@ impl From<&EngineError> for DhruvStatus {
             EngineError::TimeConversion(_) => Self::TimeConversion,
             EngineError::UnsupportedQuery(_) => Self::UnsupportedQuery,
             EngineError::EpochOutOfRange { .. } => Self::EpochOutOfRange,
+            EngineError::UnresolvedBody { .. } => Self::InvalidQuery,
             EngineError::Internal(_) => Self::Internal,
             _ => Self::Internal,
         }
@@ -464,6 +474,7 @@ impl TryFrom<&DhruvEngineConfig> for EngineConfig {
             lsk_path: PathBuf::from(lsk_path),
             cache_capacity,
             strict_validation: value.strict_validation != 0,
+            spk_load_mode: SpkLoadMode::InMemory,
         })
     }
 }
@@ -494,6 +505,7 @@ fn loaded_spk_info_to_ffi(info: &LoadedSpkInfo) -> Result<DhruvLoadedSpkInfo, Dh
     })
 }
 
+#[allow(clippy::field_reassign_with_default)]
 fn loaded_spk_list_to_ffi(infos: &[LoadedSpkInfo]) -> Result<DhruvLoadedSpkList, DhruvStatus> {
     if infos.len() > DHRUV_MAX_SPK_PATHS {
         return Err(DhruvStatus::InvalidConfig);
@@ -518,6 +530,22 @@ pub struct DhruvQuery {
     pub observer: i32,
     pub frame: i32,
     pub epoch_tdb_jd: f64,
+    /// `DHRUV_CORRECTION_*`
+    pub correction: i32,
+}
+
+/// C-compatible query at a two-part TDB Julian Date (`jd_tdb_day +
+/// jd_tdb_frac`, any split), keeping the precision a single `f64` JD loses.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvEpochQuery {
+    pub target: i32,
+    pub observer: i32,
+    pub frame: i32,
+    pub jd_tdb_day: f64,
+    pub jd_tdb_frac: f64,
+    /// `DHRUV_CORRECTION_*`
+    pub correction: i32,
 }
 
 pub const DHRUV_QUERY_TIME_JD_TDB: i32 = 0;
@@ -548,12 +576,19 @@ pub const DHRUV_SMH_FUTURE_FAMILY_STEPHENSON_2016: i32 = 5;
 
 pub const DHRUV_TT_UTC_SOURCE_LSK_DELTA_AT: i32 = 0;
 pub const DHRUV_TT_UTC_SOURCE_DELTA_T_MODEL: i32 = 1;
+pub const DHRUV_TT_UTC_SOURCE_UTC_DRIFT_TABLE: i32 = 2;
 
 pub const DHRUV_TIME_WARNING_LSK_FUTURE_FROZEN: i32 = 0;
 pub const DHRUV_TIME_WARNING_LSK_PRE_RANGE_FALLBACK: i32 = 1;
 pub const DHRUV_TIME_WARNING_EOP_FUTURE_FROZEN: i32 = 2;
 pub const DHRUV_TIME_WARNING_EOP_PRE_RANGE_FALLBACK: i32 = 3;
 pub const DHRUV_TIME_WARNING_DELTA_T_MODEL_USED: i32 = 4;
+pub const DHRUV_TIME_WARNING_UTC_DRIFT_TABLE_USED: i32 = 5;
+pub const DHRUV_TIME_WARNING_CIVIL_TIME_AS_UT1: i32 = 6;
+
+pub const DHRUV_LOCAL_DISAMBIGUATION_REJECT: i32 = 0;
+pub const DHRUV_LOCAL_DISAMBIGUATION_EARLIER: i32 = 1;
+pub const DHRUV_LOCAL_DISAMBIGUATION_LATER: i32 = 2;
 
 pub const DHRUV_DELTA_T_SEGMENT_PRE_MINUS720_QUADRATIC: i32 = 0;
 pub const DHRUV_DELTA_T_SEGMENT_SMH2016_RECONSTRUCTION: i32 = 1;
@@ -587,24 +622,46 @@ pub struct DhruvQueryRequest {
     pub epoch_tdb_jd: f64,
     pub utc: DhruvUtcTime,
     pub output_mode: i32,
+    /// `DHRUV_CORRECTION_*`
+    pub correction: i32,
 }
 
 impl TryFrom<DhruvQuery> for Query {
     type Error = EngineError;
 
     fn try_from(value: DhruvQuery) -> Result<Self, Self::Error> {
-        let target = Body::from_code(value.target)
-            .ok_or(EngineError::InvalidQuery("target code is unsupported"))?;
-        let observer = Observer::from_code(value.observer)
-            .ok_or(EngineError::InvalidQuery("observer code is unsupported"))?;
+        let target = Body::naif(value.target);
+        let observer = Observer::naif(value.observer);
         let frame = Frame::from_code(value.frame)
             .ok_or(EngineError::InvalidQuery("frame code is unsupported"))?;
+        let correction = Correction::from_code(value.correction)
+            .ok_or(EngineError::InvalidQuery("correction code is unsupported"))?;
 
         Ok(Query {
             target,
             observer,
             frame,
             epoch_tdb_jd: value.epoch_tdb_jd,
+            correction,
+        })
+    }
+}
+
+impl TryFrom<DhruvEpochQuery> for EpochQuery {
+    type Error = EngineError;
+
+    fn try_from(value: DhruvEpochQuery) -> Result<Self, Self::Error> {
+        let frame = Frame::from_code(value.frame)
+            .ok_or(EngineError::InvalidQuery("frame code is unsupported"))?;
+        let correction = Correction::from_code(value.correction)
+            .ok_or(EngineError::InvalidQuery("correction code is unsupported"))?;
+
+        Ok(EpochQuery {
+            target: Body::naif(value.target),
+            observer: Observer::naif(value.observer),
+            frame,
+            epoch: Epoch::from_jd_tdb_parts(value.jd_tdb_day, value.jd_tdb_frac),
+            correction,
         })
     }
 }
@@ -783,6 +840,7 @@ fn time_policy_from_ffi(policy: DhruvTimePolicy) -> Result<TimeConversionPolicy,
                 future_transition_years: policy.options.future_transition_years,
                 smh_future_family: smh_future_family_from_code(policy.options.smh_future_family)
                     .ok_or(DhruvStatus::InvalidInput)?,
+                ..Default::default()
             }))
         }
         _ => Err(DhruvStatus::InvalidInput),
@@ -860,6 +918,20 @@ fn time_warning_to_ffi(warning: &TimeWarning) -> DhruvTimeWarning {
             delta_t_segment: delta_t_segment_to_code(*segment),
             ..empty_time_warning()
         },
+        TimeWarning::UtcDriftTableUsed {
+            utc_seconds,
+            tai_minus_utc_seconds,
+        } => DhruvTimeWarning {
+            kind: DHRUV_TIME_WARNING_UTC_DRIFT_TABLE_USED,
+            utc_seconds: *utc_seconds,
+            used_delta_at_seconds: *tai_minus_utc_seconds,
+            ..empty_time_warning()
+        },
+        TimeWarning::CivilTimeAsUt1 { utc_seconds } => DhruvTimeWarning {
+            kind: DHRUV_TIME_WARNING_CIVIL_TIME_AS_UT1,
+            utc_seconds: *utc_seconds,
+            ..empty_time_warning()
+        },
     }
 }
 
@@ -876,6 +948,7 @@ fn time_diagnostics_to_ffi(diagnostics: &TimeDiagnostics) -> DhruvTimeDiagnostic
         source: match diagnostics.source {
             TtUtcSource::LskDeltaAt => DHRUV_TT_UTC_SOURCE_LSK_DELTA_AT,
             TtUtcSource::DeltaTModel => DHRUV_TT_UTC_SOURCE_DELTA_T_MODEL,
+            TtUtcSource::UtcDriftTable => DHRUV_TT_UTC_SOURCE_UTC_DRIFT_TABLE,
         },
         tt_minus_utc_s: diagnostics.tt_minus_utc_s,
         warning_count: warning_count as u32,
@@ -922,10 +995,17 @@ fn riseset_config_from_ffi(cfg: &DhruvRiseSetConfig) -> Result<RiseSetConfig, Dh
         Some(l) => l,
         None => return Err(DhruvStatus::InvalidQuery),
     };
+    let nominal = BodyRadii::default();
+    let or_nominal = |km: f64, default: f64| if km > 0.0 { km } else { default };
     Ok(RiseSetConfig {
         use_refraction: cfg.use_refraction != 0,
         sun_limb,
         altitude_correction: cfg.altitude_correction != 0,
+        radii: BodyRadii {
+            sun_km: or_nominal(cfg.sun_radius_km, nominal.sun_km),
+            earth_mean_km: or_nominal(cfg.earth_mean_radius_km, nominal.earth_mean_km),
+            ..nominal
+        },
     })
 }
 
@@ -1184,6 +1264,18 @@ pub fn dhruv_engine_query_internal(
     Ok(DhruvStateVector::from(state))
 }
 
+/// Query the engine at a two-part TDB Julian Date.
+pub fn dhruv_engine_query_epoch_internal(
+    engine: &Engine,
+    query: DhruvEpochQuery,
+) -> Result<DhruvStateVector, DhruvStatus> {
+    let core_query = EpochQuery::try_from(query).map_err(|err| DhruvStatus::from(&err))?;
+    let state = engine
+        .query(core_query)
+        .map_err(|err| DhruvStatus::from(&err))?;
+    Ok(DhruvStateVector::from(state))
+}
+
 fn spherical_state_from_state(state: &StateVector) -> DhruvSphericalState {
     let ss =
         dhruv_frames::cartesian_state_to_spherical_state(&state.position_km, &state.velocity_km_s);
@@ -1211,9 +1303,10 @@ fn validate_query_request_selectors(request: DhruvQueryRequest) -> Result<(), Dh
 
 fn query_from_request(engine: &Engine, request: DhruvQueryRequest) -> Result<Query, DhruvStatus> {
     validate_query_request_selectors(request)?;
-    let target = Body::from_code(request.target).ok_or(DhruvStatus::InvalidQuery)?;
-    let observer = Observer::from_code(request.observer).ok_or(DhruvStatus::InvalidQuery)?;
+    let target = Body::naif(request.target);
+    let observer = Observer::naif(request.observer);
     let frame = Frame::from_code(request.frame).ok_or(DhruvStatus::InvalidQuery)?;
+    let correction = Correction::from_code(request.correction).ok_or(DhruvStatus::InvalidQuery)?;
     let epoch_tdb_jd = match request.time_kind {
         DHRUV_QUERY_TIME_JD_TDB => request.epoch_tdb_jd,
         DHRUV_QUERY_TIME_UTC => dhruv_time::Epoch::from_utc(
@@ -1234,6 +1327,7 @@ fn query_from_request(engine: &Engine, request: DhruvQueryRequest) -> Result<Que
         observer,
         frame,
         epoch_tdb_jd,
+        correction,
     })
 }
 
@@ -1463,6 +1557,37 @@ pub unsafe extern "C" fn dhruv_engine_query(
     })
 }
 
+/// Query an existing engine handle at a two-part TDB Julian Date.
+///
+/// # Safety
+/// `engine`, `query`, and `out_state` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_engine_query_epoch(
+    engine: *const DhruvEngineHandle,
+    query: *const DhruvEpochQuery,
+    out_state: *mut DhruvStateVector,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || query.is_null() || out_state.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+
+        // SAFETY: Pointers are checked for null and only borrowed for this call.
+        let engine_ref = unsafe { &*engine };
+        // SAFETY: Pointer is checked for null and copied by value.
+        let query_value = unsafe { *query };
+
+        match dhruv_engine_query_epoch_internal(engine_ref, query_value) {
+            Ok(state) => {
+                // SAFETY: Pointer is checked for null and written once.
+                unsafe { *out_state = state };
+                DhruvStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
 /// Query an existing engine handle using the unified request transport.
 ///
 /// # Safety
@@ -1893,6 +2018,10 @@ pub struct DhruvRiseSetConfig {
     pub sun_limb: i32,
     /// Apply altitude dip correction: 1 = true, 0 = false.
     pub altitude_correction: u8,
+    /// Solar radius for the semidiameter, km (0 = IAU nominal).
+    pub sun_radius_km: f64,
+    /// Earth mean radius for the horizon dip, km (0 = IAU nominal).
+    pub earth_mean_radius_km: f64,
 }
 
 /// Sun limb: upper limb defines sunrise/sunset (conventional).
@@ -1990,9 +2119,51 @@ pub extern "C" fn dhruv_riseset_config_default() -> DhruvRiseSetConfig {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_UPPER,
         altitude_correction: 1,
+        sun_radius_km: 0.0,
+        earth_mean_radius_km: 0.0,
     }
 }
 
+/// Fill the radii of a rise/set configuration from a text PCK.
+///
+/// Sets `sun_radius_km` and `earth_mean_radius_km` from the kernel's
+/// `BODY10_RADII` / `BODY399_RADII`; a body the kernel omits keeps the
+/// IAU nominal value. Other fields are left unchanged.
+///
+/// # Safety
+/// `tpc_path_utf8` must be a valid, non-null, NUL-terminated C string.
+/// `config` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_riseset_config_load_tpc(
+    tpc_path_utf8: *const c_char,
+    config: *mut DhruvRiseSetConfig,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if tpc_path_utf8.is_null() || config.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+
+        // SAFETY: Pointer is checked for null; read until NUL byte.
+        let c_str = unsafe { std::ffi::CStr::from_ptr(tpc_path_utf8) };
+        let path_str = match c_str.to_str() {
+            Ok(s) => s,
+            Err(_) => return DhruvStatus::InvalidConfig,
+        };
+
+        match TextKernel::load(std::path::Path::new(path_str)) {
+            Ok(kernel) => {
+                let radii = BodyRadii::from_text_kernel(&kernel);
+                // SAFETY: Pointer is checked for null; update two fields.
+                let cfg = unsafe { &mut *config };
+                cfg.sun_radius_km = radii.sun_km;
+                cfg.earth_mean_radius_km = radii.earth_mean_km;
+                DhruvStatus::Ok
+            }
+            Err(_) => DhruvStatus::KernelLoad,
+        }
+    })
+}
+
 /// Convert C sun_limb code to Rust SunLimb enum.
 fn sun_limb_from_code(code: i32) -> Option<SunLimb> {
     match code {
@@ -2280,6 +2451,16 @@ pub struct DhruvUtcTime {
     pub second: f64,
 }
 
+/// Two-part TDB epoch: seconds past J2000.0 = `whole_seconds + fraction`.
+///
+/// Outputs keep `fraction` in `[0, 1)`; inputs accept any split.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvEpoch {
+    pub whole_seconds: f64,
+    pub fraction: f64,
+}
+
 /// Extract hour, minute, second from a fractional day.
 fn fractional_day_to_hms(day_frac: f64) -> (u32, u32, f64) {
     let frac = day_frac.fract();
@@ -2330,6 +2511,64 @@ pub unsafe extern "C" fn dhruv_jd_tdb_to_utc(
     })
 }
 
+/// Convert a local civil date-time string to UTC calendar components.
+///
+/// `local_iso_utf8` takes the forms accepted by `LocalTime::from_str`:
+/// `Z`, a `+hh:mm` offset, an IANA zone in brackets (`[Asia/Kolkata]`), an
+/// offset followed by a zone, or `[LMT+77.2]` for local mean time. A
+/// string without any designator is read as UTC. `disambiguation` is one
+/// of `DHRUV_LOCAL_DISAMBIGUATION_*` and decides repeated wall-clock times
+/// not already settled by an offset in the string; times skipped by a
+/// transition are rejected with `InvalidInput` unless an offset is given.
+/// `out_utc_offset_seconds` may be null.
+///
+/// # Safety
+/// `local_iso_utf8` must be a valid NUL-terminated string and `out_utc` a
+/// valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_local_time_to_utc(
+    local_iso_utf8: *const c_char,
+    disambiguation: i32,
+    out_utc: *mut DhruvUtcTime,
+    out_utc_offset_seconds: *mut i32,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if local_iso_utf8.is_null() || out_utc.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let disambiguation = match disambiguation {
+            DHRUV_LOCAL_DISAMBIGUATION_REJECT => Disambiguation::Reject,
+            DHRUV_LOCAL_DISAMBIGUATION_EARLIER => Disambiguation::Earlier,
+            DHRUV_LOCAL_DISAMBIGUATION_LATER => Disambiguation::Later,
+            _ => return DhruvStatus::InvalidInput,
+        };
+        // SAFETY: Pointer checked for null; caller guarantees NUL termination.
+        let c_str = unsafe { CStr::from_ptr(local_iso_utf8) };
+        let Ok(text) = c_str.to_str() else {
+            return DhruvStatus::InvalidInput;
+        };
+        // Reject keeps an offset written in the string as the tie-breaker.
+        let resolved = LocalTime::parse_with_default_zone(text, &TimeZone::utc())
+            .map(|local| match disambiguation {
+                Disambiguation::Reject => local,
+                other => local.with_disambiguation(other),
+            })
+            .and_then(|local| local.resolve_with_type());
+        let Ok(resolved) = resolved else {
+            return DhruvStatus::InvalidInput;
+        };
+
+        // SAFETY: Pointers checked for null (offset is optional).
+        unsafe {
+            *out_utc = utc_time_to_ffi(&resolved.utc);
+            if !out_utc_offset_seconds.is_null() {
+                *out_utc_offset_seconds = resolved.local_time_type.utc_offset_seconds;
+            }
+        }
+        DhruvStatus::Ok
+    })
+}
+
 /// Convert a rise/set result to UTC calendar components.
 ///
 /// Only valid when `result->result_type == DHRUV_RISESET_EVENT`.
@@ -2583,6 +2822,7 @@ fn bhava_result_to_ffi_with_projection(
     }
 }
 
+#[allow(clippy::needless_range_loop)]
 fn rashi_bhava_ffi_from_lagna(lagna_deg: f64) -> ([DhruvBhava; 12], f64, f64) {
     let lagna = lagna_deg.rem_euclid(360.0);
     let lagna_rashi = (lagna / 30.0).floor() as u8;
@@ -3229,47 +3469,59 @@ pub const DHRUV_CONJUNCTION_QUERY_MODE_RANGE: i32 = 2;
 pub const DHRUV_SEARCH_TIME_JD_TDB: i32 = 0;
 /// Search time input selector: Gregorian UTC.
 pub const DHRUV_SEARCH_TIME_UTC: i32 = 1;
+/// Search time input selector: two-part TDB epoch (`DhruvEpoch`).
+pub const DHRUV_SEARCH_TIME_EPOCH: i32 = 2;
 
 fn validate_search_time_kind(time_kind: i32) -> Result<(), DhruvStatus> {
     match time_kind {
-        DHRUV_SEARCH_TIME_JD_TDB | DHRUV_SEARCH_TIME_UTC => Ok(()),
+        DHRUV_SEARCH_TIME_JD_TDB | DHRUV_SEARCH_TIME_UTC | DHRUV_SEARCH_TIME_EPOCH => Ok(()),
         _ => Err(DhruvStatus::InvalidQuery),
     }
 }
 
-fn search_time_to_jd_tdb(
+fn search_time_to_epoch(
     engine: &Engine,
     time_kind: i32,
     jd_tdb: f64,
     utc: DhruvUtcTime,
-) -> Result<f64, DhruvStatus> {
+    epoch: DhruvEpoch,
+) -> Result<Epoch, DhruvStatus> {
     validate_search_time_kind(time_kind)?;
     match time_kind {
-        DHRUV_SEARCH_TIME_JD_TDB => Ok(jd_tdb),
-        DHRUV_SEARCH_TIME_UTC => Ok(dhruv_time::Epoch::from_utc(
-            utc.year,
-            utc.month,
-            utc.day,
-            utc.hour,
-            utc.minute,
-            utc.second,
-            engine.lsk(),
-        )
-        .as_jd_tdb()),
+        DHRUV_SEARCH_TIME_JD_TDB => Ok(Epoch::from_jd_tdb(jd_tdb)),
+        DHRUV_SEARCH_TIME_UTC => Ok(ffi_to_utc_time(&utc).to_epoch(engine.lsk())),
+        DHRUV_SEARCH_TIME_EPOCH => Ok(ffi_to_epoch(&epoch)),
         _ => unreachable!("validated above"),
     }
 }
 
-fn search_time_to_utc(
-    engine: &Engine,
+/// Search input resolved from a request; UTC is left for the search's own
+/// time-conversion policy.
+enum SearchTime {
+    Tdb(Epoch),
+    Utc(UtcTime),
+}
+
+impl SearchInstant for SearchTime {
+    fn to_epoch(&self, engine: &Engine) -> Epoch {
+        match self {
+            Self::Tdb(epoch) => *epoch,
+            Self::Utc(utc) => SearchInstant::to_epoch(utc, engine),
+        }
+    }
+}
+
+fn search_time_to_instant(
     time_kind: i32,
     jd_tdb: f64,
     utc: DhruvUtcTime,
-) -> Result<UtcTime, DhruvStatus> {
+    epoch: DhruvEpoch,
+) -> Result<SearchTime, DhruvStatus> {
     validate_search_time_kind(time_kind)?;
     match time_kind {
-        DHRUV_SEARCH_TIME_JD_TDB => Ok(UtcTime::from_jd_tdb(jd_tdb, engine.lsk())),
-        DHRUV_SEARCH_TIME_UTC => Ok(ffi_to_utc_time(&utc)),
+        DHRUV_SEARCH_TIME_JD_TDB => Ok(SearchTime::Tdb(Epoch::from_jd_tdb(jd_tdb))),
+        DHRUV_SEARCH_TIME_UTC => Ok(SearchTime::Utc(ffi_to_utc_time(&utc))),
+        DHRUV_SEARCH_TIME_EPOCH => Ok(SearchTime::Tdb(ffi_to_epoch(&epoch))),
         _ => unreachable!("validated above"),
     }
 }
@@ -3298,6 +3550,12 @@ pub struct DhruvConjunctionSearchRequest {
     pub start_utc: DhruvUtcTime,
     /// End of range window for range mode (UTC).
     pub end_utc: DhruvUtcTime,
+    /// Anchor time for next/prev modes (two-part TDB epoch).
+    pub at_epoch: DhruvEpoch,
+    /// Start of range window for range mode (two-part TDB epoch).
+    pub start_epoch: DhruvEpoch,
+    /// End of range window for range mode (two-part TDB epoch).
+    pub end_epoch: DhruvEpoch,
     /// Conjunction search configuration.
     pub config: DhruvConjunctionConfig,
 }
@@ -3310,6 +3568,8 @@ pub struct DhruvConjunctionEvent {
     pub jd_tdb: f64,
     /// Event time as structured Gregorian UTC.
     pub utc: DhruvUtcTime,
+    /// Event time as a two-part TDB epoch (full refinement precision).
+    pub epoch: DhruvEpoch,
     /// Actual ecliptic longitude separation at peak, in degrees.
     pub actual_separation_deg: f64,
     /// Body 1 ecliptic longitude in degrees.
@@ -3331,6 +3591,7 @@ impl From<&ConjunctionEvent> for DhruvConjunctionEvent {
         Self {
             jd_tdb: e.jd_tdb,
             utc: utc_time_to_ffi(&e.utc),
+            epoch: epoch_to_ffi(e.epoch),
             actual_separation_deg: e.actual_separation_deg,
             body1_longitude_deg: e.body1_longitude_deg,
             body2_longitude_deg: e.body2_longitude_deg,
@@ -3410,11 +3671,12 @@ pub unsafe extern "C" fn dhruv_conjunction_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3438,11 +3700,12 @@ pub unsafe extern "C" fn dhruv_conjunction_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3466,20 +3729,22 @@ pub unsafe extern "C" fn dhruv_conjunction_search_ex(
                 if out_events.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_jd_tdb(
+                let start = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_jd_tdb(
+                let end = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3571,6 +3836,12 @@ pub struct DhruvGrahanSearchRequest {
     pub start_utc: DhruvUtcTime,
     /// End of range window for range mode (UTC).
     pub end_utc: DhruvUtcTime,
+    /// Anchor time for next/prev modes (two-part TDB epoch).
+    pub at_epoch: DhruvEpoch,
+    /// Start of range window for range mode (two-part TDB epoch).
+    pub start_epoch: DhruvEpoch,
+    /// End of range window for range mode (two-part TDB epoch).
+    pub end_epoch: DhruvEpoch,
     /// Grahan search configuration.
     pub config: DhruvGrahanConfig,
 }
@@ -3588,6 +3859,7 @@ fn grahan_config_from_ffi(cfg: &DhruvGrahanConfig) -> GrahanConfig {
     GrahanConfig {
         include_penumbral: cfg.include_penumbral != 0,
         include_peak_details: cfg.include_peak_details != 0,
+        ..Default::default()
     }
 }
 
@@ -3626,30 +3898,44 @@ pub struct DhruvChandraGrahanResult {
     pub greatest_grahan_jd: f64,
     /// Time of greatest grahan as structured Gregorian UTC.
     pub greatest_grahan_utc: DhruvUtcTime,
+    /// Time of greatest grahan as a two-part TDB epoch.
+    pub greatest_grahan_epoch: DhruvEpoch,
     /// P1: First penumbral contact (JD TDB).
     pub p1_jd: f64,
     /// P1 as structured Gregorian UTC.
     pub p1_utc: DhruvUtcTime,
+    /// P1 as a two-part TDB epoch.
+    pub p1_epoch: DhruvEpoch,
     /// U1: First umbral contact (JD TDB). -1.0 if absent.
     pub u1_jd: f64,
     /// U1 as structured Gregorian UTC. Zeroed if absent.
     pub u1_utc: DhruvUtcTime,
+    /// U1 as a two-part TDB epoch. Zeroed if absent.
+    pub u1_epoch: DhruvEpoch,
     /// U2: Start of totality (JD TDB). -1.0 if absent.
     pub u2_jd: f64,
     /// U2 as structured Gregorian UTC. Zeroed if absent.
     pub u2_utc: DhruvUtcTime,
+    /// U2 as a two-part TDB epoch. Zeroed if absent.
+    pub u2_epoch: DhruvEpoch,
     /// U3: End of totality (JD TDB). -1.0 if absent.
     pub u3_jd: f64,
     /// U3 as structured Gregorian UTC. Zeroed if absent.
     pub u3_utc: DhruvUtcTime,
+    /// U3 as a two-part TDB epoch. Zeroed if absent.
+    pub u3_epoch: DhruvEpoch,
     /// U4: Last umbral contact (JD TDB). -1.0 if absent.
     pub u4_jd: f64,
     /// U4 as structured Gregorian UTC. Zeroed if absent.
     pub u4_utc: DhruvUtcTime,
+    /// U4 as a two-part TDB epoch. Zeroed if absent.
+    pub u4_epoch: DhruvEpoch,
     /// P4: Last penumbral contact (JD TDB).
     pub p4_jd: f64,
     /// P4 as structured Gregorian UTC.
     pub p4_utc: DhruvUtcTime,
+    /// P4 as a two-part TDB epoch.
+    pub p4_epoch: DhruvEpoch,
     /// Moon's ecliptic latitude at greatest grahan, in degrees.
     pub moon_ecliptic_lat_deg: f64,
     /// Angular separation at greatest grahan, in degrees.
@@ -3664,30 +3950,37 @@ impl From<&ChandraGrahan> for DhruvChandraGrahanResult {
             penumbral_magnitude: e.penumbral_magnitude,
             greatest_grahan_jd: e.greatest_grahan_jd,
             greatest_grahan_utc: utc_time_to_ffi(&e.greatest_grahan_utc),
+            greatest_grahan_epoch: epoch_to_ffi(e.greatest_grahan_epoch),
             p1_jd: e.p1_jd,
             p1_utc: utc_time_to_ffi(&e.p1_utc),
+            p1_epoch: epoch_to_ffi(e.p1_epoch),
             u1_jd: option_jd(e.u1_jd),
             u1_utc: e
                 .u1_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            u1_epoch: e.u1_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             u2_jd: option_jd(e.u2_jd),
             u2_utc: e
                 .u2_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            u2_epoch: e.u2_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             u3_jd: option_jd(e.u3_jd),
             u3_utc: e
                 .u3_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            u3_epoch: e.u3_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             u4_jd: option_jd(e.u4_jd),
             u4_utc: e
                 .u4_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            u4_epoch: e.u4_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             p4_jd: e.p4_jd,
             p4_utc: utc_time_to_ffi(&e.p4_utc),
+            p4_epoch: epoch_to_ffi(e.p4_epoch),
             moon_ecliptic_lat_deg: e.moon_ecliptic_lat_deg,
             angular_separation_deg: e.angular_separation_deg,
         }
@@ -3706,22 +3999,32 @@ pub struct DhruvSuryaGrahanResult {
     pub greatest_grahan_jd: f64,
     /// Time of greatest grahan as structured Gregorian UTC.
     pub greatest_grahan_utc: DhruvUtcTime,
+    /// Time of greatest grahan as a two-part TDB epoch.
+    pub greatest_grahan_epoch: DhruvEpoch,
     /// C1: First external contact (JD TDB). -1.0 if absent.
     pub c1_jd: f64,
     /// C1 as structured Gregorian UTC. Zeroed if absent.
     pub c1_utc: DhruvUtcTime,
+    /// C1 as a two-part TDB epoch. Zeroed if absent.
+    pub c1_epoch: DhruvEpoch,
     /// C2: First internal contact (JD TDB). -1.0 if absent.
     pub c2_jd: f64,
     /// C2 as structured Gregorian UTC. Zeroed if absent.
     pub c2_utc: DhruvUtcTime,
+    /// C2 as a two-part TDB epoch. Zeroed if absent.
+    pub c2_epoch: DhruvEpoch,
     /// C3: Last internal contact (JD TDB). -1.0 if absent.
     pub c3_jd: f64,
     /// C3 as structured Gregorian UTC. Zeroed if absent.
     pub c3_utc: DhruvUtcTime,
+    /// C3 as a two-part TDB epoch. Zeroed if absent.
+    pub c3_epoch: DhruvEpoch,
     /// C4: Last external contact (JD TDB). -1.0 if absent.
     pub c4_jd: f64,
     /// C4 as structured Gregorian UTC. Zeroed if absent.
     pub c4_utc: DhruvUtcTime,
+    /// C4 as a two-part TDB epoch. Zeroed if absent.
+    pub c4_epoch: DhruvEpoch,
     /// Moon's ecliptic latitude at greatest grahan, in degrees.
     pub moon_ecliptic_lat_deg: f64,
     /// Angular separation at greatest grahan, in degrees.
@@ -3735,26 +4038,31 @@ impl From<&SuryaGrahan> for DhruvSuryaGrahanResult {
             magnitude: e.magnitude,
             greatest_grahan_jd: e.greatest_grahan_jd,
             greatest_grahan_utc: utc_time_to_ffi(&e.greatest_grahan_utc),
+            greatest_grahan_epoch: epoch_to_ffi(e.greatest_grahan_epoch),
             c1_jd: option_jd(e.c1_jd),
             c1_utc: e
                 .c1_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            c1_epoch: e.c1_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             c2_jd: option_jd(e.c2_jd),
             c2_utc: e
                 .c2_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            c2_epoch: e.c2_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             c3_jd: option_jd(e.c3_jd),
             c3_utc: e
                 .c3_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            c3_epoch: e.c3_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             c4_jd: option_jd(e.c4_jd),
             c4_utc: e
                 .c4_utc
                 .map(|utc| utc_time_to_ffi(&utc))
                 .unwrap_or(ZEROED_UTC),
+            c4_epoch: e.c4_epoch.map(epoch_to_ffi).unwrap_or(ZEROED_EPOCH),
             moon_ecliptic_lat_deg: e.moon_ecliptic_lat_deg,
             angular_separation_deg: e.angular_separation_deg,
         }
@@ -3805,11 +4113,12 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_chandra_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3833,11 +4142,12 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_chandra_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3861,20 +4171,22 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_chandra_many.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_jd_tdb(
+                let start = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_jd_tdb(
+                let end = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3898,11 +4210,12 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_surya_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3926,11 +4239,12 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_surya_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -3954,20 +4268,22 @@ pub unsafe extern "C" fn dhruv_grahan_search_ex(
                 if out_surya_many.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_jd_tdb(
+                let start = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_jd_tdb(
+                let end = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4056,6 +4372,12 @@ pub struct DhruvMotionSearchRequest {
     pub start_utc: DhruvUtcTime,
     /// End of range window for range mode (UTC).
     pub end_utc: DhruvUtcTime,
+    /// Anchor time for next/prev modes (two-part TDB epoch).
+    pub at_epoch: DhruvEpoch,
+    /// Start of range window for range mode (two-part TDB epoch).
+    pub start_epoch: DhruvEpoch,
+    /// End of range window for range mode (two-part TDB epoch).
+    pub end_epoch: DhruvEpoch,
     /// Search configuration.
     pub config: DhruvStationaryConfig,
 }
@@ -4207,11 +4529,12 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_stationary_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4235,11 +4558,12 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_stationary_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4263,20 +4587,22 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_stationary_many.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_jd_tdb(
+                let start = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_jd_tdb(
+                let end = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4300,11 +4626,12 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_max_speed_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4328,11 +4655,12 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_max_speed_single.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_jd_tdb(
+                let at = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4356,20 +4684,22 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
                 if out_max_speed_many.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_jd_tdb(
+                let start = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_jd_tdb(
+                let end = match search_time_to_epoch(
                     engine_ref,
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
@@ -4867,6 +5197,12 @@ pub struct DhruvLunarPhaseSearchRequest {
     pub start_utc: DhruvUtcTime,
     /// End of range window for range mode (UTC).
     pub end_utc: DhruvUtcTime,
+    /// Anchor time for next/prev modes (two-part TDB epoch).
+    pub at_epoch: DhruvEpoch,
+    /// Start of range window for range mode (two-part TDB epoch).
+    pub start_epoch: DhruvEpoch,
+    /// End of range window for range mode (two-part TDB epoch).
+    pub end_epoch: DhruvEpoch,
 }
 
 /// C-compatible lunar phase event.
@@ -4874,6 +5210,8 @@ pub struct DhruvLunarPhaseSearchRequest {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvLunarPhaseEvent {
     pub utc: DhruvUtcTime,
+    /// Event time as a two-part TDB epoch (full refinement precision).
+    pub epoch: DhruvEpoch,
     /// Phase code (see DHRUV_LUNAR_PHASE_* constants).
     pub phase: i32,
     pub moon_longitude_deg: f64,
@@ -4888,6 +5226,8 @@ pub struct DhruvSankrantiConfig {
     pub ayanamsha_system: i32,
     /// Whether to apply nutation correction (0=false, 1=true).
     pub use_nutation: u8,
+    /// `DHRUV_NUTATION_MODEL_*`, used when `use_nutation` is set.
+    pub nutation_model: i32,
     /// Reference plane (0=Ecliptic, 1=Invariable). Set to -1 for system default.
     pub reference_plane: i32,
     pub step_size_days: f64,
@@ -4900,6 +5240,8 @@ pub struct DhruvSankrantiConfig {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvSankrantiEvent {
     pub utc: DhruvUtcTime,
+    /// Event time as a two-part TDB epoch (full refinement precision).
+    pub epoch: DhruvEpoch,
     /// 0-based rashi index (0=Mesha .. 11=Meena).
     pub rashi_index: i32,
     pub sun_sidereal_longitude_deg: f64,
@@ -4930,6 +5272,12 @@ pub struct DhruvSankrantiSearchRequest {
     pub start_utc: DhruvUtcTime,
     /// End of range window for range mode (UTC).
     pub end_utc: DhruvUtcTime,
+    /// Anchor time for next/prev modes (two-part TDB epoch).
+    pub at_epoch: DhruvEpoch,
+    /// Start of range window for range mode (two-part TDB epoch).
+    pub start_epoch: DhruvEpoch,
+    /// End of range window for range mode (two-part TDB epoch).
+    pub end_epoch: DhruvEpoch,
     /// Sankranti search configuration.
     pub config: DhruvSankrantiConfig,
 }
@@ -5029,6 +5377,18 @@ fn ffi_to_utc_time(t: &DhruvUtcTime) -> UtcTime {
     UtcTime::new(t.year, t.month, t.day, t.hour, t.minute, t.second)
 }
 
+fn epoch_to_ffi(epoch: Epoch) -> DhruvEpoch {
+    let (whole_seconds, fraction) = epoch.as_tdb_seconds_parts();
+    DhruvEpoch {
+        whole_seconds,
+        fraction,
+    }
+}
+
+fn ffi_to_epoch(epoch: &DhruvEpoch) -> Epoch {
+    Epoch::from_tdb_seconds_parts(epoch.whole_seconds, epoch.fraction)
+}
+
 fn lunar_phase_to_code(p: LunarPhase) -> i32 {
     match p {
         LunarPhase::NewMoon => DHRUV_LUNAR_PHASE_NEW_MOON,
@@ -5039,6 +5399,7 @@ fn lunar_phase_to_code(p: LunarPhase) -> i32 {
 fn lunar_phase_event_to_ffi(event: &dhruv_search::LunarPhaseEvent) -> DhruvLunarPhaseEvent {
     DhruvLunarPhaseEvent {
         utc: utc_time_to_ffi(&event.utc),
+        epoch: epoch_to_ffi(event.epoch),
         phase: lunar_phase_to_code(event.phase),
         moon_longitude_deg: event.moon_longitude_deg,
         sun_longitude_deg: event.sun_longitude_deg,
@@ -5048,6 +5409,7 @@ fn lunar_phase_event_to_ffi(event: &dhruv_search::LunarPhaseEvent) -> DhruvLunar
 fn sankranti_event_to_ffi(event: &dhruv_search::SankrantiEvent) -> DhruvSankrantiEvent {
     DhruvSankrantiEvent {
         utc: utc_time_to_ffi(&event.utc),
+        epoch: epoch_to_ffi(event.epoch),
         rashi_index: event.rashi_index as i32,
         sun_sidereal_longitude_deg: event.sun_sidereal_longitude_deg,
         sun_tropical_longitude_deg: event.sun_tropical_longitude_deg,
@@ -5062,6 +5424,14 @@ fn reference_plane_from_code(code: i32, system: AyanamshaSystem) -> dhruv_frames
     }
 }
 
+fn nutation_model_from_code(code: i32) -> Option<NutationModel> {
+    match code {
+        DHRUV_NUTATION_MODEL_IAU2000B => Some(NutationModel::Iau2000B),
+        DHRUV_NUTATION_MODEL_IAU1980 => Some(NutationModel::Iau1980),
+        _ => None,
+    }
+}
+
 fn precession_model_from_code(code: i32) -> Option<PrecessionModel> {
     match code {
         DHRUV_PRECESSION_MODEL_NEWCOMB1895 => Some(PrecessionModel::Newcomb1895),
@@ -5087,8 +5457,11 @@ fn resolve_graha_longitudes_config_ptr(
     };
     let precession_model =
         precession_model_from_code(raw.precession_model).ok_or(DhruvStatus::InvalidQuery)?;
+    let nutation_model =
+        nutation_model_from_code(raw.nutation_model).ok_or(DhruvStatus::InvalidQuery)?;
     let ayanamsha_system =
         ayanamsha_system_from_code(raw.ayanamsha_system).ok_or(DhruvStatus::InvalidQuery)?;
+    let correction = Correction::from_code(raw.correction).ok_or(DhruvStatus::InvalidQuery)?;
     let reference_plane = match kind {
         GrahaLongitudeKind::Sidereal => {
             reference_plane_from_code(raw.reference_plane, ayanamsha_system)
@@ -5104,8 +5477,10 @@ fn resolve_graha_longitudes_config_ptr(
         ayanamsha_system,
         use_nutation: raw.use_nutation != 0,
         precession_model,
+        nutation_model,
         reference_plane,
         include_outer_planets: true,
+        correction,
     })
 }
 
@@ -5115,6 +5490,7 @@ fn sankranti_config_from_ffi(cfg: &DhruvSankrantiConfig) -> Option<SankrantiConf
         ayanamsha_system: system,
         use_nutation: cfg.use_nutation != 0,
         precession_model: dhruv_frames::DEFAULT_PRECESSION_MODEL,
+        nutation_model: nutation_model_from_code(cfg.nutation_model)?,
         reference_plane: reference_plane_from_code(cfg.reference_plane, system),
         step_size_days: cfg.step_size_days,
         max_iterations: cfg.max_iterations,
@@ -5166,6 +5542,7 @@ pub extern "C" fn dhruv_sankranti_config_default() -> DhruvSankrantiConfig {
     DhruvSankrantiConfig {
         ayanamsha_system: 0, // Lahiri
         use_nutation: 0,
+        nutation_model: DHRUV_NUTATION_MODEL_IAU2000B,
         reference_plane: -1, // system default
         step_size_days: 1.0,
         max_iterations: 50,
@@ -5214,16 +5591,16 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match next_amavasya(engine_ref, &at) {
+                match next_amavasya(engine_ref, at) {
                     Ok(Some(event)) => {
                         unsafe {
                             *out_event = lunar_phase_event_to_ffi(&event);
@@ -5242,16 +5619,16 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match prev_amavasya(engine_ref, &at) {
+                match prev_amavasya(engine_ref, at) {
                     Ok(Some(event)) => {
                         unsafe {
                             *out_event = lunar_phase_event_to_ffi(&event);
@@ -5270,25 +5647,25 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_events.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_utc(
-                    engine_ref,
+                let start = match search_time_to_instant(
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_utc(
-                    engine_ref,
+                let end = match search_time_to_instant(
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match search_amavasyas(engine_ref, &start, &end) {
+                match search_amavasyas(engine_ref, start, end) {
                     Ok(events) => {
                         let count = events.len().min(max_count as usize);
                         let out_slice = unsafe {
@@ -5307,16 +5684,16 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match next_purnima(engine_ref, &at) {
+                match next_purnima(engine_ref, at) {
                     Ok(Some(event)) => {
                         unsafe {
                             *out_event = lunar_phase_event_to_ffi(&event);
@@ -5335,16 +5712,16 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match prev_purnima(engine_ref, &at) {
+                match prev_purnima(engine_ref, at) {
                     Ok(Some(event)) => {
                         unsafe {
                             *out_event = lunar_phase_event_to_ffi(&event);
@@ -5363,25 +5740,25 @@ pub unsafe extern "C" fn dhruv_lunar_phase_search_ex(
                 if out_events.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_utc(
-                    engine_ref,
+                let start = match search_time_to_instant(
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_utc(
-                    engine_ref,
+                let end = match search_time_to_instant(
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match search_purnimas(engine_ref, &start, &end) {
+                match search_purnimas(engine_ref, start, end) {
                     Ok(events) => {
                         let count = events.len().min(max_count as usize);
                         let out_slice = unsafe {
@@ -5457,18 +5834,18 @@ pub unsafe extern "C" fn dhruv_sankranti_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
                 let result = match specific_rashi {
-                    Some(rashi) => next_specific_sankranti(engine_ref, &at, rashi, &config),
-                    None => next_sankranti(engine_ref, &at, &config),
+                    Some(rashi) => next_specific_sankranti(engine_ref, at, rashi, &config),
+                    None => next_sankranti(engine_ref, at, &config),
                 };
                 match result {
                     Ok(Some(event)) => {
@@ -5489,18 +5866,18 @@ pub unsafe extern "C" fn dhruv_sankranti_search_ex(
                 if out_event.is_null() || out_found.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let at = match search_time_to_utc(
-                    engine_ref,
+                let at = match search_time_to_instant(
                     req.time_kind,
                     req.at_jd_tdb,
                     req.at_utc,
+                    req.at_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
                 let result = match specific_rashi {
-                    Some(rashi) => prev_specific_sankranti(engine_ref, &at, rashi, &config),
-                    None => prev_sankranti(engine_ref, &at, &config),
+                    Some(rashi) => prev_specific_sankranti(engine_ref, at, rashi, &config),
+                    None => prev_sankranti(engine_ref, at, &config),
                 };
                 match result {
                     Ok(Some(event)) => {
@@ -5521,25 +5898,25 @@ pub unsafe extern "C" fn dhruv_sankranti_search_ex(
                 if out_events.is_null() || out_count.is_null() {
                     return DhruvStatus::NullPointer;
                 }
-                let start = match search_time_to_utc(
-                    engine_ref,
+                let start = match search_time_to_instant(
                     req.time_kind,
                     req.start_jd_tdb,
                     req.start_utc,
+                    req.start_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                let end = match search_time_to_utc(
-                    engine_ref,
+                let end = match search_time_to_instant(
                     req.time_kind,
                     req.end_jd_tdb,
                     req.end_utc,
+                    req.end_epoch,
                 ) {
                     Ok(v) => v,
                     Err(status) => return status,
                 };
-                match search_sankrantis(engine_ref, &start, &end, &config) {
+                match search_sankrantis(engine_ref, start, end, &config) {
                     Ok(events) => {
                         let filtered: Vec<_> = match specific_rashi {
                             Some(rashi) => {
@@ -6098,6 +6475,11 @@ const ZEROED_UTC: DhruvUtcTime = DhruvUtcTime {
     second: 0.0,
 };
 
+const ZEROED_EPOCH: DhruvEpoch = DhruvEpoch {
+    whole_seconds: 0.0,
+    fraction: 0.0,
+};
+
 fn jd_tdb_to_utc_time(jd_tdb: f64, lsk: &dhruv_time::LeapSecondKernel) -> DhruvUtcTime {
     utc_time_to_ffi(&UtcTime::from_jd_tdb(jd_tdb, lsk))
 }
@@ -6889,6 +7271,62 @@ pub struct DhruvGhatikaInfo {
     pub end: DhruvUtcTime,
 }
 
+/// C-compatible muhurta-table period.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvMuhurtaWindow {
+    pub start: DhruvUtcTime,
+    pub end: DhruvUtcTime,
+}
+
+/// C-compatible day or night muhurta.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvMuhurtaSlot {
+    /// 0-based index within the day or the night (0-14).
+    pub index: u8,
+    /// 1 when the muhurta falls between sunset and next sunrise.
+    pub is_night: u8,
+    /// 1 when the muhurta is a Durmuhurta for the vaar.
+    pub is_durmuhurta: u8,
+    pub start: DhruvUtcTime,
+    pub end: DhruvUtcTime,
+}
+
+/// C-compatible Choghadiya slot.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvChoghadiyaSlot {
+    /// Choghadiya index (0=Udveg, 1=Char, 2=Labh, 3=Amrit, 4=Kaal, 5=Shubh, 6=Rog).
+    pub choghadiya_index: u8,
+    /// 0-based slot within the day or the night (0-7).
+    pub slot: u8,
+    /// 1 when the slot falls between sunset and next sunrise.
+    pub is_night: u8,
+    pub start: DhruvUtcTime,
+    pub end: DhruvUtcTime,
+}
+
+/// C-compatible daily muhurta periods for one Vedic day.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvMuhurtaInfo {
+    /// 0-based vaar index (0=Ravivaar/Sunday .. 6=Shanivaar/Saturday).
+    pub vaar_index: i32,
+    pub sunrise: DhruvUtcTime,
+    pub sunset: DhruvUtcTime,
+    pub next_sunrise: DhruvUtcTime,
+    pub rahu_kalam: DhruvMuhurtaWindow,
+    pub yamaganda: DhruvMuhurtaWindow,
+    pub gulika_kalam: DhruvMuhurtaWindow,
+    pub abhijit: DhruvMuhurtaWindow,
+    pub brahma_muhurta: DhruvMuhurtaWindow,
+    /// 15 day muhurtas followed by 15 night muhurtas.
+    pub muhurtas: [DhruvMuhurtaSlot; 30],
+    /// 8 day Choghadiyas followed by 8 night Choghadiyas.
+    pub choghadiyas: [DhruvChoghadiyaSlot; 16],
+}
+
 /// Determine the Tithi for a given UTC date.
 ///
 /// # Safety
@@ -7177,6 +7615,79 @@ pub unsafe extern "C" fn dhruv_ghatika_for_date(
     })
 }
 
+/// Determine the daily muhurta periods (Rahu Kalam, Yamaganda, Gulika Kalam,
+/// Abhijit, Brahma muhurta, 30 muhurtas, 16 Choghadiyas) for the Vedic day
+/// containing the given UTC date.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_muhurta_for_date(
+    engine: *const DhruvEngineHandle,
+    eop: *const DhruvEopHandle,
+    utc: *const DhruvUtcTime,
+    location: *const DhruvGeoLocation,
+    riseset_config: *const DhruvRiseSetConfig,
+    out: *mut DhruvMuhurtaInfo,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null()
+        {
+            return DhruvStatus::NullPointer;
+        }
+        let engine_ref = unsafe { &*engine };
+        let eop_ref = unsafe { &*eop };
+        let t = ffi_to_utc_time(unsafe { &*utc });
+        let loc_ref = unsafe { &*location };
+        let geo = GeoLocation::new(
+            loc_ref.latitude_deg,
+            loc_ref.longitude_deg,
+            loc_ref.altitude_m,
+        );
+        let rs_config = match resolve_riseset_config_ptr(riseset_config) {
+            Ok(c) => c,
+            Err(status) => return status,
+        };
+        match muhurta_for_date(engine_ref, eop_ref, &t, &geo, &rs_config) {
+            Ok(info) => {
+                let window = |w: &dhruv_search::MuhurtaWindow| DhruvMuhurtaWindow {
+                    start: utc_time_to_ffi(&w.start),
+                    end: utc_time_to_ffi(&w.end),
+                };
+                unsafe {
+                    *out = DhruvMuhurtaInfo {
+                        vaar_index: info.vaar.index() as i32,
+                        sunrise: utc_time_to_ffi(&info.sunrise),
+                        sunset: utc_time_to_ffi(&info.sunset),
+                        next_sunrise: utc_time_to_ffi(&info.next_sunrise),
+                        rahu_kalam: window(&info.rahu_kalam),
+                        yamaganda: window(&info.yamaganda),
+                        gulika_kalam: window(&info.gulika_kalam),
+                        abhijit: window(&info.abhijit),
+                        brahma_muhurta: window(&info.brahma_muhurta),
+                        muhurtas: info.muhurtas.map(|m| DhruvMuhurtaSlot {
+                            index: m.index,
+                            is_night: u8::from(m.is_night),
+                            is_durmuhurta: u8::from(m.is_durmuhurta),
+                            start: utc_time_to_ffi(&m.start),
+                            end: utc_time_to_ffi(&m.end),
+                        }),
+                        choghadiyas: info.choghadiyas.map(|c| DhruvChoghadiyaSlot {
+                            choghadiya_index: c.choghadiya.index(),
+                            slot: c.slot,
+                            is_night: u8::from(c.is_night),
+                            start: utc_time_to_ffi(&c.start),
+                            end: utc_time_to_ffi(&c.end),
+                        }),
+                    };
+                }
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
 /// C-compatible Panchang Nakshatra info (Moon's nakshatra with boundaries).
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -7251,6 +7762,7 @@ pub unsafe extern "C" fn dhruv_panchang_compute_ex(
             use_refraction: req.riseset_config.use_refraction != 0,
             sun_limb,
             altitude_correction: req.riseset_config.altitude_correction != 0,
+            ..Default::default()
         };
 
         let at_utc = match req.time_kind {
@@ -7815,6 +8327,66 @@ pub extern "C" fn dhruv_hora_name(index: u32) -> *const std::ffi::c_char {
     }
 }
 
+/// Return the name of a Choghadiya by index (0=Udveg .. 6=Rog).
+///
+/// Returns a NUL-terminated static string, or null for invalid index.
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_choghadiya_name(index: u32) -> *const std::ffi::c_char {
+    static NAMES: [&str; 7] = [
+        "Udveg\0", "Char\0", "Labh\0", "Amrit\0", "Kaal\0", "Shubh\0", "Rog\0",
+    ];
+    match NAMES.get(index as usize) {
+        Some(s) => s.as_ptr().cast(),
+        None => ptr::null(),
+    }
+}
+
+/// Return the name of a day (`is_night == 0`) or night muhurta by index (0-14).
+///
+/// Returns a NUL-terminated static string, or null for invalid index.
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_muhurta_name(is_night: u8, index: u32) -> *const std::ffi::c_char {
+    static DAY: [&str; 15] = [
+        "Rudra\0",
+        "Ahi\0",
+        "Mitra\0",
+        "Pitru\0",
+        "Vasu\0",
+        "Varaha\0",
+        "Vishvedeva\0",
+        "Abhijit\0",
+        "Satamukhi\0",
+        "Puruhuta\0",
+        "Vahini\0",
+        "Naktanakara\0",
+        "Varuna\0",
+        "Aryaman\0",
+        "Bhaga\0",
+    ];
+    static NIGHT: [&str; 15] = [
+        "Girisha\0",
+        "Ajapada\0",
+        "Ahirbudhnya\0",
+        "Pushya\0",
+        "Ashvini\0",
+        "Yama\0",
+        "Agni\0",
+        "Vidhatri\0",
+        "Chanda\0",
+        "Aditi\0",
+        "Jiva\0",
+        "Vishnu\0",
+        "Dyumadgadyuti\0",
+        "Tvashtri\0",
+        "Vayu\0",
+    ];
+    let names = if is_night != 0 { &NIGHT } else { &DAY };
+    match names.get(index as usize) {
+        Some(s) => s.as_ptr().cast(),
+        None => ptr::null(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Panchang composable intermediates + pre-computed input variants
 // ---------------------------------------------------------------------------
@@ -11983,33 +12555,448 @@ pub unsafe extern "C" fn dhruv_charakaraka_for_date(
 }
 
 // ---------------------------------------------------------------------------
-// Shadbala & Vimsopaka FFI functions (date-based)
+// Krishnamurti Paddhati (KP) FFI
 // ---------------------------------------------------------------------------
 
-/// Compute Shadbala for all 7 sapta grahas at a given date and location.
-///
-/// # Safety
-/// All pointers must be valid. `out` must point to a valid `DhruvShadbalaResult`.
-#[unsafe(no_mangle)]
-pub unsafe extern "C" fn dhruv_shadbala_for_date(
-    engine: *const Engine,
-    eop: *const dhruv_time::EopKernel,
-    utc: *const DhruvUtcTime,
-    location: *const DhruvGeoLocation,
-    bhava_config: *const DhruvBhavaConfig,
-    riseset_config: *const DhruvRiseSetConfig,
-    ayanamsha_system: u32,
-    use_nutation: u8,
-    amsha_selection: *const DhruvAmshaSelectionConfig,
-    out: *mut DhruvShadbalaResult,
-) -> DhruvStatus {
-    if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null() {
-        return DhruvStatus::NullPointer;
-    }
+/// Number of divisions in the KP sub table.
+pub const DHRUV_KP_SUB_COUNT: usize = 249;
 
-    let engine = unsafe { &*engine };
-    let eop = unsafe { &*eop };
-    let utc_c = unsafe { &*utc };
+/// C-compatible KP lords. All fields are graha indices (0=Sun..8=Ketu).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpLords {
+    pub sign_lord: u8,
+    pub star_lord: u8,
+    pub sub_lord: u8,
+    pub sub_sub_lord: u8,
+}
+
+/// C-compatible KP graha entry.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpGrahaEntry {
+    /// Graha index (0=Sun..8=Ketu).
+    pub graha_index: u8,
+    /// 1-based KP house (cusp to next cusp).
+    pub bhava_number: u8,
+    /// 1-based division number in the 249-sub table.
+    pub sub_number: u16,
+    /// Sidereal longitude in degrees.
+    pub sidereal_longitude: f64,
+    pub lords: DhruvKpLords,
+}
+
+/// C-compatible KP cusp entry.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpCuspEntry {
+    /// 1-based house number.
+    pub bhava_number: u8,
+    /// 1-based division number in the 249-sub table.
+    pub sub_number: u16,
+    /// Sidereal cusp longitude in degrees.
+    pub sidereal_longitude: f64,
+    pub lords: DhruvKpLords,
+}
+
+/// C-compatible four-level KP significators of one house.
+///
+/// Each level holds `*_count` graha indices; unused slots are zero.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpHouseSignificators {
+    /// 1-based house number.
+    pub bhava_number: u8,
+    pub level_a_count: u8,
+    pub level_a: [u8; 9],
+    pub level_b_count: u8,
+    pub level_b: [u8; 9],
+    pub level_c_count: u8,
+    pub level_c: [u8; 9],
+    pub level_d_count: u8,
+    pub level_d: [u8; 9],
+}
+
+/// C-compatible KP ruling planets.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpRulingPlanets {
+    /// Graha index of the weekday lord.
+    pub day_lord: u8,
+    pub lagna: DhruvKpLords,
+    pub moon: DhruvKpLords,
+    /// Number of populated `ruling` slots.
+    pub ruling_count: u8,
+    /// Distinct ruling planets, strongest first (graha indices).
+    pub ruling: [u8; 9],
+}
+
+/// C-compatible KP chart.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKpChart {
+    /// Ayanamsha used, in degrees.
+    pub ayanamsha_deg: f64,
+    /// Indexed by graha index (0=Sun..8=Ketu).
+    pub grahas: [DhruvKpGrahaEntry; 9],
+    /// Houses 1-12.
+    pub cusps: [DhruvKpCuspEntry; 12],
+    /// Houses 1-12.
+    pub significators: [DhruvKpHouseSignificators; 12],
+    pub ruling_planets: DhruvKpRulingPlanets,
+}
+
+fn kp_lords_to_ffi(lords: &dhruv_search::KpLords) -> DhruvKpLords {
+    DhruvKpLords {
+        sign_lord: lords.sign_lord.index(),
+        star_lord: lords.star_lord.index(),
+        sub_lord: lords.sub_lord.index(),
+        sub_sub_lord: lords.sub_sub_lord.index(),
+    }
+}
+
+fn kp_graha_list_to_ffi(grahas: &[dhruv_vedic_base::Graha]) -> (u8, [u8; 9]) {
+    let mut out = [0u8; 9];
+    let count = grahas.len().min(9);
+    for (slot, g) in out.iter_mut().zip(grahas.iter()) {
+        *slot = g.index();
+    }
+    (count as u8, out)
+}
+
+fn kp_chart_to_ffi(chart: &dhruv_search::KpChart) -> DhruvKpChart {
+    let grahas = chart.grahas.map(|e| DhruvKpGrahaEntry {
+        graha_index: e.graha.index(),
+        bhava_number: e.bhava_number,
+        sub_number: e.sub_number,
+        sidereal_longitude: e.sidereal_longitude,
+        lords: kp_lords_to_ffi(&e.lords),
+    });
+    let cusps = chart.cusps.map(|c| DhruvKpCuspEntry {
+        bhava_number: c.bhava_number,
+        sub_number: c.sub_number,
+        sidereal_longitude: c.sidereal_longitude,
+        lords: kp_lords_to_ffi(&c.lords),
+    });
+    let significators = std::array::from_fn(|i| {
+        let h = &chart.significators[i];
+        let (level_a_count, level_a) = kp_graha_list_to_ffi(&h.level_a);
+        let (level_b_count, level_b) = kp_graha_list_to_ffi(&h.level_b);
+        let (level_c_count, level_c) = kp_graha_list_to_ffi(&h.level_c);
+        let (level_d_count, level_d) = kp_graha_list_to_ffi(&h.level_d);
+        DhruvKpHouseSignificators {
+            bhava_number: h.bhava_number,
+            level_a_count,
+            level_a,
+            level_b_count,
+            level_b,
+            level_c_count,
+            level_c,
+            level_d_count,
+            level_d,
+        }
+    });
+    let rp = &chart.ruling_planets;
+    let (ruling_count, ruling) = kp_graha_list_to_ffi(&rp.ruling);
+    DhruvKpChart {
+        ayanamsha_deg: chart.ayanamsha_deg,
+        grahas,
+        cusps,
+        significators,
+        ruling_planets: DhruvKpRulingPlanets {
+            day_lord: rp.day_lord.index(),
+            lagna: kp_lords_to_ffi(&rp.lagna),
+            moon: kp_lords_to_ffi(&rp.moon),
+            ruling_count,
+            ruling,
+        },
+    }
+}
+
+/// KP sign, star, sub and sub-sub lords of a sidereal longitude (pure math).
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_kp_lords(
+    sidereal_lon_deg: f64,
+    out: *mut DhruvKpLords,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let lords = dhruv_vedic_base::kp_lords(sidereal_lon_deg);
+        unsafe {
+            *out = kp_lords_to_ffi(&lords);
+        }
+        DhruvStatus::Ok
+    })
+}
+
+/// Compute a Krishnamurti Paddhati chart for a given date and location.
+///
+/// Cusps follow `bhava_config` (pass `DHRUV_BHAVA_KP` with the KP ayanamsha
+/// for a classical KP chart). `bhava_config` and `riseset_config` may be null
+/// for defaults.
+///
+/// # Safety
+/// All non-config pointers must be valid. `out` must point to a valid `DhruvKpChart`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_kp_chart_for_date(
+    engine: *const Engine,
+    eop: *const dhruv_time::EopKernel,
+    utc: *const DhruvUtcTime,
+    location: *const DhruvGeoLocation,
+    bhava_config: *const DhruvBhavaConfig,
+    riseset_config: *const DhruvRiseSetConfig,
+    ayanamsha_system: u32,
+    use_nutation: u8,
+    out: *mut DhruvKpChart,
+) -> DhruvStatus {
+    if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+
+    let engine = unsafe { &*engine };
+    let eop = unsafe { &*eop };
+    let utc_c = unsafe { &*utc };
+    let loc_c = unsafe { &*location };
+
+    let utc_time = UtcTime {
+        year: utc_c.year,
+        month: utc_c.month,
+        day: utc_c.day,
+        hour: utc_c.hour,
+        minute: utc_c.minute,
+        second: utc_c.second,
+    };
+    let location = GeoLocation::new(loc_c.latitude_deg, loc_c.longitude_deg, loc_c.altitude_m);
+
+    let system = match ayanamsha_system_from_code(ayanamsha_system as i32) {
+        Some(s) => s,
+        None => return DhruvStatus::InvalidQuery,
+    };
+    let rust_bhava_config = match resolve_bhava_config_ptr(bhava_config) {
+        Ok(c) => c,
+        Err(status) => return status,
+    };
+    let rs_config = match resolve_riseset_config_ptr(riseset_config) {
+        Ok(c) => c,
+        Err(status) => return status,
+    };
+    let aya_config = SankrantiConfig::new(system, use_nutation != 0);
+
+    match kp_chart_for_date(
+        engine,
+        eop,
+        &utc_time,
+        &location,
+        &rust_bhava_config,
+        &rs_config,
+        &aya_config,
+    ) {
+        Ok(chart) => {
+            let out = unsafe { &mut *out };
+            *out = kp_chart_to_ffi(&chart);
+            DhruvStatus::Ok
+        }
+        Err(e) => DhruvStatus::from(&e),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Kundali milan (Ashtakoota / Dashakoota)
+// ---------------------------------------------------------------------------
+
+/// No cancellation applies (or the koota has no dosha).
+pub const DHRUV_MILAN_CANCELLATION_NONE: u8 = 0;
+/// Same rashi, different nakshatras.
+pub const DHRUV_MILAN_CANCELLATION_SAME_RASHI_DIFF_NAKSHATRA: u8 = 1;
+/// Same nakshatra, different rashis.
+pub const DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_RASHI: u8 = 2;
+/// Same nakshatra, different padas.
+pub const DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_PADA: u8 = 3;
+/// Both rashis share a lord.
+pub const DHRUV_MILAN_CANCELLATION_SAME_RASHI_LORD: u8 = 4;
+/// Rashi lords are mutual natural friends.
+pub const DHRUV_MILAN_CANCELLATION_FRIENDLY_RASHI_LORDS: u8 = 5;
+
+/// C-compatible score of one Ashtakoota koota.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvKootaScore {
+    /// 0=Varna, 1=Vashya, 2=Tara, 3=Yoni, 4=Graha Maitri, 5=Gana, 6=Bhakoot, 7=Nadi.
+    pub koota_index: u8,
+    /// 1 if the koota carries a dosha.
+    pub dosha: u8,
+    /// `DHRUV_MILAN_CANCELLATION_*` code.
+    pub cancellation: u8,
+    pub points: f64,
+    pub max_points: f64,
+}
+
+/// C-compatible kundali milan result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvMilanResult {
+    /// Ashtakoota kootas in traditional order.
+    pub kootas: [DhruvKootaScore; 8],
+    /// Ashtakoota total out of 36.
+    pub total: f64,
+    /// Total with cancelled doshas restored to full points.
+    pub adjusted_total: f64,
+    /// Dashakoota poruthams (Dina, Gana, Mahendra, Stree Deergha, Yoni, Rasi,
+    /// Rasyadhipati, Vasya, Rajju, Vedha): 1 = matched.
+    pub poruthams: [u8; 10],
+    pub porutham_matched_count: u8,
+    /// 1 if Rajju or Vedha failed.
+    pub critical_failure: u8,
+}
+
+fn milan_cancellation_code(c: Option<dhruv_vedic_base::MilanCancellation>) -> u8 {
+    use dhruv_vedic_base::MilanCancellation::*;
+    match c {
+        None => DHRUV_MILAN_CANCELLATION_NONE,
+        Some(SameRashiDifferentNakshatra) => DHRUV_MILAN_CANCELLATION_SAME_RASHI_DIFF_NAKSHATRA,
+        Some(SameNakshatraDifferentRashi) => DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_RASHI,
+        Some(SameNakshatraDifferentPada) => DHRUV_MILAN_CANCELLATION_SAME_NAKSHATRA_DIFF_PADA,
+        Some(SameRashiLord) => DHRUV_MILAN_CANCELLATION_SAME_RASHI_LORD,
+        Some(FriendlyRashiLords) => DHRUV_MILAN_CANCELLATION_FRIENDLY_RASHI_LORDS,
+    }
+}
+
+fn milan_to_ffi(result: &dhruv_vedic_base::MilanResult) -> DhruvMilanResult {
+    let ak = &result.ashtakoota;
+    let dk = &result.dashakoota;
+    let mut kootas = [DhruvKootaScore {
+        koota_index: 0,
+        dosha: 0,
+        cancellation: DHRUV_MILAN_CANCELLATION_NONE,
+        points: 0.0,
+        max_points: 0.0,
+    }; 8];
+    for (i, (slot, k)) in kootas.iter_mut().zip(ak.kootas.iter()).enumerate() {
+        *slot = DhruvKootaScore {
+            koota_index: i as u8,
+            dosha: u8::from(k.dosha),
+            cancellation: milan_cancellation_code(k.cancellation),
+            points: k.points,
+            max_points: k.max_points,
+        };
+    }
+    DhruvMilanResult {
+        kootas,
+        total: ak.total,
+        adjusted_total: ak.adjusted_total,
+        poruthams: dk.poruthams.map(|p| u8::from(p.matched)),
+        porutham_matched_count: dk.matched_count,
+        critical_failure: u8::from(dk.critical_failure),
+    }
+}
+
+/// Ashtakoota and Dashakoota compatibility from two sidereal Moon longitudes (pure math).
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_kundali_milan(
+    groom_moon_deg: f64,
+    bride_moon_deg: f64,
+    out: *mut DhruvMilanResult,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let result = dhruv_vedic_base::kundali_milan(groom_moon_deg, bride_moon_deg);
+        unsafe {
+            *out = milan_to_ffi(&result);
+        }
+        DhruvStatus::Ok
+    })
+}
+
+/// Ashtakoota and Dashakoota compatibility for two birth moments.
+///
+/// # Safety
+/// All pointers must be valid and non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_kundali_milan_for_dates(
+    engine: *const Engine,
+    eop: *const dhruv_time::EopKernel,
+    groom_utc: *const DhruvUtcTime,
+    bride_utc: *const DhruvUtcTime,
+    ayanamsha_system: u32,
+    use_nutation: u8,
+    out: *mut DhruvMilanResult,
+) -> DhruvStatus {
+    if engine.is_null()
+        || eop.is_null()
+        || groom_utc.is_null()
+        || bride_utc.is_null()
+        || out.is_null()
+    {
+        return DhruvStatus::NullPointer;
+    }
+
+    let engine = unsafe { &*engine };
+    let eop = unsafe { &*eop };
+    let to_utc = |c: &DhruvUtcTime| UtcTime {
+        year: c.year,
+        month: c.month,
+        day: c.day,
+        hour: c.hour,
+        minute: c.minute,
+        second: c.second,
+    };
+    let groom_time = to_utc(unsafe { &*groom_utc });
+    let bride_time = to_utc(unsafe { &*bride_utc });
+
+    let system = match ayanamsha_system_from_code(ayanamsha_system as i32) {
+        Some(s) => s,
+        None => return DhruvStatus::InvalidQuery,
+    };
+    let aya_config = SankrantiConfig::new(system, use_nutation != 0);
+
+    match kundali_milan_for_dates(engine, eop, &groom_time, &bride_time, &aya_config) {
+        Ok(result) => {
+            let out = unsafe { &mut *out };
+            *out = milan_to_ffi(&result);
+            DhruvStatus::Ok
+        }
+        Err(e) => DhruvStatus::from(&e),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shadbala & Vimsopaka FFI functions (date-based)
+// ---------------------------------------------------------------------------
+
+/// Compute Shadbala for all 7 sapta grahas at a given date and location.
+///
+/// # Safety
+/// All pointers must be valid. `out` must point to a valid `DhruvShadbalaResult`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_shadbala_for_date(
+    engine: *const Engine,
+    eop: *const dhruv_time::EopKernel,
+    utc: *const DhruvUtcTime,
+    location: *const DhruvGeoLocation,
+    bhava_config: *const DhruvBhavaConfig,
+    riseset_config: *const DhruvRiseSetConfig,
+    ayanamsha_system: u32,
+    use_nutation: u8,
+    amsha_selection: *const DhruvAmshaSelectionConfig,
+    out: *mut DhruvShadbalaResult,
+) -> DhruvStatus {
+    if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+
+    let engine = unsafe { &*engine };
+    let eop = unsafe { &*eop };
+    let utc_c = unsafe { &*utc };
     let loc_c = unsafe { &*location };
 
     let utc_time = UtcTime {
@@ -12451,8 +13438,12 @@ pub struct DhruvGrahaLongitudesConfig {
     pub use_nutation: u8,
     /// `DHRUV_PRECESSION_MODEL_*`
     pub precession_model: i32,
+    /// `DHRUV_NUTATION_MODEL_*`, used when `use_nutation` is set.
+    pub nutation_model: i32,
     /// `DhruvReferencePlane` or -1 for system default.
     pub reference_plane: i32,
+    /// `DHRUV_CORRECTION_*` applied to the planetary states.
+    pub correction: i32,
 }
 
 /// One moving osculating apogee result entry.
@@ -12501,10 +13492,17 @@ pub extern "C" fn dhruv_graha_longitudes_config_default() -> DhruvGrahaLongitude
         ayanamsha_system: 0,
         use_nutation: 0,
         precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
+        nutation_model: DHRUV_NUTATION_MODEL_IAU2000B,
         reference_plane: -1,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     }
 }
 
+/// Compute graha longitudes at a TDB Julian date.
+///
+/// # Safety
+/// `engine` and `out` must be valid, non-null pointers. `config` may be null
+/// (defaults are used) or point to a valid `DhruvGrahaLongitudesConfig`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dhruv_graha_longitudes(
     engine: *const Engine,
@@ -13321,7 +14319,8 @@ fn resolve_dasha_birth_context(
                 sankranti_config.use_nutation,
                 sankranti_config.precession_model,
                 sankranti_config.reference_plane,
-            ),
+            )
+            .with_nutation_model(sankranti_config.nutation_model),
         )
         .map_err(|err| DhruvStatus::from(&err))?;
         let lagna_sid = dhruv_search::sidereal_lagna_for_date(
@@ -13869,6 +14868,7 @@ fn tara_config_from_c(config: &DhruvTaraConfig) -> TaraConfig {
             TaraAccuracy::Astrometric
         },
         apply_parallax: config.apply_parallax != 0,
+        ..TaraConfig::default()
     }
 }
 
@@ -14150,6 +15150,41 @@ mod tests {
         assert_eq!(status, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_epoch_query_rejects_null_and_converts_two_part_jd() {
+        let mut out_state = DhruvStateVector {
+            position_km: [0.0; 3],
+            velocity_km_s: [0.0; 3],
+        };
+        let status = unsafe { dhruv_engine_query_epoch(ptr::null(), ptr::null(), &mut out_state) };
+        assert_eq!(status, DhruvStatus::NullPointer);
+
+        let query = EpochQuery::try_from(DhruvEpochQuery {
+            target: 301,
+            observer: 399,
+            frame: 0,
+            jd_tdb_day: 2_460_000.5,
+            jd_tdb_frac: 1e-11,
+            correction: DHRUV_CORRECTION_GEOMETRIC,
+        })
+        .unwrap();
+        let offset = query.epoch.seconds_since(Epoch::from_jd_tdb(2_460_000.5));
+        assert!((offset - 86_400e-11).abs() < 1e-11, "{offset}");
+
+        let bad_frame = DhruvEpochQuery {
+            frame: 99,
+            ..DhruvEpochQuery {
+                target: 301,
+                observer: 399,
+                frame: 0,
+                jd_tdb_day: 2_460_000.5,
+                jd_tdb_frac: 0.0,
+                correction: DHRUV_CORRECTION_GEOMETRIC,
+            }
+        };
+        assert!(EpochQuery::try_from(bad_frame).is_err());
+    }
+
     #[test]
     fn ffi_query_request_rejects_null_input_pointer() {
         let mut out = DhruvQueryResult {
@@ -14170,6 +15205,58 @@ mod tests {
         assert_eq!(status, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn query_correction_codes_map_and_reject_unknown() {
+        let query = DhruvQuery {
+            target: 499,
+            observer: 399,
+            frame: 0,
+            epoch_tdb_jd: 2_451_545.0,
+            correction: DHRUV_CORRECTION_APPARENT,
+        };
+        assert_eq!(
+            Query::try_from(query).unwrap().correction,
+            Correction::Apparent
+        );
+        let epoch_query = DhruvEpochQuery {
+            target: 499,
+            observer: 399,
+            frame: 0,
+            jd_tdb_day: 2_451_545.0,
+            jd_tdb_frac: 0.0,
+            correction: DHRUV_CORRECTION_LIGHT_TIME,
+        };
+        assert_eq!(
+            EpochQuery::try_from(epoch_query).unwrap().correction,
+            Correction::LightTime
+        );
+        assert!(
+            Query::try_from(DhruvQuery {
+                correction: 4,
+                ..query
+            })
+            .is_err()
+        );
+        assert!(
+            EpochQuery::try_from(DhruvEpochQuery {
+                correction: -1,
+                ..epoch_query
+            })
+            .is_err()
+        );
+
+        let mut config = dhruv_graha_longitudes_config_default();
+        assert_eq!(config.correction, DHRUV_CORRECTION_GEOMETRIC);
+        config.correction = DHRUV_CORRECTION_LIGHT_TIME_ABERRATION;
+        let resolved = resolve_graha_longitudes_config_ptr(&config).unwrap();
+        assert_eq!(resolved.correction, Correction::LightTimeAberration);
+        config.correction = 9;
+        assert_eq!(
+            resolve_graha_longitudes_config_ptr(&config).err(),
+            Some(DhruvStatus::InvalidQuery)
+        );
+    }
+
     #[test]
     fn query_request_invalid_time_kind_rejected() {
         let request = DhruvQueryRequest {
@@ -14180,6 +15267,7 @@ mod tests {
             epoch_tdb_jd: 2_451_545.0,
             utc: ZEROED_UTC,
             output_mode: DHRUV_QUERY_OUTPUT_BOTH,
+            correction: DHRUV_CORRECTION_GEOMETRIC,
         };
         let result = validate_query_request_selectors(request);
         assert_eq!(result, Err(DhruvStatus::InvalidQuery));
@@ -14195,6 +15283,7 @@ mod tests {
             epoch_tdb_jd: 2_451_545.0,
             utc: ZEROED_UTC,
             output_mode: 99,
+            correction: DHRUV_CORRECTION_GEOMETRIC,
         };
         let result = validate_query_request_selectors(request);
         assert_eq!(result, Err(DhruvStatus::InvalidQuery));
@@ -14297,6 +15386,38 @@ mod tests {
         assert_eq!(cfg.use_refraction, 1);
         assert_eq!(cfg.sun_limb, DHRUV_SUN_LIMB_UPPER);
         assert_eq!(cfg.altitude_correction, 1);
+        assert_eq!(cfg.sun_radius_km, 0.0);
+        assert_eq!(cfg.earth_mean_radius_km, 0.0);
+        let rust = riseset_config_from_ffi(&cfg).unwrap();
+        assert_eq!(rust.radii, BodyRadii::default());
+    }
+
+    #[test]
+    fn ffi_riseset_config_load_tpc_reads_radii() {
+        let path = std::env::temp_dir().join(format!("dhruv_rs_radii_{}.tpc", std::process::id()));
+        std::fs::write(
+            &path,
+            "\\begindata\nBODY10_RADII = ( 695700.0 695700.0 695700.0 )\n\
+             BODY399_RADII = ( 6378.1366 6378.1366 6356.7519 )\n\\begintext\n",
+        )
+        .unwrap();
+        let c_path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        let mut cfg = dhruv_riseset_config_default();
+        // SAFETY: Valid path string and config pointer.
+        let status = unsafe { dhruv_riseset_config_load_tpc(c_path.as_ptr(), &mut cfg) };
+        std::fs::remove_file(&path).ok();
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!(cfg.sun_radius_km, 695_700.0);
+        assert!((cfg.earth_mean_radius_km - 6371.0084).abs() < 1e-4);
+        assert_eq!(cfg.use_refraction, 1);
+
+        let rust = riseset_config_from_ffi(&cfg).unwrap();
+        assert_eq!(rust.radii.sun_km, 695_700.0);
+        assert_eq!(rust.radii.moon_km, BodyRadii::default().moon_km);
+
+        // SAFETY: Null path pointer is intentional for validation.
+        let status = unsafe { dhruv_riseset_config_load_tpc(ptr::null(), &mut cfg) };
+        assert_eq!(status, DhruvStatus::NullPointer);
     }
 
     #[test]
@@ -14548,6 +15669,47 @@ mod tests {
         assert_eq!(status, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_local_time_to_utc_zones_and_ambiguity() {
+        let mut out = ZEROED_UTC;
+        let mut offset = 0;
+        // SAFETY: NUL-terminated literal and valid out pointers.
+        let status = unsafe {
+            dhruv_local_time_to_utc(
+                c"2024-01-15T05:30:00[Asia/Kolkata]".as_ptr(),
+                DHRUV_LOCAL_DISAMBIGUATION_REJECT,
+                &mut out,
+                &mut offset,
+            )
+        };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!((out.day, out.hour, out.minute), (15, 0, 0));
+        assert_eq!(offset, 19_800);
+
+        let repeated = c"2024-11-03T01:30:00[America/New_York]";
+        // SAFETY: As above; offset output is optional.
+        let status = unsafe {
+            dhruv_local_time_to_utc(
+                repeated.as_ptr(),
+                DHRUV_LOCAL_DISAMBIGUATION_REJECT,
+                &mut out,
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, DhruvStatus::InvalidInput);
+        // SAFETY: As above.
+        let status = unsafe {
+            dhruv_local_time_to_utc(
+                repeated.as_ptr(),
+                DHRUV_LOCAL_DISAMBIGUATION_LATER,
+                &mut out,
+                &mut offset,
+            )
+        };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!((out.hour, out.minute, offset), (6, 30, -18_000));
+    }
+
     #[test]
     fn ffi_riseset_result_to_utc_never_rises() {
         let result = DhruvRiseSetResult {
@@ -15198,7 +16360,7 @@ mod tests {
 
     #[test]
     fn ffi_api_version_matches_public_constant() {
-        assert_eq!(dhruv_api_version(), DHRUV_API_VERSION as u32);
+        assert_eq!(dhruv_api_version(), DHRUV_API_VERSION);
     }
 
     #[test]
@@ -15290,6 +16452,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_conjunction_config_default(),
         };
         let mut event = std::mem::MaybeUninit::<DhruvConjunctionEvent>::uninit();
@@ -15308,6 +16473,24 @@ mod tests {
         assert_eq!(status, DhruvStatus::InvalidQuery);
     }
 
+    #[test]
+    fn ffi_search_epoch_input_keeps_two_part_precision() {
+        let input = DhruvEpoch {
+            whole_seconds: 7.5e8,
+            fraction: 12.5e-6,
+        };
+        let resolved = match search_time_to_instant(DHRUV_SEARCH_TIME_EPOCH, 0.0, ZEROED_UTC, input)
+        {
+            Ok(SearchTime::Tdb(epoch)) => epoch,
+            _ => panic!("epoch input should resolve to a TDB epoch"),
+        };
+        assert_eq!(epoch_to_ffi(resolved), input);
+        assert!(matches!(
+            search_time_to_instant(3, 0.0, ZEROED_UTC, input),
+            Err(DhruvStatus::InvalidQuery)
+        ));
+    }
+
     #[test]
     fn ffi_conjunction_search_ex_next_rejects_null_engine() {
         let request = DhruvConjunctionSearchRequest {
@@ -15321,6 +16504,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_conjunction_config_default(),
         };
         let mut event = std::mem::MaybeUninit::<DhruvConjunctionEvent>::uninit();
@@ -15353,6 +16539,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_conjunction_config_default(),
         };
         let mut event = std::mem::MaybeUninit::<DhruvConjunctionEvent>::uninit();
@@ -15413,6 +16602,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_grahan_config_default(),
         };
         let mut found: u8 = 0;
@@ -15445,6 +16637,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_grahan_config_default(),
         };
         let mut chandra = std::mem::MaybeUninit::<DhruvChandraGrahanResult>::uninit();
@@ -15477,6 +16672,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_grahan_config_default(),
         };
         let mut surya = std::mem::MaybeUninit::<DhruvSuryaGrahanResult>::uninit();
@@ -15584,6 +16782,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut found: u8 = 0;
@@ -15617,6 +16818,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut stationary = std::mem::MaybeUninit::<DhruvStationaryEvent>::uninit();
@@ -15650,6 +16854,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut max_speed = std::mem::MaybeUninit::<DhruvMaxSpeedEvent>::uninit();
@@ -15684,6 +16891,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut stationary = std::mem::MaybeUninit::<DhruvStationaryEvent>::uninit();
@@ -15718,6 +16928,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut stationary = std::mem::MaybeUninit::<DhruvStationaryEvent>::uninit();
@@ -15752,6 +16965,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_stationary_config_default(),
         };
         let mut max_speed = std::mem::MaybeUninit::<DhruvMaxSpeedEvent>::uninit();
@@ -15953,11 +17169,34 @@ mod tests {
         let config = dhruv_sankranti_config_default();
         assert_eq!(config.ayanamsha_system, 0); // Lahiri
         assert_eq!(config.use_nutation, 0);
+        assert_eq!(config.nutation_model, DHRUV_NUTATION_MODEL_IAU2000B);
         assert!((config.step_size_days - 1.0).abs() < 1e-10);
         assert_eq!(config.max_iterations, 50);
         assert!((config.convergence_days - 1e-8).abs() < 1e-15);
     }
 
+    #[test]
+    fn ffi_nutation_model_code_is_applied() {
+        let mut cfg = dhruv_sankranti_config_default();
+        cfg.nutation_model = DHRUV_NUTATION_MODEL_IAU1980;
+        let resolved = sankranti_config_from_ffi(&cfg).expect("valid config");
+        assert_eq!(resolved.nutation_model, NutationModel::Iau1980);
+
+        cfg.nutation_model = 99;
+        assert!(sankranti_config_from_ffi(&cfg).is_none());
+
+        let mut graha_cfg = dhruv_graha_longitudes_config_default();
+        graha_cfg.nutation_model = DHRUV_NUTATION_MODEL_IAU1980;
+        let resolved = resolve_graha_longitudes_config_ptr(&graha_cfg).expect("valid config");
+        assert_eq!(resolved.nutation_model, NutationModel::Iau1980);
+
+        graha_cfg.nutation_model = -1;
+        assert_eq!(
+            resolve_graha_longitudes_config_ptr(&graha_cfg),
+            Err(DhruvStatus::InvalidQuery)
+        );
+    }
+
     #[test]
     fn ffi_lunar_phase_constants() {
         assert_eq!(DHRUV_LUNAR_PHASE_NEW_MOON, 0);
@@ -16001,6 +17240,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
         };
         let mut event = std::mem::MaybeUninit::<DhruvLunarPhaseEvent>::uninit();
         let mut found: u8 = 0;
@@ -16030,6 +17272,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
         };
         let mut event = std::mem::MaybeUninit::<DhruvLunarPhaseEvent>::uninit();
         let mut found: u8 = 0;
@@ -16059,6 +17304,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
         };
         let mut event = std::mem::MaybeUninit::<DhruvLunarPhaseEvent>::uninit();
         let mut found: u8 = 0;
@@ -16108,6 +17356,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_sankranti_config_default(),
         };
         let mut event = std::mem::MaybeUninit::<DhruvSankrantiEvent>::uninit();
@@ -16139,6 +17390,9 @@ mod tests {
             at_utc: ZEROED_UTC,
             start_utc: ZEROED_UTC,
             end_utc: ZEROED_UTC,
+            at_epoch: ZEROED_EPOCH,
+            start_epoch: ZEROED_EPOCH,
+            end_epoch: ZEROED_EPOCH,
             config: dhruv_sankranti_config_default(),
         };
         let mut event = std::mem::MaybeUninit::<DhruvSankrantiEvent>::uninit();
@@ -16982,6 +18236,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn ffi_calculate_all_bav_valid() {
         let rashis = [0u8; 7];
         let mut out = [DhruvBhinnaAshtakavarga {
@@ -17143,6 +18398,127 @@ mod tests {
         assert!(m.entries[0][6].base_virupa >= 59.0);
     }
 
+    // --- kp ---
+
+    #[test]
+    fn ffi_kp_lords_rejects_null() {
+        let s = unsafe { dhruv_kp_lords(10.0, ptr::null_mut()) };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_kp_lords_valid() {
+        let mut out = std::mem::MaybeUninit::<DhruvKpLords>::uninit();
+        // 359.99° → Meena (Guru), Revati (Buddh), Shani sub
+        let s = unsafe { dhruv_kp_lords(359.99, out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let l = unsafe { out.assume_init() };
+        assert_eq!(l.sign_lord, 4);
+        assert_eq!(l.star_lord, 3);
+        assert_eq!(l.sub_lord, 6);
+    }
+
+    #[test]
+    fn ffi_kp_chart_for_date_rejects_null() {
+        let mut out = std::mem::MaybeUninit::<DhruvKpChart>::uninit();
+        let s = unsafe {
+            dhruv_kp_chart_for_date(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                2,
+                0,
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
+    // --- kundali_milan ---
+
+    #[test]
+    fn ffi_muhurta_for_date_rejects_null() {
+        let mut out = std::mem::MaybeUninit::<DhruvMuhurtaInfo>::uninit();
+        let s = unsafe {
+            dhruv_muhurta_for_date(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_muhurta_and_choghadiya_names() {
+        let name = |p: *const std::ffi::c_char| {
+            unsafe { std::ffi::CStr::from_ptr(p) }
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        for (i, n) in dhruv_vedic_base::DAY_MUHURTA_NAMES.iter().enumerate() {
+            assert_eq!(name(dhruv_muhurta_name(0, i as u32)), *n);
+        }
+        for (i, n) in dhruv_vedic_base::NIGHT_MUHURTA_NAMES.iter().enumerate() {
+            assert_eq!(name(dhruv_muhurta_name(1, i as u32)), *n);
+        }
+        for c in dhruv_vedic_base::ALL_CHOGHADIYAS {
+            assert_eq!(name(dhruv_choghadiya_name(c.index() as u32)), c.name());
+        }
+        assert!(dhruv_muhurta_name(0, 15).is_null());
+        assert!(dhruv_choghadiya_name(7).is_null());
+    }
+
+    #[test]
+    fn ffi_kundali_milan_rejects_null() {
+        let s = unsafe { dhruv_kundali_milan(10.0, 20.0, ptr::null_mut()) };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_kundali_milan_valid() {
+        let mut out = std::mem::MaybeUninit::<DhruvMilanResult>::uninit();
+        // Mesha and Vrischika: Bhakoot 6/8 cancelled by the shared lord Mangal.
+        let s = unsafe { dhruv_kundali_milan(5.0, 215.0, out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let r = unsafe { out.assume_init() };
+        let bhakoot = r.kootas[6];
+        assert_eq!(bhakoot.koota_index, 6);
+        assert_eq!(bhakoot.dosha, 1);
+        assert_eq!(
+            bhakoot.cancellation,
+            DHRUV_MILAN_CANCELLATION_SAME_RASHI_LORD
+        );
+        let total: f64 = r.kootas.iter().map(|k| k.points).sum();
+        assert!((total - r.total).abs() < 1e-12);
+        let matched = r.poruthams.iter().filter(|&&m| m == 1).count();
+        assert_eq!(matched, r.porutham_matched_count as usize);
+    }
+
+    #[test]
+    fn ffi_kundali_milan_for_dates_rejects_null() {
+        let mut out = std::mem::MaybeUninit::<DhruvMilanResult>::uninit();
+        let s = unsafe {
+            dhruv_kundali_milan_for_dates(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                0,
+                0,
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
     // --- ghatika_from_elapsed ---
 
     #[test]
@@ -17483,7 +18859,7 @@ mod tests {
             unsafe { dhruv_time_upagraha_jd(0, 0, 1, 2451545.0, 2451545.3, 2451546.0, &mut out) };
         assert_eq!(s, DhruvStatus::Ok);
         // Result should be between sunrise and sunset
-        assert!(out >= 2451545.0 && out <= 2451545.3, "out={out}");
+        assert!((2451545.0..=2451545.3).contains(&out), "out={out}");
     }
 
     #[test]
@@ -17590,7 +18966,7 @@ mod tests {
         let mut out: f64 = 0.0;
         let s = unsafe { dhruv_amsha_longitude(45.0, 9, 0, &mut out) };
         assert_eq!(s, DhruvStatus::Ok);
-        assert!(out >= 0.0 && out < 360.0);
+        assert!((0.0..360.0).contains(&out));
     }
 
     #[test]
@@ -17687,6 +19063,7 @@ mod tests {
             use_refraction: 1,
             sun_limb: 0,
             altitude_correction: 0,
+            ..dhruv_riseset_config_default()
         };
         let scope = DhruvAmshaChartScope {
             include_bhava_cusps: 0,
@@ -18,6 +18,11 @@ const ZEROED_UTC: DhruvUtcTime = DhruvUtcTime {
     second: 0.0,
 };
 
+const ZEROED_EPOCH: DhruvEpoch = DhruvEpoch {
+    whole_seconds: 0.0,
+    fraction: 0.0,
+};
+
 fn kernel_base() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../kernels/data")
 }
@@ -143,6 +148,9 @@ fn ffi_sankranti_search_ex_default_config_works() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_sankranti_config_default(),
     };
     let mut event: DhruvSankrantiEvent = unsafe { std::mem::zeroed() };
@@ -184,6 +192,9 @@ fn ffi_conjunction_search_ex_default_config_works() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_conjunction_config_default(),
     };
     let mut event: DhruvConjunctionEvent = unsafe { std::mem::zeroed() };
@@ -219,12 +230,50 @@ fn query_once_successfully_maps_through_core_contract() {
         observer: Observer::Body(Body::Earth).code(),
         frame: Frame::IcrfJ2000.code(),
         epoch_tdb_jd: 2_460_000.25,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
 
     let result = dhruv_query_once_internal(&config, query).expect("query should succeed");
     assert!(result.position_km[0].is_finite());
 }
 
+#[test]
+fn query_once_applies_requested_correction() {
+    let config = match real_config() {
+        Some(c) => c,
+        None => return,
+    };
+    let geometric = DhruvQuery {
+        target: Body::Mars.code(),
+        observer: Observer::Body(Body::Earth).code(),
+        frame: Frame::IcrfJ2000.code(),
+        epoch_tdb_jd: 2_460_000.25,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
+    };
+    let apparent = DhruvQuery {
+        correction: DHRUV_CORRECTION_APPARENT,
+        ..geometric
+    };
+
+    let a = dhruv_query_once_internal(&config, geometric).expect("geometric query");
+    let b = dhruv_query_once_internal(&config, apparent).expect("apparent query");
+    let shift_km = (0..3)
+        .map(|i| (a.position_km[i] - b.position_km[i]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    // Light time and aberration move Mars by thousands of km at this range.
+    assert!(shift_km > 1_000.0, "{shift_km}");
+
+    let bad = DhruvQuery {
+        correction: 7,
+        ..geometric
+    };
+    assert_eq!(
+        dhruv_query_once_internal(&config, bad),
+        Err(DhruvStatus::InvalidQuery)
+    );
+}
+
 #[test]
 fn query_rejects_invalid_body_code() {
     let config = match real_config() {
@@ -236,6 +285,7 @@ fn query_rejects_invalid_body_code() {
         observer: Observer::SolarSystemBarycenter.code(),
         frame: Frame::IcrfJ2000.code(),
         epoch_tdb_jd: 2_460_000.25,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
 
     let result = dhruv_query_once_internal(&config, query);
@@ -253,6 +303,7 @@ fn ffi_lifecycle_create_query_free() {
         observer: Observer::Body(Body::Earth).code(),
         frame: Frame::IcrfJ2000.code(),
         epoch_tdb_jd: 2_460_000.5,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
 
     let mut engine_ptr: *mut DhruvEngineHandle = ptr::null_mut();
@@ -485,6 +536,7 @@ fn ffi_full_longitude_workflow() {
         observer: Body::Sun.code(),
         frame: Frame::EclipticJ2000.code(),
         epoch_tdb_jd: jd_tdb,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
     let mut state = DhruvStateVector {
         position_km: [0.0; 3],
@@ -541,6 +593,7 @@ fn ffi_query_request_utc_spherical_mars_heliocentric() {
             second: 0.0,
         },
         output_mode: DHRUV_QUERY_OUTPUT_SPHERICAL,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
     let mut out: DhruvQueryResult = unsafe { std::mem::zeroed() };
 
@@ -585,6 +638,7 @@ fn ffi_query_request_utc_spherical_speeds_finite() {
             second: 0.0,
         },
         output_mode: DHRUV_QUERY_OUTPUT_SPHERICAL,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
     let mut out: DhruvQueryResult = unsafe { std::mem::zeroed() };
 
@@ -1084,6 +1138,7 @@ fn ffi_sunrise_lower_limb_later_than_upper() {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_UPPER,
         altitude_correction: 1,
+        ..dhruv_riseset_config_default()
     };
     let mut result_upper = DhruvRiseSetResult {
         result_type: -1,
@@ -1111,6 +1166,7 @@ fn ffi_sunrise_lower_limb_later_than_upper() {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_LOWER,
         altitude_correction: 1,
+        ..dhruv_riseset_config_default()
     };
     let mut result_lower = DhruvRiseSetResult {
         result_type: -1,
@@ -1190,6 +1246,7 @@ fn ffi_center_mode_between_limbs() {
             use_refraction: 1,
             sun_limb: *limb_code,
             altitude_correction: 1,
+            ..dhruv_riseset_config_default()
         };
         let mut result = DhruvRiseSetResult {
             result_type: -1,
@@ -1365,7 +1422,7 @@ fn ffi_lagna_deg_new_delhi() {
     let status = unsafe { dhruv_lagna_deg(lsk_ptr, eop_ptr, &loc, jd_utc, &mut asc) };
     assert_eq!(status, DhruvStatus::Ok);
     assert!(
-        asc >= 0.0 && asc < 360.0,
+        (0.0..360.0).contains(&asc),
         "Ascendant = {} deg, out of range",
         asc
     );
@@ -1404,7 +1461,7 @@ fn ffi_lagna_deg_new_delhi() {
     let mut mc: f64 = 0.0;
     let status = unsafe { dhruv_mc_deg(lsk_ptr, eop_ptr, &loc, jd_utc, &mut mc) };
     assert_eq!(status, DhruvStatus::Ok);
-    assert!(mc >= 0.0 && mc < 360.0, "MC = {} deg, out of range", mc);
+    assert!((0.0..360.0).contains(&mc), "MC = {} deg, out of range", mc);
 
     unsafe { dhruv_eop_free(eop_ptr) };
     unsafe { dhruv_lsk_free(lsk_ptr) };
@@ -1618,6 +1675,9 @@ fn ffi_utc_conjunction_roundtrip() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_conjunction_config_default(),
     };
     let mut jd_event: DhruvConjunctionEvent = unsafe { std::mem::zeroed() };
@@ -1681,6 +1741,39 @@ fn ffi_utc_conjunction_roundtrip() {
         "body1 lon mismatch"
     );
 
+    // The reported epoch agrees with the JD and carries the finer fraction.
+    let event_seconds = jd_event.epoch.whole_seconds + jd_event.epoch.fraction;
+    assert!((0.0..1.0).contains(&jd_event.epoch.fraction));
+    assert!((event_seconds - (jd_event.jd_tdb - 2_451_545.0) * 86_400.0).abs() < 1e-3);
+
+    // Same query through the two-part epoch input.
+    let start_seconds = (jd_start - 2_451_545.0) * 86_400.0;
+    let request_from_epoch = DhruvConjunctionSearchRequest {
+        time_kind: DHRUV_SEARCH_TIME_EPOCH,
+        at_jd_tdb: 0.0,
+        at_epoch: DhruvEpoch {
+            whole_seconds: start_seconds.floor(),
+            fraction: start_seconds - start_seconds.floor(),
+        },
+        ..request_jd
+    };
+    let mut epoch_path_event: DhruvConjunctionEvent = unsafe { std::mem::zeroed() };
+    let mut found_epoch: u8 = 0;
+    let status = unsafe {
+        dhruv_conjunction_search_ex(
+            engine_ptr,
+            &request_from_epoch,
+            &mut epoch_path_event,
+            &mut found_epoch,
+            ptr::null_mut(),
+            0,
+            ptr::null_mut(),
+        )
+    };
+    assert_eq!(status, DhruvStatus::Ok);
+    assert_eq!(found_epoch, 1, "epoch path should also find conjunction");
+    assert_eq!(epoch_path_event.epoch, jd_event.epoch);
+
     unsafe { dhruv_lsk_free(lsk_ptr) };
     unsafe { dhruv_engine_free(engine_ptr) };
 }
@@ -1708,6 +1801,9 @@ fn ffi_utc_chandra_grahan_roundtrip() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_grahan_config_default(),
     };
     let mut jd_result: DhruvChandraGrahanResult = unsafe { std::mem::zeroed() };
@@ -1822,6 +1918,9 @@ fn ffi_utc_surya_grahan_roundtrip() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_grahan_config_default(),
     };
     let mut jd_result: DhruvSuryaGrahanResult = unsafe { std::mem::zeroed() };
@@ -1937,6 +2036,9 @@ fn ffi_utc_stationary_roundtrip() {
         at_utc: ZEROED_UTC,
         start_utc: ZEROED_UTC,
         end_utc: ZEROED_UTC,
+        at_epoch: ZEROED_EPOCH,
+        start_epoch: ZEROED_EPOCH,
+        end_epoch: ZEROED_EPOCH,
         config: dhruv_stationary_config_default(),
     };
     let mut jd_event: DhruvStationaryEvent = unsafe { std::mem::zeroed() };
@@ -2030,6 +2132,7 @@ fn ffi_utc_query_roundtrip() {
             second: 0.0,
         },
         output_mode: DHRUV_QUERY_OUTPUT_SPHERICAL,
+        correction: DHRUV_CORRECTION_GEOMETRIC,
     };
     let mut out_first: DhruvQueryResult = unsafe { std::mem::zeroed() };
     let status = unsafe { dhruv_engine_query_request(engine_ptr, &request, &mut out_first) };
@@ -2599,11 +2702,11 @@ fn ffi_amsha_variation_catalogs_are_amsha_scoped() {
         variations: [DhruvAmshaVariationInfo {
             amsha_code: 0,
             variation_code: 0,
-            name: [0; DHRUV_AMSHA_VARIATION_NAME_CAPACITY as usize],
-            label: [0; DHRUV_AMSHA_VARIATION_LABEL_CAPACITY as usize],
+            name: [0; DHRUV_AMSHA_VARIATION_NAME_CAPACITY],
+            label: [0; DHRUV_AMSHA_VARIATION_LABEL_CAPACITY],
             is_default: 0,
-            description: [0; DHRUV_AMSHA_VARIATION_DESCRIPTION_CAPACITY as usize],
-        }; DHRUV_MAX_AMSHA_VARIATIONS as usize],
+            description: [0; DHRUV_AMSHA_VARIATION_DESCRIPTION_CAPACITY],
+        }; DHRUV_MAX_AMSHA_VARIATIONS],
     };
     let status = unsafe { dhruv_amsha_variations(2, &mut d2) };
     assert_eq!(status, DhruvStatus::Ok);
@@ -2621,12 +2724,12 @@ fn ffi_amsha_variation_catalogs_are_amsha_scoped() {
             variations: [DhruvAmshaVariationInfo {
                 amsha_code: 0,
                 variation_code: 0,
-                name: [0; DHRUV_AMSHA_VARIATION_NAME_CAPACITY as usize],
-                label: [0; DHRUV_AMSHA_VARIATION_LABEL_CAPACITY as usize],
+                name: [0; DHRUV_AMSHA_VARIATION_NAME_CAPACITY],
+                label: [0; DHRUV_AMSHA_VARIATION_LABEL_CAPACITY],
                 is_default: 0,
-                description: [0; DHRUV_AMSHA_VARIATION_DESCRIPTION_CAPACITY as usize],
-            }; DHRUV_MAX_AMSHA_VARIATIONS as usize],
-        }; DHRUV_MAX_AMSHA_REQUESTS as usize],
+                description: [0; DHRUV_AMSHA_VARIATION_DESCRIPTION_CAPACITY],
+            }; DHRUV_MAX_AMSHA_VARIATIONS],
+        }; DHRUV_MAX_AMSHA_REQUESTS],
     };
     let amsha_codes = [2u16, 9u16];
     let status = unsafe { dhruv_amsha_variations_many(amsha_codes.as_ptr(), 2, &mut many) };
@@ -1,14 +1,17 @@
 //! Frame conversion helpers for ephemeris computations.
 //!
-//! Provides coordinate frame rotations (ICRF ↔ Ecliptic J2000) and
-//! coordinate system conversions (Cartesian ↔ Spherical).
+//! Provides coordinate frame rotations (ICRF ↔ Ecliptic J2000, ICRF → mean
+//! and true frames of date, GCRS ↔ ITRS) and coordinate system conversions
+//! (Cartesian ↔ Spherical).
 
 pub mod invariable;
 pub mod nutation;
 pub mod obliquity;
+pub mod of_date;
 pub mod precession;
 pub mod rotation;
 pub mod spherical;
+pub mod terrestrial;
 
 pub use invariable::{
     INVARIABLE_INCLINATION_DEG, INVARIABLE_NODE_DEG, ReferencePlane,
@@ -17,12 +20,17 @@ pub use invariable::{
     invariable_to_icrf,
 };
 pub use nutation::{
-    equation_of_equinoxes_and_true_obliquity, fundamental_arguments, nutation_iau2000b,
+    DEFAULT_NUTATION_MODEL, NutationModel, equation_of_equinoxes_and_true_obliquity,
+    equation_of_equinoxes_and_true_obliquity_with_model, fundamental_arguments, nutation_iau1980,
+    nutation_iau2000b, nutation_with_model,
 };
 pub use obliquity::{
     COS_OBL, OBLIQUITY_J2000_DEG, OBLIQUITY_J2000_RAD, SIN_OBL, mean_obliquity_of_date_arcsec,
     mean_obliquity_of_date_rad,
 };
+pub use of_date::{
+    FrameOfDate, icrf_state_to_frame_of_date, icrf_to_frame_of_date, icrf_to_frame_of_date_matrix,
+};
 pub use precession::{
     DEFAULT_PRECESSION_MODEL, PrecessionModel, ecliptic_inclination_arcsec,
     ecliptic_inclination_arcsec_with_model, ecliptic_node_longitude_arcsec,
@@ -38,3 +46,8 @@ pub use spherical::{
     SphericalCoords, SphericalState, cartesian_state_to_spherical_state, cartesian_to_spherical,
     spherical_to_cartesian,
 };
+pub use terrestrial::{
+    PoleParameters, celestial_to_terrestrial_matrix, cio_locator_s, cip_xy, gcrs_to_cirs_matrix,
+    gcrs_to_itrs, itrs_to_gcrs, polar_motion_matrix, terrestrial_to_celestial_matrix,
+    tio_locator_s_prime,
+};
@@ -1,10 +1,13 @@
-//! IAU 2000B truncated nutation model (77 lunisolar terms).
+//! Nutation models: IAU 2000B and IAU 1980.
 //!
-//! Computes nutation in longitude (Δψ) and obliquity (Δε) using the
-//! simplified IAU 2000B model, sufficient for ~1 mas accuracy.
+//! Computes nutation in longitude (Δψ) and obliquity (Δε). IAU 2000B (77
+//! lunisolar terms, ~1 mas) is the default. IAU 1980 is kept for parity
+//! with legacy tools.
+//!
+//! Sources:
+//! - IERS Conventions 2010, Chapter 5, Tables 5.2e, 5.3b.
+//! - Seidelmann 1982, Celest. Mech. 27, 79-106 (IAU 1980 series).
 //!
-//! Source: IERS Conventions 2010, Chapter 5, Table 5.3b.
-//! Fundamental arguments from IERS Conventions 2010, Table 5.2e.
 //! Public domain (IAU standard).
 
 use std::f64::consts::TAU;
@@ -12,6 +15,18 @@ use std::f64::consts::TAU;
 /// Arcseconds to radians conversion factor.
 const AS2RAD: f64 = TAU / 1_296_000.0;
 
+/// Supported nutation models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NutationModel {
+    /// IAU 1980 (Wahr) series, 63 largest terms; for legacy parity.
+    Iau1980,
+    /// IAU 2000B truncated lunisolar series with fixed planetary offsets.
+    Iau2000B,
+}
+
+/// Default nutation model used by wrapper functions.
+pub const DEFAULT_NUTATION_MODEL: NutationModel = NutationModel::Iau2000B;
+
 /// Compute the five Delaunay fundamental arguments in radians.
 ///
 /// `t` = Julian centuries of TDB since J2000.0.
@@ -54,91 +69,93 @@ pub fn fundamental_arguments(t: f64) -> [f64; 5] {
 
 /// IAU 2000B lunisolar nutation term coefficients.
 ///
-/// Each row: `[nl, nl', nF, nD, nΩ, S_i, S'_i, C_i, C'_i]`
-/// where S_i, S'_i are in 0.1 μas for Δψ, and C_i, C'_i in 0.1 μas for Δε.
+/// Each row: `[nl, nl', nF, nD, nΩ, S_i, S'_i, C_i, C'_i, S''_i, C''_i]`
+/// where S_i, S'_i, S''_i are in 0.1 μas for Δψ (sine, sine·T, cosine) and
+/// C_i, C'_i, C''_i in 0.1 μas for Δε (cosine, cosine·T, sine).
 ///
-/// Source: IERS Conventions 2010, Table 5.3b (77 terms).
+/// Source: IERS Conventions 2010, Table 5.3b (77 terms), including the
+/// out-of-phase S''_i, C''_i columns.
 /// Amplitudes stored as i64 (units of 0.1 μas = 1e-7 arcsec).
 #[rustfmt::skip]
-static NUTATION_COEFFS: [[i64; 9]; 77] = [
-    //  nl  nl'  nF   nD   nΩ       S_i         S'_i         C_i         C'_i
-    [   0,   0,   0,   0,   1, -172064161,  -174666,   92052331,    9086],
-    [   0,   0,   2,  -2,   2,  -13170906,    -1675,    5730336,   -3015],
-    [   0,   0,   2,   0,   2,   -2276413,     -234,     978459,    -485],
-    [   0,   0,   0,   0,   2,    2074554,      207,    -897492,     470],
-    [   0,   1,   0,   0,   0,    1475877,    -3633,      73871,    -184],
-    [   0,   1,   2,  -2,   2,    -516821,     1226,     224386,    -677],
-    [   1,   0,   0,   0,   0,     711159,       73,      -6750,       0],
-    [   0,   0,   2,   0,   1,    -387298,     -367,     200728,      18],
-    [   1,   0,   2,   0,   2,    -301461,      -36,     129025,     -63],
-    [   0,  -1,   2,  -2,   2,     215829,     -494,     -95929,     299],
-    [   0,   0,   2,  -2,   1,     128227,      137,     -68982,      -9],
-    [  -1,   0,   2,   0,   2,     123457,       11,     -53311,      32],
-    [  -1,   0,   0,   2,   0,     156994,       10,      -1235,       0],
-    [   1,   0,   0,   0,   1,      63110,       63,     -33228,       0],
-    [  -1,   0,   0,   0,   1,     -57976,      -63,      31429,       0],
-    [  -1,   0,   2,   2,   2,     -59641,      -11,      25543,     -11],
-    [   1,   0,   2,   0,   1,     -51613,      -42,      26366,       0],
-    [  -2,   0,   2,   0,   1,      45893,       50,     -24236,     -10],
-    [   0,   0,   0,   2,   0,      63384,       11,      -1220,       0],
-    [   0,   0,   2,   2,   2,     -38571,       -1,      16452,     -11],
-    [   0,  -2,   2,  -2,   2,      32481,        0,     -13870,       0],
-    [  -2,   0,   0,   2,   0,     -47722,        0,        477,       0],
-    [   2,   0,   2,   0,   2,     -31046,       -1,      13238,     -11],
-    [   1,   0,   2,  -2,   2,      28593,        0,     -12338,      10],
-    [  -1,   0,   2,   0,   1,      20441,       21,     -10758,       0],
-    [   2,   0,   0,   0,   0,      29243,        0,       -609,       0],
-    [   0,   0,   2,   0,   0,      25887,        0,       -550,       0],
-    [   0,   1,   0,   0,   1,     -14053,      -25,       8551,      -2],
-    [  -1,   0,   0,   2,   1,      15164,       10,      -8001,       0],
-    [   0,   2,   2,  -2,   2,     -15794,       72,       6850,     -42],
-    [   0,   0,  -2,   2,   0,      21783,        0,       -167,       0],
-    [   1,   0,   0,  -2,   1,     -12873,      -10,       6953,       0],
-    [   0,  -1,   0,   0,   1,     -12654,       11,       6415,       0],
-    [  -1,   0,   2,   2,   1,     -10204,        0,       5222,       0],
-    [   0,   2,   0,   0,   0,      16707,      -85,        168,      -1],
-    [   1,   0,   2,   2,   2,      -7691,        0,       3268,       0],
-    [  -2,   0,   2,   0,   0,     -11024,        0,        104,       0],
-    [   0,   1,   2,   0,   2,       7566,      -21,      -3250,       0],
-    [   0,   0,   2,   2,   1,      -6637,      -11,       3353,       0],
-    [   0,  -1,   2,   0,   2,      -7141,       21,       3070,       0],
-    [   0,   0,   0,   2,   1,      -6302,      -11,       3272,       0],
-    [   1,   0,   2,  -2,   1,       5800,       10,      -3045,       0],
-    [   2,   0,   2,  -2,   2,       6443,        0,      -2768,       0],
-    [  -2,   0,   0,   2,   1,      -5774,      -11,       3041,       0],
-    [   2,   0,   2,   0,   1,      -5350,        0,       2695,       0],
-    [   0,  -1,   2,  -2,   1,      -4752,      -11,       2719,       0],
-    [   0,   0,   0,  -2,   1,      -4940,      -11,       2720,       0],
-    [  -1,  -1,   0,   2,   0,       7350,        0,        -51,       0],
-    [   2,   0,   0,  -2,   1,      -4803,      -11,       2556,       0],
-    [   1,   0,   0,   2,   0,      -7677,        0,        462,       0],
-    [   0,   1,   2,  -2,   1,       5417,        0,      -2520,       0],
-    [   1,  -1,   0,   0,   0,       6624,        0,       -468,       0],
-    [  -2,   0,   2,   0,   2,      -5433,        0,       2334,       0],
-    [   3,   0,   2,   0,   2,      -4632,        0,       1991,       0],
-    [   0,  -1,   0,   2,   0,       6106,        0,       -167,       0],
-    [   1,  -1,   2,   0,   2,      -3593,        0,       1556,       0],
-    [   0,   0,   0,   1,   0,      -4766,        0,        270,       0],
-    [  -1,  -1,   2,   2,   2,      -4095,        0,       1793,       0],
-    [  -1,   0,   2,   0,   0,       4229,        0,       -101,       0],
-    [   0,  -1,   2,   2,   2,      -3372,        0,       1487,       0],
-    [   2,   0,   0,   0,   1,      -3353,        0,       1758,       0],
-    [   1,   0,   2,   0,   0,      -3523,        0,        246,       0],
-    [   1,   1,   0,   0,   0,      -3613,        0,        329,       0],
-    [  -1,   0,   2,  -2,   1,       3522,        0,      -1830,       0],
-    [   2,   0,   0,   0,  -1,       3312,        0,      -1730,       0],
-    [   0,   0,  -2,   2,   1,      -3142,        0,       1704,       0],
-    [   0,   1,   0,   0,  -1,      -2927,        0,       1564,       0],
-    [   0,   1,   2,   0,   1,      -2887,        0,       1401,       0],
-    [   0,  -1,   2,   0,   1,       2451,        0,      -1200,       0],
-    [   2,   0,  -2,   0,   0,      -2790,        0,        410,       0],
-    [  -1,   0,   0,   2,  -1,       2145,        0,      -1154,       0],
-    [   0,   0,   2,  -2,   0,       2816,        0,        286,       0],
-    [   0,   1,   0,  -2,   0,       2700,        0,       -258,       0],
-    [   1,   0,   0,  -1,   0,      -2330,        0,        -37,       0],
-    [   0,   0,   0,   0,   2,       2283,        0,      -1039,       0],
-    [   1,   0,  -2,   0,   0,      -2321,        0,        284,       0],
-    [  -1,   0,   0,   1,   1,      -2049,        0,       1112,       0],
+static NUTATION_COEFFS: [[i64; 11]; 77] = [
+    // nl  nl'   nF   nD   nΩ         S_i     S'_i        C_i    C'_i   S''_i   C''_i
+    [   0,   0,   0,   0,   1, -172064161, -174666,  92052331,   9086,  33386,  15377],
+    [   0,   0,   2,  -2,   2,  -13170906,   -1675,   5730336,  -3015, -13696,  -4587],
+    [   0,   0,   2,   0,   2,   -2276413,    -234,    978459,   -485,   2796,   1374],
+    [   0,   0,   0,   0,   2,    2074554,     207,   -897492,    470,   -698,   -291],
+    [   0,   1,   0,   0,   0,    1475877,   -3633,     73871,   -184,  11817,  -1924],
+    [   0,   1,   2,  -2,   2,    -516821,    1226,    224386,   -677,   -524,   -174],
+    [   1,   0,   0,   0,   0,     711159,      73,     -6750,      0,   -872,    358],
+    [   0,   0,   2,   0,   1,    -387298,    -367,    200728,     18,    380,    318],
+    [   1,   0,   2,   0,   2,    -301461,     -36,    129025,    -63,    816,    367],
+    [   0,  -1,   2,  -2,   2,     215829,    -494,    -95929,    299,    111,    132],
+    [   0,   0,   2,  -2,   1,     128227,     137,    -68982,     -9,    181,     39],
+    [  -1,   0,   2,   0,   2,     123457,      11,    -53311,     32,     19,     -4],
+    [  -1,   0,   0,   2,   0,     156994,      10,     -1235,      0,   -168,     82],
+    [   1,   0,   0,   0,   1,      63110,      63,    -33228,      0,     27,     -9],
+    [  -1,   0,   0,   0,   1,     -57976,     -63,     31429,      0,   -189,    -75],
+    [  -1,   0,   2,   2,   2,     -59641,     -11,     25543,    -11,    149,     66],
+    [   1,   0,   2,   0,   1,     -51613,     -42,     26366,      0,    129,     78],
+    [  -2,   0,   2,   0,   1,      45893,      50,    -24236,    -10,     31,     20],
+    [   0,   0,   0,   2,   0,      63384,      11,     -1220,      0,   -150,     29],
+    [   0,   0,   2,   2,   2,     -38571,      -1,     16452,    -11,    158,     68],
+    [   0,  -2,   2,  -2,   2,      32481,       0,    -13870,      0,      0,      0],
+    [  -2,   0,   0,   2,   0,     -47722,       0,       477,      0,    -18,    -25],
+    [   2,   0,   2,   0,   2,     -31046,      -1,     13238,    -11,    131,     59],
+    [   1,   0,   2,  -2,   2,      28593,       0,    -12338,     10,     -1,     -3],
+    [  -1,   0,   2,   0,   1,      20441,      21,    -10758,      0,     10,     -3],
+    [   2,   0,   0,   0,   0,      29243,       0,      -609,      0,    -74,     13],
+    [   0,   0,   2,   0,   0,      25887,       0,      -550,      0,    -66,     11],
+    [   0,   1,   0,   0,   1,     -14053,     -25,      8551,     -2,     79,    -45],
+    [  -1,   0,   0,   2,   1,      15164,      10,     -8001,      0,     11,     -1],
+    [   0,   2,   2,  -2,   2,     -15794,      72,      6850,    -42,    -16,     -5],
+    [   0,   0,  -2,   2,   0,      21783,       0,      -167,      0,     13,     13],
+    [   1,   0,   0,  -2,   1,     -12873,     -10,      6953,      0,    -37,    -14],
+    [   0,  -1,   0,   0,   1,     -12654,      11,      6415,      0,     63,     26],
+    [  -1,   0,   2,   2,   1,     -10204,       0,      5222,      0,     25,     15],
+    [   0,   2,   0,   0,   0,      16707,     -85,       168,     -1,    -10,     10],
+    [   1,   0,   2,   2,   2,      -7691,       0,      3268,      0,     44,     19],
+    [  -2,   0,   2,   0,   0,     -11024,       0,       104,      0,    -14,      2],
+    [   0,   1,   2,   0,   2,       7566,     -21,     -3250,      0,    -11,     -5],
+    [   0,   0,   2,   2,   1,      -6637,     -11,      3353,      0,     25,     14],
+    [   0,  -1,   2,   0,   2,      -7141,      21,      3070,      0,      8,      4],
+    [   0,   0,   0,   2,   1,      -6302,     -11,      3272,      0,      2,      4],
+    [   1,   0,   2,  -2,   1,       5800,      10,     -3045,      0,      2,     -1],
+    [   2,   0,   2,  -2,   2,       6443,       0,     -2768,      0,     -7,     -4],
+    [  -2,   0,   0,   2,   1,      -5774,     -11,      3041,      0,    -15,     -5],
+    [   2,   0,   2,   0,   1,      -5350,       0,      2695,      0,     21,     12],
+    [   0,  -1,   2,  -2,   1,      -4752,     -11,      2719,      0,     -3,     -3],
+    [   0,   0,   0,  -2,   1,      -4940,     -11,      2720,      0,    -21,     -9],
+    [  -1,  -1,   0,   2,   0,       7350,       0,       -51,      0,     -8,      4],
+    [   2,   0,   0,  -2,   1,       4065,       0,     -2206,      0,      6,      1],
+    [   1,   0,   0,   2,   0,       6579,       0,      -199,      0,    -24,      2],
+    [   0,   1,   2,  -2,   1,       3579,       0,     -1900,      0,      5,      1],
+    [   1,  -1,   0,   0,   0,       4725,       0,       -41,      0,     -6,      3],
+    [  -2,   0,   2,   0,   2,      -3075,       0,      1313,      0,     -2,     -1],
+    [   3,   0,   2,   0,   2,      -2904,       0,      1233,      0,     15,      7],
+    [   0,  -1,   0,   2,   0,       4348,       0,       -81,      0,    -10,      2],
+    [   1,  -1,   2,   0,   2,      -2878,       0,      1232,      0,      8,      4],
+    [   0,   0,   0,   1,   0,      -4230,       0,       -20,      0,      5,     -2],
+    [  -1,  -1,   2,   2,   2,      -2819,       0,      1207,      0,      7,      3],
+    [  -1,   0,   2,   0,   0,      -4056,       0,        40,      0,      5,     -2],
+    [   0,  -1,   2,   2,   2,      -2647,       0,      1129,      0,     11,      5],
+    [  -2,   0,   0,   0,   1,      -2294,       0,      1266,      0,    -10,     -4],
+    [   1,   1,   2,   0,   2,       2481,       0,     -1062,      0,     -7,     -3],
+    [   2,   0,   0,   0,   1,       2179,       0,     -1129,      0,     -2,     -2],
+    [  -1,   1,   0,   1,   0,       3276,       0,        -9,      0,      1,      0],
+    [   1,   1,   0,   0,   0,      -3389,       0,        35,      0,      5,     -2],
+    [   1,   0,   2,   0,   0,       3339,       0,      -107,      0,    -13,      1],
+    [  -1,   0,   2,  -2,   1,      -1987,       0,      1073,      0,     -6,     -2],
+    [   1,   0,   0,   0,   2,      -1981,       0,       854,      0,      0,      0],
+    [  -1,   0,   0,   1,   0,       4026,       0,      -553,      0,   -353,   -139],
+    [   0,   0,   2,   1,   2,       1660,       0,      -710,      0,     -5,     -2],
+    [  -1,   0,   2,   4,   2,      -1521,       0,       647,      0,      9,      4],
+    [  -1,   1,   0,   1,   1,       1314,       0,      -700,      0,      0,      0],
+    [   0,  -2,   2,  -2,   1,      -1283,       0,       672,      0,      0,      0],
+    [   1,   0,   2,   2,   1,      -1331,       0,       663,      0,      8,      4],
+    [  -2,   0,   2,   2,   2,       1383,       0,      -594,      0,     -2,     -2],
+    [  -1,   0,   0,   0,   2,       1405,       0,      -610,      0,      4,      2],
+    [   1,   1,   2,  -2,   2,       1290,       0,      -556,      0,      0,      0],
 ];
 
 /// IAU 2000B nutation: returns (Δψ, Δε) in arcseconds.
@@ -167,10 +184,10 @@ pub fn nutation_iau2000b(t: f64) -> (f64, f64) {
         let sin_arg = arg.sin();
         let cos_arg = arg.cos();
 
-        // Δψ: (S_i + S'_i * T) * sin(arg)
-        dpsi += (row[5] as f64 + row[6] as f64 * t) * sin_arg;
-        // Δε: (C_i + C'_i * T) * cos(arg)
-        deps += (row[7] as f64 + row[8] as f64 * t) * cos_arg;
+        // Δψ: (S_i + S'_i * T) * sin(arg) + S''_i * cos(arg)
+        dpsi += (row[5] as f64 + row[6] as f64 * t) * sin_arg + row[9] as f64 * cos_arg;
+        // Δε: (C_i + C'_i * T) * cos(arg) + C''_i * sin(arg)
+        deps += (row[7] as f64 + row[8] as f64 * t) * cos_arg + row[10] as f64 * sin_arg;
     }
 
     // Convert from 0.1 μas to arcseconds: 1 unit = 1e-7 arcsec
@@ -178,15 +195,143 @@ pub fn nutation_iau2000b(t: f64) -> (f64, f64) {
     let deps_arcsec = deps * 1e-7;
 
     // Add fixed offset corrections from IAU 2000B model
-    // (frame bias contributions to approximate IAU 2000A)
+    // (stand-in for the planetary terms of IAU 2000A)
     // dpsi offset: -0.135 mas = -0.000135 arcsec
-    // deps offset: -0.388 mas = -0.000388 arcsec
+    // deps offset: +0.388 mas = +0.000388 arcsec
     let dpsi_arcsec = dpsi_arcsec - 0.000_135;
-    let deps_arcsec = deps_arcsec - 0.000_388;
+    let deps_arcsec = deps_arcsec + 0.000_388;
 
     (dpsi_arcsec, deps_arcsec)
 }
 
+/// IAU 1980 nutation series coefficients (63 largest terms).
+///
+/// Each row: `[nD, nM, nM', nF, nΩ, ψ, ψ_T, ε, ε_T]` where ψ, ε are in
+/// 0.0001 arcsec and ψ_T, ε_T in 0.0001 arcsec per Julian century.
+/// Terms below 0.0003 arcsec are omitted.
+///
+/// Source: Seidelmann 1982, Table 1 (Wahr 1981 series).
+#[rustfmt::skip]
+static NUTATION_1980_COEFFS: [[f64; 9]; 63] = [
+    //  D     M     M'    F     Ω         ψ       ψ_T        ε      ε_T
+    [  0.0,  0.0,  0.0,  0.0,  1.0, -171996.0, -174.2,  92025.0,   8.9],
+    [ -2.0,  0.0,  0.0,  2.0,  2.0,  -13187.0,   -1.6,   5736.0,  -3.1],
+    [  0.0,  0.0,  0.0,  2.0,  2.0,   -2274.0,   -0.2,    977.0,  -0.5],
+    [  0.0,  0.0,  0.0,  0.0,  2.0,    2062.0,    0.2,   -895.0,   0.5],
+    [  0.0,  1.0,  0.0,  0.0,  0.0,    1426.0,   -3.4,     54.0,  -0.1],
+    [  0.0,  0.0,  1.0,  0.0,  0.0,     712.0,    0.1,     -7.0,   0.0],
+    [ -2.0,  1.0,  0.0,  2.0,  2.0,    -517.0,    1.2,    224.0,  -0.6],
+    [  0.0,  0.0,  0.0,  2.0,  1.0,    -386.0,   -0.4,    200.0,   0.0],
+    [  0.0,  0.0,  1.0,  2.0,  2.0,    -301.0,    0.0,    129.0,  -0.1],
+    [ -2.0, -1.0,  0.0,  2.0,  2.0,     217.0,   -0.5,    -95.0,   0.3],
+    [ -2.0,  0.0,  1.0,  0.0,  0.0,    -158.0,    0.0,      0.0,   0.0],
+    [ -2.0,  0.0,  0.0,  2.0,  1.0,     129.0,    0.1,    -70.0,   0.0],
+    [  0.0,  0.0, -1.0,  2.0,  2.0,     123.0,    0.0,    -53.0,   0.0],
+    [  2.0,  0.0,  0.0,  0.0,  0.0,      63.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0,  1.0,  0.0,  1.0,      63.0,    0.1,    -33.0,   0.0],
+    [  2.0,  0.0, -1.0,  2.0,  2.0,     -59.0,    0.0,     26.0,   0.0],
+    [  0.0,  0.0, -1.0,  0.0,  1.0,     -58.0,   -0.1,     32.0,   0.0],
+    [  0.0,  0.0,  1.0,  2.0,  1.0,     -51.0,    0.0,     27.0,   0.0],
+    [ -2.0,  0.0,  2.0,  0.0,  0.0,      48.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0, -2.0,  2.0,  1.0,      46.0,    0.0,    -24.0,   0.0],
+    [  2.0,  0.0,  0.0,  2.0,  2.0,     -38.0,    0.0,     16.0,   0.0],
+    [  0.0,  0.0,  2.0,  2.0,  2.0,     -31.0,    0.0,     13.0,   0.0],
+    [  0.0,  0.0,  2.0,  0.0,  0.0,      29.0,    0.0,      0.0,   0.0],
+    [ -2.0,  0.0,  1.0,  2.0,  2.0,      29.0,    0.0,    -12.0,   0.0],
+    [  0.0,  0.0,  0.0,  2.0,  0.0,      26.0,    0.0,      0.0,   0.0],
+    [ -2.0,  0.0,  0.0,  2.0,  0.0,     -22.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0, -1.0,  2.0,  1.0,      21.0,    0.0,    -10.0,   0.0],
+    [  0.0,  2.0,  0.0,  0.0,  0.0,      17.0,   -0.1,      0.0,   0.0],
+    [  2.0,  0.0, -1.0,  0.0,  1.0,      16.0,    0.0,     -8.0,   0.0],
+    [ -2.0,  2.0,  0.0,  2.0,  2.0,     -16.0,    0.1,      7.0,   0.0],
+    [  0.0,  1.0,  0.0,  0.0,  1.0,     -15.0,    0.0,      9.0,   0.0],
+    [ -2.0,  0.0,  1.0,  0.0,  1.0,     -13.0,    0.0,      7.0,   0.0],
+    [  0.0, -1.0,  0.0,  0.0,  1.0,     -12.0,    0.0,      6.0,   0.0],
+    [  0.0,  0.0,  2.0, -2.0,  0.0,      11.0,    0.0,      0.0,   0.0],
+    [  2.0,  0.0, -1.0,  2.0,  1.0,     -10.0,    0.0,      5.0,   0.0],
+    [  2.0,  0.0,  1.0,  2.0,  2.0,      -8.0,    0.0,      3.0,   0.0],
+    [  0.0,  1.0,  0.0,  2.0,  2.0,       7.0,    0.0,     -3.0,   0.0],
+    [ -2.0,  1.0,  1.0,  0.0,  0.0,      -7.0,    0.0,      0.0,   0.0],
+    [  0.0, -1.0,  0.0,  2.0,  2.0,      -7.0,    0.0,      3.0,   0.0],
+    [  2.0,  0.0,  0.0,  2.0,  1.0,      -7.0,    0.0,      3.0,   0.0],
+    [  2.0,  0.0,  1.0,  0.0,  0.0,       6.0,    0.0,      0.0,   0.0],
+    [ -2.0,  0.0,  2.0,  2.0,  2.0,       6.0,    0.0,     -3.0,   0.0],
+    [ -2.0,  0.0,  1.0,  2.0,  1.0,       6.0,    0.0,     -3.0,   0.0],
+    [  2.0,  0.0, -2.0,  0.0,  1.0,      -6.0,    0.0,      3.0,   0.0],
+    [  2.0,  0.0,  0.0,  0.0,  1.0,      -6.0,    0.0,      3.0,   0.0],
+    [  0.0, -1.0,  1.0,  0.0,  0.0,       5.0,    0.0,      0.0,   0.0],
+    [ -2.0, -1.0,  0.0,  2.0,  1.0,      -5.0,    0.0,      3.0,   0.0],
+    [ -2.0,  0.0,  0.0,  0.0,  1.0,      -5.0,    0.0,      3.0,   0.0],
+    [  0.0,  0.0,  2.0,  2.0,  1.0,      -5.0,    0.0,      3.0,   0.0],
+    [ -2.0,  0.0,  2.0,  0.0,  1.0,       4.0,    0.0,      0.0,   0.0],
+    [ -2.0,  1.0,  0.0,  2.0,  1.0,       4.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0,  1.0, -2.0,  0.0,       4.0,    0.0,      0.0,   0.0],
+    [ -1.0,  0.0,  1.0,  0.0,  0.0,      -4.0,    0.0,      0.0,   0.0],
+    [ -2.0,  1.0,  0.0,  0.0,  0.0,      -4.0,    0.0,      0.0,   0.0],
+    [  1.0,  0.0,  0.0,  0.0,  0.0,      -4.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0,  1.0,  2.0,  0.0,       3.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0, -2.0,  2.0,  2.0,      -3.0,    0.0,      0.0,   0.0],
+    [ -1.0, -1.0,  1.0,  0.0,  0.0,      -3.0,    0.0,      0.0,   0.0],
+    [  0.0,  1.0,  1.0,  0.0,  0.0,      -3.0,    0.0,      0.0,   0.0],
+    [  0.0, -1.0,  1.0,  2.0,  2.0,      -3.0,    0.0,      0.0,   0.0],
+    [  2.0, -1.0, -1.0,  2.0,  2.0,      -3.0,    0.0,      0.0,   0.0],
+    [  0.0,  0.0,  3.0,  2.0,  2.0,      -3.0,    0.0,      0.0,   0.0],
+    [  2.0, -1.0,  0.0,  2.0,  2.0,      -3.0,    0.0,      0.0,   0.0],
+];
+
+/// IAU 1980 nutation: returns (Δψ, Δε) in arcseconds.
+///
+/// Uses the IAU 1980 fundamental arguments (D, M, M', F, Ω as in
+/// Seidelmann 1982) and the 63 largest terms of the series, accurate to
+/// ~1 mas against the full 106-term series.
+///
+/// # Arguments
+/// * `t` — Julian centuries of TDB since J2000.0
+pub fn nutation_iau1980(t: f64) -> (f64, f64) {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // Degrees
+    let d = 297.85036 + 445_267.111480 * t - 0.0019142 * t2 + t3 / 189_474.0;
+    let m = 357.52772 + 35_999.050340 * t - 0.0001603 * t2 - t3 / 300_000.0;
+    let mp = 134.96298 + 477_198.867398 * t + 0.0086972 * t2 + t3 / 56_250.0;
+    let f = 93.27191 + 483_202.017538 * t - 0.0036825 * t2 + t3 / 327_270.0;
+    let om = 125.04452 - 1934.136261 * t + 0.0020708 * t2 + t3 / 450_000.0;
+    let args = [
+        d.to_radians(),
+        m.to_radians(),
+        mp.to_radians(),
+        f.to_radians(),
+        om.to_radians(),
+    ];
+
+    let mut dpsi: f64 = 0.0;
+    let mut deps: f64 = 0.0;
+    for row in &NUTATION_1980_COEFFS {
+        let arg = row[0] * args[0]
+            + row[1] * args[1]
+            + row[2] * args[2]
+            + row[3] * args[3]
+            + row[4] * args[4];
+        let (sin_arg, cos_arg) = arg.sin_cos();
+        dpsi += (row[5] + row[6] * t) * sin_arg;
+        deps += (row[7] + row[8] * t) * cos_arg;
+    }
+
+    // 0.0001 arcsec → arcseconds
+    (dpsi * 1e-4, deps * 1e-4)
+}
+
+/// Nutation for the selected model: returns (Δψ, Δε) in arcseconds.
+///
+/// `t` = Julian centuries of TDB since J2000.0.
+pub fn nutation_with_model(t: f64, model: NutationModel) -> (f64, f64) {
+    match model {
+        NutationModel::Iau1980 => nutation_iau1980(t),
+        NutationModel::Iau2000B => nutation_iau2000b(t),
+    }
+}
+
 /// Equation of equinoxes (radians) and true obliquity (radians).
 ///
 /// Computes `nutation_iau2000b(t)` once, then:
@@ -195,7 +340,15 @@ pub fn nutation_iau2000b(t: f64) -> (f64, f64) {
 ///
 /// Returns `(ee_rad, eps_true_rad)`.
 pub fn equation_of_equinoxes_and_true_obliquity(t: f64) -> (f64, f64) {
-    let (dpsi_arcsec, deps_arcsec) = nutation_iau2000b(t);
+    equation_of_equinoxes_and_true_obliquity_with_model(t, DEFAULT_NUTATION_MODEL)
+}
+
+/// Equation of equinoxes and true obliquity (radians) for the selected model.
+pub fn equation_of_equinoxes_and_true_obliquity_with_model(
+    t: f64,
+    model: NutationModel,
+) -> (f64, f64) {
+    let (dpsi_arcsec, deps_arcsec) = nutation_with_model(t, model);
     let eps_mean = crate::obliquity::mean_obliquity_of_date_rad(t);
     let ee = (dpsi_arcsec / 3600.0).to_radians() * eps_mean.cos();
     let eps_true = eps_mean + (deps_arcsec / 3600.0).to_radians();
@@ -231,6 +384,28 @@ mod tests {
         assert!(deps.abs() < 10.0, "|Δε| should be < 10 arcsec, got {deps}");
     }
 
+    #[test]
+    fn iau2000b_matches_sofa_nut00b() {
+        // IAU SOFA nut00b at TT 2400000.5 + 53736.0. SOFA uses linear
+        // fundamental arguments; the polynomial terms here move Ω by
+        // ~0.03″, a few μas of Δψ.
+        let t = (2_400_000.5 + 53_736.0 - 2_451_545.0) / 36525.0;
+        let (dpsi, deps) = nutation_iau2000b(t);
+        let rad_to_arcsec = 648_000.0 / std::f64::consts::PI;
+        let dpsi_ref = -0.963_255_229_114_836_3e-5 * rad_to_arcsec;
+        let deps_ref = 0.406_319_710_662_115_9e-4 * rad_to_arcsec;
+        assert!(
+            (dpsi - dpsi_ref).abs() < 1e-5,
+            "Δψ off by {}",
+            dpsi - dpsi_ref
+        );
+        assert!(
+            (deps - deps_ref).abs() < 1e-5,
+            "Δε off by {}",
+            deps - deps_ref
+        );
+    }
+
     #[test]
     fn symmetry_over_nutation_period() {
         // Nutation has ~18.6 year period (Ω). Values at t and t+18.6yr
@@ -275,4 +450,40 @@ mod tests {
             ee.abs()
         );
     }
+
+    #[test]
+    fn iau1980_agrees_with_2000b() {
+        // IAU 1980 differs from IAU 2000B by up to ~15 mas.
+        for &t in &[-1.0, -0.3, 0.0, 0.24, 0.5, 1.0] {
+            let (psi_b, eps_b) = nutation_iau2000b(t);
+            let (psi_80, eps_80) = nutation_iau1980(t);
+            assert!(
+                (psi_80 - psi_b).abs() < 0.05,
+                "t={t}: Δψ 80-B = {}",
+                psi_80 - psi_b
+            );
+            assert!(
+                (eps_80 - eps_b).abs() < 0.02,
+                "t={t}: Δε 80-B = {}",
+                eps_80 - eps_b
+            );
+        }
+    }
+
+    #[test]
+    fn with_model_dispatches() {
+        let t = 0.24;
+        assert_eq!(
+            nutation_with_model(t, NutationModel::Iau2000B),
+            nutation_iau2000b(t)
+        );
+        assert_eq!(
+            nutation_with_model(t, NutationModel::Iau1980),
+            nutation_iau1980(t)
+        );
+        assert_eq!(
+            equation_of_equinoxes_and_true_obliquity_with_model(t, DEFAULT_NUTATION_MODEL),
+            equation_of_equinoxes_and_true_obliquity(t)
+        );
+    }
 }
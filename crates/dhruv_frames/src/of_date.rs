@@ -0,0 +1,395 @@
+//! Rotations from ICRF/J2000 to mean and true frames of date.
+//!
+//! All four frames are built on the ecliptic precession path:
+//! ICRF → frame bias → mean equator J2000 → ecliptic J2000 →
+//! (model-dependent) ecliptic of date, followed by
+//! - true equinox: rotation about the ecliptic pole by Δψ (IAU 2000B or
+//!   IAU 1980, per [`NutationModel`]),
+//! - equator: rotation about the equinox by the obliquity of date
+//!   (mean ε_A, or true ε_A + Δε).
+//!
+//! The obliquity is the IAU 2006 polynomial for every precession model,
+//! matching [`crate::mean_obliquity_of_date_rad`] use elsewhere, including
+//! its J2000 value ε₀ = 84381.406″ for the J2000 ecliptic. That ecliptic is
+//! therefore not the 84381.448″ [`crate::icrf_to_ecliptic`] frame used for
+//! ecliptic J2000 outputs.
+//!
+//! Sources: IERS Conventions 2010, Chapter 5 (frame bias, eq. 5.21;
+//! equinox-based transformation);
+//! Explanatory Supplement to the Astronomical Almanac (3rd ed.), §6.
+//! Implementation is original.
+
+use crate::nutation::{NutationModel, nutation_with_model};
+use crate::obliquity::mean_obliquity_of_date_rad;
+use crate::precession::{PrecessionModel, precess_ecliptic_j2000_to_date_with_model};
+
+/// IERS 2010 frame bias angles (eq. 5.21) in arcseconds: the J2000 mean
+/// pole offsets ξ₀, η₀ and the equinox offset dα₀.
+const BIAS_XI0_ARCSEC: f64 = -0.016_617;
+const BIAS_ETA0_ARCSEC: f64 = -0.006_819_2;
+const BIAS_DALPHA0_ARCSEC: f64 = -0.014_6;
+
+/// Reference frames of date reachable from ICRF/J2000.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameOfDate {
+    /// Mean equator and mean equinox of date.
+    MeanEquator,
+    /// True equator and true equinox of date.
+    TrueEquator,
+    /// Ecliptic of date with the mean equinox.
+    MeanEcliptic,
+    /// Ecliptic of date with the true equinox (longitude + Δψ).
+    TrueEcliptic,
+}
+
+/// Half-width of the central difference used for the frame rotation rate,
+/// in Julian centuries (0.01 day).
+const RATE_STEP_CENTURIES: f64 = 0.01 / 36525.0;
+
+/// Seconds per Julian century.
+const SECONDS_PER_CENTURY: f64 = 36525.0 * 86_400.0;
+
+/// Rotate a vector about the Z axis by `angle` (counter-clockwise).
+fn rotate_z(v: &[f64; 3], angle: f64) -> [f64; 3] {
+    let (s, c) = angle.sin_cos();
+    [c * v[0] - s * v[1], s * v[0] + c * v[1], v[2]]
+}
+
+/// ICRF → mean equator and equinox of J2000: `B = R1(−η₀)·R2(ξ₀)·R3(dα₀)`.
+fn frame_bias(v: &[f64; 3]) -> [f64; 3] {
+    let angle = |arcsec: f64| (arcsec / 3600.0).to_radians();
+    // R3(dα₀) is the vector rotation by −dα₀ about Z.
+    let v = rotate_z(v, -angle(BIAS_DALPHA0_ARCSEC));
+    let (s, c) = angle(BIAS_XI0_ARCSEC).sin_cos();
+    let v = [c * v[0] - s * v[2], v[1], s * v[0] + c * v[2]];
+    ecliptic_to_equator(&v, angle(BIAS_ETA0_ARCSEC))
+}
+
+/// Equator-of-date → ecliptic-of-date: `R_x(ε)`.
+fn equator_to_ecliptic(v: &[f64; 3], eps: f64) -> [f64; 3] {
+    ecliptic_to_equator(v, -eps)
+}
+
+/// Ecliptic-of-date → equator-of-date: `R_x(-ε)`.
+fn ecliptic_to_equator(v: &[f64; 3], eps: f64) -> [f64; 3] {
+    let (s, c) = eps.sin_cos();
+    [v[0], c * v[1] - s * v[2], s * v[1] + c * v[2]]
+}
+
+/// Rotate a 3-vector from ICRF/J2000 to a frame of date.
+///
+/// `t` = Julian centuries of TDB since J2000.0. `nutation` only affects the
+/// true frames.
+pub fn icrf_to_frame_of_date(
+    v: &[f64; 3],
+    t: f64,
+    frame: FrameOfDate,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> [f64; 3] {
+    let ecl_j2000 = equator_to_ecliptic(&frame_bias(v), mean_obliquity_of_date_rad(0.0));
+    let ecl_date = precess_ecliptic_j2000_to_date_with_model(&ecl_j2000, t, model);
+    match frame {
+        FrameOfDate::MeanEcliptic => ecl_date,
+        FrameOfDate::MeanEquator => ecliptic_to_equator(&ecl_date, mean_obliquity_of_date_rad(t)),
+        FrameOfDate::TrueEcliptic => {
+            let (dpsi_arcsec, _) = nutation_with_model(t, nutation);
+            rotate_z(&ecl_date, (dpsi_arcsec / 3600.0).to_radians())
+        }
+        FrameOfDate::TrueEquator => {
+            let (dpsi_arcsec, deps_arcsec) = nutation_with_model(t, nutation);
+            let true_ecl = rotate_z(&ecl_date, (dpsi_arcsec / 3600.0).to_radians());
+            let eps_true = mean_obliquity_of_date_rad(t) + (deps_arcsec / 3600.0).to_radians();
+            ecliptic_to_equator(&true_ecl, eps_true)
+        }
+    }
+}
+
+/// Rotation matrix from ICRF/J2000 to a frame of date (row-major).
+pub fn icrf_to_frame_of_date_matrix(
+    t: f64,
+    frame: FrameOfDate,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> [[f64; 3]; 3] {
+    let cols = [
+        icrf_to_frame_of_date(&[1.0, 0.0, 0.0], t, frame, model, nutation),
+        icrf_to_frame_of_date(&[0.0, 1.0, 0.0], t, frame, model, nutation),
+        icrf_to_frame_of_date(&[0.0, 0.0, 1.0], t, frame, model, nutation),
+    ];
+    let mut m = [[0.0f64; 3]; 3];
+    for (j, col) in cols.iter().enumerate() {
+        for i in 0..3 {
+            m[i][j] = col[i];
+        }
+    }
+    m
+}
+
+/// Transform an ICRF/J2000 state (km, km/s) to a frame of date.
+///
+/// Velocity is the time derivative of the position expressed in the
+/// rotating frame: `v' = R·v + (dR/dt)·r`, with `dR/dt` from a central
+/// difference of the rotation matrix.
+pub fn icrf_state_to_frame_of_date(
+    position_km: &[f64; 3],
+    velocity_km_s: &[f64; 3],
+    t: f64,
+    frame: FrameOfDate,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> ([f64; 3], [f64; 3]) {
+    let position = icrf_to_frame_of_date(position_km, t, frame, model, nutation);
+    let rotated_velocity = icrf_to_frame_of_date(velocity_km_s, t, frame, model, nutation);
+
+    let ahead = icrf_to_frame_of_date_matrix(t + RATE_STEP_CENTURIES, frame, model, nutation);
+    let behind = icrf_to_frame_of_date_matrix(t - RATE_STEP_CENTURIES, frame, model, nutation);
+    let dt_s = 2.0 * RATE_STEP_CENTURIES * SECONDS_PER_CENTURY;
+
+    let mut velocity = rotated_velocity;
+    for (i, v) in velocity.iter_mut().enumerate() {
+        for j in 0..3 {
+            *v += (ahead[i][j] - behind[i][j]) / dt_s * position_km[j];
+        }
+    }
+    (position, velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nutation::nutation_iau2000b;
+    use crate::precession::DEFAULT_PRECESSION_MODEL;
+    use crate::rotation::icrf_to_ecliptic;
+    use crate::spherical::cartesian_to_spherical;
+
+    const ALL_FRAMES: [FrameOfDate; 4] = [
+        FrameOfDate::MeanEquator,
+        FrameOfDate::TrueEquator,
+        FrameOfDate::MeanEcliptic,
+        FrameOfDate::TrueEcliptic,
+    ];
+
+    #[test]
+    fn mean_equator_at_j2000_is_frame_bias() {
+        // To first order B = [[1, dα₀, −ξ₀], [−dα₀, 1, −η₀], [ξ₀, η₀, 1]].
+        let m = icrf_to_frame_of_date_matrix(
+            0.0,
+            FrameOfDate::MeanEquator,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        let rad = |arcsec: f64| (arcsec / 3600.0).to_radians();
+        let (xi0, eta0, da0) = (
+            rad(BIAS_XI0_ARCSEC),
+            rad(BIAS_ETA0_ARCSEC),
+            rad(BIAS_DALPHA0_ARCSEC),
+        );
+        let expected = [[1.0, da0, -xi0], [-da0, 1.0, -eta0], [xi0, eta0, 1.0]];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((m[i][j] - expected[i][j]).abs() < 1e-14, "[{i}][{j}]");
+            }
+        }
+    }
+
+    #[test]
+    fn mean_equator_matches_iau_2006_bias_precession() {
+        // IAU SOFA bp06 example, rbp at TT 2400000.5 + 50123.9999.
+        let t = (2_400_000.5 + 50_123.999_9 - 2_451_545.0) / 36525.0;
+        let reference = [
+            [
+                0.999_999_550_517_600_7,
+                0.869_540_461_734_820_8e-3,
+                0.377_973_520_186_558_9e-3,
+            ],
+            [
+                -0.869_540_472_377_203_1e-3,
+                0.999_999_621_949_602_7,
+                -0.136_175_249_708_027e-6,
+            ],
+            [
+                -0.377_973_495_703_408_9e-3,
+                -0.192_488_084_789_445_7e-6,
+                0.999_999_928_567_997_2,
+            ],
+        ];
+        let m = icrf_to_frame_of_date_matrix(
+            t,
+            FrameOfDate::MeanEquator,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                let diff = m[i][j] - reference[i][j];
+                assert!(diff.abs() < 1e-11, "[{i}][{j}] off by {diff:e}");
+            }
+        }
+    }
+
+    #[test]
+    fn true_pole_matches_iau_2006_cip() {
+        // CIP X, Y at TT 2400000.5 + 53736.0 from the IAU SOFA s06/c2t06a
+        // examples (bpn2xy of pnm06a). Those use the full IAU 2000A
+        // nutation, which IAU 2000B follows to about 1 mas (5e-9 rad); an
+        // unbiased pole is off by ξ₀, η₀ (8e-8, 3.3e-8 rad).
+        let t = (2_400_000.5 + 53_736.0 - 2_451_545.0) / 36525.0;
+        let m = icrf_to_frame_of_date_matrix(
+            t,
+            FrameOfDate::TrueEquator,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        let (x, y) = (0.579_130_848_670_601_1e-3, 0.402_057_981_673_296_1e-4);
+        assert!((m[2][0] - x).abs() < 5e-9, "X off by {:e}", m[2][0] - x);
+        assert!((m[2][1] - y).abs() < 5e-9, "Y off by {:e}", m[2][1] - y);
+    }
+
+    #[test]
+    fn mean_ecliptic_at_j2000_uses_iau_2006_obliquity() {
+        // Against the 84381.448″ ecliptic J2000 frame the pole tilts by
+        // -(42 - 6.8192) mas · cos ε: the obliquity change less η₀.
+        let v = [0.0, 0.0, 1.0];
+        let out = icrf_to_frame_of_date(
+            &v,
+            0.0,
+            FrameOfDate::MeanEcliptic,
+            DEFAULT_PRECESSION_MODEL,
+            NutationModel::Iau2000B,
+        );
+        let ecl = icrf_to_ecliptic(&v);
+        let tilt_mas = (out[1] - ecl[1]).to_degrees() * 3.6e6;
+        assert!((tilt_mas + 32.278).abs() < 0.01, "{tilt_mas}");
+    }
+
+    #[test]
+    fn matrices_are_orthonormal() {
+        for frame in ALL_FRAMES {
+            let m = icrf_to_frame_of_date_matrix(
+                0.25,
+                frame,
+                PrecessionModel::Iau2006,
+                NutationModel::Iau2000B,
+            );
+            for i in 0..3 {
+                for j in 0..3 {
+                    let dot: f64 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!(
+                        (dot - expected).abs() < 1e-14,
+                        "{frame:?} [{i}][{j}] = {dot}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn true_ecliptic_longitude_is_mean_plus_dpsi() {
+        let t = 0.24;
+        let v = [0.3, 0.9, 0.1];
+        let mean = icrf_to_frame_of_date(
+            &v,
+            t,
+            FrameOfDate::MeanEcliptic,
+            DEFAULT_PRECESSION_MODEL,
+            NutationModel::Iau2000B,
+        );
+        let true_ = icrf_to_frame_of_date(
+            &v,
+            t,
+            FrameOfDate::TrueEcliptic,
+            DEFAULT_PRECESSION_MODEL,
+            NutationModel::Iau2000B,
+        );
+        let (dpsi_arcsec, _) = nutation_iau2000b(t);
+        let diff = cartesian_to_spherical(&true_).lon_deg - cartesian_to_spherical(&mean).lon_deg;
+        assert!((diff * 3600.0 - dpsi_arcsec).abs() < 1e-6);
+        assert!(
+            (cartesian_to_spherical(&true_).lat_deg - cartesian_to_spherical(&mean).lat_deg).abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn nutation_model_only_moves_true_frames() {
+        let t = 0.24;
+        let v = [0.3, 0.9, 0.1];
+        let at = |frame, nutation| {
+            icrf_to_frame_of_date(&v, t, frame, PrecessionModel::Iau2006, nutation)
+        };
+        for frame in [FrameOfDate::MeanEquator, FrameOfDate::MeanEcliptic] {
+            assert_eq!(
+                at(frame, NutationModel::Iau1980),
+                at(frame, NutationModel::Iau2000B)
+            );
+        }
+        let lon =
+            |nutation| cartesian_to_spherical(&at(FrameOfDate::TrueEcliptic, nutation)).lon_deg;
+        let expected = nutation_with_model(t, NutationModel::Iau1980).0
+            - nutation_with_model(t, NutationModel::Iau2000B).0;
+        let diff = (lon(NutationModel::Iau1980) - lon(NutationModel::Iau2000B)) * 3600.0;
+        assert!(expected.abs() > 1e-3);
+        assert!((diff - expected).abs() < 1e-6, "{diff} vs {expected}");
+    }
+
+    #[test]
+    fn fixed_direction_drifts_at_precession_rate() {
+        // A direction fixed in ICRF gains ~50.3"/yr of ecliptic longitude.
+        let t = 0.25;
+        let pos = [1.0e9, 2.0e8, -1.0e8];
+        let (p, v) = icrf_state_to_frame_of_date(
+            &pos,
+            &[0.0; 3],
+            t,
+            FrameOfDate::MeanEcliptic,
+            DEFAULT_PRECESSION_MODEL,
+            NutationModel::Iau2000B,
+        );
+        let rho2 = p[0] * p[0] + p[1] * p[1];
+        let lon_rate_rad_s = (p[0] * v[1] - p[1] * v[0]) / rho2;
+        let arcsec_per_year = lon_rate_rad_s.to_degrees() * 3600.0 * 365.25 * 86_400.0;
+        assert!(
+            (arcsec_per_year - 50.29).abs() < 0.1,
+            "rate = {arcsec_per_year}\"/yr"
+        );
+    }
+
+    #[test]
+    fn state_position_matches_vector_rotation() {
+        let pos = [1.0e8, -2.0e7, 4.0e7];
+        let vel = [10.0, 20.0, -5.0];
+        for frame in ALL_FRAMES {
+            let (p, v) = icrf_state_to_frame_of_date(
+                &pos,
+                &vel,
+                -0.5,
+                frame,
+                PrecessionModel::Lieske1977,
+                NutationModel::Iau2000B,
+            );
+            assert_eq!(
+                p,
+                icrf_to_frame_of_date(
+                    &pos,
+                    -0.5,
+                    frame,
+                    PrecessionModel::Lieske1977,
+                    NutationModel::Iau2000B
+                )
+            );
+            let rotated = icrf_to_frame_of_date(
+                &vel,
+                -0.5,
+                frame,
+                PrecessionModel::Lieske1977,
+                NutationModel::Iau2000B,
+            );
+            for i in 0..3 {
+                // Frame-rate term is ~1e-3 km/s at 1e8 km.
+                assert!((v[i] - rotated[i]).abs() < 1e-2, "{frame:?} axis {i}");
+            }
+        }
+    }
+}
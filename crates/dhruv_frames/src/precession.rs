@@ -46,10 +46,10 @@ const AS2R: f64 = PI / 648_000.0;
 #[derive(Clone, Copy)]
 struct VondrakTable1Term {
     period_centuries: f64,
-    ap: f64,
-    bp: f64,
-    aq: f64,
-    bq: f64,
+    cp: f64,
+    cq: f64,
+    sp: f64,
+    sq: f64,
 }
 
 #[derive(Clone, Copy)]
@@ -62,59 +62,59 @@ struct VondrakTable3Term {
 const VON_TABLE1_TERMS: [VondrakTable1Term; 8] = [
     VondrakTable1Term {
         period_centuries: 708.15,
-        ap: -5_486.751_211,
-        bp: -684.661_560,
-        aq: 667.666_730,
-        bq: -5_523.863_691,
+        cp: -5_486.751_211,
+        cq: -684.661_560,
+        sp: 667.666_730,
+        sq: -5_523.863_691,
     },
     VondrakTable1Term {
         period_centuries: 2309.0,
-        ap: -17.127_623,
-        bp: 2_446.283_880,
-        aq: -2_354.886_252,
-        bq: -549.747_450,
+        cp: -17.127_623,
+        cq: 2_446.283_880,
+        sp: -2_354.886_252,
+        sq: -549.747_450,
     },
     VondrakTable1Term {
         period_centuries: 1620.0,
-        ap: -617.517_403,
-        bp: 399.671_049,
-        aq: -428.152_441,
-        bq: -310.998_056,
+        cp: -617.517_403,
+        cq: 399.671_049,
+        sp: -428.152_441,
+        sq: -310.998_056,
     },
     VondrakTable1Term {
         period_centuries: 492.2,
-        ap: 413.442_940,
-        bp: -356.652_376,
-        aq: 376.202_861,
-        bq: 421.535_876,
+        cp: 413.442_940,
+        cq: -356.652_376,
+        sp: 376.202_861,
+        sq: 421.535_876,
     },
     VondrakTable1Term {
         period_centuries: 1183.0,
-        ap: 78.614_193,
-        bp: -186.387_003,
-        aq: 184.778_874,
-        bq: -36.776_172,
+        cp: 78.614_193,
+        cq: -186.387_003,
+        sp: 184.778_874,
+        sq: -36.776_172,
     },
     VondrakTable1Term {
         period_centuries: 622.0,
-        ap: -180.732_815,
-        bp: -316.800_070,
-        aq: 335.321_713,
-        bq: -145.278_396,
+        cp: -180.732_815,
+        cq: -316.800_070,
+        sp: 335.321_713,
+        sq: -145.278_396,
     },
     VondrakTable1Term {
         period_centuries: 882.0,
-        ap: -87.676_083,
-        bp: 198.296_701,
-        aq: -185.138_669,
-        bq: -34.744_450,
+        cp: -87.676_083,
+        cq: 198.296_701,
+        sp: -185.138_669,
+        sq: -34.744_450,
     },
     VondrakTable1Term {
         period_centuries: 547.0,
-        ap: 46.140_315,
-        bp: 101.135_679,
-        aq: -120.972_830,
-        bq: 22.885_731,
+        cp: 46.140_315,
+        cq: 101.135_679,
+        sp: -120.972_830,
+        sq: 22.885_731,
     },
 ];
 
@@ -176,6 +176,7 @@ fn vondrak2011_periodic_argument_rad(t: f64, period_centuries: f64) -> f64 {
     TAU * t / period_centuries
 }
 
+/// Vondrák et al. 2011 eq. (8) with Table 1: P_A, Q_A of the ecliptic pole.
 #[inline]
 fn vondrak2011_pq_raw_rad(t: f64) -> (f64, f64) {
     let t2 = t * t;
@@ -184,18 +185,16 @@ fn vondrak2011_pq_raw_rad(t: f64) -> (f64, f64) {
     let mut q_arcsec = -1_600.886_300 + 1.168_981_8 * t - 0.000_000_20 * t2 - 0.000_000_437 * t3;
     for term in VON_TABLE1_TERMS {
         let (s, c) = vondrak2011_periodic_argument_rad(t, term.period_centuries).sin_cos();
-        // Vondrak Table 1 uses opposite sign for the p-series sine term
-        // under this positive argument convention.
-        p_arcsec += term.ap * c - term.bp * s;
-        q_arcsec += term.aq * c + term.bq * s;
+        p_arcsec += term.cp * c + term.sp * s;
+        q_arcsec += term.cq * c + term.sq * s;
     }
     (p_arcsec * AS2R, q_arcsec * AS2R)
 }
 
 #[inline]
 fn vondrak2011_pq_rad(t: f64) -> (f64, f64) {
-    // Eq. (4)/(5) contain a fitted J2000 offset. Normalize so these track
-    // ecliptic precession relative to J2000 in this API.
+    // The tabulated coefficients leave a ~1e-13" residual at J2000;
+    // remove it so π_A vanishes exactly there.
     let (p, q) = vondrak2011_pq_raw_rad(t);
     let (p0, q0) = vondrak2011_pq_raw_rad(0.0);
     (p - p0, q - q0)
@@ -239,16 +238,18 @@ fn lieske1977_general_precession_longitude_arcsec(t: f64) -> f64 {
 
 #[inline]
 fn lieske1977_ecliptic_inclination_arcsec(t: f64) -> f64 {
+    // Lieske 1977 Table 5 with the starting epoch fixed at J2000 (T = 0).
     let t2 = t * t;
     let t3 = t2 * t;
-    47.0029 * t - 0.06603 * t2 + 0.000598 * t3
+    47.0029 * t - 0.03302 * t2 + 0.000060 * t3
 }
 
 #[inline]
 fn lieske1977_ecliptic_node_longitude_arcsec(t: f64) -> f64 {
-    // 174°52'34.982" = 629554.982"
+    // 174°52'34.982" = 629554.982"; the +3289.4789"T term moves the
+    // starting epoch, the interval rate is -869.8089"t.
     let t2 = t * t;
-    629_554.982 + 3289.4789 * t + 0.60622 * t2
+    629_554.982 - 869.8089 * t + 0.03536 * t2
 }
 
 #[inline]
@@ -276,10 +277,8 @@ fn iau2006_ecliptic_node_longitude_arcsec(t: f64) -> f64 {
     let t3 = t2 * t;
     let t4 = t3 * t;
     let t5 = t4 * t;
-    629_546.793_6 + 3_289.478_9 * t + 0.606_22 * t2
-        - 0.000_83 * t3
-        - 0.000_01 * t4
-        - 0.000_000_01 * t5
+    629_546.793_6 - 867.957_58 * t + 0.157_992 * t2 - 0.000_537_1 * t3 - 0.000_047_97 * t4
+        + 0.000_000_072 * t5
 }
 
 #[inline]
@@ -573,6 +572,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn iau2006_ecliptic_pole_matches_p03_pq() {
+        // P_A = sin π_A sin Π_A, Q_A = sin π_A cos Π_A from the P03
+        // canonical series (Capitaine et al. 2003, eq. 39).
+        for &t in &[-1.0_f64, -0.5, 0.06, 0.5, 1.0] {
+            let pi_a = (iau2006_ecliptic_inclination_arcsec(t) / 3600.0).to_radians();
+            let cap = (iau2006_ecliptic_node_longitude_arcsec(t) / 3600.0).to_radians();
+            let t2 = t * t;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            let t5 = t4 * t;
+            let p = 4.199_094 * t + 0.193_987_3 * t2 - 0.000_224_66 * t3 - 0.000_000_912 * t4
+                + 0.000_000_012 * t5;
+            let q = -46.811_015 * t + 0.051_028_3 * t2 + 0.000_524_13 * t3
+                - 0.000_006_46 * t4
+                - 0.000_000_017_2 * t5;
+            let dp = pi_a.sin() * cap.sin() / AS2R - p;
+            let dq = pi_a.sin() * cap.cos() / AS2R - q;
+            assert!(
+                dp.abs() < 1e-4 && dq.abs() < 1e-4,
+                "t={t}: ΔP={dp}\" ΔQ={dq}\""
+            );
+        }
+    }
+
     // ---------- precession matrix ----------
 
     #[test]
@@ -725,8 +749,8 @@ mod tests {
 
     #[test]
     fn vondrak_pq_tracks_iau_near_modern_epochs() {
-        // Around modern epochs the long-term Vondrak series should closely
-        // track IAU 2006 p/q components (sub-arcsecond scale).
+        // Around modern epochs the long-term Vondrak series tracks the
+        // IAU 2006 P_A/Q_A components to well under a mas.
         for &t in &[-1.0_f64, -0.6804, 0.26, 1.0] {
             let (p_v, q_v) = vondrak2011_pq_rad(t);
             let pi_i = (iau2006_ecliptic_inclination_arcsec(t) / 3600.0).to_radians();
@@ -736,8 +760,8 @@ mod tests {
 
             let p_err_arcsec = ((p_v - p_i) / AS2R).abs();
             let q_err_arcsec = ((q_v - q_i) / AS2R).abs();
-            assert!(p_err_arcsec < 2.0, "t={t}: |Δp|={p_err_arcsec}\"");
-            assert!(q_err_arcsec < 0.1, "t={t}: |Δq|={q_err_arcsec}\"");
+            assert!(p_err_arcsec < 1e-3, "t={t}: |Δp|={p_err_arcsec}\"");
+            assert!(q_err_arcsec < 1e-3, "t={t}: |Δq|={q_err_arcsec}\"");
         }
     }
 }
@@ -0,0 +1,377 @@
+//! CIO-based celestial (GCRS) ↔ terrestrial (ITRS) rotation.
+//!
+//! `[ITRS] = W(t) · R3(ERA) · Q(t) · [GCRS]`, where
+//! - `Q` is the GCRS → CIRS rotation built from the CIP coordinates X, Y
+//!   (taken from the IAU 2006 / IAU 2000B true-equator matrix of
+//!   [`crate::of_date`],
+//!   plus the observed IERS offsets dX, dY) and the CIO locator s,
+//! - `ERA` is the Earth Rotation Angle, supplied by the caller,
+//! - `W` is the polar motion matrix from the IERS pole coordinates and the
+//!   TIO locator s′.
+//!
+//! Sources: IERS Conventions 2010, Chapter 5 (§5.4.1, §5.4.4, §5.5.1–5.5.2,
+//! Table 5.2d for the series of s). Implementation is original.
+
+use std::f64::consts::TAU;
+
+use crate::nutation::NutationModel;
+use crate::nutation::fundamental_arguments;
+use crate::of_date::{FrameOfDate, icrf_to_frame_of_date_matrix};
+use crate::precession::PrecessionModel;
+
+/// Arcseconds to radians conversion factor.
+const AS2RAD: f64 = TAU / 1_296_000.0;
+
+/// Microarcseconds to radians conversion factor.
+const UAS2RAD: f64 = AS2RAD * 1e-6;
+
+/// Polynomial part of `s + XY/2` in µas (powers 0–5 of `t`).
+const S_POLYNOMIAL_UAS: [f64; 6] = [94.0, 3808.65, -122.68, -72574.11, 27.98, 15.62];
+
+/// Periodic terms of `s + XY/2` at `t⁰`: (multipliers of l, l′, F, D, Ω;
+/// sine amplitude in µas). Terms below 1 µas are omitted.
+const S_TERMS_T0: [([f64; 5], f64); 10] = [
+    ([0.0, 0.0, 0.0, 0.0, 1.0], -2640.73),
+    ([0.0, 0.0, 0.0, 0.0, 2.0], -63.53),
+    ([0.0, 0.0, 2.0, -2.0, 3.0], -11.75),
+    ([0.0, 0.0, 2.0, -2.0, 1.0], -11.21),
+    ([0.0, 0.0, 2.0, -2.0, 2.0], 4.57),
+    ([0.0, 0.0, 2.0, 0.0, 3.0], -2.02),
+    ([0.0, 0.0, 2.0, 0.0, 1.0], -1.98),
+    ([0.0, 0.0, 0.0, 0.0, 3.0], 1.72),
+    ([0.0, 1.0, 0.0, 0.0, 1.0], 1.41),
+    ([0.0, 1.0, 0.0, 0.0, -1.0], 1.26),
+];
+
+/// Periodic terms of `s + XY/2` at `t²`.
+const S_TERMS_T2: [([f64; 5], f64); 4] = [
+    ([0.0, 0.0, 0.0, 0.0, 1.0], 743.52),
+    ([0.0, 0.0, 2.0, -2.0, 2.0], 56.91),
+    ([0.0, 0.0, 2.0, 0.0, 2.0], 9.84),
+    ([0.0, 0.0, 0.0, 0.0, 2.0], -8.85),
+];
+
+/// Rate of the TIO locator s′ in arcseconds per century.
+const S_PRIME_RATE_ARCSEC: f64 = -47e-6;
+
+/// IERS pole parameters for the celestial ↔ terrestrial rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PoleParameters {
+    /// Pole coordinate x_p in arcseconds.
+    pub xp_arcsec: f64,
+    /// Pole coordinate y_p in arcseconds.
+    pub yp_arcsec: f64,
+    /// Celestial pole offset dX in arcseconds.
+    pub dx_arcsec: f64,
+    /// Celestial pole offset dY in arcseconds.
+    pub dy_arcsec: f64,
+}
+
+/// CIP coordinates `(X, Y)` in the GCRS, in radians, from the IAU 2006 /
+/// IAU 2000B true-equator rotation (without IERS offsets).
+///
+/// The IERS dX, dY are published against IAU 2006/2000A, so the CIP always
+/// uses IAU 2000B nutation rather than a caller-selected model.
+///
+/// `t` = Julian centuries of TDB since J2000.0.
+pub fn cip_xy(t: f64) -> (f64, f64) {
+    let m = icrf_to_frame_of_date_matrix(
+        t,
+        FrameOfDate::TrueEquator,
+        PrecessionModel::Iau2006,
+        NutationModel::Iau2000B,
+    );
+    (m[2][0], m[2][1])
+}
+
+/// CIO locator s in radians for the CIP coordinates `x`, `y` (radians).
+///
+/// `t` = Julian centuries of TDB since J2000.0. The retained series terms
+/// reproduce the full IAU 2006/2000A series to about 1 µas over this
+/// century.
+pub fn cio_locator_s(t: f64, x: f64, y: f64) -> f64 {
+    let args = fundamental_arguments(t);
+    let series = |terms: &[([f64; 5], f64)]| {
+        terms
+            .iter()
+            .map(|(mult, amplitude)| {
+                let arg: f64 = mult.iter().zip(&args).map(|(m, a)| m * a).sum();
+                amplitude * arg.sin()
+            })
+            .sum::<f64>()
+    };
+    let polynomial = S_POLYNOMIAL_UAS
+        .iter()
+        .rev()
+        .fold(0.0, |acc, c| acc * t + c);
+    let s_plus_xy2 = polynomial + series(&S_TERMS_T0) + t * t * series(&S_TERMS_T2);
+    s_plus_xy2 * UAS2RAD - x * y / 2.0
+}
+
+/// TIO locator s′ in radians (`−47 µas · t`).
+///
+/// `t` = Julian centuries of TT since J2000.0.
+pub fn tio_locator_s_prime(t: f64) -> f64 {
+    S_PRIME_RATE_ARCSEC * t * AS2RAD
+}
+
+/// Rotation matrix (row-major) from GCRS to the Celestial Intermediate
+/// Reference System, including the IERS offsets dX, dY from `pole`.
+///
+/// `t` = Julian centuries of TDB since J2000.0.
+pub fn gcrs_to_cirs_matrix(t: f64, pole: &PoleParameters) -> [[f64; 3]; 3] {
+    let (x0, y0) = cip_xy(t);
+    let x = x0 + pole.dx_arcsec * AS2RAD;
+    let y = y0 + pole.dy_arcsec * AS2RAD;
+    let s = cio_locator_s(t, x, y);
+
+    let r2 = x * x + y * y;
+    let e = if r2 > 0.0 { y.atan2(x) } else { 0.0 };
+    let d = (r2 / (1.0 - r2)).sqrt().atan();
+    mat_mul(&rot_z(-(e + s)), &mat_mul(&rot_y(d), &rot_z(e)))
+}
+
+/// Polar motion matrix `W = R1(−y_p) · R2(−x_p) · R3(s′)` (row-major), from
+/// the terrestrial intermediate system to ITRS.
+///
+/// `t` = Julian centuries of TT since J2000.0.
+pub fn polar_motion_matrix(t: f64, pole: &PoleParameters) -> [[f64; 3]; 3] {
+    let xp = pole.xp_arcsec * AS2RAD;
+    let yp = pole.yp_arcsec * AS2RAD;
+    mat_mul(
+        &rot_x(-yp),
+        &mat_mul(&rot_y(-xp), &rot_z(tio_locator_s_prime(t))),
+    )
+}
+
+/// Rotation matrix (row-major) from GCRS to ITRS.
+///
+/// * `t` — Julian centuries of TDB since J2000.0
+/// * `era_rad` — Earth Rotation Angle at the matching UT1
+/// * `pole` — IERS pole coordinates and celestial pole offsets
+pub fn celestial_to_terrestrial_matrix(
+    t: f64,
+    era_rad: f64,
+    pole: &PoleParameters,
+) -> [[f64; 3]; 3] {
+    let c2i = gcrs_to_cirs_matrix(t, pole);
+    mat_mul(
+        &polar_motion_matrix(t, pole),
+        &mat_mul(&rot_z(era_rad), &c2i),
+    )
+}
+
+/// Rotation matrix (row-major) from ITRS to GCRS; the transpose of
+/// [`celestial_to_terrestrial_matrix`].
+pub fn terrestrial_to_celestial_matrix(
+    t: f64,
+    era_rad: f64,
+    pole: &PoleParameters,
+) -> [[f64; 3]; 3] {
+    let m = celestial_to_terrestrial_matrix(t, era_rad, pole);
+    [0, 1, 2].map(|i| [m[0][i], m[1][i], m[2][i]])
+}
+
+/// Rotate a GCRS vector into ITRS.
+pub fn gcrs_to_itrs(v: &[f64; 3], t: f64, era_rad: f64, pole: &PoleParameters) -> [f64; 3] {
+    mat_vec(&celestial_to_terrestrial_matrix(t, era_rad, pole), v)
+}
+
+/// Rotate an ITRS vector into GCRS.
+pub fn itrs_to_gcrs(v: &[f64; 3], t: f64, era_rad: f64, pole: &PoleParameters) -> [f64; 3] {
+    mat_vec(&terrestrial_to_celestial_matrix(t, era_rad, pole), v)
+}
+
+/// Frame rotation about X by `angle` (SOFA `R1` convention).
+fn rot_x(angle: f64) -> [[f64; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    [[1.0, 0.0, 0.0], [0.0, c, s], [0.0, -s, c]]
+}
+
+/// Frame rotation about Y by `angle` (SOFA `R2` convention).
+fn rot_y(angle: f64) -> [[f64; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    [[c, 0.0, -s], [0.0, 1.0, 0.0], [s, 0.0, c]]
+}
+
+/// Frame rotation about Z by `angle` (SOFA `R3` convention).
+fn rot_z(angle: f64) -> [[f64; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    [[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, 1.0]]
+}
+
+fn mat_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for (row, a_row) in out.iter_mut().zip(a) {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = a_row[0] * b[0][j] + a_row[1] * b[1][j] + a_row[2] * b[2][j];
+        }
+    }
+    out
+}
+
+fn mat_vec(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nutation::{equation_of_equinoxes_and_true_obliquity, nutation_iau2000b};
+
+    const MAS: f64 = AS2RAD * 1e-3;
+
+    #[test]
+    fn cio_locator_reference_value() {
+        // IAU SOFA s06 at TT 2400000.5 + 53736.0 with X, Y from the same
+        // example: s = -0.1220032213076463117e-7 rad.
+        let t = (2_400_000.5 + 53_736.0 - 2_451_545.0) / 36525.0;
+        let s = cio_locator_s(t, 0.5791308486706011e-3, 0.4020579816732961e-4);
+        assert!(
+            (s - -0.122_003_221_307_646_3e-7).abs() < 2.0 * UAS2RAD,
+            "{s}"
+        );
+    }
+
+    #[test]
+    fn cip_matches_true_pole_of_date() {
+        // At J2000 the CIP is displaced from the ICRF pole by the frame
+        // bias and nutation: X ≈ ξ₀ + Δψ·sin ε, Y ≈ η₀ + Δε.
+        let (x, y) = cip_xy(0.0);
+        let (dpsi, deps) = nutation_iau2000b(0.0);
+        let (dpsi, deps) = (dpsi * AS2RAD, deps * AS2RAD);
+        let eps = 84_381.406 * AS2RAD;
+        let (xi0, eta0) = (-16.617 * MAS, -6.8192 * MAS);
+        assert!((x - xi0 - dpsi * eps.sin()).abs() < MAS, "{}", x / AS2RAD);
+        assert!((y - eta0 - deps).abs() < MAS, "{}", y / AS2RAD);
+        // Precession moves X by about 2004″ per century.
+        let (x1, _) = cip_xy(0.1);
+        let (x0, _) = cip_xy(-0.1);
+        assert!(((x1 - x0) / AS2RAD - 400.8).abs() < 2.0);
+    }
+
+    #[test]
+    fn matches_sofa_c2t06a() {
+        // IAU SOFA c2t06a example: TT = UT1 = 2400000.5 + 53736.0,
+        // x_p = 2.55060238e-7 rad, y_p = 1.860359247e-6 rad. SOFA uses the
+        // full IAU 2000A nutation; IAU 2000B stays within 0.2 mas here,
+        // while a missing frame bias would show up at ~8e-8.
+        let t = (2_400_000.5 + 53_736.0 - 2_451_545.0) / 36525.0;
+        let du: f64 = 53_736.0 - 51_544.5;
+        let era = TAU * (0.779_057_273_264 + 0.002_737_811_911_354_48 * du + du.fract());
+        let pole = PoleParameters {
+            xp_arcsec: 2.550_602_38e-7 / AS2RAD,
+            yp_arcsec: 1.860_359_247e-6 / AS2RAD,
+            ..Default::default()
+        };
+        let reference = [
+            [
+                -0.181_033_212_852_868_6,
+                0.983_476_980_689_768_5,
+                0.655_553_563_998_263_4e-4,
+            ],
+            [
+                -0.983_476_813_409_521_1,
+                -0.181_033_220_387_102_4,
+                0.574_980_111_612_643_9e-3,
+            ],
+            [
+                0.577_347_401_408_153_9e-3,
+                0.396_183_239_176_864_1e-4,
+                0.999_999_832_550_169_2,
+            ],
+        ];
+        let m = celestial_to_terrestrial_matrix(t, era.rem_euclid(TAU), &pole);
+        for i in 0..3 {
+            for j in 0..3 {
+                let diff = m[i][j] - reference[i][j];
+                assert!(diff.abs() < 2e-9, "[{i}][{j}] off by {diff:e}");
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_is_orthonormal_and_inverts() {
+        let pole = PoleParameters {
+            xp_arcsec: 0.2,
+            yp_arcsec: 0.35,
+            dx_arcsec: 0.0003,
+            dy_arcsec: -0.0002,
+        };
+        let m = celestial_to_terrestrial_matrix(0.24, 1.3, &pole);
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f64 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-14);
+            }
+        }
+        let v = [0.3, -0.8, 0.52];
+        let back = itrs_to_gcrs(&gcrs_to_itrs(&v, 0.24, 1.3, &pole), 0.24, 1.3, &pole);
+        for axis in 0..3 {
+            assert!((back[axis] - v[axis]).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn agrees_with_equinox_based_rotation() {
+        // R3(ERA)·Q and R3(GAST)·N·P describe the same rotation when GAST is
+        // ERA minus the equation of the origins. Build GAST from the CIO
+        // locator and compare the two paths directly.
+        let t = 0.23;
+        let era = 2.1;
+        let np = icrf_to_frame_of_date_matrix(
+            t,
+            FrameOfDate::TrueEquator,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        let q = gcrs_to_cirs_matrix(t, &PoleParameters::default());
+        // CIO direction in the true-equinox frame: its right ascension is
+        // minus the equation of the origins.
+        let cio_gcrs = [q[0][0], q[0][1], q[0][2]];
+        let cio_true = mat_vec(&np, &cio_gcrs);
+        let eo = -cio_true[1].atan2(cio_true[0]);
+        let gast = era - eo;
+
+        let cio_path = mat_mul(&rot_z(era), &q);
+        let equinox_path = mat_mul(&rot_z(gast), &np);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((cio_path[i][j] - equinox_path[i][j]).abs() < 1e-12);
+            }
+        }
+
+        // The equation of the origins is dominated by accumulated precession
+        // in RA (the IAU 2006 GMST polynomial less ERA) plus the equation of
+        // the equinoxes.
+        let (ee, _) = equation_of_equinoxes_and_true_obliquity(t);
+        let expected = -(0.014_506 + t * (4_612.156_534 + t * 1.391_581_7)) * AS2RAD - ee;
+        assert!(
+            (eo - expected).abs() < 0.05 * AS2RAD,
+            "{}",
+            (eo - expected) / AS2RAD
+        );
+    }
+
+    #[test]
+    fn pole_parameters_tilt_terrestrial_axes() {
+        let t = 0.2;
+        let base = celestial_to_terrestrial_matrix(t, 0.0, &PoleParameters::default());
+        let moved = celestial_to_terrestrial_matrix(
+            t,
+            0.0,
+            &PoleParameters {
+                xp_arcsec: 1.0,
+                ..Default::default()
+            },
+        );
+        // The ITRS Z axis in GCRS shifts by x_p.
+        let z = |m: &[[f64; 3]; 3]| [m[2][0], m[2][1], m[2][2]];
+        let (a, b) = (z(&base), z(&moved));
+        let sep = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+        assert!((sep / AS2RAD - 1.0).abs() < 1e-6);
+
+        assert!((tio_locator_s_prime(1.0) / AS2RAD + 47e-6).abs() < 1e-15);
+    }
+}
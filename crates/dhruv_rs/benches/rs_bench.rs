@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use dhruv_core::Query;
+use dhruv_core::{Correction, Query};
 use dhruv_rs::{
     Body, DhruvContext, EngineConfig, Frame, LunarPhaseKind, LunarPhaseRequest,
     LunarPhaseRequestQuery, LunarPhaseResult, Observer, TimeInput, UtcDate, lunar_phase,
@@ -32,6 +32,7 @@ fn rs_query_bench(c: &mut Criterion) {
         observer: Observer::Body(Body::Earth),
         frame: Frame::EclipticJ2000,
         epoch_tdb_jd: utc.to_jd_tdb(ctx.engine().lsk()),
+        correction: Correction::Geometric,
     };
 
     let mut group = c.benchmark_group("dhruv_rs_query");
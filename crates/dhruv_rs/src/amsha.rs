@@ -22,6 +22,7 @@ pub fn amsha_variations_many(amshas: &[Amsha]) -> Vec<AmshaVariationCatalog> {
 }
 
 /// Compute amsha charts for a given date and location using explicit configs.
+#[allow(clippy::too_many_arguments)]
 pub fn charts_for_date(
     ctx: &DhruvContext,
     eop: &EopKernel,
@@ -50,6 +51,7 @@ pub fn charts_for_date(
 }
 
 /// Compute amsha charts for a given date and location using default bhava and rise/set configs.
+#[allow(clippy::too_many_arguments)]
 pub fn charts(
     ctx: &DhruvContext,
     eop: &EopKernel,
@@ -75,6 +77,7 @@ pub fn charts(
 }
 
 /// Compute a single amsha chart using explicit configs.
+#[allow(clippy::too_many_arguments)]
 pub fn chart_for_date(
     ctx: &DhruvContext,
     eop: &EopKernel,
@@ -107,6 +110,7 @@ pub fn chart_for_date(
 }
 
 /// Compute a single amsha chart using default bhava and rise/set configs.
+#[allow(clippy::too_many_arguments)]
 pub fn chart(
     ctx: &DhruvContext,
     eop: &EopKernel,
@@ -1,20 +1,25 @@
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use dhruv_time::UtcTime;
+use dhruv_time::{LocalTime, UtcTime};
 
 use crate::DhruvError;
 
 /// UTC calendar date with sub-second precision.
 ///
 /// Used as input to convenience functions. Supports construction via
-/// [`UtcDate::new`] or parsing ISO 8601 strings via [`FromStr`].
+/// [`UtcDate::new`] or parsing ISO 8601 strings via [`FromStr`]. Local
+/// times with a UTC offset or time zone are converted to UTC while parsing
+/// (see [`LocalTime`]).
 ///
 /// ```
 /// use dhruv_rs::UtcDate;
 /// let d: UtcDate = "2024-03-20T12:00:00Z".parse().unwrap();
 /// assert_eq!(d.year, 2024);
 /// assert_eq!(d.month, 3);
+///
+/// let local: UtcDate = "1943-06-01T12:00:00[Asia/Kolkata]".parse().unwrap();
+/// assert_eq!((local.hour, local.min), (5, 30));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct UtcDate {
@@ -61,7 +66,10 @@ impl Display for UtcDate {
 
 /// Parse ISO 8601 subset: `YYYY-MM-DDTHH:MM:SS[.f]Z`
 ///
-/// Supports fractional seconds. The trailing `Z` is required.
+/// Supports fractional seconds. A zone designator is required: the
+/// trailing `Z`, or a local-time designator (`+05:30`, `[Asia/Kolkata]`,
+/// `-04:00[America/New_York]`, `[LMT+77.209]`) that is resolved to UTC.
+/// Repeated or skipped local readings are rejected.
 impl FromStr for UtcDate {
     type Err = DhruvError;
 
@@ -70,6 +78,19 @@ impl FromStr for UtcDate {
 
         let bytes = s.as_bytes();
 
+        // Skip a leading sign so negative years are not taken as offsets.
+        let time_part = s
+            .get(1..)
+            .and_then(|r| r.find(['T', ' ']))
+            .map_or("", |i| &s[i + 1..]);
+        if !s.ends_with('Z') && time_part.contains(['+', '-', '[']) {
+            return s
+                .parse::<LocalTime>()
+                .and_then(|local| local.to_utc())
+                .map(UtcDate::from)
+                .map_err(|e| DhruvError::DateParse(format!("{e}: {s}")));
+        }
+
         // Minimum: "YYYY-MM-DDTHH:MM:SSZ" = 20 chars
         if bytes.len() < 20 {
             return Err(err());
@@ -176,6 +197,25 @@ mod tests {
         assert_eq!(d.year, -500);
     }
 
+    #[test]
+    fn parse_local_time_designators() {
+        let offset: UtcDate = "2024-03-20T12:00:00+05:30".parse().unwrap();
+        assert_eq!((offset.hour, offset.min), (6, 30));
+        let zoned: UtcDate = "2024-07-01T12:00:00[Europe/London]".parse().unwrap();
+        assert_eq!(zoned.hour, 11);
+        let tokyo: UtcDate = "2024-07-01T12:00:00[Asia/Tokyo]".parse().unwrap();
+        assert_eq!(tokyo.hour, 3);
+        assert!(
+            "2024-11-03T01:30:00[America/New_York]"
+                .parse::<UtcDate>()
+                .is_err()
+        );
+        let picked: UtcDate = "2024-11-03T01:30:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+        assert_eq!(picked.hour, 6);
+    }
+
     #[test]
     fn rejects_missing_z() {
         assert!("2024-03-20T12:00:00".parse::<UtcDate>().is_err());
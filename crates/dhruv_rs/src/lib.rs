@@ -64,7 +64,10 @@ pub use dhruv_search::{
 pub use dhruv_tara::{
     EarthState, EquatorialPosition, TaraAccuracy, TaraCatalog, TaraConfig, TaraError, TaraId,
 };
-pub use dhruv_time::{EopKernel, TimeConversionOptions, TimeConversionPolicy, UtcTime};
+pub use dhruv_time::{
+    Disambiguation, EopKernel, LocalTime, TimeConversionOptions, TimeConversionPolicy, TimeZone,
+    UtcTime,
+};
 pub use dhruv_vedic_base::dasha::{
     DashaEntity, DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot, DashaSystem,
     DashaVariationConfig, SubPeriodMethod, YoginiScheme,
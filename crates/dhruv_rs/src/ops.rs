@@ -400,6 +400,7 @@ pub fn ayanamsha_op(ctx: &DhruvContext, request: &AyanamshaRequest) -> Result<f6
         mode,
         at_jd_tdb: time_input_to_jd_tdb(ctx, request.at),
         use_nutation,
+        nutation_model: dhruv_frames::DEFAULT_NUTATION_MODEL,
         delta_psi_arcsec,
     };
     Ok(dhruv_vedic_ops::ayanamsha(&op)?)
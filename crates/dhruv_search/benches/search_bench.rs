@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Engine, EngineConfig, SpkLoadMode};
 use dhruv_search::{
     AmshaChartScope, AmshaSelectionConfig, SankrantiConfig, next_purnima, next_sankranti,
 };
@@ -25,6 +25,7 @@ fn load_engine() -> Option<Engine> {
             lsk_path: lsk,
             cache_capacity: 256,
             strict_validation: true,
+            spk_load_mode: SpkLoadMode::InMemory,
         })
         .expect("should load engine"),
     )
@@ -224,6 +225,7 @@ fn avastha_for_date_bench(c: &mut Criterion) {
     group.finish();
 }
 
+#[allow(clippy::field_reassign_with_default)]
 fn dasha_hierarchy_bench(c: &mut Criterion) {
     let engine = match load_engine() {
         Some(v) => v,
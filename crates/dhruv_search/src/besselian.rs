@@ -0,0 +1,587 @@
+//! Besselian elements and ground paths of surya grahan (solar eclipses).
+//!
+//! Besselian elements describe the Moon's shadow on the fundamental plane:
+//! the plane through Earth's center perpendicular to the Moon→Sun axis.
+//!
+//! Elements algorithm:
+//!   1. Query apparent geocentric Sun and Moon on the true equator of date
+//!   2. Shadow axis = Sun − Moon; its direction gives `a` (RA) and `d`
+//!   3. Project the Moon onto the fundamental plane for `x`, `y`, `z`
+//!   4. Cone half-angles from the Sun and Moon radii give `l1`, `l2`
+//!   5. `μ` = GAST − `a`; rates by central differences
+//!
+//! Path algorithm:
+//!   1. Sample elements across the grahan
+//!   2. Central line: shadow axis `(x, y)` projected onto the WGS84 ellipsoid
+//!   3. Limits: the point of the shadow outline that is stationary relative
+//!      to the rotating Earth (outline envelope)
+//!   4. Sunrise/sunset curves: where the penumbral outline crosses the
+//!      Earth's limb
+//!
+//! Sources: Explanatory Supplement to the Astronomical Almanac (Ch. 11,
+//! Besselian elements), Meeus "Elements of Solar Eclipses". See
+//! docs/clean_room_grahan.md.
+
+use dhruv_core::topocentric::WGS84_F;
+use dhruv_core::{Body, BodyRadii, Correction, Engine, EpochQuery, Frame, Observer};
+use dhruv_frames::{DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL};
+use dhruv_time::{EopKernel, Epoch};
+
+use crate::besselian_types::{
+    BesselianElements, SuryaGrahanPath, SuryaGrahanPathConfig, SuryaGrahanPathLine,
+    SuryaGrahanPathPoint,
+};
+use crate::error::SearchError;
+use crate::grahan_types::SuryaGrahan;
+use crate::search_util::{SearchInstant, earth_rotation_at};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Central-difference half-step for element rates (days): 5 minutes.
+const RATE_STEP_DAYS: f64 = 5.0 / 1440.0;
+
+/// Half-width of the path sampling window around geocentric greatest grahan
+/// (days). The penumbra touches the Earth for at most ~6.5 hours.
+const PATH_HALF_WINDOW_DAYS: f64 = 4.5 / 24.0;
+
+/// Fixed-point iterations for a limit point on the ellipsoid.
+const LIMIT_ITERATIONS: usize = 8;
+
+// ---------------------------------------------------------------------------
+// Elements
+// ---------------------------------------------------------------------------
+
+/// Instantaneous elements without rates (angles in radians).
+#[derive(Debug, Clone, Copy)]
+struct ShadowGeometry {
+    x: f64,
+    y: f64,
+    d: f64,
+    mu: f64,
+    l1: f64,
+    l2: f64,
+    tan_f1: f64,
+    tan_f2: f64,
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn shadow_geometry(
+    engine: &Engine,
+    epoch: Epoch,
+    eop: Option<&EopKernel>,
+    radii: &BodyRadii,
+) -> Result<ShadowGeometry, SearchError> {
+    let query = |target| EpochQuery {
+        target,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::TrueEquatorOfDate(DEFAULT_PRECESSION_MODEL, DEFAULT_NUTATION_MODEL),
+        epoch,
+        correction: Correction::LightTimeAberration,
+    };
+    let er = radii.earth_equatorial_km;
+    let sun = engine.query(query(Body::Sun))?.position_km.map(|c| c / er);
+    let moon = engine.query(query(Body::Moon))?.position_km.map(|c| c / er);
+
+    let axis = [sun[0] - moon[0], sun[1] - moon[1], sun[2] - moon[2]];
+    let g = dot(&axis, &axis).sqrt();
+    let k = axis.map(|c| c / g);
+    let d = k[2].clamp(-1.0, 1.0).asin();
+    let a = k[1].atan2(k[0]);
+    let i = [-a.sin(), a.cos(), 0.0];
+    let j = [
+        k[1] * i[2] - k[2] * i[1],
+        k[2] * i[0] - k[0] * i[2],
+        k[0] * i[1] - k[1] * i[0],
+    ];
+    let z = dot(&moon, &k);
+
+    let sun_r = radii.sun_km / er;
+    let moon_r = radii.moon_km / er;
+    let sin_f1 = (sun_r + moon_r) / g;
+    let sin_f2 = (sun_r - moon_r) / g;
+    let tan_f1 = sin_f1 / (1.0 - sin_f1 * sin_f1).sqrt();
+    let tan_f2 = sin_f2 / (1.0 - sin_f2 * sin_f2).sqrt();
+
+    let (jd_ut1, _) = earth_rotation_at(engine, epoch, eop)?;
+    let gast = dhruv_time::gast_rad(
+        jd_ut1,
+        epoch.as_jd_tdb(),
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    );
+
+    Ok(ShadowGeometry {
+        x: dot(&moon, &i),
+        y: dot(&moon, &j),
+        d,
+        mu: (gast - a).rem_euclid(std::f64::consts::TAU),
+        l1: (z + moon_r / sin_f1) * tan_f1,
+        l2: (z - moon_r / sin_f2) * tan_f2,
+        tan_f1,
+        tan_f2,
+    })
+}
+
+/// Besselian elements of the Moon's shadow at `at` (see [`SearchInstant`]).
+///
+/// Uses apparent (light time + aberration) geocentric Sun and Moon on the
+/// true equator of date, with radii from `config`. `μ` takes UT1 from the
+/// LSK plus DUT1 from `eop` (UT1 = UTC without it). Rates are central
+/// differences over ±5 minutes.
+pub fn besselian_elements(
+    engine: &Engine,
+    at: impl SearchInstant,
+    eop: Option<&EopKernel>,
+    config: &SuryaGrahanPathConfig,
+) -> Result<BesselianElements, SearchError> {
+    let epoch = at.to_epoch(engine);
+    let now = shadow_geometry(engine, epoch, eop, &config.radii)?;
+    let before = shadow_geometry(engine, epoch.add_days(-RATE_STEP_DAYS), eop, &config.radii)?;
+    let after = shadow_geometry(engine, epoch.add_days(RATE_STEP_DAYS), eop, &config.radii)?;
+
+    let span_h = 2.0 * RATE_STEP_DAYS * 24.0;
+    let rate = |f: fn(&ShadowGeometry) -> f64| (f(&after) - f(&before)) / span_h;
+    let mu_step = (after.mu - before.mu + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU)
+        - std::f64::consts::PI;
+
+    Ok(BesselianElements {
+        jd_tdb: epoch.as_jd_tdb(),
+        epoch,
+        x: now.x,
+        y: now.y,
+        d_deg: now.d.to_degrees(),
+        mu_deg: now.mu.to_degrees(),
+        l1: now.l1,
+        l2: now.l2,
+        tan_f1: now.tan_f1,
+        tan_f2: now.tan_f2,
+        x_rate: rate(|g| g.x),
+        y_rate: rate(|g| g.y),
+        d_rate_deg: rate(|g| g.d).to_degrees(),
+        mu_rate_deg: (mu_step / span_h).to_degrees(),
+        l1_rate: rate(|g| g.l1),
+        l2_rate: rate(|g| g.l2),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Fundamental plane ↔ Earth surface
+// ---------------------------------------------------------------------------
+
+/// Square of the WGS84 eccentricity.
+fn eccentricity_sq() -> f64 {
+    WGS84_F * (2.0 - WGS84_F)
+}
+
+/// `ζ` of the sunward ellipsoid point at fundamental-plane `(ξ, η)`, or
+/// `None` if `(ξ, η)` lies outside the Earth's outline.
+fn surface_zeta(xi: f64, eta: f64, d: f64) -> Option<f64> {
+    let e2 = eccentricity_sq();
+    let c = e2 / (1.0 - e2);
+    let (sin_d, cos_d) = d.sin_cos();
+    // ξ² + η² + ζ² + c·Z² = 1 with Z = η·cos d + ζ·sin d
+    let qa = 1.0 + c * sin_d * sin_d;
+    let qb = 2.0 * c * eta * cos_d * sin_d;
+    let qc = xi * xi + eta * eta * (1.0 + c * cos_d * cos_d) - 1.0;
+    let disc = qb * qb - 4.0 * qa * qc;
+    (disc >= 0.0).then(|| (-qb + disc.sqrt()) / (2.0 * qa))
+}
+
+/// Geodetic latitude and east longitude (degrees) of the surface point
+/// `(ξ, η, ζ)` for axis declination `d` and hour angle `μ` (radians).
+fn fundamental_to_geodetic(xi: f64, eta: f64, zeta: f64, d: f64, mu: f64) -> (f64, f64) {
+    let (sin_d, cos_d) = d.sin_cos();
+    // Equatorial coordinates with the X axis at the shadow axis' RA
+    let x = zeta * cos_d - eta * sin_d;
+    let y = xi;
+    let z = eta * cos_d + zeta * sin_d;
+    let lat = z.atan2((1.0 - eccentricity_sq()) * x.hypot(y));
+    let lon = y.atan2(x) - mu;
+    (
+        lat.to_degrees(),
+        (lon.to_degrees() + 180.0).rem_euclid(360.0) - 180.0,
+    )
+}
+
+/// Where the shadow axis meets the Earth: `(lat, lon)` in degrees.
+fn central_point(el: &BesselianElements) -> Option<(f64, f64)> {
+    let d = el.d_deg.to_radians();
+    let zeta = surface_zeta(el.x, el.y, d)?;
+    Some(fundamental_to_geodetic(
+        el.x,
+        el.y,
+        zeta,
+        d,
+        el.mu_deg.to_radians(),
+    ))
+}
+
+/// Limit point of the shadow cone `(l, l′, tan f)` on one side of the track.
+///
+/// `side` = +1 for the left (north) side of the shadow's motion relative to
+/// the ground, −1 for the right (south). The point lies on the shadow
+/// outline where it is stationary in time (the envelope of the outlines):
+/// `u·u′ + v·v′ = L·L′` with `(u, v)` the point relative to the axis and
+/// `L = l − ζ·tan f`.
+fn limit_point(
+    el: &BesselianElements,
+    l: f64,
+    l_rate: f64,
+    tan_f: f64,
+    side: f64,
+) -> Option<(f64, f64)> {
+    let d = el.d_deg.to_radians();
+    let mu = el.mu_deg.to_radians();
+    let mu_rate = el.mu_rate_deg.to_radians();
+    let d_rate = el.d_rate_deg.to_radians();
+    let (sin_d, cos_d) = d.sin_cos();
+
+    let (mut xi, mut eta) = (el.x, el.y);
+    let mut zeta = surface_zeta(xi, eta, d).unwrap_or(0.0);
+    for _ in 0..LIMIT_ITERATIONS {
+        // Velocity of the ground point on the fundamental plane
+        let xi_rate = mu_rate * (zeta * cos_d - eta * sin_d);
+        let eta_rate = mu_rate * xi * sin_d - d_rate * zeta;
+        let zeta_rate = -mu_rate * xi * cos_d + d_rate * eta;
+        let (a, b) = (el.x_rate - xi_rate, el.y_rate - eta_rate);
+        let n = a.hypot(b);
+        if n == 0.0 {
+            return None;
+        }
+        let signed_radius = l - zeta * tan_f;
+        let radius_rate = (l_rate - zeta_rate * tan_f) * signed_radius.signum();
+        // Angle of (u, v) from the relative motion: cos θ = −R′ / |(a, b)|
+        let cos_theta = -radius_rate / n;
+        if cos_theta.abs() > 1.0 {
+            return None;
+        }
+        let angle = b.atan2(a) + side * cos_theta.acos();
+        let radius = signed_radius.abs();
+        xi = el.x + radius * angle.cos();
+        eta = el.y + radius * angle.sin();
+        zeta = surface_zeta(xi, eta, d)?;
+    }
+    Some(fundamental_to_geodetic(xi, eta, zeta, d, mu))
+}
+
+/// The two points where the shadow outline `(l, tan f)` crosses the Earth's
+/// limb (the Sun on the horizon), as `(ξ, η, ζ)`, or `None` when the outline
+/// misses the limb.
+///
+/// The limb is the ellipse `ξ² + η²/ρ1² = 1`, `ρ1² = 1 − e²·cos² d`. Start
+/// from the circle–circle intersection in `η/ρ1` and refine the limb
+/// parameter by Newton iteration.
+fn limb_crossings(el: &BesselianElements, l: f64, tan_f: f64) -> Option<[[f64; 3]; 2]> {
+    let d = el.d_deg.to_radians();
+    let (sin_d, cos_d) = d.sin_cos();
+    let e2 = eccentricity_sq();
+    let c = e2 / (1.0 - e2);
+    let rho1 = (1.0 - e2 * cos_d * cos_d).sqrt();
+    // ζ of the limb point at η (the double root of `surface_zeta`)
+    let limb_zeta = |eta: f64| -c * eta * cos_d * sin_d / (1.0 + c * sin_d * sin_d);
+
+    let y1 = el.y / rho1;
+    let m = el.x.hypot(y1);
+    let radius = l.abs();
+    let cos_alpha = (m * m + 1.0 - radius * radius) / (2.0 * m);
+    if m == 0.0 || cos_alpha.abs() > 1.0 {
+        return None;
+    }
+    let center = y1.atan2(el.x);
+    let alpha = cos_alpha.acos();
+
+    let refine = |mut theta: f64| {
+        for _ in 0..LIMIT_ITERATIONS {
+            let (sin_t, cos_t) = theta.sin_cos();
+            let (u, v) = (cos_t - el.x, rho1 * sin_t - el.y);
+            let r = l - limb_zeta(rho1 * sin_t) * tan_f;
+            let g = u * u + v * v - r * r;
+            let dg = 2.0 * (-u * sin_t + v * rho1 * cos_t);
+            if dg == 0.0 {
+                break;
+            }
+            theta -= g / dg;
+        }
+        let (sin_t, cos_t) = theta.sin_cos();
+        let eta = rho1 * sin_t;
+        [cos_t, eta, limb_zeta(eta)]
+    };
+    Some([refine(center + alpha), refine(center - alpha)])
+}
+
+/// Whether the Sun is rising at the surface point `(ξ, η)`: `ζ′ > 0`.
+fn sun_rising(el: &BesselianElements, xi: f64, eta: f64) -> bool {
+    let d = el.d_deg.to_radians();
+    let zeta_rate = -el.mu_rate_deg.to_radians() * xi * d.cos() + el.d_rate_deg.to_radians() * eta;
+    zeta_rate > 0.0
+}
+
+// ---------------------------------------------------------------------------
+// Path tracing
+// ---------------------------------------------------------------------------
+
+/// Accumulates path points into polylines, splitting at gaps and at the
+/// antimeridian.
+#[derive(Default)]
+struct PathBuilder {
+    lines: SuryaGrahanPathLine,
+    open: bool,
+}
+
+impl PathBuilder {
+    fn push(&mut self, el: &BesselianElements, point: Option<(f64, f64)>) {
+        let Some((latitude_deg, longitude_deg)) = point else {
+            self.open = false;
+            return;
+        };
+        let point = SuryaGrahanPathPoint {
+            jd_tdb: el.jd_tdb,
+            epoch: el.epoch,
+            latitude_deg,
+            longitude_deg,
+        };
+        match self.lines.last_mut() {
+            Some(line)
+                if self.open
+                    && line
+                        .last()
+                        .is_some_and(|p| (p.longitude_deg - longitude_deg).abs() <= 180.0) =>
+            {
+                line.push(point)
+            }
+            _ => self.lines.push(vec![point]),
+        }
+        self.open = true;
+    }
+}
+
+/// Ground path of a surya grahan from its Besselian elements.
+///
+/// Samples elements every `config.step_minutes` over ±4.5 hours of
+/// geocentric greatest grahan and traces the central line, the northern and
+/// southern limits of the umbra/antumbra (totality or annularity) and of the
+/// penumbra (partial visibility), and the sunrise/sunset curves where the
+/// penumbral outline meets the limb.
+pub fn surya_grahan_path(
+    engine: &Engine,
+    grahan: &SuryaGrahan,
+    eop: Option<&EopKernel>,
+    config: &SuryaGrahanPathConfig,
+) -> Result<SuryaGrahanPath, SearchError> {
+    if !(config.step_minutes.is_finite() && config.step_minutes > 0.0) {
+        return Err(SearchError::InvalidConfig("step_minutes must be positive"));
+    }
+
+    let greatest = grahan.greatest_grahan_epoch;
+    let step_days = config.step_minutes / 1440.0;
+    let steps = (2.0 * PATH_HALF_WINDOW_DAYS / step_days).ceil() as usize;
+    let start = greatest.add_days(-PATH_HALF_WINDOW_DAYS);
+
+    let mut central = PathBuilder::default();
+    let mut umbral_north = PathBuilder::default();
+    let mut umbral_south = PathBuilder::default();
+    let mut penumbral_north = PathBuilder::default();
+    let mut penumbral_south = PathBuilder::default();
+    // One builder per limb crossing and per rising/setting side
+    let mut sunrise = [PathBuilder::default(), PathBuilder::default()];
+    let mut sunset = [PathBuilder::default(), PathBuilder::default()];
+
+    for i in 0..=steps {
+        let el = besselian_elements(engine, start.add_days(i as f64 * step_days), eop, config)?;
+        central.push(&el, central_point(&el));
+        umbral_north.push(&el, limit_point(&el, el.l2, el.l2_rate, el.tan_f2, 1.0));
+        umbral_south.push(&el, limit_point(&el, el.l2, el.l2_rate, el.tan_f2, -1.0));
+        penumbral_north.push(&el, limit_point(&el, el.l1, el.l1_rate, el.tan_f1, 1.0));
+        penumbral_south.push(&el, limit_point(&el, el.l1, el.l1_rate, el.tan_f1, -1.0));
+
+        let crossings = limb_crossings(&el, el.l1, el.tan_f1);
+        let mu = el.mu_deg.to_radians();
+        for k in 0..2 {
+            let (rise, set) = match crossings.map(|c| c[k]) {
+                Some([xi, eta, zeta]) => {
+                    let point = fundamental_to_geodetic(xi, eta, zeta, el.d_deg.to_radians(), mu);
+                    if sun_rising(&el, xi, eta) {
+                        (Some(point), None)
+                    } else {
+                        (None, Some(point))
+                    }
+                }
+                None => (None, None),
+            };
+            sunrise[k].push(&el, rise);
+            sunset[k].push(&el, set);
+        }
+    }
+
+    Ok(SuryaGrahanPath {
+        elements: besselian_elements(engine, greatest, eop, config)?,
+        central_line: central.lines,
+        umbral_north_limit: umbral_north.lines,
+        umbral_south_limit: umbral_south.lines,
+        penumbral_north_limit: penumbral_north.lines,
+        penumbral_south_limit: penumbral_south.lines,
+        sunrise_limit: sunrise.into_iter().flat_map(|b| b.lines).collect(),
+        sunset_limit: sunset.into_iter().flat_map(|b| b.lines).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(x: f64, y: f64, d_deg: f64, mu_deg: f64) -> BesselianElements {
+        BesselianElements {
+            jd_tdb: 2_460_409.25,
+            epoch: Epoch::from_jd_tdb(2_460_409.25),
+            x,
+            y,
+            d_deg,
+            mu_deg,
+            l1: 0.5358,
+            l2: -0.0103,
+            tan_f1: 0.004_665,
+            tan_f2: 0.004_641,
+            x_rate: 0.5117,
+            y_rate: 0.2709,
+            d_rate_deg: 0.015,
+            mu_rate_deg: 15.0,
+            l1_rate: 0.0,
+            l2_rate: 0.0,
+        }
+    }
+
+    #[test]
+    fn sub_axis_point_is_on_equator_at_axis_meridian() {
+        let zeta = surface_zeta(0.0, 0.0, 0.0).unwrap();
+        assert!((zeta - 1.0).abs() < 1e-12);
+        let (lat, lon) = fundamental_to_geodetic(0.0, 0.0, zeta, 0.0, 30f64.to_radians());
+        assert!(lat.abs() < 1e-12);
+        assert!((lon + 30.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn top_of_outline_is_pole() {
+        // With d = 0 the north pole sits at η = polar radius
+        let b = 1.0 - WGS84_F;
+        let zeta = surface_zeta(0.0, b, 0.0).unwrap();
+        assert!(zeta.abs() < 1e-6);
+        let (lat, _) = fundamental_to_geodetic(0.0, b, zeta, 0.0, 0.0);
+        assert!((lat - 90.0).abs() < 1e-3, "lat = {lat}");
+        assert!(surface_zeta(0.0, 1.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn geodetic_latitude_exceeds_geocentric() {
+        let d = 10f64.to_radians();
+        let zeta = surface_zeta(0.2, 0.5, d).unwrap();
+        let (lat, _) = fundamental_to_geodetic(0.2, 0.5, zeta, d, 0.0);
+        let (sin_d, cos_d) = d.sin_cos();
+        let z = 0.5 * cos_d + zeta * sin_d;
+        let x = zeta * cos_d - 0.5 * sin_d;
+        let geocentric = z.atan2(x.hypot(0.2)).to_degrees();
+        assert!(lat > geocentric && lat - geocentric < 0.2);
+    }
+
+    #[test]
+    fn limits_straddle_central_line() {
+        let el = elements(-0.3182, 0.2198, 7.586, 89.591);
+        let (c_lat, _) = central_point(&el).unwrap();
+        let (n_lat, _) = limit_point(&el, el.l2, el.l2_rate, el.tan_f2, 1.0).unwrap();
+        let (s_lat, _) = limit_point(&el, el.l2, el.l2_rate, el.tan_f2, -1.0).unwrap();
+        assert!(n_lat > c_lat && c_lat > s_lat);
+        // Totality path ~200 km wide: under 2 degrees of latitude
+        assert!(n_lat - s_lat < 2.0);
+        let (pn_lat, _) = limit_point(&el, el.l1, el.l1_rate, el.tan_f1, 1.0).unwrap();
+        assert!(pn_lat > n_lat + 20.0);
+    }
+
+    /// Fundamental-plane `(ξ, η, ζ)` of a fixed ground point for elements
+    /// propagated linearly by `hours`.
+    fn ground_point_at(el: &BesselianElements, lat: f64, lon: f64, hours: f64) -> [f64; 3] {
+        let e2 = eccentricity_sq();
+        let (lat, lon) = (lat.to_radians(), lon.to_radians());
+        let d = (el.d_deg + el.d_rate_deg * hours).to_radians();
+        let h = lon + (el.mu_deg + el.mu_rate_deg * hours).to_radians();
+        let n = 1.0 / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+        let (x, y, z) = (
+            n * lat.cos() * h.cos(),
+            n * lat.cos() * h.sin(),
+            n * (1.0 - e2) * lat.sin(),
+        );
+        [y, z * d.cos() - x * d.sin(), x * d.cos() + z * d.sin()]
+    }
+
+    #[test]
+    fn limit_point_is_on_the_outline_envelope() {
+        let mut el = elements(-0.3182, 0.2198, 7.586, 89.591);
+        el.l1_rate = 0.01;
+        let (lat, lon) = limit_point(&el, el.l1, el.l1_rate, el.tan_f1, 1.0).unwrap();
+        // Outline function u² + v² − L² of the fixed ground point over time
+        let outline = |hours: f64| {
+            let [xi, eta, zeta] = ground_point_at(&el, lat, lon, hours);
+            let (u, v) = (
+                xi - el.x - el.x_rate * hours,
+                eta - el.y - el.y_rate * hours,
+            );
+            let radius = el.l1 + el.l1_rate * hours - zeta * el.tan_f1;
+            u * u + v * v - radius * radius
+        };
+        let h = 1e-3;
+        assert!(outline(0.0).abs() < 1e-9, "off outline: {}", outline(0.0));
+        let rate = (outline(h) - outline(-h)) / (2.0 * h);
+        assert!(rate.abs() < 1e-6, "not stationary: {rate}");
+
+        // Dropping l′ moves the limit by far more than the tolerance above
+        let (lat0, lon0) = limit_point(&el, el.l1, 0.0, el.tan_f1, 1.0).unwrap();
+        assert!((lat - lat0).abs() + (lon - lon0).abs() > 0.1);
+    }
+
+    #[test]
+    fn limb_crossings_lie_on_limb_and_outline() {
+        // Early in the grahan: shadow entering over the western (morning) limb
+        let el = elements(-1.2, 0.2, 7.586, 89.591);
+        let crossings = limb_crossings(&el, el.l1, el.tan_f1).unwrap();
+        let rho1_sq = 1.0 - eccentricity_sq() * el.d_deg.to_radians().cos().powi(2);
+        for [xi, eta, zeta] in crossings {
+            assert!((xi * xi + eta * eta / rho1_sq - 1.0).abs() < 1e-12);
+            let radius = el.l1 - zeta * el.tan_f1;
+            assert!(((xi - el.x).hypot(eta - el.y) - radius).abs() < 1e-12);
+            assert!(sun_rising(&el, xi, eta));
+        }
+        assert!((crossings[0][1] - crossings[1][1]).abs() > 0.5);
+
+        // Late: leaving over the eastern limb at sunset
+        let el = elements(1.2, 0.2, 7.586, 89.591);
+        for [xi, eta, _] in limb_crossings(&el, el.l1, el.tan_f1).unwrap() {
+            assert!(!sun_rising(&el, xi, eta));
+        }
+
+        // Penumbra wholly inside or wholly off the disc
+        assert!(limb_crossings(&elements(0.1, 0.1, 7.0, 80.0), 0.5358, 0.0047).is_none());
+        assert!(limb_crossings(&elements(1.8, 0.1, 7.0, 80.0), 0.5358, 0.0047).is_none());
+    }
+
+    #[test]
+    fn axis_off_earth_has_no_central_point() {
+        let el = elements(1.2, 0.3, 7.0, 80.0);
+        assert!(central_point(&el).is_none());
+    }
+
+    #[test]
+    fn path_builder_splits_at_gaps_and_antimeridian() {
+        let el = elements(0.0, 0.0, 0.0, 0.0);
+        let mut b = PathBuilder::default();
+        b.push(&el, Some((0.0, 170.0)));
+        b.push(&el, Some((0.0, 175.0)));
+        b.push(&el, Some((0.0, -178.0)));
+        b.push(&el, None);
+        b.push(&el, Some((0.0, -170.0)));
+        let sizes: Vec<usize> = b.lines.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 1, 1]);
+    }
+}
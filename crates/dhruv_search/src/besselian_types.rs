@@ -0,0 +1,104 @@
+//! Types for Besselian elements and surya grahan ground paths.
+
+use dhruv_core::BodyRadii;
+use dhruv_time::Epoch;
+
+/// Besselian elements of a surya grahan at one instant.
+///
+/// Lengths are in Earth equatorial radii on the fundamental plane (through
+/// Earth's center, perpendicular to the Moon→Sun shadow axis). Rates are per
+/// hour of TDB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BesselianElements {
+    /// Instant (JD TDB).
+    pub jd_tdb: f64,
+    /// Instant as a full-precision TDB epoch.
+    pub epoch: Epoch,
+    /// Shadow-axis intercept, positive towards the east.
+    pub x: f64,
+    /// Shadow-axis intercept, positive towards the north.
+    pub y: f64,
+    /// Declination of the shadow axis (true equator of date), in degrees.
+    pub d_deg: f64,
+    /// Greenwich hour angle of the shadow axis, in degrees `[0, 360)`.
+    pub mu_deg: f64,
+    /// Penumbral cone radius on the fundamental plane.
+    pub l1: f64,
+    /// Umbral cone radius on the fundamental plane (negative: umbra reaches
+    /// the plane, i.e. total; positive: antumbra, annular).
+    pub l2: f64,
+    /// Tangent of the penumbral cone half-angle.
+    pub tan_f1: f64,
+    /// Tangent of the umbral cone half-angle.
+    pub tan_f2: f64,
+    /// Rate of `x` per hour.
+    pub x_rate: f64,
+    /// Rate of `y` per hour.
+    pub y_rate: f64,
+    /// Rate of `d` in degrees per hour.
+    pub d_rate_deg: f64,
+    /// Rate of `μ` in degrees per hour.
+    pub mu_rate_deg: f64,
+    /// Rate of `l1` per hour.
+    pub l1_rate: f64,
+    /// Rate of `l2` per hour.
+    pub l2_rate: f64,
+}
+
+/// One point of a surya grahan ground path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuryaGrahanPathPoint {
+    /// Instant at which the shadow is at this point (JD TDB).
+    pub jd_tdb: f64,
+    /// Instant as a full-precision TDB epoch.
+    pub epoch: Epoch,
+    /// Geodetic latitude (WGS84), in degrees.
+    pub latitude_deg: f64,
+    /// East longitude, in degrees `[-180, 180)`.
+    pub longitude_deg: f64,
+}
+
+/// A path curve as polylines. A new polyline starts where the curve leaves
+/// the Earth or crosses the antimeridian.
+pub type SuryaGrahanPathLine = Vec<Vec<SuryaGrahanPathPoint>>;
+
+/// Ground track of a surya grahan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuryaGrahanPath {
+    /// Besselian elements at geocentric greatest grahan.
+    pub elements: BesselianElements,
+    /// Where the shadow axis meets the Earth. Empty for partial grahan.
+    pub central_line: SuryaGrahanPathLine,
+    /// Northern limit of totality/annularity.
+    pub umbral_north_limit: SuryaGrahanPathLine,
+    /// Southern limit of totality/annularity.
+    pub umbral_south_limit: SuryaGrahanPathLine,
+    /// Northern limit of partial visibility.
+    pub penumbral_north_limit: SuryaGrahanPathLine,
+    /// Southern limit of partial visibility.
+    pub penumbral_south_limit: SuryaGrahanPathLine,
+    /// Where partial visibility begins or ends at sunrise (penumbral outline
+    /// on the morning limb).
+    pub sunrise_limit: SuryaGrahanPathLine,
+    /// Where partial visibility begins or ends at sunset (penumbral outline
+    /// on the evening limb).
+    pub sunset_limit: SuryaGrahanPathLine,
+}
+
+/// Ground path tracing configuration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuryaGrahanPathConfig {
+    /// Sampling step along the path, in minutes. Default: 1.
+    pub step_minutes: f64,
+    /// Sun, Earth and Moon radii. Default: IAU 2015 nominal values.
+    pub radii: BodyRadii,
+}
+
+impl Default for SuryaGrahanPathConfig {
+    fn default() -> Self {
+        Self {
+            step_minutes: 1.0,
+            radii: BodyRadii::default(),
+        }
+    }
+}
@@ -7,16 +7,16 @@
 //! The normalize function wraps to [-180, +180] so zero-crossings correspond to
 //! the target separation. Standard numerical root-finding; no external code referenced.
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, EpochQuery, Frame, Observer};
 use dhruv_frames::{
     DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, cartesian_to_spherical,
-    icrf_to_ecliptic, icrf_to_invariable, precess_ecliptic_j2000_to_date_with_model,
+    icrf_to_invariable,
 };
-use dhruv_time::UtcTime;
+use dhruv_time::{Epoch, UtcTime};
 
 use crate::conjunction_types::{ConjunctionConfig, ConjunctionEvent, SearchDirection};
 use crate::error::SearchError;
-use crate::search_util::{is_genuine_crossing, normalize_to_pm180};
+use crate::search_util::{SearchInstant, bisect_epoch, is_genuine_crossing, normalize_to_pm180};
 
 /// Maximum scan range in days (~800 days covers all synodic periods).
 const MAX_SCAN_DAYS: f64 = 800.0;
@@ -26,32 +26,32 @@ const MAX_SCAN_DAYS: f64 = 800.0;
 /// Queries ICRF/J2000, rotates to J2000 ecliptic, then applies the selected
 /// 3D precession model to yield ecliptic-of-date coordinates. This is the primary
 /// choke-point for all graha tropical longitudes.
+///
+/// `at` is a JD TDB `f64`, an [`Epoch`] or a [`UtcTime`] (see [`SearchInstant`]).
 pub fn body_ecliptic_lon_lat(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
 ) -> Result<(f64, f64), SearchError> {
-    body_ecliptic_lon_lat_with_model(engine, body, jd_tdb, DEFAULT_PRECESSION_MODEL)
+    body_ecliptic_lon_lat_with_model(engine, body, at, DEFAULT_PRECESSION_MODEL)
 }
 
 /// Model-aware variant of [`body_ecliptic_lon_lat`].
 pub fn body_ecliptic_lon_lat_with_model(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     precession_model: PrecessionModel,
 ) -> Result<(f64, f64), SearchError> {
-    let query = Query {
+    let query = EpochQuery {
         target: body,
         observer: Observer::Body(Body::Earth),
-        frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
+        frame: Frame::MeanEclipticOfDate(precession_model),
+        epoch: at.to_epoch(engine),
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
-    let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-    let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let ecl_date = precess_ecliptic_j2000_to_date_with_model(&ecl_j2000, t, precession_model);
-    let sph = cartesian_to_spherical(&ecl_date);
+    let sph = cartesian_to_spherical(&state.position_km);
     Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg))
 }
 
@@ -62,27 +62,50 @@ pub fn body_ecliptic_lon_lat_with_model(
 pub fn body_lon_lat_on_plane(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     precession_model: PrecessionModel,
     plane: ReferencePlane,
 ) -> Result<(f64, f64), SearchError> {
-    match plane {
-        ReferencePlane::Ecliptic => {
-            body_ecliptic_lon_lat_with_model(engine, body, jd_tdb, precession_model)
-        }
-        ReferencePlane::Invariable => {
-            let query = Query {
-                target: body,
-                observer: Observer::Body(Body::Earth),
-                frame: Frame::IcrfJ2000,
-                epoch_tdb_jd: jd_tdb,
-            };
-            let state = engine.query(query)?;
-            let inv = icrf_to_invariable(&state.position_km);
-            let sph = cartesian_to_spherical(&inv);
-            Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg))
-        }
-    }
+    body_lon_lat_on_plane_with_correction(
+        engine,
+        body,
+        at,
+        precession_model,
+        plane,
+        Correction::Geometric,
+    )
+}
+
+/// Correction-aware variant of [`body_lon_lat_on_plane`].
+///
+/// The geocentric query applies `correction` in ICRF before the plane
+/// rotation, so `Correction::Apparent` yields apparent longitudes.
+pub fn body_lon_lat_on_plane_with_correction(
+    engine: &Engine,
+    body: Body,
+    at: impl SearchInstant,
+    precession_model: PrecessionModel,
+    plane: ReferencePlane,
+    correction: Correction,
+) -> Result<(f64, f64), SearchError> {
+    let frame = match plane {
+        ReferencePlane::Ecliptic => Frame::MeanEclipticOfDate(precession_model),
+        ReferencePlane::Invariable => Frame::IcrfJ2000,
+    };
+    let query = EpochQuery {
+        target: body,
+        observer: Observer::Body(Body::Earth),
+        frame,
+        epoch: at.to_epoch(engine),
+        correction,
+    };
+    let state = engine.query(query)?;
+    let on_plane = match plane {
+        ReferencePlane::Ecliptic => state.position_km,
+        ReferencePlane::Invariable => icrf_to_invariable(&state.position_km),
+    };
+    let sph = cartesian_to_spherical(&on_plane);
+    Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg))
 }
 
 /// Query a body's ecliptic-of-date longitude, latitude, and longitude speed.
@@ -118,10 +141,10 @@ fn separation_function(
     body1: Body,
     body2: Body,
     target_deg: f64,
-    jd_tdb: f64,
+    epoch: Epoch,
 ) -> Result<(f64, f64, f64, f64, f64), SearchError> {
-    let (lon1, lat1) = body_ecliptic_lon_lat(engine, body1, jd_tdb)?;
-    let (lon2, lat2) = body_ecliptic_lon_lat(engine, body2, jd_tdb)?;
+    let (lon1, lat1) = body_ecliptic_lon_lat(engine, body1, epoch)?;
+    let (lon2, lat2) = body_ecliptic_lon_lat(engine, body2, epoch)?;
     let f = normalize_to_pm180(lon1 - lon2 - target_deg);
     Ok((f, lon1, lon2, lat1, lat2))
 }
@@ -144,45 +167,31 @@ fn bisect_refinement(
     body1: Body,
     body2: Body,
     target_deg: f64,
-    mut t_a: f64,
-    mut f_a: f64,
-    mut t_b: f64,
+    t_a: Epoch,
+    f_a: f64,
+    t_b: Epoch,
     _f_b: f64,
     config: &ConjunctionConfig,
 ) -> Result<ConjunctionEvent, SearchError> {
-    let mut lon1 = 0.0;
-    let mut lon2 = 0.0;
-    let mut lat1 = 0.0;
-    let mut lat2 = 0.0;
-
-    for _ in 0..config.max_iterations {
-        let t_mid = 0.5 * (t_a + t_b);
-        let (f_mid, l1, l2, la1, la2) =
-            separation_function(engine, body1, body2, target_deg, t_mid)?;
-
-        lon1 = l1;
-        lon2 = l2;
-        lat1 = la1;
-        lat2 = la2;
-
-        if f_a * f_mid <= 0.0 {
-            t_b = t_mid;
-        } else {
-            t_a = t_mid;
-            f_a = f_mid;
-        }
-
-        if (t_b - t_a).abs() < config.convergence_days {
-            break;
-        }
-    }
-
-    let t_final = 0.5 * (t_a + t_b);
+    let f = |t: Epoch| -> Result<f64, SearchError> {
+        Ok(separation_function(engine, body1, body2, target_deg, t)?.0)
+    };
+    let t_final = bisect_epoch(
+        &f,
+        t_a,
+        f_a,
+        t_b,
+        config.max_iterations,
+        config.convergence_days * 86_400.0,
+    )?;
+    let (_, lon1, lon2, lat1, lat2) =
+        separation_function(engine, body1, body2, target_deg, t_final)?;
     let actual_sep = compute_actual_separation(lon1, lon2, target_deg);
 
     Ok(ConjunctionEvent {
-        jd_tdb: t_final,
-        utc: UtcTime::from_jd_tdb(t_final, engine.lsk()),
+        jd_tdb: t_final.as_jd_tdb(),
+        epoch: t_final,
+        utc: UtcTime::from_epoch(t_final, engine.lsk()),
         actual_separation_deg: actual_sep,
         body1_longitude_deg: lon1,
         body2_longitude_deg: lon2,
@@ -198,7 +207,7 @@ fn find_event(
     engine: &Engine,
     body1: Body,
     body2: Body,
-    jd_start: f64,
+    start: Epoch,
     direction: SearchDirection,
     config: &ConjunctionConfig,
 ) -> Result<Option<ConjunctionEvent>, SearchError> {
@@ -212,18 +221,18 @@ fn find_event(
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
 
     let (mut f_prev, _, _, _, _) =
-        separation_function(engine, body1, body2, config.target_separation_deg, jd_start)?;
-    let mut t_prev = jd_start;
+        separation_function(engine, body1, body2, config.target_separation_deg, start)?;
+    let mut t_prev = start;
 
     for _ in 0..max_steps {
-        let t_curr = t_prev + step;
+        let t_curr = t_prev.add_days(step);
         let (f_curr, _, _, _, _) =
             separation_function(engine, body1, body2, config.target_separation_deg, t_curr)?;
 
         // Check for genuine zero crossing (not a wrap-around discontinuity)
         if is_genuine_crossing(f_prev, f_curr) {
             // Ensure t_a < t_b for bisection
-            let (t_a, f_a, t_b, f_b) = if t_prev < t_curr {
+            let (t_a, f_a, t_b, f_b) = if step > 0.0 {
                 (t_prev, f_prev, t_curr, f_curr)
             } else {
                 (t_curr, f_curr, t_prev, f_prev)
@@ -249,37 +258,37 @@ fn find_event(
     Ok(None)
 }
 
-/// Find the next conjunction/aspect event after `jd_tdb`.
+/// Find the next conjunction/aspect event after `at` (see [`SearchInstant`]).
 pub fn next_conjunction(
     engine: &Engine,
     body1: Body,
     body2: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &ConjunctionConfig,
 ) -> Result<Option<ConjunctionEvent>, SearchError> {
     find_event(
         engine,
         body1,
         body2,
-        jd_tdb,
+        at.to_epoch(engine),
         SearchDirection::Forward,
         config,
     )
 }
 
-/// Find the previous conjunction/aspect event before `jd_tdb`.
+/// Find the previous conjunction/aspect event before `at` (see [`SearchInstant`]).
 pub fn prev_conjunction(
     engine: &Engine,
     body1: Body,
     body2: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &ConjunctionConfig,
 ) -> Result<Option<ConjunctionEvent>, SearchError> {
     find_event(
         engine,
         body1,
         body2,
-        jd_tdb,
+        at.to_epoch(engine),
         SearchDirection::Backward,
         config,
     )
@@ -290,13 +299,15 @@ pub fn search_conjunctions(
     engine: &Engine,
     body1: Body,
     body2: Body,
-    jd_start: f64,
-    jd_end: f64,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &ConjunctionConfig,
 ) -> Result<Vec<ConjunctionEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    if jd_end <= jd_start {
+    let start = start.to_epoch(engine);
+    let end = end.to_epoch(engine);
+    if end.seconds_since(start) <= 0.0 {
         return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
     }
 
@@ -304,11 +315,16 @@ pub fn search_conjunctions(
     let step = config.step_size_days;
 
     let (mut f_prev, _, _, _, _) =
-        separation_function(engine, body1, body2, config.target_separation_deg, jd_start)?;
-    let mut t_prev = jd_start;
+        separation_function(engine, body1, body2, config.target_separation_deg, start)?;
+    let mut t_prev = start;
 
     loop {
-        let t_curr = (t_prev + step).min(jd_end);
+        let t_next = t_prev.add_days(step);
+        let t_curr = if t_next.seconds_since(end) > 0.0 {
+            end
+        } else {
+            t_next
+        };
         let (f_curr, _, _, _, _) =
             separation_function(engine, body1, body2, config.target_separation_deg, t_curr)?;
 
@@ -324,12 +340,12 @@ pub fn search_conjunctions(
                 f_curr,
                 config,
             )?;
-            if event.jd_tdb >= jd_start && event.jd_tdb <= jd_end {
+            if event.epoch.seconds_since(start) >= 0.0 && end.seconds_since(event.epoch) >= 0.0 {
                 events.push(event);
             }
         }
 
-        if t_curr >= jd_end {
+        if t_curr == end {
             break;
         }
 
@@ -1,7 +1,7 @@
 //! Types for conjunction, opposition, and aspect search.
 
 use dhruv_core::Body;
-use dhruv_time::UtcTime;
+use dhruv_time::{Epoch, UtcTime};
 
 /// Configuration for a conjunction/aspect search.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -82,6 +82,8 @@ pub enum SearchDirection {
 pub struct ConjunctionEvent {
     /// Event time as Julian Date (TDB).
     pub jd_tdb: f64,
+    /// Event time as a full-precision TDB epoch.
+    pub epoch: Epoch,
     /// Event time as structured Gregorian UTC.
     pub utc: UtcTime,
     /// Actual ecliptic longitude separation at peak, in degrees [0, 360).
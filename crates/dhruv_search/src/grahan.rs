@@ -17,33 +17,42 @@
 //!   4. Classify by comparing radii and minimum separation
 //!   5. Find contact times by bisection
 //!
+//! Surya grahan local circumstances (topocentric):
+//!   1. Sample topocentric Sun-Moon separation around geocentric maximum
+//!   2. Refine the least separation (local maximum) by golden-section search
+//!   3. Classify and compute magnitude/obscuration from topocentric radii
+//!   4. Find local contacts by bisection; report Sun altitude/azimuth
+//!
+//! Chandra grahan visibility (topocentric):
+//!   1. Moon altitude/azimuth at each global contact for the location
+//!   2. Moonrise/moonset inside P1–P4 by sampling and bisection
+//!   3. Visible part of each phase; world grid sampled every 10 minutes
+//!
 //! Sources: standard spherical astronomy (Meeus Ch. 54 for shadow geometry,
 //! IAU 2015 nominal radii). See docs/clean_room_grahan.md.
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
-use dhruv_frames::{cartesian_to_spherical, icrf_to_ecliptic, precess_ecliptic_j2000_to_date};
-use dhruv_time::UtcTime;
+use dhruv_core::topocentric::{gcrs_to_itrs_matrix, horizontal_from_gcrs, site_gcrs_state};
+use dhruv_core::{
+    Body, BodyRadii, Correction, Engine, EpochQuery, Frame, Observer, TopocentricEpochQuery,
+    TopocentricOptions,
+};
+use dhruv_frames::{DEFAULT_PRECESSION_MODEL, cartesian_to_spherical};
+use dhruv_time::{EopKernel, Epoch, UtcTime};
 
 use crate::conjunction::{next_conjunction, prev_conjunction, search_conjunctions};
 use crate::conjunction_types::ConjunctionConfig;
 use crate::error::SearchError;
 use crate::grahan_types::{
-    ChandraGrahan, ChandraGrahanType, GrahanConfig, SuryaGrahan, SuryaGrahanType,
+    ChandraGrahan, ChandraGrahanLocal, ChandraGrahanLocalContact, ChandraGrahanType,
+    ChandraGrahanVisibilityGrid, GeoLocation, GrahanConfig, GrahanVisibility, GrahanVisibilityCell,
+    GrahanVisibleWindow, SuryaGrahan, SuryaGrahanLocal, SuryaGrahanLocalContact, SuryaGrahanType,
 };
+use crate::search_util::{SearchInstant, bisect_epoch, earth_rotation_at};
 
 // ---------------------------------------------------------------------------
-// Constants (IAU 2015 nominal values)
+// Constants
 // ---------------------------------------------------------------------------
 
-/// Earth equatorial radius in km (IAU 2015 Resolution B3).
-const EARTH_RADIUS_KM: f64 = 6378.137;
-
-/// Sun nominal radius in km (IAU 2015 Resolution B3).
-const SUN_RADIUS_KM: f64 = 696_000.0;
-
-/// Moon mean radius in km (IAU 2015).
-const MOON_RADIUS_KM: f64 = 1737.4;
-
 /// Danjon atmospheric enlargement factor for Earth's shadow.
 /// The Earth's atmosphere causes the geometrical shadow to appear ~2% larger.
 /// Published in Meeus, "Astronomical Algorithms", Ch. 54.
@@ -57,8 +66,8 @@ const GRAHAN_LAT_THRESHOLD_DEG: f64 = 2.0;
 /// so 0.5 day step safely brackets all crossings.
 const MOON_STEP_DAYS: f64 = 0.5;
 
-/// Bisection convergence for contact times (days). ~0.86 ms precision.
-const CONTACT_CONVERGENCE_DAYS: f64 = 1e-8;
+/// Bisection convergence for contact times (seconds).
+const CONTACT_CONVERGENCE_S: f64 = 1e-5;
 
 /// Maximum bisection iterations for contact times.
 const CONTACT_MAX_ITER: u32 = 50;
@@ -68,28 +77,27 @@ const CONTACT_MAX_ITER: u32 = 50;
 // ---------------------------------------------------------------------------
 
 /// Query Moon's ecliptic-of-date longitude, latitude (deg), and distance (km).
-fn moon_ecliptic(engine: &Engine, jd_tdb: f64) -> Result<(f64, f64, f64), SearchError> {
-    let query = Query {
+fn moon_ecliptic(engine: &Engine, epoch: Epoch) -> Result<(f64, f64, f64), SearchError> {
+    let query = EpochQuery {
         target: Body::Moon,
         observer: Observer::Body(Body::Earth),
-        frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
+        frame: Frame::MeanEclipticOfDate(DEFAULT_PRECESSION_MODEL),
+        epoch,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
-    let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-    let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, t);
-    let sph = cartesian_to_spherical(&ecl_date);
+    let sph = cartesian_to_spherical(&state.position_km);
     Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg, sph.distance_km))
 }
 
 /// Query Sun's distance from Earth in km.
-fn sun_distance(engine: &Engine, jd_tdb: f64) -> Result<f64, SearchError> {
-    let query = Query {
+fn sun_distance(engine: &Engine, epoch: Epoch) -> Result<f64, SearchError> {
+    let query = EpochQuery {
         target: Body::Sun,
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
+        epoch,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
     let r = (state.position_km[0].powi(2)
@@ -101,18 +109,20 @@ fn sun_distance(engine: &Engine, jd_tdb: f64) -> Result<f64, SearchError> {
 
 /// Angular separation between Sun and Moon centers (degrees) at a given epoch.
 /// Computed from their ICRF positions relative to Earth.
-fn sun_moon_angular_separation(engine: &Engine, jd_tdb: f64) -> Result<f64, SearchError> {
-    let sun_q = Query {
+fn sun_moon_angular_separation(engine: &Engine, epoch: Epoch) -> Result<f64, SearchError> {
+    let sun_q = EpochQuery {
         target: Body::Sun,
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
+        epoch,
+        correction: Correction::Geometric,
     };
-    let moon_q = Query {
+    let moon_q = EpochQuery {
         target: Body::Moon,
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
+        epoch,
+        correction: Correction::Geometric,
     };
     let sun_state = engine.query(sun_q)?;
     let moon_state = engine.query(moon_q)?;
@@ -139,13 +149,13 @@ fn sun_moon_angular_separation(engine: &Engine, jd_tdb: f64) -> Result<f64, Sear
 ///
 /// The Danjon method enlarges the geometrical shadow by 2% to account
 /// for Earth's atmosphere.
-fn shadow_radii_deg(sun_dist_km: f64, moon_dist_km: f64) -> (f64, f64) {
+fn shadow_radii_deg(sun_dist_km: f64, moon_dist_km: f64, radii: &BodyRadii) -> (f64, f64) {
     // Parallax of Sun and Moon
-    let pi_sun = (EARTH_RADIUS_KM / sun_dist_km).asin();
-    let pi_moon = (EARTH_RADIUS_KM / moon_dist_km).asin();
+    let pi_sun = (radii.earth_equatorial_km / sun_dist_km).asin();
+    let pi_moon = (radii.earth_equatorial_km / moon_dist_km).asin();
 
     // Angular semidiameter of the Sun as seen from Earth
-    let s_sun = (SUN_RADIUS_KM / sun_dist_km).asin();
+    let s_sun = (radii.sun_km / sun_dist_km).asin();
 
     // Penumbral shadow radius (projected at Moon's distance)
     let penumbral_rad = DANJON_ENLARGEMENT * (pi_moon + pi_sun + s_sun);
@@ -156,20 +166,20 @@ fn shadow_radii_deg(sun_dist_km: f64, moon_dist_km: f64) -> (f64, f64) {
 }
 
 /// Moon's angular semidiameter in degrees.
-fn moon_angular_radius_deg(moon_dist_km: f64) -> f64 {
-    (MOON_RADIUS_KM / moon_dist_km).asin().to_degrees()
+fn moon_angular_radius_deg(moon_dist_km: f64, radii: &BodyRadii) -> f64 {
+    (radii.moon_km / moon_dist_km).asin().to_degrees()
 }
 
 /// Sun's angular semidiameter in degrees.
-fn sun_angular_radius_deg(sun_dist_km: f64) -> f64 {
-    (SUN_RADIUS_KM / sun_dist_km).asin().to_degrees()
+fn sun_angular_radius_deg(sun_dist_km: f64, radii: &BodyRadii) -> f64 {
+    (radii.sun_km / sun_dist_km).asin().to_degrees()
 }
 
 /// Angular distance of the Moon's center from the anti-solar point (shadow axis).
 /// At full moon, this is approximately 180 - (Sun-Moon separation),
 /// which gives the angular offset from the center of Earth's shadow.
-fn moon_shadow_offset_deg(engine: &Engine, jd_tdb: f64) -> Result<f64, SearchError> {
-    let sep = sun_moon_angular_separation(engine, jd_tdb)?;
+fn moon_shadow_offset_deg(engine: &Engine, epoch: Epoch) -> Result<f64, SearchError> {
+    let sep = sun_moon_angular_separation(engine, epoch)?;
     // At exact opposition sep = 180°. Shadow offset = 180° - sep.
     // The Moon's ecliptic latitude drives this offset.
     Ok((180.0 - sep).abs())
@@ -211,62 +221,50 @@ fn classify_chandra(
 /// Searches between `t_a` and `t_b`.
 fn find_chandra_contact(
     engine: &Engine,
-    t_a: f64,
-    t_b: f64,
+    t_a: Epoch,
+    t_b: Epoch,
     boundary_radius_deg: f64,
     limb_sign: f64,
-) -> Result<f64, SearchError> {
+    radii: &BodyRadii,
+) -> Result<Epoch, SearchError> {
     // f(t) = (shadow_offset + limb_sign * moon_radius) - boundary_radius
     // We look for f(t) = 0
 
-    let f = |jd: f64| -> Result<f64, SearchError> {
-        let offset = moon_shadow_offset_deg(engine, jd)?;
-        let (_, _, moon_dist) = moon_ecliptic(engine, jd)?;
-        let moon_r = moon_angular_radius_deg(moon_dist);
+    let f = |t: Epoch| -> Result<f64, SearchError> {
+        let offset = moon_shadow_offset_deg(engine, t)?;
+        let (_, _, moon_dist) = moon_ecliptic(engine, t)?;
+        let moon_r = moon_angular_radius_deg(moon_dist, radii);
         Ok(offset + limb_sign * moon_r - boundary_radius_deg)
     };
 
-    let mut ta = t_a;
-    let mut tb = t_b;
-    let mut fa = f(ta)?;
-
-    for _ in 0..CONTACT_MAX_ITER {
-        let tm = 0.5 * (ta + tb);
-        let fm = f(tm)?;
-
-        if fa * fm <= 0.0 {
-            tb = tm;
-        } else {
-            ta = tm;
-            fa = fm;
-        }
-
-        if (tb - ta).abs() < CONTACT_CONVERGENCE_DAYS {
-            break;
-        }
-    }
-
-    Ok(0.5 * (ta + tb))
+    bisect_epoch(
+        &f,
+        t_a,
+        f(t_a)?,
+        t_b,
+        CONTACT_MAX_ITER,
+        CONTACT_CONVERGENCE_S,
+    )
 }
 
 /// Compute a single chandra grahan from a full moon event.
 fn compute_chandra_grahan(
     engine: &Engine,
-    full_moon_jd: f64,
+    full_moon: Epoch,
     config: &GrahanConfig,
 ) -> Result<Option<ChandraGrahan>, SearchError> {
     // Get Moon's ecliptic latitude at full moon
-    let (_, moon_lat, moon_dist) = moon_ecliptic(engine, full_moon_jd)?;
+    let (_, moon_lat, moon_dist) = moon_ecliptic(engine, full_moon)?;
 
     // Quick filter
     if moon_lat.abs() > GRAHAN_LAT_THRESHOLD_DEG {
         return Ok(None);
     }
 
-    let sun_dist = sun_distance(engine, full_moon_jd)?;
-    let (penumbral_radius, umbral_radius) = shadow_radii_deg(sun_dist, moon_dist);
-    let moon_radius = moon_angular_radius_deg(moon_dist);
-    let shadow_offset = moon_shadow_offset_deg(engine, full_moon_jd)?;
+    let sun_dist = sun_distance(engine, full_moon)?;
+    let (penumbral_radius, umbral_radius) = shadow_radii_deg(sun_dist, moon_dist, &config.radii);
+    let moon_radius = moon_angular_radius_deg(moon_dist, &config.radii);
+    let shadow_offset = moon_shadow_offset_deg(engine, full_moon)?;
 
     let grahan_type =
         match classify_chandra(shadow_offset, moon_radius, umbral_radius, penumbral_radius) {
@@ -287,38 +285,42 @@ fn compute_chandra_grahan(
     let half_window = 0.25; // 6 hours in days
 
     // P1: near limb enters penumbra (outer limb, going in)
-    let p1_jd = find_chandra_contact(
+    let p1 = find_chandra_contact(
         engine,
-        full_moon_jd - half_window,
-        full_moon_jd,
+        full_moon.add_days(-half_window),
+        full_moon,
         penumbral_radius,
         1.0, // far limb crosses penumbra boundary
+        &config.radii,
     )?;
 
     // P4: far limb exits penumbra
-    let p4_jd = find_chandra_contact(
+    let p4 = find_chandra_contact(
         engine,
-        full_moon_jd,
-        full_moon_jd + half_window,
+        full_moon,
+        full_moon.add_days(half_window),
         penumbral_radius,
         1.0,
+        &config.radii,
     )?;
 
     // U1/U4: umbral contacts (only if partial or total)
-    let (u1_jd, u4_jd) = if grahan_type != ChandraGrahanType::Penumbral {
+    let (u1, u4) = if grahan_type != ChandraGrahanType::Penumbral {
         let u1 = find_chandra_contact(
             engine,
-            full_moon_jd - half_window,
-            full_moon_jd,
+            full_moon.add_days(-half_window),
+            full_moon,
             umbral_radius,
             1.0,
+            &config.radii,
         )?;
         let u4 = find_chandra_contact(
             engine,
-            full_moon_jd,
-            full_moon_jd + half_window,
+            full_moon,
+            full_moon.add_days(half_window),
             umbral_radius,
             1.0,
+            &config.radii,
         )?;
         (Some(u1), Some(u4))
     } else {
@@ -326,98 +328,108 @@ fn compute_chandra_grahan(
     };
 
     // U2/U3: totality contacts (only if total)
-    let (u2_jd, u3_jd) = if grahan_type == ChandraGrahanType::Total {
+    let (u2, u3) = if grahan_type == ChandraGrahanType::Total {
         let u2 = find_chandra_contact(
             engine,
-            full_moon_jd - half_window,
-            full_moon_jd,
+            full_moon.add_days(-half_window),
+            full_moon,
             umbral_radius,
             -1.0, // near limb crosses umbra boundary
+            &config.radii,
         )?;
         let u3 = find_chandra_contact(
             engine,
-            full_moon_jd,
-            full_moon_jd + half_window,
+            full_moon,
+            full_moon.add_days(half_window),
             umbral_radius,
             -1.0,
+            &config.radii,
         )?;
         (Some(u2), Some(u3))
     } else {
         (None, None)
     };
 
-    let angular_sep = sun_moon_angular_separation(engine, full_moon_jd)?;
+    let angular_sep = sun_moon_angular_separation(engine, full_moon)?;
+    let utc = |t: Epoch| UtcTime::from_epoch(t, engine.lsk());
 
     Ok(Some(ChandraGrahan {
         grahan_type,
         magnitude: umbral_magnitude,
         penumbral_magnitude,
-        greatest_grahan_jd: full_moon_jd,
-        greatest_grahan_utc: UtcTime::from_jd_tdb(full_moon_jd, engine.lsk()),
-        p1_jd,
-        p1_utc: UtcTime::from_jd_tdb(p1_jd, engine.lsk()),
-        u1_jd,
-        u1_utc: u1_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        u2_jd,
-        u2_utc: u2_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        u3_jd,
-        u3_utc: u3_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        u4_jd,
-        u4_utc: u4_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        p4_jd,
-        p4_utc: UtcTime::from_jd_tdb(p4_jd, engine.lsk()),
+        greatest_grahan_jd: full_moon.as_jd_tdb(),
+        greatest_grahan_epoch: full_moon,
+        greatest_grahan_utc: utc(full_moon),
+        p1_jd: p1.as_jd_tdb(),
+        p1_epoch: p1,
+        p1_utc: utc(p1),
+        u1_jd: u1.map(Epoch::as_jd_tdb),
+        u1_epoch: u1,
+        u1_utc: u1.map(utc),
+        u2_jd: u2.map(Epoch::as_jd_tdb),
+        u2_epoch: u2,
+        u2_utc: u2.map(utc),
+        u3_jd: u3.map(Epoch::as_jd_tdb),
+        u3_epoch: u3,
+        u3_utc: u3.map(utc),
+        u4_jd: u4.map(Epoch::as_jd_tdb),
+        u4_epoch: u4,
+        u4_utc: u4.map(utc),
+        p4_jd: p4.as_jd_tdb(),
+        p4_epoch: p4,
+        p4_utc: utc(p4),
         moon_ecliptic_lat_deg: moon_lat,
         angular_separation_deg: angular_sep,
     }))
 }
 
-/// Find the next chandra grahan (lunar eclipse) after `jd_tdb`.
+/// Find the next chandra grahan (lunar eclipse) after `at` (see [`SearchInstant`]).
 pub fn next_chandra_grahan(
     engine: &Engine,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Option<ChandraGrahan>, SearchError> {
     let moon_config = ConjunctionConfig::opposition(MOON_STEP_DAYS);
-    let mut search_jd = jd_tdb;
+    let mut search_from = at.to_epoch(engine);
 
     // Search up to ~2 years (enough for at least 2 grahan seasons)
     for _ in 0..50 {
-        let full_moon = next_conjunction(engine, Body::Sun, Body::Moon, search_jd, &moon_config)?;
+        let full_moon = next_conjunction(engine, Body::Sun, Body::Moon, search_from, &moon_config)?;
         let Some(fm) = full_moon else {
             return Ok(None);
         };
 
-        if let Some(grahan) = compute_chandra_grahan(engine, fm.jd_tdb, config)? {
+        if let Some(grahan) = compute_chandra_grahan(engine, fm.epoch, config)? {
             return Ok(Some(grahan));
         }
 
         // Advance past this full moon
-        search_jd = fm.jd_tdb + 1.0;
+        search_from = fm.epoch.add_days(1.0);
     }
 
     Ok(None)
 }
 
-/// Find the previous chandra grahan (lunar eclipse) before `jd_tdb`.
+/// Find the previous chandra grahan (lunar eclipse) before `at` (see [`SearchInstant`]).
 pub fn prev_chandra_grahan(
     engine: &Engine,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Option<ChandraGrahan>, SearchError> {
     let moon_config = ConjunctionConfig::opposition(MOON_STEP_DAYS);
-    let mut search_jd = jd_tdb;
+    let mut search_from = at.to_epoch(engine);
 
     for _ in 0..50 {
-        let full_moon = prev_conjunction(engine, Body::Sun, Body::Moon, search_jd, &moon_config)?;
+        let full_moon = prev_conjunction(engine, Body::Sun, Body::Moon, search_from, &moon_config)?;
         let Some(fm) = full_moon else {
             return Ok(None);
         };
 
-        if let Some(grahan) = compute_chandra_grahan(engine, fm.jd_tdb, config)? {
+        if let Some(grahan) = compute_chandra_grahan(engine, fm.epoch, config)? {
             return Ok(Some(grahan));
         }
 
-        search_jd = fm.jd_tdb - 1.0;
+        search_from = fm.epoch.add_days(-1.0);
     }
 
     Ok(None)
@@ -426,27 +438,22 @@ pub fn prev_chandra_grahan(
 /// Search for all chandra grahan in a time range.
 pub fn search_chandra_grahan(
     engine: &Engine,
-    jd_start: f64,
-    jd_end: f64,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Vec<ChandraGrahan>, SearchError> {
-    if jd_end <= jd_start {
+    let start = start.to_epoch(engine);
+    let end = end.to_epoch(engine);
+    if end.seconds_since(start) <= 0.0 {
         return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
     }
 
     let moon_config = ConjunctionConfig::opposition(MOON_STEP_DAYS);
-    let full_moons = search_conjunctions(
-        engine,
-        Body::Sun,
-        Body::Moon,
-        jd_start,
-        jd_end,
-        &moon_config,
-    )?;
+    let full_moons = search_conjunctions(engine, Body::Sun, Body::Moon, start, end, &moon_config)?;
 
     let mut results = Vec::new();
     for fm in &full_moons {
-        if let Some(grahan) = compute_chandra_grahan(engine, fm.jd_tdb, config)? {
+        if let Some(grahan) = compute_chandra_grahan(engine, fm.epoch, config)? {
             results.push(grahan);
         }
     }
@@ -490,55 +497,42 @@ fn classify_surya(
 /// (sun_r + moon_r for external, |sun_r - moon_r| for internal).
 fn find_surya_contact(
     engine: &Engine,
-    t_a: f64,
-    t_b: f64,
+    t_a: Epoch,
+    t_b: Epoch,
     target_sep_deg: f64,
-) -> Result<f64, SearchError> {
-    let f = |jd: f64| -> Result<f64, SearchError> {
-        let sep = sun_moon_angular_separation(engine, jd)?;
+) -> Result<Epoch, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> {
+        let sep = sun_moon_angular_separation(engine, t)?;
         Ok(sep - target_sep_deg)
     };
 
-    let mut ta = t_a;
-    let mut tb = t_b;
-    let mut fa = f(ta)?;
-
-    for _ in 0..CONTACT_MAX_ITER {
-        let tm = 0.5 * (ta + tb);
-        let fm = f(tm)?;
-
-        if fa * fm <= 0.0 {
-            tb = tm;
-        } else {
-            ta = tm;
-            fa = fm;
-        }
-
-        if (tb - ta).abs() < CONTACT_CONVERGENCE_DAYS {
-            break;
-        }
-    }
-
-    Ok(0.5 * (ta + tb))
+    bisect_epoch(
+        &f,
+        t_a,
+        f(t_a)?,
+        t_b,
+        CONTACT_MAX_ITER,
+        CONTACT_CONVERGENCE_S,
+    )
 }
 
 /// Compute a single geocentric surya grahan from a new moon event.
 fn compute_surya_grahan(
     engine: &Engine,
-    new_moon_jd: f64,
-    _config: &GrahanConfig,
+    new_moon: Epoch,
+    config: &GrahanConfig,
 ) -> Result<Option<SuryaGrahan>, SearchError> {
     // Get Moon's ecliptic latitude at new moon
-    let (_, moon_lat, moon_dist) = moon_ecliptic(engine, new_moon_jd)?;
+    let (_, moon_lat, moon_dist) = moon_ecliptic(engine, new_moon)?;
 
     if moon_lat.abs() > GRAHAN_LAT_THRESHOLD_DEG {
         return Ok(None);
     }
 
-    let sun_dist = sun_distance(engine, new_moon_jd)?;
-    let sun_r = sun_angular_radius_deg(sun_dist);
-    let moon_r = moon_angular_radius_deg(moon_dist);
-    let min_sep = sun_moon_angular_separation(engine, new_moon_jd)?;
+    let sun_dist = sun_distance(engine, new_moon)?;
+    let sun_r = sun_angular_radius_deg(sun_dist, &config.radii);
+    let moon_r = moon_angular_radius_deg(moon_dist, &config.radii);
+    let min_sep = sun_moon_angular_separation(engine, new_moon)?;
 
     let grahan_type = match classify_surya(sun_r, moon_r, min_sep) {
         Some(t) => t,
@@ -553,89 +547,114 @@ fn compute_surya_grahan(
     let internal_sep = (sun_r - moon_r).abs();
 
     // C1: first external contact (disks start touching)
-    let c1_jd =
-        find_surya_contact(engine, new_moon_jd - half_window, new_moon_jd, external_sep).ok();
+    let c1 = find_surya_contact(
+        engine,
+        new_moon.add_days(-half_window),
+        new_moon,
+        external_sep,
+    )
+    .ok();
 
     // C4: last external contact (disks stop touching)
-    let c4_jd =
-        find_surya_contact(engine, new_moon_jd, new_moon_jd + half_window, external_sep).ok();
+    let c4 = find_surya_contact(
+        engine,
+        new_moon,
+        new_moon.add_days(half_window),
+        external_sep,
+    )
+    .ok();
 
     // C2/C3: internal contacts (only for total/annular)
-    let (c2_jd, c3_jd) = if grahan_type == SuryaGrahanType::Total
-        || grahan_type == SuryaGrahanType::Annular
-    {
-        let c2 =
-            find_surya_contact(engine, new_moon_jd - half_window, new_moon_jd, internal_sep).ok();
-        let c3 =
-            find_surya_contact(engine, new_moon_jd, new_moon_jd + half_window, internal_sep).ok();
-        (c2, c3)
-    } else {
-        (None, None)
-    };
+    let (c2, c3) =
+        if grahan_type == SuryaGrahanType::Total || grahan_type == SuryaGrahanType::Annular {
+            let c2 = find_surya_contact(
+                engine,
+                new_moon.add_days(-half_window),
+                new_moon,
+                internal_sep,
+            )
+            .ok();
+            let c3 = find_surya_contact(
+                engine,
+                new_moon,
+                new_moon.add_days(half_window),
+                internal_sep,
+            )
+            .ok();
+            (c2, c3)
+        } else {
+            (None, None)
+        };
 
+    let utc = |t: Epoch| UtcTime::from_epoch(t, engine.lsk());
     Ok(Some(SuryaGrahan {
         grahan_type,
         magnitude,
-        greatest_grahan_jd: new_moon_jd,
-        greatest_grahan_utc: UtcTime::from_jd_tdb(new_moon_jd, engine.lsk()),
-        c1_jd,
-        c1_utc: c1_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        c2_jd,
-        c2_utc: c2_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        c3_jd,
-        c3_utc: c3_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
-        c4_jd,
-        c4_utc: c4_jd.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
+        greatest_grahan_jd: new_moon.as_jd_tdb(),
+        greatest_grahan_epoch: new_moon,
+        greatest_grahan_utc: utc(new_moon),
+        c1_jd: c1.map(Epoch::as_jd_tdb),
+        c1_epoch: c1,
+        c1_utc: c1.map(utc),
+        c2_jd: c2.map(Epoch::as_jd_tdb),
+        c2_epoch: c2,
+        c2_utc: c2.map(utc),
+        c3_jd: c3.map(Epoch::as_jd_tdb),
+        c3_epoch: c3,
+        c3_utc: c3.map(utc),
+        c4_jd: c4.map(Epoch::as_jd_tdb),
+        c4_epoch: c4,
+        c4_utc: c4.map(utc),
         moon_ecliptic_lat_deg: moon_lat,
         angular_separation_deg: min_sep,
     }))
 }
 
-/// Find the next geocentric surya grahan (solar eclipse) after `jd_tdb`.
+/// Find the next geocentric surya grahan (solar eclipse) after `at` (see [`SearchInstant`]).
 pub fn next_surya_grahan(
     engine: &Engine,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Option<SuryaGrahan>, SearchError> {
     let moon_config = ConjunctionConfig::conjunction(MOON_STEP_DAYS);
-    let mut search_jd = jd_tdb;
+    let mut search_from = at.to_epoch(engine);
 
     for _ in 0..50 {
-        let new_moon = next_conjunction(engine, Body::Sun, Body::Moon, search_jd, &moon_config)?;
+        let new_moon = next_conjunction(engine, Body::Sun, Body::Moon, search_from, &moon_config)?;
         let Some(nm) = new_moon else {
             return Ok(None);
         };
 
-        if let Some(grahan) = compute_surya_grahan(engine, nm.jd_tdb, config)? {
+        if let Some(grahan) = compute_surya_grahan(engine, nm.epoch, config)? {
             return Ok(Some(grahan));
         }
 
-        search_jd = nm.jd_tdb + 1.0;
+        search_from = nm.epoch.add_days(1.0);
     }
 
     Ok(None)
 }
 
-/// Find the previous geocentric surya grahan (solar eclipse) before `jd_tdb`.
+/// Find the previous geocentric surya grahan (solar eclipse) before `at` (see [`SearchInstant`]).
 pub fn prev_surya_grahan(
     engine: &Engine,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Option<SuryaGrahan>, SearchError> {
     let moon_config = ConjunctionConfig::conjunction(MOON_STEP_DAYS);
-    let mut search_jd = jd_tdb;
+    let mut search_from = at.to_epoch(engine);
 
     for _ in 0..50 {
-        let new_moon = prev_conjunction(engine, Body::Sun, Body::Moon, search_jd, &moon_config)?;
+        let new_moon = prev_conjunction(engine, Body::Sun, Body::Moon, search_from, &moon_config)?;
         let Some(nm) = new_moon else {
             return Ok(None);
         };
 
-        if let Some(grahan) = compute_surya_grahan(engine, nm.jd_tdb, config)? {
+        if let Some(grahan) = compute_surya_grahan(engine, nm.epoch, config)? {
             return Ok(Some(grahan));
         }
 
-        search_jd = nm.jd_tdb - 1.0;
+        search_from = nm.epoch.add_days(-1.0);
     }
 
     Ok(None)
@@ -644,27 +663,22 @@ pub fn prev_surya_grahan(
 /// Search for all geocentric surya grahan in a time range.
 pub fn search_surya_grahan(
     engine: &Engine,
-    jd_start: f64,
-    jd_end: f64,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &GrahanConfig,
 ) -> Result<Vec<SuryaGrahan>, SearchError> {
-    if jd_end <= jd_start {
+    let start = start.to_epoch(engine);
+    let end = end.to_epoch(engine);
+    if end.seconds_since(start) <= 0.0 {
         return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
     }
 
     let moon_config = ConjunctionConfig::conjunction(MOON_STEP_DAYS);
-    let new_moons = search_conjunctions(
-        engine,
-        Body::Sun,
-        Body::Moon,
-        jd_start,
-        jd_end,
-        &moon_config,
-    )?;
+    let new_moons = search_conjunctions(engine, Body::Sun, Body::Moon, start, end, &moon_config)?;
 
     let mut results = Vec::new();
     for nm in &new_moons {
-        if let Some(grahan) = compute_surya_grahan(engine, nm.jd_tdb, config)? {
+        if let Some(grahan) = compute_surya_grahan(engine, nm.epoch, config)? {
             results.push(grahan);
         }
     }
@@ -672,6 +686,562 @@ pub fn search_surya_grahan(
     Ok(results)
 }
 
+// ---------------------------------------------------------------------------
+// Surya grahan local circumstances (topocentric)
+// ---------------------------------------------------------------------------
+
+/// Half-width of the local search window around geocentric greatest grahan
+/// (days). Topocentric parallax shifts local contacts by at most a few hours.
+const LOCAL_HALF_WINDOW_DAYS: f64 = 5.0 / 24.0;
+
+/// Coarse sampling step for the local search (days): 5 minutes.
+const LOCAL_STEP_DAYS: f64 = 5.0 / 1440.0;
+
+/// Convergence of the local maximum search (seconds).
+const LOCAL_MAXIMUM_CONVERGENCE_S: f64 = 1e-2;
+
+/// Topocentric Sun–Moon geometry at one instant.
+#[derive(Debug, Clone, Copy)]
+struct LocalGeometry {
+    epoch: Epoch,
+    separation_deg: f64,
+    sun_radius_deg: f64,
+    moon_radius_deg: f64,
+    sun_altitude_deg: f64,
+    sun_azimuth_deg: f64,
+}
+
+impl LocalGeometry {
+    /// Separation minus the external-contact separation (negative while the disks overlap).
+    fn external(&self) -> f64 {
+        self.separation_deg - (self.sun_radius_deg + self.moon_radius_deg)
+    }
+
+    /// Separation minus the internal-contact separation (negative while one disk is inside the other).
+    fn internal(&self) -> f64 {
+        self.separation_deg - (self.sun_radius_deg - self.moon_radius_deg).abs()
+    }
+
+    fn above_horizon(&self) -> bool {
+        self.sun_altitude_deg > -self.sun_radius_deg
+    }
+}
+
+/// Topocentric Sun and Moon (light time + aberration) seen from `location`.
+fn local_geometry(
+    engine: &Engine,
+    epoch: Epoch,
+    location: &GeoLocation,
+    eop: Option<&EopKernel>,
+    options: &TopocentricOptions,
+    radii: &BodyRadii,
+) -> Result<LocalGeometry, SearchError> {
+    let query = |target| TopocentricEpochQuery {
+        target,
+        location: *location,
+        frame: Frame::IcrfJ2000,
+        epoch,
+        correction: Correction::LightTimeAberration,
+    };
+    let sun = engine.query_topocentric(query(Body::Sun), eop, options)?;
+    let moon = engine.query_topocentric(query(Body::Moon), eop, options)?;
+
+    let s = &sun.state.position_km;
+    let m = &moon.state.position_km;
+    let r_s = (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]).sqrt();
+    let r_m = (m[0] * m[0] + m[1] * m[1] + m[2] * m[2]).sqrt();
+    let dot = (s[0] * m[0] + s[1] * m[1] + s[2] * m[2]) / (r_s * r_m);
+
+    Ok(LocalGeometry {
+        epoch,
+        separation_deg: dot.clamp(-1.0, 1.0).acos().to_degrees(),
+        sun_radius_deg: sun_angular_radius_deg(r_s, radii),
+        moon_radius_deg: moon_angular_radius_deg(r_m, radii),
+        sun_altitude_deg: sun.horizontal.altitude_deg,
+        sun_azimuth_deg: sun.horizontal.azimuth_deg,
+    })
+}
+
+/// Fraction of the Sun's disk area covered by the Moon (flat-disk overlap).
+fn solar_obscuration(sun_radius_deg: f64, moon_radius_deg: f64, separation_deg: f64) -> f64 {
+    let (r_sun, r_moon, d) = (sun_radius_deg, moon_radius_deg, separation_deg);
+    if d >= r_sun + r_moon {
+        return 0.0;
+    }
+    if d <= (r_sun - r_moon).abs() {
+        return (r_moon.min(r_sun) / r_sun).powi(2);
+    }
+
+    let a_moon = ((d * d + r_moon * r_moon - r_sun * r_sun) / (2.0 * d * r_moon))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let a_sun = ((d * d + r_sun * r_sun - r_moon * r_moon) / (2.0 * d * r_sun))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let kite = ((-d + r_moon + r_sun)
+        * (d + r_moon - r_sun)
+        * (d - r_moon + r_sun)
+        * (d + r_moon + r_sun))
+        .max(0.0)
+        .sqrt();
+    let overlap = r_moon * r_moon * a_moon + r_sun * r_sun * a_sun - 0.5 * kite;
+    (overlap / (std::f64::consts::PI * r_sun * r_sun)).clamp(0.0, 1.0)
+}
+
+/// Golden-section search for the least topocentric separation in `[a, b]`.
+fn local_minimum_separation(
+    geometry: &dyn Fn(Epoch) -> Result<LocalGeometry, SearchError>,
+    a: Epoch,
+    b: Epoch,
+) -> Result<LocalGeometry, SearchError> {
+    let inv_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut lo, mut hi) = (0.0, b.seconds_since(a));
+    let mut x1 = hi - inv_phi * (hi - lo);
+    let mut x2 = lo + inv_phi * (hi - lo);
+    let mut g1 = geometry(a.add_seconds(x1))?;
+    let mut g2 = geometry(a.add_seconds(x2))?;
+
+    for _ in 0..CONTACT_MAX_ITER * 2 {
+        if hi - lo < LOCAL_MAXIMUM_CONVERGENCE_S {
+            break;
+        }
+        if g1.separation_deg < g2.separation_deg {
+            hi = x2;
+            x2 = x1;
+            g2 = g1;
+            x1 = hi - inv_phi * (hi - lo);
+            g1 = geometry(a.add_seconds(x1))?;
+        } else {
+            lo = x1;
+            x1 = x2;
+            g1 = g2;
+            x2 = lo + inv_phi * (hi - lo);
+            g2 = geometry(a.add_seconds(x2))?;
+        }
+    }
+
+    geometry(a.add_seconds(0.5 * (lo + hi)))
+}
+
+/// Bisect a local contact between a sample outside the phase and the local maximum.
+fn local_contact(
+    geometry: &dyn Fn(Epoch) -> Result<LocalGeometry, SearchError>,
+    contact: fn(&LocalGeometry) -> f64,
+    outside: &LocalGeometry,
+    maximum: Epoch,
+) -> Result<LocalGeometry, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> { Ok(contact(&geometry(t)?)) };
+    let (a, f_a, b) = if outside.epoch.seconds_since(maximum) < 0.0 {
+        (outside.epoch, contact(outside), maximum)
+    } else {
+        (maximum, f(maximum)?, outside.epoch)
+    };
+    let t = bisect_epoch(&f, a, f_a, b, CONTACT_MAX_ITER, CONTACT_CONVERGENCE_S)?;
+    geometry(t)
+}
+
+/// Local circumstances of a surya grahan (solar eclipse) at `location`.
+///
+/// Takes a geocentric event from [`next_surya_grahan`] / [`search_surya_grahan`]
+/// and repeats the disk geometry with topocentric Sun and Moon positions
+/// (light time + aberration, via [`Engine::query_topocentric`]) over ±5 hours
+/// of geocentric greatest grahan. Returns `None` if the Moon's disk never
+/// overlaps the Sun's as seen from the location.
+///
+/// Each contact reports the Sun's altitude and azimuth. `visible` is true
+/// when the Sun's upper limb clears the horizon at a contact, at maximum or
+/// at any 5-minute sample in between. `options` controls refraction of the reported
+/// altitudes; `eop` supplies UT1 and polar motion as in
+/// [`Engine::query_topocentric`].
+pub fn surya_grahan_local_circumstances(
+    engine: &Engine,
+    grahan: &SuryaGrahan,
+    location: &GeoLocation,
+    eop: Option<&EopKernel>,
+    options: &TopocentricOptions,
+    config: &GrahanConfig,
+) -> Result<Option<SuryaGrahanLocal>, SearchError> {
+    let geometry = |t: Epoch| local_geometry(engine, t, location, eop, options, &config.radii);
+
+    let start = grahan
+        .greatest_grahan_epoch
+        .add_days(-LOCAL_HALF_WINDOW_DAYS);
+    let steps = (2.0 * LOCAL_HALF_WINDOW_DAYS / LOCAL_STEP_DAYS).round() as usize;
+    let samples = (0..=steps)
+        .map(|i| geometry(start.add_days(i as f64 * LOCAL_STEP_DAYS)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let i_min = samples
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.separation_deg.total_cmp(&b.1.separation_deg))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let max = local_minimum_separation(
+        &geometry,
+        samples[i_min.saturating_sub(1)].epoch,
+        samples[(i_min + 1).min(steps)].epoch,
+    )?;
+
+    let Some(grahan_type) =
+        classify_surya(max.sun_radius_deg, max.moon_radius_deg, max.separation_deg)
+    else {
+        return Ok(None);
+    };
+
+    let before = |contact: fn(&LocalGeometry) -> f64| {
+        samples
+            .iter()
+            .rev()
+            .find(|s| s.epoch.seconds_since(max.epoch) < 0.0 && contact(s) > 0.0)
+    };
+    let after = |contact: fn(&LocalGeometry) -> f64| {
+        samples
+            .iter()
+            .find(|s| s.epoch.seconds_since(max.epoch) > 0.0 && contact(s) > 0.0)
+    };
+    let contact = |outside: Option<&LocalGeometry>, f: fn(&LocalGeometry) -> f64| {
+        outside
+            .map(|o| local_contact(&geometry, f, o, max.epoch))
+            .transpose()
+    };
+
+    let c1 = contact(before(LocalGeometry::external), LocalGeometry::external)?;
+    let c4 = contact(after(LocalGeometry::external), LocalGeometry::external)?;
+    let (c2, c3) = if grahan_type == SuryaGrahanType::Partial {
+        (None, None)
+    } else {
+        (
+            contact(before(LocalGeometry::internal), LocalGeometry::internal)?,
+            contact(after(LocalGeometry::internal), LocalGeometry::internal)?,
+        )
+    };
+
+    let first = c1.map_or(samples[0].epoch, |c| c.epoch);
+    let last = c4.map_or(samples[steps].epoch, |c| c.epoch);
+    let visible =
+        [c1, Some(max), c4]
+            .iter()
+            .flatten()
+            .chain(samples.iter().filter(|s| {
+                s.epoch.seconds_since(first) >= 0.0 && s.epoch.seconds_since(last) <= 0.0
+            }))
+            .any(LocalGeometry::above_horizon);
+
+    let local_contact = |g: LocalGeometry| SuryaGrahanLocalContact {
+        jd: g.epoch.as_jd_tdb(),
+        epoch: g.epoch,
+        utc: UtcTime::from_epoch(g.epoch, engine.lsk()),
+        sun_altitude_deg: g.sun_altitude_deg,
+        sun_azimuth_deg: g.sun_azimuth_deg,
+        above_horizon: g.above_horizon(),
+    };
+
+    Ok(Some(SuryaGrahanLocal {
+        location: *location,
+        grahan_type,
+        magnitude: (max.sun_radius_deg + max.moon_radius_deg - max.separation_deg)
+            / (2.0 * max.sun_radius_deg),
+        obscuration: solar_obscuration(max.sun_radius_deg, max.moon_radius_deg, max.separation_deg),
+        angular_separation_deg: max.separation_deg,
+        greatest_grahan: local_contact(max),
+        c1: c1.map(local_contact),
+        c2: c2.map(local_contact),
+        c3: c3.map(local_contact),
+        c4: c4.map(local_contact),
+        visible,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Chandra grahan visibility (topocentric)
+// ---------------------------------------------------------------------------
+
+/// Moon-altitude sampling step for local visibility (days): 5 minutes.
+const VISIBILITY_STEP_DAYS: f64 = 5.0 / 1440.0;
+
+/// Time sampling step for the world visibility grid (days): 10 minutes.
+const GRID_STEP_DAYS: f64 = 10.0 / 1440.0;
+
+/// Topocentric Moon in the local sky at one instant.
+#[derive(Debug, Clone, Copy)]
+struct MoonHorizon {
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    radius_deg: f64,
+}
+
+impl MoonHorizon {
+    /// Altitude of the Moon's upper limb (positive: above the horizon).
+    fn upper_limb_deg(&self) -> f64 {
+        self.altitude_deg + self.radius_deg
+    }
+}
+
+fn moon_horizon(
+    engine: &Engine,
+    epoch: Epoch,
+    location: &GeoLocation,
+    eop: Option<&EopKernel>,
+    options: &TopocentricOptions,
+    radii: &BodyRadii,
+) -> Result<MoonHorizon, SearchError> {
+    let query = TopocentricEpochQuery {
+        target: Body::Moon,
+        location: *location,
+        frame: Frame::IcrfJ2000,
+        epoch,
+        correction: Correction::LightTimeAberration,
+    };
+    let state = engine.query_topocentric(query, eop, options)?;
+    Ok(MoonHorizon {
+        altitude_deg: state.horizontal.altitude_deg,
+        azimuth_deg: state.horizontal.azimuth_deg,
+        radius_deg: moon_angular_radius_deg(state.horizontal.distance_km, radii),
+    })
+}
+
+/// Moonrise/moonset crossings in `[start, end]` as `(epoch, rising)`.
+fn horizon_crossings(
+    f: &dyn Fn(Epoch) -> Result<f64, SearchError>,
+    start: Epoch,
+    end: Epoch,
+) -> Result<Vec<(Epoch, bool)>, SearchError> {
+    let span_days = end.seconds_since(start) / 86_400.0;
+    let steps = (span_days / VISIBILITY_STEP_DAYS).ceil().max(1.0) as usize;
+    let mut crossings = Vec::new();
+    let (mut t_prev, mut f_prev) = (start, f(start)?);
+    for i in 1..=steps {
+        let t = if i == steps {
+            end
+        } else {
+            start.add_days(i as f64 * VISIBILITY_STEP_DAYS)
+        };
+        let f_curr = f(t)?;
+        if (f_prev > 0.0) != (f_curr > 0.0) {
+            let root = bisect_epoch(
+                f,
+                t_prev,
+                f_prev,
+                t,
+                CONTACT_MAX_ITER,
+                CONTACT_CONVERGENCE_S,
+            )?;
+            crossings.push((root, f_curr > 0.0));
+        }
+        t_prev = t;
+        f_prev = f_curr;
+    }
+    Ok(crossings)
+}
+
+/// First-to-last visible instant of the phase `[a, b]`.
+///
+/// If the Moon sets and rises again inside the phase (possible only at
+/// high latitudes) the window spans the gap.
+fn visible_window(
+    a: Epoch,
+    above_a: bool,
+    b: Epoch,
+    above_b: bool,
+    crossings: &[(Epoch, bool)],
+) -> Option<(Epoch, Epoch)> {
+    let inside = |t: &Epoch| t.seconds_since(a) > 0.0 && t.seconds_since(b) < 0.0;
+    let start = if above_a {
+        Some(a)
+    } else {
+        crossings
+            .iter()
+            .find(|(t, rising)| *rising && inside(t))
+            .map(|(t, _)| *t)
+    }?;
+    let end = if above_b {
+        Some(b)
+    } else {
+        crossings
+            .iter()
+            .rev()
+            .find(|(t, rising)| !*rising && inside(t))
+            .map(|(t, _)| *t)
+    }?;
+    (end.seconds_since(start) >= 0.0).then_some((start, end))
+}
+
+/// Visibility of P1–P4 from above-horizon samples taken across it.
+fn classify_visibility(above: impl IntoIterator<Item = bool>) -> GrahanVisibility {
+    let (mut any, mut all) = (false, true);
+    for a in above {
+        any |= a;
+        all &= a;
+    }
+    match (any, all) {
+        (true, true) => GrahanVisibility::Visible,
+        (true, false) => GrahanVisibility::PartlyVisible,
+        _ => GrahanVisibility::NotVisible,
+    }
+}
+
+/// Local circumstances of a chandra grahan (lunar eclipse) at `location`.
+///
+/// Contact times are those of `grahan`; this adds the topocentric Moon's
+/// altitude and azimuth at each contact and the parts of the penumbral
+/// (P1–P4), umbral (U1–U4) and total (U2–U3) phases during which the Moon's
+/// upper limb is above the horizon. Moonrise/moonset inside the grahan are
+/// found by 5-minute sampling and bisection. `options` controls refraction;
+/// `eop` supplies UT1 and polar motion as in [`Engine::query_topocentric`].
+pub fn chandra_grahan_local_circumstances(
+    engine: &Engine,
+    grahan: &ChandraGrahan,
+    location: &GeoLocation,
+    eop: Option<&EopKernel>,
+    options: &TopocentricOptions,
+    config: &GrahanConfig,
+) -> Result<ChandraGrahanLocal, SearchError> {
+    let horizon = |t: Epoch| moon_horizon(engine, t, location, eop, options, &config.radii);
+    let upper_limb = |t: Epoch| -> Result<f64, SearchError> { Ok(horizon(t)?.upper_limb_deg()) };
+    let crossings = horizon_crossings(&upper_limb, grahan.p1_epoch, grahan.p4_epoch)?;
+
+    let utc = |t: Epoch| UtcTime::from_epoch(t, engine.lsk());
+    let contact = |t: Epoch| -> Result<ChandraGrahanLocalContact, SearchError> {
+        let h = horizon(t)?;
+        Ok(ChandraGrahanLocalContact {
+            jd: t.as_jd_tdb(),
+            epoch: t,
+            utc: utc(t),
+            moon_altitude_deg: h.altitude_deg,
+            moon_azimuth_deg: h.azimuth_deg,
+            above_horizon: h.upper_limb_deg() > 0.0,
+        })
+    };
+    let optional = |t: Option<Epoch>| t.map(contact).transpose();
+
+    let p1 = contact(grahan.p1_epoch)?;
+    let p4 = contact(grahan.p4_epoch)?;
+    let u1 = optional(grahan.u1_epoch)?;
+    let u2 = optional(grahan.u2_epoch)?;
+    let u3 = optional(grahan.u3_epoch)?;
+    let u4 = optional(grahan.u4_epoch)?;
+
+    let window = |a: Option<ChandraGrahanLocalContact>, b: Option<ChandraGrahanLocalContact>| {
+        let (a, b) = (a?, b?);
+        let (start, end) = visible_window(
+            a.epoch,
+            a.above_horizon,
+            b.epoch,
+            b.above_horizon,
+            &crossings,
+        )?;
+        Some(GrahanVisibleWindow {
+            start_jd: start.as_jd_tdb(),
+            start_epoch: start,
+            start_utc: utc(start),
+            end_jd: end.as_jd_tdb(),
+            end_epoch: end,
+            end_utc: utc(end),
+        })
+    };
+    let penumbral_window = window(Some(p1), Some(p4));
+    let visibility = match (crossings.is_empty(), p1.above_horizon) {
+        (false, _) => GrahanVisibility::PartlyVisible,
+        (true, true) => GrahanVisibility::Visible,
+        (true, false) => GrahanVisibility::NotVisible,
+    };
+
+    Ok(ChandraGrahanLocal {
+        location: *location,
+        p1,
+        u1,
+        u2,
+        greatest_grahan: contact(grahan.greatest_grahan_epoch)?,
+        u3,
+        u4,
+        p4,
+        penumbral_window,
+        umbral_window: window(u1, u4),
+        total_window: window(u2, u3),
+        visibility,
+    })
+}
+
+/// Rows and columns of a `step_deg` grid: latitudes −90°..=+90° and
+/// longitudes from −180° up to but excluding +180°, so no meridian appears
+/// twice.
+fn grid_shape(step_deg: f64) -> (usize, usize) {
+    let rows = (180.0 / step_deg).floor() as usize + 1;
+    let cols = (360.0 / step_deg).floor() as usize;
+    (rows, cols)
+}
+
+/// Latitude and longitude of grid cell `(row, col)`; the last row is held
+/// to +90° against round-off.
+fn grid_point(step_deg: f64, row: usize, col: usize) -> (f64, f64) {
+    let latitude_deg = (-90.0 + row as f64 * step_deg).min(90.0);
+    (latitude_deg, -180.0 + col as f64 * step_deg)
+}
+
+/// Sampled world visibility map of a chandra grahan.
+///
+/// Evaluates the Moon's topocentric altitude on a `step_deg` latitude ×
+/// longitude grid every 10 minutes from P1 to P4 (plus both contacts) and
+/// classifies each grid point as visible throughout, partly visible or not
+/// visible. Uses one geocentric Moon query per instant and the WGS84 site
+/// offset of each grid point, so diurnal aberration is omitted.
+pub fn chandra_grahan_visibility_grid(
+    engine: &Engine,
+    grahan: &ChandraGrahan,
+    step_deg: f64,
+    eop: Option<&EopKernel>,
+    options: &TopocentricOptions,
+    config: &GrahanConfig,
+) -> Result<ChandraGrahanVisibilityGrid, SearchError> {
+    if !(step_deg.is_finite() && step_deg > 0.0 && step_deg <= 90.0) {
+        return Err(SearchError::InvalidConfig("step_deg must be in (0, 90]"));
+    }
+
+    let span_days = grahan.p4_epoch.seconds_since(grahan.p1_epoch) / 86_400.0;
+    let steps = (span_days / GRID_STEP_DAYS).ceil().max(1.0) as usize;
+    let mut samples = Vec::with_capacity(steps + 1);
+    for i in 0..=steps {
+        let t = if i == steps {
+            grahan.p4_epoch
+        } else {
+            grahan.p1_epoch.add_days(i as f64 * GRID_STEP_DAYS)
+        };
+        let (jd_ut1, pole) = earth_rotation_at(engine, t, eop)?;
+        let matrix = gcrs_to_itrs_matrix(t.as_jd_tdb(), jd_ut1, &pole);
+        let moon = engine.query(EpochQuery {
+            target: Body::Moon,
+            observer: Observer::Body(Body::Earth),
+            frame: Frame::IcrfJ2000,
+            epoch: t,
+            correction: Correction::LightTimeAberration,
+        })?;
+        samples.push((matrix, moon.position_km));
+    }
+
+    let (rows, cols) = grid_shape(step_deg);
+    let mut cells = Vec::with_capacity(rows * cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            let (latitude_deg, longitude_deg) = grid_point(step_deg, r, c);
+            let location = GeoLocation::new(latitude_deg, longitude_deg, 0.0);
+            let visibility = classify_visibility(samples.iter().map(|(matrix, moon)| {
+                let site = site_gcrs_state(&location, matrix);
+                let rel = [moon[0] - site[0], moon[1] - site[1], moon[2] - site[2]];
+                let h = horizontal_from_gcrs(&location, matrix, &rel, options);
+                h.altitude_deg + moon_angular_radius_deg(h.distance_km, &config.radii) > 0.0
+            }));
+            cells.push(GrahanVisibilityCell {
+                latitude_deg,
+                longitude_deg,
+                visibility,
+            });
+        }
+    }
+
+    Ok(ChandraGrahanVisibilityGrid { step_deg, cells })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -679,23 +1249,40 @@ mod tests {
     #[test]
     fn shadow_radii_reasonable() {
         // Sun at ~1 AU, Moon at ~384400 km
-        let (pen, umb) = shadow_radii_deg(149_597_870.7, 384_400.0);
+        let (pen, umb) = shadow_radii_deg(149_597_870.7, 384_400.0, &BodyRadii::default());
         // Penumbral radius ~1.2-1.3 deg (pi_moon ~0.95 deg dominates)
         assert!(pen > 1.1 && pen < 1.4, "penumbral = {pen}");
         // Umbral radius ~0.65-0.75 deg (pi_moon - s_sun, Danjon enlarged)
         assert!(umb > 0.6 && umb < 0.8, "umbral = {umb}");
     }
 
+    #[test]
+    fn grid_longitudes_stay_below_180() {
+        assert_eq!(grid_shape(10.0), (19, 36));
+        assert_eq!(grid_shape(7.0), (26, 51));
+        // Steps of 360/n such as 360/227 round so that 360/step lands just
+        // above n; a ceiling would then add a column at +180.
+        let steps = (1..=9000)
+            .map(|i| f64::from(i) / 100.0)
+            .chain((4..=5000).map(|n| 360.0 / f64::from(n)));
+        for step in steps {
+            let (rows, cols) = grid_shape(step);
+            let (last_lat, last_lon) = grid_point(step, rows - 1, cols - 1);
+            assert!(last_lon < 180.0, "step {step}: last longitude {last_lon}");
+            assert!(last_lat <= 90.0, "step {step}: last latitude {last_lat}");
+        }
+    }
+
     #[test]
     fn moon_angular_radius_typical() {
-        let r = moon_angular_radius_deg(384_400.0);
+        let r = moon_angular_radius_deg(384_400.0, &BodyRadii::default());
         // ~0.26 deg
         assert!(r > 0.24 && r < 0.28, "moon angular radius = {r}");
     }
 
     #[test]
     fn sun_angular_radius_typical() {
-        let r = sun_angular_radius_deg(149_597_870.7);
+        let r = sun_angular_radius_deg(149_597_870.7, &BodyRadii::default());
         // ~0.266 deg
         assert!(r > 0.25 && r < 0.28, "sun angular radius = {r}");
     }
@@ -760,6 +1347,80 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn solar_obscuration_limits() {
+        assert_eq!(solar_obscuration(0.266, 0.260, 0.6), 0.0);
+        assert!((solar_obscuration(0.266, 0.270, 0.002) - 1.0).abs() < 1e-12);
+        // Annular: ring of the Sun stays uncovered
+        let annular = solar_obscuration(0.266, 0.250, 0.002);
+        assert!((annular - (0.250_f64 / 0.266).powi(2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn solar_obscuration_equal_disks() {
+        // Equal radii with centers one radius apart: (2π/3 − √3/2)/π ≈ 0.391
+        let r = 0.26;
+        let expected =
+            (2.0 * std::f64::consts::PI / 3.0 - 3.0_f64.sqrt() / 2.0) / std::f64::consts::PI;
+        assert!((solar_obscuration(r, r, r) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn solar_obscuration_grows_as_separation_shrinks() {
+        let mut prev = 0.0;
+        for i in (0..=52).rev() {
+            let o = solar_obscuration(0.266, 0.260, i as f64 * 0.01);
+            assert!(
+                o >= prev,
+                "obscuration decreased at sep {}",
+                i as f64 * 0.01
+            );
+            prev = o;
+        }
+    }
+
+    #[test]
+    fn visible_window_cases() {
+        let a = Epoch::from_jd_tdb(2_460_926.1);
+        let b = a.add_days(0.25);
+        let rise = a.add_days(0.05);
+        let set = a.add_days(0.2);
+
+        let full = visible_window(a, true, b, true, &[]).unwrap();
+        assert_eq!(full, (a, b));
+
+        // Moon rises during the phase
+        let w = visible_window(a, false, b, true, &[(rise, true)]).unwrap();
+        assert_eq!(w, (rise, b));
+
+        // Moon sets during the phase
+        let w = visible_window(a, true, b, false, &[(set, false)]).unwrap();
+        assert_eq!(w, (a, set));
+
+        // Rises and sets inside; crossings outside the phase are ignored
+        let crossings = [(a.add_days(-0.1), false), (rise, true), (set, false)];
+        let w = visible_window(a, false, b, false, &crossings).unwrap();
+        assert_eq!(w, (rise, set));
+
+        assert!(visible_window(a, false, b, false, &[]).is_none());
+    }
+
+    #[test]
+    fn classify_visibility_cases() {
+        assert_eq!(
+            classify_visibility([true, true, true]),
+            GrahanVisibility::Visible
+        );
+        assert_eq!(
+            classify_visibility([false, true, true]),
+            GrahanVisibility::PartlyVisible
+        );
+        assert_eq!(
+            classify_visibility([false, false]),
+            GrahanVisibility::NotVisible
+        );
+    }
+
     #[test]
     fn grahan_config_defaults() {
         let c = GrahanConfig::default();
@@ -1,38 +1,10 @@
 //! Types for grahan (eclipse) computation.
 
-use dhruv_time::UtcTime;
+use dhruv_core::BodyRadii;
+use dhruv_time::{Epoch, UtcTime};
 
-/// Geographic location on Earth's surface.
-///
-/// Identical fields to `dhruv_vedic_base::GeoLocation` but defined
-/// independently to avoid a dependency on the vedic crate.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GeoLocation {
-    /// Geodetic latitude in degrees, north positive. Range: [-90, 90].
-    pub latitude_deg: f64,
-    /// Geodetic longitude in degrees, east positive. Range: [-180, 180].
-    pub longitude_deg: f64,
-    /// Altitude above mean sea level in meters.
-    pub altitude_m: f64,
-}
-
-impl GeoLocation {
-    pub fn new(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Self {
-        Self {
-            latitude_deg,
-            longitude_deg,
-            altitude_m,
-        }
-    }
-
-    pub fn latitude_rad(&self) -> f64 {
-        self.latitude_deg.to_radians()
-    }
-
-    pub fn longitude_rad(&self) -> f64 {
-        self.longitude_deg.to_radians()
-    }
-}
+/// Geographic location on Earth's surface (WGS84 geodetic).
+pub use dhruv_core::GeoLocation;
 
 /// Grahan search configuration.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -41,6 +13,9 @@ pub struct GrahanConfig {
     pub include_penumbral: bool,
     /// Include ecliptic latitude and angular separation at peak. Default: true.
     pub include_peak_details: bool,
+    /// Sun, Earth and Moon radii for shadow and semidiameter geometry.
+    /// Default: IAU 2015 nominal values.
+    pub radii: BodyRadii,
 }
 
 impl Default for GrahanConfig {
@@ -48,6 +23,7 @@ impl Default for GrahanConfig {
         Self {
             include_penumbral: true,
             include_peak_details: true,
+            radii: BodyRadii::default(),
         }
     }
 }
@@ -75,30 +51,44 @@ pub struct ChandraGrahan {
     pub penumbral_magnitude: f64,
     /// Time of greatest grahan (JD TDB).
     pub greatest_grahan_jd: f64,
+    /// Time of greatest grahan as a full-precision TDB epoch.
+    pub greatest_grahan_epoch: Epoch,
     /// Time of greatest grahan as structured Gregorian UTC.
     pub greatest_grahan_utc: UtcTime,
     /// P1: First penumbral contact (JD TDB).
     pub p1_jd: f64,
+    /// P1 as a full-precision TDB epoch.
+    pub p1_epoch: Epoch,
     /// P1: First penumbral contact as structured Gregorian UTC.
     pub p1_utc: UtcTime,
     /// U1: First umbral contact (JD TDB). None for penumbral-only.
     pub u1_jd: Option<f64>,
+    /// U1 as a full-precision TDB epoch.
+    pub u1_epoch: Option<Epoch>,
     /// U1: First umbral contact as structured Gregorian UTC. None for penumbral-only.
     pub u1_utc: Option<UtcTime>,
     /// U2: Start of totality (JD TDB). None unless total.
     pub u2_jd: Option<f64>,
+    /// U2 as a full-precision TDB epoch.
+    pub u2_epoch: Option<Epoch>,
     /// U2: Start of totality as structured Gregorian UTC. None unless total.
     pub u2_utc: Option<UtcTime>,
     /// U3: End of totality (JD TDB). None unless total.
     pub u3_jd: Option<f64>,
+    /// U3 as a full-precision TDB epoch.
+    pub u3_epoch: Option<Epoch>,
     /// U3: End of totality as structured Gregorian UTC. None unless total.
     pub u3_utc: Option<UtcTime>,
     /// U4: Last umbral contact (JD TDB). None for penumbral-only.
     pub u4_jd: Option<f64>,
+    /// U4 as a full-precision TDB epoch.
+    pub u4_epoch: Option<Epoch>,
     /// U4: Last umbral contact as structured Gregorian UTC. None for penumbral-only.
     pub u4_utc: Option<UtcTime>,
     /// P4: Last penumbral contact (JD TDB).
     pub p4_jd: f64,
+    /// P4 as a full-precision TDB epoch.
+    pub p4_epoch: Epoch,
     /// P4: Last penumbral contact as structured Gregorian UTC.
     pub p4_utc: UtcTime,
     /// Moon's ecliptic latitude at greatest grahan, in degrees.
@@ -129,22 +119,32 @@ pub struct SuryaGrahan {
     pub magnitude: f64,
     /// Time of greatest grahan (JD TDB).
     pub greatest_grahan_jd: f64,
+    /// Time of greatest grahan as a full-precision TDB epoch.
+    pub greatest_grahan_epoch: Epoch,
     /// Time of greatest grahan as structured Gregorian UTC.
     pub greatest_grahan_utc: UtcTime,
     /// C1: First external contact (JD TDB). Moon's limb first touches Sun's limb.
     pub c1_jd: Option<f64>,
+    /// C1 as a full-precision TDB epoch.
+    pub c1_epoch: Option<Epoch>,
     /// C1 as structured Gregorian UTC. None if absent.
     pub c1_utc: Option<UtcTime>,
     /// C2: First internal contact (JD TDB). None for partial grahan.
     pub c2_jd: Option<f64>,
+    /// C2 as a full-precision TDB epoch.
+    pub c2_epoch: Option<Epoch>,
     /// C2 as structured Gregorian UTC. None if absent.
     pub c2_utc: Option<UtcTime>,
     /// C3: Last internal contact (JD TDB). None for partial grahan.
     pub c3_jd: Option<f64>,
+    /// C3 as a full-precision TDB epoch.
+    pub c3_epoch: Option<Epoch>,
     /// C3 as structured Gregorian UTC. None if absent.
     pub c3_utc: Option<UtcTime>,
     /// C4: Last external contact (JD TDB). Moon's limb last touches Sun's limb.
     pub c4_jd: Option<f64>,
+    /// C4 as a full-precision TDB epoch.
+    pub c4_epoch: Option<Epoch>,
     /// C4 as structured Gregorian UTC. None if absent.
     pub c4_utc: Option<UtcTime>,
     /// Moon's ecliptic latitude at greatest grahan, in degrees.
@@ -152,3 +152,144 @@ pub struct SuryaGrahan {
     /// Angular separation between Sun and Moon centers at greatest grahan, in degrees.
     pub angular_separation_deg: f64,
 }
+
+/// Sun's position in the local sky at one surya grahan instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuryaGrahanLocalContact {
+    /// Instant (JD TDB).
+    pub jd: f64,
+    /// Instant as a full-precision TDB epoch.
+    pub epoch: Epoch,
+    /// Instant as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// Topocentric Sun altitude in degrees (refracted if requested).
+    pub sun_altitude_deg: f64,
+    /// Topocentric Sun azimuth in degrees, measured from north through east.
+    pub sun_azimuth_deg: f64,
+    /// Whether the Sun's upper limb is above the horizon.
+    pub above_horizon: bool,
+}
+
+/// Surya grahan (solar eclipse) circumstances for one observer location.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuryaGrahanLocal {
+    /// Observer location the circumstances were computed for.
+    pub location: GeoLocation,
+    /// Local classification: total/annular only inside the central path.
+    pub grahan_type: SuryaGrahanType,
+    /// Local magnitude: fraction of the Sun's diameter covered at maximum.
+    pub magnitude: f64,
+    /// Local obscuration: fraction of the Sun's disk area covered at maximum.
+    pub obscuration: f64,
+    /// Topocentric Sun–Moon center separation at maximum, in degrees.
+    pub angular_separation_deg: f64,
+    /// Local maximum (least topocentric separation of the disk centers).
+    pub greatest_grahan: SuryaGrahanLocalContact,
+    /// C1: first external contact seen from the location.
+    pub c1: Option<SuryaGrahanLocalContact>,
+    /// C2: first internal contact. None unless total or annular locally.
+    pub c2: Option<SuryaGrahanLocalContact>,
+    /// C3: last internal contact. None unless total or annular locally.
+    pub c3: Option<SuryaGrahanLocalContact>,
+    /// C4: last external contact seen from the location.
+    pub c4: Option<SuryaGrahanLocalContact>,
+    /// Whether the Sun is above the horizon at any time between C1 and C4.
+    pub visible: bool,
+}
+
+/// How much of a grahan is above the horizon at a location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrahanVisibility {
+    /// Above the horizon from first to last contact.
+    Visible,
+    /// Above the horizon for part of the grahan (rises or sets during it).
+    PartlyVisible,
+    /// Below the horizon throughout.
+    NotVisible,
+}
+
+/// Moon's position in the local sky at one chandra grahan contact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChandraGrahanLocalContact {
+    /// Instant (JD TDB).
+    pub jd: f64,
+    /// Instant as a full-precision TDB epoch.
+    pub epoch: Epoch,
+    /// Instant as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// Topocentric Moon altitude in degrees (refracted if requested).
+    pub moon_altitude_deg: f64,
+    /// Topocentric Moon azimuth in degrees, measured from north through east.
+    pub moon_azimuth_deg: f64,
+    /// Whether the Moon's upper limb is above the horizon.
+    pub above_horizon: bool,
+}
+
+/// Part of a grahan phase during which the Moon is above the horizon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrahanVisibleWindow {
+    /// Start of the visible part (JD TDB).
+    pub start_jd: f64,
+    /// Start as a full-precision TDB epoch.
+    pub start_epoch: Epoch,
+    /// Start as structured Gregorian UTC.
+    pub start_utc: UtcTime,
+    /// End of the visible part (JD TDB).
+    pub end_jd: f64,
+    /// End as a full-precision TDB epoch.
+    pub end_epoch: Epoch,
+    /// End as structured Gregorian UTC.
+    pub end_utc: UtcTime,
+}
+
+/// Chandra grahan (lunar eclipse) circumstances for one observer location.
+///
+/// Contact instants are global; only the Moon's altitude differs by location.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChandraGrahanLocal {
+    /// Observer location the circumstances were computed for.
+    pub location: GeoLocation,
+    /// P1: first penumbral contact.
+    pub p1: ChandraGrahanLocalContact,
+    /// U1: first umbral contact. None for penumbral-only.
+    pub u1: Option<ChandraGrahanLocalContact>,
+    /// U2: start of totality. None unless total.
+    pub u2: Option<ChandraGrahanLocalContact>,
+    /// Greatest grahan.
+    pub greatest_grahan: ChandraGrahanLocalContact,
+    /// U3: end of totality. None unless total.
+    pub u3: Option<ChandraGrahanLocalContact>,
+    /// U4: last umbral contact. None for penumbral-only.
+    pub u4: Option<ChandraGrahanLocalContact>,
+    /// P4: last penumbral contact.
+    pub p4: ChandraGrahanLocalContact,
+    /// Visible part of P1–P4.
+    pub penumbral_window: Option<GrahanVisibleWindow>,
+    /// Visible part of U1–U4 (partial phase and totality).
+    pub umbral_window: Option<GrahanVisibleWindow>,
+    /// Visible part of U2–U3.
+    pub total_window: Option<GrahanVisibleWindow>,
+    /// Visibility of the whole grahan (P1–P4).
+    pub visibility: GrahanVisibility,
+}
+
+/// One cell of a chandra grahan world visibility grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrahanVisibilityCell {
+    /// Geodetic latitude of the grid point, in degrees.
+    pub latitude_deg: f64,
+    /// East longitude of the grid point, in degrees.
+    pub longitude_deg: f64,
+    /// Visibility of the grahan (P1–P4) at the grid point.
+    pub visibility: GrahanVisibility,
+}
+
+/// Sampled world visibility map of a chandra grahan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChandraGrahanVisibilityGrid {
+    /// Grid spacing in degrees (latitude and longitude).
+    pub step_deg: f64,
+    /// Cells in row-major order: latitude from −90° northwards, longitude
+    /// from −180° eastwards and below +180°.
+    pub cells: Vec<GrahanVisibilityCell>,
+}
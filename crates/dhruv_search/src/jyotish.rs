@@ -4,7 +4,7 @@
 //! Vedic calculation modules. Queries all 9 graha positions at a given
 //! epoch and converts to sidereal longitudes.
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
 use dhruv_frames::{
     ReferencePlane, cartesian_to_spherical, ecliptic_lon_to_invariable_lon, icrf_to_ecliptic,
     icrf_to_invariable, invariable_lon_to_ecliptic_lon, mean_obliquity_of_date_rad,
@@ -26,24 +26,28 @@ use dhruv_vedic_base::{
     ALL_GRAHAS, AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AmshaRequest,
     ArudhaResult, AshtakavargaResult, AvasthaInputs, Bhava, BhavaBalaBirthPeriod, BhavaBalaInputs,
     BhavaBalaResult, BhavaConfig, BhavaResult, CharakarakaResult, CharakarakaScheme,
-    DIG_BALA_BHAVA, Dignity, DrishtiEntry, Graha, GrahaAvasthas, GrahaDrishtiMatrix,
-    KalaBalaInputs, LajjitadiInputs, LunarNode, NodeDignityPolicy, NodeMode, SAPTA_GRAHAS,
-    SayanadiInputs, SayanadiResult, ShadbalaInputs, TimeUpagrahaConfig, all_avasthas,
-    all_combustion_status, all_shadbalas_from_inputs, all_sphutas, amsha_longitude, baladi_avastha,
-    bhava_bala_entry, bhrigu_bindu, calculate_ashtakavarga, calculate_bhava_bala,
-    charakarakas_from_longitudes, compound_dignity_in_rashi, compute_bhavas,
-    deeptadi_avasthas_with_dynamic_nature, default_amsha_variation,
-    dignity_in_rashi_with_positions, ghati_lagna, ghatikas_since_sunrise, graha_drishti,
-    graha_drishti_matrix, hora_lagna, hora_lord as graha_hora_lord, is_valid_amsha_variation,
-    jagradadi_avastha, jd_tdb_to_centuries, kala_abda_lord, kala_masa_lord, lagna_longitude_rad,
-    lajjitadi_avasthas_with_dynamic_nature, lost_planetary_war, lunar_node_deg_for_epoch_on_plane,
-    nakshatra_from_longitude, node_dignity_in_rashi, node_dignity_in_rashi_with_temporal_context,
-    normalize_360, nth_rashi_from, own_signs, pranapada_lagna, rashi_from_longitude,
-    rashi_lord_by_index, sayanadi_all_sub_states, sayanadi_avastha, shadbala_from_inputs,
-    sree_lagna, sun_based_upagrahas, time_upagraha_jd_with_config, vaar_lord as graha_vaar_lord,
+    DIG_BALA_BHAVA, DetectedYoga, Dignity, DoshaConfig, DoshaResult, DrishtiEntry, Graha,
+    GrahaAvasthas, GrahaDrishtiMatrix, KalaBalaInputs, LajjitadiInputs, LunarNode, MilanResult,
+    NodeDignityPolicy, NodeMode, SAPTA_GRAHAS, SayanadiInputs, SayanadiResult, ShadbalaInputs,
+    TimeUpagrahaConfig, YogaChart, YogaRule, all_avasthas, all_combustion_status,
+    all_shadbalas_from_inputs, all_sphutas, amsha_longitude, baladi_avastha, bhava_bala_entry,
+    bhrigu_bindu, calculate_ashtakavarga, calculate_bhava_bala, charakarakas_from_longitudes,
+    compound_dignity_in_rashi, compute_bhavas, deeptadi_avasthas_with_dynamic_nature,
+    default_amsha_variation, detect_yogas, dignity_in_rashi_with_positions, dosha_analysis,
+    ghati_lagna, ghatikas_since_sunrise, graha_drishti, graha_drishti_matrix, hora_lagna,
+    hora_lord as graha_hora_lord, is_valid_amsha_variation, jagradadi_avastha, jd_tdb_to_centuries,
+    kala_abda_lord, kala_masa_lord, kp_bhava_of, kp_lords, kp_ruling_planets, kp_significators,
+    kp_sub_number, kundali_milan, lagna_longitude_rad, lajjitadi_avasthas_with_dynamic_nature,
+    lost_planetary_war, lunar_node_deg_for_epoch_on_plane, nakshatra_from_longitude,
+    node_dignity_in_rashi, node_dignity_in_rashi_with_temporal_context, normalize_360,
+    nth_rashi_from, own_signs, pranapada_lagna, rashi_from_longitude, rashi_lord_by_index,
+    sayanadi_all_sub_states, sayanadi_avastha, shadbala_from_inputs, sree_lagna,
+    sun_based_upagrahas, time_upagraha_jd_with_config, vaar_lord as graha_vaar_lord,
 };
 
-use crate::conjunction::{body_ecliptic_lon_lat, body_ecliptic_state, body_lon_lat_on_plane};
+use crate::conjunction::{
+    body_ecliptic_lon_lat, body_ecliptic_state, body_lon_lat_on_plane_with_correction,
+};
 use crate::dasha::{
     DashaInputs, dasha_hierarchy_with_inputs, dasha_snapshot_with_inputs, is_rashi_system,
     needs_moon_lon, needs_sunrise_sunset,
@@ -54,8 +58,9 @@ use crate::jyotish_types::{
     BhavaResultSet, BindusConfig, BindusResult, DashaSelectionConfig, DashaSnapshotTime,
     DrishtiConfig, DrishtiResult, FullKundaliConfig, FullKundaliResult, GrahaEntry,
     GrahaLongitudeKind, GrahaLongitudes, GrahaLongitudesConfig, GrahaPositions,
-    GrahaPositionsConfig, MAX_AMSHA_REQUESTS, MovingOsculatingApogeeEntry, MovingOsculatingApogees,
-    ShadbalaEntry, ShadbalaResult, SphutalResult, VimsopakaEntry, VimsopakaResult,
+    GrahaPositionsConfig, KpChart, KpCuspEntry, KpGrahaEntry, MAX_AMSHA_REQUESTS,
+    MovingOsculatingApogeeEntry, MovingOsculatingApogees, ShadbalaEntry, ShadbalaResult,
+    SphutalResult, VimsopakaEntry, VimsopakaResult,
 };
 use crate::panchang::{
     hora_from_sunrises, masa_for_date_with_eop, panchang_for_date, varsha_for_date_with_eop,
@@ -231,29 +236,32 @@ fn outer_planet_longitudes_for_config(
         && config.reference_plane == ReferencePlane::Ecliptic
     {
         let t = jd_tdb_to_centuries(jd_tdb);
-        let (dpsi_arcsec, _deps_arcsec) = dhruv_frames::nutation_iau2000b(t);
+        let (dpsi_arcsec, _deps_arcsec) =
+            dhruv_frames::nutation_with_model(t, config.nutation_model);
         dpsi_arcsec / 3600.0
     } else {
         0.0
     };
     let aya = if config.kind == GrahaLongitudeKind::Sidereal {
-        dhruv_vedic_base::ayanamsha_deg_on_plane(
+        dhruv_vedic_base::ayanamsha_deg_on_plane_with_models(
             config.ayanamsha_system,
             jd_tdb_to_centuries(jd_tdb),
             config.use_nutation,
             config.precession_model,
+            config.nutation_model,
             config.reference_plane,
         )
     } else {
         0.0
     };
     for (index, body) in OUTER_PLANET_BODIES.iter().copied().enumerate() {
-        let (lon, _lat) = body_lon_lat_on_plane(
+        let (lon, _lat) = body_lon_lat_on_plane_with_correction(
             engine,
             body,
             jd_tdb,
             config.precession_model,
             config.reference_plane,
+            config.correction,
         )?;
         longitudes[index] = normalize(lon + dpsi_deg - aya);
     }
@@ -479,6 +487,7 @@ impl JyotishContext {
                     aya_config.precession_model,
                     aya_config.reference_plane,
                 )
+                .with_nutation_model(aya_config.nutation_model)
                 .with_outer_planets(false),
             )?;
             self.graha_lons = Some(lons);
@@ -499,7 +508,8 @@ impl JyotishContext {
             aya_config.use_nutation,
             aya_config.precession_model,
             aya_config.reference_plane,
-        );
+        )
+        .with_nutation_model(aya_config.nutation_model);
         let lons = outer_planet_longitudes_for_config(engine, self.jd_tdb, &config)?;
         self.outer_planet_lons = Some(lons);
         Ok(lons)
@@ -925,6 +935,7 @@ impl JyotishContext {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn upagrahas<'a>(
         &'a mut self,
         engine: &Engine,
@@ -1187,6 +1198,7 @@ fn osculating_longitudes_for_body(
         observer: Observer::Body(Body::Sun),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     })?;
     osculating_longitudes_from_heliocentric_state(
         state.position_km,
@@ -1245,11 +1257,12 @@ fn osculating_longitudes_from_heliocentric_state(
     let aphelion_reference_plane_longitude =
         normalize_360(periapsis_reference_plane_longitude + 180.0);
     let t = jd_tdb_to_centuries(jd_tdb);
-    let ayanamsha_deg = dhruv_vedic_base::ayanamsha_deg_on_plane(
+    let ayanamsha_deg = dhruv_vedic_base::ayanamsha_deg_on_plane_with_models(
         config.ayanamsha_system,
         t,
         config.use_nutation,
         config.precession_model,
+        config.nutation_model,
         config.reference_plane,
     );
 
@@ -1431,11 +1444,12 @@ fn graha_sidereal_longitudes_for_config(
     config: &GrahaLongitudesConfig,
 ) -> Result<GrahaLongitudes, SearchError> {
     let t = jd_tdb_to_centuries(jd_tdb);
-    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane(
+    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane_with_models(
         config.ayanamsha_system,
         t,
         config.use_nutation,
         config.precession_model,
+        config.nutation_model,
         config.reference_plane,
     );
     let rahu_on_plane = lunar_node_deg_for_epoch_on_plane(
@@ -1461,12 +1475,13 @@ fn graha_sidereal_longitudes_for_config(
             }
             _ => {
                 let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
+                let (lon, _lat) = body_lon_lat_on_plane_with_correction(
                     engine,
                     body,
                     jd_tdb,
                     config.precession_model,
                     config.reference_plane,
+                    config.correction,
                 )?;
                 longitudes[idx] = normalize(lon - aya);
             }
@@ -1492,7 +1507,8 @@ fn graha_reference_plane_longitudes(
 ) -> Result<GrahaLongitudes, SearchError> {
     let dpsi_deg = if config.use_nutation && config.reference_plane == ReferencePlane::Ecliptic {
         let t = jd_tdb_to_centuries(jd_tdb);
-        let (dpsi_arcsec, _deps_arcsec) = dhruv_frames::nutation_iau2000b(t);
+        let (dpsi_arcsec, _deps_arcsec) =
+            dhruv_frames::nutation_with_model(t, config.nutation_model);
         dpsi_arcsec / 3600.0
     } else {
         0.0
@@ -1516,12 +1532,13 @@ fn graha_reference_plane_longitudes(
             Graha::Ketu => longitudes[idx] = normalize(ketu_tropical + dpsi_deg),
             _ => {
                 let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
+                let (lon, _lat) = body_lon_lat_on_plane_with_correction(
                     engine,
                     body,
                     jd_tdb,
                     config.precession_model,
                     config.reference_plane,
+                    config.correction,
                 )?;
                 longitudes[idx] = normalize(lon + dpsi_deg);
             }
@@ -1757,6 +1774,7 @@ pub fn all_upagrahas_for_date_with_config(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn all_upagrahas_for_date_with_ctx(
     engine: &Engine,
     eop: &EopKernel,
@@ -2368,6 +2386,88 @@ pub fn charakaraka_for_date(
     Ok(charakarakas_from_longitudes(&lons.longitudes, scheme))
 }
 
+/// Compute a Krishnamurti Paddhati chart for a given date and location.
+///
+/// Cusps come from `bhava_config`; pass `BhavaSystem::KP` (Placidus) with the
+/// KP ayanamsha for a classical KP chart. The day lord for the ruling
+/// planets follows the sunrise-to-sunrise vaar.
+#[allow(clippy::too_many_arguments)]
+pub fn kp_chart_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    bhava_config: &BhavaConfig,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+) -> Result<KpChart, SearchError> {
+    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    let graha_lons = ctx.graha_lons(engine, aya_config)?.longitudes;
+    let cusps = ctx.sidereal_bhava_cusps(engine, eop, location, bhava_config)?;
+    let lagna_sid = ctx.lagna_sid(engine, eop, location)?;
+    let (jd_sunrise, _) = ctx.sunrise_pair(engine, eop, utc, location, riseset_config)?;
+    let day_lord = graha_vaar_lord(vaar_from_jd(jd_sunrise));
+
+    let grahas = ALL_GRAHAS.map(|graha| {
+        let lon = graha_lons[graha.index() as usize];
+        KpGrahaEntry {
+            graha,
+            sidereal_longitude: lon,
+            bhava_number: kp_bhava_of(lon, &cusps),
+            sub_number: kp_sub_number(lon),
+            lords: kp_lords(lon),
+        }
+    });
+    let cusp_entries = std::array::from_fn(|i| KpCuspEntry {
+        bhava_number: i as u8 + 1,
+        sidereal_longitude: cusps[i],
+        sub_number: kp_sub_number(cusps[i]),
+        lords: kp_lords(cusps[i]),
+    });
+
+    Ok(KpChart {
+        ayanamsha_deg: ctx.ayanamsha,
+        grahas,
+        cusps: cusp_entries,
+        significators: kp_significators(&graha_lons, &cusps),
+        ruling_planets: kp_ruling_planets(day_lord, lagna_sid, &graha_lons),
+    })
+}
+
+/// Compute Ashtakoota and Dashakoota compatibility for two birth moments.
+///
+/// Only the sidereal Moon of each chart is used, so no birth location is
+/// needed. Use [`kundali_milan`] directly when the Moon longitudes are known.
+pub fn kundali_milan_for_dates(
+    engine: &Engine,
+    eop: &EopKernel,
+    groom_utc: &UtcTime,
+    bride_utc: &UtcTime,
+    aya_config: &SankrantiConfig,
+) -> Result<MilanResult, SearchError> {
+    let moon = Graha::Chandra.index() as usize;
+    let mut groom_ctx = JyotishContext::new(engine, Some(eop), groom_utc, aya_config);
+    let groom_moon = groom_ctx.graha_lons(engine, aya_config)?.longitudes[moon];
+    let mut bride_ctx = JyotishContext::new(engine, Some(eop), bride_utc, aya_config);
+    let bride_moon = bride_ctx.graha_lons(engine, aya_config)?.longitudes[moon];
+    Ok(kundali_milan(groom_moon, bride_moon))
+}
+
+/// Detect Mangal, Kaal Sarpa, Pitru and Gandanta doshas for a given date and location.
+pub fn doshas_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    aya_config: &SankrantiConfig,
+    config: &DoshaConfig,
+) -> Result<DoshaResult, SearchError> {
+    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    let graha_lons = ctx.graha_lons(engine, aya_config)?.longitudes;
+    let lagna_sid = ctx.lagna_sid(engine, eop, location)?;
+    Ok(dosha_analysis(lagna_sid, &graha_lons, config))
+}
+
 /// Compute a full kundali in one shot, sharing intermediates across sections.
 #[allow(clippy::too_many_arguments)]
 pub fn full_kundali_for_date(
@@ -3079,6 +3179,7 @@ fn normalize(deg: f64) -> f64 {
 // ---------------------------------------------------------------------------
 
 /// Compute Shadbala for all 7 sapta grahas at a given date and location.
+#[allow(clippy::too_many_arguments)]
 pub fn shadbala_for_date(
     engine: &Engine,
     eop: &EopKernel,
@@ -3349,6 +3450,7 @@ fn vimsopaka_entry_from_cache(
 }
 
 /// Compute Vimsopaka Bala for a single graha. Accepts all 9 navagrahas.
+#[allow(clippy::too_many_arguments)]
 pub fn vimsopaka_for_graha(
     engine: &Engine,
     eop: &EopKernel,
@@ -3407,7 +3509,8 @@ fn assemble_shadbala_inputs(
         aya_config.use_nutation,
         aya_config.precession_model,
         aya_config.reference_plane,
-    );
+    )
+    .with_nutation_model(aya_config.nutation_model);
     let mut cheshta_madhyama_lons = [0.0f64; 7];
     let mut cheshta_chaloccha_lons = [0.0f64; 7];
     for graha in [
@@ -3587,6 +3690,7 @@ fn classify_bhavabala_birth_period(
 /// Compute varga longitudes and rashi indices for the 7 saptavargaja vargas × 7 sapta grahas.
 ///
 /// The 7 vargas are: D1, D2, D3, D7, D9, D12, D30.
+#[allow(clippy::type_complexity)]
 fn assemble_varga_data(
     engine: &Engine,
     aya_config: &SankrantiConfig,
@@ -4026,7 +4130,7 @@ fn unique_amsha_requests_for_compute(requests: &[AmshaRequest]) -> (Vec<AmshaReq
 }
 
 /// Build an AmshaChart for one amsha request given pre-computed D1 longitudes.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::needless_range_loop)]
 fn build_amsha_chart(
     req: &AmshaRequest,
     graha_lons: &[f64; 9],
@@ -4195,7 +4299,8 @@ pub fn amsha_charts_for_date(
             aya_config.use_nutation,
             aya_config.precession_model,
             aya_config.reference_plane,
-        );
+        )
+        .with_nutation_model(aya_config.nutation_model);
         Some(outer_planet_longitudes_for_config(
             engine, ctx.jd_tdb, &config,
         )?)
@@ -4333,6 +4438,47 @@ pub fn amsha_charts_for_date(
     Ok(AmshaResult { charts })
 }
 
+/// Pure-math transform: detect graha yogas in a pre-computed kundali.
+///
+/// Requires graha_positions (with lagna) to be present. Pass
+/// `CLASSICAL_YOGA_RULES` or an extended rule table.
+pub fn yogas_from_kundali(
+    kundali: &FullKundaliResult,
+    rules: &[YogaRule],
+) -> Result<Vec<DetectedYoga>, SearchError> {
+    let gp = kundali
+        .graha_positions
+        .as_ref()
+        .ok_or(SearchError::InvalidConfig(
+            "graha_positions required for yoga detection",
+        ))?;
+    let chart = YogaChart::new(
+        gp.lagna.sidereal_longitude,
+        gp.grahas.map(|g| g.sidereal_longitude),
+    );
+    Ok(detect_yogas(&chart, rules))
+}
+
+/// Pure-math transform: detect doshas in a pre-computed kundali.
+///
+/// Requires graha_positions (with lagna) to be present.
+pub fn doshas_from_kundali(
+    kundali: &FullKundaliResult,
+    config: &DoshaConfig,
+) -> Result<DoshaResult, SearchError> {
+    let gp = kundali
+        .graha_positions
+        .as_ref()
+        .ok_or(SearchError::InvalidConfig(
+            "graha_positions required for dosha detection",
+        ))?;
+    Ok(dosha_analysis(
+        gp.lagna.sidereal_longitude,
+        &gp.grahas.map(|g| g.sidereal_longitude),
+        config,
+    ))
+}
+
 /// Pure-math transform: compute amsha charts from pre-computed D1 kundali data.
 ///
 /// Requires graha_positions (with lagna) to be present.
@@ -4453,6 +4599,7 @@ pub fn amsha_charts_from_kundali(
 }
 
 /// Internal: compute amsha charts from within full_kundali_for_date.
+#[allow(clippy::too_many_arguments)]
 fn amsha_charts_from_kundali_with_ctx(
     requests: &[AmshaRequest],
     scope: &AmshaChartScope,
@@ -4466,7 +4613,7 @@ fn amsha_charts_from_kundali_with_ctx(
     special_lagnas: &Option<AllSpecialLagnas>,
     ctx: &mut JyotishContext,
 ) -> Result<AmshaResult, SearchError> {
-    validate_amsha_requests(&requests)?;
+    validate_amsha_requests(requests)?;
     let (unique_requests, unique_positions) = unique_amsha_requests_for_compute(requests);
 
     let gp = graha_positions.as_ref().ok_or(SearchError::InvalidConfig(
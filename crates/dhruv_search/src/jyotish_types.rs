@@ -1,13 +1,18 @@
 //! Types for Vedic jyotish orchestration (graha longitudes, etc.).
 
 use crate::panchang_types::PanchangInfo;
-use dhruv_frames::{DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane};
+use dhruv_core::Correction;
+use dhruv_frames::{
+    DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel,
+    ReferencePlane,
+};
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
     AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AshtakavargaResult, AyanamshaSystem,
     BhavaBalaResult, BhavaResult, CharakarakaResult, CharakarakaScheme, Dms, DrishtiEntry, Graha,
-    GrahaDrishtiMatrix, KalaBalaBreakdown, Nakshatra, NodeDignityPolicy, Rashi, ShadbalaBreakdown,
-    SthanaBalaBreakdown, TimeUpagrahaConfig,
+    GrahaDrishtiMatrix, KalaBalaBreakdown, KpHouseSignificators, KpLords, KpRulingPlanets,
+    Nakshatra, NodeDignityPolicy, Rashi, ShadbalaBreakdown, SthanaBalaBreakdown,
+    TimeUpagrahaConfig,
 };
 
 /// Longitudes of all 9 grahas plus optional outer planets.
@@ -60,8 +65,12 @@ pub struct GrahaLongitudesConfig {
     pub ayanamsha_system: AyanamshaSystem,
     pub use_nutation: bool,
     pub precession_model: PrecessionModel,
+    /// Nutation model used when `use_nutation` is set (default IAU 2000B).
+    pub nutation_model: NutationModel,
     pub reference_plane: ReferencePlane,
     pub include_outer_planets: bool,
+    /// Observer-relative corrections for planetary positions (default geometric).
+    pub correction: Correction,
 }
 
 impl GrahaLongitudesConfig {
@@ -85,8 +94,10 @@ impl GrahaLongitudesConfig {
             ayanamsha_system,
             use_nutation,
             precession_model,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane,
             include_outer_planets: true,
+            correction: Correction::Geometric,
         }
     }
 
@@ -108,8 +119,10 @@ impl GrahaLongitudesConfig {
             ayanamsha_system: AyanamshaSystem::Lahiri,
             use_nutation,
             precession_model,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane,
             include_outer_planets: true,
+            correction: Correction::Geometric,
         }
     }
 
@@ -117,6 +130,20 @@ impl GrahaLongitudesConfig {
         self.include_outer_planets = include_outer_planets;
         self
     }
+
+    /// Select light-time/aberration/deflection corrections.
+    ///
+    /// [`Correction::Apparent`] gives apparent geocentric longitudes.
+    pub fn with_correction(mut self, correction: Correction) -> Self {
+        self.correction = correction;
+        self
+    }
+
+    /// Select the nutation model applied when `use_nutation` is set.
+    pub fn with_nutation_model(mut self, nutation_model: NutationModel) -> Self {
+        self.nutation_model = nutation_model;
+        self
+    }
 }
 
 impl Default for GrahaLongitudesConfig {
@@ -439,6 +466,50 @@ pub struct BalaBundleResult {
     pub bhavabala: BhavaBalaResult,
 }
 
+// ---------------------------------------------------------------------------
+// Krishnamurti Paddhati (KP) types
+// ---------------------------------------------------------------------------
+
+/// KP lords and house placement of one graha.
+#[derive(Debug, Clone, Copy)]
+pub struct KpGrahaEntry {
+    pub graha: Graha,
+    /// Sidereal longitude in degrees.
+    pub sidereal_longitude: f64,
+    /// 1-based KP house (cusp to next cusp).
+    pub bhava_number: u8,
+    /// 1-based division number in the 249-sub table.
+    pub sub_number: u16,
+    pub lords: KpLords,
+}
+
+/// KP lords of one house cusp.
+#[derive(Debug, Clone, Copy)]
+pub struct KpCuspEntry {
+    /// 1-based house number.
+    pub bhava_number: u8,
+    /// Sidereal cusp longitude in degrees.
+    pub sidereal_longitude: f64,
+    /// 1-based division number in the 249-sub table.
+    pub sub_number: u16,
+    pub lords: KpLords,
+}
+
+/// Assembled KP chart: graha and cusp lords, significators, ruling planets.
+#[derive(Debug, Clone)]
+pub struct KpChart {
+    /// Ayanamsha used, in degrees.
+    pub ayanamsha_deg: f64,
+    /// Entries indexed by `Graha::index()`.
+    pub grahas: [KpGrahaEntry; 9],
+    /// Entries for houses 1-12.
+    pub cusps: [KpCuspEntry; 12],
+    /// Four-level significators for houses 1-12.
+    pub significators: [KpHouseSignificators; 12],
+    /// Ruling planets at the query moment.
+    pub ruling_planets: KpRulingPlanets,
+}
+
 // ---------------------------------------------------------------------------
 // Dasha selection config (for FullKundaliConfig)
 // ---------------------------------------------------------------------------
@@ -5,9 +5,12 @@
 //! - General-purpose conjunction/separation engine for any body pair
 //! - Chandra grahan (lunar eclipse) computation (penumbral, partial, total)
 //! - Surya grahan (solar eclipse) computation (geocentric and topocentric)
+//! - Besselian elements and surya grahan ground paths
 //! - Stationary point search (retrograde/direct stations)
 //! - Max-speed search (velocity extrema)
 
+pub mod besselian;
+pub mod besselian_types;
 pub mod conjunction;
 pub mod conjunction_types;
 pub mod dasha;
@@ -27,9 +30,14 @@ pub(crate) mod search_util;
 pub mod stationary;
 pub mod stationary_types;
 
+pub use besselian::{besselian_elements, surya_grahan_path};
+pub use besselian_types::{
+    BesselianElements, SuryaGrahanPath, SuryaGrahanPathConfig, SuryaGrahanPathLine,
+    SuryaGrahanPathPoint,
+};
 pub use conjunction::{
-    body_ecliptic_lon_lat, body_lon_lat_on_plane, next_conjunction, prev_conjunction,
-    search_conjunctions,
+    body_ecliptic_lon_lat, body_lon_lat_on_plane, body_lon_lat_on_plane_with_correction,
+    next_conjunction, prev_conjunction, search_conjunctions,
 };
 pub use conjunction_types::{ConjunctionConfig, ConjunctionEvent, SearchDirection};
 pub use dasha::{
@@ -40,35 +48,43 @@ pub use dasha::{
     dasha_level0_with_inputs, dasha_snapshot_at, dasha_snapshot_with_inputs,
 };
 pub use dhruv_vedic_base::{
-    BhavaBalaBirthPeriod, BhavaBalaEntry, BhavaBalaInputs, BhavaBalaResult, CharakarakaEntry,
-    CharakarakaResult, CharakarakaRole, CharakarakaScheme,
+    AshtakootaResult, BhavaBalaBirthPeriod, BhavaBalaEntry, BhavaBalaInputs, BhavaBalaResult,
+    CLASSICAL_YOGA_RULES, CharakarakaEntry, CharakarakaResult, CharakarakaRole, CharakarakaScheme,
+    DashakootaResult, DetectedYoga, DoshaConfig, DoshaResult, Koota, KootaScore,
+    KpHouseSignificators, KpLords, KpRulingPlanets, MilanCancellation, MilanResult, Porutham,
+    PoruthamResult, YogaCategory, YogaChart, YogaHit, YogaRule,
 };
 pub use error::SearchError;
 pub use grahan::{
-    next_chandra_grahan, next_surya_grahan, prev_chandra_grahan, prev_surya_grahan,
-    search_chandra_grahan, search_surya_grahan,
+    chandra_grahan_local_circumstances, chandra_grahan_visibility_grid, next_chandra_grahan,
+    next_surya_grahan, prev_chandra_grahan, prev_surya_grahan, search_chandra_grahan,
+    search_surya_grahan, surya_grahan_local_circumstances,
 };
 pub use grahan_types::{
-    ChandraGrahan, ChandraGrahanType, GeoLocation, GrahanConfig, SuryaGrahan, SuryaGrahanType,
+    ChandraGrahan, ChandraGrahanLocal, ChandraGrahanLocalContact, ChandraGrahanType,
+    ChandraGrahanVisibilityGrid, GeoLocation, GrahanConfig, GrahanVisibility, GrahanVisibilityCell,
+    GrahanVisibleWindow, SuryaGrahan, SuryaGrahanLocal, SuryaGrahanLocalContact, SuryaGrahanType,
 };
 pub use jyotish::{
     all_upagrahas_for_date, all_upagrahas_for_date_with_config, amsha_charts_for_date,
     amsha_charts_from_kundali, arudha_padas_for_date, ashtakavarga_for_date, avastha_for_date,
     avastha_for_graha, balas_for_date, bhavabala_for_bhava, bhavabala_for_date,
-    charakaraka_for_date, core_bindus, drishti_for_date, full_kundali_for_date, graha_longitudes,
-    graha_positions, moving_osculating_apogees, moving_osculating_apogees_for_date,
+    charakaraka_for_date, core_bindus, doshas_for_date, doshas_from_kundali, drishti_for_date,
+    full_kundali_for_date, graha_longitudes, graha_positions, kp_chart_for_date,
+    kundali_milan_for_dates, moving_osculating_apogees, moving_osculating_apogees_for_date,
     outer_planet_longitudes, shadbala_for_date, shadbala_for_graha,
     sidereal_bhava_results_for_date, sidereal_bhavas_for_date, sidereal_lagna_for_date,
     sidereal_mc_for_date, siderealize_bhava_result, special_lagnas_for_date,
-    tropical_to_sidereal_longitude, vimsopaka_for_date, vimsopaka_for_graha,
+    tropical_to_sidereal_longitude, vimsopaka_for_date, vimsopaka_for_graha, yogas_from_kundali,
 };
 pub use jyotish_types::{
     AmshaChart, AmshaChartScope, AmshaEntry, AmshaResult, AmshaSelectionConfig, BalaBundleResult,
     BhavaResultSet, BindusConfig, BindusResult, DashaSelectionConfig, DashaSnapshotTime,
     DrishtiConfig, DrishtiResult, FullKundaliConfig, FullKundaliResult, GrahaEntry,
     GrahaLongitudeKind, GrahaLongitudes, GrahaLongitudesConfig, GrahaPositions,
-    GrahaPositionsConfig, MAX_AMSHA_REQUESTS, MovingOsculatingApogeeEntry, MovingOsculatingApogees,
-    ShadbalaEntry, ShadbalaResult, SphutalResult, VimsopakaEntry, VimsopakaResult,
+    GrahaPositionsConfig, KpChart, KpCuspEntry, KpGrahaEntry, MAX_AMSHA_REQUESTS,
+    MovingOsculatingApogeeEntry, MovingOsculatingApogees, ShadbalaEntry, ShadbalaResult,
+    SphutalResult, VimsopakaEntry, VimsopakaResult,
 };
 pub use lunar_phase::{
     next_amavasya, next_purnima, prev_amavasya, prev_purnima, search_amavasyas, search_purnimas,
@@ -90,19 +106,21 @@ pub use operations::{
 pub use panchang::{
     ayana_for_date, elongation_at, ghatika_for_date, ghatika_from_sunrises, hora_for_date,
     hora_from_sunrises, karana_at, karana_for_date, masa_for_date, moon_sidereal_longitude_at,
-    nakshatra_at, nakshatra_for_date, panchang_for_date, sidereal_sum_at, tithi_at, tithi_for_date,
-    vaar_for_date, vaar_from_sunrises, varsha_for_date, vedic_day_sunrises, yoga_at, yoga_for_date,
+    muhurta_for_date, muhurta_from_sunrises, nakshatra_at, nakshatra_for_date, panchang_for_date,
+    sidereal_sum_at, tithi_at, tithi_for_date, vaar_for_date, vaar_from_sunrises, varsha_for_date,
+    vedic_day_sunrises, yoga_at, yoga_for_date,
 };
 pub use panchang_types::{
-    AyanaInfo, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, PanchangInfo, PanchangNakshatraInfo,
-    TithiInfo, VaarInfo, VarshaInfo, YogaInfo,
+    AyanaInfo, ChoghadiyaSlot, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, MuhurtaInfo,
+    MuhurtaSlot, MuhurtaWindow, PanchangInfo, PanchangNakshatraInfo, TithiInfo, VaarInfo,
+    VarshaInfo, YogaInfo,
 };
 pub use sankranti::{
     next_sankranti, next_specific_sankranti, prev_sankranti, prev_specific_sankranti,
     search_sankrantis,
 };
 pub use sankranti_types::{SankrantiConfig, SankrantiEvent};
-pub use search_util::{set_time_conversion_policy, time_conversion_policy};
+pub use search_util::{SearchInstant, set_time_conversion_policy, time_conversion_policy};
 pub use stationary::{
     next_max_speed, next_stationary, prev_max_speed, prev_stationary, search_max_speed,
     search_stationary,
@@ -3,7 +3,8 @@
 //! Thin wrappers around the conjunction engine. Purnima is Sun-Moon opposition
 //! (180 deg), Amavasya is Sun-Moon conjunction (0 deg).
 //!
-//! All public functions accept and return UTC times; JD TDB is internal only.
+//! Public functions take any [`SearchInstant`] (usually a UTC time) and
+//! return UTC times alongside full-precision TDB epochs.
 
 use dhruv_core::{Body, Engine};
 use dhruv_time::UtcTime;
@@ -12,6 +13,7 @@ use crate::conjunction::{next_conjunction, prev_conjunction, search_conjunctions
 use crate::conjunction_types::ConjunctionConfig;
 use crate::error::SearchError;
 use crate::lunar_phase_types::{LunarPhase, LunarPhaseEvent};
+use crate::search_util::SearchInstant;
 
 /// Step size for lunar phase search (days).
 /// Half a day gives reliable detection of the ~29.53-day synodic cycle.
@@ -32,7 +34,8 @@ fn conjunction_to_phase(
     lsk: &dhruv_time::LeapSecondKernel,
 ) -> LunarPhaseEvent {
     LunarPhaseEvent {
-        utc: UtcTime::from_jd_tdb(event.jd_tdb, lsk),
+        utc: UtcTime::from_epoch(event.epoch, lsk),
+        epoch: event.epoch,
         phase,
         // body1=Sun, body2=Moon
         sun_longitude_deg: event.body1_longitude_deg,
@@ -40,76 +43,72 @@ fn conjunction_to_phase(
     }
 }
 
-/// Find the next Purnima (full moon) after the given UTC time.
+/// Find the next Purnima (full moon) after `at`.
 pub fn next_purnima(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
 ) -> Result<Option<LunarPhaseEvent>, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let config = make_config(180.0);
-    let result = next_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let result = next_conjunction(engine, Body::Sun, Body::Moon, start, &config)?;
     Ok(result.map(|e| conjunction_to_phase(&e, LunarPhase::FullMoon, engine.lsk())))
 }
 
-/// Find the previous Purnima (full moon) before the given UTC time.
+/// Find the previous Purnima (full moon) before `at`.
 pub fn prev_purnima(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
 ) -> Result<Option<LunarPhaseEvent>, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let config = make_config(180.0);
-    let result = prev_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let result = prev_conjunction(engine, Body::Sun, Body::Moon, start, &config)?;
     Ok(result.map(|e| conjunction_to_phase(&e, LunarPhase::FullMoon, engine.lsk())))
 }
 
-/// Find the next Amavasya (new moon) after the given UTC time.
+/// Find the next Amavasya (new moon) after `at`.
 pub fn next_amavasya(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
 ) -> Result<Option<LunarPhaseEvent>, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let config = make_config(0.0);
-    let result = next_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let result = next_conjunction(engine, Body::Sun, Body::Moon, start, &config)?;
     Ok(result.map(|e| conjunction_to_phase(&e, LunarPhase::NewMoon, engine.lsk())))
 }
 
-/// Find the previous Amavasya (new moon) before the given UTC time.
+/// Find the previous Amavasya (new moon) before `at`.
 pub fn prev_amavasya(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
 ) -> Result<Option<LunarPhaseEvent>, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let config = make_config(0.0);
-    let result = prev_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let result = prev_conjunction(engine, Body::Sun, Body::Moon, start, &config)?;
     Ok(result.map(|e| conjunction_to_phase(&e, LunarPhase::NewMoon, engine.lsk())))
 }
 
-/// Search for all Purnimas (full moons) in a UTC time range.
+/// Search for all Purnimas (full moons) between `start` and `end`.
 pub fn search_purnimas(
     engine: &Engine,
-    start: &UtcTime,
-    end: &UtcTime,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
 ) -> Result<Vec<LunarPhaseEvent>, SearchError> {
-    let jd_start = crate::search_util::utc_to_jd_tdb(engine, &start);
-    let jd_end = crate::search_util::utc_to_jd_tdb(engine, &end);
     let config = make_config(180.0);
-    let events = search_conjunctions(engine, Body::Sun, Body::Moon, jd_start, jd_end, &config)?;
+    let events = search_conjunctions(engine, Body::Sun, Body::Moon, start, end, &config)?;
     Ok(events
         .iter()
         .map(|e| conjunction_to_phase(e, LunarPhase::FullMoon, engine.lsk()))
         .collect())
 }
 
-/// Search for all Amavasyas (new moons) in a UTC time range.
+/// Search for all Amavasyas (new moons) between `start` and `end`.
 pub fn search_amavasyas(
     engine: &Engine,
-    start: &UtcTime,
-    end: &UtcTime,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
 ) -> Result<Vec<LunarPhaseEvent>, SearchError> {
-    let jd_start = crate::search_util::utc_to_jd_tdb(engine, &start);
-    let jd_end = crate::search_util::utc_to_jd_tdb(engine, &end);
     let config = make_config(0.0);
-    let events = search_conjunctions(engine, Body::Sun, Body::Moon, jd_start, jd_end, &config)?;
+    let events = search_conjunctions(engine, Body::Sun, Body::Moon, start, end, &config)?;
     Ok(events
         .iter()
         .map(|e| conjunction_to_phase(e, LunarPhase::NewMoon, engine.lsk()))
@@ -1,6 +1,6 @@
 //! Types for lunar phase search results.
 
-use dhruv_time::UtcTime;
+use dhruv_time::{Epoch, UtcTime};
 
 /// Lunar phase type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -25,6 +25,8 @@ impl LunarPhase {
 pub struct LunarPhaseEvent {
     /// UTC time of the event.
     pub utc: UtcTime,
+    /// Event time as a full-precision TDB epoch.
+    pub epoch: Epoch,
     /// Phase type (new moon or full moon).
     pub phase: LunarPhase,
     /// Tropical ecliptic longitude of the Moon at the event (degrees).
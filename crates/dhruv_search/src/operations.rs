@@ -4,16 +4,16 @@
 //! (`next_*`, `prev_*`, `search_*`) to config-driven operation requests.
 
 use dhruv_core::{Body, Engine};
-use dhruv_frames::SphericalCoords;
+use dhruv_frames::{DEFAULT_PRECESSION_MODEL, NutationModel, SphericalCoords};
 use dhruv_tara::{
     EarthState, EquatorialPosition, TaraCatalog, TaraConfig, TaraError, TaraId,
     position_ecliptic_with_config, position_equatorial_with_config, sidereal_longitude_with_config,
 };
 use dhruv_time::{EopKernel, UtcTime};
 use dhruv_vedic_base::{
-    AyanamshaSystem, GeoLocation, LunarNode, NodeMode, Rashi, RiseSetConfig, ayanamsha_deg,
-    ayanamsha_mean_deg, ayanamsha_true_deg, jd_tdb_to_centuries, lunar_node_deg,
-    lunar_node_deg_for_epoch,
+    AyanamshaSystem, GeoLocation, LunarNode, NodeMode, Rashi, RiseSetConfig,
+    ayanamsha_deg_with_models, ayanamsha_mean_deg, ayanamsha_true_deg, jd_tdb_to_centuries,
+    lunar_node_deg, lunar_node_deg_for_epoch,
 };
 
 use crate::conjunction_types::{ConjunctionConfig, ConjunctionEvent};
@@ -377,6 +377,8 @@ pub struct AyanamshaOperation {
     pub at_jd_tdb: f64,
     /// Nutation inclusion flag used by `Unified` mode.
     pub use_nutation: bool,
+    /// Nutation model used by `Unified` mode when `use_nutation` is set.
+    pub nutation_model: NutationModel,
     /// Delta-psi arcseconds used by `True` mode.
     pub delta_psi_arcsec: f64,
 }
@@ -387,7 +389,13 @@ pub fn ayanamsha(op: &AyanamshaOperation) -> Result<f64, SearchError> {
     let deg = match op.mode {
         AyanamshaMode::Mean => ayanamsha_mean_deg(op.system, t),
         AyanamshaMode::True => ayanamsha_true_deg(op.system, t, op.delta_psi_arcsec),
-        AyanamshaMode::Unified => ayanamsha_deg(op.system, t, op.use_nutation),
+        AyanamshaMode::Unified => ayanamsha_deg_with_models(
+            op.system,
+            t,
+            op.use_nutation,
+            DEFAULT_PRECESSION_MODEL,
+            op.nutation_model,
+        ),
     };
     Ok(deg)
 }
@@ -490,10 +498,10 @@ pub fn lunar_phase(
 ) -> Result<LunarPhaseResult, SearchError> {
     match (op.kind, op.query) {
         (LunarPhaseKind::Amavasya, LunarPhaseQuery::Next { at_jd_tdb }) => Ok(
-            LunarPhaseResult::Single(next_amavasya(engine, &jd_tdb_to_utc(engine, at_jd_tdb))?),
+            LunarPhaseResult::Single(next_amavasya(engine, jd_tdb_to_utc(engine, at_jd_tdb))?),
         ),
         (LunarPhaseKind::Amavasya, LunarPhaseQuery::Prev { at_jd_tdb }) => Ok(
-            LunarPhaseResult::Single(prev_amavasya(engine, &jd_tdb_to_utc(engine, at_jd_tdb))?),
+            LunarPhaseResult::Single(prev_amavasya(engine, jd_tdb_to_utc(engine, at_jd_tdb))?),
         ),
         (
             LunarPhaseKind::Amavasya,
@@ -509,15 +517,15 @@ pub fn lunar_phase(
             }
             Ok(LunarPhaseResult::Many(search_amavasyas(
                 engine,
-                &jd_tdb_to_utc(engine, start_jd_tdb),
-                &jd_tdb_to_utc(engine, end_jd_tdb),
+                jd_tdb_to_utc(engine, start_jd_tdb),
+                jd_tdb_to_utc(engine, end_jd_tdb),
             )?))
         }
         (LunarPhaseKind::Purnima, LunarPhaseQuery::Next { at_jd_tdb }) => Ok(
-            LunarPhaseResult::Single(next_purnima(engine, &jd_tdb_to_utc(engine, at_jd_tdb))?),
+            LunarPhaseResult::Single(next_purnima(engine, jd_tdb_to_utc(engine, at_jd_tdb))?),
         ),
         (LunarPhaseKind::Purnima, LunarPhaseQuery::Prev { at_jd_tdb }) => Ok(
-            LunarPhaseResult::Single(prev_purnima(engine, &jd_tdb_to_utc(engine, at_jd_tdb))?),
+            LunarPhaseResult::Single(prev_purnima(engine, jd_tdb_to_utc(engine, at_jd_tdb))?),
         ),
         (
             LunarPhaseKind::Purnima,
@@ -533,8 +541,8 @@ pub fn lunar_phase(
             }
             Ok(LunarPhaseResult::Many(search_purnimas(
                 engine,
-                &jd_tdb_to_utc(engine, start_jd_tdb),
-                &jd_tdb_to_utc(engine, end_jd_tdb),
+                jd_tdb_to_utc(engine, start_jd_tdb),
+                jd_tdb_to_utc(engine, end_jd_tdb),
             )?))
         }
     }
@@ -595,10 +603,10 @@ pub enum SankrantiResult {
 pub fn sankranti(engine: &Engine, op: &SankrantiOperation) -> Result<SankrantiResult, SearchError> {
     match (op.target, op.query) {
         (SankrantiTarget::Any, SankrantiQuery::Next { at_jd_tdb }) => Ok(SankrantiResult::Single(
-            next_sankranti(engine, &jd_tdb_to_utc(engine, at_jd_tdb), &op.config)?,
+            next_sankranti(engine, jd_tdb_to_utc(engine, at_jd_tdb), &op.config)?,
         )),
         (SankrantiTarget::Any, SankrantiQuery::Prev { at_jd_tdb }) => Ok(SankrantiResult::Single(
-            prev_sankranti(engine, &jd_tdb_to_utc(engine, at_jd_tdb), &op.config)?,
+            prev_sankranti(engine, jd_tdb_to_utc(engine, at_jd_tdb), &op.config)?,
         )),
         (
             SankrantiTarget::Any,
@@ -614,15 +622,15 @@ pub fn sankranti(engine: &Engine, op: &SankrantiOperation) -> Result<SankrantiRe
             }
             Ok(SankrantiResult::Many(search_sankrantis(
                 engine,
-                &jd_tdb_to_utc(engine, start_jd_tdb),
-                &jd_tdb_to_utc(engine, end_jd_tdb),
+                jd_tdb_to_utc(engine, start_jd_tdb),
+                jd_tdb_to_utc(engine, end_jd_tdb),
                 &op.config,
             )?))
         }
         (SankrantiTarget::SpecificRashi(rashi), SankrantiQuery::Next { at_jd_tdb }) => {
             Ok(SankrantiResult::Single(next_specific_sankranti(
                 engine,
-                &jd_tdb_to_utc(engine, at_jd_tdb),
+                jd_tdb_to_utc(engine, at_jd_tdb),
                 rashi,
                 &op.config,
             )?))
@@ -630,7 +638,7 @@ pub fn sankranti(engine: &Engine, op: &SankrantiOperation) -> Result<SankrantiRe
         (SankrantiTarget::SpecificRashi(rashi), SankrantiQuery::Prev { at_jd_tdb }) => {
             Ok(SankrantiResult::Single(prev_specific_sankranti(
                 engine,
-                &jd_tdb_to_utc(engine, at_jd_tdb),
+                jd_tdb_to_utc(engine, at_jd_tdb),
                 rashi,
                 &op.config,
             )?))
@@ -649,8 +657,8 @@ pub fn sankranti(engine: &Engine, op: &SankrantiOperation) -> Result<SankrantiRe
             }
             let all = search_sankrantis(
                 engine,
-                &jd_tdb_to_utc(engine, start_jd_tdb),
-                &jd_tdb_to_utc(engine, end_jd_tdb),
+                jd_tdb_to_utc(engine, start_jd_tdb),
+                jd_tdb_to_utc(engine, end_jd_tdb),
                 &op.config,
             )?;
             let filtered = all.into_iter().filter(|ev| ev.rashi == rashi).collect();
@@ -924,6 +932,7 @@ pub fn tara(catalog: &TaraCatalog, op: &TaraOperation) -> Result<TaraResult, Tar
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dhruv_frames::DEFAULT_NUTATION_MODEL;
 
     #[test]
     fn conjunction_query_mode_is_stable() {
@@ -1020,6 +1029,7 @@ mod tests {
             mode: AyanamshaMode::Mean,
             at_jd_tdb: 2_451_545.0,
             use_nutation: false,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             delta_psi_arcsec: 0.0,
         };
         assert!(ayanamsha(&op).is_ok());
@@ -1,5 +1,6 @@
 //! Panchang classification: Tithi, Karana, Yoga, Nakshatra, Vaar, Hora,
-//! Ghatika, Masa, Ayana, and Varsha determination.
+//! Ghatika, Masa, Ayana, and Varsha determination, plus daily muhurta
+//! periods (Rahu Kalam, Abhijit, Choghadiya, ...).
 //!
 //! Given a UTC date, these functions determine the panchang elements.
 //! All functions accept and return UTC times; JD TDB is internal only.
@@ -9,20 +10,23 @@
 use dhruv_core::{Body, Engine};
 use dhruv_time::{EopKernel, LeapSecondKernel, UtcTime, calendar_to_jd};
 use dhruv_vedic_base::{
-    Ayana, GeoLocation, HORA_COUNT, KARANA_SEGMENT_DEG, NAKSHATRA_SPAN_27, Rashi, RiseSetConfig,
-    RiseSetEvent, RiseSetResult, TITHI_SEGMENT_DEG, YOGA_SEGMENT_DEG, approximate_local_noon_jd,
-    ayana_from_sidereal_longitude, compute_rise_set, ghatika_from_elapsed, hora_at,
-    jd_tdb_to_centuries, karana_from_elongation, masa_from_rashi_index, nakshatra_from_longitude,
-    rashi_from_longitude, samvatsara_from_year, tithi_from_elongation, utc_day_start_jd,
-    vaar_from_jd, yoga_from_sum,
+    ABHIJIT_MUHURTA_INDEX, Ayana, BRAHMA_MUHURTA_INDEX, CHOGHADIYA_COUNT, DAY_MUHURTA_NAMES,
+    GeoLocation, HORA_COUNT, KALAM_SEGMENT_COUNT, KARANA_SEGMENT_DEG, MUHURTA_COUNT,
+    NAKSHATRA_SPAN_27, NIGHT_MUHURTA_NAMES, Rashi, RiseSetConfig, RiseSetEvent, RiseSetResult,
+    TITHI_SEGMENT_DEG, YOGA_SEGMENT_DEG, approximate_local_noon_jd, ayana_from_sidereal_longitude,
+    choghadiya_at, compute_rise_set, ghatika_from_elapsed, gulika_kalam_segment, hora_at,
+    is_durmuhurta, jd_tdb_to_centuries, karana_from_elongation, masa_from_rashi_index,
+    nakshatra_from_longitude, rahu_kalam_segment, rashi_from_longitude, samvatsara_from_year,
+    tithi_from_elongation, utc_day_start_jd, vaar_from_jd, yamaganda_segment, yoga_from_sum,
 };
 
 use crate::conjunction::{body_ecliptic_lon_lat, body_lon_lat_on_plane};
 use crate::error::SearchError;
 use crate::lunar_phase::{next_amavasya, prev_amavasya};
 use crate::panchang_types::{
-    AyanaInfo, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, PanchangInfo, PanchangNakshatraInfo,
-    TithiInfo, VaarInfo, VarshaInfo, YogaInfo,
+    AyanaInfo, ChoghadiyaSlot, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, MuhurtaInfo,
+    MuhurtaSlot, MuhurtaWindow, PanchangInfo, PanchangNakshatraInfo, TithiInfo, VaarInfo,
+    VarshaInfo, YogaInfo,
 };
 use crate::sankranti::{next_specific_sankranti, prev_specific_sankranti};
 use crate::sankranti_types::SankrantiConfig;
@@ -232,11 +236,11 @@ fn find_chaitra_pratipada_for(
 ) -> Result<UtcTime, SearchError> {
     // Find Mesha Sankranti (Sun entering sidereal 0 deg) near this date
     let search_start = UtcTime::new(utc.year, 1, 15, 0, 0, 0.0);
-    let mesha_sankranti = next_specific_sankranti(engine, &search_start, Rashi::Mesha, config)?
+    let mesha_sankranti = next_specific_sankranti(engine, search_start, Rashi::Mesha, config)?
         .ok_or(SearchError::NoConvergence("could not find Mesha Sankranti"))?;
 
     // Find the next new moon after Mesha Sankranti
-    let nm = next_amavasya(engine, &mesha_sankranti.utc)?.ok_or(SearchError::NoConvergence(
+    let nm = next_amavasya(engine, mesha_sankranti.utc)?.ok_or(SearchError::NoConvergence(
         "could not find new moon after Mesha Sankranti",
     ))?;
 
@@ -317,7 +321,7 @@ pub fn nakshatra_for_date(
     utc: &UtcTime,
     config: &SankrantiConfig,
 ) -> Result<PanchangNakshatraInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let moon_sid = moon_sidereal_longitude_at(engine, jd, config)?;
     nakshatra_at(engine, jd, moon_sid, config)
 }
@@ -388,7 +392,7 @@ fn utc_to_jd_utc(utc: &UtcTime) -> f64 {
 /// Tithi = which of 30 segments of Moon-Sun elongation (12 deg each) the
 /// current moment falls in. Returns tithi with start/end UTC times.
 pub fn tithi_for_date(engine: &Engine, utc: &UtcTime) -> Result<TithiInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let elong = elongation_at(engine, jd)?;
     tithi_at(engine, jd, elong)
 }
@@ -431,7 +435,7 @@ pub fn tithi_at(
 /// Karana = which of 60 segments of Moon-Sun elongation (6 deg each) the
 /// current moment falls in. Uses traditional fixed/movable mapping.
 pub fn karana_for_date(engine: &Engine, utc: &UtcTime) -> Result<KaranaInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let elong = elongation_at(engine, jd)?;
     karana_at(engine, jd, elong)
 }
@@ -475,7 +479,7 @@ pub fn yoga_for_date(
     utc: &UtcTime,
     config: &SankrantiConfig,
 ) -> Result<YogaInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let sum = sidereal_sum_at(engine, jd, config)?;
     yoga_at(engine, jd, sum, config)
 }
@@ -732,6 +736,149 @@ pub fn ghatika_from_sunrises(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Daily muhurta periods
+// ---------------------------------------------------------------------------
+
+/// Determine the daily muhurta periods for a given date and location.
+///
+/// Covers the Vedic day containing `utc`: day periods divide sunrise to
+/// sunset, night periods divide sunset to the next sunrise.
+pub fn muhurta_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+) -> Result<MuhurtaInfo, SearchError> {
+    let (sunrise_jd, next_sunrise_jd) =
+        vedic_day_sunrises(engine, eop, utc, location, riseset_config)?;
+
+    // Approximate local noon (UTC) for the civil day on which the vedic day
+    // starts; the local date is taken at local mean time so far-east
+    // longitudes don't fall back a day.
+    let sunrise_jd_utc = utc_to_jd_utc(&UtcTime::from_jd_tdb(sunrise_jd, engine.lsk()));
+    let jd_midnight = utc_day_start_jd(sunrise_jd_utc + location.longitude_deg / 360.0);
+    let jd_noon = approximate_local_noon_jd(jd_midnight, location.longitude_deg);
+
+    let sunset_result = compute_rise_set(
+        engine,
+        engine.lsk(),
+        eop,
+        location,
+        RiseSetEvent::Sunset,
+        jd_noon,
+        riseset_config,
+    )
+    .map_err(|_| SearchError::NoConvergence("sunset computation failed"))?;
+    let sunset_jd = match sunset_result {
+        RiseSetResult::Event { jd_tdb, .. } if jd_tdb > sunrise_jd && jd_tdb < next_sunrise_jd => {
+            jd_tdb
+        }
+        _ => {
+            return Err(SearchError::NoConvergence(
+                "no sunset between consecutive sunrises",
+            ));
+        }
+    };
+
+    Ok(muhurta_from_sunrises(
+        sunrise_jd,
+        sunset_jd,
+        next_sunrise_jd,
+        engine.lsk(),
+    ))
+}
+
+/// Determine the daily muhurta periods from pre-computed sunrise/sunset.
+///
+/// Pure arithmetic — no engine queries needed. The `lsk` is used only
+/// for converting JD TDB to UTC in the start/end fields.
+pub fn muhurta_from_sunrises(
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    lsk: &LeapSecondKernel,
+) -> MuhurtaInfo {
+    let vaar = vaar_from_jd(sunrise_jd);
+    let day = sunset_jd - sunrise_jd;
+    let night = next_sunrise_jd - sunset_jd;
+    let utc = |jd: f64| UtcTime::from_jd_tdb(jd, lsk);
+    let window = |base: f64, span: f64, parts: u8, index: u8| {
+        let part = span / parts as f64;
+        let start = base + index as f64 * part;
+        MuhurtaWindow {
+            start: utc(start),
+            end: utc(start + part),
+        }
+    };
+
+    let muhurtas = std::array::from_fn(|i| {
+        let is_night = i >= MUHURTA_COUNT as usize;
+        let index = (i % MUHURTA_COUNT as usize) as u8;
+        let (base, span, names) = if is_night {
+            (sunset_jd, night, &NIGHT_MUHURTA_NAMES)
+        } else {
+            (sunrise_jd, day, &DAY_MUHURTA_NAMES)
+        };
+        let w = window(base, span, MUHURTA_COUNT, index);
+        MuhurtaSlot {
+            name: names[index as usize],
+            index,
+            is_night,
+            is_durmuhurta: is_durmuhurta(vaar, is_night, index),
+            start: w.start,
+            end: w.end,
+        }
+    });
+    let choghadiyas = std::array::from_fn(|i| {
+        let is_night = i >= CHOGHADIYA_COUNT as usize;
+        let slot = (i % CHOGHADIYA_COUNT as usize) as u8;
+        let (base, span) = if is_night {
+            (sunset_jd, night)
+        } else {
+            (sunrise_jd, day)
+        };
+        let w = window(base, span, CHOGHADIYA_COUNT, slot);
+        ChoghadiyaSlot {
+            choghadiya: choghadiya_at(vaar, is_night, slot),
+            slot,
+            is_night,
+            start: w.start,
+            end: w.end,
+        }
+    });
+
+    MuhurtaInfo {
+        vaar,
+        sunrise: utc(sunrise_jd),
+        sunset: utc(sunset_jd),
+        next_sunrise: utc(next_sunrise_jd),
+        rahu_kalam: window(
+            sunrise_jd,
+            day,
+            KALAM_SEGMENT_COUNT,
+            rahu_kalam_segment(vaar),
+        ),
+        yamaganda: window(
+            sunrise_jd,
+            day,
+            KALAM_SEGMENT_COUNT,
+            yamaganda_segment(vaar),
+        ),
+        gulika_kalam: window(
+            sunrise_jd,
+            day,
+            KALAM_SEGMENT_COUNT,
+            gulika_kalam_segment(vaar),
+        ),
+        abhijit: window(sunrise_jd, day, MUHURTA_COUNT, ABHIJIT_MUHURTA_INDEX),
+        brahma_muhurta: window(sunset_jd, night, MUHURTA_COUNT, BRAHMA_MUHURTA_INDEX),
+        muhurtas,
+        choghadiyas,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Combined panchang
 // ---------------------------------------------------------------------------
@@ -2,7 +2,7 @@
 
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
-    Ayana, Hora, Karana, Masa, Nakshatra, Paksha, Samvatsara, Tithi, Vaar, Yoga,
+    Ayana, Choghadiya, Hora, Karana, Masa, Nakshatra, Paksha, Samvatsara, Tithi, Vaar, Yoga,
 };
 
 /// Masa (lunar month) classification result.
@@ -120,6 +120,66 @@ pub struct GhatikaInfo {
     pub end: UtcTime,
 }
 
+/// A muhurta-table period as a UTC interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MuhurtaWindow {
+    /// Start of the period (UTC).
+    pub start: UtcTime,
+    /// End of the period (UTC).
+    pub end: UtcTime,
+}
+
+/// One of the 15 day or 15 night muhurtas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MuhurtaSlot {
+    /// Traditional name of the muhurta.
+    pub name: &'static str,
+    /// 0-based index within the day or the night (0-14).
+    pub index: u8,
+    /// Whether this muhurta falls between sunset and next sunrise.
+    pub is_night: bool,
+    /// Whether this muhurta is a Durmuhurta for the vaar.
+    pub is_durmuhurta: bool,
+    pub start: UtcTime,
+    pub end: UtcTime,
+}
+
+/// One of the 8 day or 8 night Choghadiya slots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChoghadiyaSlot {
+    pub choghadiya: Choghadiya,
+    /// 0-based slot within the day or the night (0-7).
+    pub slot: u8,
+    /// Whether this slot falls between sunset and next sunrise.
+    pub is_night: bool,
+    pub start: UtcTime,
+    pub end: UtcTime,
+}
+
+/// Daily muhurta periods for one Vedic day (sunrise to next sunrise).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MuhurtaInfo {
+    /// Vaar of the Vedic day, which selects every table below.
+    pub vaar: Vaar,
+    pub sunrise: UtcTime,
+    pub sunset: UtcTime,
+    pub next_sunrise: UtcTime,
+    /// Rahu Kalam (one eighth of the daytime).
+    pub rahu_kalam: MuhurtaWindow,
+    /// Yamaganda (one eighth of the daytime).
+    pub yamaganda: MuhurtaWindow,
+    /// Gulika Kalam (one eighth of the daytime).
+    pub gulika_kalam: MuhurtaWindow,
+    /// Abhijit muhurta (8th day muhurta, around local noon).
+    pub abhijit: MuhurtaWindow,
+    /// Brahma muhurta (14th night muhurta, before next sunrise).
+    pub brahma_muhurta: MuhurtaWindow,
+    /// The 15 day muhurtas followed by the 15 night muhurtas.
+    pub muhurtas: [MuhurtaSlot; 30],
+    /// The 8 day Choghadiyas followed by the 8 night Choghadiyas.
+    pub choghadiyas: [ChoghadiyaSlot; 16],
+}
+
 /// Moon's nakshatra classification result with start/end times.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PanchangNakshatraInfo {
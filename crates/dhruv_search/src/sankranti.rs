@@ -7,34 +7,34 @@
 //! Clean-room implementation from standard astronomical conventions.
 
 use dhruv_core::{Body, Engine};
-use dhruv_time::UtcTime;
+use dhruv_time::{Epoch, UtcTime};
 use dhruv_vedic_base::{ALL_RASHIS, Rashi, jd_tdb_to_centuries};
 
 use crate::conjunction::{body_ecliptic_lon_lat, body_lon_lat_on_plane};
 use crate::error::SearchError;
 use crate::sankranti_types::{SankrantiConfig, SankrantiEvent};
-use crate::search_util::{find_zero_crossing, normalize_to_pm180};
+use crate::search_util::{SearchInstant, find_zero_crossing_epoch, normalize_to_pm180};
 
 /// Maximum scan range in days (~400 days covers more than a full year).
 const MAX_SCAN_DAYS: f64 = 400.0;
 
-/// Get Sun's sidereal longitude at a given JD TDB.
+/// Get Sun's sidereal longitude at a given TDB epoch.
 ///
 /// Uses the reference plane configured in `config` for both the body longitude
 /// and the ayanamsha, ensuring frame consistency.
 fn sun_sidereal_longitude(
     engine: &Engine,
-    jd_tdb: f64,
+    epoch: Epoch,
     config: &SankrantiConfig,
 ) -> Result<f64, SearchError> {
     let (lon, _lat) = body_lon_lat_on_plane(
         engine,
         Body::Sun,
-        jd_tdb,
+        epoch,
         config.precession_model,
         config.reference_plane,
     )?;
-    let t = jd_tdb_to_centuries(jd_tdb);
+    let t = jd_tdb_to_centuries(epoch.as_jd_tdb());
     let aya = config.ayanamsha_deg_at_centuries(t);
     let sid = (lon - aya).rem_euclid(360.0);
     Ok(sid)
@@ -58,21 +58,21 @@ fn prev_boundary(sidereal_lon: f64) -> f64 {
 
 fn build_event(
     engine: &Engine,
-    jd_tdb: f64,
+    epoch: Epoch,
     boundary_deg: f64,
     config: &SankrantiConfig,
 ) -> Result<SankrantiEvent, SearchError> {
     // sun_tropical_longitude_deg is ALWAYS ecliptic tropical (existing semantics).
-    let (tropical_lon, _lat) = body_ecliptic_lon_lat(engine, Body::Sun, jd_tdb)?;
+    let (tropical_lon, _lat) = body_ecliptic_lon_lat(engine, Body::Sun, epoch)?;
     // Sidereal via reference plane (may be invariable for Jagganatha).
     let (lon_on_plane, _) = body_lon_lat_on_plane(
         engine,
         Body::Sun,
-        jd_tdb,
+        epoch,
         config.precession_model,
         config.reference_plane,
     )?;
-    let t = jd_tdb_to_centuries(jd_tdb);
+    let t = jd_tdb_to_centuries(epoch.as_jd_tdb());
     let aya = config.ayanamsha_deg_at_centuries(t);
     let sid = (lon_on_plane - aya).rem_euclid(360.0);
 
@@ -81,7 +81,8 @@ fn build_event(
     let rashi = ALL_RASHIS[rashi_index as usize];
 
     Ok(SankrantiEvent {
-        utc: UtcTime::from_jd_tdb(jd_tdb, engine.lsk()),
+        utc: UtcTime::from_epoch(epoch, engine.lsk()),
+        epoch,
         rashi,
         rashi_index,
         sun_sidereal_longitude_deg: sid,
@@ -89,32 +90,32 @@ fn build_event(
     })
 }
 
-/// Find the next Sankranti (Sun entering any rashi) after the given UTC time.
+/// Find the next Sankranti (Sun entering any rashi) after `at` (see [`SearchInstant`]).
 pub fn next_sankranti(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
     config: &SankrantiConfig,
 ) -> Result<Option<SankrantiEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
-    let sid_lon = sun_sidereal_longitude(engine, jd, config)?;
+    let start = at.to_epoch(engine);
+    let sid_lon = sun_sidereal_longitude(engine, start, config)?;
     let boundary = next_boundary(sid_lon);
 
     // Sun moves ~0.986 deg/day in sidereal
     let deg_to_go = (boundary - sid_lon).rem_euclid(360.0);
     let estimate_days = deg_to_go / 0.986;
     // Start search a bit before the estimate
-    let search_start = jd + estimate_days.max(0.5) - 2.0;
+    let search_start = start.add_days(estimate_days.max(0.5) - 2.0);
 
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
 
-    let f = |t: f64| -> Result<f64, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> {
         let sid = sun_sidereal_longitude(engine, t, config)?;
         Ok(normalize_to_pm180(sid - boundary))
     };
 
-    let result = find_zero_crossing(
+    let result = find_zero_crossing_epoch(
         &f,
         search_start,
         config.step_size_days,
@@ -124,12 +125,14 @@ pub fn next_sankranti(
     )?;
 
     match result {
-        Some(t) if t >= jd => Ok(Some(build_event(engine, t, boundary, config)?)),
-        // If we found a crossing before jd (shouldn't happen often), retry
+        Some(t) if t.seconds_since(start) >= 0.0 => {
+            Ok(Some(build_event(engine, t, boundary, config)?))
+        }
+        // If we found a crossing before start (shouldn't happen often), retry
         Some(_) => {
-            let result2 = find_zero_crossing(
+            let result2 = find_zero_crossing_epoch(
                 &f,
-                jd,
+                start,
                 config.step_size_days,
                 max_steps,
                 config.max_iterations,
@@ -144,31 +147,31 @@ pub fn next_sankranti(
     }
 }
 
-/// Find the previous Sankranti (Sun entering any rashi) before the given UTC time.
+/// Find the previous Sankranti (Sun entering any rashi) before `at` (see [`SearchInstant`]).
 pub fn prev_sankranti(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
     config: &SankrantiConfig,
 ) -> Result<Option<SankrantiEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
-    let sid_lon = sun_sidereal_longitude(engine, jd, config)?;
+    let start = at.to_epoch(engine);
+    let sid_lon = sun_sidereal_longitude(engine, start, config)?;
     let boundary = prev_boundary(sid_lon);
 
     // Sun moves ~0.986 deg/day backwards in time
     let deg_since = (sid_lon - boundary).rem_euclid(360.0);
     let estimate_days = deg_since / 0.986;
-    let search_start = jd - estimate_days.max(0.5) + 2.0;
+    let search_start = start.add_days(-estimate_days.max(0.5) + 2.0);
 
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
 
-    let f = |t: f64| -> Result<f64, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> {
         let sid = sun_sidereal_longitude(engine, t, config)?;
         Ok(normalize_to_pm180(sid - boundary))
     };
 
-    let result = find_zero_crossing(
+    let result = find_zero_crossing_epoch(
         &f,
         search_start,
         -config.step_size_days,
@@ -178,11 +181,13 @@ pub fn prev_sankranti(
     )?;
 
     match result {
-        Some(t) if t <= jd => Ok(Some(build_event(engine, t, boundary, config)?)),
+        Some(t) if start.seconds_since(t) >= 0.0 => {
+            Ok(Some(build_event(engine, t, boundary, config)?))
+        }
         Some(_) => {
-            let result2 = find_zero_crossing(
+            let result2 = find_zero_crossing_epoch(
                 &f,
-                jd,
+                start,
                 -config.step_size_days,
                 max_steps,
                 config.max_iterations,
@@ -197,33 +202,32 @@ pub fn prev_sankranti(
     }
 }
 
-/// Search for all Sankrantis in a UTC time range.
+/// Search for all Sankrantis between `start` and `end`.
 pub fn search_sankrantis(
     engine: &Engine,
-    start: &UtcTime,
-    end: &UtcTime,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &SankrantiConfig,
 ) -> Result<Vec<SankrantiEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    let jd_start = crate::search_util::utc_to_jd_tdb(engine, &start);
-    let jd_end = crate::search_util::utc_to_jd_tdb(engine, &end);
+    let start = start.to_epoch(engine);
+    let end = end.to_epoch(engine);
 
-    if jd_end <= jd_start {
+    if end.seconds_since(start) <= 0.0 {
         return Err(SearchError::InvalidConfig("end must be after start"));
     }
 
     let mut events = Vec::new();
-    let mut cursor = *start;
+    let mut cursor = start;
 
     // Find sankrantis iteratively
-    while let Some(event) = next_sankranti(engine, &cursor, config)? {
-        let event_jd = crate::search_util::utc_to_jd_tdb(engine, &event.utc);
-        if event_jd > jd_end {
+    while let Some(event) = next_sankranti(engine, cursor, config)? {
+        if event.epoch.seconds_since(end) > 0.0 {
             break;
         }
         // Advance cursor slightly past this event
-        cursor = UtcTime::from_jd_tdb(event_jd + 0.01, engine.lsk());
+        cursor = event.epoch.add_days(0.01);
         events.push(event);
     }
 
@@ -233,25 +237,25 @@ pub fn search_sankrantis(
 /// Find the next time the Sun enters a specific rashi.
 pub fn next_specific_sankranti(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
     rashi: Rashi,
     config: &SankrantiConfig,
 ) -> Result<Option<SankrantiEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let boundary = rashi.index() as f64 * 30.0;
 
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
 
-    let f = |t: f64| -> Result<f64, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> {
         let sid = sun_sidereal_longitude(engine, t, config)?;
         Ok(normalize_to_pm180(sid - boundary))
     };
 
-    let result = find_zero_crossing(
+    let result = find_zero_crossing_epoch(
         &f,
-        jd,
+        start,
         config.step_size_days,
         max_steps,
         config.max_iterations,
@@ -267,25 +271,25 @@ pub fn next_specific_sankranti(
 /// Find the previous time the Sun entered a specific rashi.
 pub fn prev_specific_sankranti(
     engine: &Engine,
-    utc: &UtcTime,
+    at: impl SearchInstant,
     rashi: Rashi,
     config: &SankrantiConfig,
 ) -> Result<Option<SankrantiEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
 
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let start = at.to_epoch(engine);
     let boundary = rashi.index() as f64 * 30.0;
 
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
 
-    let f = |t: f64| -> Result<f64, SearchError> {
+    let f = |t: Epoch| -> Result<f64, SearchError> {
         let sid = sun_sidereal_longitude(engine, t, config)?;
         Ok(normalize_to_pm180(sid - boundary))
     };
 
-    let result = find_zero_crossing(
+    let result = find_zero_crossing_epoch(
         &f,
-        jd,
+        start,
         -config.step_size_days,
         max_steps,
         config.max_iterations,
@@ -301,7 +305,7 @@ pub fn prev_specific_sankranti(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use dhruv_frames::DEFAULT_PRECESSION_MODEL;
+    use dhruv_frames::{DEFAULT_PRECESSION_MODEL, NutationModel};
     use dhruv_vedic_base::AyanamshaSystem;
 
     #[test]
@@ -345,4 +349,23 @@ mod tests {
         assert!(!c.use_nutation);
         assert_eq!(c.precession_model, DEFAULT_PRECESSION_MODEL);
     }
+
+    #[test]
+    fn nutation_model_changes_ayanamsha_only_with_nutation() {
+        let t = 0.24;
+        let base = SankrantiConfig::new(AyanamshaSystem::Lahiri, true);
+        let a = base.with_nutation_model(NutationModel::Iau1980);
+        let b = base.with_nutation_model(NutationModel::Iau2000B);
+        assert_ne!(
+            a.ayanamsha_deg_at_centuries(t),
+            b.ayanamsha_deg_at_centuries(t)
+        );
+
+        let mean = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+        assert_eq!(
+            mean.with_nutation_model(NutationModel::Iau1980)
+                .ayanamsha_deg_at_centuries(t),
+            mean.ayanamsha_deg_at_centuries(t)
+        );
+    }
 }
@@ -1,8 +1,13 @@
 //! Types for Sankranti search results.
 
-use dhruv_frames::{DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane};
-use dhruv_time::UtcTime;
-use dhruv_vedic_base::{AyanamshaSystem, Rashi, ayanamsha_deg_on_plane, ayanamsha_deg_with_model};
+use dhruv_frames::{
+    DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel,
+    ReferencePlane,
+};
+use dhruv_time::{Epoch, UtcTime};
+use dhruv_vedic_base::{
+    AyanamshaSystem, Rashi, ayanamsha_deg_on_plane_with_models, ayanamsha_deg_with_models,
+};
 
 /// Configuration for Sankranti search.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -13,6 +18,8 @@ pub struct SankrantiConfig {
     pub use_nutation: bool,
     /// Precession model used by ayanamsha propagation.
     pub precession_model: PrecessionModel,
+    /// Nutation model used when `use_nutation` is set.
+    pub nutation_model: NutationModel,
     /// Reference plane for longitude measurements.
     ///
     /// Derived from `ayanamsha_system.default_reference_plane()` by default.
@@ -42,6 +49,7 @@ impl SankrantiConfig {
             ayanamsha_system,
             use_nutation,
             precession_model,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane: ayanamsha_system.default_reference_plane(),
             step_size_days: 1.0,
             max_iterations: 50,
@@ -55,6 +63,7 @@ impl SankrantiConfig {
             ayanamsha_system: AyanamshaSystem::Lahiri,
             use_nutation: false,
             precession_model: DEFAULT_PRECESSION_MODEL,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane: ReferencePlane::Ecliptic,
             step_size_days: 1.0,
             max_iterations: 50,
@@ -62,17 +71,24 @@ impl SankrantiConfig {
         }
     }
 
+    /// Select the nutation model applied when `use_nutation` is set.
+    pub fn with_nutation_model(mut self, nutation_model: NutationModel) -> Self {
+        self.nutation_model = nutation_model;
+        self
+    }
+
     /// Ayanamsha at `t_centuries`, using this configuration's model and plane settings.
     ///
     /// For `Ecliptic` plane, this returns the standard ecliptic ayanamsha.
     /// For `Invariable` plane, returns the ayanamsha computed on the invariable plane
     /// (nutation is not applied — it's an ecliptic concept).
     pub fn ayanamsha_deg_at_centuries(&self, t_centuries: f64) -> f64 {
-        ayanamsha_deg_on_plane(
+        ayanamsha_deg_on_plane_with_models(
             self.ayanamsha_system,
             t_centuries,
             self.use_nutation,
             self.precession_model,
+            self.nutation_model,
             self.reference_plane,
         )
     }
@@ -81,11 +97,12 @@ impl SankrantiConfig {
     ///
     /// Used by code paths that always need ecliptic ayanamsha (e.g. tithi, elongation).
     pub fn ayanamsha_deg_ecliptic(&self, t_centuries: f64) -> f64 {
-        ayanamsha_deg_with_model(
+        ayanamsha_deg_with_models(
             self.ayanamsha_system,
             t_centuries,
             self.use_nutation,
             self.precession_model,
+            self.nutation_model,
         )
     }
 
@@ -108,6 +125,8 @@ impl SankrantiConfig {
 pub struct SankrantiEvent {
     /// UTC time of the event.
     pub utc: UtcTime,
+    /// Event time as a full-precision TDB epoch.
+    pub epoch: Epoch,
     /// The rashi the Sun is entering.
     pub rashi: Rashi,
     /// 0-based rashi index (0=Mesha .. 11=Meena).
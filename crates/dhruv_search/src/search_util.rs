@@ -7,8 +7,8 @@
 use crate::error::SearchError;
 use dhruv_core::Engine;
 use dhruv_time::{
-    EopKernel, TimeConversionPolicy, TimeWarning, UtcTime, calendar_to_jd, jd_to_tdb_seconds,
-    tdb_seconds_to_jd,
+    EopKernel, Epoch, TimeConversionPolicy, TimeWarning, UtcTime, calendar_to_jd,
+    jd_to_tdb_seconds, tdb_seconds_to_jd,
 };
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{LazyLock, RwLock};
@@ -20,6 +20,8 @@ static LSK_FUTURE_WARNED: AtomicBool = AtomicBool::new(false);
 static EOP_PRE_RANGE_WARNED: AtomicBool = AtomicBool::new(false);
 static EOP_FUTURE_WARNED: AtomicBool = AtomicBool::new(false);
 static DELTA_T_MODEL_WARNED: AtomicBool = AtomicBool::new(false);
+static UTC_DRIFT_TABLE_WARNED: AtomicBool = AtomicBool::new(false);
+static CIVIL_TIME_AS_UT1_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// Set crate-level UTC->TDB conversion policy used by date-driven search APIs.
 pub fn set_time_conversion_policy(policy: TimeConversionPolicy) {
@@ -68,6 +70,54 @@ pub(crate) fn utc_to_jd_tdb_with_eop(
     tdb_seconds_to_jd(out.tdb_seconds)
 }
 
+/// Convert UTC to a TDB [`Epoch`] using the crate-level time policy, without
+/// rounding through a single-`f64` Julian Date.
+pub(crate) fn utc_to_epoch(engine: &Engine, utc: &UtcTime) -> Epoch {
+    let out = engine.lsk().utc_to_tdb_with_policy_and_eop(
+        utc.to_utc_seconds(),
+        None,
+        time_conversion_policy(),
+    );
+    for w in &out.diagnostics.warnings {
+        emit_warning_once(w);
+    }
+    Epoch::from_tdb_seconds(out.tdb_seconds)
+}
+
+/// A time argument accepted by the search APIs.
+///
+/// A bare `f64` is a Julian Date in TDB, as before; [`Epoch`] keeps
+/// sub-microsecond resolution; [`UtcTime`] is converted with the crate-level
+/// time policy (see [`set_time_conversion_policy`]).
+pub trait SearchInstant {
+    /// The instant as a TDB epoch.
+    fn to_epoch(&self, engine: &Engine) -> Epoch;
+}
+
+impl SearchInstant for f64 {
+    fn to_epoch(&self, _engine: &Engine) -> Epoch {
+        Epoch::from_jd_tdb(*self)
+    }
+}
+
+impl SearchInstant for Epoch {
+    fn to_epoch(&self, _engine: &Engine) -> Epoch {
+        *self
+    }
+}
+
+impl SearchInstant for UtcTime {
+    fn to_epoch(&self, engine: &Engine) -> Epoch {
+        utc_to_epoch(engine, self)
+    }
+}
+
+impl<T: SearchInstant + ?Sized> SearchInstant for &T {
+    fn to_epoch(&self, engine: &Engine) -> Epoch {
+        (**self).to_epoch(engine)
+    }
+}
+
 fn emit_warning_once(warning: &TimeWarning) {
     match warning {
         TimeWarning::LskPreRangeFallback { .. } => {
@@ -95,6 +145,16 @@ fn emit_warning_once(warning: &TimeWarning) {
                 eprintln!("Warning: {warning}");
             }
         }
+        TimeWarning::UtcDriftTableUsed { .. } => {
+            if !UTC_DRIFT_TABLE_WARNED.swap(true, Ordering::Relaxed) {
+                eprintln!("Warning: {warning}");
+            }
+        }
+        TimeWarning::CivilTimeAsUt1 { .. } => {
+            if !CIVIL_TIME_AS_UT1_WARNED.swap(true, Ordering::Relaxed) {
+                eprintln!("Warning: {warning}");
+            }
+        }
     }
 }
 
@@ -170,6 +230,104 @@ pub(crate) fn find_zero_crossing(
     Ok(None)
 }
 
+/// UT1 Julian Date and pole parameters for Earth rotation at a TDB epoch.
+///
+/// UTC comes from the engine's LSK; `eop` adds DUT1, polar motion and the
+/// celestial pole offsets. Without it UT1 = UTC and the pole is undisplaced,
+/// as in [`Engine::query_topocentric`].
+pub(crate) fn earth_rotation_at(
+    engine: &Engine,
+    epoch: Epoch,
+    eop: Option<&EopKernel>,
+) -> Result<(f64, dhruv_frames::PoleParameters), SearchError> {
+    let tdb_s = jd_to_tdb_seconds(epoch.as_jd_tdb());
+    let jd_utc = tdb_seconds_to_jd(engine.lsk().tdb_to_utc(tdb_s));
+    match eop {
+        Some(eop) => {
+            let jd_ut1 = eop.utc_to_ut1_jd(jd_utc).map_err(|e| {
+                SearchError::Engine(dhruv_core::EngineError::TimeConversion(e.to_string()))
+            })?;
+            Ok((
+                jd_ut1,
+                dhruv_core::topocentric::eop_pole_parameters(eop, jd_utc),
+            ))
+        }
+        None => Ok((jd_utc, dhruv_frames::PoleParameters::default())),
+    }
+}
+
+/// Bisect a bracketed root of `f` between epochs `a` and `b` (`f_a` = `f(a)`).
+///
+/// Works on a seconds offset from `a`, so the result keeps the epoch's
+/// sub-microsecond resolution. Stops once the bracket is narrower than
+/// `tolerance_s` or after `max_iterations` halvings.
+pub(crate) fn bisect_epoch(
+    f: &dyn Fn(Epoch) -> Result<f64, SearchError>,
+    a: Epoch,
+    mut f_a: f64,
+    b: Epoch,
+    max_iterations: u32,
+    tolerance_s: f64,
+) -> Result<Epoch, SearchError> {
+    let mut lo = 0.0;
+    let mut hi = b.seconds_since(a);
+    for _ in 0..max_iterations {
+        let mid = 0.5 * (lo + hi);
+        let f_mid = f(a.add_seconds(mid))?;
+        if f_a * f_mid <= 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+            f_a = f_mid;
+        }
+        if (hi - lo).abs() < tolerance_s {
+            break;
+        }
+    }
+    Ok(a.add_seconds(0.5 * (lo + hi)))
+}
+
+/// [`find_zero_crossing`] over epochs: the coarse scan steps `step` days from
+/// `start` and the bisection runs in [`Epoch`] space to `convergence_days`.
+pub(crate) fn find_zero_crossing_epoch(
+    f: &dyn Fn(Epoch) -> Result<f64, SearchError>,
+    start: Epoch,
+    step: f64,
+    max_steps: usize,
+    max_iterations: u32,
+    convergence_days: f64,
+) -> Result<Option<Epoch>, SearchError> {
+    let mut f_prev = f(start)?;
+    let mut t_prev = start;
+
+    for _ in 0..max_steps {
+        let t_curr = t_prev.add_days(step);
+        let f_curr = f(t_curr)?;
+
+        if is_genuine_crossing(f_prev, f_curr) {
+            let (t_a, f_a, t_b) = if step > 0.0 {
+                (t_prev, f_prev, t_curr)
+            } else {
+                (t_curr, f_curr, t_prev)
+            };
+            let root = bisect_epoch(
+                f,
+                t_a,
+                f_a,
+                t_b,
+                max_iterations,
+                convergence_days * 86_400.0,
+            )?;
+            return Ok(Some(root));
+        }
+
+        t_prev = t_curr;
+        f_prev = f_curr;
+    }
+
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +379,22 @@ mod tests {
         let t = result.unwrap();
         assert!((t - 5.7).abs() < 1e-8, "got {t}");
     }
+
+    #[test]
+    fn epoch_crossing_resolves_microseconds() {
+        // Root 0.3 µs after a day boundary far from J2000, where a single
+        // f64 JD only resolves ~40 µs.
+        let root = Epoch::from_jd_tdb_parts(2_469_807.5, 0.0).add_seconds(3.0e-7);
+        let f = |t: Epoch| -> Result<f64, SearchError> { Ok(t.seconds_since(root) / 86_400.0) };
+        let start = root.add_days(-3.4);
+        let found = find_zero_crossing_epoch(&f, start, 1.0, 10, 80, 1e-12)
+            .unwrap()
+            .unwrap();
+        assert!(found.seconds_since(root).abs() < 1e-7);
+
+        let back = find_zero_crossing_epoch(&f, root.add_days(2.6), -1.0, 10, 80, 1e-12)
+            .unwrap()
+            .unwrap();
+        assert!(back.seconds_since(root).abs() < 1e-7);
+    }
 }
@@ -19,6 +19,7 @@ use dhruv_time::UtcTime;
 use crate::conjunction::body_ecliptic_state;
 use crate::conjunction_types::SearchDirection;
 use crate::error::SearchError;
+use crate::search_util::SearchInstant;
 use crate::stationary_types::{
     MaxSpeedEvent, MaxSpeedType, StationType, StationaryConfig, StationaryEvent,
 };
@@ -177,9 +178,10 @@ fn find_stationary_event(
 pub fn next_stationary(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Option<StationaryEvent>, SearchError> {
+    let jd_tdb = at.to_epoch(engine).as_jd_tdb();
     find_stationary_event(engine, body, jd_tdb, SearchDirection::Forward, config)
 }
 
@@ -187,9 +189,10 @@ pub fn next_stationary(
 pub fn prev_stationary(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Option<StationaryEvent>, SearchError> {
+    let jd_tdb = at.to_epoch(engine).as_jd_tdb();
     find_stationary_event(engine, body, jd_tdb, SearchDirection::Backward, config)
 }
 
@@ -197,13 +200,15 @@ pub fn prev_stationary(
 pub fn search_stationary(
     engine: &Engine,
     body: Body,
-    jd_start: f64,
-    jd_end: f64,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Vec<StationaryEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
     validate_stationary_body(body)?;
 
+    let jd_start = start.to_epoch(engine).as_jd_tdb();
+    let jd_end = end.to_epoch(engine).as_jd_tdb();
     if jd_end <= jd_start {
         return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
     }
@@ -352,9 +357,10 @@ fn find_max_speed_event(
 pub fn next_max_speed(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Option<MaxSpeedEvent>, SearchError> {
+    let jd_tdb = at.to_epoch(engine).as_jd_tdb();
     find_max_speed_event(engine, body, jd_tdb, SearchDirection::Forward, config)
 }
 
@@ -362,9 +368,10 @@ pub fn next_max_speed(
 pub fn prev_max_speed(
     engine: &Engine,
     body: Body,
-    jd_tdb: f64,
+    at: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Option<MaxSpeedEvent>, SearchError> {
+    let jd_tdb = at.to_epoch(engine).as_jd_tdb();
     find_max_speed_event(engine, body, jd_tdb, SearchDirection::Backward, config)
 }
 
@@ -372,13 +379,15 @@ pub fn prev_max_speed(
 pub fn search_max_speed(
     engine: &Engine,
     body: Body,
-    jd_start: f64,
-    jd_end: f64,
+    start: impl SearchInstant,
+    end: impl SearchInstant,
     config: &StationaryConfig,
 ) -> Result<Vec<MaxSpeedEvent>, SearchError> {
     config.validate().map_err(SearchError::InvalidConfig)?;
     validate_max_speed_body(body)?;
 
+    let jd_start = start.to_epoch(engine).as_jd_tdb();
+    let jd_end = end.to_epoch(engine).as_jd_tdb();
     if jd_end <= jd_start {
         return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
     }
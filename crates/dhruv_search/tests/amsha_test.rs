@@ -313,6 +313,7 @@ fn amsha_from_kundali_matches_direct() {
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_with_amshas() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -360,6 +361,7 @@ fn full_kundali_with_amshas() {
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_amsha_scope_includes_optional_sections() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -591,6 +593,7 @@ fn validation_inapplicable_variation() {
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn validation_unknown_amsha_code() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -80,23 +80,23 @@ fn avastha_all_nine_valid() {
         // All strength factors should be in [0, 1]
         let bf = entry.baladi.strength_factor();
         assert!(
-            bf >= 0.0 && bf <= 1.0,
+            (0.0..=1.0).contains(&bf),
             "baladi strength for graha {i}: {bf}"
         );
         let jf = entry.jagradadi.strength_factor();
         assert!(
-            jf >= 0.0 && jf <= 1.0,
+            (0.0..=1.0).contains(&jf),
             "jagradadi strength for graha {i}: {jf}"
         );
         let df = entry.deeptadi.strength_factor();
         assert!(
-            df >= 0.0 && df <= 1.0,
+            (0.0..=1.0).contains(&df),
             "deeptadi strength for graha {i}: {df}"
         );
         if let Some(lajjitadi) = entry.lajjitadi {
             let lf = lajjitadi.strength_factor();
             assert!(
-                lf >= 0.0 && lf <= 1.0,
+                (0.0..=1.0).contains(&lf),
                 "lajjitadi strength for graha {i}: {lf}"
             );
         }
@@ -343,6 +343,7 @@ fn all_flags_on() {
 // ===== Cross-check: arudha padas match standalone =====
 
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn arudha_padas_match_standalone() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -640,6 +640,7 @@ fn kundali_config_with_dasha(dasha_config: DashaSelectionConfig) -> FullKundaliC
 
 /// FullKundali with single dasha system, no snapshot.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_with_dasha() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -677,6 +678,7 @@ fn full_kundali_with_dasha() {
 
 /// FullKundali with snapshot_time.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_dasha_snapshot() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -712,6 +714,7 @@ fn full_kundali_dasha_snapshot() {
 
 /// FullKundali with 3 systems, ordering preserved.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_dasha_multiple_systems() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -748,6 +751,7 @@ fn full_kundali_dasha_multiple_systems() {
 
 /// Per-system max_levels should override the shared fallback depth.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_dasha_per_system_max_levels() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -788,6 +792,7 @@ fn full_kundali_dasha_per_system_max_levels() {
 
 /// Partial failure: Kala fails at polar location, Vimshottari succeeds.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_dasha_fallback_partial() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -819,12 +824,13 @@ fn full_kundali_dasha_fallback_partial() {
     // Vimshottari should succeed; Kala may fail at polar location
     assert!(r.dasha.is_some());
     let dashas = r.dasha.unwrap();
-    assert!(dashas.len() >= 1);
+    assert!(!dashas.is_empty());
     assert_eq!(dashas[0].system, DashaSystem::Vimshottari);
 }
 
 /// All fail → dasha is None.
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_dasha_fallback_all_fail() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -917,6 +923,7 @@ fn full_kundali_dasha_zero_count() {
 
 /// Ordering preserved: [Vimshottari, Kala-fail, Chara] → [Vimshottari, Chara].
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn fallback_ordering_preserved() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -1192,7 +1199,7 @@ fn non_chakra_parity_golden_values() {
         vim.levels[0][0].start_jd
     );
     assert!(
-        (vim.levels[0][0].end_jd - 2451060.8949179528).abs() < 1e-6,
+        (vim.levels[0][0].end_jd - 2_451_060.894_917_953).abs() < 1e-6,
         "Vimshottari end_jd: {:.10}",
         vim.levels[0][0].end_jd
     );
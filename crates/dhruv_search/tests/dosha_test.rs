@@ -0,0 +1,129 @@
+//! Integration tests for doshas_for_date and doshas_from_kundali.
+//!
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::sankranti_types::SankrantiConfig;
+use dhruv_search::{
+    DoshaConfig, FullKundaliConfig, SearchError, doshas_for_date, doshas_from_kundali,
+    full_kundali_for_date,
+};
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{AyanamshaSystem, BhavaConfig, MANGAL_DOSHA_BHAVAS_NORTH};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping dosha_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping dosha_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+#[test]
+fn doshas_for_date_matches_kundali_transform() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let location = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+    let config = DoshaConfig::default();
+
+    let direct = doshas_for_date(&engine, &eop, &utc, &location, &aya_config, &config)
+        .expect("doshas_for_date should succeed");
+    let kundali = full_kundali_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &aya_config,
+        &FullKundaliConfig::default(),
+    )
+    .expect("full_kundali_for_date should succeed");
+    let from_kundali = doshas_from_kundali(&kundali, &config).expect("doshas");
+
+    assert_eq!(direct.mangal, from_kundali.mangal);
+    assert_eq!(direct.kaal_sarpa, from_kundali.kaal_sarpa);
+    assert_eq!(direct.pitru, from_kundali.pitru);
+    assert_eq!(direct.gandanta.len(), from_kundali.gandanta.len());
+    for p in &direct.gandanta {
+        assert!(p.offset_deg.abs() < config.gandanta_orb_deg);
+    }
+    if !direct.mangal.is_present() {
+        assert!(direct.mangal.cancellations.is_empty());
+    }
+}
+
+#[test]
+fn north_house_set_is_subset_of_south() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(1990, 6, 1, 6, 30, 0.0);
+    let location = GeoLocation::new(13.0827, 80.2707, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+
+    let south = doshas_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &aya_config,
+        &DoshaConfig::default(),
+    )
+    .expect("south");
+    let north_config = DoshaConfig {
+        mangal_bhavas: MANGAL_DOSHA_BHAVAS_NORTH.to_vec(),
+        ..DoshaConfig::default()
+    };
+    let north =
+        doshas_for_date(&engine, &eop, &utc, &location, &aya_config, &north_config).expect("north");
+    for p in &north.mangal.placements {
+        assert!(south.mangal.placements.contains(p));
+    }
+}
+
+#[test]
+fn doshas_from_kundali_requires_graha_positions() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let location = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+    let config = FullKundaliConfig {
+        include_graha_positions: false,
+        ..FullKundaliConfig::default()
+    };
+    let kundali = full_kundali_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &aya_config,
+        &config,
+    )
+    .expect("full_kundali_for_date should succeed");
+
+    assert!(matches!(
+        doshas_from_kundali(&kundali, &DoshaConfig::default()),
+        Err(SearchError::InvalidConfig(_))
+    ));
+}
@@ -5,10 +5,14 @@
 
 use std::path::Path;
 
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::TopocentricOptions;
+use dhruv_core::{Engine, EngineConfig, Epoch};
 use dhruv_search::{
-    ChandraGrahanType, GrahanConfig, next_chandra_grahan, next_surya_grahan, prev_chandra_grahan,
+    ChandraGrahanType, GeoLocation, GrahanConfig, GrahanVisibility, SuryaGrahanPathConfig,
+    SuryaGrahanPathLine, SuryaGrahanType, besselian_elements, chandra_grahan_local_circumstances,
+    chandra_grahan_visibility_grid, next_chandra_grahan, next_surya_grahan, prev_chandra_grahan,
     prev_surya_grahan, search_chandra_grahan, search_surya_grahan,
+    surya_grahan_local_circumstances, surya_grahan_path,
 };
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
@@ -229,3 +233,257 @@ fn prev_surya_grahan_from_2024() {
         assert!(grahan.greatest_grahan_jd < c4);
     }
 }
+
+/// 2024-Apr-08 total surya grahan seen from Dallas, Texas.
+/// NASA local circumstances (CDT = UTC - 5 h): partial from 12:23:42,
+/// totality 13:40:44-13:44:35 (3m51s), maximum ~13:42:40, partial ends
+/// 15:02:50; Sun altitude ~64 deg at maximum.
+#[test]
+fn surya_grahan_2024_apr_local_dallas() {
+    let Some(engine) = load_engine() else { return };
+    let config = GrahanConfig::default();
+    let grahan = next_surya_grahan(&engine, jd_from_date(2024, 4, 1.0), &config)
+        .expect("search should succeed")
+        .expect("should find a surya grahan");
+    let dallas = GeoLocation::new(32.7767, -96.7970, 131.0);
+    let local = surya_grahan_local_circumstances(
+        &engine,
+        &grahan,
+        &dallas,
+        None,
+        &TopocentricOptions::default(),
+        &config,
+    )
+    .expect("local circumstances should succeed")
+    .expect("grahan should be seen from Dallas");
+
+    assert_eq!(local.grahan_type, SuryaGrahanType::Total);
+    assert!(local.visible);
+    assert!(local.magnitude > 1.0, "magnitude = {}", local.magnitude);
+    assert!((local.obscuration - 1.0).abs() < 1e-12);
+
+    // TDB = UTC + 69.184 s in 2024 (TT - TDB stays under 2 ms).
+    let utc = |h: u32, m: u32, s: u32| {
+        Epoch::from_jd_tdb(jd_from_date(2024, 4, 8.0))
+            .add_seconds(f64::from(h * 3600 + m * 60 + s) + 69.184)
+    };
+    let check = |label: &str, got: Epoch, expected: Epoch, tol_s: f64| {
+        let diff_s = got.seconds_since(expected).abs();
+        assert!(diff_s < tol_s, "{label} off by {diff_s:.1}s");
+    };
+    let c2 = local.c2.expect("C2").epoch;
+    let c3 = local.c3.expect("C3").epoch;
+    check("C1", local.c1.expect("C1").epoch, utc(17, 23, 42), 60.0);
+    check("C2", c2, utc(18, 40, 44), 15.0);
+    check("max", local.greatest_grahan.epoch, utc(18, 42, 40), 60.0);
+    check("C3", c3, utc(18, 44, 35), 15.0);
+    check("C4", local.c4.expect("C4").epoch, utc(20, 2, 50), 60.0);
+    let totality_s = c3.seconds_since(c2);
+    assert!(
+        (totality_s - 231.0).abs() < 10.0,
+        "totality lasts {totality_s:.1}s"
+    );
+    assert_eq!(local.c2.unwrap().utc.hour, 18);
+    assert_eq!(local.c2.unwrap().utc.minute, 40);
+
+    let alt = local.greatest_grahan.sun_altitude_deg;
+    assert!((alt - 64.0).abs() < 2.0, "Sun altitude at max = {alt}");
+}
+
+/// The same grahan is not visible from Delhi (local night).
+#[test]
+fn surya_grahan_2024_apr_local_not_visible_from_delhi() {
+    let Some(engine) = load_engine() else { return };
+    let config = GrahanConfig::default();
+    let grahan = next_surya_grahan(&engine, jd_from_date(2024, 4, 1.0), &config)
+        .expect("search should succeed")
+        .expect("should find a surya grahan");
+    let delhi = GeoLocation::new(28.6139, 77.2090, 216.0);
+    let local = surya_grahan_local_circumstances(
+        &engine,
+        &grahan,
+        &delhi,
+        None,
+        &TopocentricOptions::default(),
+        &config,
+    )
+    .expect("local circumstances should succeed");
+    assert!(local.is_none_or(|l| !l.visible));
+}
+
+/// 2024-Apr-08 Besselian elements at t0 = 18:00 TT.
+/// NASA (Espenak): x = -0.31824, y = 0.21976, d = 7.5862°, μ = 89.5912°,
+/// l1 = 0.53581, l2 = -0.01027; x' = 0.51171, y' = 0.27096 per hour.
+#[test]
+fn besselian_elements_2024_apr() {
+    let Some(engine) = load_engine() else { return };
+    let jd_tt = jd_from_date(2024, 4, 8.75);
+    let el = besselian_elements(&engine, jd_tt, None, &SuryaGrahanPathConfig::default())
+        .expect("elements should compute");
+
+    let check = |label: &str, got: f64, expected: f64, tol: f64| {
+        assert!(
+            (got - expected).abs() < tol,
+            "{label} = {got}, expected {expected}"
+        );
+    };
+    check("x", el.x, -0.31824, 2e-3);
+    check("y", el.y, 0.21976, 2e-3);
+    check("d", el.d_deg, 7.5862, 2e-3);
+    check("mu", el.mu_deg, 89.5912, 0.02);
+    check("l1", el.l1, 0.53581, 2e-3);
+    check("l2", el.l2, -0.01027, 2e-3);
+    check("x'", el.x_rate, 0.51171, 2e-3);
+    check("y'", el.y_rate, 0.27096, 2e-3);
+    check("mu'", el.mu_rate_deg, 15.004, 0.01);
+}
+
+/// Dallas lies inside the 2024-Apr-08 path of totality.
+#[test]
+fn surya_grahan_path_2024_apr_covers_dallas() {
+    let Some(engine) = load_engine() else { return };
+    let grahan = next_surya_grahan(
+        &engine,
+        jd_from_date(2024, 4, 1.0),
+        &GrahanConfig::default(),
+    )
+    .expect("search should succeed")
+    .expect("should find a surya grahan");
+    let path = surya_grahan_path(&engine, &grahan, None, &SuryaGrahanPathConfig::default())
+        .expect("path should compute");
+
+    let (lat, lon) = (32.7767, -96.7970);
+    let lat_at = |line: &SuryaGrahanPathLine| {
+        line.iter()
+            .flatten()
+            .min_by(|a, b| {
+                (a.longitude_deg - lon)
+                    .abs()
+                    .total_cmp(&(b.longitude_deg - lon).abs())
+            })
+            .map(|p| p.latitude_deg)
+            .expect("line should have points")
+    };
+    assert!((lat_at(&path.central_line) - lat).abs() < 1.0);
+    assert!(lat_at(&path.umbral_north_limit) > lat);
+    assert!(lat_at(&path.umbral_south_limit) < lat);
+    assert!(lat_at(&path.penumbral_north_limit) > lat_at(&path.umbral_north_limit));
+    assert!(!path.penumbral_south_limit.is_empty());
+    // Partial phase begins over the Pacific at sunrise, ends over the
+    // Atlantic at sunset
+    assert!(!path.sunrise_limit.is_empty());
+    assert!(!path.sunset_limit.is_empty());
+}
+
+/// 2025-Sep-07 total chandra grahan from Varanasi: the Moon is up from
+/// before P1 (~15:28 UTC) until after P4 (~20:55 UTC).
+#[test]
+fn chandra_grahan_2025_sep_visible_from_varanasi() {
+    let Some(engine) = load_engine() else { return };
+    let config = GrahanConfig::default();
+    let grahan = next_chandra_grahan(&engine, jd_from_date(2025, 9, 1.0), &config)
+        .expect("search should succeed")
+        .expect("should find a chandra grahan");
+    assert_eq!(grahan.grahan_type, ChandraGrahanType::Total);
+
+    let varanasi = GeoLocation::new(25.3176, 82.9739, 80.0);
+    let local = chandra_grahan_local_circumstances(
+        &engine,
+        &grahan,
+        &varanasi,
+        None,
+        &TopocentricOptions::default(),
+        &config,
+    )
+    .expect("local circumstances should succeed");
+
+    assert_eq!(local.visibility, GrahanVisibility::Visible);
+    assert!(local.p1.above_horizon && local.p4.above_horizon);
+    let total = local.total_window.expect("totality should be visible");
+    assert_eq!(total.start_epoch, grahan.u2_epoch.unwrap());
+    assert!(local.greatest_grahan.moon_altitude_deg > 30.0);
+}
+
+/// From Berlin the Moon rises totally eclipsed during the same grahan.
+/// NASA: P1 15:28:18, U2 17:30:40, U3 18:52:55, P4 20:55:18 UTC. The Moon's
+/// upper limb clears the geometric horizon at 17:40:51 UTC (Meeus Ch. 47
+/// lunar theory, WGS84 site, no refraction).
+#[test]
+fn chandra_grahan_2025_sep_moonrise_in_berlin() {
+    let Some(engine) = load_engine() else { return };
+    let config = GrahanConfig::default();
+    let grahan = next_chandra_grahan(&engine, jd_from_date(2025, 9, 1.0), &config)
+        .expect("search should succeed")
+        .expect("should find a chandra grahan");
+
+    let berlin = GeoLocation::new(52.52, 13.405, 34.0);
+    let local = chandra_grahan_local_circumstances(
+        &engine,
+        &grahan,
+        &berlin,
+        None,
+        &TopocentricOptions::default(),
+        &config,
+    )
+    .expect("local circumstances should succeed");
+
+    assert_eq!(local.visibility, GrahanVisibility::PartlyVisible);
+    assert!(!local.p1.above_horizon);
+    assert!(!local.u2.expect("U2").above_horizon);
+    assert!(local.u3.expect("U3").above_horizon);
+    assert!(local.p4.above_horizon);
+
+    // TDB = UTC + 69.184 s in 2025.
+    let moonrise = Epoch::from_jd_tdb(jd_from_date(2025, 9, 7.0))
+        .add_seconds(f64::from(17 * 3600 + 40 * 60 + 51) + 69.184);
+    let total = local
+        .total_window
+        .expect("totality should be partly visible");
+    let diff_s = total.start_epoch.seconds_since(moonrise).abs();
+    assert!(diff_s < 30.0, "moonrise off by {diff_s:.1}s");
+    assert_eq!(total.end_epoch, grahan.u3_epoch.unwrap());
+    let penumbral = local.penumbral_window.expect("penumbral window");
+    assert_eq!(penumbral.start_epoch, total.start_epoch);
+    assert_eq!(penumbral.end_epoch, grahan.p4_epoch);
+}
+
+/// The same grahan is not visible from Dallas (local daytime), and the
+/// world grid has all three classes.
+#[test]
+fn chandra_grahan_2025_sep_visibility_grid() {
+    let Some(engine) = load_engine() else { return };
+    let config = GrahanConfig::default();
+    let grahan = next_chandra_grahan(&engine, jd_from_date(2025, 9, 1.0), &config)
+        .expect("search should succeed")
+        .expect("should find a chandra grahan");
+    let options = TopocentricOptions::default();
+
+    let dallas = GeoLocation::new(32.7767, -96.7970, 131.0);
+    let local =
+        chandra_grahan_local_circumstances(&engine, &grahan, &dallas, None, &options, &config)
+            .expect("local circumstances should succeed");
+    assert_eq!(local.visibility, GrahanVisibility::NotVisible);
+    assert!(local.penumbral_window.is_none());
+
+    let grid = chandra_grahan_visibility_grid(&engine, &grahan, 10.0, None, &options, &config)
+        .expect("grid should compute");
+    assert_eq!(grid.cells.len(), 19 * 36);
+    for class in [
+        GrahanVisibility::Visible,
+        GrahanVisibility::PartlyVisible,
+        GrahanVisibility::NotVisible,
+    ] {
+        assert!(grid.cells.iter().any(|c| c.visibility == class));
+    }
+    let at = |lat: f64, lon: f64| {
+        grid.cells
+            .iter()
+            .find(|c| c.latitude_deg == lat && c.longitude_deg == lon)
+            .unwrap()
+            .visibility
+    };
+    assert_eq!(at(20.0, 80.0), GrahanVisibility::Visible);
+    assert_eq!(at(50.0, 10.0), GrahanVisibility::PartlyVisible);
+    assert_eq!(at(30.0, -100.0), GrahanVisibility::NotVisible);
+    assert!(grid.cells.iter().all(|c| c.longitude_deg < 180.0));
+}
@@ -364,7 +364,7 @@ fn sankranti_jagganatha_makar_2024() {
     let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let config = SankrantiConfig::new(AyanamshaSystem::Jagganatha, true);
 
-    let event = next_specific_sankranti(&engine, &utc, Rashi::Makara, &config)
+    let event = next_specific_sankranti(&engine, utc, Rashi::Makara, &config)
         .unwrap()
         .expect("should find Makar Sankranti");
 
@@ -392,7 +392,7 @@ fn sankranti_jagganatha_mesha_2024() {
     let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
     let config = SankrantiConfig::new(AyanamshaSystem::Jagganatha, true);
 
-    let event = next_specific_sankranti(&engine, &utc, Rashi::Mesha, &config)
+    let event = next_specific_sankranti(&engine, utc, Rashi::Mesha, &config)
         .unwrap()
         .expect("should find Mesha Sankranti");
 
@@ -414,7 +414,7 @@ fn sankranti_jagganatha_12_events_per_year() {
     let end = UtcTime::new(2025, 1, 1, 0, 0, 0.0);
     let config = SankrantiConfig::new(AyanamshaSystem::Jagganatha, true);
 
-    let events = search_sankrantis(&engine, &start, &end, &config).unwrap();
+    let events = search_sankrantis(&engine, start, end, &config).unwrap();
     assert!(
         events.len() >= 12,
         "expected >= 12 sankrantis, got {}",
@@ -441,10 +441,10 @@ fn sankranti_jagganatha_dates_close_to_lahiri() {
     let lahiri_cfg = SankrantiConfig::default_lahiri();
     let jagg_cfg = SankrantiConfig::new(AyanamshaSystem::Jagganatha, true);
 
-    let lahiri_event = next_specific_sankranti(&engine, &utc, Rashi::Makara, &lahiri_cfg)
+    let lahiri_event = next_specific_sankranti(&engine, utc, Rashi::Makara, &lahiri_cfg)
         .unwrap()
         .expect("Lahiri Makar Sankranti");
-    let jagg_event = next_specific_sankranti(&engine, &utc, Rashi::Makara, &jagg_cfg)
+    let jagg_event = next_specific_sankranti(&engine, utc, Rashi::Makara, &jagg_cfg)
         .unwrap()
         .expect("Jagganatha Makar Sankranti");
 
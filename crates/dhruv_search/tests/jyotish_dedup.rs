@@ -278,6 +278,7 @@ fn single_item_endpoints_match_full_results() {
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn full_kundali_mixed_sections_match_standalone_results() {
     let Some(engine) = load_engine() else { return };
     let Some(eop) = load_eop() else { return };
@@ -0,0 +1,85 @@
+//! Integration tests for kp_chart_for_date.
+//!
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::kp_chart_for_date;
+use dhruv_search::sankranti_types::SankrantiConfig;
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{AyanamshaSystem, BhavaConfig, BhavaSystem, Graha, kp_lords};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping kp_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping kp_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+fn kp_bhava_config() -> BhavaConfig {
+    BhavaConfig {
+        system: BhavaSystem::KP,
+        ..BhavaConfig::default()
+    }
+}
+
+#[test]
+fn kp_chart_is_self_consistent() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let location = GeoLocation::new(13.0827, 80.2707, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::KP, false);
+
+    let chart = kp_chart_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &kp_bhava_config(),
+        &RiseSetConfig::default(),
+        &aya_config,
+    )
+    .expect("kp_chart_for_date should succeed");
+
+    for (i, e) in chart.grahas.iter().enumerate() {
+        assert_eq!(e.graha.index() as usize, i);
+        assert_eq!(e.lords, kp_lords(e.sidereal_longitude));
+        assert!((1..=12).contains(&e.bhava_number));
+        assert!((1..=249).contains(&e.sub_number));
+    }
+
+    // Every graha occupies exactly one house.
+    let occupants: usize = chart.significators.iter().map(|h| h.level_b.len()).sum();
+    assert_eq!(occupants, 9);
+    for h in &chart.significators {
+        assert_eq!(h.level_d.len(), 1);
+        assert_eq!(
+            h.level_d[0],
+            chart.cusps[h.bhava_number as usize - 1].lords.sign_lord
+        );
+    }
+
+    // Lagna lords match the first cusp for a Placidus-based chart.
+    assert_eq!(chart.ruling_planets.lagna, chart.cusps[0].lords);
+    let moon = &chart.grahas[Graha::Chandra.index() as usize];
+    assert_eq!(chart.ruling_planets.moon, moon.lords);
+    // 2024-01-15 12:00 UTC is Monday evening in Chennai.
+    assert_eq!(chart.ruling_planets.day_lord, Graha::Chandra);
+}
@@ -28,7 +28,7 @@ fn load_engine() -> Option<Engine> {
 fn purnima_jan_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
-    let event = next_purnima(&engine, &utc)
+    let event = next_purnima(&engine, utc)
         .unwrap()
         .expect("should find purnima");
     assert_eq!(event.utc.year, 2024);
@@ -48,7 +48,7 @@ fn purnima_jan_2024() {
 fn amavasya_jan_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
-    let event = next_amavasya(&engine, &utc)
+    let event = next_amavasya(&engine, utc)
         .unwrap()
         .expect("should find amavasya");
     assert_eq!(event.utc.year, 2024);
@@ -67,7 +67,7 @@ fn amavasya_jan_2024() {
 fn purnima_feb_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 2, 1, 0, 0, 0.0);
-    let event = next_purnima(&engine, &utc)
+    let event = next_purnima(&engine, utc)
         .unwrap()
         .expect("should find purnima");
     assert_eq!(event.utc.year, 2024);
@@ -81,7 +81,7 @@ fn search_purnimas_2024() {
     let Some(engine) = load_engine() else { return };
     let start = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let end = UtcTime::new(2025, 1, 1, 0, 0, 0.0);
-    let events = search_purnimas(&engine, &start, &end).unwrap();
+    let events = search_purnimas(&engine, start, end).unwrap();
     // A year has 12 or 13 full moons
     assert!(
         events.len() >= 12 && events.len() <= 13,
@@ -102,7 +102,7 @@ fn search_amavasyas_2024() {
     let Some(engine) = load_engine() else { return };
     let start = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let end = UtcTime::new(2025, 1, 1, 0, 0, 0.0);
-    let events = search_amavasyas(&engine, &start, &end).unwrap();
+    let events = search_amavasyas(&engine, start, end).unwrap();
     assert!(
         events.len() >= 12 && events.len() <= 13,
         "expected 12-13 amavasyas, got {}",
@@ -115,7 +115,7 @@ fn search_amavasyas_2024() {
 fn prev_purnima_from_feb_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 2, 15, 0, 0, 0.0);
-    let event = prev_purnima(&engine, &utc)
+    let event = prev_purnima(&engine, utc)
         .unwrap()
         .expect("should find prev purnima");
     // Should find Jan 25 full moon
@@ -129,7 +129,7 @@ fn prev_purnima_from_feb_2024() {
 fn prev_amavasya_from_feb_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 2, 1, 0, 0, 0.0);
-    let event = prev_amavasya(&engine, &utc)
+    let event = prev_amavasya(&engine, utc)
         .unwrap()
         .expect("should find prev amavasya");
     // Should find Jan 11 new moon
@@ -143,7 +143,7 @@ fn prev_amavasya_from_feb_2024() {
 fn purnima_longitude_opposition() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
-    let event = next_purnima(&engine, &utc)
+    let event = next_purnima(&engine, utc)
         .unwrap()
         .expect("should find purnima");
     let diff = (event.moon_longitude_deg - event.sun_longitude_deg).abs();
@@ -159,7 +159,7 @@ fn purnima_longitude_opposition() {
 fn amavasya_longitude_conjunction() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
-    let event = next_amavasya(&engine, &utc)
+    let event = next_amavasya(&engine, utc)
         .unwrap()
         .expect("should find amavasya");
     let diff = (event.moon_longitude_deg - event.sun_longitude_deg).abs();
@@ -0,0 +1,62 @@
+//! Integration tests for kundali_milan_for_dates.
+//!
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::sankranti_types::SankrantiConfig;
+use dhruv_search::{GrahaLongitudesConfig, graha_longitudes, kundali_milan_for_dates};
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::{AyanamshaSystem, Graha, kundali_milan};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping milan_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping milan_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+#[test]
+fn milan_for_dates_matches_moon_longitudes() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let groom = UtcTime::new(1990, 5, 12, 6, 30, 0.0);
+    let bride = UtcTime::new(1992, 11, 3, 18, 0, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+
+    let result = kundali_milan_for_dates(&engine, &eop, &groom, &bride, &aya_config)
+        .expect("kundali_milan_for_dates should succeed");
+    assert!((0.0..=36.0).contains(&result.ashtakoota.total));
+    assert!(result.dashakoota.matched_count <= 10);
+
+    let moon = |utc: &UtcTime| {
+        let jd = utc.to_jd_tdb(engine.lsk());
+        let config = GrahaLongitudesConfig::sidereal_with_model(
+            aya_config.ayanamsha_system,
+            aya_config.use_nutation,
+            aya_config.precession_model,
+            aya_config.reference_plane,
+        );
+        graha_longitudes(&engine, jd, &config)
+            .expect("graha_longitudes")
+            .longitudes[Graha::Chandra.index() as usize]
+    };
+    let expected = kundali_milan(moon(&groom), moon(&bride));
+    assert_eq!(result.ashtakoota.total, expected.ashtakoota.total);
+    assert_eq!(result.dashakoota, expected.dashakoota);
+}
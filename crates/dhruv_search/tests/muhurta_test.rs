@@ -0,0 +1,134 @@
+//! Integration tests for muhurta_for_date.
+//!
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::{muhurta_for_date, vaar_for_date};
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{Choghadiya, Vaar};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+/// One second, in days.
+const EPS_DAYS: f64 = 1.0 / 86_400.0;
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping muhurta_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping muhurta_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+#[test]
+fn muhurta_slots_tile_the_vedic_day() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let lsk = engine.lsk();
+    // 2024-01-15 is a Monday.
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let rs = RiseSetConfig::default();
+
+    let info = muhurta_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    let vaar = vaar_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    assert_eq!(info.vaar, vaar.vaar);
+    assert_eq!(info.vaar, Vaar::Somvaar);
+
+    let sunrise = info.sunrise.to_jd_tdb(lsk);
+    let sunset = info.sunset.to_jd_tdb(lsk);
+    let next_sunrise = info.next_sunrise.to_jd_tdb(lsk);
+    assert!(sunrise < sunset && sunset < next_sunrise);
+
+    let mut prev_end = sunrise;
+    for m in &info.muhurtas {
+        let start = m.start.to_jd_tdb(lsk);
+        assert!(
+            (start - prev_end).abs() < EPS_DAYS,
+            "{} not contiguous",
+            m.name
+        );
+        prev_end = m.end.to_jd_tdb(lsk);
+    }
+    assert!((prev_end - next_sunrise).abs() < EPS_DAYS);
+    assert!((info.muhurtas[15].start.to_jd_tdb(lsk) - sunset).abs() < EPS_DAYS);
+
+    let mut prev_end = sunrise;
+    for c in &info.choghadiyas {
+        assert!((c.start.to_jd_tdb(lsk) - prev_end).abs() < EPS_DAYS);
+        prev_end = c.end.to_jd_tdb(lsk);
+    }
+    assert_eq!(info.choghadiyas[0].choghadiya, Choghadiya::Amrit);
+
+    // Monday Rahu Kalam is the second eighth of the day.
+    let eighth = (sunset - sunrise) / 8.0;
+    assert!((info.rahu_kalam.start.to_jd_tdb(lsk) - (sunrise + eighth)).abs() < EPS_DAYS);
+    // Monday Durmuhurtas: 9th and 12th day muhurtas.
+    let dur: Vec<u8> = info
+        .muhurtas
+        .iter()
+        .filter(|m| m.is_durmuhurta)
+        .map(|m| m.index)
+        .collect();
+    assert_eq!(dur, [8, 11]);
+
+    // Abhijit straddles local apparent noon; Brahma muhurta ends one
+    // night muhurta before the next sunrise.
+    let midday = (sunrise + sunset) / 2.0;
+    assert!(info.abhijit.start.to_jd_tdb(lsk) < midday);
+    assert!(info.abhijit.end.to_jd_tdb(lsk) > midday);
+    let night_muhurta = (next_sunrise - sunset) / 15.0;
+    let brahma_end = info.brahma_muhurta.end.to_jd_tdb(lsk);
+    assert!((next_sunrise - brahma_end - night_muhurta).abs() < EPS_DAYS);
+}
+
+#[test]
+fn muhurta_before_sunrise_uses_previous_day() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    // 00:00 UTC is 05:30 IST, before sunrise in Delhi.
+    let utc = UtcTime::new(2024, 1, 15, 0, 0, 0.0);
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let rs = RiseSetConfig::default();
+
+    let info = muhurta_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    assert_eq!(info.vaar, Vaar::Ravivaar);
+    let lsk = engine.lsk();
+    let jd = utc.to_jd_tdb(lsk);
+    assert!(info.sunrise.to_jd_tdb(lsk) < jd);
+    assert!(jd < info.next_sunrise.to_jd_tdb(lsk));
+}
+
+#[test]
+fn muhurta_sunset_far_east_is_same_civil_day() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    // 2024-01-15 02:00 UTC is 13:00 AEDT in Sydney; sunrise was the
+    // previous UTC day.
+    let utc = UtcTime::new(2024, 1, 15, 2, 0, 0.0);
+    let loc = GeoLocation::new(-33.8688, 151.2093, 0.0);
+    let rs = RiseSetConfig::default();
+
+    let info = muhurta_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    let lsk = engine.lsk();
+    let sunrise = info.sunrise.to_jd_tdb(lsk);
+    let sunset = info.sunset.to_jd_tdb(lsk);
+    assert!(sunrise < utc.to_jd_tdb(lsk));
+    // Summer day length in Sydney is a little over 14 hours.
+    let day_hours = (sunset - sunrise) * 24.0;
+    assert!((13.5..15.0).contains(&day_hours), "day = {day_hours} h");
+}
@@ -36,7 +36,7 @@ fn makar_sankranti_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let config = default_config();
-    let event = next_specific_sankranti(&engine, &utc, Rashi::Makara, &config)
+    let event = next_specific_sankranti(&engine, utc, Rashi::Makara, &config)
         .unwrap()
         .expect("should find Makar Sankranti");
     assert_eq!(event.utc.year, 2024);
@@ -62,7 +62,7 @@ fn mesha_sankranti_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
     let config = default_config();
-    let event = next_specific_sankranti(&engine, &utc, Rashi::Mesha, &config)
+    let event = next_specific_sankranti(&engine, utc, Rashi::Mesha, &config)
         .unwrap()
         .expect("should find Mesha Sankranti");
     assert_eq!(event.utc.year, 2024);
@@ -82,7 +82,7 @@ fn next_sankranti_from_jan_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let config = default_config();
-    let event = next_sankranti(&engine, &utc, &config)
+    let event = next_sankranti(&engine, utc, &config)
         .unwrap()
         .expect("should find a sankranti");
     assert_eq!(event.utc.year, 2024);
@@ -101,7 +101,7 @@ fn prev_sankranti_from_feb_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 2, 1, 0, 0, 0.0);
     let config = default_config();
-    let event = prev_sankranti(&engine, &utc, &config)
+    let event = prev_sankranti(&engine, utc, &config)
         .unwrap()
         .expect("should find prev sankranti");
     assert_eq!(event.utc.year, 2024);
@@ -116,7 +116,7 @@ fn search_all_sankrantis_2024() {
     let start = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
     let end = UtcTime::new(2025, 1, 1, 0, 0, 0.0);
     let config = default_config();
-    let events = search_sankrantis(&engine, &start, &end, &config).unwrap();
+    let events = search_sankrantis(&engine, start, end, &config).unwrap();
     // A year should have 12 sankrantis (one per rashi)
     assert_eq!(
         events.len(),
@@ -144,7 +144,7 @@ fn prev_karka_sankranti_from_aug_2024() {
     let Some(engine) = load_engine() else { return };
     let utc = UtcTime::new(2024, 8, 1, 0, 0, 0.0);
     let config = default_config();
-    let event = prev_specific_sankranti(&engine, &utc, Rashi::Karka, &config)
+    let event = prev_specific_sankranti(&engine, utc, Rashi::Karka, &config)
         .unwrap()
         .expect("should find prev Karka Sankranti");
     assert_eq!(event.utc.year, 2024);
@@ -4,7 +4,7 @@
 
 use std::path::{Path, PathBuf};
 
-use dhruv_core::{Body, Engine, EngineConfig};
+use dhruv_core::{Body, Engine, EngineConfig, SpkLoadMode};
 use dhruv_frames::PrecessionModel;
 use dhruv_search::conjunction::body_ecliptic_lon_lat_with_model;
 
@@ -25,6 +25,7 @@ fn load_engine() -> Option<Engine> {
         lsk_path: PathBuf::from(LSK_PATH),
         cache_capacity: 1024,
         strict_validation: false,
+        spk_load_mode: SpkLoadMode::InMemory,
     };
     Engine::new(config).ok()
 }
@@ -4,7 +4,7 @@
 
 use std::path::Path;
 
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Correction, Engine, EngineConfig};
 use dhruv_search::{GrahaLongitudesConfig, graha_longitudes, moving_osculating_apogees};
 use dhruv_vedic_base::{ALL_GRAHAS, AyanamshaSystem, Graha, ayanamsha_deg, jd_tdb_to_centuries};
 
@@ -158,3 +158,32 @@ fn graha_longitudes_outer_planets_can_be_disabled_in_rust_config() {
 
     assert!(result.outer_planets.is_none());
 }
+
+#[test]
+fn apparent_correction_shifts_sun_by_aberration() {
+    let engine = match load_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let jd = 2_460_000.5;
+    let geometric = graha_longitudes(&engine, jd, &GrahaLongitudesConfig::tropical(false))
+        .expect("geometric longitudes");
+    let apparent = graha_longitudes(
+        &engine,
+        jd,
+        &GrahaLongitudesConfig::tropical(false).with_correction(Correction::Apparent),
+    )
+    .expect("apparent longitudes");
+
+    // Annual aberration moves the Sun ~20.5" backwards along the ecliptic.
+    let sun = Graha::Surya.index() as usize;
+    let diff_arcsec = (apparent.longitudes[sun] - geometric.longitudes[sun]) * 3600.0;
+    assert!(
+        (diff_arcsec + 20.5).abs() < 0.5,
+        "Sun apparent - geometric = {diff_arcsec}\""
+    );
+
+    // Nodes are mean/true node computations and are unaffected.
+    let rahu = Graha::Rahu.index() as usize;
+    assert_eq!(apparent.longitudes[rahu], geometric.longitudes[rahu]);
+}
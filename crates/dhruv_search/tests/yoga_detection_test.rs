@@ -0,0 +1,105 @@
+//! Integration tests for yogas_from_kundali.
+//!
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::sankranti_types::SankrantiConfig;
+use dhruv_search::{
+    CLASSICAL_YOGA_RULES, FullKundaliConfig, SearchError, YogaChart, full_kundali_for_date,
+    yogas_from_kundali,
+};
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{AyanamshaSystem, BhavaConfig, detect_yogas};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping yoga_detection_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping yoga_detection_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+#[test]
+fn yogas_from_kundali_matches_direct_detection() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let location = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+
+    let kundali = full_kundali_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &aya_config,
+        &FullKundaliConfig::default(),
+    )
+    .expect("full_kundali_for_date should succeed");
+
+    let yogas = yogas_from_kundali(&kundali, CLASSICAL_YOGA_RULES).expect("yogas");
+    let gp = kundali.graha_positions.as_ref().expect("graha positions");
+    let chart = YogaChart::new(
+        gp.lagna.sidereal_longitude,
+        gp.grahas.map(|g| g.sidereal_longitude),
+    );
+    assert_eq!(yogas, detect_yogas(&chart, CLASSICAL_YOGA_RULES));
+    for y in &yogas {
+        assert!(!y.grahas.is_empty(), "{} has no grahas", y.name);
+        assert!(!y.detail.is_empty());
+    }
+
+    // Sunapha, Anapha and Durudhara are mutually exclusive.
+    let flanking = yogas
+        .iter()
+        .filter(|y| ["Sunapha", "Anapha", "Durudhara"].contains(&y.name))
+        .count();
+    assert!(flanking <= 1);
+}
+
+#[test]
+fn yogas_from_kundali_requires_graha_positions() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 12, 0, 0.0);
+    let location = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let aya_config = SankrantiConfig::new(AyanamshaSystem::Lahiri, false);
+    let config = FullKundaliConfig {
+        include_graha_positions: false,
+        ..FullKundaliConfig::default()
+    };
+    let kundali = full_kundali_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &aya_config,
+        &config,
+    )
+    .expect("full_kundali_for_date should succeed");
+
+    assert!(matches!(
+        yogas_from_kundali(&kundali, CLASSICAL_YOGA_RULES),
+        Err(SearchError::InvalidConfig(_))
+    ));
+}
@@ -1,5 +1,7 @@
 //! Configuration types for fixed star position queries.
 
+use dhruv_frames::{DEFAULT_NUTATION_MODEL, NutationModel};
+
 /// Accuracy tier for star position computation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TaraAccuracy {
@@ -21,6 +23,8 @@ pub struct TaraConfig {
     /// Whether to apply parallax correction (shifts position based on Earth's
     /// position relative to the star). Requires `EarthState`.
     pub apply_parallax: bool,
+    /// Nutation model for the [`TaraAccuracy::Apparent`] tier.
+    pub nutation_model: NutationModel,
 }
 
 impl Default for TaraConfig {
@@ -28,6 +32,7 @@ impl Default for TaraConfig {
         Self {
             accuracy: TaraAccuracy::Astrometric,
             apply_parallax: false,
+            nutation_model: DEFAULT_NUTATION_MODEL,
         }
     }
 }
@@ -53,5 +58,6 @@ mod tests {
         let cfg = TaraConfig::default();
         assert_eq!(cfg.accuracy, TaraAccuracy::Astrometric);
         assert!(!cfg.apply_parallax);
+        assert_eq!(cfg.nutation_model, DEFAULT_NUTATION_MODEL);
     }
 }
@@ -175,6 +175,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn rotation_matrix_orthogonal() {
         // R^T should be orthogonal: R^T · R = I
         for i in 0..3 {
@@ -3,7 +3,7 @@
 //! All public APIs accept `jd_tdb: f64` (Julian Date in TDB).
 
 use dhruv_frames::{
-    SphericalCoords, cartesian_to_spherical, icrf_to_ecliptic, nutation_iau2000b,
+    SphericalCoords, cartesian_to_spherical, icrf_to_ecliptic, nutation_with_model,
     precess_ecliptic_j2000_to_date,
 };
 
@@ -148,7 +148,7 @@ pub fn position_ecliptic_with_config(
 
     // For Apparent tier, apply nutation to the ecliptic longitude
     if config.accuracy == TaraAccuracy::Apparent {
-        let (dpsi_arcsec, _deps_arcsec) = nutation_iau2000b(t_centuries);
+        let (dpsi_arcsec, _deps_arcsec) = nutation_with_model(t_centuries, config.nutation_model);
         let sc = cartesian_to_spherical(&ecl_of_date);
         // dpsi is in arcseconds; convert to radians
         let dpsi_rad = dpsi_arcsec * std::f64::consts::PI / 648_000.0;
@@ -314,6 +314,7 @@ fn direction_scaled(unit: &[f64; 3], scale: f64) -> [f64; 3] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dhruv_frames::NutationModel;
 
     fn test_catalog() -> TaraCatalog {
         TaraCatalog::parse(
@@ -402,12 +403,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apparent_longitude_follows_nutation_model() {
+        let cat = test_catalog();
+        let jd = 2_460_311.0;
+        let earth = EarthState {
+            position_au: [-0.17, 0.89, 0.39],
+            velocity_au_day: [-0.017, -0.0027, -0.0012],
+        };
+        let lon = |nutation_model| {
+            let config = TaraConfig {
+                accuracy: TaraAccuracy::Apparent,
+                nutation_model,
+                ..TaraConfig::default()
+            };
+            position_ecliptic_with_config(&cat, TaraId::Chitra, jd, &config, Some(&earth))
+                .unwrap()
+                .lon_deg
+        };
+        let t = (jd - J2000_JD) / 36525.0;
+        let expected = nutation_with_model(t, NutationModel::Iau1980).0
+            - nutation_with_model(t, NutationModel::Iau2000B).0;
+        let diff = (lon(NutationModel::Iau1980) - lon(NutationModel::Iau2000B)) * 3600.0;
+        assert!((diff - expected).abs() < 1e-9, "{diff}″ vs {expected}″");
+    }
+
     #[test]
     fn earth_state_required_for_apparent() {
         let cat = test_catalog();
         let config = TaraConfig {
             accuracy: TaraAccuracy::Apparent,
             apply_parallax: false,
+            ..TaraConfig::default()
         };
         let result = position_equatorial_with_config(&cat, TaraId::Chitra, J2000_JD, &config, None);
         assert!(matches!(result, Err(TaraError::EarthStateRequired)));
@@ -419,6 +446,7 @@ mod tests {
         let config = TaraConfig {
             accuracy: TaraAccuracy::Astrometric,
             apply_parallax: true,
+            ..TaraConfig::default()
         };
         let result = position_equatorial_with_config(&cat, TaraId::Chitra, J2000_JD, &config, None);
         assert!(matches!(result, Err(TaraError::EarthStateRequired)));
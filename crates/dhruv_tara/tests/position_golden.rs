@@ -182,10 +182,12 @@ fn test7_aberration_shift() {
     let config_astro = TaraConfig {
         accuracy: TaraAccuracy::Astrometric,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let config_app = TaraConfig {
         accuracy: TaraAccuracy::Apparent,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     // Synthetic Earth state: ~1 AU from Sun, ~29.8 km/s
     let earth = EarthState {
@@ -226,6 +228,7 @@ fn test9_apparent_requires_earth_state() {
     let config = TaraConfig {
         accuracy: TaraAccuracy::Apparent,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let result = position_equatorial_with_config(&cat, TaraId::Chitra, JD_2024, &config, None);
     assert!(
@@ -249,6 +252,7 @@ fn test9_apparent_requires_earth_state() {
     let config_plx = TaraConfig {
         accuracy: TaraAccuracy::Astrometric,
         apply_parallax: true,
+        ..TaraConfig::default()
     };
     let result = position_equatorial_with_config(&cat, TaraId::Chitra, JD_2024, &config_plx, None);
     assert!(
@@ -265,10 +269,12 @@ fn test10_astrometric_vs_apparent_bounded() {
     let config_astro = TaraConfig {
         accuracy: TaraAccuracy::Astrometric,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let config_app = TaraConfig {
         accuracy: TaraAccuracy::Apparent,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let earth = EarthState {
         position_au: [0.983, 0.0, 0.0], // realistic ~1 AU
@@ -308,6 +314,7 @@ fn test11_nutation_on_off_difference() {
     let config_astro = TaraConfig {
         accuracy: TaraAccuracy::Astrometric,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let eclip_astro =
         position_ecliptic_with_config(&cat, TaraId::Chitra, JD_2024, &config_astro, None)
@@ -317,6 +324,7 @@ fn test11_nutation_on_off_difference() {
     let config_app = TaraConfig {
         accuracy: TaraAccuracy::Apparent,
         apply_parallax: false,
+        ..TaraConfig::default()
     };
     let eclip_app =
         position_ecliptic_with_config(&cat, TaraId::Chitra, JD_2024, &config_app, Some(&earth))
@@ -221,7 +221,7 @@ pub enum DeltaTSegment {
 }
 
 /// Delta-T model selector.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DeltaTModel {
     /// Legacy Espenak/Meeus-style piecewise polynomial currently used by Dhruv.
     LegacyEspenakMeeus2006,
@@ -229,6 +229,7 @@ pub enum DeltaTModel {
     ///
     /// Phase A scaffolding keeps output parity with the legacy model until
     /// SMH2016 data assets are integrated.
+    #[default]
     Smh2016WithPre720Quadratic,
 }
 
@@ -238,12 +239,13 @@ pub enum DeltaTModel {
 /// - Morrison et al. (2021) Addendum 2020, Eq. (5) + Table 1.
 /// - NASA GSFC Delta-T help page `deltaT2.html` (Stephenson 1997 summary).
 /// - Stephenson, Morrison, Hohenkerk (2016), Eq. (4.1).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SmhFutureParabolaFamily {
     /// Piecewise family from Addendum 2020 Table 1:
     /// - `c=-20.0` for `-720..2019`
     /// - `c=-17.52` for `2019..3000`
     /// - `c=-15.32` for `3000..10000`
+    #[default]
     Addendum2020Piecewise,
     /// Forced family member `c=-20.0`.
     ConstantCMinus20,
@@ -263,18 +265,6 @@ pub enum SmhFutureParabolaFamily {
     Stephenson2016,
 }
 
-impl Default for SmhFutureParabolaFamily {
-    fn default() -> Self {
-        Self::Addendum2020Piecewise
-    }
-}
-
-impl Default for DeltaTModel {
-    fn default() -> Self {
-        Self::Smh2016WithPre720Quadratic
-    }
-}
-
 /// Compute Delta-T (`TT-UT1`) in seconds for a UT1 Julian date.
 ///
 /// Returns `(delta_t_seconds, segment_used)`.
@@ -616,7 +606,7 @@ mod tests {
         let jd = calendar_to_jd(1000, 1, 1.0);
         let (dt, seg) = delta_t_seconds_smh_model(jd, Some(&table));
         assert_eq!(seg, DeltaTSegment::Smh2016Reconstruction);
-        assert!(dt >= 10.0 && dt <= 20.0);
+        assert!((10.0..=20.0).contains(&dt));
     }
 
     #[test]
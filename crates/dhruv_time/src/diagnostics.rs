@@ -34,6 +34,14 @@ pub enum TimeWarning {
         segment: DeltaTSegment,
         assumed_dut1_seconds: f64,
     },
+    /// UTC epoch is in 1961–1971; TAI−UTC came from the drift-rate table.
+    UtcDriftTableUsed {
+        utc_seconds: f64,
+        tai_minus_utc_seconds: f64,
+    },
+    /// UTC epoch predates UTC itself (1961); the civil time was treated
+    /// as UT1.
+    CivilTimeAsUt1 { utc_seconds: f64 },
 }
 
 /// Source used for `TT-UTC`.
@@ -43,6 +51,9 @@ pub enum TtUtcSource {
     LskDeltaAt,
     /// Computed from Delta-T model (`(TT-UT1) + DUT1`).
     DeltaTModel,
+    /// Computed from the 1961–1971 UTC drift-rate table
+    /// (`TAI-UTC + 32.184`).
+    UtcDriftTable,
 }
 
 /// Diagnostics produced by UTC->TDB conversion.
@@ -101,6 +112,17 @@ impl std::fmt::Display for TimeWarning {
                 f,
                 "Delta-T fallback used (model={model:?}, segment={segment:?}); assumed DUT1={assumed_dut1_seconds:.6}s."
             ),
+            Self::UtcDriftTableUsed {
+                utc_seconds,
+                tai_minus_utc_seconds,
+            } => write!(
+                f,
+                "UTC {utc_seconds:.3}s is in the 1961-1971 rubber-second era; using drift-table TAI-UTC={tai_minus_utc_seconds:.6}s."
+            ),
+            Self::CivilTimeAsUt1 { utc_seconds } => write!(
+                f,
+                "UTC {utc_seconds:.3}s predates UTC (1961); civil time treated as UT1 with TT-UT1 from the Delta-T model."
+            ),
         }
     }
 }
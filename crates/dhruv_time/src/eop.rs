@@ -1,4 +1,5 @@
-//! IERS Earth Orientation Parameters (EOP) — UT1−UTC lookup.
+//! IERS Earth Orientation Parameters (EOP) — UT1−UTC, polar motion, celestial
+//! pole offsets and length-of-day lookup.
 //!
 //! Supports:
 //! - `finals2000A` fixed-width parsing (historical + rapid + predicted tail)
@@ -33,6 +34,15 @@ pub enum EopSource {
 struct EopEntry {
     mjd: f64,
     dut1: f64,
+    /// Pole coordinates x_p, y_p in arcseconds (zero when the row has none).
+    xp: f64,
+    yp: f64,
+    /// Celestial pole offsets dX, dY relative to IAU 2006/2000A, in
+    /// arcseconds (zero when the row has none).
+    dx: f64,
+    dy: f64,
+    /// Excess length of day in seconds (zero when the row has none).
+    lod: f64,
     source: EopSource,
 }
 
@@ -65,7 +75,7 @@ pub struct Dut1LookupResult {
     pub warnings: Vec<TimeWarning>,
 }
 
-/// Parsed IERS Earth Orientation Parameters (DUT1 lookup table).
+/// Parsed IERS Earth Orientation Parameters (DUT1 and polar motion table).
 #[derive(Debug, Clone)]
 pub struct EopData {
     /// Daily entries sorted ascending by MJD.
@@ -77,7 +87,9 @@ pub struct EopData {
 impl EopData {
     /// Parse IERS finals2000A fixed-width format.
     ///
-    /// Extracts MJD (col 8-15), source flag (col 58), and DUT1 (col 59-68).
+    /// Extracts MJD (col 8-15), x_p (col 19-27), y_p (col 38-46), source
+    /// flag (col 58), DUT1 (col 59-68), LOD (col 80-86, ms), and the
+    /// celestial pole offsets dX (col 98-106) and dY (col 117-125) in mas.
     pub fn parse_finals(content: &str) -> Result<Self, TimeError> {
         let entries = Self::parse_finals_entries(content)?;
         Ok(Self::from_sorted_entries(entries))
@@ -85,7 +97,8 @@ impl EopData {
 
     /// Parse IERS C04 format (whitespace-delimited operational/reprocessed files).
     ///
-    /// Expected row head: `year month day mjd x y ut1_utc ...`
+    /// Expected row head: `year month day mjd x y ut1_utc lod dX dY ...`;
+    /// columns after `ut1_utc` are optional.
     pub fn parse_c04(content: &str) -> Result<Self, TimeError> {
         let entries = Self::parse_c04_entries(content)?;
         Ok(Self::from_sorted_entries(entries))
@@ -156,6 +169,13 @@ impl EopData {
                 Err(_) => continue,
             };
 
+            let xp = parse_column(line, 18, 27);
+            let yp = parse_column(line, 37, 46);
+            // LOD is published in ms, dX/dY in mas.
+            let lod = parse_column(line, 79, 86) / 1000.0;
+            let dx = parse_column(line, 97, 106) / 1000.0;
+            let dy = parse_column(line, 116, 125) / 1000.0;
+
             let flag = bytes[57] as char;
             let source = if flag == 'P' {
                 EopSource::FinalsPredicted
@@ -163,7 +183,16 @@ impl EopData {
                 EopSource::FinalsFinal
             };
 
-            entries.push(EopEntry { mjd, dut1, source });
+            entries.push(EopEntry {
+                mjd,
+                dut1,
+                xp,
+                yp,
+                dx,
+                dy,
+                lod,
+                source,
+            });
         }
 
         if entries.is_empty() {
@@ -212,9 +241,19 @@ impl EopData {
                 Err(_) => continue,
             };
 
+            let column = |i: usize| {
+                cols.get(i)
+                    .and_then(|field| field.parse().ok())
+                    .unwrap_or(0.0)
+            };
             entries.push(EopEntry {
                 mjd,
                 dut1,
+                xp: column(4),
+                yp: column(5),
+                lod: column(7),
+                dx: column(8),
+                dy: column(9),
                 source: EopSource::C04Final,
             });
         }
@@ -295,6 +334,54 @@ impl EopData {
         (dut1, source)
     }
 
+    /// Pole coordinates `(x_p, y_p)` in arcseconds at a given MJD, linearly
+    /// interpolated.
+    ///
+    /// Outside the table the nearest entry is used; polar motion stays
+    /// within about half an arcsecond, so no error or warning is raised.
+    pub fn polar_motion_at_mjd(&self, mjd: f64) -> (f64, f64) {
+        let [xp, yp] = self.interpolate_clamped(mjd, |e| [e.xp, e.yp]);
+        (xp, yp)
+    }
+
+    /// Celestial pole offsets `(dX, dY)` in arcseconds at a given MJD,
+    /// linearly interpolated and clamped like [`Self::polar_motion_at_mjd`].
+    ///
+    /// These are the observed corrections to the IAU 2006/2000A CIP
+    /// coordinates; the offsets are a fraction of a milliarcsecond.
+    pub fn celestial_pole_offsets_at_mjd(&self, mjd: f64) -> (f64, f64) {
+        let [dx, dy] = self.interpolate_clamped(mjd, |e| [e.dx, e.dy]);
+        (dx, dy)
+    }
+
+    /// Excess length of day in seconds at a given MJD, linearly interpolated
+    /// and clamped like [`Self::polar_motion_at_mjd`].
+    pub fn lod_at_mjd(&self, mjd: f64) -> f64 {
+        let [lod] = self.interpolate_clamped(mjd, |e| [e.lod]);
+        lod
+    }
+
+    /// Linear interpolation of per-entry values, holding the nearest entry
+    /// outside the table.
+    fn interpolate_clamped<const N: usize>(
+        &self,
+        mjd: f64,
+        values: impl Fn(&EopEntry) -> [f64; N],
+    ) -> [f64; N] {
+        let idx = self
+            .entries
+            .partition_point(|e| e.mjd < mjd)
+            .saturating_sub(1);
+        let e0 = &self.entries[idx];
+        if mjd <= e0.mjd || idx + 1 >= self.entries.len() {
+            return values(e0);
+        }
+        let e1 = &self.entries[idx + 1];
+        let frac = (mjd - e0.mjd) / (e1.mjd - e0.mjd);
+        let (v0, v1) = (values(e0), values(e1));
+        std::array::from_fn(|i| v0[i] + frac * (v1[i] - v0[i]))
+    }
+
     /// DUT1 (UT1−UTC) in seconds at a given MJD, linearly interpolated.
     ///
     /// Default behavior:
@@ -406,6 +493,13 @@ impl EopData {
     }
 }
 
+/// Parse a fixed-width numeric column, reading blanks as zero.
+fn parse_column(line: &str, start: usize, end: usize) -> f64 {
+    line.get(start..end)
+        .and_then(|field| field.trim().parse().ok())
+        .unwrap_or(0.0)
+}
+
 fn source_rank(source: EopSource) -> u8 {
     match source {
         EopSource::C04Final => 0,
@@ -417,15 +511,13 @@ fn source_rank(source: EopSource) -> u8 {
 
 fn emit_warning_once(warning: &TimeWarning) {
     match warning {
-        TimeWarning::EopPreRangeFallback { .. } => {
-            if !EOP_PRE_RANGE_WARNED.swap(true, Ordering::Relaxed) {
-                eprintln!("Warning: {warning}");
-            }
+        TimeWarning::EopPreRangeFallback { .. }
+            if !EOP_PRE_RANGE_WARNED.swap(true, Ordering::Relaxed) =>
+        {
+            eprintln!("Warning: {warning}");
         }
-        TimeWarning::EopFutureFrozen { .. } => {
-            if !EOP_FUTURE_WARNED.swap(true, Ordering::Relaxed) {
-                eprintln!("Warning: {warning}");
-            }
+        TimeWarning::EopFutureFrozen { .. } if !EOP_FUTURE_WARNED.swap(true, Ordering::Relaxed) => {
+            eprintln!("Warning: {warning}");
         }
         _ => {}
     }
@@ -496,6 +588,22 @@ impl EopKernel {
         self.data.utc_to_ut1_jd(jd_utc)
     }
 
+    /// Pole coordinates `(x_p, y_p)` in arcseconds at a UTC Julian Date.
+    pub fn polar_motion_arcsec(&self, jd_utc: f64) -> (f64, f64) {
+        self.data.polar_motion_at_mjd(jd_utc - 2_400_000.5)
+    }
+
+    /// Celestial pole offsets `(dX, dY)` in arcseconds at a UTC Julian Date.
+    pub fn celestial_pole_offsets_arcsec(&self, jd_utc: f64) -> (f64, f64) {
+        self.data
+            .celestial_pole_offsets_at_mjd(jd_utc - 2_400_000.5)
+    }
+
+    /// Excess length of day in seconds at a UTC Julian Date.
+    pub fn lod_seconds(&self, jd_utc: f64) -> f64 {
+        self.data.lod_at_mjd(jd_utc - 2_400_000.5)
+    }
+
     /// Strict UTC->UT1 conversion (errors for future out-of-range dates).
     pub fn utc_to_ut1_jd_strict(&self, jd_utc: f64) -> Result<f64, TimeError> {
         self.data.utc_to_ut1_jd_strict(jd_utc)
@@ -527,7 +635,7 @@ mod tests {
 
     // A minimal hand-crafted finals snippet (3 lines).
     fn finals_snippet() -> String {
-        vec![
+        [
             finals_line(60000.0, 'I', 0.1234567),
             finals_line(60001.0, 'I', 0.2345678),
             finals_line(60002.0, 'P', -0.1000000),
@@ -635,7 +743,7 @@ mod tests {
 
     #[test]
     fn merged_precedence_prefers_c04_over_finals_i_and_p() {
-        let finals = vec![
+        let finals = [
             finals_line(60000.0, 'I', 0.2000000),
             finals_line(60001.0, 'P', 0.5000000),
         ]
@@ -646,10 +754,64 @@ mod tests {
         assert_eq!(data.dut1_at_mjd(60001.0).unwrap(), 0.11);
     }
 
+    #[test]
+    fn polar_motion_interpolates_and_clamps() {
+        let mut lines = [
+            finals_line(60000.0, 'I', 0.1),
+            finals_line(60001.0, 'I', 0.2),
+        ];
+        for (line, (xp, yp)) in lines.iter_mut().zip([(0.1, 0.3), (0.2, 0.5)]) {
+            line.replace_range(18..27, &format!("{xp:9.6}"));
+            line.replace_range(37..46, &format!("{yp:9.6}"));
+        }
+        let data = EopData::parse_finals(&lines.join("\n")).unwrap();
+        let (xp, yp) = data.polar_motion_at_mjd(60000.25);
+        assert!((xp - 0.125).abs() < 1e-12 && (yp - 0.35).abs() < 1e-12);
+        assert_eq!(data.polar_motion_at_mjd(59000.0), (0.1, 0.3));
+        assert_eq!(data.polar_motion_at_mjd(61000.0), (0.2, 0.5));
+
+        let c04 = EopData::parse_c04("2020 01 01 58849 0.076 0.282 -0.177 0 0 0").unwrap();
+        assert_eq!(c04.polar_motion_at_mjd(58849.0), (0.076, 0.282));
+    }
+
+    #[test]
+    fn pole_offsets_and_lod_parse_and_interpolate() {
+        let mut lines = [
+            finals_line(60000.0, 'I', 0.1),
+            finals_line(60001.0, 'I', 0.2),
+        ];
+        for (line, (lod_ms, dx_mas, dy_mas)) in
+            lines.iter_mut().zip([(1.2, 0.3, -0.1), (0.8, 0.5, -0.3)])
+        {
+            line.push_str(&" ".repeat(125 - line.len()));
+            line.replace_range(79..86, &format!("{lod_ms:7.4}"));
+            line.replace_range(97..106, &format!("{dx_mas:9.3}"));
+            line.replace_range(116..125, &format!("{dy_mas:9.3}"));
+        }
+        let data = EopData::parse_finals(&lines.join("\n")).unwrap();
+        let (dx, dy) = data.celestial_pole_offsets_at_mjd(60000.5);
+        assert!((dx - 0.0004).abs() < 1e-12 && (dy + 0.0002).abs() < 1e-12);
+        assert!((data.lod_at_mjd(60000.5) - 0.001).abs() < 1e-12);
+        assert_eq!(data.lod_at_mjd(62000.0), 0.0008);
+
+        // Short finals rows (no LOD or offsets) read as zero.
+        let short = EopData::parse_finals(&finals_snippet()).unwrap();
+        assert_eq!(short.celestial_pole_offsets_at_mjd(60001.0), (0.0, 0.0));
+
+        let c04 =
+            EopData::parse_c04("2020 01 01 58849 0.076 0.282 -0.177 0.0004 0.00012 -0.00008 0 0")
+                .unwrap();
+        assert_eq!(c04.lod_at_mjd(58849.0), 0.0004);
+        assert_eq!(
+            c04.celestial_pole_offsets_at_mjd(58849.0),
+            (0.00012, -0.00008)
+        );
+    }
+
     #[test]
     fn merged_uses_daily_predicted_when_primary_older() {
         let finals_primary = finals_line(61000.0, 'I', 0.1000000);
-        let finals_daily = vec![
+        let finals_daily = [
             finals_line(61000.0, 'I', 0.1000000),
             finals_line(61001.0, 'P', 0.1200000),
         ]
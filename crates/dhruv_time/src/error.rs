@@ -19,6 +19,18 @@ pub enum TimeError {
     Pre1972Utc,
     /// UTC calendar instant is invalid.
     InvalidUtc(String),
+    /// Time zone name is not in the tz database.
+    UnknownTimeZone(String),
+    /// TZif zone data parsing failed.
+    TzifParse(String),
+    /// Local calendar time is invalid or cannot be parsed.
+    InvalidLocalTime(String),
+    /// Local time occurs twice (clocks set back) and no disambiguation
+    /// was requested.
+    AmbiguousLocalTime(String),
+    /// Local time was skipped (clocks set forward) and no disambiguation
+    /// was requested.
+    NonexistentLocalTime(String),
 }
 
 impl Display for TimeError {
@@ -30,6 +42,11 @@ impl Display for TimeError {
             Self::Io(msg) => write!(f, "I/O error: {msg}"),
             Self::Pre1972Utc => write!(f, "UTC before 1972-Jan-01 is not supported"),
             Self::InvalidUtc(msg) => write!(f, "invalid UTC instant: {msg}"),
+            Self::UnknownTimeZone(name) => write!(f, "unknown time zone: {name}"),
+            Self::TzifParse(msg) => write!(f, "TZif parse error: {msg}"),
+            Self::InvalidLocalTime(msg) => write!(f, "invalid local time: {msg}"),
+            Self::AmbiguousLocalTime(msg) => write!(f, "ambiguous local time: {msg}"),
+            Self::NonexistentLocalTime(msg) => write!(f, "nonexistent local time: {msg}"),
         }
     }
 }
@@ -10,20 +10,15 @@ pub const J2000_JD: f64 = 2_451_545.0;
 pub const SECONDS_PER_DAY: f64 = 86_400.0;
 
 /// Calendar policy for historical dates.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CalendarPolicy {
     /// Extend Gregorian calendar rules to all epochs.
+    #[default]
     ProlepticGregorian,
     /// Use historical Gregorian cutover (1582-10-15).
     GregorianCutover1582,
 }
 
-impl Default for CalendarPolicy {
-    fn default() -> Self {
-        Self::ProlepticGregorian
-    }
-}
-
 /// Two-part Julian Date representation (`jd = day + frac`) where
 /// `frac` is kept near zero to reduce cancellation error.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -4,16 +4,21 @@
 //! - Julian Date ↔ calendar conversions
 //! - LSK (Leapseconds Kernel) file parsing
 //! - UTC → TAI → TT → TDB conversion chain (and inverse)
+//! - Selectable TT → TDB model (NAIF one-term or Fairhead–Bretagnon series)
 //! - An `Epoch` type for type-safe TDB epoch handling
+//! - Local civil time in IANA zones from an embedded tz database
 
 pub mod delta_t;
 pub mod diagnostics;
 pub mod eop;
 pub mod error;
 pub mod julian;
+pub mod local_time;
 pub mod lsk;
 pub mod scales;
 pub mod sidereal;
+pub mod tdb;
+pub mod tz;
 pub mod utc_time;
 
 use std::path::Path;
@@ -32,11 +37,19 @@ pub use julian::{
     calendar_to_jd_with_policy, jd_to_calendar, jd_to_calendar_with_policy, jd_to_tdb_seconds,
     tdb_seconds_to_jd,
 };
+pub use local_time::{Disambiguation, LocalTime, LocalTimeResolution, ResolvedLocalTime};
 pub use lsk::LskData;
 pub use scales::{
-    FutureDeltaTTransition, TimeConversionOptions, TimeConversionPolicy, UtcToTdbResult,
+    FutureDeltaTTransition, Pre1972UtcMode, TimeConversionOptions, TimeConversionPolicy,
+    UtcToTdbResult, utc_drift_tai_minus_utc,
 };
-pub use sidereal::{earth_rotation_angle_rad, gmst_rad, local_sidereal_time_rad};
+pub use sidereal::{
+    earth_rotation_angle_rad, equation_of_equinoxes_complementary_rad, equation_of_equinoxes_rad,
+    equation_of_equinoxes_rad_with_model, equation_of_origins_rad, gast_rad, gmst_rad,
+    gmst_rad_with_model, last_rad, local_sidereal_time_rad,
+};
+pub use tdb::{TdbModel, TdbObserver, fairhead_bretagnon_tdb_minus_tt};
+pub use tz::{LocalTimeType, TimeZone, available_time_zones, tzdata_version};
 pub use utc_time::UtcTime;
 
 /// A loaded leap-second kernel, ready for time conversions.
@@ -97,24 +110,66 @@ impl LeapSecondKernel {
 
 /// A TDB epoch represented as seconds past J2000.0.
 ///
-/// This is the primary time type used throughout the engine.
-/// It wraps an `f64` providing type safety and convenient conversions.
+/// This is the primary time type used throughout the engine. The seconds
+/// are held in two parts (whole seconds plus a fraction in `[0, 1)`), so an
+/// epoch keeps sub-nanosecond resolution at any date a kernel covers; a
+/// single `f64` Julian Date only resolves ~40 µs.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Epoch {
-    tdb_seconds: f64,
+    whole_seconds: f64,
+    fraction: f64,
 }
 
 impl Epoch {
+    /// Normalize `whole + extra` so the fraction lies in `[0, 1)`.
+    fn normalized(whole_seconds: f64, extra_seconds: f64) -> Self {
+        let carry = extra_seconds.floor();
+        let mut out = Self {
+            whole_seconds: whole_seconds + carry,
+            fraction: extra_seconds - carry,
+        };
+        if out.fraction >= 1.0 {
+            out.whole_seconds += 1.0;
+            out.fraction -= 1.0;
+        }
+        out
+    }
+
     /// Create an epoch from TDB seconds past J2000.0.
     pub fn from_tdb_seconds(s: f64) -> Self {
-        Self { tdb_seconds: s }
+        Self::normalized(0.0, s)
+    }
+
+    /// Create an epoch from TDB seconds past J2000.0 split as
+    /// `whole_seconds + fraction` (any split).
+    pub fn from_tdb_seconds_parts(whole_seconds: f64, fraction: f64) -> Self {
+        let whole = whole_seconds.floor();
+        Self::normalized(whole, (whole_seconds - whole) + fraction)
     }
 
     /// Create an epoch from a Julian Date in TDB.
     pub fn from_jd_tdb(jd: f64) -> Self {
-        Self {
-            tdb_seconds: jd_to_tdb_seconds(jd),
-        }
+        Self::from_two_part_jd(TwoPartJd::from_jd(jd))
+    }
+
+    /// Create an epoch from a Julian Date in TDB split as `jd1 + jd2`
+    /// (any split; e.g. day number plus fraction of day).
+    pub fn from_jd_tdb_parts(jd1: f64, jd2: f64) -> Self {
+        let split = |days: f64| {
+            let whole_days = days.floor();
+            (
+                whole_days * SECONDS_PER_DAY,
+                (days - whole_days) * SECONDS_PER_DAY,
+            )
+        };
+        let (w1, f1) = split(jd1 - J2000_JD);
+        let (w2, f2) = split(jd2);
+        Self::normalized(w1 + w2, f1 + f2)
+    }
+
+    /// Create an epoch from a two-part Julian Date in TDB.
+    pub fn from_two_part_jd(jd: TwoPartJd) -> Self {
+        Self::from_jd_tdb_parts(jd.day, jd.frac)
     }
 
     /// Create an epoch from a UTC calendar date using an LSK for leap seconds.
@@ -131,17 +186,54 @@ impl Epoch {
         let jd = calendar_to_jd(year, month, day_frac);
         let utc_s = jd_to_tdb_seconds(jd); // Note: this is UTC seconds past J2000, not TDB
         let tdb_s = lsk.utc_to_tdb(utc_s);
-        Self { tdb_seconds: tdb_s }
+        Self::from_tdb_seconds(tdb_s)
     }
 
     /// TDB seconds past J2000.0.
     pub fn as_tdb_seconds(self) -> f64 {
-        self.tdb_seconds
+        self.whole_seconds + self.fraction
+    }
+
+    /// Whole TDB seconds past J2000.0 and the fraction in `[0, 1)`.
+    pub fn as_tdb_seconds_parts(self) -> (f64, f64) {
+        (self.whole_seconds, self.fraction)
     }
 
     /// Julian Date in TDB.
     pub fn as_jd_tdb(self) -> f64 {
-        tdb_seconds_to_jd(self.tdb_seconds)
+        self.to_two_part_jd().to_jd()
+    }
+
+    /// Two-part Julian Date in TDB (whole day plus fraction).
+    pub fn to_two_part_jd(self) -> TwoPartJd {
+        let days = (self.whole_seconds / SECONDS_PER_DAY).floor();
+        let rem_seconds = self.whole_seconds - days * SECONDS_PER_DAY;
+        TwoPartJd {
+            day: J2000_JD + days,
+            frac: (rem_seconds + self.fraction) / SECONDS_PER_DAY,
+        }
+        .normalized()
+    }
+
+    /// This epoch shifted by `seconds`.
+    pub fn add_seconds(self, seconds: f64) -> Self {
+        let whole = seconds.floor();
+        Self::normalized(
+            self.whole_seconds + whole,
+            self.fraction + (seconds - whole),
+        )
+    }
+
+    /// This epoch shifted by `days`.
+    pub fn add_days(self, days: f64) -> Self {
+        let whole_days = days.floor();
+        self.add_seconds((days - whole_days) * SECONDS_PER_DAY)
+            .add_seconds(whole_days * SECONDS_PER_DAY)
+    }
+
+    /// Seconds from `earlier` to this epoch.
+    pub fn seconds_since(self, earlier: Epoch) -> f64 {
+        (self.whole_seconds - earlier.whole_seconds) + (self.fraction - earlier.fraction)
     }
 }
 
@@ -161,4 +253,40 @@ mod tests {
         let epoch = Epoch::from_jd_tdb(J2000_JD);
         assert_eq!(epoch.as_tdb_seconds(), 0.0);
     }
+
+    #[test]
+    fn epoch_two_part_keeps_microseconds() {
+        // 12.345678 µs after a date far from J2000, beyond f64 JD resolution.
+        let day = 2_816_788.5;
+        let offset_s = 12.345_678e-6;
+        let epoch = Epoch::from_jd_tdb_parts(day, offset_s / SECONDS_PER_DAY);
+        let base = Epoch::from_jd_tdb_parts(day, 0.0);
+        assert!((epoch.seconds_since(base) - offset_s).abs() < 1e-12);
+        let single = Epoch::from_jd_tdb(day + offset_s / SECONDS_PER_DAY);
+        assert!((single.seconds_since(base) - offset_s).abs() > 1e-7);
+
+        let parts = epoch.to_two_part_jd();
+        assert_eq!(parts.day, day.floor());
+        let back = Epoch::from_two_part_jd(parts);
+        assert!(back.seconds_since(epoch).abs() < 1e-9);
+
+        let later = epoch.add_seconds(-0.75).add_days(1.5);
+        assert!((later.seconds_since(epoch) - (1.5 * SECONDS_PER_DAY - 0.75)).abs() < 1e-12);
+        assert!(later > epoch);
+        let (whole, fraction) = later.as_tdb_seconds_parts();
+        assert_eq!(whole.fract(), 0.0);
+        assert!((0.0..1.0).contains(&fraction));
+    }
+
+    #[test]
+    fn epoch_from_seconds_parts_normalizes_any_split() {
+        let epoch = Epoch::from_tdb_seconds_parts(7.5e8 + 0.25, 1.5e-6);
+        let (whole, fraction) = epoch.as_tdb_seconds_parts();
+        assert_eq!(whole, 7.5e8);
+        assert!((fraction - (0.25 + 1.5e-6)).abs() < 1e-15);
+
+        let negative = Epoch::from_tdb_seconds_parts(-10.0, -0.25);
+        assert_eq!(negative.as_tdb_seconds_parts(), (-11.0, 0.75));
+        assert_eq!(epoch, Epoch::from_tdb_seconds_parts(whole, fraction));
+    }
 }
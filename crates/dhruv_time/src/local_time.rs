@@ -0,0 +1,581 @@
+//! Local civil time in a time zone and its resolution to UTC.
+//!
+//! A [`LocalTime`] is a wall-clock reading plus a [`TimeZone`]. Resolving it
+//! reports whether the reading is unique, repeated (clocks set back) or
+//! skipped (clocks set forward); [`Disambiguation`] decides which instant
+//! [`LocalTime::to_utc`] returns in the last two cases.
+//!
+//! Text form: `YYYY-MM-DDThh:mm[:ss[.f]]` followed by a zone designator:
+//! `Z`, a UTC offset (`+05:30`), an IANA zone in brackets
+//! (`[Asia/Kolkata]`), an offset and zone (`-04:00[America/New_York]`, the
+//! offset picking between repeated readings), or Local Mean Time for a
+//! longitude (`[LMT+77.209]`, degrees east positive).
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::TimeError;
+use crate::tz::{
+    LocalTimeType, TimeZone, UNIX_TO_J2000_SECONDS, civil_from_days, days_from_civil, format_offset,
+};
+use crate::utc_time::UtcTime;
+
+/// Choice of instant for a repeated or skipped local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Disambiguation {
+    /// Return an error.
+    #[default]
+    Reject,
+    /// The earlier of the two candidate instants.
+    Earlier,
+    /// The later of the two candidate instants. For a skipped reading this
+    /// is the reading taken with the pre-transition offset, i.e. the clock
+    /// time moved forward by the gap.
+    Later,
+    /// The candidate whose UTC offset (seconds east) matches.
+    Offset(i32),
+}
+
+/// Wall-clock reading in a time zone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: f64,
+    pub zone: TimeZone,
+    /// How [`LocalTime::to_utc`] treats repeated or skipped readings.
+    pub disambiguation: Disambiguation,
+}
+
+/// One UTC interpretation of a local reading.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedLocalTime {
+    pub utc: UtcTime,
+    /// Offset and designation in effect at `utc`.
+    pub local_time_type: LocalTimeType,
+}
+
+/// Outcome of resolving a local reading against its zone.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalTimeResolution {
+    /// Exactly one instant shows this reading.
+    Unique(ResolvedLocalTime),
+    /// The reading occurs twice; candidates in UTC order.
+    Ambiguous {
+        earlier: ResolvedLocalTime,
+        later: ResolvedLocalTime,
+    },
+    /// The reading was skipped. `earlier` reads it with the post-transition
+    /// offset, `later` with the pre-transition offset.
+    Gap {
+        earlier: ResolvedLocalTime,
+        later: ResolvedLocalTime,
+    },
+}
+
+impl LocalTime {
+    pub fn new(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: f64,
+        zone: TimeZone,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            zone,
+            disambiguation: Disambiguation::default(),
+        }
+    }
+
+    /// Set how repeated or skipped readings resolve.
+    pub fn with_disambiguation(mut self, disambiguation: Disambiguation) -> Self {
+        self.disambiguation = disambiguation;
+        self
+    }
+
+    /// Local reading of a UTC instant in `zone`.
+    pub fn from_utc(utc: &UtcTime, zone: TimeZone) -> Self {
+        let (whole, frac) = split_seconds(utc.second);
+        let utc_unix = minute_seconds(utc.year, utc.month, utc.day, utc.hour, utc.minute) + whole;
+        let offset = zone.type_at_unix(utc_unix).utc_offset_seconds;
+        let t = unix_to_utc(utc_unix + i64::from(offset), frac);
+        Self::new(t.year, t.month, t.day, t.hour, t.minute, t.second, zone)
+    }
+
+    /// Parse a local time, treating a reading without zone designator as
+    /// being in `default_zone`.
+    pub fn parse_with_default_zone(s: &str, default_zone: &TimeZone) -> Result<Self, TimeError> {
+        parse_local(s, Some(default_zone))
+    }
+
+    /// All UTC interpretations of this reading.
+    pub fn resolve(&self) -> Result<LocalTimeResolution, TimeError> {
+        UtcTime::new(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        )
+        .validate(None)
+        .map_err(|e| match e {
+            TimeError::InvalidUtc(msg) => TimeError::InvalidLocalTime(msg),
+            other => other,
+        })?;
+
+        // Whole seconds on the Unix time line; transitions fall on whole
+        // seconds, so the fraction only rides along.
+        let (whole, frac) = split_seconds(self.second);
+        let wall = minute_seconds(self.year, self.month, self.day, self.hour, self.minute) + whole;
+        // UTC offsets stay within ±26 h, so two days either side cover
+        // every period that can contain this reading.
+        let from = wall - 2 * 86_400;
+        let to = wall + 2 * 86_400;
+        let mut periods = vec![(i64::MIN, self.zone.type_at_unix(from))];
+        periods.extend(self.zone.transitions_between(from, to));
+
+        let ends = periods
+            .iter()
+            .skip(1)
+            .map(|p| p.0)
+            .chain(std::iter::once(i64::MAX));
+        let hits: Vec<i64> = periods
+            .iter()
+            .zip(ends)
+            .filter_map(|(&(start, ty), end)| {
+                let utc = wall - i64::from(ty.utc_offset_seconds);
+                (utc >= start && utc < end).then_some(utc)
+            })
+            .collect();
+
+        let resolved = |utc_unix: i64| ResolvedLocalTime {
+            utc: unix_to_utc(utc_unix, frac),
+            local_time_type: self.zone.type_at_unix(utc_unix).clone(),
+        };
+        match hits[..] {
+            [utc] => Ok(LocalTimeResolution::Unique(resolved(utc))),
+            [first, .., last] => Ok(LocalTimeResolution::Ambiguous {
+                earlier: resolved(first),
+                later: resolved(last),
+            }),
+            [] => {
+                let gap = periods.windows(2).find(|w| {
+                    let (start, after) = w[1];
+                    wall - i64::from(w[0].1.utc_offset_seconds) >= start
+                        && wall - i64::from(after.utc_offset_seconds) < start
+                });
+                let Some(w) = gap else {
+                    return Err(TimeError::InvalidLocalTime(format!(
+                        "{self} has no UTC interpretation"
+                    )));
+                };
+                Ok(LocalTimeResolution::Gap {
+                    earlier: resolved(wall - i64::from(w[1].1.utc_offset_seconds)),
+                    later: resolved(wall - i64::from(w[0].1.utc_offset_seconds)),
+                })
+            }
+        }
+    }
+
+    /// UTC instant of this reading, applying [`LocalTime::disambiguation`].
+    pub fn to_utc(&self) -> Result<UtcTime, TimeError> {
+        self.resolve_with_type().map(|r| r.utc)
+    }
+
+    /// UTC instant and the offset/designation in effect, applying
+    /// [`LocalTime::disambiguation`].
+    pub fn resolve_with_type(&self) -> Result<ResolvedLocalTime, TimeError> {
+        let describe = |a: &ResolvedLocalTime, b: &ResolvedLocalTime| {
+            format!(
+                "{self} ({} {} or {} {}); add the UTC offset, e.g. {}{}[{}]",
+                a.local_time_type.abbreviation,
+                format_offset(a.local_time_type.utc_offset_seconds),
+                b.local_time_type.abbreviation,
+                format_offset(b.local_time_type.utc_offset_seconds),
+                self.wall_text(),
+                format_offset(a.local_time_type.utc_offset_seconds),
+                self.zone.name(),
+            )
+        };
+        match self.resolve()? {
+            LocalTimeResolution::Unique(r) => match self.disambiguation {
+                Disambiguation::Offset(offset)
+                    if offset != r.local_time_type.utc_offset_seconds =>
+                {
+                    Err(TimeError::InvalidLocalTime(format!(
+                        "{self} has offset {}, not {}",
+                        format_offset(r.local_time_type.utc_offset_seconds),
+                        format_offset(offset)
+                    )))
+                }
+                _ => Ok(r),
+            },
+            LocalTimeResolution::Ambiguous { earlier, later } => match self.disambiguation {
+                Disambiguation::Reject => {
+                    Err(TimeError::AmbiguousLocalTime(describe(&earlier, &later)))
+                }
+                Disambiguation::Earlier => Ok(earlier),
+                Disambiguation::Later => Ok(later),
+                Disambiguation::Offset(offset) => [earlier, later]
+                    .into_iter()
+                    .find(|r| r.local_time_type.utc_offset_seconds == offset)
+                    .ok_or_else(|| {
+                        TimeError::InvalidLocalTime(format!(
+                            "{self} has no reading with offset {}",
+                            format_offset(offset)
+                        ))
+                    }),
+            },
+            LocalTimeResolution::Gap { earlier, later } => match self.disambiguation {
+                Disambiguation::Earlier => Ok(earlier),
+                Disambiguation::Later => Ok(later),
+                _ => Err(TimeError::NonexistentLocalTime(format!(
+                    "{self} falls in a transition gap (clocks moved from {} to {})",
+                    format_offset(earlier.local_time_type.utc_offset_seconds),
+                    format_offset(later.local_time_type.utc_offset_seconds),
+                ))),
+            },
+        }
+    }
+
+    fn wall_text(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second.floor() as u32
+        )
+    }
+}
+
+impl Display for LocalTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.wall_text(), self.zone.name())
+    }
+}
+
+impl TryFrom<&LocalTime> for UtcTime {
+    type Error = TimeError;
+
+    fn try_from(local: &LocalTime) -> Result<Self, Self::Error> {
+        local.to_utc()
+    }
+}
+
+/// Parse a local time; a zone designator is required.
+impl FromStr for LocalTime {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_local(s, None)
+    }
+}
+
+/// Unix seconds of a calendar minute (no leap seconds).
+fn minute_seconds(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+    days_from_civil(i64::from(year), month, day) * 86_400
+        + i64::from(hour) * 3600
+        + i64::from(minute) * 60
+}
+
+fn split_seconds(second: f64) -> (i64, f64) {
+    let whole = second.floor();
+    (whole as i64, second - whole)
+}
+
+fn unix_to_utc(whole: i64, frac: f64) -> UtcTime {
+    let (year, month, day) = civil_from_days(whole.div_euclid(86_400));
+    let sod = whole.rem_euclid(86_400);
+    UtcTime::new(
+        year as i32,
+        month,
+        day,
+        (sod / 3600) as u32,
+        (sod / 60 % 60) as u32,
+        (sod % 60) as f64 + frac,
+    )
+}
+
+impl ResolvedLocalTime {
+    /// UTC seconds past J2000 of this interpretation.
+    pub fn utc_seconds(&self) -> f64 {
+        let u = &self.utc;
+        (minute_seconds(u.year, u.month, u.day, u.hour, u.minute) - UNIX_TO_J2000_SECONDS) as f64
+            + u.second
+    }
+}
+
+fn parse_local(s: &str, default_zone: Option<&TimeZone>) -> Result<LocalTime, TimeError> {
+    let err = |why: &str| TimeError::InvalidLocalTime(format!("{why}: {s}"));
+    let s = s.trim();
+
+    // Date: [sign]YYYY-MM-DD, then 'T' or ' '.
+    let sep = s
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| c == 'T' || c == ' ')
+        .map(|(i, _)| i)
+        .ok_or_else(|| err("expected YYYY-MM-DDThh:mm[:ss]"))?;
+    let (date, rest) = (&s[..sep], &s[sep + 1..]);
+    let (sign, digits) = match date.strip_prefix('-') {
+        Some(d) => (-1, d),
+        None => (1, date.strip_prefix('+').unwrap_or(date)),
+    };
+    let mut date_parts = digits.split('-');
+    let mut field = |what: &str| -> Result<u32, TimeError> {
+        date_parts
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(|| err(&format!("invalid {what}")))
+    };
+    let year = sign * field("year")? as i32;
+    let month = field("month")?;
+    let day = field("day")?;
+    if date_parts.next().is_some() {
+        return Err(err("invalid date"));
+    }
+
+    // Time, then designator.
+    let zone_at = rest.find(['Z', 'z', '+', '-', '[']).unwrap_or(rest.len());
+    let (time, designator) = (&rest[..zone_at], &rest[zone_at..]);
+    let mut time_parts = time.split(':');
+    let hour: u32 = time_parts
+        .next()
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| err("invalid hour"))?;
+    let minute: u32 = time_parts
+        .next()
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| err("invalid minute"))?;
+    let second: f64 = match time_parts.next() {
+        Some(v) => v.parse().map_err(|_| err("invalid second"))?,
+        None => 0.0,
+    };
+    if time_parts.next().is_some() {
+        return Err(err("invalid time"));
+    }
+
+    let (zone, disambiguation) = parse_designator(designator, default_zone)
+        .ok_or_else(|| err("invalid or missing zone designator"))??;
+    Ok(LocalTime::new(year, month, day, hour, minute, second, zone)
+        .with_disambiguation(disambiguation))
+}
+
+/// Zone and disambiguation from `Z`, `±hh[:mm[:ss]]`, `[Zone]` or
+/// `±hh:mm[Zone]`. `None` when malformed.
+fn parse_designator(
+    designator: &str,
+    default_zone: Option<&TimeZone>,
+) -> Option<Result<(TimeZone, Disambiguation), TimeError>> {
+    if designator.is_empty() {
+        return default_zone.map(|z| Ok((z.clone(), Disambiguation::default())));
+    }
+    if designator.eq_ignore_ascii_case("z") {
+        return Some(Ok((TimeZone::utc(), Disambiguation::default())));
+    }
+    let (offset_text, bracket) = match designator.find('[') {
+        Some(i) => (&designator[..i], Some(&designator[i..])),
+        None => (designator, None),
+    };
+    let offset = if offset_text.is_empty() {
+        None
+    } else {
+        Some(parse_offset(offset_text)?)
+    };
+    let Some(bracket) = bracket else {
+        return Some(Ok((TimeZone::fixed(offset?), Disambiguation::default())));
+    };
+    let name = bracket.strip_prefix('[')?.strip_suffix(']')?;
+    let zone = match name.strip_prefix("LMT") {
+        Some(lon) if !lon.is_empty() => Ok(TimeZone::local_mean_time(lon.parse().ok()?)),
+        _ => TimeZone::get(name),
+    };
+    let disambiguation = offset.map_or(Disambiguation::default(), Disambiguation::Offset);
+    Some(zone.map(|z| (z, disambiguation)))
+}
+
+/// `±hh`, `±hhmm`, `±hh:mm` or `±hh:mm:ss` in seconds east of UTC.
+fn parse_offset(text: &str) -> Option<i32> {
+    let (sign, body) = match text.as_bytes().first()? {
+        b'+' => (1, &text[1..]),
+        b'-' => (-1, &text[1..]),
+        _ => return None,
+    };
+    let parts: Vec<&str> = if body.contains(':') {
+        body.split(':').collect()
+    } else if body.len() == 4 {
+        vec![&body[..2], &body[2..]]
+    } else {
+        vec![body]
+    };
+    if parts.is_empty() || parts.len() > 3 || parts.iter().any(|p| p.len() != 2) {
+        return None;
+    }
+    let mut seconds = 0;
+    for (part, scale) in parts.iter().zip([3600, 60, 1]) {
+        let v: i32 = part.parse().ok()?;
+        let max = if scale == 3600 { 26 } else { 59 };
+        if v > max {
+            return None;
+        }
+        seconds += v * scale;
+    }
+    Some(sign * seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(name: &str) -> TimeZone {
+        TimeZone::get(name).unwrap()
+    }
+
+    fn hms(utc: &UtcTime) -> (i32, u32, u32, u32, u32, f64) {
+        (
+            utc.year, utc.month, utc.day, utc.hour, utc.minute, utc.second,
+        )
+    }
+
+    #[test]
+    fn unique_reading_uses_historical_offset() {
+        // Indian wartime (UTC+06:30) in 1943 versus IST today.
+        let war = LocalTime::new(1943, 6, 1, 12, 0, 0.0, zone("Asia/Kolkata"));
+        assert_eq!(hms(&war.to_utc().unwrap()), (1943, 6, 1, 5, 30, 0.0));
+        let now = LocalTime::new(2024, 6, 1, 12, 0, 30.25, zone("Asia/Kolkata"));
+        assert_eq!(hms(&now.to_utc().unwrap()), (2024, 6, 1, 6, 30, 30.25));
+    }
+
+    #[test]
+    fn repeated_reading_is_reported_and_disambiguated() {
+        // US fall back: 01:30 occurs in EDT and again in EST.
+        let local = LocalTime::new(2024, 11, 3, 1, 30, 0.0, zone("America/New_York"));
+        let LocalTimeResolution::Ambiguous { earlier, later } = local.resolve().unwrap() else {
+            panic!("expected ambiguity");
+        };
+        assert_eq!(earlier.local_time_type.abbreviation, "EDT");
+        assert_eq!(hms(&earlier.utc), (2024, 11, 3, 5, 30, 0.0));
+        assert_eq!(hms(&later.utc), (2024, 11, 3, 6, 30, 0.0));
+        assert!(matches!(
+            local.to_utc(),
+            Err(TimeError::AmbiguousLocalTime(_))
+        ));
+        let later_utc = local
+            .clone()
+            .with_disambiguation(Disambiguation::Later)
+            .to_utc()
+            .unwrap();
+        assert_eq!(later_utc, later.utc);
+        let by_offset = local
+            .with_disambiguation(Disambiguation::Offset(-4 * 3600))
+            .to_utc()
+            .unwrap();
+        assert_eq!(by_offset, earlier.utc);
+    }
+
+    #[test]
+    fn skipped_reading_is_reported() {
+        // US spring forward: 02:30 does not exist.
+        let local = LocalTime::new(2024, 3, 10, 2, 30, 0.0, zone("America/New_York"));
+        let LocalTimeResolution::Gap { earlier, later } = local.resolve().unwrap() else {
+            panic!("expected gap");
+        };
+        assert_eq!(hms(&earlier.utc), (2024, 3, 10, 6, 30, 0.0));
+        assert_eq!(hms(&later.utc), (2024, 3, 10, 7, 30, 0.0));
+        assert_eq!(later.local_time_type.abbreviation, "EDT");
+        assert!(matches!(
+            local.to_utc(),
+            Err(TimeError::NonexistentLocalTime(_))
+        ));
+        // Far-future transitions come from the POSIX footer rule.
+        let future = LocalTime::new(2090, 3, 12, 2, 30, 0.0, zone("America/New_York"));
+        assert!(matches!(
+            future.resolve().unwrap(),
+            LocalTimeResolution::Gap { .. }
+        ));
+    }
+
+    #[test]
+    fn local_mean_time_before_standard_time() {
+        let local = LocalTime::new(1850, 1, 1, 12, 0, 0.0, TimeZone::local_mean_time(77.209));
+        // 77.209° E = +05:08:50.
+        assert_eq!(hms(&local.to_utc().unwrap()), (1850, 1, 1, 6, 51, 10.0));
+        // Asia/Kolkata starts with Kolkata's LMT until 1854.
+        let zone = zone("Asia/Kolkata").with_local_mean_time(77.209);
+        let in_zone = LocalTime::new(1850, 1, 1, 12, 0, 0.0, zone);
+        assert_eq!(in_zone.to_utc().unwrap(), local.to_utc().unwrap());
+    }
+
+    #[test]
+    fn parses_designators() {
+        let utc: LocalTime = "2024-03-20T12:00:00Z".parse().unwrap();
+        assert_eq!(hms(&utc.to_utc().unwrap()), (2024, 3, 20, 12, 0, 0.0));
+
+        let fixed: LocalTime = "2024-03-20T12:00:00+05:30".parse().unwrap();
+        assert_eq!(hms(&fixed.to_utc().unwrap()), (2024, 3, 20, 6, 30, 0.0));
+
+        let named: LocalTime = "1943-06-01 12:00[Asia/Kolkata]".parse().unwrap();
+        assert_eq!(hms(&named.to_utc().unwrap()), (1943, 6, 1, 5, 30, 0.0));
+
+        let picked: LocalTime = "2024-11-03T01:30:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+        assert_eq!(hms(&picked.to_utc().unwrap()), (2024, 11, 3, 6, 30, 0.0));
+
+        let lmt: LocalTime = "1880-01-01T12:00:00[LMT-73.5]".parse().unwrap();
+        assert_eq!(hms(&lmt.to_utc().unwrap()), (1880, 1, 1, 16, 54, 0.0));
+
+        let negative: LocalTime = "-500-06-15T00:00:00Z".parse().unwrap();
+        assert_eq!(negative.year, -500);
+
+        assert!("2024-03-20T12:00:00".parse::<LocalTime>().is_err());
+        assert!(matches!(
+            "2024-03-20T12:00:00[Nowhere/Land]".parse::<LocalTime>(),
+            Err(TimeError::UnknownTimeZone(_))
+        ));
+        assert!("2024-03-20T12:00:00+5".parse::<LocalTime>().is_err());
+        assert!(
+            "2024-02-30T12:00:00Z"
+                .parse::<LocalTime>()
+                .unwrap()
+                .to_utc()
+                .is_err()
+        );
+
+        let default =
+            LocalTime::parse_with_default_zone("2024-03-20T12:00", &TimeZone::utc()).unwrap();
+        assert_eq!(default.zone.name(), "UTC");
+    }
+
+    #[test]
+    fn from_utc_roundtrip() {
+        let zone = zone("Europe/London");
+        let utc = UtcTime::new(2024, 7, 1, 23, 30, 15.5);
+        let local = LocalTime::from_utc(&utc, zone);
+        assert_eq!((local.day, local.hour, local.minute), (2, 0, 30));
+        assert_eq!(local.to_utc().unwrap(), utc);
+        let resolved = local.resolve_with_type().unwrap();
+        assert_eq!(resolved.local_time_type.abbreviation, "BST");
+        let expected = crate::julian::jd_to_tdb_seconds(crate::julian::calendar_to_jd(
+            2024,
+            7,
+            1.0 + (23.0 * 3600.0 + 30.0 * 60.0 + 15.5) / 86_400.0,
+        ));
+        assert!((resolved.utc_seconds() - expected).abs() < 1e-4);
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! All internal representations use f64 seconds past J2000.0.
 //!
-//! Reference: NAIF Time Required Reading, IAU 1991 recommendations.
-//! Implementation is original.
+//! UTC between 1961-01-01 and 1972-01-01 ran at a frequency offset from TAI
+//! with fractional steps ("rubber seconds"); TAI−UTC for that era comes from
+//! the built-in drift-rate table rather than the LSK.
+//!
+//! Reference: NAIF Time Required Reading, IAU 1991 recommendations, USNO
+//! `tai-utc.dat` (1961–1971 drift table). Implementation is original.
 
 use crate::delta_t::{
     DeltaTModel, SmhFutureParabolaFamily, delta_t_seconds_with_model,
@@ -13,6 +17,7 @@ use crate::diagnostics::{TimeDiagnostics, TimeWarning, TtUtcSource};
 use crate::eop::{EopData, EopLookupOptions};
 use crate::julian::{jd_to_tdb_seconds, tdb_seconds_to_jd};
 use crate::lsk::LskData;
+use crate::tdb::{TdbModel, TdbObserver, fairhead_bretagnon_tdb_minus_tt};
 
 /// Options for hybrid UTC->TDB conversion.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -33,6 +38,12 @@ pub struct TimeConversionOptions {
     /// fallback is active for `Smh2016WithPre720Quadratic` under bridge
     /// transition strategy.
     pub smh_future_family: SmhFutureParabolaFamily,
+    /// Treatment of UTC epochs before the LSK leap-second table.
+    pub pre_1972_utc: Pre1972UtcMode,
+    /// TT->TDB model.
+    pub tdb_model: TdbModel,
+    /// Observer for the topocentric TDB term (Fairhead-Bretagnon only).
+    pub tdb_observer: Option<TdbObserver>,
 }
 
 impl Default for TimeConversionOptions {
@@ -45,31 +56,99 @@ impl Default for TimeConversionOptions {
             future_delta_t_transition: FutureDeltaTTransition::default(),
             future_transition_years: 100.0,
             smh_future_family: SmhFutureParabolaFamily::default(),
+            pre_1972_utc: Pre1972UtcMode::default(),
+            tdb_model: TdbModel::default(),
+            tdb_observer: None,
         }
     }
 }
 
+/// Treatment of UTC epochs before 1972-01-01 in the hybrid policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pre1972UtcMode {
+    /// 1961-01-01 to 1972-01-01: TAI−UTC from the official drift-rate table
+    /// ([`utc_drift_tai_minus_utc`]). Earlier civil times are treated as
+    /// UT1, so TT−UTC is the Delta-T model value (plus any configured DUT1
+    /// fallback).
+    #[default]
+    DriftTableThenUt1,
+    /// Treat every pre-1972 civil time as UT1 via the Delta-T model,
+    /// ignoring the drift table (behavior before the table was added).
+    DeltaTModel,
+}
+
+/// Official TAI−UTC segments for 1961-01-01 to 1972-01-01:
+/// `(start MJD, offset s, reference MJD, rate s/day)`, giving
+/// `TAI−UTC = offset + (MJD − reference) × rate` with MJD in UTC.
+const UTC_DRIFT_TABLE: [(f64, f64, f64, f64); 14] = [
+    (37300.0, 1.422_818_0, 37300.0, 0.001_296),
+    (37512.0, 1.372_818_0, 37300.0, 0.001_296),
+    (37665.0, 1.845_858_0, 37665.0, 0.001_123_2),
+    (38334.0, 1.945_858_0, 37665.0, 0.001_123_2),
+    (38395.0, 3.240_130_0, 38761.0, 0.001_296),
+    (38486.0, 3.340_130_0, 38761.0, 0.001_296),
+    (38639.0, 3.440_130_0, 38761.0, 0.001_296),
+    (38761.0, 3.540_130_0, 38761.0, 0.001_296),
+    (38820.0, 3.640_130_0, 38761.0, 0.001_296),
+    (38942.0, 3.740_130_0, 38761.0, 0.001_296),
+    (39004.0, 3.840_130_0, 38761.0, 0.001_296),
+    (39126.0, 4.313_170_0, 39126.0, 0.002_592),
+    (39887.0, 4.213_170_0, 39126.0, 0.002_592),
+    // End marker: 1972-01-01, where integral leap seconds begin.
+    (41317.0, 10.0, 41317.0, 0.0),
+];
+
+/// TAI−UTC in seconds from the 1961–1971 drift-rate table.
+///
+/// Returns `None` outside [1961-01-01, 1972-01-01) UTC.
+pub fn utc_drift_tai_minus_utc(utc_s: f64) -> Option<f64> {
+    let mjd = utc_s / 86_400.0 + 51_544.5;
+    let end = UTC_DRIFT_TABLE[UTC_DRIFT_TABLE.len() - 1].0;
+    if !(UTC_DRIFT_TABLE[0].0..end).contains(&mjd) {
+        return None;
+    }
+    let idx = UTC_DRIFT_TABLE.partition_point(|e| e.0 <= mjd) - 1;
+    let (_, offset, reference, rate) = UTC_DRIFT_TABLE[idx];
+    Some(offset + (mjd - reference) * rate)
+}
+
+/// TAI−UTC for a UTC epoch: the drift table before the first LSK entry,
+/// otherwise the LSK leap-second count.
+fn tai_minus_utc(utc_s: f64, lsk: &LskData) -> (f64, bool) {
+    let before_lsk = lsk
+        .leap_seconds
+        .first()
+        .is_none_or(|&(_, first_epoch)| utc_s < first_epoch);
+    if before_lsk && let Some(drift) = utc_drift_tai_minus_utc(utc_s) {
+        return (drift, true);
+    }
+    (lookup_delta_at(utc_s, lsk), false)
+}
+
 /// Future Delta-T transition behavior for UTC epochs after leap-table coverage.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FutureDeltaTTransition {
     /// Legacy frozen-compatible behavior:
     /// `TT-UTC = last DELTA_AT + DELTA_T_A`.
+    #[default]
     LegacyTtUtcBlend,
     /// Bridge from modern endpoint to selected asymptotic model over the
     /// configured transition window.
     BridgeFromModernEndpoint,
 }
 
-impl Default for FutureDeltaTTransition {
-    fn default() -> Self {
-        Self::LegacyTtUtcBlend
-    }
-}
-
 /// UTC->TDB conversion policy.
+///
+/// Regimes by UTC epoch:
+/// - 1972 onward: LSK leap seconds (`TT−UTC = DELTA_AT + 32.184`).
+/// - 1961–1971: the drift-rate table, in both policies.
+/// - Before 1961, `StrictLsk` uses TAI−UTC = 0; `HybridDeltaT` treats the
+///   civil time as UT1, so `TT−UTC` is the Delta-T model value, and reports
+///   [`TimeWarning::CivilTimeAsUt1`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TimeConversionPolicy {
-    /// Existing behavior: always use LSK leap-second lookup.
+    /// Existing behavior: always use LSK leap-second lookup (with the
+    /// 1961–1971 drift table before the LSK).
     StrictLsk,
     /// Hybrid behavior: use Delta-T fallback outside LSK coverage.
     HybridDeltaT(TimeConversionOptions),
@@ -107,8 +186,10 @@ pub fn lookup_delta_at(utc_seconds: f64, lsk: &LskData) -> f64 {
 }
 
 /// Convert UTC seconds past J2000 to TAI seconds past J2000.
+///
+/// Uses the drift-rate table for 1961–1971 epochs before the LSK table.
 pub fn utc_to_tai(utc_s: f64, lsk: &LskData) -> f64 {
-    utc_s + lookup_delta_at(utc_s, lsk)
+    utc_s + tai_minus_utc(utc_s, lsk).0
 }
 
 /// Convert TAI seconds past J2000 to TT (Terrestrial Time) seconds past J2000.
@@ -149,6 +230,46 @@ pub fn tdb_to_tt(tdb_s: f64, lsk: &LskData) -> f64 {
     tt
 }
 
+/// Convert TT to TDB seconds past J2000 with a selectable model.
+///
+/// `observer` adds the topocentric term under
+/// [`TdbModel::FairheadBretagnonTruncated`] and is ignored by the one-term formula.
+pub fn tt_to_tdb_with_model(
+    tt_s: f64,
+    lsk: &LskData,
+    model: TdbModel,
+    observer: Option<&TdbObserver>,
+) -> f64 {
+    match model {
+        TdbModel::NaifOneTerm => tt_to_tdb(tt_s, lsk),
+        TdbModel::FairheadBretagnonTruncated => {
+            tt_s + fairhead_bretagnon_tdb_minus_tt(tt_s, observer)
+        }
+    }
+}
+
+/// Inverse of [`tt_to_tdb_with_model`].
+///
+/// TDB − TT changes by under 1e-8 s per second, so the fixed-point
+/// iteration converges to well below a picosecond in three steps.
+pub fn tdb_to_tt_with_model(
+    tdb_s: f64,
+    lsk: &LskData,
+    model: TdbModel,
+    observer: Option<&TdbObserver>,
+) -> f64 {
+    match model {
+        TdbModel::NaifOneTerm => tdb_to_tt(tdb_s, lsk),
+        TdbModel::FairheadBretagnonTruncated => {
+            let mut tt = tdb_s;
+            for _ in 0..3 {
+                tt = tdb_s - fairhead_bretagnon_tdb_minus_tt(tt, observer);
+            }
+            tt
+        }
+    }
+}
+
 fn solve_kepler(m: f64, e: f64) -> f64 {
     // Fixed-point solve of E = M + e*sin(E).
     let mut ecc_anom = m;
@@ -189,14 +310,18 @@ pub fn utc_to_tdb_with_policy_and_eop(
 ) -> UtcToTdbResult {
     match policy {
         TimeConversionPolicy::StrictLsk => {
-            let delta_at = lookup_delta_at(utc_s, lsk);
+            let (delta_at, drift) = tai_minus_utc(utc_s, lsk);
             let tt_minus_utc = delta_at + lsk.delta_t_a;
             UtcToTdbResult {
                 tdb_seconds: utc_to_tdb(utc_s, lsk),
                 diagnostics: TimeDiagnostics {
                     warnings: Vec::new(),
                     tt_minus_utc_s: tt_minus_utc,
-                    source: TtUtcSource::LskDeltaAt,
+                    source: if drift {
+                        TtUtcSource::UtcDriftTable
+                    } else {
+                        TtUtcSource::LskDeltaAt
+                    },
                 },
             }
         }
@@ -219,7 +344,26 @@ pub fn utc_to_tdb_with_policy_and_eop(
                 let (_, first_epoch) = table[0];
                 let (last_delta_at, last_epoch) = table[table.len() - 1];
 
-                if utc_s < first_epoch {
+                let drift = match options.pre_1972_utc {
+                    Pre1972UtcMode::DriftTableThenUt1 if utc_s < first_epoch => {
+                        utc_drift_tai_minus_utc(utc_s)
+                    }
+                    _ => None,
+                };
+
+                if let Some(tai_minus_utc) = drift {
+                    if options.warn_on_fallback {
+                        warnings.push(TimeWarning::UtcDriftTableUsed {
+                            utc_seconds: utc_s,
+                            tai_minus_utc_seconds: tai_minus_utc,
+                        });
+                    }
+                    (
+                        tai_minus_utc + lsk.delta_t_a,
+                        TtUtcSource::UtcDriftTable,
+                        warnings,
+                    )
+                } else if utc_s < first_epoch {
                     let (delta_t, segment) =
                         delta_t_seconds_with_model(tdb_seconds_to_jd(utc_s), options.delta_t_model);
                     let (dut1, mut dut1_warnings) = fallback_dut1_seconds(utc_s, eop, options);
@@ -229,6 +373,7 @@ pub fn utc_to_tdb_with_policy_and_eop(
                             utc_seconds: utc_s,
                             first_entry_utc_seconds: first_epoch,
                         });
+                        warnings.push(TimeWarning::CivilTimeAsUt1 { utc_seconds: utc_s });
                         warnings.push(TimeWarning::DeltaTModelUsed {
                             model: options.delta_t_model,
                             segment,
@@ -288,7 +433,12 @@ pub fn utc_to_tdb_with_policy_and_eop(
 
             let tt = utc_s + tt_minus_utc;
             UtcToTdbResult {
-                tdb_seconds: tt_to_tdb(tt, lsk),
+                tdb_seconds: tt_to_tdb_with_model(
+                    tt,
+                    lsk,
+                    options.tdb_model,
+                    options.tdb_observer.as_ref(),
+                ),
                 diagnostics: TimeDiagnostics {
                     warnings,
                     tt_minus_utc_s: tt_minus_utc,
@@ -360,15 +510,16 @@ fn fallback_dut1_seconds(
 /// Full inverse conversion: TDB seconds past J2000 → UTC seconds past J2000.
 ///
 /// Uses iteration because the leap-second lookup depends on UTC,
-/// which is what we're solving for. Converges in 2-3 iterations.
+/// which is what we're solving for. Converges in 2-3 iterations; the
+/// 1961–1971 drift rates (< 3 ms/day) add one more.
 pub fn tdb_to_utc(tdb_s: f64, lsk: &LskData) -> f64 {
     let tt = tdb_to_tt(tdb_s, lsk);
     let tai = tt_to_tai(tt, lsk);
 
     // Iteratively solve for UTC: tai = utc + delta_at(utc)
     let mut utc = tai; // initial guess (off by leap seconds)
-    for _ in 0..3 {
-        let delta = lookup_delta_at(utc, lsk);
+    for _ in 0..4 {
+        let delta = tai_minus_utc(utc, lsk).0;
         utc = tai - delta;
     }
     utc
@@ -380,6 +531,7 @@ mod tests {
     use crate::eop::EopData;
     use crate::julian::{calendar_to_jd, jd_to_tdb_seconds};
     use crate::lsk::parse_lsk;
+    use crate::tdb::{TdbModel, TdbObserver, fairhead_bretagnon_tdb_minus_tt};
 
     fn test_lsk() -> LskData {
         // Minimal LSK for testing.
@@ -479,6 +631,106 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
         assert_eq!(out.diagnostics.source, TtUtcSource::DeltaTModel);
     }
 
+    #[test]
+    fn utc_drift_table_reference_values() {
+        let utc_at = |y, m, d| jd_to_tdb_seconds(calendar_to_jd(y, m, d));
+        assert!((utc_drift_tai_minus_utc(utc_at(1965, 1, 1.0)).unwrap() - 3.540_130).abs() < 1e-9);
+        assert!((utc_drift_tai_minus_utc(utc_at(1970, 1, 1.0)).unwrap() - 8.000_082).abs() < 1e-9);
+        assert_eq!(utc_drift_tai_minus_utc(utc_at(1960, 12, 31.0)), None);
+        assert_eq!(utc_drift_tai_minus_utc(utc_at(1972, 1, 1.0)), None);
+
+        let lsk = test_lsk();
+        let utc_s = utc_at(1968, 7, 1.5);
+        let drift = utc_drift_tai_minus_utc(utc_s).unwrap();
+        assert!((utc_to_tai(utc_s, &lsk) - utc_s - drift).abs() < 1e-6);
+        let recovered = tdb_to_utc(utc_to_tdb(utc_s, &lsk), &lsk);
+        assert!(
+            (recovered - utc_s).abs() < 1e-6,
+            "roundtrip {recovered} vs {utc_s}"
+        );
+    }
+
+    #[test]
+    fn hybrid_rubber_second_era_uses_drift_table() {
+        let lsk = test_lsk();
+        let utc_s = jd_to_tdb_seconds(calendar_to_jd(1970, 1, 1.0));
+        let out = utc_to_tdb_with_policy(
+            utc_s,
+            &lsk,
+            TimeConversionPolicy::HybridDeltaT(TimeConversionOptions::default()),
+        );
+        assert_eq!(out.diagnostics.source, TtUtcSource::UtcDriftTable);
+        assert!((out.diagnostics.tt_minus_utc_s - (8.000_082 + 32.184)).abs() < 1e-9);
+        assert!(matches!(
+            out.diagnostics.warnings[..],
+            [TimeWarning::UtcDriftTableUsed { .. }]
+        ));
+        let strict = utc_to_tdb_with_policy(utc_s, &lsk, TimeConversionPolicy::StrictLsk);
+        assert_eq!(strict.diagnostics.source, TtUtcSource::UtcDriftTable);
+        assert!((strict.tdb_seconds - out.tdb_seconds).abs() < 1e-9);
+
+        let as_ut1 = utc_to_tdb_with_policy(
+            utc_s,
+            &lsk,
+            TimeConversionPolicy::HybridDeltaT(TimeConversionOptions {
+                pre_1972_utc: Pre1972UtcMode::DeltaTModel,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(as_ut1.diagnostics.source, TtUtcSource::DeltaTModel);
+    }
+
+    #[test]
+    fn fairhead_bretagnon_model_roundtrip_and_policy() {
+        let lsk = test_lsk();
+        let observer = TdbObserver::from_geodetic(28.6139, 77.209, 216.0);
+        let tt_s = jd_to_tdb_seconds(calendar_to_jd(2024, 3, 20.5));
+        for observer in [None, Some(&observer)] {
+            let tdb =
+                tt_to_tdb_with_model(tt_s, &lsk, TdbModel::FairheadBretagnonTruncated, observer);
+            let back =
+                tdb_to_tt_with_model(tdb, &lsk, TdbModel::FairheadBretagnonTruncated, observer);
+            assert!((back - tt_s).abs() < 1e-9);
+        }
+        // Within the one-term formula's ~30 µs of the series.
+        let naif = tt_to_tdb_with_model(tt_s, &lsk, TdbModel::NaifOneTerm, None);
+        let fb = tt_to_tdb_with_model(tt_s, &lsk, TdbModel::FairheadBretagnonTruncated, None);
+        assert!((naif - fb).abs() < 5e-5);
+        assert_eq!(naif, tt_to_tdb(tt_s, &lsk));
+
+        let utc_s = jd_to_tdb_seconds(calendar_to_jd(2010, 6, 1.0));
+        let options = TimeConversionOptions {
+            tdb_model: TdbModel::FairheadBretagnonTruncated,
+            tdb_observer: Some(observer),
+            ..Default::default()
+        };
+        let out = utc_to_tdb_with_policy(utc_s, &lsk, TimeConversionPolicy::HybridDeltaT(options));
+        let tt = utc_s + out.diagnostics.tt_minus_utc_s;
+        let expected = tt + fairhead_bretagnon_tdb_minus_tt(tt, Some(&observer));
+        assert_eq!(out.tdb_seconds, expected);
+    }
+
+    #[test]
+    fn hybrid_pre_1961_reports_civil_time_as_ut1() {
+        let lsk = test_lsk();
+        let utc_s = jd_to_tdb_seconds(calendar_to_jd(1900, 1, 1.0));
+        let out = utc_to_tdb_with_policy(
+            utc_s,
+            &lsk,
+            TimeConversionPolicy::HybridDeltaT(TimeConversionOptions::default()),
+        );
+        assert_eq!(out.diagnostics.source, TtUtcSource::DeltaTModel);
+        assert!(
+            out.diagnostics
+                .warnings
+                .iter()
+                .any(|w| matches!(w, TimeWarning::CivilTimeAsUt1 { .. }))
+        );
+        let (delta_t, _) =
+            delta_t_seconds_with_model(tdb_seconds_to_jd(utc_s), DeltaTModel::default());
+        assert!((out.diagnostics.tt_minus_utc_s - delta_t).abs() < 1e-9);
+    }
+
     #[test]
     fn strict_and_hybrid_match_for_lsk_covered_modern_date() {
         let lsk = test_lsk();
@@ -516,6 +768,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::default(),
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         assert_eq!(out.diagnostics.source, TtUtcSource::DeltaTModel);
@@ -537,6 +792,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::default(),
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let (expected, _segment) = delta_t_with_bridge_transition(
@@ -567,7 +825,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         // Between LSK end (~2017) and EOP prediction end (MJD 62000), bridge
@@ -585,6 +843,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::default(),
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let jd_utc = tdb_seconds_to_jd(utc_s);
@@ -612,7 +873,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let utc_s = jd_to_tdb_seconds(2_400_000.5 + 65000.0); // well after prediction tail
@@ -628,6 +889,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 1.0,
                 smh_future_family: SmhFutureParabolaFamily::default(),
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let jd_utc = tdb_seconds_to_jd(utc_s);
@@ -659,7 +923,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let utc_s = jd_to_tdb_seconds(2_400_000.5 + 65000.0);
@@ -676,6 +940,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 1.0,
                 smh_future_family: SmhFutureParabolaFamily::ConstantCMinus20,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let out_c17 = utc_to_tdb_with_policy_and_eop(
@@ -690,6 +957,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 1.0,
                 smh_future_family: SmhFutureParabolaFamily::ConstantCMinus17p52,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
 
@@ -711,7 +981,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let utc_s = jd_to_tdb_seconds(2_400_000.5 + 65000.0);
@@ -728,6 +998,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::LegacyTtUtcBlend,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Addendum2020Piecewise,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let out_stephenson = utc_to_tdb_with_policy_and_eop(
@@ -742,6 +1015,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::LegacyTtUtcBlend,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Stephenson1997,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         assert!(
@@ -767,7 +1043,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let anchor_utc_s = jd_to_tdb_seconds(2_400_000.5 + 62000.0);
@@ -785,6 +1061,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Stephenson1997,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
 
@@ -816,7 +1095,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let anchor_utc_s = jd_to_tdb_seconds(2_400_000.5 + 62000.0);
@@ -834,6 +1113,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::BridgeFromModernEndpoint,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Stephenson2016,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
 
@@ -865,7 +1147,7 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 line[58..68].copy_from_slice(dut1_s.as_bytes());
                 String::from_utf8(line).unwrap()
             };
-            vec![mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
+            [mk(61000.0, 'I', 0.10), mk(62000.0, 'P', 0.12)].join("\n")
         };
         let eop = EopData::parse_finals(&eop_snippet).unwrap();
         let utc_s = jd_to_tdb_seconds(2_400_000.5 + 65000.0);
@@ -882,6 +1164,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::LegacyTtUtcBlend,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Addendum2020Piecewise,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         let out_steph2016 = utc_to_tdb_with_policy_and_eop(
@@ -896,6 +1181,9 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
                 future_delta_t_transition: FutureDeltaTTransition::LegacyTtUtcBlend,
                 future_transition_years: 100.0,
                 smh_future_family: SmhFutureParabolaFamily::Stephenson2016,
+                pre_1972_utc: Pre1972UtcMode::default(),
+                tdb_model: TdbModel::default(),
+                tdb_observer: None,
             }),
         );
         assert!(
@@ -1,22 +1,60 @@
-//! Greenwich Mean Sidereal Time and Earth Rotation Angle.
+//! Earth Rotation Angle, sidereal time and the equation of the origins.
 //!
-//! Provides the ERA and GMST needed for converting between celestial
+//! Provides the ERA, GMST and GAST needed for converting between celestial
 //! (RA/Dec) and terrestrial (hour angle) coordinate systems.
 //!
-//! All functions take UT1 Julian Dates. Callers convert UTC→UT1 using
-//! [`crate::EopKernel::utc_to_ut1_jd`] before calling these functions.
+//! Earth rotation is taken as UT1 Julian Dates. Callers convert UTC→UT1
+//! using [`crate::EopKernel::utc_to_ut1_jd`]. The apparent-time functions
+//! also take a TT Julian Date (TDB is interchangeable here) for
+//! precession-nutation.
+//!
+//! GAST is `ERA − EO`. For [`PrecessionModel::Iau2006`] the equation of the
+//! origins EO is the IERS 2010 closed form: minus the IAU 2006 GMST
+//! polynomial, minus the equation of the equinoxes (`Δψ·cos ε_A` from the
+//! selected [`NutationModel`] plus the IAU 2000 complementary terms). Other
+//! precession models shift EO by their accumulated precession in right
+//! ascension relative to IAU 2006, read from the true-equator rotations of
+//! `dhruv_frames`.
 //!
 //! Sources:
 //! - ERA: IERS Conventions 2010, Eq. 5.15. Public domain.
 //! - GMST polynomial: Capitaine et al. 2003, Table 2. Public domain.
+//! - EO and complementary terms: IERS Conventions 2010, Eq. 5.31,
+//!   Table 5.2e. Public domain.
 
 use std::f64::consts::{PI, TAU};
 
+use dhruv_frames::{
+    DEFAULT_NUTATION_MODEL, FrameOfDate, NutationModel, PrecessionModel, fundamental_arguments,
+    icrf_to_frame_of_date_matrix, mean_obliquity_of_date_rad, nutation_with_model,
+};
+
 use crate::julian::J2000_JD;
 
 /// Arcseconds to radians: 1″ = π / (180 × 3600).
 const ARCSEC_TO_RAD: f64 = PI / (180.0 * 3600.0);
 
+/// Complementary terms of the equation of the equinoxes at `t⁰`:
+/// (multipliers of l, l′, F, D, Ω; sine and cosine amplitudes in µas).
+/// Terms below 0.5 µas are omitted.
+const EE_COMPLEMENTARY_T0: [([f64; 5], f64, f64); 12] = [
+    ([0.0, 0.0, 0.0, 0.0, 1.0], 2640.96, -0.39),
+    ([0.0, 0.0, 0.0, 0.0, 2.0], 63.52, -0.02),
+    ([0.0, 0.0, 2.0, -2.0, 3.0], 11.75, 0.01),
+    ([0.0, 0.0, 2.0, -2.0, 1.0], 11.21, 0.01),
+    ([0.0, 0.0, 2.0, -2.0, 2.0], -4.55, 0.0),
+    ([0.0, 0.0, 2.0, 0.0, 3.0], 2.02, 0.0),
+    ([0.0, 0.0, 2.0, 0.0, 1.0], 1.98, 0.0),
+    ([0.0, 0.0, 0.0, 0.0, 3.0], -1.72, 0.0),
+    ([0.0, 1.0, 0.0, 0.0, 1.0], -1.41, -0.01),
+    ([0.0, 1.0, 0.0, 0.0, -1.0], -1.26, -0.01),
+    ([1.0, 0.0, 0.0, 0.0, -1.0], -0.63, 0.0),
+    ([1.0, 0.0, 0.0, 0.0, 1.0], -0.63, 0.0),
+];
+
+/// Complementary term at `t¹` (Ω, sine amplitude in µas).
+const EE_COMPLEMENTARY_T1_UAS: f64 = -0.87;
+
 /// Earth Rotation Angle at a given UT1 Julian Date.
 ///
 /// θ = 2π × (0.7790572732640 + 1.00273781191135448 × Du)
@@ -45,18 +83,122 @@ pub fn earth_rotation_angle_rad(jd_ut1: f64) -> f64 {
 pub fn gmst_rad(jd_ut1: f64) -> f64 {
     let era = earth_rotation_angle_rad(jd_ut1);
     let t = (jd_ut1 - J2000_JD) / 36525.0;
+    let gmst = era + gmst_polynomial_arcsec(t) * ARCSEC_TO_RAD;
+    gmst.rem_euclid(TAU)
+}
+
+/// IAU 2006 GMST polynomial (`GMST − ERA`) in arcseconds.
+fn gmst_polynomial_arcsec(t: f64) -> f64 {
     let t2 = t * t;
     let t3 = t2 * t;
     let t4 = t3 * t;
     let t5 = t4 * t;
-
-    let poly_arcsec = 0.014506 + 4612.156534 * t + 1.3915817 * t2
+    0.014506 + 4612.156534 * t + 1.3915817 * t2
         - 0.00000044 * t3
         - 0.000029956 * t4
-        - 0.0000000368 * t5;
+        - 0.0000000368 * t5
+}
 
-    let gmst = era + poly_arcsec * ARCSEC_TO_RAD;
-    gmst.rem_euclid(TAU)
+/// Julian centuries of TT since J2000.0.
+fn tt_centuries(jd_tt: f64) -> f64 {
+    (jd_tt - J2000_JD) / 36525.0
+}
+
+/// Complementary terms of the equation of the equinoxes in radians.
+///
+/// These are the IAU 2000 terms that keep GAST consistent with the
+/// CIO-based Earth rotation (IERS Conventions 2010, Table 5.2e); their sum
+/// stays below 3 mas.
+pub fn equation_of_equinoxes_complementary_rad(jd_tt: f64) -> f64 {
+    let t = tt_centuries(jd_tt);
+    let args = fundamental_arguments(t);
+    let periodic: f64 = EE_COMPLEMENTARY_T0
+        .iter()
+        .map(|(mult, sin_uas, cos_uas)| {
+            let arg: f64 = mult.iter().zip(&args).map(|(m, a)| m * a).sum();
+            sin_uas * arg.sin() + cos_uas * arg.cos()
+        })
+        .sum();
+    let secular = EE_COMPLEMENTARY_T1_UAS * t * args[4].sin();
+    (periodic + secular) * 1e-6 * ARCSEC_TO_RAD
+}
+
+/// Equation of the equinoxes in radians: `Δψ·cos ε_A` (IAU 2000B nutation,
+/// IAU 2006 mean obliquity) plus the complementary terms.
+pub fn equation_of_equinoxes_rad(jd_tt: f64) -> f64 {
+    equation_of_equinoxes_rad_with_model(jd_tt, DEFAULT_NUTATION_MODEL)
+}
+
+/// [`equation_of_equinoxes_rad`] with `Δψ` from an explicit nutation model.
+pub fn equation_of_equinoxes_rad_with_model(jd_tt: f64, nutation: NutationModel) -> f64 {
+    let t = tt_centuries(jd_tt);
+    let (dpsi_arcsec, _) = nutation_with_model(t, nutation);
+    dpsi_arcsec * ARCSEC_TO_RAD * mean_obliquity_of_date_rad(t).cos()
+        + equation_of_equinoxes_complementary_rad(jd_tt)
+}
+
+/// Right ascension of the CIO in the true-equinox frame of `model`, in
+/// radians (equals −EO up to the CIO locator s, which cancels between
+/// models).
+fn cio_right_ascension_rad(t: f64, model: PrecessionModel, nutation: NutationModel) -> f64 {
+    let m = icrf_to_frame_of_date_matrix(t, FrameOfDate::TrueEquator, model, nutation);
+    // The CIO direction (to first order in s) in GCRS, from the CIP row.
+    let x = m[2][0];
+    let ax = x / (1.0 + m[2][2]);
+    let cio = [1.0 - ax * x, -ax * m[2][1], -x];
+    let p = m[0][0] * cio[0] + m[0][1] * cio[1] + m[0][2] * cio[2];
+    let q = m[1][0] * cio[0] + m[1][1] * cio[1] + m[1][2] * cio[2];
+    q.atan2(p)
+}
+
+/// Equation of the origins (ERA − GAST) in radians at a TT Julian Date.
+///
+/// For [`PrecessionModel::Iau2006`] this is the IERS 2010 closed form;
+/// other models add the difference of their accumulated precession in RA.
+/// `nutation` supplies the `Δψ` of the equation of the equinoxes.
+pub fn equation_of_origins_rad(jd_tt: f64, model: PrecessionModel, nutation: NutationModel) -> f64 {
+    let t = tt_centuries(jd_tt);
+    let eo_iau2006 = -gmst_polynomial_arcsec(t) * ARCSEC_TO_RAD
+        - equation_of_equinoxes_rad_with_model(jd_tt, nutation);
+    match model {
+        PrecessionModel::Iau2006 => eo_iau2006,
+        other => {
+            eo_iau2006 + cio_right_ascension_rad(t, PrecessionModel::Iau2006, nutation)
+                - cio_right_ascension_rad(t, other, nutation)
+        }
+    }
+}
+
+/// Greenwich Mean Sidereal Time for a precession model: `GAST − EE`.
+///
+/// For [`PrecessionModel::Iau2006`] this is the IAU 2006 polynomial
+/// evaluated with TT, as [`gmst_rad`] does with UT1.
+///
+/// Returns radians in [0, 2π).
+pub fn gmst_rad_with_model(jd_ut1: f64, jd_tt: f64, model: PrecessionModel) -> f64 {
+    (gast_rad(jd_ut1, jd_tt, model, DEFAULT_NUTATION_MODEL) - equation_of_equinoxes_rad(jd_tt))
+        .rem_euclid(TAU)
+}
+
+/// Greenwich Apparent Sidereal Time: `ERA(UT1) − EO(TT)`.
+///
+/// Returns radians in [0, 2π).
+pub fn gast_rad(jd_ut1: f64, jd_tt: f64, model: PrecessionModel, nutation: NutationModel) -> f64 {
+    (earth_rotation_angle_rad(jd_ut1) - equation_of_origins_rad(jd_tt, model, nutation))
+        .rem_euclid(TAU)
+}
+
+/// Local Apparent Sidereal Time: GAST plus the observer's east longitude.
+///
+/// Returns radians in [0, 2π).
+pub fn last_rad(
+    jd_ut1: f64,
+    jd_tt: f64,
+    longitude_east_rad: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> f64 {
+    local_sidereal_time_rad(gast_rad(jd_ut1, jd_tt, model, nutation), longitude_east_rad)
 }
 
 /// Local Sidereal Time from GMST and observer east longitude.
@@ -128,4 +270,120 @@ mod tests {
             assert!((0.0..TAU).contains(&g), "GMST out of range: {g}");
         }
     }
+
+    fn angle_diff(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(TAU);
+        d.min(TAU - d)
+    }
+
+    #[test]
+    fn complementary_terms_reference_value() {
+        // IAU SOFA eect00 at TT 2400000.5 + 53736.0: 0.2046085004885125264e-8.
+        let ct = equation_of_equinoxes_complementary_rad(2_400_000.5 + 53_736.0);
+        // The omitted sub-0.5 µas and planetary terms account for the rest.
+        assert!(
+            (ct - 0.204_608_500_488_512_5e-8).abs() < 1e-6 * ARCSEC_TO_RAD,
+            "{ct}"
+        );
+    }
+
+    #[test]
+    fn gast_reference_value() {
+        // IAU SOFA gst06a at UT1 2400000.5 + 53736.0, TT + 53736.0:
+        // 1.754166137675019159 rad (IAU 2000A nutation). The truncated
+        // IAU 2000B series used here leaves a few mas in Δψ·cos ε_A.
+        let jd = 2_400_000.5 + 53_736.0;
+        let gast = gast_rad(jd, jd, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        let d = angle_diff(gast, 1.754_166_137_675_019);
+        assert!(d < 0.01 * ARCSEC_TO_RAD, "{}″", d / ARCSEC_TO_RAD);
+    }
+
+    #[test]
+    fn iau2006_gmst_and_gast_relations() {
+        let jd = 2_460_310.5;
+        let gmst = gmst_rad_with_model(jd, jd, PrecessionModel::Iau2006);
+        assert!(angle_diff(gmst, gmst_rad(jd)) < 1e-12);
+        let gast = gast_rad(jd, jd, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        assert!(angle_diff(gast, gmst + equation_of_equinoxes_rad(jd)) < 1e-12);
+        let eo = equation_of_origins_rad(jd, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        assert!(angle_diff(earth_rotation_angle_rad(jd) - eo, gast) < 1e-12);
+        let last = last_rad(
+            jd,
+            jd,
+            PI / 2.0,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        assert!(angle_diff(last, gast + PI / 2.0) < 1e-12);
+    }
+
+    #[test]
+    fn nutation_model_reaches_gast() {
+        // IAU 1980 and IAU 2000B Δψ differ by tens of mas; GAST moves by
+        // exactly that difference times cos ε_A.
+        let jd = 2_460_310.5;
+        let t = tt_centuries(jd);
+        let a = gast_rad(jd, jd, PrecessionModel::Iau2006, NutationModel::Iau1980);
+        let b = gast_rad(jd, jd, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        let dpsi = nutation_with_model(t, NutationModel::Iau1980).0
+            - nutation_with_model(t, NutationModel::Iau2000B).0;
+        let expected = dpsi * ARCSEC_TO_RAD * mean_obliquity_of_date_rad(t).cos();
+        assert!(expected.abs() > 0.001 * ARCSEC_TO_RAD);
+        assert!(((a - b) - expected).abs() < 1e-6 * ARCSEC_TO_RAD);
+        assert_eq!(
+            equation_of_equinoxes_rad(jd),
+            equation_of_equinoxes_rad_with_model(jd, DEFAULT_NUTATION_MODEL)
+        );
+    }
+
+    #[test]
+    fn equation_of_origins_matches_cio_locator() {
+        // Numerically, EO = s − (RA of the CIO's first-order direction).
+        let jd = 2_460_000.5;
+        let t = tt_centuries(jd);
+        let m = icrf_to_frame_of_date_matrix(
+            t,
+            FrameOfDate::TrueEquator,
+            PrecessionModel::Iau2006,
+            NutationModel::Iau2000B,
+        );
+        let s = dhruv_frames::cio_locator_s(t, m[2][0], m[2][1]);
+        let numeric =
+            s - cio_right_ascension_rad(t, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        let eo = equation_of_origins_rad(jd, PrecessionModel::Iau2006, NutationModel::Iau2000B);
+        assert!((eo - numeric).abs() < 0.01 * ARCSEC_TO_RAD);
+    }
+
+    #[test]
+    fn models_agree_near_j2000_and_diverge_later() {
+        let near = 2_451_545.0 + 3650.0;
+        for model in [PrecessionModel::Lieske1977, PrecessionModel::Vondrak2011] {
+            let d = angle_diff(
+                gast_rad(near, near, model, NutationModel::Iau2000B),
+                gast_rad(
+                    near,
+                    near,
+                    PrecessionModel::Iau2006,
+                    NutationModel::Iau2000B,
+                ),
+            );
+            assert!(
+                d < 0.05 * ARCSEC_TO_RAD,
+                "{model:?}: {}″",
+                d / ARCSEC_TO_RAD
+            );
+        }
+        // Five millennia out, Vondrák and IAU 2006 separate by arcseconds.
+        let far = 2_451_545.0 - 50.0 * 36525.0;
+        let d = angle_diff(
+            gast_rad(
+                far,
+                far,
+                PrecessionModel::Vondrak2011,
+                NutationModel::Iau2000B,
+            ),
+            gast_rad(far, far, PrecessionModel::Iau2006, NutationModel::Iau2000B),
+        );
+        assert!(d > 1.0 * ARCSEC_TO_RAD, "{}″", d / ARCSEC_TO_RAD);
+    }
 }
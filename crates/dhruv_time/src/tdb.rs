@@ -0,0 +1,264 @@
+//! TDB − TT models.
+//!
+//! The default is the NAIF one-term formula driven by the LSK constants
+//! (see [`crate::scales::tt_to_tdb`]), good to about 30 µs. This module adds
+//! the Fairhead & Bretagnon (1990) analytical series, truncated to its
+//! leading terms, and the Moyer (1981) / Murray (1983) topocentric term for
+//! an observer on the Earth's surface.
+//!
+//! The series is `Σₖ Tᵏ Σᵢ Aᵢ sin(ωᵢ T + φᵢ)` with `T` in Julian millennia
+//! of TDB from J2000; TT is used as the argument, which changes the result
+//! by far less than a nanosecond.
+//!
+//! Only 50 of the 787 terms are kept (40 `T⁰`, 6 `T¹`, 3 `T²`, 1 `T³`).
+//! Each omitted `T⁰` term is below 0.05 µs and each omitted `T¹` term below
+//! 0.08 µs per millennium; together they are expected to stay within a few
+//! tenths of a µs near J2000 (about 10 ns at the published 1992 reference)
+//! and grow with `|T|`. It is not a substitute for the full series at the
+//! nanosecond level.
+
+use std::f64::consts::TAU;
+
+/// Seconds in a Julian millennium.
+const SECONDS_PER_MILLENNIUM: f64 = 86_400.0 * 365_250.0;
+
+/// WGS84 equatorial radius (km) and flattening.
+const WGS84_A_KM: f64 = 6378.137;
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// `T⁰` terms: (amplitude s, frequency rad/millennium, phase rad).
+const FB_T0: [(f64, f64, f64); 40] = [
+    (1656.674564e-6, 6283.075849991, 6.240054195),
+    (22.417471e-6, 5753.384884897, 4.296977442),
+    (13.839792e-6, 12566.151699983, 6.196904410),
+    (4.770086e-6, 529.690965095, 0.444401603),
+    (4.676740e-6, 6069.776754553, 4.021195093),
+    (2.256707e-6, 213.299095438, 5.543113262),
+    (1.694205e-6, -3.523118349, 5.025132748),
+    (1.554905e-6, 77713.771467920, 5.198467090),
+    (1.276839e-6, 7860.419392439, 5.988822341),
+    (1.193379e-6, 5223.693919802, 3.649823730),
+    (1.115322e-6, 3930.209696220, 1.422745069),
+    (0.794185e-6, 11506.769769794, 2.322313077),
+    (0.447061e-6, 26.298319800, 3.615796498),
+    (0.435206e-6, -398.149003408, 4.349338347),
+    (0.600309e-6, 1577.343542448, 2.678271909),
+    (0.496817e-6, 6208.294251424, 5.696701824),
+    (0.486306e-6, 5884.926846583, 0.520007179),
+    (0.432392e-6, 74.781598567, 2.435898309),
+    (0.468597e-6, 6244.942814354, 5.866398759),
+    (0.375510e-6, 5507.553238667, 4.103476804),
+    (0.243085e-6, -775.522611324, 3.651837925),
+    (0.173435e-6, 18849.227549974, 6.153743485),
+    (0.230685e-6, 5856.477659115, 4.773852582),
+    (0.203747e-6, 12036.460734888, 4.333987818),
+    (0.143935e-6, -796.298006816, 5.957517795),
+    (0.159080e-6, 10977.078804699, 1.890075226),
+    (0.119979e-6, 38.133035638, 4.551585768),
+    (0.118971e-6, 5486.777843175, 1.914547226),
+    (0.116120e-6, 1059.381930189, 0.873504123),
+    (0.137927e-6, 11790.629088659, 1.135934669),
+    (0.098358e-6, 2544.314419883, 0.092793886),
+    (0.101868e-6, -5573.142801634, 5.984503847),
+    (0.080164e-6, 206.185548437, 2.095377709),
+    (0.079645e-6, 4694.002954708, 2.949233637),
+    (0.062617e-6, 20.775395492, 2.654394814),
+    (0.075019e-6, 2942.463423292, 4.980931759),
+    (0.064397e-6, 5746.271337896, 1.280308748),
+    (0.063814e-6, 5760.498431898, 4.167901731),
+    (0.048042e-6, 2146.165416475, 1.495846011),
+    (0.048373e-6, 155.420399434, 2.251573730),
+];
+
+/// `T¹` terms.
+const FB_T1: [(f64, f64, f64); 6] = [
+    (102.156724e-6, 6283.075849991, 4.249032005),
+    (1.706807e-6, 12566.151699983, 4.205904248),
+    (0.269668e-6, 213.299095438, 3.400290479),
+    (0.265919e-6, 529.690965095, 5.836047367),
+    (0.210568e-6, -3.523118349, 6.262738348),
+    (0.077996e-6, 5223.693919802, 4.670344204),
+];
+
+/// `T²` terms.
+const FB_T2: [(f64, f64, f64); 3] = [
+    (4.322990e-6, 6283.075849991, 2.642893748),
+    (0.406495e-6, 0.0, 4.712388980),
+    (0.122605e-6, 12566.151699983, 2.438140634),
+];
+
+/// `T³` terms.
+const FB_T3: [(f64, f64, f64); 1] = [(0.143388e-6, 6283.075849991, 1.131453581)];
+
+/// TDB − TT model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TdbModel {
+    /// NAIF one-term formula with the LSK `K`, `EB`, `M` constants
+    /// (~30 µs).
+    #[default]
+    NaifOneTerm,
+    /// Fairhead & Bretagnon (1990) series truncated to its 50 leading
+    /// terms (sub-microsecond near J2000), plus the topocentric term when
+    /// an observer is configured.
+    FairheadBretagnonTruncated,
+}
+
+/// Observer position for the topocentric TDB − TT term (amplitude ~2 µs).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TdbObserver {
+    /// East longitude in radians.
+    pub longitude_east_rad: f64,
+    /// Distance from the Earth's spin axis (km).
+    pub spin_axis_distance_km: f64,
+    /// Distance north of the equatorial plane (km).
+    pub equatorial_plane_distance_km: f64,
+}
+
+impl TdbObserver {
+    /// Observer from WGS84 geodetic latitude/longitude (degrees) and
+    /// ellipsoidal height (meters).
+    pub fn from_geodetic(latitude_deg: f64, longitude_east_deg: f64, height_m: f64) -> Self {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let (sin_lat, cos_lat) = latitude_deg.to_radians().sin_cos();
+        let n = WGS84_A_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        let h = height_m / 1000.0;
+        Self {
+            longitude_east_rad: longitude_east_deg.to_radians(),
+            spin_axis_distance_km: (n + h) * cos_lat,
+            equatorial_plane_distance_km: (n * (1.0 - e2) + h) * sin_lat,
+        }
+    }
+}
+
+/// TDB − TT in seconds from the truncated Fairhead & Bretagnon series at TT
+/// seconds past J2000, including the topocentric term for `observer`.
+///
+/// The topocentric term needs UT for the observer's solar time; TT stands
+/// in for it, which moves the result by at most ~10 ns.
+pub fn fairhead_bretagnon_tdb_minus_tt(tt_s: f64, observer: Option<&TdbObserver>) -> f64 {
+    let t = tt_s / SECONDS_PER_MILLENNIUM;
+    let sum = |terms: &[(f64, f64, f64)]| {
+        terms
+            .iter()
+            .map(|&(amplitude, frequency, phase)| amplitude * (frequency * t + phase).sin())
+            .sum::<f64>()
+    };
+    let series = sum(&FB_T0) + t * (sum(&FB_T1) + t * (sum(&FB_T2) + t * sum(&FB_T3)));
+    series + observer.map_or(0.0, |o| topocentric_term(tt_s, t, o))
+}
+
+/// Moyer (1981) / Murray (1983) topocentric term, with fundamental
+/// arguments from Simon et al. (1994).
+fn topocentric_term(tt_s: f64, t: f64, observer: &TdbObserver) -> f64 {
+    let ut_fraction = (tt_s / 86_400.0 + 0.5).rem_euclid(1.0);
+    let tsol = ut_fraction * TAU + observer.longitude_east_rad;
+
+    // Degrees plus arcseconds per millennium.
+    let w = t / 3600.0;
+    let angle = |deg: f64, rate: f64| (deg + rate * w).rem_euclid(360.0).to_radians();
+    let elsun = angle(280.466_456_83, 1_296_027_711.034_29);
+    let emsun = angle(357.529_109_18, 1_295_965_810.481);
+    let d = angle(297.850_195_47, 16_029_616_012.090);
+    let elj = angle(34.351_518_74, 109_306_899.894_53);
+    let els = angle(50.077_444_30, 44_046_398.470_38);
+
+    let u = observer.spin_axis_distance_km;
+    let v = observer.equatorial_plane_distance_km;
+    (0.00029 * u * (tsol + elsun - els).sin()
+        + 0.00100 * u * (tsol - 2.0 * emsun).sin()
+        + 0.00133 * u * (tsol - d).sin()
+        + 0.00133 * u * (tsol + elsun - elj).sin()
+        - 0.00229 * u * (tsol + 2.0 * elsun + emsun).sin()
+        - 0.02200 * v * (elsun + emsun).cos()
+        + 0.05312 * u * (tsol - emsun).sin()
+        - 0.13677 * u * (tsol + 2.0 * elsun).sin()
+        - 1.31840 * v * elsun.cos()
+        + 3.17679 * u * tsol.sin())
+        * 1e-10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_published_reference_with_topocentric_term() {
+        // TDB − TT = −1.280368005937 ms at TDB 2448939.623 (1992-11-13) for
+        // an observer 5525.242 km from the axis, 3190 km above the equator,
+        // east longitude 5.0123 rad, UT fraction 0.76543.
+        let jd: f64 = 2_448_939.5 + 0.123;
+        let tt_s = (jd - 2_451_545.0) * 86_400.0;
+        let observer = TdbObserver {
+            longitude_east_rad: 5.0123,
+            spin_axis_distance_km: 5525.242,
+            equatorial_plane_distance_km: 3190.0,
+        };
+        // The reference passes UT separately; shift TT so its day fraction
+        // is the reference UT.
+        let ut_shift = (0.76543 - (jd + 0.5).fract()) * 86_400.0;
+        let topo = topocentric_term(tt_s + ut_shift, tt_s / SECONDS_PER_MILLENNIUM, &observer);
+        let value = fairhead_bretagnon_tdb_minus_tt(tt_s, None) + topo;
+        assert!((value - -1.280_368_005_937e-3).abs() < 5e-8, "{value}");
+        // The topocentric term is a few hundred ns here.
+        assert!(topo.abs() > 1e-7 && topo.abs() < 3e-6);
+    }
+
+    #[test]
+    fn series_tracks_one_term_formula() {
+        // The NAIF formula is the dominant annual term; the two agree to
+        // tens of µs over centuries.
+        for years in [-300.0_f64, -50.0, 0.0, 24.0, 200.0] {
+            let tt_s = years * 365.25 * 86_400.0;
+            let m = 6.239_996 + 1.990_968_71e-7 * tt_s;
+            let naif = 1.657e-3 * (m + 1.671e-2 * m.sin()).sin();
+            let fb = fairhead_bretagnon_tdb_minus_tt(tt_s, None);
+            assert!((fb - naif).abs() < 6e-5, "{years}: {fb} vs {naif}");
+        }
+    }
+
+    /// USNO Circular 179 (Kaplan 2005) eq. 2.6, a rounded seven-term form
+    /// of the same series quoted as good to about 10 µs for 1600–2200.
+    fn usno_circular_179(tt_s: f64) -> f64 {
+        let t = tt_s / (SECONDS_PER_MILLENNIUM / 10.0);
+        0.001_657 * (628.307_6 * t + 6.240_1).sin()
+            + 0.000_022 * (575.338_5 * t + 4.297_0).sin()
+            + 0.000_014 * (1_256.615_2 * t + 6.196_9).sin()
+            + 0.000_005 * (606.977_7 * t + 4.021_2).sin()
+            + 0.000_005 * (52.969_1 * t + 0.444_4).sin()
+            + 0.000_002 * (21.329_9 * t + 5.543_1).sin()
+            + 0.000_010 * t * (628.307_6 * t + 4.249_0).sin()
+    }
+
+    #[test]
+    fn matches_usno_formula_across_epochs() {
+        // Inside 1600–2200 the two agree within the short formula's 10 µs.
+        for year in [
+            1600.0_f64, 1723.4, 1850.0, 1969.7, 2000.0, 2050.2, 2133.3, 2200.0,
+        ] {
+            let tt_s = (year - 2000.0) * 365.25 * 86_400.0;
+            let fb = fairhead_bretagnon_tdb_minus_tt(tt_s, None);
+            let usno = usno_circular_179(tt_s);
+            assert!((fb - usno).abs() < 1e-5, "{year}: {fb} vs {usno}");
+        }
+        // Far from J2000 the short formula drops the T¹ minor terms and all
+        // T² and T³ terms (4.9 µs·T², 0.15 µs·|T|³ with T in millennia).
+        for year in [-1000.0_f64, -500.0, 1000.0, 3000.0, 4000.0] {
+            let tt_s = (year - 2000.0) * 365.25 * 86_400.0;
+            let t = tt_s.abs() / SECONDS_PER_MILLENNIUM;
+            let bound = 1e-5 + 2.6e-6 * t + 4.9e-6 * t * t + 0.15e-6 * t.powi(3);
+            let fb = fairhead_bretagnon_tdb_minus_tt(tt_s, None);
+            let usno = usno_circular_179(tt_s);
+            assert!((fb - usno).abs() < bound, "{year}: {fb} vs {usno}");
+        }
+    }
+
+    #[test]
+    fn geodetic_observer_distances() {
+        let equator = TdbObserver::from_geodetic(0.0, 90.0, 0.0);
+        assert!((equator.spin_axis_distance_km - WGS84_A_KM).abs() < 1e-9);
+        assert!(equator.equatorial_plane_distance_km.abs() < 1e-9);
+        let pole = TdbObserver::from_geodetic(90.0, 0.0, 1000.0);
+        assert!(pole.spin_axis_distance_km.abs() < 1e-9);
+        assert!((pole.equatorial_plane_distance_km - 6357.752314).abs() < 1e-5);
+    }
+}
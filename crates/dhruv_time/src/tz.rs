@@ -0,0 +1,750 @@
+//! IANA time zones from an embedded tz database.
+//!
+//! Zones are read from the TZif compilation of the IANA Time Zone Database
+//! bundled by the `jiff-tzdb` crate, so lookups need neither system zone
+//! files nor network access. Instants after a zone's last listed transition
+//! follow the POSIX TZ rule in the TZif footer. Zones can also be built as
+//! fixed UTC offsets or as Local Mean Time for a longitude.
+//!
+//! UTC is handled without leap seconds (POSIX time), matching the calendar
+//! arithmetic of [`crate::UtcTime`].
+//!
+//! Reference: RFC 8536 (TZif), POSIX.1-2017 §8.3 (`TZ`).
+//! Implementation is original.
+
+use std::sync::Arc;
+
+use crate::error::TimeError;
+
+/// Seconds from 1970-01-01T00:00Z (Unix epoch) to 2000-01-01T12:00Z
+/// (J2000.0), counting UTC days of 86 400 s.
+pub const UNIX_TO_J2000_SECONDS: i64 = 946_728_000;
+
+/// Version of the embedded IANA Time Zone Database (e.g. `"2026e"`).
+pub fn tzdata_version() -> Option<&'static str> {
+    jiff_tzdb::VERSION
+}
+
+/// Canonical names of all zones in the embedded database.
+pub fn available_time_zones() -> impl Iterator<Item = &'static str> {
+    jiff_tzdb::available()
+}
+
+/// UTC offset and designation in effect over an interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    /// Seconds east of UTC.
+    pub utc_offset_seconds: i32,
+    /// Whether daylight saving time is in effect.
+    pub is_dst: bool,
+    /// Designation such as `"IST"`, `"EDT"` or `"LMT"`.
+    pub abbreviation: String,
+}
+
+/// `"IST +05:30"`, with `" DST"` appended during daylight saving time.
+impl std::fmt::Display for LocalTimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.abbreviation,
+            format_offset(self.utc_offset_seconds)
+        )?;
+        if self.is_dst {
+            write!(f, " DST")?;
+        }
+        Ok(())
+    }
+}
+
+/// A time zone: an IANA zone, a fixed offset or Local Mean Time.
+///
+/// Cloning is cheap; zone data is shared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeZone {
+    inner: Arc<ZoneData>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ZoneData {
+    name: String,
+    /// Transition instants, Unix seconds, ascending.
+    transitions: Vec<i64>,
+    /// Index into `types` for each transition.
+    transition_types: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    /// Type in effect before the first transition.
+    initial: usize,
+    /// Rule for instants after the last transition.
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// Look up an IANA zone (e.g. `"Asia/Kolkata"`) in the embedded
+    /// database. Matching is case-insensitive and [`TimeZone::name`]
+    /// reports the database spelling; backward links such as
+    /// `Asia/Calcutta` carry the same data as their target.
+    pub fn get(name: &str) -> Result<Self, TimeError> {
+        let (canonical, data) =
+            jiff_tzdb::get(name).ok_or_else(|| TimeError::UnknownTimeZone(name.to_string()))?;
+        Self::from_tzif(canonical, data)
+    }
+
+    /// Parse a zone from TZif bytes (RFC 8536, versions 1–4), e.g. a file
+    /// from a system zoneinfo directory. Leap-second records are ignored.
+    pub fn from_tzif(name: &str, data: &[u8]) -> Result<Self, TimeError> {
+        let zone = parse_tzif(name, data)?;
+        Ok(Self {
+            inner: Arc::new(zone),
+        })
+    }
+
+    /// UTC.
+    pub fn utc() -> Self {
+        Self::constant("UTC", 0, "UTC")
+    }
+
+    /// Fixed offset in seconds east of UTC, named like `"+05:30"`.
+    pub fn fixed(utc_offset_seconds: i32) -> Self {
+        let name = format_offset(utc_offset_seconds);
+        Self::constant(&name, utc_offset_seconds, &name)
+    }
+
+    /// Local Mean Time for a longitude (degrees, east positive): an offset
+    /// of 4 minutes per degree, rounded to the nearest second.
+    pub fn local_mean_time(longitude_deg: f64) -> Self {
+        Self::constant(
+            &format!("LMT{longitude_deg:+}"),
+            lmt_offset_seconds(longitude_deg),
+            "LMT",
+        )
+    }
+
+    /// Use Local Mean Time of `longitude_deg` instead of the zone's own
+    /// LMT entry before its first transition (pre-standard-time dates).
+    ///
+    /// The database gives LMT of the zone's reference city; this applies
+    /// the birth place's longitude instead. Zones whose earliest period is
+    /// not LMT are returned unchanged.
+    pub fn with_local_mean_time(&self, longitude_deg: f64) -> Self {
+        let data = &self.inner;
+        if data.types[data.initial].abbreviation != "LMT" {
+            return self.clone();
+        }
+        let mut types = data.types.clone();
+        types.push(LocalTimeType {
+            utc_offset_seconds: lmt_offset_seconds(longitude_deg),
+            is_dst: false,
+            abbreviation: "LMT".to_string(),
+        });
+        Self {
+            inner: Arc::new(ZoneData {
+                name: data.name.clone(),
+                transitions: data.transitions.clone(),
+                transition_types: data.transition_types.clone(),
+                initial: types.len() - 1,
+                types,
+                rule: data.rule.clone(),
+            }),
+        }
+    }
+
+    /// Zone name (canonical IANA name for database zones).
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Local time type in effect at a UTC instant (seconds past J2000).
+    pub fn local_time_type_at(&self, utc_seconds: f64) -> &LocalTimeType {
+        self.type_at_unix((utc_seconds.floor() as i64) + UNIX_TO_J2000_SECONDS)
+    }
+
+    /// Whether a UTC instant (seconds past J2000) precedes the zone's first
+    /// transition, i.e. falls in its Local Mean Time period.
+    pub fn is_pre_standard_time(&self, utc_seconds: f64) -> bool {
+        let data = &self.inner;
+        data.types[data.initial].abbreviation == "LMT"
+            && data
+                .transitions
+                .first()
+                .is_some_and(|&t| ((utc_seconds.floor() as i64) + UNIX_TO_J2000_SECONDS) < t)
+    }
+
+    fn constant(name: &str, utc_offset_seconds: i32, abbreviation: &str) -> Self {
+        Self {
+            inner: Arc::new(ZoneData {
+                name: name.to_string(),
+                transitions: Vec::new(),
+                transition_types: Vec::new(),
+                types: vec![LocalTimeType {
+                    utc_offset_seconds,
+                    is_dst: false,
+                    abbreviation: abbreviation.to_string(),
+                }],
+                initial: 0,
+                rule: None,
+            }),
+        }
+    }
+
+    pub(crate) fn type_at_unix(&self, t: i64) -> &LocalTimeType {
+        let data = &self.inner;
+        let idx = data.transitions.partition_point(|&x| x <= t);
+        if idx == data.transitions.len()
+            && let Some(rule) = &data.rule
+        {
+            return rule.type_at(t);
+        }
+        if idx == 0 {
+            &data.types[data.initial]
+        } else {
+            &data.types[data.transition_types[idx - 1]]
+        }
+    }
+
+    /// Transitions in `(from, to]` (Unix seconds) with the type that starts
+    /// at each.
+    pub(crate) fn transitions_between(&self, from: i64, to: i64) -> Vec<(i64, &LocalTimeType)> {
+        let data = &self.inner;
+        let mut out: Vec<(i64, &LocalTimeType)> = data
+            .transitions
+            .iter()
+            .zip(&data.transition_types)
+            .filter(|&(&t, _)| t > from && t <= to)
+            .map(|(&t, &ty)| (t, &data.types[ty]))
+            .collect();
+        if let Some(rule) = &data.rule {
+            let after = data.transitions.last().map_or(from, |&last| last.max(from));
+            if after < to {
+                out.extend(rule.transitions_between(after, to));
+            }
+        }
+        out
+    }
+}
+
+fn lmt_offset_seconds(longitude_deg: f64) -> i32 {
+    (longitude_deg * 240.0).round() as i32
+}
+
+/// `±hh:mm` (with `:ss` when needed).
+pub(crate) fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let s = seconds.unsigned_abs();
+    if s.is_multiple_of(60) {
+        format!("{sign}{:02}:{:02}", s / 3600, s / 60 % 60)
+    } else {
+        format!("{sign}{:02}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TZif
+// ---------------------------------------------------------------------------
+
+fn parse_tzif(name: &str, data: &[u8]) -> Result<ZoneData, TimeError> {
+    let err = |msg: &str| TimeError::TzifParse(format!("{name}: {msg}"));
+    let header = |at: usize| -> Result<(u8, [usize; 6]), TimeError> {
+        let h = data
+            .get(at..at + 44)
+            .ok_or_else(|| err("truncated header"))?;
+        if &h[..4] != b"TZif" {
+            return Err(err("missing TZif magic"));
+        }
+        let mut counts = [0usize; 6];
+        for (i, c) in counts.iter_mut().enumerate() {
+            let b = &h[20 + 4 * i..24 + 4 * i];
+            *c = u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as usize;
+        }
+        Ok((h[4], counts))
+    };
+
+    let (version, v1) = header(0)?;
+    let block_len = |c: &[usize; 6], time_size: usize| {
+        let [isut, isstd, leap, time, types, chars] = *c;
+        time * time_size + time + types * 6 + chars + leap * (time_size + 4) + isstd + isut
+    };
+    let (start, counts, time_size) = if version >= b'2' {
+        let at = 44 + block_len(&v1, 4);
+        let (_, v2) = header(at)?;
+        (at + 44, v2, 8)
+    } else {
+        (44, v1, 4)
+    };
+    let [_, _, _, time_count, type_count, char_count] = counts;
+    let end = start + block_len(&counts, time_size);
+    let block = data
+        .get(start..end)
+        .ok_or_else(|| err("truncated data block"))?;
+    if type_count == 0 {
+        return Err(err("no local time types"));
+    }
+
+    let mut pos = 0;
+    let mut transitions = Vec::with_capacity(time_count);
+    for _ in 0..time_count {
+        let b = &block[pos..pos + time_size];
+        transitions.push(if time_size == 8 {
+            i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+        } else {
+            i64::from(i32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        });
+        pos += time_size;
+    }
+    if transitions.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(err("transitions are not ascending"));
+    }
+    let transition_types: Vec<usize> = block[pos..pos + time_count]
+        .iter()
+        .map(|&i| i as usize)
+        .collect();
+    pos += time_count;
+    if transition_types.iter().any(|&i| i >= type_count) {
+        return Err(err("transition type index out of range"));
+    }
+
+    let chars = &block[pos + 6 * type_count..pos + 6 * type_count + char_count];
+    let mut types = Vec::with_capacity(type_count);
+    for i in 0..type_count {
+        let b = &block[pos + 6 * i..pos + 6 * i + 6];
+        let offset = i32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+        let abbr_start = b[5] as usize;
+        let abbr = chars
+            .get(abbr_start..)
+            .and_then(|rest| rest.split(|&c| c == 0).next())
+            .ok_or_else(|| err("abbreviation index out of range"))?;
+        types.push(LocalTimeType {
+            utc_offset_seconds: offset,
+            is_dst: b[4] != 0,
+            abbreviation: String::from_utf8_lossy(abbr).into_owned(),
+        });
+    }
+
+    let rule = if version >= b'2' {
+        let footer = &data[end.min(data.len())..];
+        let text = std::str::from_utf8(footer)
+            .map_err(|_| err("footer is not UTF-8"))?
+            .trim_matches('\n');
+        if text.is_empty() {
+            None
+        } else {
+            Some(PosixRule::parse(text).ok_or_else(|| err("invalid POSIX TZ footer"))?)
+        }
+    } else {
+        None
+    };
+
+    Ok(ZoneData {
+        name: name.to_string(),
+        transitions,
+        transition_types,
+        types,
+        initial: 0,
+        rule,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// POSIX TZ rules
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+struct PosixRule {
+    std: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DstRule {
+    dst: LocalTimeType,
+    start: RuleDate,
+    /// Local (standard) time of day of the start, seconds.
+    start_time: i32,
+    end: RuleDate,
+    /// Local (daylight) time of day of the end, seconds.
+    end_time: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RuleDate {
+    /// `Jn`: day 1..=365, February 29 never counted.
+    Julian(u16),
+    /// `n`: zero-based day 0..=365, counting February 29.
+    ZeroBased(u16),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeek { month: u32, week: u32, weekday: i64 },
+}
+
+impl PosixRule {
+    fn parse(text: &str) -> Option<Self> {
+        let mut p = Cursor {
+            s: text.as_bytes(),
+            i: 0,
+        };
+        let std_name = p.name()?;
+        let std_offset = -p.offset()?;
+        let std = LocalTimeType {
+            utc_offset_seconds: std_offset,
+            is_dst: false,
+            abbreviation: std_name,
+        };
+        if p.done() {
+            return Some(Self { std, dst: None });
+        }
+        let dst_name = p.name()?;
+        let dst_offset = if p.peek().is_some_and(|c| c != b',') {
+            -p.offset()?
+        } else {
+            std_offset + 3600
+        };
+        // Without an explicit rule, use the US rule like common C libraries.
+        let (start, start_time, end, end_time) = if p.eat(b',') {
+            let (start, start_time) = p.rule_part()?;
+            if !p.eat(b',') {
+                return None;
+            }
+            let (end, end_time) = p.rule_part()?;
+            (start, start_time, end, end_time)
+        } else {
+            let m = |month, week| RuleDate::MonthWeek {
+                month,
+                week,
+                weekday: 0,
+            };
+            (m(3, 2), 7200, m(11, 1), 7200)
+        };
+        if !p.done() {
+            return None;
+        }
+        Some(Self {
+            std,
+            dst: Some(DstRule {
+                dst: LocalTimeType {
+                    utc_offset_seconds: dst_offset,
+                    is_dst: true,
+                    abbreviation: dst_name,
+                },
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// DST start and end instants (Unix seconds) in `year`.
+    fn year_transitions(rule: &DstRule, std_offset: i32, year: i64) -> [(i64, bool); 2] {
+        let start = rule.start.day(year) * 86_400 + i64::from(rule.start_time - std_offset);
+        let end =
+            rule.end.day(year) * 86_400 + i64::from(rule.end_time - rule.dst.utc_offset_seconds);
+        [(start, true), (end, false)]
+    }
+
+    fn events(&self, rule: &DstRule, first_year: i64, last_year: i64) -> Vec<(i64, bool)> {
+        let mut events: Vec<(i64, bool)> = (first_year..=last_year)
+            .flat_map(|y| Self::year_transitions(rule, self.std.utc_offset_seconds, y))
+            .collect();
+        events.sort_by_key(|e| e.0);
+        events
+    }
+
+    fn type_at(&self, t: i64) -> &LocalTimeType {
+        let Some(rule) = &self.dst else {
+            return &self.std;
+        };
+        let year = civil_from_days(t.div_euclid(86_400)).0;
+        let events = self.events(rule, year - 1, year + 1);
+        match events.iter().rev().find(|e| e.0 <= t) {
+            Some(&(_, true)) => &rule.dst,
+            _ => &self.std,
+        }
+    }
+
+    fn transitions_between(&self, from: i64, to: i64) -> Vec<(i64, &LocalTimeType)> {
+        let Some(rule) = &self.dst else {
+            return Vec::new();
+        };
+        let first = civil_from_days(from.div_euclid(86_400)).0 - 1;
+        let last = civil_from_days(to.div_euclid(86_400)).0 + 1;
+        self.events(rule, first, last)
+            .into_iter()
+            .filter(|&(t, _)| t > from && t <= to)
+            .map(|(t, is_dst)| (t, if is_dst { &rule.dst } else { &self.std }))
+            .collect()
+    }
+}
+
+impl RuleDate {
+    /// Day number (days since 1970-01-01) of this date in `year`.
+    fn day(self, year: i64) -> i64 {
+        let jan1 = days_from_civil(year, 1, 1);
+        match self {
+            Self::Julian(n) => {
+                let n = i64::from(n);
+                jan1 + n - 1 + i64::from(is_leap(year) && n >= 60)
+            }
+            Self::ZeroBased(n) => jan1 + i64::from(n),
+            Self::MonthWeek {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day =
+                    first + (weekday - first_weekday).rem_euclid(7) + 7 * (week as i64 - 1);
+                let next_month = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+struct Cursor<'a> {
+    s: &'a [u8],
+    i: usize,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.i).copied()
+    }
+
+    fn done(&self) -> bool {
+        self.i >= self.s.len()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.i += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn name(&mut self) -> Option<String> {
+        let start = self.i;
+        let name = if self.eat(b'<') {
+            while self.peek().is_some_and(|c| c != b'>') {
+                self.i += 1;
+            }
+            let name = &self.s[start + 1..self.i];
+            if !self.eat(b'>') {
+                return None;
+            }
+            name
+        } else {
+            while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                self.i += 1;
+            }
+            &self.s[start..self.i]
+        };
+        (name.len() >= 3).then(|| String::from_utf8_lossy(name).into_owned())
+    }
+
+    fn number(&mut self) -> Option<i32> {
+        let start = self.i;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.i += 1;
+        }
+        std::str::from_utf8(&self.s[start..self.i])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds.
+    fn offset(&mut self) -> Option<i32> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number()? * 3600;
+        if self.eat(b':') {
+            seconds += self.number()? * 60;
+            if self.eat(b':') {
+                seconds += self.number()?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    fn rule_part(&mut self) -> Option<(RuleDate, i32)> {
+        let date = if self.eat(b'J') {
+            RuleDate::Julian(u16::try_from(self.number()?).ok()?)
+        } else if self.eat(b'M') {
+            let month = u32::try_from(self.number()?).ok()?;
+            if !self.eat(b'.') {
+                return None;
+            }
+            let week = self.number()?;
+            if !self.eat(b'.') {
+                return None;
+            }
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday)
+            {
+                return None;
+            }
+            RuleDate::MonthWeek {
+                month,
+                week: week as u32,
+                weekday: i64::from(weekday),
+            }
+        } else {
+            RuleDate::ZeroBased(u16::try_from(self.number()?).ok()?)
+        };
+        let time = if self.eat(b'/') { self.offset()? } else { 7200 };
+        Some((date, time))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Proleptic Gregorian day numbers (days since 1970-01-01)
+// ---------------------------------------------------------------------------
+
+fn is_leap(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = i64::from(month);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unix(y: i64, mo: u32, d: u32, h: i64, mi: i64) -> i64 {
+        days_from_civil(y, mo, d) * 86_400 + h * 3600 + mi * 60
+    }
+
+    #[test]
+    fn day_numbers_roundtrip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(
+            days_from_civil(2000, 1, 1) * 86_400 + 43_200,
+            UNIX_TO_J2000_SECONDS
+        );
+        for days in [-800_000, -1, 0, 59, 10_957, 20_000, 400_000] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn posix_rule_us_eastern() {
+        let rule = PosixRule::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        // 2030: DST from Mar 10 07:00Z to Nov 3 06:00Z.
+        let start = unix(2030, 3, 10, 7, 0);
+        let end = unix(2030, 11, 3, 6, 0);
+        assert_eq!(rule.type_at(start - 1).abbreviation, "EST");
+        assert_eq!(rule.type_at(start).abbreviation, "EDT");
+        assert_eq!(rule.type_at(end - 1).utc_offset_seconds, -4 * 3600);
+        assert_eq!(rule.type_at(end).utc_offset_seconds, -5 * 3600);
+        let transitions = rule.transitions_between(start - 10, end);
+        assert_eq!(transitions.len(), 2);
+    }
+
+    #[test]
+    fn posix_rule_southern_and_quoted() {
+        // Australia/Sydney: DST from first Sunday of October to first Sunday
+        // of April, 03:00 local daylight time.
+        let rule = PosixRule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert!(rule.type_at(unix(2031, 1, 15, 0, 0)).is_dst);
+        assert!(!rule.type_at(unix(2031, 6, 15, 0, 0)).is_dst);
+
+        let fixed = PosixRule::parse("<+0530>-5:30").unwrap();
+        assert_eq!(fixed.std.abbreviation, "+0530");
+        assert_eq!(fixed.std.utc_offset_seconds, 19_800);
+        assert!(PosixRule::parse("EST5EDT,M13.1.0,M11.1.0").is_none());
+    }
+
+    #[test]
+    fn embedded_zone_history_and_footer() {
+        let kolkata = TimeZone::get("asia/kolkata").unwrap();
+        assert_eq!(kolkata.name(), "Asia/Kolkata");
+        let link = TimeZone::get("Asia/Calcutta").unwrap();
+        assert_eq!(link.type_at_unix(0), kolkata.type_at_unix(0));
+        // Wartime IST+1 (UTC+06:30) in 1943.
+        let war = kolkata.type_at_unix(unix(1943, 6, 1, 0, 0));
+        assert_eq!(war.utc_offset_seconds, 23_400);
+        assert_eq!(
+            kolkata.type_at_unix(unix(2024, 1, 1, 0, 0)).abbreviation,
+            "IST"
+        );
+
+        let ny = TimeZone::get("America/New_York").unwrap();
+        // Beyond the table (slim TZif), from the footer rule.
+        assert!(ny.type_at_unix(unix(2090, 7, 1, 0, 0)).is_dst);
+        assert!(!ny.type_at_unix(unix(2090, 12, 1, 0, 0)).is_dst);
+        assert_eq!(ny.type_at_unix(unix(1850, 1, 1, 0, 0)).abbreviation, "LMT");
+
+        assert!(matches!(
+            TimeZone::get("Mars/Olympus_Mons"),
+            Err(TimeError::UnknownTimeZone(_))
+        ));
+        assert!(tzdata_version().is_some());
+        assert!(available_time_zones().any(|z| z == "Europe/London"));
+    }
+
+    #[test]
+    fn local_mean_time_override() {
+        let kolkata = TimeZone::get("Asia/Kolkata").unwrap();
+        let t = unix(1850, 1, 1, 0, 0);
+        let utc_s = (t - UNIX_TO_J2000_SECONDS) as f64;
+        assert!(kolkata.is_pre_standard_time(utc_s));
+        // Database LMT is Kolkata's (+05:53:28).
+        assert_eq!(kolkata.type_at_unix(t).utc_offset_seconds, 21_208);
+        let delhi = kolkata.with_local_mean_time(77.209);
+        assert_eq!(delhi.type_at_unix(t).utc_offset_seconds, 18_530);
+        // Later periods are unchanged.
+        let modern = unix(2000, 1, 1, 0, 0);
+        assert_eq!(delhi.type_at_unix(modern), kolkata.type_at_unix(modern));
+        assert_eq!(
+            TimeZone::local_mean_time(-73.5)
+                .local_time_type_at(0.0)
+                .utc_offset_seconds,
+            -17_640
+        );
+        assert_eq!(TimeZone::fixed(-9_000).name(), "-02:30");
+    }
+
+    #[test]
+    fn rejects_malformed_tzif() {
+        assert!(matches!(
+            TimeZone::from_tzif("bad", b"TZif2"),
+            Err(TimeError::TzifParse(_))
+        ));
+        assert!(TimeZone::from_tzif("bad", &[0u8; 60]).is_err());
+    }
+}
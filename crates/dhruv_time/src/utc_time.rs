@@ -3,9 +3,10 @@
 //! Provides `UtcTime`, the canonical UTC representation used throughout
 //! the engine. Conversion to/from JD TDB requires a [`LeapSecondKernel`].
 
-use crate::LeapSecondKernel;
 use crate::error::TimeError;
+use crate::julian::{J2000_JD, SECONDS_PER_DAY};
 use crate::julian::{calendar_to_jd, jd_to_calendar, jd_to_tdb_seconds, tdb_seconds_to_jd};
+use crate::{Epoch, LeapSecondKernel};
 
 /// UTC calendar date with sub-second precision.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -201,6 +202,49 @@ impl UtcTime {
             second,
         }
     }
+
+    /// UTC seconds past J2000.0, split by calendar day so the time of day
+    /// keeps full `f64` resolution.
+    pub fn to_utc_seconds(&self) -> f64 {
+        let midnight_jd = calendar_to_jd(self.year, self.month, self.day as f64);
+        let day_seconds = (midnight_jd - J2000_JD) * SECONDS_PER_DAY;
+        day_seconds + (self.hour as f64 * 3600.0 + self.minute as f64 * 60.0 + self.second)
+    }
+
+    /// UTC calendar time from UTC seconds past J2000.0.
+    pub fn from_utc_seconds(utc_s: f64) -> Self {
+        // Days since 2000-01-01T00:00 UTC (J2000 is noon).
+        let since_midnight = utc_s + SECONDS_PER_DAY / 2.0;
+        let days = (since_midnight / SECONDS_PER_DAY).floor();
+        let mut seconds_of_day = since_midnight - days * SECONDS_PER_DAY;
+        let mut days = days;
+        if seconds_of_day >= SECONDS_PER_DAY {
+            seconds_of_day -= SECONDS_PER_DAY;
+            days += 1.0;
+        }
+        let (year, month, day_frac) = jd_to_calendar(J2000_JD - 0.5 + days);
+        let hour = (seconds_of_day / 3600.0).floor() as u32;
+        let minute = ((seconds_of_day - hour as f64 * 3600.0) / 60.0).floor() as u32;
+        Self {
+            year,
+            month,
+            day: day_frac.round() as u32,
+            hour,
+            minute,
+            second: seconds_of_day - hour as f64 * 3600.0 - minute as f64 * 60.0,
+        }
+    }
+
+    /// Convert to a TDB [`Epoch`] using leap-second data.
+    pub fn to_epoch(&self, lsk: &LeapSecondKernel) -> Epoch {
+        Epoch::from_tdb_seconds(lsk.utc_to_tdb(self.to_utc_seconds()))
+    }
+
+    /// Convert a TDB [`Epoch`] to UTC calendar time without passing
+    /// through a single-`f64` Julian Date.
+    pub fn from_epoch(epoch: Epoch, lsk: &LeapSecondKernel) -> Self {
+        Self::from_utc_seconds(lsk.tdb_to_utc(epoch.as_tdb_seconds()))
+    }
 }
 
 fn is_leap_year(year: i32) -> bool {
@@ -282,6 +326,21 @@ mod tests {
         assert!(s.contains("12:30:"), "got: {s}");
     }
 
+    #[test]
+    fn utc_seconds_roundtrip_keeps_sub_microseconds() {
+        let t = UtcTime::new(2024, 12, 31, 23, 59, 59.123_456_7);
+        let s = t.to_utc_seconds();
+        let back = UtcTime::from_utc_seconds(s);
+        assert_eq!((back.year, back.month, back.day), (2024, 12, 31));
+        assert_eq!((back.hour, back.minute), (23, 59));
+        assert!((back.second - 59.123_456_7).abs() < 1e-6);
+        let noon = UtcTime::from_utc_seconds(0.0);
+        assert_eq!(
+            (noon.year, noon.month, noon.day, noon.hour),
+            (2000, 1, 1, 12)
+        );
+    }
+
     fn test_lsk() -> LeapSecondKernel {
         let content = r#"
 \begindata
@@ -329,4 +388,15 @@ DELTET/DELTA_AT  = ( 10, @1972-JAN-1
         assert_eq!(t.minute, 0);
         assert!((t.second - 0.2).abs() < 1e-9);
     }
+
+    #[test]
+    fn epoch_roundtrip_matches_jd_path() {
+        let lsk = test_lsk();
+        let t = UtcTime::new(2024, 3, 20, 3, 6, 21.250_125);
+        let epoch = t.to_epoch(&lsk);
+        assert!((epoch.as_jd_tdb() - t.to_jd_tdb(&lsk)).abs() < 1e-9);
+        let back = UtcTime::from_epoch(epoch, &lsk);
+        assert_eq!((back.day, back.hour, back.minute), (20, 3, 6));
+        assert!((back.second - 21.250_125).abs() < 1e-6);
+    }
 }
@@ -17,7 +17,8 @@ use crate::ayanamsha_anchor::{
 };
 use crate::ayanamsha_tara::{tara_anchor_ayanamsha_deg, tara_anchor_ayanamsha_deg_on_plane};
 use dhruv_frames::{
-    DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, nutation_iau2000b,
+    DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel,
+    ReferencePlane, nutation_iau2000b, nutation_with_model,
     precess_ecliptic_j2000_to_date_with_model,
 };
 use dhruv_tara::TaraCatalog;
@@ -260,14 +261,41 @@ pub fn ayanamsha_deg_on_plane(
     use_nutation: bool,
     model: PrecessionModel,
     plane: ReferencePlane,
+) -> f64 {
+    ayanamsha_deg_on_plane_with_models(
+        system,
+        t_centuries,
+        use_nutation,
+        model,
+        DEFAULT_NUTATION_MODEL,
+        plane,
+    )
+}
+
+/// Compute ayanamsha on a specified reference plane with explicit precession
+/// and nutation models.
+///
+/// `nutation_model` only matters when `use_nutation` is true and
+/// `plane == Ecliptic`.
+pub fn ayanamsha_deg_on_plane_with_models(
+    system: AyanamshaSystem,
+    t_centuries: f64,
+    use_nutation: bool,
+    precession_model: PrecessionModel,
+    nutation_model: NutationModel,
+    plane: ReferencePlane,
 ) -> f64 {
     match plane {
-        ReferencePlane::Ecliptic => {
-            ayanamsha_deg_with_model(system, t_centuries, use_nutation, model)
-        }
+        ReferencePlane::Ecliptic => ayanamsha_deg_with_models(
+            system,
+            t_centuries,
+            use_nutation,
+            precession_model,
+            nutation_model,
+        ),
         ReferencePlane::Invariable => {
             // Nutation not applicable on invariable plane.
-            ayanamsha_mean_deg_on_plane(system, t_centuries, model, plane)
+            ayanamsha_mean_deg_on_plane(system, t_centuries, precession_model, plane)
         }
     }
 }
@@ -400,6 +428,17 @@ pub fn ayanamsha_true_deg_with_model(
     ayanamsha_mean_deg_with_model(system, t_centuries, model) + delta_psi_arcsec / 3600.0
 }
 
+/// "True"-mode ayanamsha with Δψ taken from the selected nutation model, in degrees.
+pub fn ayanamsha_true_deg_with_models(
+    system: AyanamshaSystem,
+    t_centuries: f64,
+    precession_model: PrecessionModel,
+    nutation_model: NutationModel,
+) -> f64 {
+    let (delta_psi_arcsec, _) = nutation_with_model(t_centuries, nutation_model);
+    ayanamsha_true_deg_with_model(system, t_centuries, delta_psi_arcsec, precession_model)
+}
+
 /// Compute ayanamsha, optionally with nutation correction.
 ///
 /// When `use_nutation` is true, nutation in longitude (Δψ) is computed
@@ -425,9 +464,29 @@ pub fn ayanamsha_deg_with_model(
     use_nutation: bool,
     model: PrecessionModel,
 ) -> f64 {
-    let mean = ayanamsha_mean_deg_with_model(system, t_centuries, model);
+    ayanamsha_deg_with_models(
+        system,
+        t_centuries,
+        use_nutation,
+        model,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// Compute ayanamsha, optionally with nutation, with selected precession and
+/// nutation models.
+///
+/// When `use_nutation` is true, Δψ from `nutation_model` is added for all systems.
+pub fn ayanamsha_deg_with_models(
+    system: AyanamshaSystem,
+    t_centuries: f64,
+    use_nutation: bool,
+    precession_model: PrecessionModel,
+    nutation_model: NutationModel,
+) -> f64 {
+    let mean = ayanamsha_mean_deg_with_model(system, t_centuries, precession_model);
     if use_nutation {
-        let (delta_psi_arcsec, _) = nutation_iau2000b(t_centuries);
+        let (delta_psi_arcsec, _) = nutation_with_model(t_centuries, nutation_model);
         mean + delta_psi_arcsec / 3600.0
     } else {
         mean
@@ -498,9 +557,30 @@ pub fn ayanamsha_deg_with_catalog_and_model(
     catalog: Option<&TaraCatalog>,
     model: PrecessionModel,
 ) -> f64 {
-    let mean = ayanamsha_mean_deg_with_catalog_and_model(system, t_centuries, catalog, model);
+    ayanamsha_deg_with_catalog_and_models(
+        system,
+        t_centuries,
+        use_nutation,
+        catalog,
+        model,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// Compute ayanamsha with optional nutation, star catalog, and precession and
+/// nutation models.
+pub fn ayanamsha_deg_with_catalog_and_models(
+    system: AyanamshaSystem,
+    t_centuries: f64,
+    use_nutation: bool,
+    catalog: Option<&TaraCatalog>,
+    precession_model: PrecessionModel,
+    nutation_model: NutationModel,
+) -> f64 {
+    let mean =
+        ayanamsha_mean_deg_with_catalog_and_model(system, t_centuries, catalog, precession_model);
     if use_nutation {
-        let (delta_psi_arcsec, _) = nutation_iau2000b(t_centuries);
+        let (delta_psi_arcsec, _) = nutation_with_model(t_centuries, nutation_model);
         mean + delta_psi_arcsec / 3600.0
     } else {
         mean
@@ -633,6 +713,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn true_deg_with_models_uses_selected_nutation() {
+        let t = 0.24;
+        for nut in [NutationModel::Iau1980, NutationModel::Iau2000B] {
+            let (dpsi, _) = nutation_with_model(t, nut);
+            let expected = ayanamsha_true_deg(AyanamshaSystem::TrueLahiri, t, dpsi);
+            let got = ayanamsha_true_deg_with_models(
+                AyanamshaSystem::TrueLahiri,
+                t,
+                DEFAULT_PRECESSION_MODEL,
+                nut,
+            );
+            assert!(
+                (got - expected).abs() < 1e-12,
+                "{nut:?}: {got} vs {expected}"
+            );
+        }
+        let default = ayanamsha_deg(AyanamshaSystem::Lahiri, t, true);
+        let via_models = ayanamsha_deg_with_models(
+            AyanamshaSystem::Lahiri,
+            t,
+            true,
+            DEFAULT_PRECESSION_MODEL,
+            DEFAULT_NUTATION_MODEL,
+        );
+        assert_eq!(default, via_models);
+    }
+
     #[test]
     fn true_deg_applies_nutation_all_systems() {
         let dpsi = 17.0; // arcseconds
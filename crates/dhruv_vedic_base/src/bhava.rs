@@ -11,7 +11,7 @@
 
 use std::f64::consts::{PI, TAU};
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
 use dhruv_frames::{cartesian_to_spherical, icrf_to_ecliptic, precess_ecliptic_j2000_to_date};
 use dhruv_time::{LeapSecondKernel, jd_to_tdb_seconds, tdb_seconds_to_jd};
 
@@ -155,6 +155,7 @@ fn body_ecliptic_longitude_deg(
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
     let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
@@ -36,6 +36,9 @@ pub mod combustion {
 pub mod dasha {
     pub use dhruv_vedic_math::dasha::*;
 }
+pub mod dosha {
+    pub use dhruv_vedic_math::dosha::*;
+}
 pub mod drishti {
     pub use dhruv_vedic_math::drishti::*;
 }
@@ -57,6 +60,9 @@ pub mod hora {
 pub mod karana {
     pub use dhruv_vedic_math::karana::*;
 }
+pub mod kp {
+    pub use dhruv_vedic_math::kp::*;
+}
 pub mod lagna {
     pub use dhruv_vedic_engine::lagna::*;
 }
@@ -66,6 +72,12 @@ pub mod lunar_nodes {
 pub mod masa {
     pub use dhruv_vedic_math::masa::*;
 }
+pub mod milan {
+    pub use dhruv_vedic_math::milan::*;
+}
+pub mod muhurta {
+    pub use dhruv_vedic_math::muhurta::*;
+}
 pub mod nakshatra {
     pub use dhruv_vedic_math::nakshatra::*;
 
@@ -148,6 +160,9 @@ pub mod vimsopaka {
 pub mod yoga {
     pub use dhruv_vedic_math::yoga::*;
 }
+pub mod yoga_detection {
+    pub use dhruv_vedic_math::yoga_detection::*;
+}
 
 pub use dhruv_vedic_engine::VedicError;
 pub use dhruv_vedic_engine::*;
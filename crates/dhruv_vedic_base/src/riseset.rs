@@ -10,7 +10,7 @@
 
 use std::f64::consts::TAU;
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
 use dhruv_frames::{icrf_to_ecliptic, mean_obliquity_of_date_rad, precess_ecliptic_j2000_to_date};
 use dhruv_time::{
     EopKernel, LeapSecondKernel, gmst_rad, jd_to_tdb_seconds, local_sidereal_time_rad,
@@ -52,6 +52,7 @@ fn sun_equatorial_ra_dec_dist(engine: &Engine, jd_tdb: f64) -> Result<(f64, f64,
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
 
@@ -8,39 +8,8 @@ use std::f64::consts::PI;
 /// Mean Earth radius in meters (IAU nominal, for geometric dip).
 const EARTH_RADIUS_M: f64 = 6_371_000.0;
 
-/// Geographic location on Earth's surface.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GeoLocation {
-    /// Geodetic latitude in degrees, north positive. Range: [-90, 90].
-    /// **Not validated** — out-of-range values are accepted but produce
-    /// undefined astronomical results from downstream functions.
-    pub latitude_deg: f64,
-    /// Geodetic longitude in degrees, east positive. Range: [-180, 180].
-    pub longitude_deg: f64,
-    /// Altitude above mean sea level in meters.
-    pub altitude_m: f64,
-}
-
-impl GeoLocation {
-    /// Create a new geographic location.
-    pub fn new(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Self {
-        Self {
-            latitude_deg,
-            longitude_deg,
-            altitude_m,
-        }
-    }
-
-    /// Latitude in radians.
-    pub fn latitude_rad(&self) -> f64 {
-        self.latitude_deg.to_radians()
-    }
-
-    /// Longitude in radians (east positive).
-    pub fn longitude_rad(&self) -> f64 {
-        self.longitude_deg.to_radians()
-    }
-}
+/// Geographic location on Earth's surface (WGS84 geodetic).
+pub use dhruv_core::GeoLocation;
 
 /// Rise/set event types, including twilight variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -6,7 +6,7 @@
 use std::f64::consts::{PI, TAU};
 use std::path::Path;
 
-use dhruv_core::{Body, Engine, EngineConfig};
+use dhruv_core::{Body, Engine, EngineConfig, SpkLoadMode};
 use dhruv_time::{EopKernel, LeapSecondKernel, gmst_rad, local_sidereal_time_rad};
 use dhruv_vedic_base::{
     BhavaConfig, BhavaReferenceMode, BhavaStartingPoint, BhavaSystem, GeoLocation, compute_bhavas,
@@ -31,6 +31,7 @@ fn load_test_resources() -> Option<(Engine, LeapSecondKernel, EopKernel)> {
         lsk_path: LSK_PATH.into(),
         cache_capacity: 1024,
         strict_validation: false,
+        spk_load_mode: SpkLoadMode::InMemory,
     };
     let engine = Engine::new(config).ok()?;
     let lsk = LeapSecondKernel::load(Path::new(LSK_PATH)).ok()?;
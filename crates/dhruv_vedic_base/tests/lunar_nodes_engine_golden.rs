@@ -4,7 +4,7 @@
 
 use std::path::{Path, PathBuf};
 
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Engine, EngineConfig, SpkLoadMode};
 use dhruv_frames::PrecessionModel;
 use dhruv_time::{LeapSecondKernel, UtcTime};
 use dhruv_vedic_base::{
@@ -31,6 +31,7 @@ fn load_test_resources() -> Option<(Engine, LeapSecondKernel)> {
         lsk_path: lsk.clone(),
         cache_capacity: 1024,
         strict_validation: false,
+        spk_load_mode: SpkLoadMode::InMemory,
     };
     let engine = Engine::new(config).ok()?;
     let lsk_kernel = LeapSecondKernel::load(&lsk).ok()?;
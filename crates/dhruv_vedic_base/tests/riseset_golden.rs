@@ -5,7 +5,7 @@
 
 use std::path::Path;
 
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Engine, EngineConfig, SpkLoadMode};
 use dhruv_time::{EopKernel, LeapSecondKernel};
 use dhruv_vedic_base::{
     GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult, approximate_local_noon_jd,
@@ -30,6 +30,7 @@ fn load_test_resources() -> Option<(Engine, LeapSecondKernel, EopKernel)> {
         lsk_path: LSK_PATH.into(),
         cache_capacity: 1024,
         strict_validation: false,
+        spk_load_mode: SpkLoadMode::InMemory,
     };
     let engine = Engine::new(config).ok()?;
     let lsk = LeapSecondKernel::load(Path::new(LSK_PATH)).ok()?;
@@ -11,8 +11,8 @@
 
 use std::f64::consts::{PI, TAU};
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
-use dhruv_frames::{cartesian_to_spherical, icrf_to_ecliptic, precess_ecliptic_j2000_to_date};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
+use dhruv_frames::{DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, cartesian_to_spherical};
 use dhruv_time::{LeapSecondKernel, jd_to_tdb_seconds, tdb_seconds_to_jd};
 
 use crate::bhava_types::{
@@ -50,7 +50,14 @@ pub fn compute_bhavas(
     config: &BhavaConfig,
 ) -> Result<BhavaResult, VedicError> {
     // Compute apparent (GAST-based) LST and true obliquity once
-    let (lst_apparent, eps_true) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc)?;
+    let (lst_apparent, eps_true) = apparent_lst_and_true_eps(
+        lsk,
+        eop,
+        location,
+        jd_utc,
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    )?;
     let lat_rad = location.latitude_rad();
     let (asc_rad, mc_rad, ramc) = lagna_mc_ramc_from_lst(lst_apparent, lat_rad, eps_true);
 
@@ -153,14 +160,12 @@ fn body_ecliptic_longitude_deg(
     let query = Query {
         target: body,
         observer: Observer::Body(Body::Earth),
-        frame: Frame::IcrfJ2000,
+        frame: Frame::MeanEclipticOfDate(DEFAULT_PRECESSION_MODEL),
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
-    let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-    let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, t);
-    let sph = cartesian_to_spherical(&ecl_date);
+    let sph = cartesian_to_spherical(&state.position_km);
     Ok(normalize_deg(sph.lon_deg))
 }
 
@@ -612,6 +617,7 @@ mod tests {
     use dhruv_frames::OBLIQUITY_J2000_RAD;
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn equal_cusps_30_deg_apart() {
         let cusps = compute_equal(100.0);
         for i in 0..12 {
@@ -675,6 +681,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn build_bhavas_numbers() {
         let cusps = compute_equal(0.0);
         let bhavas = build_bhavas(&cusps, BhavaReferenceMode::StartOfFirst);
@@ -747,6 +754,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn axial_rotation_cusps_valid() {
         let ramc = 1.5;
         let eps = OBLIQUITY_J2000_RAD;
@@ -761,6 +769,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn regiomontanus_cusps_valid() {
         let ramc = 1.0;
         let lat = 28.6_f64.to_radians();
@@ -776,6 +785,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn campanus_cusps_valid() {
         let ramc = 2.0;
         let lat = 28.6_f64.to_radians();
@@ -68,9 +68,10 @@ impl BhavaSystem {
 }
 
 /// What defines the starting point for house cusps.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum BhavaStartingPoint {
     /// Use the Lagna (Ascendant) as the starting point (default).
+    #[default]
     Lagna,
     /// Use a body's ecliptic longitude as the starting point.
     BodyLongitude(Body),
@@ -78,12 +79,6 @@ pub enum BhavaStartingPoint {
     CustomDeg(f64),
 }
 
-impl Default for BhavaStartingPoint {
-    fn default() -> Self {
-        Self::Lagna
-    }
-}
-
 /// Whether the starting point is the start or middle of bhava 1.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum BhavaReferenceMode {
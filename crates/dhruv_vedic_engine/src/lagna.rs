@@ -3,9 +3,12 @@
 //! Standalone reusable module implementing the standard spherical astronomy
 //! formulas for the ecliptic longitude of the Lagna and MC.
 //!
-//! Uses apparent (GAST-based) local sidereal time and true obliquity
-//! (IAU 2006 mean + IAU 2000B nutation), matching the standard astrological
-//! convention (Meeus Ch. 13, IERS 2010).
+//! Uses apparent (GAST-based) local sidereal time from
+//! [`dhruv_time::last_rad`] and true obliquity (IAU 2006 mean + IAU 2000B
+//! nutation), matching the standard astrological convention (Meeus Ch. 13,
+//! IERS 2010). The `_with_model` variants choose the precession and
+//! nutation models behind GAST and the true obliquity; the plain functions
+//! use [`DEFAULT_PRECESSION_MODEL`] and [`DEFAULT_NUTATION_MODEL`].
 //!
 //! Sources: Meeus, "Astronomical Algorithms" (2nd ed), Chapter 13;
 //! standard spherical astronomy (Montenbruck & Pfleger).
@@ -13,11 +16,11 @@
 
 use std::f64::consts::TAU;
 
-use dhruv_frames::equation_of_equinoxes_and_true_obliquity;
-use dhruv_time::{
-    EopKernel, LeapSecondKernel, gmst_rad, jd_to_tdb_seconds, local_sidereal_time_rad,
-    tdb_seconds_to_jd,
+use dhruv_frames::{
+    DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel,
+    equation_of_equinoxes_and_true_obliquity_with_model,
 };
+use dhruv_time::{EopKernel, LeapSecondKernel, jd_to_tdb_seconds, last_rad, tdb_seconds_to_jd};
 
 use crate::error::VedicError;
 use crate::riseset_types::GeoLocation;
@@ -25,9 +28,9 @@ use crate::time_policy::time_conversion_policy;
 
 /// Compute apparent (GAST-based) local sidereal time and true obliquity.
 ///
-/// Calls `nutation_iau2000b(t)` once via [`equation_of_equinoxes_and_true_obliquity`], then:
-/// - GAST = GMST + Δψ·cos(ε_mean)  (equation of equinoxes, IERS 2010)
-/// - True ε = ε_mean + Δε           (nutation in obliquity)
+/// - LAST = ERA(UT1) − EO(TDB) + λ, from [`last_rad`] for `model` and
+///   `nutation`
+/// - True ε = ε_mean + Δε (nutation in obliquity from `nutation`)
 ///
 /// Returns `(apparent_lst_rad, true_eps_rad)`.
 pub(crate) fn apparent_lst_and_true_eps(
@@ -35,13 +38,12 @@ pub(crate) fn apparent_lst_and_true_eps(
     eop: &EopKernel,
     location: &GeoLocation,
     jd_utc: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
 ) -> Result<(f64, f64), VedicError> {
-    // GMST-based LST
     let jd_ut1 = eop.utc_to_ut1_jd(jd_utc)?;
-    let gmst = gmst_rad(jd_ut1);
-    let lst_mean = local_sidereal_time_rad(gmst, location.longitude_rad());
 
-    // TDB epoch for nutation and obliquity
+    // TDB epoch for precession-nutation and obliquity (TT to well under 1 ms)
     let utc_s = jd_to_tdb_seconds(jd_utc);
     let tdb_s = lsk
         .utc_to_tdb_with_policy_and_eop(utc_s, Some(eop), time_conversion_policy())
@@ -49,9 +51,8 @@ pub(crate) fn apparent_lst_and_true_eps(
     let jd_tdb = tdb_seconds_to_jd(tdb_s);
     let t = (jd_tdb - 2_451_545.0) / 36525.0;
 
-    // Single nutation call → both EE and true obliquity
-    let (ee_rad, eps_true) = equation_of_equinoxes_and_true_obliquity(t);
-    let lst_apparent = (lst_mean + ee_rad).rem_euclid(TAU);
+    let lst_apparent = last_rad(jd_ut1, jd_tdb, location.longitude_rad(), model, nutation);
+    let (_, eps_true) = equation_of_equinoxes_and_true_obliquity_with_model(t, nutation);
 
     Ok((lst_apparent, eps_true))
 }
@@ -76,7 +77,27 @@ pub fn lagna_longitude_rad(
     location: &GeoLocation,
     jd_utc: f64,
 ) -> Result<f64, VedicError> {
-    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc)?;
+    lagna_longitude_rad_with_model(
+        lsk,
+        eop,
+        location,
+        jd_utc,
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// [`lagna_longitude_rad`] with explicit precession and nutation models for
+/// sidereal time and obliquity.
+pub fn lagna_longitude_rad_with_model(
+    lsk: &LeapSecondKernel,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_utc: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> Result<f64, VedicError> {
+    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc, model, nutation)?;
     let phi = location.latitude_rad();
 
     let asc = f64::atan2(lst.cos(), -(lst.sin() * eps.cos() + phi.tan() * eps.sin()));
@@ -96,7 +117,27 @@ pub fn mc_longitude_rad(
     location: &GeoLocation,
     jd_utc: f64,
 ) -> Result<f64, VedicError> {
-    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc)?;
+    mc_longitude_rad_with_model(
+        lsk,
+        eop,
+        location,
+        jd_utc,
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// [`mc_longitude_rad`] with explicit precession and nutation models for
+/// sidereal time and obliquity.
+pub fn mc_longitude_rad_with_model(
+    lsk: &LeapSecondKernel,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_utc: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> Result<f64, VedicError> {
+    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc, model, nutation)?;
 
     let mc = f64::atan2(lst.sin(), lst.cos() * eps.cos());
     Ok(mc.rem_euclid(TAU))
@@ -113,7 +154,27 @@ pub fn lagna_and_mc_rad(
     location: &GeoLocation,
     jd_utc: f64,
 ) -> Result<(f64, f64), VedicError> {
-    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc)?;
+    lagna_and_mc_rad_with_model(
+        lsk,
+        eop,
+        location,
+        jd_utc,
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// [`lagna_and_mc_rad`] with explicit precession and nutation models for
+/// sidereal time and obliquity.
+pub fn lagna_and_mc_rad_with_model(
+    lsk: &LeapSecondKernel,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_utc: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> Result<(f64, f64), VedicError> {
+    let (lst, eps) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc, model, nutation)?;
     let phi = location.latitude_rad();
 
     let asc = f64::atan2(lst.cos(), -(lst.sin() * eps.cos() + phi.tan() * eps.sin()));
@@ -134,7 +195,27 @@ pub fn ramc_rad(
     location: &GeoLocation,
     jd_utc: f64,
 ) -> Result<f64, VedicError> {
-    let (lst_apparent, _) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc)?;
+    ramc_rad_with_model(
+        lsk,
+        eop,
+        location,
+        jd_utc,
+        DEFAULT_PRECESSION_MODEL,
+        DEFAULT_NUTATION_MODEL,
+    )
+}
+
+/// [`ramc_rad`] with explicit precession and nutation models for
+/// sidereal time and obliquity.
+pub fn ramc_rad_with_model(
+    lsk: &LeapSecondKernel,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_utc: f64,
+    model: PrecessionModel,
+    nutation: NutationModel,
+) -> Result<f64, VedicError> {
+    let (lst_apparent, _) = apparent_lst_and_true_eps(lsk, eop, location, jd_utc, model, nutation)?;
     Ok(lst_apparent)
 }
 
@@ -379,7 +460,7 @@ mod tests {
                 let (asc, _, _) = lagna_mc_ramc_from_lst(lst, phi, OBLIQUITY_J2000_RAD);
                 // rem_euclid(TAU) can return exactly TAU due to f64 rounding
                 assert!(
-                    asc.is_finite() && asc >= 0.0 && asc <= TAU,
+                    asc.is_finite() && (0.0..=TAU).contains(&asc),
                     "lat={lat_deg}, LST={}: asc={asc}",
                     lst.to_degrees()
                 );
@@ -394,7 +475,7 @@ mod tests {
             let phi = lat_deg.to_radians();
             let (asc, _, _) = lagna_mc_ramc_from_lst(1.0, phi, OBLIQUITY_J2000_RAD);
             assert!(
-                asc.is_finite() && asc >= 0.0 && asc <= TAU,
+                asc.is_finite() && (0.0..=TAU).contains(&asc),
                 "lat={lat_deg}: asc={asc}"
             );
         }
@@ -407,7 +488,7 @@ mod tests {
             let phi = lat_deg.to_radians();
             let (asc, _, _) = lagna_mc_ramc_from_lst(1.0, phi, OBLIQUITY_J2000_RAD);
             assert!(
-                asc.is_finite() && asc >= 0.0 && asc <= TAU,
+                asc.is_finite() && (0.0..=TAU).contains(&asc),
                 "lat={lat_deg}: asc={asc}"
             );
         }
@@ -421,7 +502,7 @@ mod tests {
         for &lst in &[PI / 2.0 - 0.001, PI / 2.0, PI / 2.0 + 0.001] {
             let (asc, _, _) = lagna_mc_ramc_from_lst(lst, phi, OBLIQUITY_J2000_RAD);
             assert!(
-                asc.is_finite() && asc >= 0.0 && asc <= TAU,
+                asc.is_finite() && (0.0..=TAU).contains(&asc),
                 "lst={}, phi=-66.56: asc={}",
                 lst.to_degrees(),
                 asc.to_degrees()
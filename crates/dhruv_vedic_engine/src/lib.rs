@@ -13,13 +13,15 @@ pub mod riseset_types;
 pub mod time_policy;
 
 pub use ayanamsha::{
-    AyanamshaSystem, ayanamsha_deg, ayanamsha_deg_on_plane, ayanamsha_deg_static,
-    ayanamsha_deg_with_catalog, ayanamsha_deg_with_catalog_and_model,
-    ayanamsha_deg_with_catalog_on_plane, ayanamsha_deg_with_model, ayanamsha_mean_deg,
+    AyanamshaSystem, ayanamsha_deg, ayanamsha_deg_on_plane, ayanamsha_deg_on_plane_with_models,
+    ayanamsha_deg_static, ayanamsha_deg_with_catalog, ayanamsha_deg_with_catalog_and_model,
+    ayanamsha_deg_with_catalog_and_models, ayanamsha_deg_with_catalog_on_plane,
+    ayanamsha_deg_with_model, ayanamsha_deg_with_models, ayanamsha_mean_deg,
     ayanamsha_mean_deg_static, ayanamsha_mean_deg_static_on_plane,
     ayanamsha_mean_deg_static_with_model, ayanamsha_mean_deg_with_catalog,
     ayanamsha_mean_deg_with_catalog_and_model, ayanamsha_mean_deg_with_model, ayanamsha_true_deg,
-    ayanamsha_true_deg_with_model, jd_tdb_to_centuries, tdb_seconds_to_centuries,
+    ayanamsha_true_deg_with_model, ayanamsha_true_deg_with_models, jd_tdb_to_centuries,
+    tdb_seconds_to_centuries,
 };
 pub use bhava::compute_bhavas;
 pub use bhava_types::{
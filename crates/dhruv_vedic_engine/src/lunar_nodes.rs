@@ -15,7 +15,7 @@
 //!
 //! Clean-room implementation. See `docs/clean_room_lunar_nodes.md`.
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
 use dhruv_frames::{
     DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, fundamental_arguments,
     icrf_to_ecliptic, icrf_to_invariable, precess_ecliptic_j2000_to_date_with_model,
@@ -231,6 +231,7 @@ fn osculating_rahu_deg_on_plane(
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
 
@@ -10,12 +10,9 @@
 
 use std::f64::consts::TAU;
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
-use dhruv_frames::{icrf_to_ecliptic, mean_obliquity_of_date_rad, precess_ecliptic_j2000_to_date};
-use dhruv_time::{
-    EopKernel, LeapSecondKernel, gmst_rad, jd_to_tdb_seconds, local_sidereal_time_rad,
-    tdb_seconds_to_jd,
-};
+use dhruv_core::topocentric::{eop_pole_parameters, gcrs_to_itrs_matrix};
+use dhruv_core::{Body, Correction, Engine, Frame, Observer, Query};
+use dhruv_time::{EopKernel, LeapSecondKernel, jd_to_tdb_seconds, tdb_seconds_to_jd};
 
 use crate::error::VedicError;
 use crate::riseset_types::{GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult};
@@ -27,9 +24,6 @@ const MAX_ITERATIONS: usize = 5;
 /// Convergence threshold in days (~0.086 seconds).
 const CONVERGENCE_DAYS: f64 = 1.0e-6;
 
-/// IAU 2015 nominal solar radius in km (Resolution B3).
-const SUN_RADIUS_KM: f64 = 696_000.0;
-
 /// Approximate local solar noon JD from 0h UT JD and longitude.
 ///
 /// `JD_noon = JD_0h + 0.5 - longitude_deg / 360`
@@ -48,50 +42,58 @@ pub fn utc_day_start_jd(jd_utc: f64) -> f64 {
     (jd_utc + 0.5).floor() - 0.5
 }
 
-/// Compute the Sun's geocentric equatorial RA, Dec, and distance at a given JD TDB.
+/// Compute the Sun's local hour angle, declination, and distance.
 ///
-/// Queries the ephemeris engine for the Sun's position relative to Earth
-/// in ICRF/J2000, then converts to spherical coordinates.
+/// Queries the ephemeris engine for the Sun's geocentric ICRF position and
+/// rotates it into ITRS with the CIO-based Earth rotation (UT1, polar
+/// motion and celestial pole offsets from `eop`). Hour angle and
+/// declination are then read against the terrestrial equator and the
+/// observer's meridian, so no separate sidereal time is needed.
 ///
-/// Returns `(ra_rad, dec_rad, distance_km)` where RA is in [0, 2pi),
-/// Dec in [-pi/2, pi/2], and distance in km.
-fn sun_equatorial_ra_dec_dist(engine: &Engine, jd_tdb: f64) -> Result<(f64, f64, f64), VedicError> {
+/// Returns `(hour_angle_rad, dec_rad, distance_km)` where the hour angle is
+/// in [-pi, pi] (positive west of the meridian), Dec in [-pi/2, pi/2], and
+/// distance in km.
+fn sun_hour_angle_dec_dist(
+    engine: &Engine,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_utc: f64,
+    jd_tdb: f64,
+) -> Result<(f64, f64, f64), VedicError> {
     let query = Query {
         target: Body::Sun,
         observer: Observer::Body(Body::Earth),
         frame: Frame::IcrfJ2000,
         epoch_tdb_jd: jd_tdb,
+        correction: Correction::Geometric,
     };
     let state = engine.query(query)?;
+    let jd_ut1 = eop.utc_to_ut1_jd(jd_utc)?;
+    let matrix = gcrs_to_itrs_matrix(jd_tdb, jd_ut1, &eop_pole_parameters(eop, jd_utc));
+    let itrs = matrix.map(|row| {
+        row[0] * state.position_km[0]
+            + row[1] * state.position_km[1]
+            + row[2] * state.position_km[2]
+    });
+
+    let r = (itrs[0] * itrs[0] + itrs[1] * itrs[1] + itrs[2] * itrs[2]).sqrt();
+    let ha = (location.longitude_rad() - f64::atan2(itrs[1], itrs[0])).rem_euclid(TAU);
+    let ha = if ha > std::f64::consts::PI {
+        ha - TAU
+    } else {
+        ha
+    };
+    let dec = (itrs[2] / r).asin();
 
-    // ICRF J2000 → ecliptic J2000 → ecliptic of date → equatorial of date.
-    // This aligns RA with the equinox-of-date so that hour angle = LST - RA is correct.
-    let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-    let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, t);
-
-    // Ecliptic-of-date → equatorial-of-date: R_x(-eps_of_date).
-    let eps = mean_obliquity_of_date_rad(t);
-    let (se, ce) = eps.sin_cos();
-    let eq_date = [
-        ecl_date[0],
-        ce * ecl_date[1] - se * ecl_date[2],
-        se * ecl_date[1] + ce * ecl_date[2],
-    ];
-
-    let r = (eq_date[0] * eq_date[0] + eq_date[1] * eq_date[1] + eq_date[2] * eq_date[2]).sqrt();
-    let ra = f64::atan2(eq_date[1], eq_date[0]).rem_euclid(TAU);
-    let dec = (eq_date[2] / r).asin();
-
-    Ok((ra, dec, r))
+    Ok((ha, dec, r))
 }
 
 /// Compute solar angular semidiameter from Earth-Sun distance.
 ///
 /// Returns semidiameter in arcminutes.
 /// Varies ~15.7' (aphelion) to ~16.3' (perihelion).
-fn solar_semidiameter_arcmin(distance_km: f64) -> f64 {
-    (SUN_RADIUS_KM / distance_km).asin().to_degrees() * 60.0
+fn solar_semidiameter_arcmin(distance_km: f64, sun_radius_km: f64) -> f64 {
+    (sun_radius_km / distance_km).asin().to_degrees() * 60.0
 }
 
 /// Compute a single rise/set event for the Sun.
@@ -128,9 +130,10 @@ pub fn compute_rise_set(
         .tdb_seconds;
     let jd_tdb_noon = tdb_seconds_to_jd(noon_tdb_s);
 
-    // Initial Sun RA/Dec/distance at noon
-    let (ra, dec, dist) = sun_equatorial_ra_dec_dist(engine, jd_tdb_noon)?;
-    let semidiameter = solar_semidiameter_arcmin(dist);
+    // Initial Sun hour angle/Dec/distance at noon (hour angle close to 0)
+    let (ha_noon, dec, dist) =
+        sun_hour_angle_dec_dist(engine, eop, location, jd_utc_noon, jd_tdb_noon)?;
+    let semidiameter = solar_semidiameter_arcmin(dist, config.radii.sun_km);
 
     // Target altitude (negative = below horizon)
     let h0_deg = config.target_altitude_deg(event, semidiameter, location.altitude_m);
@@ -149,20 +152,6 @@ pub fn compute_rise_set(
 
     let h0 = cos_h0.acos(); // hour angle in radians, always positive
 
-    // Convert noon UTC to UT1 for sidereal time
-    let jd_ut1_noon = eop.utc_to_ut1_jd(jd_utc_noon)?;
-    let gmst_noon = gmst_rad(jd_ut1_noon);
-    let lst_noon = local_sidereal_time_rad(gmst_noon, location.longitude_rad());
-
-    // Sun hour angle at noon (should be close to 0)
-    let ha_noon = (lst_noon - ra).rem_euclid(TAU);
-    // Normalize to [-pi, pi]
-    let ha_noon = if ha_noon > std::f64::consts::PI {
-        ha_noon - TAU
-    } else {
-        ha_noon
-    };
-
     // Transit time (when HA = 0): correct noon by the offset
     // HA advances at ~1.00274 rev/day = TAU * 1.00274 rad/day
     let sidereal_rate = TAU * 1.002_737_811_911_354_6; // rad/day
@@ -186,9 +175,10 @@ pub fn compute_rise_set(
             .tdb_seconds;
         let jd_tdb_event = tdb_seconds_to_jd(event_tdb_s);
 
-        // Recompute Sun RA/Dec/distance at event time
-        let (ra_i, dec_i, dist_i) = sun_equatorial_ra_dec_dist(engine, jd_tdb_event)?;
-        let sd_i = solar_semidiameter_arcmin(dist_i);
+        // Recompute Sun hour angle/Dec/distance at event time
+        let (ha_actual, dec_i, dist_i) =
+            sun_hour_angle_dec_dist(engine, eop, location, jd_utc_event, jd_tdb_event)?;
+        let sd_i = solar_semidiameter_arcmin(dist_i, config.radii.sun_km);
 
         // Recompute target altitude with updated semidiameter
         let h0_deg_i = config.target_altitude_deg(event, sd_i, location.altitude_m);
@@ -204,15 +194,6 @@ pub fn compute_rise_set(
         }
         let h_target = cos_h_i.acos();
 
-        // Compute actual HA at event time via GMST
-        let jd_ut1_event = eop.utc_to_ut1_jd(jd_utc_event)?;
-        let gmst_event = gmst_rad(jd_ut1_event);
-        let lst_event = local_sidereal_time_rad(gmst_event, location.longitude_rad());
-        let mut ha_actual = (lst_event - ra_i).rem_euclid(TAU);
-        if ha_actual > std::f64::consts::PI {
-            ha_actual -= TAU;
-        }
-
         // For rising events, target HA is negative; for setting, positive
         let ha_target = if event.is_rising() {
             -h_target
@@ -296,6 +277,9 @@ pub fn compute_all_events(
 mod tests {
     use super::*;
 
+    /// IAU 2015 nominal solar radius in km.
+    const SUN_KM: f64 = 696_000.0;
+
     #[test]
     fn local_noon_greenwich() {
         let jd_0h = 2_460_000.5;
@@ -327,14 +311,14 @@ mod tests {
 
     #[test]
     fn utc_day_start_after_noon() {
-        let jd_utc = 2_461_117.12939;
+        let jd_utc = 2_461_117.129_39;
         assert!((utc_day_start_jd(jd_utc) - 2_461_116.5).abs() < 1e-10);
     }
 
     #[test]
     fn solar_semidiameter_typical() {
         // 1 AU ≈ 149_597_870.7 km → semidiameter ≈ 16 arcmin
-        let sd = solar_semidiameter_arcmin(149_597_870.7);
+        let sd = solar_semidiameter_arcmin(149_597_870.7, SUN_KM);
         assert!(
             (sd - 16.0).abs() < 0.5,
             "semidiameter at 1 AU = {sd}, expected ~16'"
@@ -344,8 +328,8 @@ mod tests {
     #[test]
     fn solar_semidiameter_varies() {
         // Perihelion (~147.1e6 km) vs aphelion (~152.1e6 km)
-        let sd_peri = solar_semidiameter_arcmin(147_100_000.0);
-        let sd_aph = solar_semidiameter_arcmin(152_100_000.0);
+        let sd_peri = solar_semidiameter_arcmin(147_100_000.0, SUN_KM);
+        let sd_aph = solar_semidiameter_arcmin(152_100_000.0, SUN_KM);
         assert!(sd_peri > sd_aph, "perihelion SD should be larger");
         assert!(sd_peri > 16.0, "perihelion SD ~ 16.3', got {sd_peri}");
         assert!(sd_aph < 16.0, "aphelion SD ~ 15.7', got {sd_aph}");
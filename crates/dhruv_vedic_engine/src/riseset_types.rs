@@ -5,42 +5,10 @@
 
 use std::f64::consts::PI;
 
-/// Mean Earth radius in meters (IAU nominal, for geometric dip).
-const EARTH_RADIUS_M: f64 = 6_371_000.0;
+use dhruv_core::BodyRadii;
 
-/// Geographic location on Earth's surface.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GeoLocation {
-    /// Geodetic latitude in degrees, north positive. Range: [-90, 90].
-    /// **Not validated** — out-of-range values are accepted but produce
-    /// undefined astronomical results from downstream functions.
-    pub latitude_deg: f64,
-    /// Geodetic longitude in degrees, east positive. Range: [-180, 180].
-    pub longitude_deg: f64,
-    /// Altitude above mean sea level in meters.
-    pub altitude_m: f64,
-}
-
-impl GeoLocation {
-    /// Create a new geographic location.
-    pub fn new(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Self {
-        Self {
-            latitude_deg,
-            longitude_deg,
-            altitude_m,
-        }
-    }
-
-    /// Latitude in radians.
-    pub fn latitude_rad(&self) -> f64 {
-        self.latitude_deg.to_radians()
-    }
-
-    /// Longitude in radians (east positive).
-    pub fn longitude_rad(&self) -> f64 {
-        self.longitude_deg.to_radians()
-    }
-}
+/// Geographic location on Earth's surface (WGS84 geodetic).
+pub use dhruv_core::GeoLocation;
 
 /// Rise/set event types, including twilight variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -117,6 +85,9 @@ pub struct RiseSetConfig {
     /// Dip angle = arccos(R / (R + h)) where R = Earth radius, h = altitude.
     /// Default: true.
     pub altitude_correction: bool,
+    /// Solar radius (semidiameter) and Earth mean radius (dip).
+    /// Default: IAU 2015 nominal values.
+    pub radii: BodyRadii,
 }
 
 impl Default for RiseSetConfig {
@@ -125,6 +96,7 @@ impl Default for RiseSetConfig {
             use_refraction: true,
             sun_limb: SunLimb::UpperLimb,
             altitude_correction: true,
+            radii: BodyRadii::default(),
         }
     }
 }
@@ -178,7 +150,8 @@ impl RiseSetConfig {
 
         // Geometric dip correction
         if self.altitude_correction && altitude_m > 0.0 {
-            let dip_rad = (2.0 * altitude_m / EARTH_RADIUS_M).sqrt();
+            let earth_radius_m = self.radii.earth_mean_km * 1000.0;
+            let dip_rad = (2.0 * altitude_m / earth_radius_m).sqrt();
             base - dip_rad * (180.0 / PI)
         } else {
             base
@@ -1091,31 +1091,31 @@ mod tests {
     #[test]
     fn boundary_zero() {
         let result = amsha_longitude(0.0, Amsha::D9, None);
-        assert!(result >= 0.0 && result < 360.0);
+        assert!((0.0..360.0).contains(&result));
     }
 
     #[test]
     fn boundary_near_30() {
         let result = amsha_longitude(29.999, Amsha::D9, None);
-        assert!(result >= 0.0 && result < 360.0);
+        assert!((0.0..360.0).contains(&result));
     }
 
     #[test]
     fn boundary_exactly_30() {
         let result = amsha_longitude(30.0, Amsha::D9, None);
-        assert!(result >= 0.0 && result < 360.0);
+        assert!((0.0..360.0).contains(&result));
     }
 
     #[test]
     fn boundary_near_360() {
         let result = amsha_longitude(359.999, Amsha::D9, None);
-        assert!(result >= 0.0 && result < 360.0);
+        assert!((0.0..360.0).contains(&result));
     }
 
     #[test]
     fn boundary_negative() {
         let result = amsha_longitude(-10.0, Amsha::D9, None);
-        assert!(result >= 0.0 && result < 360.0);
+        assert!((0.0..360.0).contains(&result));
     }
 
     #[test]
@@ -1629,7 +1629,7 @@ mod tests {
         ];
         for a in all_baladi {
             let f = a.strength_factor();
-            assert!(f >= 0.0 && f <= 1.0, "{:?} factor={f}", a);
+            assert!((0.0..=1.0).contains(&f), "{:?} factor={f}", a);
         }
 
         let all_jagradadi = [
@@ -1639,7 +1639,7 @@ mod tests {
         ];
         for a in all_jagradadi {
             let f = a.strength_factor();
-            assert!(f >= 0.0 && f <= 1.0, "{:?} factor={f}", a);
+            assert!((0.0..=1.0).contains(&f), "{:?} factor={f}", a);
         }
 
         let all_deeptadi = [
@@ -1655,7 +1655,7 @@ mod tests {
         ];
         for a in all_deeptadi {
             let f = a.strength_factor();
-            assert!(f >= 0.0 && f <= 1.0, "{:?} factor={f}", a);
+            assert!((0.0..=1.0).contains(&f), "{:?} factor={f}", a);
         }
 
         let all_lajjitadi = [
@@ -1668,7 +1668,7 @@ mod tests {
         ];
         for a in all_lajjitadi {
             let f = a.strength_factor();
-            assert!(f >= 0.0 && f <= 1.0, "{:?} factor={f}", a);
+            assert!((0.0..=1.0).contains(&f), "{:?} factor={f}", a);
         }
 
         let all_sub = [
@@ -1678,7 +1678,7 @@ mod tests {
         ];
         for a in all_sub {
             let f = a.strength_factor();
-            assert!(f >= 0.0 && f <= 1.0, "{:?} factor={f}", a);
+            assert!((0.0..=1.0).contains(&f), "{:?} factor={f}", a);
         }
     }
 
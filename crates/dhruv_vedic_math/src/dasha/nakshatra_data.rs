@@ -77,7 +77,7 @@ impl NakshatraDashaConfig {
 // ---------------------------------------------------------------------------
 
 /// Vimshottari graha sequence: Ketu, Shukra, Surya, Chandra, Mangal, Rahu, Guru, Shani, Buddh.
-const VIMSHOTTARI_GRAHAS: [Graha; 9] = [
+pub(crate) const VIMSHOTTARI_GRAHAS: [Graha; 9] = [
     Graha::Ketu,
     Graha::Shukra,
     Graha::Surya,
@@ -90,10 +90,10 @@ const VIMSHOTTARI_GRAHAS: [Graha; 9] = [
 ];
 
 /// Vimshottari periods in years.
-const VIMSHOTTARI_YEARS: [f64; 9] = [7.0, 20.0, 6.0, 10.0, 7.0, 18.0, 16.0, 19.0, 17.0];
+pub(crate) const VIMSHOTTARI_YEARS: [f64; 9] = [7.0, 20.0, 6.0, 10.0, 7.0, 18.0, 16.0, 19.0, 17.0];
 
 /// Nakshatra-to-graha mapping for Vimshottari (every 3rd nakshatra shares a graha).
-const VIMSHOTTARI_NAK_MAP: [u8; 27] = [
+pub(crate) const VIMSHOTTARI_NAK_MAP: [u8; 27] = [
     0, 1, 2, 3, 4, 5, 6, 7, 8, // Ashwini..Ashlesha
     0, 1, 2, 3, 4, 5, 6, 7, 8, // Magha..Jyeshtha
     0, 1, 2, 3, 4, 5, 6, 7, 8, // Mula..Revati
@@ -0,0 +1,598 @@
+//! Dosha detection: Mangal (Kuja) dosha, Kaal Sarpa, Pitru dosha and Gandanta.
+//!
+//! Every check returns structured reasons (which reference, which bhava,
+//! which cancellation) instead of a bare flag. Bhavas are whole-sign,
+//! counted with the [`YogaChart`] helpers.
+//!
+//! Clean-room implementation from BPHS, Phaladeepika and standard muhurta
+//! texts. See docs/clean_room_dosha.md.
+
+use crate::graha::{ALL_GRAHAS, Graha, SAPTA_GRAHAS};
+use crate::nakshatra::{NAKSHATRA_SPAN_27, PADA_SPAN};
+use crate::util::normalize_360;
+use crate::yoga_detection::YogaChart;
+
+/// Mangal dosha bhavas in the common North Indian reckoning.
+pub const MANGAL_DOSHA_BHAVAS_NORTH: [u8; 5] = [1, 4, 7, 8, 12];
+/// Mangal dosha bhavas including the 2nd (South Indian reckoning).
+pub const MANGAL_DOSHA_BHAVAS_SOUTH: [u8; 6] = [1, 2, 4, 7, 8, 12];
+
+/// Configuration for [`dosha_analysis`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoshaConfig {
+    /// Bhavas (1-12) in which Mangal causes dosha.
+    pub mangal_bhavas: Vec<u8>,
+    /// Count Mangal's bhava from the lagna.
+    pub mangal_from_lagna: bool,
+    /// Count Mangal's bhava from Chandra.
+    pub mangal_from_chandra: bool,
+    /// Count Mangal's bhava from Shukra.
+    pub mangal_from_shukra: bool,
+    /// Report Kaal Sarpa when exactly one graha is outside the nodal axis.
+    pub include_partial_kaal_sarpa: bool,
+    /// Half-width of each gandanta zone in degrees (default one pada).
+    pub gandanta_orb_deg: f64,
+}
+
+impl Default for DoshaConfig {
+    fn default() -> Self {
+        Self {
+            mangal_bhavas: MANGAL_DOSHA_BHAVAS_SOUTH.to_vec(),
+            mangal_from_lagna: true,
+            mangal_from_chandra: true,
+            mangal_from_shukra: true,
+            include_partial_kaal_sarpa: true,
+            gandanta_orb_deg: PADA_SPAN,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mangal dosha
+// ---------------------------------------------------------------------------
+
+/// Point from which Mangal's bhava is counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MangalDoshaReference {
+    Lagna,
+    Chandra,
+    Shukra,
+}
+
+/// Mangal in a dosha bhava from one reference point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MangalDoshaPlacement {
+    pub reference: MangalDoshaReference,
+    /// Bhava (1-12) of Mangal from the reference.
+    pub bhava: u8,
+}
+
+/// Condition that cancels Mangal dosha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MangalDoshaCancellation {
+    /// Mangal in Mesha, Vrischika or Makara.
+    OwnOrExaltationSign,
+    /// Guru conjoins or aspects Mangal.
+    GuruAssociation,
+    /// Mangal conjoins Chandra (Chandra-Mangala yoga).
+    ConjunctChandra,
+    /// Karka or Simha lagna, where Mangal is a yogakaraka.
+    KarkaOrSimhaLagna,
+    /// Mangal's bhava from lagna is in a sign traditionally exempt for it
+    /// (2nd in Mithuna/Kanya, 4th in Mesha/Vrischika, 7th in Karka/Makara,
+    /// 8th in Dhanu/Meena, 12th in Vrishabha/Tula).
+    BhavaSignExemption { bhava: u8 },
+}
+
+/// Mangal (Kuja) dosha with its placements and cancellations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MangalDosha {
+    /// Every reference from which Mangal sits in a dosha bhava.
+    pub placements: Vec<MangalDoshaPlacement>,
+    /// Cancellation conditions that hold (empty when there is no dosha).
+    pub cancellations: Vec<MangalDoshaCancellation>,
+}
+
+impl MangalDosha {
+    /// Mangal occupies a dosha bhava from at least one reference.
+    pub fn is_present(&self) -> bool {
+        !self.placements.is_empty()
+    }
+
+    /// Present and not cancelled.
+    pub fn is_effective(&self) -> bool {
+        self.is_present() && self.cancellations.is_empty()
+    }
+}
+
+/// Mangal's signs exempt from dosha by bhava from lagna.
+fn mangal_bhava_sign_exempt(bhava: u8, rashi: u8) -> bool {
+    matches!(
+        (bhava, rashi),
+        (2, 2 | 5) | (4, 0 | 7) | (7, 3 | 9) | (8, 8 | 11) | (12, 1 | 6)
+    )
+}
+
+/// Mangal dosha from the lagna, Chandra and/or Shukra.
+pub fn mangal_dosha(
+    lagna_sidereal_deg: f64,
+    graha_lons: &[f64; 9],
+    config: &DoshaConfig,
+) -> MangalDosha {
+    let chart = YogaChart::new(lagna_sidereal_deg, *graha_lons);
+    let mut placements = Vec::new();
+    let references = [
+        (config.mangal_from_lagna, MangalDoshaReference::Lagna),
+        (config.mangal_from_chandra, MangalDoshaReference::Chandra),
+        (config.mangal_from_shukra, MangalDoshaReference::Shukra),
+    ];
+    for (enabled, reference) in references {
+        if !enabled {
+            continue;
+        }
+        let bhava = match reference {
+            MangalDoshaReference::Lagna => chart.bhava(Graha::Mangal),
+            MangalDoshaReference::Chandra => chart.bhava_from(Graha::Mangal, Graha::Chandra),
+            MangalDoshaReference::Shukra => chart.bhava_from(Graha::Mangal, Graha::Shukra),
+        };
+        if config.mangal_bhavas.contains(&bhava) {
+            placements.push(MangalDoshaPlacement { reference, bhava });
+        }
+    }
+
+    let mut cancellations = Vec::new();
+    if !placements.is_empty() {
+        let mangal_rashi = chart.rashi(Graha::Mangal);
+        if chart.in_own_sign(Graha::Mangal) || chart.in_exaltation_sign(Graha::Mangal) {
+            cancellations.push(MangalDoshaCancellation::OwnOrExaltationSign);
+        }
+        if chart.rashi(Graha::Guru) == mangal_rashi
+            || chart.aspects_rashi(Graha::Guru, mangal_rashi)
+        {
+            cancellations.push(MangalDoshaCancellation::GuruAssociation);
+        }
+        if chart.rashi(Graha::Chandra) == mangal_rashi {
+            cancellations.push(MangalDoshaCancellation::ConjunctChandra);
+        }
+        if matches!(chart.lagna_rashi(), 3 | 4) {
+            cancellations.push(MangalDoshaCancellation::KarkaOrSimhaLagna);
+        }
+        let bhava = chart.bhava(Graha::Mangal);
+        if mangal_bhava_sign_exempt(bhava, mangal_rashi) {
+            cancellations.push(MangalDoshaCancellation::BhavaSignExemption { bhava });
+        }
+    }
+
+    MangalDosha {
+        placements,
+        cancellations,
+    }
+}
+
+/// Whether both partners carry an uncancelled Mangal dosha, which cancels
+/// it for the match.
+///
+/// A dosha already cancelled in one chart does not count towards mutual
+/// cancellation.
+pub fn mangal_dosha_mutually_cancelled(a: &MangalDosha, b: &MangalDosha) -> bool {
+    a.is_effective() && b.is_effective()
+}
+
+// ---------------------------------------------------------------------------
+// Kaal Sarpa
+// ---------------------------------------------------------------------------
+
+/// Whether all grahas are hemmed by the nodes or all but one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KaalSarpaKind {
+    Full,
+    Partial,
+}
+
+/// Side of the nodal axis holding the grahas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KaalSarpaDirection {
+    /// Grahas lie in the zodiacal arc from Rahu to Ketu (Kaal Sarpa proper).
+    RahuToKetu,
+    /// Grahas lie in the arc from Ketu to Rahu (often called Kaal Amrita).
+    KetuToRahu,
+}
+
+/// Kaal Sarpa dosha details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KaalSarpaDosha {
+    pub kind: KaalSarpaKind,
+    pub direction: KaalSarpaDirection,
+    /// Whole-sign bhava (1-12) of Rahu from the lagna.
+    pub rahu_bhava: u8,
+    /// Traditional name keyed by Rahu's bhava (Anant .. Sheshnag).
+    pub name: &'static str,
+    /// Grahas outside the hemmed arc (one for a partial dosha).
+    pub outside: Vec<Graha>,
+}
+
+/// Kaal Sarpa names by Rahu's bhava from the lagna (1-12).
+pub const KAAL_SARPA_NAMES: [&str; 12] = [
+    "Anant",
+    "Kulik",
+    "Vasuki",
+    "Shankhpal",
+    "Padma",
+    "Mahapadma",
+    "Takshak",
+    "Karkotak",
+    "Shankhachood",
+    "Ghatak",
+    "Vishdhar",
+    "Sheshnag",
+];
+
+/// Kaal Sarpa from the Rahu–Ketu axis, by longitude.
+///
+/// A graha is on the Rahu-to-Ketu side when it lies less than 180° ahead of
+/// Rahu in zodiacal order.
+pub fn kaal_sarpa_dosha(
+    lagna_sidereal_deg: f64,
+    graha_lons: &[f64; 9],
+    include_partial: bool,
+) -> Option<KaalSarpaDosha> {
+    let rahu = graha_lons[Graha::Rahu.index() as usize];
+    let (ahead, behind): (Vec<Graha>, Vec<Graha>) = SAPTA_GRAHAS
+        .iter()
+        .partition(|&&g| normalize_360(graha_lons[g.index() as usize] - rahu) < 180.0);
+
+    let (direction, outside) = if ahead.len() >= behind.len() {
+        (KaalSarpaDirection::RahuToKetu, behind)
+    } else {
+        (KaalSarpaDirection::KetuToRahu, ahead)
+    };
+    let kind = match outside.len() {
+        0 => KaalSarpaKind::Full,
+        1 if include_partial => KaalSarpaKind::Partial,
+        _ => return None,
+    };
+    let rahu_bhava = YogaChart::new(lagna_sidereal_deg, *graha_lons).bhava(Graha::Rahu);
+    Some(KaalSarpaDosha {
+        kind,
+        direction,
+        rahu_bhava,
+        name: KAAL_SARPA_NAMES[rahu_bhava as usize - 1],
+        outside,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Pitru dosha
+// ---------------------------------------------------------------------------
+
+/// A condition that indicates Pitru dosha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PitruDoshaReason {
+    /// Surya shares a rashi with Rahu or Ketu.
+    SuryaWithNode { node: Graha },
+    /// Surya shares a rashi with Shani.
+    SuryaWithShani,
+    /// Rahu or Ketu occupies the 9th bhava.
+    NodeInNinthBhava { node: Graha },
+    /// The 9th lord shares a rashi with Rahu or Ketu.
+    NinthLordWithNode { lord: Graha, node: Graha },
+}
+
+/// Pitru dosha conditions that hold (empty when there is none).
+pub fn pitru_dosha(lagna_sidereal_deg: f64, graha_lons: &[f64; 9]) -> Vec<PitruDoshaReason> {
+    let chart = YogaChart::new(lagna_sidereal_deg, *graha_lons);
+    let ninth_lord = chart.bhava_lord(9);
+    let mut reasons = Vec::new();
+    for node in [Graha::Rahu, Graha::Ketu] {
+        if chart.rashi(Graha::Surya) == chart.rashi(node) {
+            reasons.push(PitruDoshaReason::SuryaWithNode { node });
+        }
+    }
+    if chart.rashi(Graha::Surya) == chart.rashi(Graha::Shani) {
+        reasons.push(PitruDoshaReason::SuryaWithShani);
+    }
+    for node in [Graha::Rahu, Graha::Ketu] {
+        if chart.bhava(node) == 9 {
+            reasons.push(PitruDoshaReason::NodeInNinthBhava { node });
+        }
+    }
+    for node in [Graha::Rahu, Graha::Ketu] {
+        if chart.rashi(ninth_lord) == chart.rashi(node) {
+            reasons.push(PitruDoshaReason::NinthLordWithNode {
+                lord: ninth_lord,
+                node,
+            });
+        }
+    }
+    reasons
+}
+
+// ---------------------------------------------------------------------------
+// Gandanta
+// ---------------------------------------------------------------------------
+
+/// Water–fire rashi junctions, which coincide with nakshatra junctions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GandantaJunction {
+    /// Karka/Simha: Ashlesha–Magha at 120°.
+    KarkaSimha,
+    /// Vrischika/Dhanu: Jyeshtha–Mula at 240°.
+    VrischikaDhanu,
+    /// Meena/Mesha: Revati–Ashwini at 0°.
+    MeenaMesha,
+}
+
+impl GandantaJunction {
+    /// Sidereal longitude of the junction (end of every 9th nakshatra).
+    pub fn longitude(self) -> f64 {
+        let k = match self {
+            Self::KarkaSimha => 1.0,
+            Self::VrischikaDhanu => 2.0,
+            Self::MeenaMesha => 3.0,
+        };
+        normalize_360(9.0 * k * NAKSHATRA_SPAN_27)
+    }
+}
+
+const ALL_GANDANTA_JUNCTIONS: [GandantaJunction; 3] = [
+    GandantaJunction::KarkaSimha,
+    GandantaJunction::VrischikaDhanu,
+    GandantaJunction::MeenaMesha,
+];
+
+/// Point tested for gandanta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GandantaPoint {
+    Lagna,
+    Graha(Graha),
+}
+
+/// A point inside a gandanta zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GandantaPlacement {
+    pub point: GandantaPoint,
+    pub junction: GandantaJunction,
+    /// Signed distance from the junction in degrees: negative in the water
+    /// sign (before), positive in the fire sign (after).
+    pub offset_deg: f64,
+}
+
+fn gandanta_of(point: GandantaPoint, lon: f64, orb_deg: f64) -> Option<GandantaPlacement> {
+    ALL_GANDANTA_JUNCTIONS.iter().find_map(|&junction| {
+        let offset = normalize_360(lon - junction.longitude() + 180.0) - 180.0;
+        (offset.abs() < orb_deg).then_some(GandantaPlacement {
+            point,
+            junction,
+            offset_deg: offset,
+        })
+    })
+}
+
+/// Lagna and grahas within `orb_deg` of a water–fire junction.
+pub fn gandanta(
+    lagna_sidereal_deg: f64,
+    graha_lons: &[f64; 9],
+    orb_deg: f64,
+) -> Vec<GandantaPlacement> {
+    let lagna = gandanta_of(GandantaPoint::Lagna, lagna_sidereal_deg, orb_deg);
+    lagna
+        .into_iter()
+        .chain(ALL_GRAHAS.iter().filter_map(|&g| {
+            gandanta_of(
+                GandantaPoint::Graha(g),
+                graha_lons[g.index() as usize],
+                orb_deg,
+            )
+        }))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Combined
+// ---------------------------------------------------------------------------
+
+/// All doshas for a chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoshaResult {
+    pub mangal: MangalDosha,
+    pub kaal_sarpa: Option<KaalSarpaDosha>,
+    /// Empty when there is no Pitru dosha.
+    pub pitru: Vec<PitruDoshaReason>,
+    /// Empty when no point is in gandanta.
+    pub gandanta: Vec<GandantaPlacement>,
+}
+
+/// Mangal, Kaal Sarpa, Pitru and Gandanta doshas from sidereal positions.
+pub fn dosha_analysis(
+    lagna_sidereal_deg: f64,
+    graha_lons: &[f64; 9],
+    config: &DoshaConfig,
+) -> DoshaResult {
+    DoshaResult {
+        mangal: mangal_dosha(lagna_sidereal_deg, graha_lons, config),
+        kaal_sarpa: kaal_sarpa_dosha(
+            lagna_sidereal_deg,
+            graha_lons,
+            config.include_partial_kaal_sarpa,
+        ),
+        pitru: pitru_dosha(lagna_sidereal_deg, graha_lons),
+        gandanta: gandanta(lagna_sidereal_deg, graha_lons, config.gandanta_orb_deg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Longitudes at the middle of the given rashis.
+    fn mid(rashis: [u8; 9]) -> [f64; 9] {
+        rashis.map(|r| r as f64 * 30.0 + 15.0)
+    }
+
+    #[test]
+    fn mangal_in_seventh_from_lagna() {
+        // Mesha lagna, Mangal in Tula (7th). Chandra/Shukra placed so that
+        // Mangal is not in a dosha bhava from them (Mangal 3rd from Simha),
+        // and Guru in Vrishabha does not aspect Tula.
+        let lons = mid([0, 4, 6, 0, 1, 4, 0, 2, 8]);
+        let d = mangal_dosha(15.0, &lons, &DoshaConfig::default());
+        assert_eq!(
+            d.placements,
+            vec![MangalDoshaPlacement {
+                reference: MangalDoshaReference::Lagna,
+                bhava: 7
+            }]
+        );
+        assert!(d.is_effective());
+    }
+
+    #[test]
+    fn mangal_house_set_is_configurable() {
+        // Mangal in Vrishabha, 2nd from Mesha lagna.
+        let lons = mid([0, 4, 1, 0, 0, 4, 0, 2, 8]);
+        let south = mangal_dosha(15.0, &lons, &DoshaConfig::default());
+        assert!(south.is_present());
+        let north = DoshaConfig {
+            mangal_bhavas: MANGAL_DOSHA_BHAVAS_NORTH.to_vec(),
+            ..DoshaConfig::default()
+        };
+        assert!(!mangal_dosha(15.0, &lons, &north).is_present());
+    }
+
+    #[test]
+    fn mangal_dosha_cancellations() {
+        // Mangal in Mesha (own sign) in the lagna; Guru in Dhanu aspects it (5th).
+        let lons = mid([4, 4, 0, 4, 8, 4, 4, 2, 8]);
+        let d = mangal_dosha(15.0, &lons, &DoshaConfig::default());
+        assert!(d.is_present());
+        assert!(!d.is_effective());
+        assert!(
+            d.cancellations
+                .contains(&MangalDoshaCancellation::OwnOrExaltationSign)
+        );
+        assert!(
+            d.cancellations
+                .contains(&MangalDoshaCancellation::GuruAssociation)
+        );
+
+        // Karka lagna with Mangal in Makara (7th): yogakaraka lagna and
+        // 7th-in-Makara exemption.
+        let lons = mid([0, 1, 9, 0, 2, 1, 0, 2, 8]);
+        let d = mangal_dosha(105.0, &lons, &DoshaConfig::default());
+        assert!(
+            d.cancellations
+                .contains(&MangalDoshaCancellation::KarkaOrSimhaLagna)
+        );
+        assert!(
+            d.cancellations
+                .contains(&MangalDoshaCancellation::BhavaSignExemption { bhava: 7 })
+        );
+    }
+
+    #[test]
+    fn mutual_mangal_dosha() {
+        let lons = mid([0, 4, 6, 0, 1, 4, 0, 2, 8]);
+        let a = mangal_dosha(15.0, &lons, &DoshaConfig::default());
+        assert!(a.is_effective());
+        assert!(mangal_dosha_mutually_cancelled(&a, &a));
+
+        // Guru in Mesha aspects Mangal in Tula: present but already cancelled.
+        let lons = mid([0, 4, 6, 0, 0, 4, 0, 2, 8]);
+        let cancelled = mangal_dosha(15.0, &lons, &DoshaConfig::default());
+        assert!(cancelled.is_present());
+        assert!(!cancelled.is_effective());
+        assert!(!mangal_dosha_mutually_cancelled(&a, &cancelled));
+        assert!(!mangal_dosha_mutually_cancelled(&cancelled, &a));
+
+        let none = MangalDosha {
+            placements: Vec::new(),
+            cancellations: Vec::new(),
+        };
+        assert!(!mangal_dosha_mutually_cancelled(&a, &none));
+    }
+
+    #[test]
+    fn full_kaal_sarpa() {
+        // Rahu at 15° Mesha, Ketu at 15° Tula; all grahas between them.
+        let mut lons = mid([1, 2, 3, 4, 5, 1, 2, 0, 6]);
+        lons[7] = 15.0;
+        lons[8] = 195.0;
+        let ks = kaal_sarpa_dosha(15.0, &lons, true).expect("kaal sarpa");
+        assert_eq!(ks.kind, KaalSarpaKind::Full);
+        assert_eq!(ks.direction, KaalSarpaDirection::RahuToKetu);
+        assert_eq!(ks.rahu_bhava, 1);
+        assert_eq!(ks.name, "Anant");
+        assert!(ks.outside.is_empty());
+    }
+
+    #[test]
+    fn partial_kaal_sarpa_and_kaal_amrita() {
+        // Shani on the other side.
+        let mut lons = mid([1, 2, 3, 4, 5, 1, 8, 0, 6]);
+        lons[7] = 15.0;
+        lons[8] = 195.0;
+        let ks = kaal_sarpa_dosha(15.0, &lons, true).expect("partial");
+        assert_eq!(ks.kind, KaalSarpaKind::Partial);
+        assert_eq!(ks.outside, vec![Graha::Shani]);
+        assert!(kaal_sarpa_dosha(15.0, &lons, false).is_none());
+
+        // All grahas from Ketu to Rahu.
+        let mut lons = mid([7, 8, 9, 10, 11, 7, 8, 0, 6]);
+        lons[7] = 15.0;
+        lons[8] = 195.0;
+        let ks = kaal_sarpa_dosha(15.0, &lons, true).expect("kaal amrita");
+        assert_eq!(ks.direction, KaalSarpaDirection::KetuToRahu);
+    }
+
+    #[test]
+    fn no_kaal_sarpa_when_split() {
+        let lons = mid([1, 2, 8, 9, 5, 1, 8, 0, 6]);
+        assert!(kaal_sarpa_dosha(15.0, &lons, true).is_none());
+    }
+
+    #[test]
+    fn pitru_dosha_reasons() {
+        // Mesha lagna; Surya with Rahu in Dhanu (9th). 9th lord Guru with Rahu too.
+        let lons = mid([8, 0, 0, 0, 8, 0, 0, 8, 2]);
+        let reasons = pitru_dosha(15.0, &lons);
+        assert!(reasons.contains(&PitruDoshaReason::SuryaWithNode { node: Graha::Rahu }));
+        assert!(reasons.contains(&PitruDoshaReason::NodeInNinthBhava { node: Graha::Rahu }));
+        assert!(reasons.contains(&PitruDoshaReason::NinthLordWithNode {
+            lord: Graha::Guru,
+            node: Graha::Rahu
+        }));
+        assert!(!reasons.contains(&PitruDoshaReason::SuryaWithShani));
+    }
+
+    #[test]
+    fn gandanta_junctions() {
+        assert!((GandantaJunction::KarkaSimha.longitude() - 120.0).abs() < 1e-9);
+        assert!((GandantaJunction::VrischikaDhanu.longitude() - 240.0).abs() < 1e-9);
+        assert!(GandantaJunction::MeenaMesha.longitude().abs() < 1e-9);
+
+        // Chandra at 29° Karka (Ashlesha pada 4), lagna at 1° Mesha.
+        let mut lons = mid([5; 9]);
+        lons[1] = 119.0;
+        let g = gandanta(1.0, &lons, PADA_SPAN);
+        assert_eq!(g.len(), 2);
+        assert_eq!(g[0].point, GandantaPoint::Lagna);
+        assert_eq!(g[0].junction, GandantaJunction::MeenaMesha);
+        assert!((g[0].offset_deg - 1.0).abs() < 1e-9);
+        assert_eq!(g[1].point, GandantaPoint::Graha(Graha::Chandra));
+        assert!((g[1].offset_deg + 1.0).abs() < 1e-9);
+
+        // Tighter orb excludes both.
+        assert!(gandanta(1.0, &lons, 0.8).is_empty());
+    }
+
+    #[test]
+    fn dosha_analysis_combines_all() {
+        let lons = mid([8, 0, 0, 0, 8, 0, 0, 8, 2]);
+        let config = DoshaConfig::default();
+        let r = dosha_analysis(15.0, &lons, &config);
+        assert_eq!(r.mangal, mangal_dosha(15.0, &lons, &config));
+        assert_eq!(r.pitru, pitru_dosha(15.0, &lons));
+        assert_eq!(r.kaal_sarpa, kaal_sarpa_dosha(15.0, &lons, true));
+        assert_eq!(r.gandanta.len(), 0);
+    }
+}
@@ -82,27 +82,9 @@ pub fn base_virupa(angular_distance: f64) -> f64 {
 pub fn special_virupa(graha: Graha, angular_distance: f64) -> f64 {
     let a = normalize_360(angular_distance);
     match graha {
-        Graha::Mangal => {
-            if (90.0..120.0).contains(&a) || (210.0..240.0).contains(&a) {
-                15.0
-            } else {
-                0.0
-            }
-        }
-        Graha::Guru => {
-            if (120.0..150.0).contains(&a) || (240.0..270.0).contains(&a) {
-                30.0
-            } else {
-                0.0
-            }
-        }
-        Graha::Shani => {
-            if (60.0..90.0).contains(&a) || (270.0..300.0).contains(&a) {
-                45.0
-            } else {
-                0.0
-            }
-        }
+        Graha::Mangal if ((90.0..120.0).contains(&a) || (210.0..240.0).contains(&a)) => 15.0,
+        Graha::Guru if ((120.0..150.0).contains(&a) || (240.0..270.0).contains(&a)) => 30.0,
+        Graha::Shani if ((60.0..90.0).contains(&a) || (270.0..300.0).contains(&a)) => 45.0,
         _ => 0.0,
     }
 }
@@ -0,0 +1,412 @@
+//! Krishnamurti Paddhati (KP) lords, significators, and ruling planets.
+//!
+//! Each nakshatra (13°20') is divided into 9 subs in Vimshottari order,
+//! starting from the nakshatra's own star lord, with each sub spanning
+//! `13°20' × years / 120`. Each sub is divided again the same way into 9
+//! sub-subs. Splitting the 243 subs where they cross a rashi boundary gives
+//! the classical 249-division KP table.
+//!
+//! Significators follow the four KP levels for each house:
+//! - A: grahas in the nakshatra of occupants of the house
+//! - B: occupants of the house
+//! - C: grahas in the nakshatra of the cusp lord
+//! - D: the cusp lord
+//!
+//! House occupancy uses the KP (cusp-to-cusp) bhava, not the rashi. Rahu and
+//! Ketu are treated like the other grahas; their agency for sign and star
+//! lords is left to the caller.
+//!
+//! Clean-room implementation from K. S. Krishnamurti's published Readers.
+//! See docs/clean_room_kp.md.
+
+use crate::dasha::nakshatra_data::{VIMSHOTTARI_GRAHAS, VIMSHOTTARI_NAK_MAP, VIMSHOTTARI_YEARS};
+use crate::graha::{Graha, rashi_lord_by_index};
+use crate::nakshatra::{NAKSHATRA_SPAN_27, nakshatra_from_longitude};
+use crate::util::normalize_360;
+use std::sync::OnceLock;
+
+/// Number of divisions in the KP sub table.
+pub const KP_SUB_COUNT: usize = 249;
+
+/// Total Vimshottari cycle in years.
+const VIMSHOTTARI_TOTAL_YEARS: f64 = 120.0;
+
+/// Tolerance for matching sub boundaries against rashi boundaries.
+const BOUNDARY_EPS: f64 = 1e-9;
+
+/// Sign, star, sub and sub-sub lords of a sidereal longitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KpLords {
+    /// Lord of the rashi.
+    pub sign_lord: Graha,
+    /// Vimshottari lord of the nakshatra.
+    pub star_lord: Graha,
+    /// Lord of the sub within the nakshatra.
+    pub sub_lord: Graha,
+    /// Lord of the sub-sub within the sub.
+    pub sub_sub_lord: Graha,
+}
+
+/// One row of the 249-division KP sub table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KpSubDivision {
+    /// 1-based division number (1..=249).
+    pub number: u16,
+    /// Start sidereal longitude in degrees.
+    pub start_deg: f64,
+    /// End sidereal longitude in degrees (exclusive).
+    pub end_deg: f64,
+    /// Lord of the rashi.
+    pub sign_lord: Graha,
+    /// Lord of the nakshatra.
+    pub star_lord: Graha,
+    /// Lord of the sub.
+    pub sub_lord: Graha,
+}
+
+/// Four-level KP significators of one house.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KpHouseSignificators {
+    /// 1-based house number.
+    pub bhava_number: u8,
+    /// Level A: grahas in the nakshatra of occupants.
+    pub level_a: Vec<Graha>,
+    /// Level B: occupants of the house.
+    pub level_b: Vec<Graha>,
+    /// Level C: grahas in the nakshatra of the cusp lord.
+    pub level_c: Vec<Graha>,
+    /// Level D: the cusp lord.
+    pub level_d: Vec<Graha>,
+}
+
+/// KP ruling planets at a moment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KpRulingPlanets {
+    /// Lord of the weekday (sunrise-to-sunrise).
+    pub day_lord: Graha,
+    /// Lords of the lagna.
+    pub lagna: KpLords,
+    /// Lords of the Moon.
+    pub moon: KpLords,
+    /// Distinct ruling planets, strongest first: lagna star lord, lagna sign
+    /// lord, Moon star lord, Moon sign lord, day lord, then Rahu/Ketu when
+    /// they occupy the sign or nakshatra of one of these.
+    pub ruling: Vec<Graha>,
+}
+
+/// Split `span` degrees into 9 Vimshottari parts starting at graha `first`
+/// (index into the Vimshottari sequence) and locate `offset` in them.
+///
+/// Returns (sequence index, offset within part, part span).
+fn vimshottari_part(first: usize, offset: f64, span: f64) -> (usize, f64, f64) {
+    let mut start = 0.0;
+    for k in 0..9 {
+        let idx = (first + k) % 9;
+        let part = span * VIMSHOTTARI_YEARS[idx] / VIMSHOTTARI_TOTAL_YEARS;
+        if offset < start + part || k == 8 {
+            return (idx, (offset - start).max(0.0), part);
+        }
+        start += part;
+    }
+    unreachable!("loop returns on the last part")
+}
+
+/// Sign, star, sub and sub-sub lords for a sidereal longitude.
+pub fn kp_lords(sidereal_lon_deg: f64) -> KpLords {
+    let lon = normalize_360(sidereal_lon_deg);
+    let rashi_index = ((lon / 30.0).floor() as u8).min(11);
+    let nak = nakshatra_from_longitude(lon);
+    let star_idx = VIMSHOTTARI_NAK_MAP[nak.nakshatra_index as usize] as usize;
+    let (sub_idx, offset_in_sub, sub_span) =
+        vimshottari_part(star_idx, nak.degrees_in_nakshatra, NAKSHATRA_SPAN_27);
+    let (sub_sub_idx, _, _) = vimshottari_part(sub_idx, offset_in_sub, sub_span);
+    KpLords {
+        sign_lord: rashi_lord_by_index(rashi_index).expect("rashi index < 12"),
+        star_lord: VIMSHOTTARI_GRAHAS[star_idx],
+        sub_lord: VIMSHOTTARI_GRAHAS[sub_idx],
+        sub_sub_lord: VIMSHOTTARI_GRAHAS[sub_sub_idx],
+    }
+}
+
+/// The 249-division KP sub table, ordered by longitude.
+pub fn kp_sub_table() -> Vec<KpSubDivision> {
+    cached_sub_table().to_vec()
+}
+
+/// The KP sub table, built once on first use.
+fn cached_sub_table() -> &'static [KpSubDivision] {
+    static TABLE: OnceLock<Vec<KpSubDivision>> = OnceLock::new();
+    TABLE.get_or_init(build_sub_table)
+}
+
+fn build_sub_table() -> Vec<KpSubDivision> {
+    let mut table = Vec::with_capacity(KP_SUB_COUNT);
+    let mut push = |start: f64, end: f64, star: Graha, sub: Graha| {
+        let rashi_index = ((start / 30.0 + BOUNDARY_EPS).floor() as u8).min(11);
+        table.push(KpSubDivision {
+            number: table.len() as u16 + 1,
+            start_deg: start,
+            end_deg: end,
+            sign_lord: rashi_lord_by_index(rashi_index).expect("rashi index < 12"),
+            star_lord: star,
+            sub_lord: sub,
+        });
+    };
+    for (nak, &star) in VIMSHOTTARI_NAK_MAP.iter().enumerate() {
+        let star_idx = star as usize;
+        let mut start = nak as f64 * NAKSHATRA_SPAN_27;
+        for k in 0..9 {
+            let idx = (star_idx + k) % 9;
+            let end = if k == 8 {
+                (nak + 1) as f64 * NAKSHATRA_SPAN_27
+            } else {
+                start + NAKSHATRA_SPAN_27 * VIMSHOTTARI_YEARS[idx] / VIMSHOTTARI_TOTAL_YEARS
+            };
+            let boundary = (start / 30.0 + BOUNDARY_EPS).floor() * 30.0 + 30.0;
+            let star = VIMSHOTTARI_GRAHAS[star_idx];
+            let sub = VIMSHOTTARI_GRAHAS[idx];
+            if end > boundary + BOUNDARY_EPS {
+                push(start, boundary, star, sub);
+                push(boundary, end, star, sub);
+            } else {
+                push(start, end, star, sub);
+            }
+            start = end;
+        }
+    }
+    table
+}
+
+/// 1-based number (1..=249) of the KP sub division containing a longitude.
+pub fn kp_sub_number(sidereal_lon_deg: f64) -> u16 {
+    let lon = normalize_360(sidereal_lon_deg);
+    let table = cached_sub_table();
+    let pos = table.partition_point(|d| d.start_deg <= lon);
+    table[pos.saturating_sub(1)].number
+}
+
+/// 1-based KP house (cusp to next cusp) containing a sidereal longitude.
+///
+/// `cusps` are the 12 sidereal cusp longitudes, house 1 first.
+pub fn kp_bhava_of(sidereal_lon_deg: f64, cusps: &[f64; 12]) -> u8 {
+    let lon = normalize_360(sidereal_lon_deg);
+    for i in 0..12 {
+        let width = normalize_360(cusps[(i + 1) % 12] - cusps[i]);
+        if normalize_360(lon - cusps[i]) < width {
+            return i as u8 + 1;
+        }
+    }
+    1
+}
+
+fn push_unique(list: &mut Vec<Graha>, graha: Graha) {
+    if !list.contains(&graha) {
+        list.push(graha);
+    }
+}
+
+/// Four-level significators for all 12 houses.
+///
+/// `graha_lons` are sidereal longitudes indexed by [`Graha::index`];
+/// `cusps` are the 12 sidereal KP cusps.
+pub fn kp_significators(graha_lons: &[f64; 9], cusps: &[f64; 12]) -> [KpHouseSignificators; 12] {
+    let star_lords: [Graha; 9] = std::array::from_fn(|i| kp_lords(graha_lons[i]).star_lord);
+    let bhavas: [u8; 9] = std::array::from_fn(|i| kp_bhava_of(graha_lons[i], cusps));
+    let in_star_of = |lord: Graha| -> Vec<Graha> {
+        VIMSHOTTARI_GRAHAS
+            .iter()
+            .copied()
+            .filter(|g| star_lords[g.index() as usize] == lord)
+            .collect()
+    };
+
+    std::array::from_fn(|h| {
+        let bhava_number = h as u8 + 1;
+        let mut level_b = Vec::new();
+        for g in VIMSHOTTARI_GRAHAS {
+            if bhavas[g.index() as usize] == bhava_number {
+                level_b.push(g);
+            }
+        }
+        let mut level_a = Vec::new();
+        for &occupant in &level_b {
+            for g in in_star_of(occupant) {
+                push_unique(&mut level_a, g);
+            }
+        }
+        let cusp_lord = kp_lords(cusps[h]).sign_lord;
+        KpHouseSignificators {
+            bhava_number,
+            level_a,
+            level_b,
+            level_c: in_star_of(cusp_lord),
+            level_d: vec![cusp_lord],
+        }
+    })
+}
+
+/// KP ruling planets from the day lord, lagna and graha longitudes.
+///
+/// `graha_lons` are sidereal longitudes indexed by [`Graha::index`].
+pub fn kp_ruling_planets(
+    day_lord: Graha,
+    lagna_sidereal_deg: f64,
+    graha_lons: &[f64; 9],
+) -> KpRulingPlanets {
+    let lagna = kp_lords(lagna_sidereal_deg);
+    let moon = kp_lords(graha_lons[Graha::Chandra.index() as usize]);
+    let mut ruling = Vec::with_capacity(7);
+    for g in [
+        lagna.star_lord,
+        lagna.sign_lord,
+        moon.star_lord,
+        moon.sign_lord,
+        day_lord,
+    ] {
+        push_unique(&mut ruling, g);
+    }
+    let base = ruling.clone();
+    for node in [Graha::Rahu, Graha::Ketu] {
+        let lords = kp_lords(graha_lons[node.index() as usize]);
+        if base.contains(&lords.sign_lord) || base.contains(&lords.star_lord) {
+            push_unique(&mut ruling, node);
+        }
+    }
+    KpRulingPlanets {
+        day_lord,
+        lagna,
+        moon,
+        ruling,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_table_has_249_divisions() {
+        let table = kp_sub_table();
+        assert_eq!(table.len(), KP_SUB_COUNT);
+        assert_eq!(table[0].start_deg, 0.0);
+        assert!((table[KP_SUB_COUNT - 1].end_deg - 360.0).abs() < 1e-9);
+        for pair in table.windows(2) {
+            assert!((pair[0].end_deg - pair[1].start_deg).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sub_table_first_and_last_rows() {
+        let table = kp_sub_table();
+        let first = table[0];
+        assert_eq!(first.star_lord, Graha::Ketu);
+        assert_eq!(first.sub_lord, Graha::Ketu);
+        assert!((first.end_deg - 7.0 / 9.0).abs() < 1e-9);
+        let last = table[KP_SUB_COUNT - 1];
+        assert_eq!(last.sign_lord, Graha::Guru);
+        assert_eq!(last.star_lord, Graha::Buddh);
+        assert_eq!(last.sub_lord, Graha::Shani);
+    }
+
+    #[test]
+    fn sub_table_splits_at_rashi_boundary() {
+        // Krittika (Surya) crosses Mesha/Vrishabha inside its Rahu sub.
+        let table = kp_sub_table();
+        let split: Vec<_> = table
+            .iter()
+            .filter(|d| d.star_lord == Graha::Surya && d.sub_lord == Graha::Rahu)
+            .filter(|d| d.start_deg < 40.0)
+            .collect();
+        assert_eq!(split.len(), 2);
+        assert_eq!(split[0].sign_lord, Graha::Mangal);
+        assert_eq!(split[1].sign_lord, Graha::Shukra);
+        assert!((split[0].end_deg - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lords_at_known_longitudes() {
+        let l = kp_lords(0.01);
+        assert_eq!(l.sign_lord, Graha::Mangal);
+        assert_eq!(l.star_lord, Graha::Ketu);
+        assert_eq!(l.sub_lord, Graha::Ketu);
+        assert_eq!(l.sub_sub_lord, Graha::Ketu);
+
+        // Ashwini Shukra sub starts at 0°46'40".
+        let l = kp_lords(7.0 / 9.0 + 1e-6);
+        assert_eq!(l.sub_lord, Graha::Shukra);
+        assert_eq!(l.sub_sub_lord, Graha::Shukra);
+
+        let l = kp_lords(359.99);
+        assert_eq!(l.sign_lord, Graha::Guru);
+        assert_eq!(l.star_lord, Graha::Buddh);
+        assert_eq!(l.sub_lord, Graha::Shani);
+    }
+
+    #[test]
+    fn lords_agree_with_table() {
+        for d in kp_sub_table() {
+            let mid = 0.5 * (d.start_deg + d.end_deg);
+            let l = kp_lords(mid);
+            assert_eq!(l.sign_lord, d.sign_lord, "division {}", d.number);
+            assert_eq!(l.star_lord, d.star_lord, "division {}", d.number);
+            assert_eq!(l.sub_lord, d.sub_lord, "division {}", d.number);
+            assert_eq!(kp_sub_number(mid), d.number);
+        }
+    }
+
+    #[test]
+    fn bhava_of_handles_wraparound() {
+        let mut cusps = [0.0; 12];
+        for (i, c) in cusps.iter_mut().enumerate() {
+            *c = normalize_360(350.0 + 30.0 * i as f64);
+        }
+        assert_eq!(kp_bhava_of(355.0, &cusps), 1);
+        assert_eq!(kp_bhava_of(5.0, &cusps), 1);
+        assert_eq!(kp_bhava_of(20.0, &cusps), 2);
+        assert_eq!(kp_bhava_of(349.0, &cusps), 12);
+    }
+
+    #[test]
+    fn significators_levels() {
+        let cusps: [f64; 12] = std::array::from_fn(|i| 30.0 * i as f64);
+        // Surya at 1° (Ashwini, Ketu star), Ketu at 45° (Rohini, Chandra star),
+        // remaining grahas parked in house 7 (Swati, Rahu star).
+        let mut lons = [190.0; 9];
+        lons[Graha::Surya.index() as usize] = 1.0;
+        lons[Graha::Ketu.index() as usize] = 45.0;
+        let sig = kp_significators(&lons, &cusps);
+
+        assert_eq!(sig[0].level_b, vec![Graha::Surya]);
+        assert!(sig[0].level_a.is_empty());
+        assert_eq!(sig[0].level_d, vec![Graha::Mangal]);
+        assert!(sig[0].level_c.is_empty());
+
+        assert_eq!(sig[1].level_b, vec![Graha::Ketu]);
+        assert_eq!(sig[1].level_a, vec![Graha::Surya]);
+
+        assert_eq!(sig[6].level_b.len(), 7);
+        assert_eq!(sig[6].level_d, vec![Graha::Shukra]);
+    }
+
+    #[test]
+    fn ruling_planets_order_and_nodes() {
+        let mut lons = [0.0; 9];
+        lons[Graha::Chandra.index() as usize] = 45.0; // Vrishabha, Rohini
+        lons[Graha::Rahu.index() as usize] = 100.0; // Karka, Pushya (Shani)
+        lons[Graha::Ketu.index() as usize] = 280.0; // Makara, Shravana (Chandra)
+        let rp = kp_ruling_planets(Graha::Guru, 1.0, &lons);
+        assert_eq!(rp.lagna.star_lord, Graha::Ketu);
+        assert_eq!(rp.moon.sign_lord, Graha::Shukra);
+        assert_eq!(
+            rp.ruling,
+            vec![
+                Graha::Ketu,
+                Graha::Mangal,
+                Graha::Chandra,
+                Graha::Shukra,
+                Graha::Guru,
+                Graha::Rahu,
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@ pub mod bhavabala;
 pub mod charakaraka;
 pub mod combustion;
 pub mod dasha;
+pub mod dosha;
 pub mod drishti;
 pub mod error;
 pub mod ghatika;
@@ -16,7 +17,10 @@ pub mod graha;
 pub mod graha_relationships;
 pub mod hora;
 pub mod karana;
+pub mod kp;
 pub mod masa;
+pub mod milan;
+pub mod muhurta;
 pub mod nakshatra;
 pub mod rashi;
 pub mod samvatsara;
@@ -29,6 +33,7 @@ pub mod util;
 pub mod vaar;
 pub mod vimsopaka;
 pub mod yoga;
+pub mod yoga_detection;
 
 pub use amsha::{
     ALL_AMSHAS, Amsha, AmshaRequest, AmshaVariationCatalog, AmshaVariationCode, AmshaVariationInfo,
@@ -76,6 +81,13 @@ pub use dasha::{
     nakshatra_complete_level, nakshatra_hierarchy, nakshatra_level0, nakshatra_level0_entity,
     nakshatra_snapshot, snapshot_from_hierarchy, vimshottari_config,
 };
+pub use dosha::{
+    DoshaConfig, DoshaResult, GandantaJunction, GandantaPlacement, GandantaPoint, KAAL_SARPA_NAMES,
+    KaalSarpaDirection, KaalSarpaDosha, KaalSarpaKind, MANGAL_DOSHA_BHAVAS_NORTH,
+    MANGAL_DOSHA_BHAVAS_SOUTH, MangalDosha, MangalDoshaCancellation, MangalDoshaPlacement,
+    MangalDoshaReference, PitruDoshaReason, dosha_analysis, gandanta, kaal_sarpa_dosha,
+    mangal_dosha, mangal_dosha_mutually_cancelled, pitru_dosha,
+};
 pub use drishti::{
     DrishtiEntry, GrahaDrishtiMatrix, base_virupa, graha_drishti, graha_drishti_matrix,
     special_virupa,
@@ -98,7 +110,21 @@ pub use graha_relationships::{
 };
 pub use hora::{CHALDEAN_SEQUENCE, HORA_COUNT, Hora, hora_at, vaar_day_lord};
 pub use karana::{ALL_KARANAS, KARANA_SEGMENT_DEG, Karana, KaranaPosition, karana_from_elongation};
+pub use kp::{
+    KP_SUB_COUNT, KpHouseSignificators, KpLords, KpRulingPlanets, KpSubDivision, kp_bhava_of,
+    kp_lords, kp_ruling_planets, kp_significators, kp_sub_number, kp_sub_table,
+};
 pub use masa::{ALL_MASAS, Masa, masa_from_rashi_index};
+pub use milan::{
+    ALL_KOOTAS, ALL_PORUTHAMS, ASHTAKOOTA_MAX, AshtakootaResult, DashakootaResult, Koota,
+    KootaScore, MilanCancellation, MilanResult, Porutham, PoruthamResult, ashtakoota, dashakoota,
+    kundali_milan,
+};
+pub use muhurta::{
+    ABHIJIT_MUHURTA_INDEX, ALL_CHOGHADIYAS, BRAHMA_MUHURTA_INDEX, CHOGHADIYA_COUNT, Choghadiya,
+    ChoghadiyaNature, DAY_MUHURTA_NAMES, KALAM_SEGMENT_COUNT, MUHURTA_COUNT, NIGHT_MUHURTA_NAMES,
+    choghadiya_at, gulika_kalam_segment, is_durmuhurta, rahu_kalam_segment, yamaganda_segment,
+};
 pub use nakshatra::{
     ALL_NAKSHATRAS_27, ALL_NAKSHATRAS_28, NAKSHATRA_SPAN_27, Nakshatra, Nakshatra28,
     Nakshatra28Info, NakshatraInfo, nakshatra_from_longitude, nakshatra28_from_longitude,
@@ -148,3 +174,7 @@ pub use vimsopaka::{
     vimsopaka_from_entries,
 };
 pub use yoga::{ALL_YOGAS, YOGA_SEGMENT_DEG, Yoga, YogaPosition, yoga_from_sum};
+pub use yoga_detection::{
+    CLASSICAL_YOGA_RULES, DHANA_BHAVAS, DUSTHANA_BHAVAS, DetectedYoga, KENDRA_BHAVAS, Sambandha,
+    TRIKONA_BHAVAS, YogaCategory, YogaChart, YogaHit, YogaRule, detect_yogas,
+};
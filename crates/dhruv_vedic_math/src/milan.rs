@@ -0,0 +1,694 @@
+//! Marriage compatibility (kundali milan) from the two Moon positions.
+//!
+//! Ashtakoota (North Indian guna milan) scores eight kootas for a total of
+//! 36 points. Dashakoota (South Indian porutham) checks ten pass/fail
+//! poruthams. Both use only the sidereal Moon longitude of each partner:
+//! its nakshatra, pada and rashi.
+//!
+//! Counts "from the bride to the groom" are inclusive: the bride's own
+//! nakshatra (or rashi) is 1.
+//!
+//! Clean-room implementation from standard muhurta/milan texts
+//! (Muhurta Chintamani, BPHS). See docs/clean_room_milan.md.
+
+use crate::graha::{Graha, rashi_lord_by_index};
+use crate::graha_relationships::{NaisargikaMaitri, naisargika_maitri};
+use crate::nakshatra::nakshatra_from_longitude;
+use crate::util::normalize_360;
+
+/// Maximum Ashtakoota score.
+pub const ASHTAKOOTA_MAX: f64 = 36.0;
+
+/// The eight Ashtakoota kootas, in traditional order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Koota {
+    Varna,
+    Vashya,
+    Tara,
+    Yoni,
+    GrahaMaitri,
+    Gana,
+    Bhakoot,
+    Nadi,
+}
+
+/// All 8 kootas in traditional order.
+pub const ALL_KOOTAS: [Koota; 8] = [
+    Koota::Varna,
+    Koota::Vashya,
+    Koota::Tara,
+    Koota::Yoni,
+    Koota::GrahaMaitri,
+    Koota::Gana,
+    Koota::Bhakoot,
+    Koota::Nadi,
+];
+
+impl Koota {
+    /// Name of the koota.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Varna => "Varna",
+            Self::Vashya => "Vashya",
+            Self::Tara => "Tara",
+            Self::Yoni => "Yoni",
+            Self::GrahaMaitri => "Graha Maitri",
+            Self::Gana => "Gana",
+            Self::Bhakoot => "Bhakoot",
+            Self::Nadi => "Nadi",
+        }
+    }
+
+    /// Maximum points for the koota.
+    pub const fn max_points(self) -> f64 {
+        match self {
+            Self::Varna => 1.0,
+            Self::Vashya => 2.0,
+            Self::Tara => 3.0,
+            Self::Yoni => 4.0,
+            Self::GrahaMaitri => 5.0,
+            Self::Gana => 6.0,
+            Self::Bhakoot => 7.0,
+            Self::Nadi => 8.0,
+        }
+    }
+}
+
+/// The ten Dashakoota poruthams, in traditional order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Porutham {
+    Dina,
+    Gana,
+    Mahendra,
+    StreeDeergha,
+    Yoni,
+    Rasi,
+    Rasyadhipati,
+    Vasya,
+    Rajju,
+    Vedha,
+}
+
+/// All 10 poruthams in traditional order.
+pub const ALL_PORUTHAMS: [Porutham; 10] = [
+    Porutham::Dina,
+    Porutham::Gana,
+    Porutham::Mahendra,
+    Porutham::StreeDeergha,
+    Porutham::Yoni,
+    Porutham::Rasi,
+    Porutham::Rasyadhipati,
+    Porutham::Vasya,
+    Porutham::Rajju,
+    Porutham::Vedha,
+];
+
+impl Porutham {
+    /// Name of the porutham.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Dina => "Dina",
+            Self::Gana => "Gana",
+            Self::Mahendra => "Mahendra",
+            Self::StreeDeergha => "Stree Deergha",
+            Self::Yoni => "Yoni",
+            Self::Rasi => "Rasi",
+            Self::Rasyadhipati => "Rasyadhipati",
+            Self::Vasya => "Vasya",
+            Self::Rajju => "Rajju",
+            Self::Vedha => "Vedha",
+        }
+    }
+}
+
+/// Why a koota dosha is considered cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MilanCancellation {
+    /// Both Moons in the same rashi but different nakshatras.
+    SameRashiDifferentNakshatra,
+    /// Both Moons in the same nakshatra but different rashis.
+    SameNakshatraDifferentRashi,
+    /// Both Moons in the same nakshatra but different padas.
+    SameNakshatraDifferentPada,
+    /// Both rashis share a lord.
+    SameRashiLord,
+    /// The rashi lords are mutual natural friends.
+    FriendlyRashiLords,
+}
+
+/// Score of one Ashtakoota koota.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KootaScore {
+    pub koota: Koota,
+    /// Points awarded.
+    pub points: f64,
+    /// Maximum points for this koota.
+    pub max_points: f64,
+    /// Whether the koota carries a named dosha (Gana, Bhakoot, Nadi at 0).
+    pub dosha: bool,
+    /// First matching cancellation rule, when the dosha is cancelled.
+    pub cancellation: Option<MilanCancellation>,
+}
+
+/// Ashtakoota (guna milan) result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AshtakootaResult {
+    /// Kootas in [`ALL_KOOTAS`] order.
+    pub kootas: [KootaScore; 8],
+    /// Sum of awarded points (out of 36).
+    pub total: f64,
+    /// Total with cancelled doshas restored to full points.
+    pub adjusted_total: f64,
+}
+
+/// Outcome of one Dashakoota porutham.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoruthamResult {
+    pub porutham: Porutham,
+    pub matched: bool,
+}
+
+/// Dashakoota (ten porutham) result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashakootaResult {
+    /// Poruthams in [`ALL_PORUTHAMS`] order.
+    pub poruthams: [PoruthamResult; 10],
+    /// Number of matched poruthams.
+    pub matched_count: u8,
+    /// Rajju or Vedha failed; traditionally rejects the match outright.
+    pub critical_failure: bool,
+}
+
+/// Combined Ashtakoota and Dashakoota result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MilanResult {
+    pub ashtakoota: AshtakootaResult,
+    pub dashakoota: DashakootaResult,
+}
+
+/// Moon placement used by the matching rules.
+#[derive(Debug, Clone, Copy)]
+struct MoonSign {
+    nakshatra: u8,
+    pada: u8,
+    rashi: u8,
+    /// Degrees within the rashi, for Dhanu/Makara vashya halves.
+    deg_in_rashi: f64,
+}
+
+impl MoonSign {
+    fn new(moon_sidereal_deg: f64) -> Self {
+        let lon = normalize_360(moon_sidereal_deg);
+        let nak = nakshatra_from_longitude(lon);
+        let rashi = ((lon / 30.0).floor() as u8).min(11);
+        Self {
+            nakshatra: nak.nakshatra_index,
+            pada: nak.pada,
+            rashi,
+            deg_in_rashi: lon - rashi as f64 * 30.0,
+        }
+    }
+
+    fn lord(self) -> Graha {
+        rashi_lord_by_index(self.rashi).expect("rashi index < 12")
+    }
+}
+
+/// Inclusive count from `from` to `to` over `n` positions (1..=n).
+fn count_from(from: u8, to: u8, n: u8) -> u8 {
+    (to + n - from) % n + 1
+}
+
+// ---------------------------------------------------------------------------
+// Ashtakoota tables
+// ---------------------------------------------------------------------------
+
+/// Varna rank by rashi: 3 = Brahmin, 2 = Kshatriya, 1 = Vaishya, 0 = Shudra.
+const VARNA_RANK: [u8; 12] = [2, 1, 0, 3, 2, 1, 0, 3, 2, 1, 0, 3];
+
+/// Vashya groups.
+const CHATUSHPADA: usize = 0;
+const MANAVA: usize = 1;
+const JALACHARA: usize = 2;
+const VANACHARA: usize = 3;
+const KEETA: usize = 4;
+
+/// Vashya points, groom group (row) × bride group (column).
+const VASHYA_POINTS: [[f64; 5]; 5] = [
+    [2.0, 1.0, 1.0, 0.5, 1.0],
+    [1.0, 2.0, 0.5, 0.0, 1.0],
+    [1.0, 0.5, 2.0, 1.0, 1.0],
+    [0.5, 0.0, 1.0, 2.0, 0.0],
+    [1.0, 1.0, 1.0, 0.0, 2.0],
+];
+
+fn vashya_group(m: MoonSign) -> usize {
+    match m.rashi {
+        0 | 1 => CHATUSHPADA,
+        2 | 5 | 6 | 10 => MANAVA,
+        3 | 11 => JALACHARA,
+        4 => VANACHARA,
+        7 => KEETA,
+        // Dhanu: first half human, second half quadruped.
+        8 if m.deg_in_rashi < 15.0 => MANAVA,
+        8 => CHATUSHPADA,
+        // Makara: first half quadruped, second half aquatic.
+        9 if m.deg_in_rashi < 15.0 => CHATUSHPADA,
+        _ => JALACHARA,
+    }
+}
+
+/// Yoni animal index per nakshatra (0 = Horse .. 13 = Lion).
+const NAKSHATRA_YONI: [u8; 27] = [
+    0, 1, 2, 3, 3, 4, 5, 2, 5, 6, 6, 7, 8, 9, 8, 9, 10, 10, 4, 11, 12, 11, 13, 0, 13, 7, 1,
+];
+
+/// Yoni points; order Horse, Elephant, Sheep, Serpent, Dog, Cat, Rat, Cow,
+/// Buffalo, Tiger, Deer, Monkey, Mongoose, Lion. Sworn enemies score 0.
+const YONI_POINTS: [[u8; 14]; 14] = [
+    [4, 2, 2, 3, 2, 2, 2, 1, 0, 1, 3, 3, 2, 1],
+    [2, 4, 3, 3, 2, 2, 2, 2, 3, 1, 2, 3, 2, 0],
+    [2, 3, 4, 2, 1, 2, 1, 3, 3, 1, 2, 0, 3, 1],
+    [3, 3, 2, 4, 2, 1, 1, 1, 1, 2, 2, 2, 0, 2],
+    [2, 2, 1, 2, 4, 2, 1, 2, 2, 1, 0, 2, 1, 1],
+    [2, 2, 2, 1, 2, 4, 0, 2, 2, 1, 3, 3, 2, 1],
+    [2, 2, 1, 1, 1, 0, 4, 2, 2, 2, 2, 2, 1, 2],
+    [1, 2, 3, 1, 2, 2, 2, 4, 3, 0, 3, 2, 2, 1],
+    [0, 3, 3, 1, 2, 2, 2, 3, 4, 1, 2, 2, 2, 1],
+    [1, 1, 1, 2, 1, 1, 2, 0, 1, 4, 1, 1, 2, 1],
+    [3, 2, 2, 2, 0, 3, 2, 3, 2, 1, 4, 2, 2, 1],
+    [3, 3, 0, 2, 2, 3, 2, 2, 2, 1, 2, 4, 3, 2],
+    [2, 2, 3, 0, 1, 2, 1, 2, 2, 2, 2, 3, 4, 2],
+    [1, 0, 1, 2, 1, 1, 2, 1, 1, 1, 1, 2, 2, 4],
+];
+
+/// Gana per nakshatra: 0 = Deva, 1 = Manushya, 2 = Rakshasa.
+const NAKSHATRA_GANA: [u8; 27] = [
+    0, 1, 2, 1, 0, 1, 0, 0, 2, 2, 1, 1, 0, 2, 0, 2, 0, 2, 2, 1, 1, 0, 2, 2, 1, 1, 0,
+];
+
+/// Gana points, groom gana (row) × bride gana (column).
+const GANA_POINTS: [[f64; 3]; 3] = [[6.0, 6.0, 1.0], [5.0, 6.0, 0.0], [1.0, 0.0, 6.0]];
+
+/// Nadi per nakshatra position within each 6-nakshatra zigzag:
+/// 0 = Aadi, 1 = Madhya, 2 = Antya.
+const NADI_PATTERN: [u8; 6] = [0, 1, 2, 2, 1, 0];
+
+fn nadi(nakshatra: u8) -> u8 {
+    NADI_PATTERN[(nakshatra % 6) as usize]
+}
+
+fn maitri_points(groom_lord: Graha, bride_lord: Graha) -> f64 {
+    use NaisargikaMaitri::*;
+    if groom_lord == bride_lord {
+        return 5.0;
+    }
+    match (
+        naisargika_maitri(groom_lord, bride_lord),
+        naisargika_maitri(bride_lord, groom_lord),
+    ) {
+        (Friend, Friend) => 5.0,
+        (Friend, Neutral) | (Neutral, Friend) => 4.0,
+        (Neutral, Neutral) => 3.0,
+        (Friend, Enemy) | (Enemy, Friend) => 1.0,
+        (Neutral, Enemy) | (Enemy, Neutral) => 0.5,
+        (Enemy, Enemy) => 0.0,
+    }
+}
+
+fn lord_cancellation(groom: MoonSign, bride: MoonSign) -> Option<MilanCancellation> {
+    let (gl, bl) = (groom.lord(), bride.lord());
+    if gl == bl {
+        Some(MilanCancellation::SameRashiLord)
+    } else if naisargika_maitri(gl, bl) == NaisargikaMaitri::Friend
+        && naisargika_maitri(bl, gl) == NaisargikaMaitri::Friend
+    {
+        Some(MilanCancellation::FriendlyRashiLords)
+    } else {
+        None
+    }
+}
+
+fn tara_is_good(count: u8) -> bool {
+    !matches!(count % 9, 3 | 5 | 7)
+}
+
+fn bhakoot_dosha(groom: MoonSign, bride: MoonSign) -> bool {
+    let n = count_from(bride.rashi, groom.rashi, 12);
+    matches!(n, 2 | 12 | 5 | 9 | 6 | 8)
+}
+
+fn koota(
+    koota: Koota,
+    points: f64,
+    dosha: bool,
+    cancellation: Option<MilanCancellation>,
+) -> KootaScore {
+    KootaScore {
+        koota,
+        points,
+        max_points: koota.max_points(),
+        dosha,
+        cancellation: if dosha { cancellation } else { None },
+    }
+}
+
+/// Ashtakoota (guna milan) from the groom's and bride's sidereal Moon longitudes.
+///
+/// Doshas reported: Gana (0 points), Bhakoot (2/12, 5/9, 6/8) and Nadi
+/// (same nadi). Nadi dosha is cancelled by same rashi with different
+/// nakshatras, same nakshatra across different rashis, or same nakshatra
+/// with different padas. Bhakoot and Gana doshas are cancelled when the
+/// rashi lords are the same or mutual natural friends.
+pub fn ashtakoota(groom_moon_deg: f64, bride_moon_deg: f64) -> AshtakootaResult {
+    let groom = MoonSign::new(groom_moon_deg);
+    let bride = MoonSign::new(bride_moon_deg);
+    let lords_cancel = lord_cancellation(groom, bride);
+
+    let varna = if VARNA_RANK[groom.rashi as usize] >= VARNA_RANK[bride.rashi as usize] {
+        1.0
+    } else {
+        0.0
+    };
+
+    let vashya = VASHYA_POINTS[vashya_group(groom)][vashya_group(bride)];
+
+    let tara = [
+        count_from(bride.nakshatra, groom.nakshatra, 27),
+        count_from(groom.nakshatra, bride.nakshatra, 27),
+    ]
+    .iter()
+    .filter(|&&n| tara_is_good(n))
+    .count() as f64
+        * 1.5;
+
+    let yoni = YONI_POINTS[NAKSHATRA_YONI[groom.nakshatra as usize] as usize]
+        [NAKSHATRA_YONI[bride.nakshatra as usize] as usize] as f64;
+
+    let maitri = maitri_points(groom.lord(), bride.lord());
+
+    let gana = GANA_POINTS[NAKSHATRA_GANA[groom.nakshatra as usize] as usize]
+        [NAKSHATRA_GANA[bride.nakshatra as usize] as usize];
+
+    let bhakoot_has_dosha = bhakoot_dosha(groom, bride);
+    let bhakoot = if bhakoot_has_dosha { 0.0 } else { 7.0 };
+
+    let nadi_has_dosha = nadi(groom.nakshatra) == nadi(bride.nakshatra);
+    let nadi_cancel = if groom.rashi == bride.rashi && groom.nakshatra != bride.nakshatra {
+        Some(MilanCancellation::SameRashiDifferentNakshatra)
+    } else if groom.nakshatra == bride.nakshatra && groom.rashi != bride.rashi {
+        Some(MilanCancellation::SameNakshatraDifferentRashi)
+    } else if groom.nakshatra == bride.nakshatra && groom.pada != bride.pada {
+        Some(MilanCancellation::SameNakshatraDifferentPada)
+    } else {
+        None
+    };
+    let nadi_points = if nadi_has_dosha { 0.0 } else { 8.0 };
+
+    let kootas = [
+        koota(Koota::Varna, varna, false, None),
+        koota(Koota::Vashya, vashya, false, None),
+        koota(Koota::Tara, tara, false, None),
+        koota(Koota::Yoni, yoni, false, None),
+        koota(Koota::GrahaMaitri, maitri, false, None),
+        koota(Koota::Gana, gana, gana == 0.0, lords_cancel),
+        koota(Koota::Bhakoot, bhakoot, bhakoot_has_dosha, lords_cancel),
+        koota(Koota::Nadi, nadi_points, nadi_has_dosha, nadi_cancel),
+    ];
+    let total = kootas.iter().map(|k| k.points).sum();
+    let adjusted_total = kootas
+        .iter()
+        .map(|k| {
+            if k.cancellation.is_some() {
+                k.max_points
+            } else {
+                k.points
+            }
+        })
+        .sum();
+
+    AshtakootaResult {
+        kootas,
+        total,
+        adjusted_total,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dashakoota tables
+// ---------------------------------------------------------------------------
+
+/// Rajju per nakshatra position within each 9-nakshatra group:
+/// 0 = Pada, 1 = Kati, 2 = Nabhi, 3 = Kanta, 4 = Shiro.
+const RAJJU_PATTERN: [u8; 9] = [0, 1, 2, 3, 4, 3, 2, 1, 0];
+
+/// Vedha partner nakshatras. Mrigashira, Chitra and Dhanishta afflict each other.
+const VEDHA_PAIRS: [(u8, u8); 15] = [
+    (0, 17),
+    (1, 16),
+    (2, 15),
+    (3, 14),
+    (5, 21),
+    (6, 20),
+    (7, 19),
+    (8, 18),
+    (9, 26),
+    (10, 25),
+    (11, 24),
+    (12, 23),
+    (4, 13),
+    (4, 22),
+    (13, 22),
+];
+
+/// Tamil vasya table: rashis that are vasya to each rashi.
+const RASHI_VASYA: [&[u8]; 12] = [
+    &[4, 7],
+    &[3, 6],
+    &[5],
+    &[7, 8],
+    &[6],
+    &[2, 11],
+    &[9, 5],
+    &[3],
+    &[11],
+    &[0, 10],
+    &[0],
+    &[9],
+];
+
+fn porutham(porutham: Porutham, matched: bool) -> PoruthamResult {
+    PoruthamResult { porutham, matched }
+}
+
+/// Dashakoota (ten porutham) from the groom's and bride's sidereal Moon longitudes.
+pub fn dashakoota(groom_moon_deg: f64, bride_moon_deg: f64) -> DashakootaResult {
+    let groom = MoonSign::new(groom_moon_deg);
+    let bride = MoonSign::new(bride_moon_deg);
+    let nak_count = count_from(bride.nakshatra, groom.nakshatra, 27);
+    let rashi_count = count_from(bride.rashi, groom.rashi, 12);
+
+    let dina = matches!(nak_count % 9, 0 | 2 | 4 | 6 | 8);
+    let gana = GANA_POINTS[NAKSHATRA_GANA[groom.nakshatra as usize] as usize]
+        [NAKSHATRA_GANA[bride.nakshatra as usize] as usize]
+        >= 5.0;
+    let mahendra = matches!(nak_count, 4 | 7 | 10 | 13 | 16 | 19 | 22 | 25);
+    let stree_deergha = nak_count > 13;
+    let yoni = YONI_POINTS[NAKSHATRA_YONI[groom.nakshatra as usize] as usize]
+        [NAKSHATRA_YONI[bride.nakshatra as usize] as usize]
+        >= 2;
+    let rasi = matches!(rashi_count, 1 | 7 | 9 | 10 | 11);
+    let (gl, bl) = (groom.lord(), bride.lord());
+    let rasyadhipati = naisargika_maitri(gl, bl) != NaisargikaMaitri::Enemy
+        && naisargika_maitri(bl, gl) != NaisargikaMaitri::Enemy;
+    let vasya = RASHI_VASYA[groom.rashi as usize].contains(&bride.rashi)
+        || RASHI_VASYA[bride.rashi as usize].contains(&groom.rashi);
+    let rajju = RAJJU_PATTERN[(groom.nakshatra % 9) as usize]
+        != RAJJU_PATTERN[(bride.nakshatra % 9) as usize];
+    let vedha = !VEDHA_PAIRS.iter().any(|&(a, b)| {
+        (a, b) == (groom.nakshatra, bride.nakshatra) || (b, a) == (groom.nakshatra, bride.nakshatra)
+    });
+
+    let poruthams = [
+        porutham(Porutham::Dina, dina),
+        porutham(Porutham::Gana, gana),
+        porutham(Porutham::Mahendra, mahendra),
+        porutham(Porutham::StreeDeergha, stree_deergha),
+        porutham(Porutham::Yoni, yoni),
+        porutham(Porutham::Rasi, rasi),
+        porutham(Porutham::Rasyadhipati, rasyadhipati),
+        porutham(Porutham::Vasya, vasya),
+        porutham(Porutham::Rajju, rajju),
+        porutham(Porutham::Vedha, vedha),
+    ];
+    DashakootaResult {
+        poruthams,
+        matched_count: poruthams.iter().filter(|p| p.matched).count() as u8,
+        critical_failure: !rajju || !vedha,
+    }
+}
+
+/// Ashtakoota and Dashakoota from the groom's and bride's sidereal Moon longitudes.
+pub fn kundali_milan(groom_moon_deg: f64, bride_moon_deg: f64) -> MilanResult {
+    MilanResult {
+        ashtakoota: ashtakoota(groom_moon_deg, bride_moon_deg),
+        dashakoota: dashakoota(groom_moon_deg, bride_moon_deg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nakshatra::NAKSHATRA_SPAN_27;
+
+    /// Midpoint of a nakshatra's given pada (1-4).
+    fn nak_pada(nakshatra: u8, pada: u8) -> f64 {
+        nakshatra as f64 * NAKSHATRA_SPAN_27 + (pada as f64 - 0.5) * NAKSHATRA_SPAN_27 / 4.0
+    }
+
+    #[test]
+    fn koota_maxima_sum_to_36() {
+        let sum: f64 = ALL_KOOTAS.iter().map(|k| k.max_points()).sum();
+        assert_eq!(sum, ASHTAKOOTA_MAX);
+    }
+
+    #[test]
+    fn yoni_table_symmetric_with_enemy_zeros() {
+        for (i, row) in YONI_POINTS.iter().enumerate() {
+            assert_eq!(row[i], 4);
+            for (j, &v) in row.iter().enumerate() {
+                assert_eq!(v, YONI_POINTS[j][i], "yoni[{i}][{j}]");
+            }
+        }
+        // Horse-Buffalo, Elephant-Lion, Sheep-Monkey, Serpent-Mongoose,
+        // Dog-Deer, Cat-Rat, Cow-Tiger.
+        for (a, b) in [(0, 8), (1, 13), (2, 11), (3, 12), (4, 10), (5, 6), (7, 9)] {
+            assert_eq!(YONI_POINTS[a][b], 0);
+        }
+    }
+
+    #[test]
+    fn nadi_pattern_matches_classical_groups() {
+        // Aadi: Ashwini, Ardra, Punarvasu, Uttara Phalguni, Hasta, Jyeshtha,
+        // Mula, Shatabhisha, Purva Bhadrapada.
+        for n in [0, 5, 6, 11, 12, 17, 18, 23, 24] {
+            assert_eq!(nadi(n), 0, "nakshatra {n}");
+        }
+        // Antya includes Krittika, Rohini and Revati.
+        for n in [2, 3, 26] {
+            assert_eq!(nadi(n), 2, "nakshatra {n}");
+        }
+    }
+
+    #[test]
+    fn identical_moons_have_nadi_dosha_without_cancellation() {
+        let lon = nak_pada(3, 2); // Rohini pada 2
+        let r = ashtakoota(lon, lon);
+        let nadi = r.kootas[7];
+        assert!(nadi.dosha);
+        assert_eq!(nadi.points, 0.0);
+        assert_eq!(nadi.cancellation, None);
+        // Same nakshatra: full Varna, Vashya, Yoni, Maitri, Gana, Bhakoot.
+        assert_eq!(r.total, 1.0 + 2.0 + 3.0 + 4.0 + 5.0 + 6.0 + 7.0);
+    }
+
+    #[test]
+    fn nadi_cancelled_for_same_nakshatra_different_pada() {
+        let r = ashtakoota(nak_pada(3, 1), nak_pada(3, 4));
+        assert_eq!(
+            r.kootas[7].cancellation,
+            Some(MilanCancellation::SameNakshatraDifferentPada)
+        );
+        assert_eq!(r.adjusted_total, r.total + 8.0);
+    }
+
+    #[test]
+    fn nadi_cancelled_across_rashi_boundary() {
+        // Krittika pada 1 (Mesha) and pada 2 (Vrishabha).
+        let r = ashtakoota(nak_pada(2, 1), nak_pada(2, 2));
+        assert_eq!(
+            r.kootas[7].cancellation,
+            Some(MilanCancellation::SameNakshatraDifferentRashi)
+        );
+    }
+
+    #[test]
+    fn bhakoot_six_eight_is_dosha() {
+        // Groom in Mesha, bride in Kanya: groom is 8th from bride.
+        let r = ashtakoota(5.0, 155.0);
+        let bhakoot = r.kootas[6];
+        assert!(bhakoot.dosha);
+        assert_eq!(bhakoot.points, 0.0);
+        // Mangal and Buddh are not mutual friends.
+        assert_eq!(bhakoot.cancellation, None);
+    }
+
+    #[test]
+    fn bhakoot_cancelled_by_same_lord() {
+        // Mesha and Vrischika (6/8), both ruled by Mangal.
+        let r = ashtakoota(5.0, 215.0);
+        assert_eq!(
+            r.kootas[6].cancellation,
+            Some(MilanCancellation::SameRashiLord)
+        );
+    }
+
+    #[test]
+    fn tara_counts_both_directions() {
+        // Groom Bharani, bride Ashwini: counts 2 and 27 → both good.
+        let r = ashtakoota(nak_pada(1, 1), nak_pada(0, 1));
+        assert_eq!(r.kootas[2].points, 3.0);
+        // Groom Krittika, bride Ashwini: count 3 (vipat) and 26 (8) → 1.5.
+        let r = ashtakoota(nak_pada(2, 1), nak_pada(0, 1));
+        assert_eq!(r.kootas[2].points, 1.5);
+    }
+
+    #[test]
+    fn vashya_uses_dhanu_and_makara_halves() {
+        let first = MoonSign::new(245.0);
+        let second = MoonSign::new(260.0);
+        assert_eq!(vashya_group(first), MANAVA);
+        assert_eq!(vashya_group(second), CHATUSHPADA);
+        assert_eq!(vashya_group(MoonSign::new(275.0)), CHATUSHPADA);
+        assert_eq!(vashya_group(MoonSign::new(290.0)), JALACHARA);
+    }
+
+    #[test]
+    fn dashakoota_rajju_and_vedha() {
+        // Ashwini and Jyeshtha: vedha pair, and both Pada rajju.
+        let r = dashakoota(nak_pada(0, 1), nak_pada(17, 1));
+        assert!(!r.poruthams[8].matched);
+        assert!(!r.poruthams[9].matched);
+        assert!(r.critical_failure);
+
+        // Bharani groom, Ashwini bride: different rajju, no vedha.
+        let r = dashakoota(nak_pada(1, 1), nak_pada(0, 1));
+        assert!(r.poruthams[8].matched);
+        assert!(r.poruthams[9].matched);
+        assert!(!r.critical_failure);
+        assert_eq!(
+            r.matched_count as usize,
+            r.poruthams.iter().filter(|p| p.matched).count()
+        );
+    }
+
+    #[test]
+    fn dashakoota_counts() {
+        // Groom 4th nakshatra from bride: Mahendra matches, Stree Deergha does not.
+        let r = dashakoota(nak_pada(3, 1), nak_pada(0, 1));
+        assert!(r.poruthams[2].matched);
+        assert!(!r.poruthams[3].matched);
+        // Groom 15th from bride: Stree Deergha matches.
+        let r = dashakoota(nak_pada(14, 1), nak_pada(0, 1));
+        assert!(r.poruthams[3].matched);
+    }
+
+    #[test]
+    fn milan_combines_both() {
+        let m = kundali_milan(100.0, 200.0);
+        assert_eq!(m.ashtakoota, ashtakoota(100.0, 200.0));
+        assert_eq!(m.dashakoota, dashakoota(100.0, 200.0));
+        assert!(m.ashtakoota.total <= ASHTAKOOTA_MAX);
+        assert!(m.ashtakoota.adjusted_total >= m.ashtakoota.total);
+    }
+}
@@ -0,0 +1,327 @@
+//! Daily muhurta tables: Rahu Kalam, Yamaganda, Gulika Kalam, the 15 day
+//! and 15 night muhurtas with Durmuhurta, and Choghadiya.
+//!
+//! Daytime (sunrise to sunset) and nighttime (sunset to next sunrise) are
+//! divided separately, so every slot scales with the actual length of the
+//! day or night:
+//! - Rahu Kalam, Yamaganda and Gulika Kalam: one of eight day segments,
+//!   chosen by vaar.
+//! - Muhurtas: fifteen equal parts of the day and of the night. Abhijit is
+//!   the 8th day muhurta; Brahma muhurta is the 14th night muhurta, ending
+//!   one muhurta before sunrise.
+//! - Choghadiya: eight equal parts of the day and of the night, ruled in
+//!   Chaldean order.
+//!
+//! Clean-room implementation from Muhurta Chintamani and the standard
+//! panchang conventions. See docs/clean_room_muhurta.md.
+
+use crate::hora::{CHALDEAN_SEQUENCE, Hora, vaar_day_lord};
+use crate::vaar::Vaar;
+
+/// Number of muhurtas in the day and, separately, in the night.
+pub const MUHURTA_COUNT: u8 = 15;
+
+/// Number of segments used for Rahu Kalam, Yamaganda and Gulika Kalam.
+pub const KALAM_SEGMENT_COUNT: u8 = 8;
+
+/// Number of Choghadiya slots in the day and, separately, in the night.
+pub const CHOGHADIYA_COUNT: u8 = 8;
+
+/// 0-based day muhurta index of Abhijit (the 8th, spanning local noon).
+pub const ABHIJIT_MUHURTA_INDEX: u8 = 7;
+
+/// 0-based night muhurta index of Brahma muhurta (the 14th).
+pub const BRAHMA_MUHURTA_INDEX: u8 = 13;
+
+/// Names of the 15 day muhurtas, from sunrise.
+pub const DAY_MUHURTA_NAMES: [&str; 15] = [
+    "Rudra",
+    "Ahi",
+    "Mitra",
+    "Pitru",
+    "Vasu",
+    "Varaha",
+    "Vishvedeva",
+    "Abhijit",
+    "Satamukhi",
+    "Puruhuta",
+    "Vahini",
+    "Naktanakara",
+    "Varuna",
+    "Aryaman",
+    "Bhaga",
+];
+
+/// Names of the 15 night muhurtas, from sunset.
+pub const NIGHT_MUHURTA_NAMES: [&str; 15] = [
+    "Girisha",
+    "Ajapada",
+    "Ahirbudhnya",
+    "Pushya",
+    "Ashvini",
+    "Yama",
+    "Agni",
+    "Vidhatri",
+    "Chanda",
+    "Aditi",
+    "Jiva",
+    "Vishnu",
+    "Dyumadgadyuti",
+    "Tvashtri",
+    "Vayu",
+];
+
+/// Rahu Kalam segment (1-8) by vaar index (Ravivaar first).
+const RAHU_KALAM_SEGMENT: [u8; 7] = [8, 2, 7, 5, 6, 4, 3];
+/// Yamaganda segment (1-8) by vaar index.
+const YAMAGANDA_SEGMENT: [u8; 7] = [5, 4, 3, 2, 1, 7, 6];
+/// Gulika Kalam segment (1-8) by vaar index.
+const GULIKA_KALAM_SEGMENT: [u8; 7] = [7, 6, 5, 4, 3, 2, 1];
+
+/// 0-based day segment (of 8) holding Rahu Kalam.
+pub const fn rahu_kalam_segment(vaar: Vaar) -> u8 {
+    RAHU_KALAM_SEGMENT[vaar.index() as usize] - 1
+}
+
+/// 0-based day segment (of 8) holding Yamaganda.
+pub const fn yamaganda_segment(vaar: Vaar) -> u8 {
+    YAMAGANDA_SEGMENT[vaar.index() as usize] - 1
+}
+
+/// 0-based day segment (of 8) holding Gulika Kalam.
+pub const fn gulika_kalam_segment(vaar: Vaar) -> u8 {
+    GULIKA_KALAM_SEGMENT[vaar.index() as usize] - 1
+}
+
+/// Whether a muhurta is a Durmuhurta for the given vaar.
+///
+/// `index` is 0-based within the day (`is_night == false`) or the night.
+/// Day Durmuhurtas (1-based): Ravi 14; Soma 9, 12; Mangala 4; Budha 8;
+/// Guru 6, 12; Shukra 4, 9; Shani 1, 2. Mangalvaar adds the 7th night
+/// muhurta.
+pub fn is_durmuhurta(vaar: Vaar, is_night: bool, index: u8) -> bool {
+    let n = index + 1;
+    if is_night {
+        return vaar == Vaar::Mangalvaar && n == 7;
+    }
+    match vaar {
+        Vaar::Ravivaar => n == 14,
+        Vaar::Somvaar => n == 9 || n == 12,
+        Vaar::Mangalvaar => n == 4,
+        Vaar::Budhvaar => n == 8,
+        Vaar::Guruvaar => n == 6 || n == 12,
+        Vaar::Shukravaar => n == 4 || n == 9,
+        Vaar::Shanivaar => n == 1 || n == 2,
+    }
+}
+
+/// The seven Choghadiya kinds, in Chaldean lord order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Choghadiya {
+    /// Ruled by Surya.
+    Udveg,
+    /// Ruled by Shukra.
+    Char,
+    /// Ruled by Buddh.
+    Labh,
+    /// Ruled by Chandra.
+    Amrit,
+    /// Ruled by Shani.
+    Kaal,
+    /// Ruled by Guru.
+    Shubh,
+    /// Ruled by Mangal.
+    Rog,
+}
+
+/// All 7 Choghadiyas in Chaldean lord order (Udveg first), for FFI indexing.
+pub const ALL_CHOGHADIYAS: [Choghadiya; 7] = [
+    Choghadiya::Udveg,
+    Choghadiya::Char,
+    Choghadiya::Labh,
+    Choghadiya::Amrit,
+    Choghadiya::Kaal,
+    Choghadiya::Shubh,
+    Choghadiya::Rog,
+];
+
+/// Broad quality of a Choghadiya.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChoghadiyaNature {
+    Auspicious,
+    Neutral,
+    Inauspicious,
+}
+
+impl Choghadiya {
+    /// Name of the Choghadiya.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Udveg => "Udveg",
+            Self::Char => "Char",
+            Self::Labh => "Labh",
+            Self::Amrit => "Amrit",
+            Self::Kaal => "Kaal",
+            Self::Shubh => "Shubh",
+            Self::Rog => "Rog",
+        }
+    }
+
+    /// Index in ALL_CHOGHADIYAS (0 = Udveg, 6 = Rog).
+    pub const fn index(self) -> u8 {
+        match self {
+            Self::Udveg => 0,
+            Self::Char => 1,
+            Self::Labh => 2,
+            Self::Amrit => 3,
+            Self::Kaal => 4,
+            Self::Shubh => 5,
+            Self::Rog => 6,
+        }
+    }
+
+    /// Ruling graha, as a hora lord.
+    pub const fn lord(self) -> Hora {
+        CHALDEAN_SEQUENCE[self.index() as usize]
+    }
+
+    /// Auspicious (Amrit, Shubh, Labh), neutral (Char) or inauspicious.
+    pub const fn nature(self) -> ChoghadiyaNature {
+        match self {
+            Self::Amrit | Self::Shubh | Self::Labh => ChoghadiyaNature::Auspicious,
+            Self::Char => ChoghadiyaNature::Neutral,
+            Self::Udveg | Self::Kaal | Self::Rog => ChoghadiyaNature::Inauspicious,
+        }
+    }
+}
+
+/// Choghadiya for a day or night slot (0-7).
+///
+/// The first day slot belongs to the day lord and each following slot
+/// takes the next lord in Chaldean order. The first night slot belongs to
+/// the fifth lord from the day lord, and each following slot steps five
+/// lords on. The eighth slot repeats the first.
+pub fn choghadiya_at(vaar: Vaar, is_night: bool, slot: u8) -> Choghadiya {
+    let start = vaar_day_lord(vaar).index() as u16;
+    let slot = slot as u16;
+    let index = if is_night {
+        (start + 5 + 5 * slot) % 7
+    } else {
+        (start + slot) % 7
+    };
+    ALL_CHOGHADIYAS[index as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vaar::ALL_VAARS;
+
+    #[test]
+    fn kalam_segments_are_distinct_each_day() {
+        for vaar in ALL_VAARS {
+            let r = rahu_kalam_segment(vaar);
+            let y = yamaganda_segment(vaar);
+            let g = gulika_kalam_segment(vaar);
+            assert!(r < KALAM_SEGMENT_COUNT && y < KALAM_SEGMENT_COUNT);
+            assert!(g < KALAM_SEGMENT_COUNT);
+            assert!(r != y && r != g && y != g, "{vaar:?}");
+        }
+    }
+
+    #[test]
+    fn rahu_kalam_known_days() {
+        // Sunday: last eighth (16:30-18:00 for a 6-to-6 day).
+        assert_eq!(rahu_kalam_segment(Vaar::Ravivaar), 7);
+        // Monday: second eighth (07:30-09:00).
+        assert_eq!(rahu_kalam_segment(Vaar::Somvaar), 1);
+        // Saturday: third eighth (09:00-10:30).
+        assert_eq!(rahu_kalam_segment(Vaar::Shanivaar), 2);
+    }
+
+    #[test]
+    fn gulika_walks_back_from_sunday() {
+        for (i, vaar) in ALL_VAARS.iter().enumerate() {
+            assert_eq!(gulika_kalam_segment(*vaar), 6 - i as u8);
+        }
+    }
+
+    #[test]
+    fn abhijit_and_brahma_indices() {
+        assert_eq!(DAY_MUHURTA_NAMES[ABHIJIT_MUHURTA_INDEX as usize], "Abhijit");
+        // Brahma muhurta is the second-to-last night muhurta.
+        assert_eq!(BRAHMA_MUHURTA_INDEX, MUHURTA_COUNT - 2);
+    }
+
+    #[test]
+    fn durmuhurta_counts() {
+        let count = |vaar: Vaar| {
+            (0..MUHURTA_COUNT)
+                .flat_map(|i| [is_durmuhurta(vaar, false, i), is_durmuhurta(vaar, true, i)])
+                .filter(|&d| d)
+                .count()
+        };
+        assert_eq!(count(Vaar::Ravivaar), 1);
+        assert_eq!(count(Vaar::Budhvaar), 1);
+        assert_eq!(count(Vaar::Mangalvaar), 2);
+        assert_eq!(count(Vaar::Shanivaar), 2);
+        assert!(is_durmuhurta(Vaar::Budhvaar, false, ABHIJIT_MUHURTA_INDEX));
+        assert!(is_durmuhurta(Vaar::Mangalvaar, true, 6));
+    }
+
+    #[test]
+    fn sunday_choghadiya_sequence() {
+        let day: Vec<_> = (0..CHOGHADIYA_COUNT)
+            .map(|s| choghadiya_at(Vaar::Ravivaar, false, s))
+            .collect();
+        assert_eq!(
+            day,
+            [
+                Choghadiya::Udveg,
+                Choghadiya::Char,
+                Choghadiya::Labh,
+                Choghadiya::Amrit,
+                Choghadiya::Kaal,
+                Choghadiya::Shubh,
+                Choghadiya::Rog,
+                Choghadiya::Udveg,
+            ]
+        );
+        let night: Vec<_> = (0..CHOGHADIYA_COUNT)
+            .map(|s| choghadiya_at(Vaar::Ravivaar, true, s))
+            .collect();
+        assert_eq!(
+            night,
+            [
+                Choghadiya::Shubh,
+                Choghadiya::Amrit,
+                Choghadiya::Char,
+                Choghadiya::Rog,
+                Choghadiya::Kaal,
+                Choghadiya::Labh,
+                Choghadiya::Udveg,
+                Choghadiya::Shubh,
+            ]
+        );
+    }
+
+    #[test]
+    fn monday_choghadiya_starts() {
+        assert_eq!(choghadiya_at(Vaar::Somvaar, false, 0), Choghadiya::Amrit);
+        assert_eq!(choghadiya_at(Vaar::Somvaar, true, 0), Choghadiya::Char);
+        assert_eq!(choghadiya_at(Vaar::Shanivaar, false, 0), Choghadiya::Kaal);
+        assert_eq!(choghadiya_at(Vaar::Shanivaar, true, 0), Choghadiya::Labh);
+    }
+
+    #[test]
+    fn choghadiya_lords_and_nature() {
+        assert_eq!(Choghadiya::Amrit.lord(), Hora::Chandra);
+        assert_eq!(Choghadiya::Rog.lord(), Hora::Mangal);
+        assert_eq!(Choghadiya::Char.nature(), ChoghadiyaNature::Neutral);
+        assert_eq!(Choghadiya::Kaal.nature(), ChoghadiyaNature::Inauspicious);
+        for (i, c) in ALL_CHOGHADIYAS.iter().enumerate() {
+            assert_eq!(c.index() as usize, i);
+            assert!(!c.name().is_empty());
+        }
+    }
+}
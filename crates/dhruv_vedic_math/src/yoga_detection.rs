@@ -0,0 +1,841 @@
+//! Rule-based graha yoga detection.
+//!
+//! Yogas are evaluated over a [`YogaChart`] (sidereal lagna plus the nine
+//! graha longitudes) using whole-sign bhavas counted from the lagna rashi.
+//! Each yoga is a [`YogaRule`] in a plain table; callers can extend
+//! [`CLASSICAL_YOGA_RULES`] with their own rules and pass the combined slice
+//! to [`detect_yogas`].
+//!
+//! Graha association (sambandha) is conjunction in one rashi, mutual rashi
+//! drishti, or exchange of signs (parivartana). Rahu and Ketu own no houses
+//! here and do not take part in lordship-based yogas.
+//!
+//! Clean-room implementation from BPHS and Phaladeepika.
+//! See docs/clean_room_yoga_detection.md.
+
+use crate::graha::{Graha, SAPTA_GRAHAS, rashi_lord_by_index};
+use crate::graha_relationships::{debilitation_degree, exaltation_degree, own_signs};
+use crate::util::normalize_360;
+
+/// Kendra (angular) bhavas.
+pub const KENDRA_BHAVAS: [u8; 4] = [1, 4, 7, 10];
+/// Trikona (trinal) bhavas.
+pub const TRIKONA_BHAVAS: [u8; 3] = [1, 5, 9];
+/// Dusthana (difficult) bhavas.
+pub const DUSTHANA_BHAVAS: [u8; 3] = [6, 8, 12];
+/// Bhavas whose lords form Dhana yogas (lagna, wealth, trikonas, gains).
+pub const DHANA_BHAVAS: [u8; 5] = [1, 2, 5, 9, 11];
+
+/// Grahas that form Chandra/Surya yogas (no luminaries or nodes).
+const TARA_GRAHAS: [Graha; 5] = [
+    Graha::Mangal,
+    Graha::Buddh,
+    Graha::Guru,
+    Graha::Shukra,
+    Graha::Shani,
+];
+
+/// Broad family a yoga belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YogaCategory {
+    /// Pancha Mahapurusha yogas.
+    MahaPurusha,
+    /// Yogas counted from the Moon (Gaja Kesari, Sunapha, Anapha, ...).
+    Chandra,
+    /// Yogas counted from the Sun (Vesi, Vasi, Ubhayachari).
+    Surya,
+    /// Kendra–trikona lord combinations.
+    Raja,
+    /// Wealth-lord combinations.
+    Dhana,
+    /// Dusthana lords in dusthanas.
+    ViparitaRaja,
+    /// Cancellation of a graha's debilitation.
+    NeechaBhanga,
+    /// Caller-defined rules.
+    Custom,
+}
+
+/// Chart data the rules run on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YogaChart {
+    /// Sidereal lagna longitude in degrees.
+    pub lagna_longitude: f64,
+    /// Sidereal graha longitudes, indexed by [`Graha::index`].
+    pub graha_longitudes: [f64; 9],
+}
+
+impl YogaChart {
+    pub fn new(lagna_longitude: f64, graha_longitudes: [f64; 9]) -> Self {
+        Self {
+            lagna_longitude: normalize_360(lagna_longitude),
+            graha_longitudes: graha_longitudes.map(normalize_360),
+        }
+    }
+
+    /// 0-based rashi of the lagna.
+    pub fn lagna_rashi(&self) -> u8 {
+        rashi_index(self.lagna_longitude)
+    }
+
+    /// 0-based rashi occupied by a graha.
+    pub fn rashi(&self, graha: Graha) -> u8 {
+        rashi_index(self.graha_longitudes[graha.index() as usize])
+    }
+
+    /// Whole-sign bhava (1-12) of a graha counted from the lagna.
+    pub fn bhava(&self, graha: Graha) -> u8 {
+        count_from(self.lagna_rashi(), self.rashi(graha))
+    }
+
+    /// Whole-sign bhava (1-12) of `graha` counted from another graha's rashi.
+    pub fn bhava_from(&self, graha: Graha, reference: Graha) -> u8 {
+        count_from(self.rashi(reference), self.rashi(graha))
+    }
+
+    /// Lord of a whole-sign bhava (1-12).
+    pub fn bhava_lord(&self, bhava: u8) -> Graha {
+        let rashi = (self.lagna_rashi() + bhava + 11) % 12;
+        rashi_lord_by_index(rashi).expect("rashi index < 12")
+    }
+
+    /// Bhavas (1-12) ruled by a graha; empty for Rahu and Ketu.
+    pub fn bhavas_ruled(&self, graha: Graha) -> Vec<u8> {
+        (1..=12).filter(|&b| self.bhava_lord(b) == graha).collect()
+    }
+
+    /// Whether the graha occupies its own sign.
+    pub fn in_own_sign(&self, graha: Graha) -> bool {
+        own_signs(graha).contains(&self.rashi(graha))
+    }
+
+    /// Whether the graha occupies its exaltation sign.
+    pub fn in_exaltation_sign(&self, graha: Graha) -> bool {
+        exaltation_rashi(graha) == Some(self.rashi(graha))
+    }
+
+    /// Whether the graha occupies its debilitation sign.
+    pub fn in_debilitation_sign(&self, graha: Graha) -> bool {
+        debilitation_rashi(graha) == Some(self.rashi(graha))
+    }
+
+    /// Whether `graha` casts rashi drishti on `target_rashi`.
+    ///
+    /// All grahas aspect the 7th; Mangal also the 4th and 8th, Guru the 5th
+    /// and 9th, Shani the 3rd and 10th.
+    pub fn aspects_rashi(&self, graha: Graha, target_rashi: u8) -> bool {
+        let n = count_from(self.rashi(graha), target_rashi);
+        match graha {
+            Graha::Mangal => matches!(n, 4 | 7 | 8),
+            Graha::Guru => matches!(n, 5 | 7 | 9),
+            Graha::Shani => matches!(n, 3 | 7 | 10),
+            _ => n == 7,
+        }
+    }
+
+    /// Association between two grahas, if any.
+    pub fn sambandha(&self, a: Graha, b: Graha) -> Option<Sambandha> {
+        let (ra, rb) = (self.rashi(a), self.rashi(b));
+        if ra == rb {
+            Some(Sambandha::Conjunction)
+        } else if rashi_lord_by_index(ra) == Some(b) && rashi_lord_by_index(rb) == Some(a) {
+            Some(Sambandha::Exchange)
+        } else if self.aspects_rashi(a, rb) && self.aspects_rashi(b, ra) {
+            Some(Sambandha::MutualAspect)
+        } else {
+            None
+        }
+    }
+}
+
+/// How two grahas are associated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sambandha {
+    Conjunction,
+    Exchange,
+    MutualAspect,
+}
+
+impl Sambandha {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Conjunction => "conjunction",
+            Self::Exchange => "exchange",
+            Self::MutualAspect => "mutual aspect",
+        }
+    }
+}
+
+/// One firing of a rule: the grahas involved and what matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YogaHit {
+    pub grahas: Vec<Graha>,
+    pub detail: String,
+}
+
+/// A yoga rule: metadata plus a detector returning every occurrence.
+#[derive(Debug, Clone, Copy)]
+pub struct YogaRule {
+    pub name: &'static str,
+    pub category: YogaCategory,
+    /// Short statement of the condition, reported with each hit.
+    pub rule: &'static str,
+    pub detect: fn(&YogaChart) -> Vec<YogaHit>,
+}
+
+/// A detected yoga.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedYoga {
+    pub name: &'static str,
+    pub category: YogaCategory,
+    /// The rule that fired.
+    pub rule: &'static str,
+    /// Participating grahas.
+    pub grahas: Vec<Graha>,
+    /// Chart-specific explanation (houses, signs, association).
+    pub detail: String,
+}
+
+/// Run `rules` over a chart, in table order.
+pub fn detect_yogas(chart: &YogaChart, rules: &[YogaRule]) -> Vec<DetectedYoga> {
+    rules
+        .iter()
+        .flat_map(|r| {
+            (r.detect)(chart).into_iter().map(|hit| DetectedYoga {
+                name: r.name,
+                category: r.category,
+                rule: r.rule,
+                grahas: hit.grahas,
+                detail: hit.detail,
+            })
+        })
+        .collect()
+}
+
+fn rashi_index(lon: f64) -> u8 {
+    ((normalize_360(lon) / 30.0).floor() as u8).min(11)
+}
+
+/// Inclusive count from one rashi to another (1-12).
+fn count_from(from: u8, to: u8) -> u8 {
+    (to + 12 - from) % 12 + 1
+}
+
+fn exaltation_rashi(graha: Graha) -> Option<u8> {
+    exaltation_degree(graha).map(|d| (d / 30.0) as u8)
+}
+
+fn debilitation_rashi(graha: Graha) -> Option<u8> {
+    debilitation_degree(graha).map(|d| (d / 30.0) as u8)
+}
+
+fn hit(grahas: Vec<Graha>, detail: String) -> YogaHit {
+    YogaHit { grahas, detail }
+}
+
+// ---------------------------------------------------------------------------
+// Pancha Mahapurusha
+// ---------------------------------------------------------------------------
+
+fn mahapurusha(chart: &YogaChart, graha: Graha) -> Vec<YogaHit> {
+    let bhava = chart.bhava(graha);
+    if !KENDRA_BHAVAS.contains(&bhava) {
+        return Vec::new();
+    }
+    let dignity = if chart.in_exaltation_sign(graha) {
+        "exaltation"
+    } else if chart.in_own_sign(graha) {
+        "own sign"
+    } else {
+        return Vec::new();
+    };
+    vec![hit(
+        vec![graha],
+        format!("{} in bhava {bhava} in {dignity}", graha.name()),
+    )]
+}
+
+fn ruchaka(chart: &YogaChart) -> Vec<YogaHit> {
+    mahapurusha(chart, Graha::Mangal)
+}
+
+fn bhadra(chart: &YogaChart) -> Vec<YogaHit> {
+    mahapurusha(chart, Graha::Buddh)
+}
+
+fn hamsa(chart: &YogaChart) -> Vec<YogaHit> {
+    mahapurusha(chart, Graha::Guru)
+}
+
+fn malavya(chart: &YogaChart) -> Vec<YogaHit> {
+    mahapurusha(chart, Graha::Shukra)
+}
+
+fn sasa(chart: &YogaChart) -> Vec<YogaHit> {
+    mahapurusha(chart, Graha::Shani)
+}
+
+// ---------------------------------------------------------------------------
+// Chandra and Surya yogas
+// ---------------------------------------------------------------------------
+
+fn gaja_kesari(chart: &YogaChart) -> Vec<YogaHit> {
+    let n = chart.bhava_from(Graha::Guru, Graha::Chandra);
+    if KENDRA_BHAVAS.contains(&n) {
+        vec![hit(
+            vec![Graha::Guru, Graha::Chandra],
+            format!("Guru in bhava {n} from Chandra"),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Tara grahas in the 2nd and 12th from a luminary.
+fn flanking(chart: &YogaChart, reference: Graha) -> (Vec<Graha>, Vec<Graha>) {
+    let in_bhava = |n: u8| {
+        TARA_GRAHAS
+            .iter()
+            .copied()
+            .filter(|&g| chart.bhava_from(g, reference) == n)
+            .collect::<Vec<_>>()
+    };
+    (in_bhava(2), in_bhava(12))
+}
+
+fn graha_names(grahas: &[Graha]) -> String {
+    grahas
+        .iter()
+        .map(|g| g.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn flank_hit(
+    reference: Graha,
+    second: &[Graha],
+    twelfth: &[Graha],
+    want_second: bool,
+    want_twelfth: bool,
+) -> Vec<YogaHit> {
+    if second.is_empty() == want_second || twelfth.is_empty() == want_twelfth {
+        return Vec::new();
+    }
+    let mut grahas = vec![reference];
+    grahas.extend_from_slice(second);
+    grahas.extend_from_slice(twelfth);
+    let mut parts = Vec::new();
+    if want_second {
+        parts.push(format!("{} in 2nd", graha_names(second)));
+    }
+    if want_twelfth {
+        parts.push(format!("{} in 12th", graha_names(twelfth)));
+    }
+    vec![hit(
+        grahas,
+        format!("{} from {}", parts.join(" and "), reference.name()),
+    )]
+}
+
+fn sunapha(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Chandra);
+    flank_hit(Graha::Chandra, &second, &twelfth, true, false)
+}
+
+fn anapha(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Chandra);
+    flank_hit(Graha::Chandra, &second, &twelfth, false, true)
+}
+
+fn durudhara(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Chandra);
+    flank_hit(Graha::Chandra, &second, &twelfth, true, true)
+}
+
+fn kemadruma(chart: &YogaChart) -> Vec<YogaHit> {
+    let occupied = TARA_GRAHAS.iter().any(|&g| {
+        let n = chart.bhava_from(g, Graha::Chandra);
+        matches!(n, 2 | 12) || KENDRA_BHAVAS.contains(&n)
+    });
+    if occupied {
+        Vec::new()
+    } else {
+        vec![hit(
+            vec![Graha::Chandra],
+            "no graha in the 2nd, 12th or a kendra from Chandra".to_string(),
+        )]
+    }
+}
+
+fn vesi(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Surya);
+    flank_hit(Graha::Surya, &second, &twelfth, true, false)
+}
+
+fn vasi(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Surya);
+    flank_hit(Graha::Surya, &second, &twelfth, false, true)
+}
+
+fn ubhayachari(chart: &YogaChart) -> Vec<YogaHit> {
+    let (second, twelfth) = flanking(chart, Graha::Surya);
+    flank_hit(Graha::Surya, &second, &twelfth, true, true)
+}
+
+// ---------------------------------------------------------------------------
+// Lordship yogas
+// ---------------------------------------------------------------------------
+
+fn bhava_list(bhavas: &[u8]) -> String {
+    bhavas
+        .iter()
+        .map(|b| b.to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Associated pairs of distinct sapta grahas where one rules a bhava in
+/// `first` and the other a different bhava in `second`.
+fn lord_pairs(chart: &YogaChart, first: &[u8], second: &[u8]) -> Vec<YogaHit> {
+    let mut hits = Vec::new();
+    for (i, &a) in SAPTA_GRAHAS.iter().enumerate() {
+        for &b in &SAPTA_GRAHAS[i + 1..] {
+            let ruled_a = chart.bhavas_ruled(a);
+            let ruled_b = chart.bhavas_ruled(b);
+            let pick = |x: &[u8], set: &[u8]| -> Vec<u8> {
+                x.iter().copied().filter(|h| set.contains(h)).collect()
+            };
+            let qualifies = |x: &[u8], y: &[u8]| {
+                let xf = pick(x, first);
+                let ys = pick(y, second);
+                xf.iter().any(|h| ys.iter().any(|k| k != h))
+            };
+            if !(qualifies(&ruled_a, &ruled_b) || qualifies(&ruled_b, &ruled_a)) {
+                continue;
+            }
+            let Some(rel) = chart.sambandha(a, b) else {
+                continue;
+            };
+            let union: Vec<u8> = first.iter().chain(second).copied().collect();
+            hits.push(hit(
+                vec![a, b],
+                format!(
+                    "lord of {} ({}) and lord of {} ({}) in {}",
+                    bhava_list(&pick(&ruled_a, &union)),
+                    a.name(),
+                    bhava_list(&pick(&ruled_b, &union)),
+                    b.name(),
+                    rel.name()
+                ),
+            ));
+        }
+    }
+    hits
+}
+
+fn raja(chart: &YogaChart) -> Vec<YogaHit> {
+    lord_pairs(chart, &KENDRA_BHAVAS, &TRIKONA_BHAVAS)
+}
+
+fn yogakaraka(chart: &YogaChart) -> Vec<YogaHit> {
+    SAPTA_GRAHAS
+        .iter()
+        .filter_map(|&g| {
+            let ruled = chart.bhavas_ruled(g);
+            let kendra = ruled.iter().find(|b| matches!(b, 4 | 7 | 10))?;
+            let trikona = ruled.iter().find(|b| matches!(b, 5 | 9))?;
+            Some(hit(
+                vec![g],
+                format!("{} rules kendra {kendra} and trikona {trikona}", g.name()),
+            ))
+        })
+        .collect()
+}
+
+fn dhana(chart: &YogaChart) -> Vec<YogaHit> {
+    lord_pairs(chart, &[2, 11], &DHANA_BHAVAS)
+}
+
+fn viparita(chart: &YogaChart, lord_of: u8) -> Vec<YogaHit> {
+    let lord = chart.bhava_lord(lord_of);
+    let bhava = chart.bhava(lord);
+    if DUSTHANA_BHAVAS.contains(&bhava) {
+        vec![hit(
+            vec![lord],
+            format!("lord of {lord_of} ({}) in bhava {bhava}", lord.name()),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn harsha(chart: &YogaChart) -> Vec<YogaHit> {
+    viparita(chart, 6)
+}
+
+fn sarala(chart: &YogaChart) -> Vec<YogaHit> {
+    viparita(chart, 8)
+}
+
+fn vimala(chart: &YogaChart) -> Vec<YogaHit> {
+    viparita(chart, 12)
+}
+
+// ---------------------------------------------------------------------------
+// Neecha Bhanga
+// ---------------------------------------------------------------------------
+
+fn neecha_bhanga(chart: &YogaChart) -> Vec<YogaHit> {
+    // Chandra is trivially in a kendra from itself, so only the lagna counts for it.
+    let in_kendra = |g: Graha| {
+        KENDRA_BHAVAS.contains(&chart.bhava(g))
+            || (g != Graha::Chandra && KENDRA_BHAVAS.contains(&chart.bhava_from(g, Graha::Chandra)))
+    };
+    let mut hits = Vec::new();
+    for &graha in &SAPTA_GRAHAS {
+        if !chart.in_debilitation_sign(graha) {
+            continue;
+        }
+        let deb_rashi = chart.rashi(graha);
+        let mut cancellers = Vec::new();
+        let mut reasons = Vec::new();
+        // Buddh lords its own exaltation sign; a graha cannot cancel itself.
+        let mut check = |g: Graha, what: &str| {
+            if g != graha && in_kendra(g) {
+                reasons.push(format!("{} ({what}) in kendra", g.name()));
+                if !cancellers.contains(&g) {
+                    cancellers.push(g);
+                }
+            }
+        };
+        check(
+            rashi_lord_by_index(deb_rashi).expect("rashi index < 12"),
+            "debilitation-sign lord",
+        );
+        if let Some(ex) = exaltation_rashi(graha) {
+            check(
+                rashi_lord_by_index(ex).expect("rashi index < 12"),
+                "exaltation-sign lord",
+            );
+        }
+        if let Some(&g) = SAPTA_GRAHAS
+            .iter()
+            .find(|&&g| exaltation_rashi(g) == Some(deb_rashi))
+        {
+            check(g, "exalted in that sign");
+        }
+        if reasons.is_empty() {
+            continue;
+        }
+        let mut grahas = vec![graha];
+        grahas.extend(cancellers);
+        hits.push(hit(
+            grahas,
+            format!(
+                "{} debilitated; {} from lagna or Chandra",
+                graha.name(),
+                reasons.join(", ")
+            ),
+        ));
+    }
+    hits
+}
+
+/// Classical rule table, in reporting order.
+pub const CLASSICAL_YOGA_RULES: &[YogaRule] = &[
+    YogaRule {
+        name: "Ruchaka",
+        category: YogaCategory::MahaPurusha,
+        rule: "Mangal in a kendra in own or exaltation sign",
+        detect: ruchaka,
+    },
+    YogaRule {
+        name: "Bhadra",
+        category: YogaCategory::MahaPurusha,
+        rule: "Buddh in a kendra in own or exaltation sign",
+        detect: bhadra,
+    },
+    YogaRule {
+        name: "Hamsa",
+        category: YogaCategory::MahaPurusha,
+        rule: "Guru in a kendra in own or exaltation sign",
+        detect: hamsa,
+    },
+    YogaRule {
+        name: "Malavya",
+        category: YogaCategory::MahaPurusha,
+        rule: "Shukra in a kendra in own or exaltation sign",
+        detect: malavya,
+    },
+    YogaRule {
+        name: "Sasa",
+        category: YogaCategory::MahaPurusha,
+        rule: "Shani in a kendra in own or exaltation sign",
+        detect: sasa,
+    },
+    YogaRule {
+        name: "Gaja Kesari",
+        category: YogaCategory::Chandra,
+        rule: "Guru in a kendra from Chandra",
+        detect: gaja_kesari,
+    },
+    YogaRule {
+        name: "Sunapha",
+        category: YogaCategory::Chandra,
+        rule: "grahas other than Surya and nodes in the 2nd from Chandra only",
+        detect: sunapha,
+    },
+    YogaRule {
+        name: "Anapha",
+        category: YogaCategory::Chandra,
+        rule: "grahas other than Surya and nodes in the 12th from Chandra only",
+        detect: anapha,
+    },
+    YogaRule {
+        name: "Durudhara",
+        category: YogaCategory::Chandra,
+        rule: "grahas other than Surya and nodes in both the 2nd and 12th from Chandra",
+        detect: durudhara,
+    },
+    YogaRule {
+        name: "Kemadruma",
+        category: YogaCategory::Chandra,
+        rule: "no graha other than Surya and nodes in the 2nd, 12th or a kendra from Chandra",
+        detect: kemadruma,
+    },
+    YogaRule {
+        name: "Vesi",
+        category: YogaCategory::Surya,
+        rule: "grahas other than Chandra and nodes in the 2nd from Surya only",
+        detect: vesi,
+    },
+    YogaRule {
+        name: "Vasi",
+        category: YogaCategory::Surya,
+        rule: "grahas other than Chandra and nodes in the 12th from Surya only",
+        detect: vasi,
+    },
+    YogaRule {
+        name: "Ubhayachari",
+        category: YogaCategory::Surya,
+        rule: "grahas other than Chandra and nodes in both the 2nd and 12th from Surya",
+        detect: ubhayachari,
+    },
+    YogaRule {
+        name: "Raja",
+        category: YogaCategory::Raja,
+        rule: "kendra lord associated with a trikona lord",
+        detect: raja,
+    },
+    YogaRule {
+        name: "Yogakaraka",
+        category: YogaCategory::Raja,
+        rule: "one graha rules both a kendra (4/7/10) and a trikona (5/9)",
+        detect: yogakaraka,
+    },
+    YogaRule {
+        name: "Dhana",
+        category: YogaCategory::Dhana,
+        rule: "lord of 2 or 11 associated with the lord of 1, 2, 5, 9 or 11",
+        detect: dhana,
+    },
+    YogaRule {
+        name: "Harsha",
+        category: YogaCategory::ViparitaRaja,
+        rule: "lord of 6 in 6, 8 or 12",
+        detect: harsha,
+    },
+    YogaRule {
+        name: "Sarala",
+        category: YogaCategory::ViparitaRaja,
+        rule: "lord of 8 in 6, 8 or 12",
+        detect: sarala,
+    },
+    YogaRule {
+        name: "Vimala",
+        category: YogaCategory::ViparitaRaja,
+        rule: "lord of 12 in 6, 8 or 12",
+        detect: vimala,
+    },
+    YogaRule {
+        name: "Neecha Bhanga",
+        category: YogaCategory::NeechaBhanga,
+        rule: "debilitated graha whose sign lord, exaltation lord or the graha exalted there is in a kendra from lagna or Chandra",
+        detect: neecha_bhanga,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Chart with the lagna and every graha at the middle of the given rashis.
+    fn chart(lagna_rashi: u8, rashis: [u8; 9]) -> YogaChart {
+        YogaChart::new(
+            lagna_rashi as f64 * 30.0 + 15.0,
+            rashis.map(|r| r as f64 * 30.0 + 15.0),
+        )
+    }
+
+    fn names(found: &[DetectedYoga]) -> Vec<&'static str> {
+        found.iter().map(|y| y.name).collect()
+    }
+
+    fn find<'a>(found: &'a [DetectedYoga], name: &str) -> Vec<&'a DetectedYoga> {
+        found.iter().filter(|y| y.name == name).collect()
+    }
+
+    #[test]
+    fn bhava_lords_follow_lagna() {
+        // Mesha lagna: 1 Mangal, 4 Chandra, 9 Guru, 10 Shani.
+        let c = chart(0, [0; 9]);
+        assert_eq!(c.bhava_lord(1), Graha::Mangal);
+        assert_eq!(c.bhava_lord(4), Graha::Chandra);
+        assert_eq!(c.bhava_lord(9), Graha::Guru);
+        assert_eq!(c.bhava_lord(10), Graha::Shani);
+        assert_eq!(c.bhavas_ruled(Graha::Mangal), vec![1, 8]);
+        assert!(c.bhavas_ruled(Graha::Rahu).is_empty());
+    }
+
+    #[test]
+    fn hamsa_for_exalted_guru_in_kendra() {
+        // Mesha lagna, Guru in Karka (4th, exalted).
+        let c = chart(0, [6, 6, 6, 6, 3, 6, 6, 6, 0]);
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        let hamsa = find(&found, "Hamsa");
+        assert_eq!(hamsa.len(), 1);
+        assert_eq!(hamsa[0].grahas, vec![Graha::Guru]);
+        assert_eq!(hamsa[0].category, YogaCategory::MahaPurusha);
+        assert!(hamsa[0].detail.contains("exaltation"));
+    }
+
+    #[test]
+    fn gaja_kesari_from_moon() {
+        // Guru 7th from Chandra.
+        let c = chart(0, [1, 2, 1, 1, 8, 1, 1, 1, 7]);
+        assert!(names(&detect_yogas(&c, CLASSICAL_YOGA_RULES)).contains(&"Gaja Kesari"));
+        // Guru 2nd from Chandra: no Gaja Kesari.
+        let c = chart(0, [1, 2, 1, 1, 3, 1, 1, 1, 7]);
+        assert!(!names(&detect_yogas(&c, CLASSICAL_YOGA_RULES)).contains(&"Gaja Kesari"));
+    }
+
+    #[test]
+    fn chandra_flanking_yogas_are_exclusive() {
+        // Chandra in Mithuna (2); Shukra in Karka (2nd), Shani in Vrishabha (12th).
+        let c = chart(0, [8, 2, 8, 8, 8, 3, 1, 10, 4]);
+        let found = names(&detect_yogas(&c, CLASSICAL_YOGA_RULES));
+        assert!(found.contains(&"Durudhara"));
+        assert!(!found.contains(&"Sunapha"));
+        assert!(!found.contains(&"Anapha"));
+        assert!(!found.contains(&"Kemadruma"));
+
+        // Only Shukra in the 2nd.
+        let c = chart(0, [8, 2, 8, 8, 8, 3, 8, 10, 4]);
+        let found = names(&detect_yogas(&c, CLASSICAL_YOGA_RULES));
+        assert!(found.contains(&"Sunapha"));
+        assert!(!found.contains(&"Durudhara"));
+    }
+
+    #[test]
+    fn kemadruma_when_moon_isolated() {
+        // Chandra in Mesha, all tara grahas in Simha (5th) or Dhanu (9th).
+        let c = chart(0, [4, 0, 4, 4, 8, 8, 8, 3, 9]);
+        let k = find(&detect_yogas(&c, CLASSICAL_YOGA_RULES), "Kemadruma").len();
+        assert_eq!(k, 1);
+    }
+
+    #[test]
+    fn vesi_ignores_moon() {
+        // Surya in Mesha; only Chandra in Vrishabha → no Vesi.
+        let c = chart(0, [0, 1, 6, 6, 6, 6, 6, 5, 11]);
+        assert!(!names(&detect_yogas(&c, CLASSICAL_YOGA_RULES)).contains(&"Vesi"));
+        // Buddh in Vrishabha → Vesi.
+        let c = chart(0, [0, 6, 6, 1, 6, 6, 6, 5, 11]);
+        assert!(names(&detect_yogas(&c, CLASSICAL_YOGA_RULES)).contains(&"Vesi"));
+    }
+
+    #[test]
+    fn raja_yoga_from_conjunct_kendra_and_trikona_lords() {
+        // Mesha lagna: Shani (10/11) with Guru (9/12) in Makara.
+        let c = chart(0, [4, 3, 0, 5, 9, 1, 9, 2, 8]);
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        let raja = find(&found, "Raja");
+        assert!(
+            raja.iter()
+                .any(|y| y.grahas == vec![Graha::Guru, Graha::Shani]
+                    && y.detail.contains("conjunction")),
+            "{raja:?}"
+        );
+    }
+
+    #[test]
+    fn raja_yoga_from_exchange() {
+        // Mesha lagna: Shani (10) in Dhanu, Guru (9) in Makara.
+        let c = chart(0, [4, 3, 0, 5, 9, 1, 8, 2, 8]);
+        assert_eq!(
+            c.sambandha(Graha::Guru, Graha::Shani),
+            Some(Sambandha::Exchange)
+        );
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        assert!(
+            find(&found, "Raja")
+                .iter()
+                .any(|y| y.grahas == vec![Graha::Guru, Graha::Shani])
+        );
+    }
+
+    #[test]
+    fn yogakaraka_for_shani_in_tula_lagna() {
+        let c = chart(6, [0; 9]);
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        let yk = find(&found, "Yogakaraka");
+        assert_eq!(yk.len(), 1);
+        assert_eq!(yk[0].grahas, vec![Graha::Shani]);
+    }
+
+    #[test]
+    fn viparita_raja_for_sixth_lord_in_eighth() {
+        // Mesha lagna: 6th lord Buddh in Vrischika (8th).
+        let c = chart(0, [0, 0, 0, 7, 0, 0, 0, 0, 6]);
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        let harsha = find(&found, "Harsha");
+        assert_eq!(harsha.len(), 1);
+        assert_eq!(harsha[0].grahas, vec![Graha::Buddh]);
+    }
+
+    #[test]
+    fn neecha_bhanga_by_sign_lord_in_kendra() {
+        // Mesha lagna: Guru debilitated in Makara (10th); Shani (Makara lord)
+        // in Tula (7th, kendra).
+        let c = chart(0, [4, 4, 4, 4, 9, 4, 6, 2, 8]);
+        let found = detect_yogas(&c, CLASSICAL_YOGA_RULES);
+        let nb = find(&found, "Neecha Bhanga");
+        assert_eq!(nb.len(), 1);
+        assert_eq!(nb[0].grahas[0], Graha::Guru);
+        assert!(nb[0].grahas.contains(&Graha::Shani));
+    }
+
+    #[test]
+    fn custom_rules_extend_the_table() {
+        fn surya_in_lagna(chart: &YogaChart) -> Vec<YogaHit> {
+            if chart.bhava(Graha::Surya) == 1 {
+                vec![YogaHit {
+                    grahas: vec![Graha::Surya],
+                    detail: "Surya in lagna".to_string(),
+                }]
+            } else {
+                Vec::new()
+            }
+        }
+        let mut rules = CLASSICAL_YOGA_RULES.to_vec();
+        rules.push(YogaRule {
+            name: "House Surya",
+            category: YogaCategory::Custom,
+            rule: "Surya in bhava 1",
+            detect: surya_in_lagna,
+        });
+        let c = chart(0, [0; 9]);
+        let found = detect_yogas(&c, &rules);
+        assert_eq!(found.last().map(|y| y.name), Some("House Surya"));
+    }
+}
@@ -112,7 +112,8 @@ fn assemble_rashi_inputs(
             aya_config.use_nutation,
             aya_config.precession_model,
             aya_config.reference_plane,
-        ),
+        )
+        .with_nutation_model(aya_config.nutation_model),
     )?;
 
     let jd_utc = utc_to_jd_utc(utc);
@@ -159,6 +159,7 @@ impl JyotishContext {
                     aya_config.precession_model,
                     aya_config.reference_plane,
                 )
+                .with_nutation_model(aya_config.nutation_model)
                 .with_outer_planets(false),
             )?;
             self.graha_lons = Some(lons);
@@ -392,17 +393,19 @@ fn outer_planet_longitudes_for_config(
         && config.reference_plane == ReferencePlane::Ecliptic
     {
         let t = jd_tdb_to_centuries(jd_tdb);
-        let (dpsi_arcsec, _deps_arcsec) = dhruv_frames::nutation_iau2000b(t);
+        let (dpsi_arcsec, _deps_arcsec) =
+            dhruv_frames::nutation_with_model(t, config.nutation_model);
         dpsi_arcsec / 3600.0
     } else {
         0.0
     };
     let aya = if config.kind == GrahaLongitudeKind::Sidereal {
-        dhruv_vedic_base::ayanamsha_deg_on_plane(
+        dhruv_vedic_base::ayanamsha_deg_on_plane_with_models(
             config.ayanamsha_system,
             jd_tdb_to_centuries(jd_tdb),
             config.use_nutation,
             config.precession_model,
+            config.nutation_model,
             config.reference_plane,
         )
     } else {
@@ -429,11 +432,12 @@ fn graha_sidereal_longitudes_for_config(
     config: &GrahaLongitudesConfig,
 ) -> Result<GrahaLongitudes, SearchError> {
     let t = jd_tdb_to_centuries(jd_tdb);
-    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane(
+    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane_with_models(
         config.ayanamsha_system,
         t,
         config.use_nutation,
         config.precession_model,
+        config.nutation_model,
         config.reference_plane,
     );
     let rahu_on_plane = lunar_node_deg_for_epoch_on_plane(
@@ -490,7 +494,8 @@ fn graha_reference_plane_longitudes(
 ) -> Result<GrahaLongitudes, SearchError> {
     let dpsi_deg = if config.use_nutation && config.reference_plane == ReferencePlane::Ecliptic {
         let t = jd_tdb_to_centuries(jd_tdb);
-        let (dpsi_arcsec, _deps_arcsec) = dhruv_frames::nutation_iau2000b(t);
+        let (dpsi_arcsec, _deps_arcsec) =
+            dhruv_frames::nutation_with_model(t, config.nutation_model);
         dpsi_arcsec / 3600.0
     } else {
         0.0
@@ -685,6 +690,7 @@ pub fn all_upagrahas_for_date_with_config(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn all_upagrahas_for_date_with_ctx(
     engine: &Engine,
     eop: &EopKernel,
@@ -1947,7 +1953,8 @@ fn assemble_shadbala_inputs(
         aya_config.use_nutation,
         aya_config.precession_model,
         aya_config.reference_plane,
-    );
+    )
+    .with_nutation_model(aya_config.nutation_model);
     let cheshta_motions = dhruv_search::jyotish::cheshta_motion_entries(
         engine,
         ctx.jd_tdb,
@@ -1,7 +1,10 @@
 //! Types for Vedic jyotish orchestration (graha longitudes, etc.).
 
 use crate::panchang_types::PanchangInfo;
-use dhruv_frames::{DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane};
+use dhruv_frames::{
+    DEFAULT_NUTATION_MODEL, DEFAULT_PRECESSION_MODEL, NutationModel, PrecessionModel,
+    ReferencePlane,
+};
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
     AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AshtakavargaResult, AyanamshaSystem,
@@ -60,6 +63,8 @@ pub struct GrahaLongitudesConfig {
     pub ayanamsha_system: AyanamshaSystem,
     pub use_nutation: bool,
     pub precession_model: PrecessionModel,
+    /// Nutation model used when `use_nutation` is set (default IAU 2000B).
+    pub nutation_model: NutationModel,
     pub reference_plane: ReferencePlane,
     pub include_outer_planets: bool,
 }
@@ -85,6 +90,7 @@ impl GrahaLongitudesConfig {
             ayanamsha_system,
             use_nutation,
             precession_model,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane,
             include_outer_planets: true,
         }
@@ -108,6 +114,7 @@ impl GrahaLongitudesConfig {
             ayanamsha_system: AyanamshaSystem::Lahiri,
             use_nutation,
             precession_model,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             reference_plane,
             include_outer_planets: true,
         }
@@ -117,6 +124,12 @@ impl GrahaLongitudesConfig {
         self.include_outer_planets = include_outer_planets;
         self
     }
+
+    /// Select the nutation model applied when `use_nutation` is set.
+    pub fn with_nutation_model(mut self, nutation_model: NutationModel) -> Self {
+        self.nutation_model = nutation_model;
+        self
+    }
 }
 
 impl Default for GrahaLongitudesConfig {
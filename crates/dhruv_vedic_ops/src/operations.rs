@@ -1,7 +1,7 @@
 //! Canonical non-search operation APIs shared across wrappers and frontends.
 
 use dhruv_core::Engine;
-use dhruv_frames::SphericalCoords;
+use dhruv_frames::{DEFAULT_PRECESSION_MODEL, NutationModel, SphericalCoords};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_tara::{
     EarthState, EquatorialPosition, TaraCatalog, TaraConfig, TaraError, TaraId,
@@ -9,7 +9,7 @@ use dhruv_tara::{
 };
 use dhruv_time::{EopKernel, UtcTime};
 use dhruv_vedic_base::{
-    AyanamshaSystem, GeoLocation, LunarNode, NodeMode, RiseSetConfig, ayanamsha_deg,
+    AyanamshaSystem, GeoLocation, LunarNode, NodeMode, RiseSetConfig, ayanamsha_deg_with_models,
     ayanamsha_mean_deg, ayanamsha_true_deg, jd_tdb_to_centuries, lunar_node_deg,
     lunar_node_deg_for_epoch,
 };
@@ -60,6 +60,8 @@ pub struct AyanamshaOperation {
     pub at_jd_tdb: f64,
     /// Nutation inclusion flag used by `Unified` mode.
     pub use_nutation: bool,
+    /// Nutation model used by `Unified` mode when `use_nutation` is set.
+    pub nutation_model: NutationModel,
     /// Delta-psi arcseconds used by `True` mode.
     pub delta_psi_arcsec: f64,
 }
@@ -70,7 +72,13 @@ pub fn ayanamsha(op: &AyanamshaOperation) -> Result<f64, SearchError> {
     let deg = match op.mode {
         AyanamshaMode::Mean => ayanamsha_mean_deg(op.system, t),
         AyanamshaMode::True => ayanamsha_true_deg(op.system, t, op.delta_psi_arcsec),
-        AyanamshaMode::Unified => ayanamsha_deg(op.system, t, op.use_nutation),
+        AyanamshaMode::Unified => ayanamsha_deg_with_models(
+            op.system,
+            t,
+            op.use_nutation,
+            DEFAULT_PRECESSION_MODEL,
+            op.nutation_model,
+        ),
     };
     Ok(deg)
 }
@@ -378,6 +386,7 @@ pub fn tara(catalog: &TaraCatalog, op: &TaraOperation) -> Result<TaraResult, Tar
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dhruv_frames::DEFAULT_NUTATION_MODEL;
 
     #[test]
     fn query_mode_at_date_is_stable() {
@@ -391,6 +400,7 @@ mod tests {
             mode: AyanamshaMode::Mean,
             at_jd_tdb: 2_451_545.0,
             use_nutation: false,
+            nutation_model: DEFAULT_NUTATION_MODEL,
             delta_psi_arcsec: 0.0,
         };
         assert!(ayanamsha(&op).is_ok());
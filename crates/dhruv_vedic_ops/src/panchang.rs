@@ -233,11 +233,11 @@ fn find_chaitra_pratipada_for(
 ) -> Result<UtcTime, SearchError> {
     // Find Mesha Sankranti (Sun entering sidereal 0 deg) near this date
     let search_start = UtcTime::new(utc.year, 1, 15, 0, 0, 0.0);
-    let mesha_sankranti = next_specific_sankranti(engine, &search_start, Rashi::Mesha, config)?
+    let mesha_sankranti = next_specific_sankranti(engine, search_start, Rashi::Mesha, config)?
         .ok_or(SearchError::NoConvergence("could not find Mesha Sankranti"))?;
 
     // Find the next new moon after Mesha Sankranti
-    let nm = next_amavasya(engine, &mesha_sankranti.utc)?.ok_or(SearchError::NoConvergence(
+    let nm = next_amavasya(engine, mesha_sankranti.utc)?.ok_or(SearchError::NoConvergence(
         "could not find new moon after Mesha Sankranti",
     ))?;
 
@@ -318,7 +318,7 @@ pub fn nakshatra_for_date(
     utc: &UtcTime,
     config: &SankrantiConfig,
 ) -> Result<PanchangNakshatraInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let moon_sid = moon_sidereal_longitude_at(engine, jd, config)?;
     nakshatra_at(engine, jd, moon_sid, config)
 }
@@ -389,7 +389,7 @@ fn utc_to_jd_utc(utc: &UtcTime) -> f64 {
 /// Tithi = which of 30 segments of Moon-Sun elongation (12 deg each) the
 /// current moment falls in. Returns tithi with start/end UTC times.
 pub fn tithi_for_date(engine: &Engine, utc: &UtcTime) -> Result<TithiInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let elong = elongation_at(engine, jd)?;
     tithi_at(engine, jd, elong)
 }
@@ -432,7 +432,7 @@ pub fn tithi_at(
 /// Karana = which of 60 segments of Moon-Sun elongation (6 deg each) the
 /// current moment falls in. Uses traditional fixed/movable mapping.
 pub fn karana_for_date(engine: &Engine, utc: &UtcTime) -> Result<KaranaInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let elong = elongation_at(engine, jd)?;
     karana_at(engine, jd, elong)
 }
@@ -476,7 +476,7 @@ pub fn yoga_for_date(
     utc: &UtcTime,
     config: &SankrantiConfig,
 ) -> Result<YogaInfo, SearchError> {
-    let jd = crate::search_util::utc_to_jd_tdb(engine, &utc);
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
     let sum = sidereal_sum_at(engine, jd, config)?;
     yoga_at(engine, jd, sum, config)
 }
@@ -20,6 +20,8 @@ static LSK_FUTURE_WARNED: AtomicBool = AtomicBool::new(false);
 static EOP_PRE_RANGE_WARNED: AtomicBool = AtomicBool::new(false);
 static EOP_FUTURE_WARNED: AtomicBool = AtomicBool::new(false);
 static DELTA_T_MODEL_WARNED: AtomicBool = AtomicBool::new(false);
+static UTC_DRIFT_TABLE_WARNED: AtomicBool = AtomicBool::new(false);
+static CIVIL_TIME_AS_UT1_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// Set crate-level UTC->TDB conversion policy used by date-driven search APIs.
 pub fn set_time_conversion_policy(policy: TimeConversionPolicy) {
@@ -95,6 +97,16 @@ fn emit_warning_once(warning: &TimeWarning) {
                 eprintln!("Warning: {warning}");
             }
         }
+        TimeWarning::UtcDriftTableUsed { .. } => {
+            if !UTC_DRIFT_TABLE_WARNED.swap(true, Ordering::Relaxed) {
+                eprintln!("Warning: {warning}");
+            }
+        }
+        TimeWarning::CivilTimeAsUt1 { .. } => {
+            if !CIVIL_TIME_AS_UT1_WARNED.swap(true, Ordering::Relaxed) {
+                eprintln!("Warning: {warning}");
+            }
+        }
     }
 }
 
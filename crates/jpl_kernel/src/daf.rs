@@ -4,6 +4,7 @@
 //! Implementation is original, written from the public specification.
 
 use crate::error::KernelError;
+use crate::source::ByteSource;
 
 /// Size of every DAF record in bytes.
 const RECORD_BYTES: usize = 1024;
@@ -39,6 +40,8 @@ pub struct FileRecord {
 pub struct DafSummary {
     pub doubles: Vec<f64>,
     pub integers: Vec<i32>,
+    /// Segment name from the name record paired with the summary record.
+    pub name: String,
 }
 
 // ---------------------------------------------------------------------------
@@ -122,6 +125,43 @@ fn summary_size(nd: i32, ni: i32) -> usize {
 pub fn read_summaries(
     data: &[u8],
     file_record: &FileRecord,
+) -> Result<Vec<DafSummary>, KernelError> {
+    walk_summaries(file_record, data.len() as u64, |offset, record| {
+        record.copy_from_slice(&data[offset as usize..offset as usize + RECORD_BYTES]);
+        Ok(())
+    })
+}
+
+/// Read the file record from an on-demand byte source.
+pub fn read_file_record_from(source: &dyn ByteSource) -> Result<FileRecord, KernelError> {
+    if source.size() < RECORD_BYTES as u64 {
+        return Err(KernelError::FileTooSmall {
+            expected: RECORD_BYTES,
+            actual: source.size() as usize,
+        });
+    }
+    let mut record = [0u8; RECORD_BYTES];
+    source.read_exact_at(0, &mut record)?;
+    parse_file_record(&record)
+}
+
+/// Walk the summary-record linked list of an on-demand byte source,
+/// reading only the summary records.
+pub fn read_summaries_from(
+    source: &dyn ByteSource,
+    file_record: &FileRecord,
+) -> Result<Vec<DafSummary>, KernelError> {
+    walk_summaries(file_record, source.size(), |offset, record| {
+        source.read_exact_at(offset, record)
+    })
+}
+
+/// Shared summary walk; `read_record` fills one 1024-byte record at a
+/// byte offset already checked against `size`.
+fn walk_summaries(
+    file_record: &FileRecord,
+    size: u64,
+    mut read_record: impl FnMut(u64, &mut [u8; RECORD_BYTES]) -> Result<(), KernelError>,
 ) -> Result<Vec<DafSummary>, KernelError> {
     let nd = file_record.nd as usize;
     let ni = file_record.ni as usize;
@@ -130,22 +170,33 @@ pub fn read_summaries(
 
     let mut summaries = Vec::new();
     let mut record_num = file_record.fward as usize;
+    let mut record = [0u8; RECORD_BYTES];
+    let mut names = [0u8; RECORD_BYTES];
 
     while record_num != 0 {
-        let rec_offset = (record_num - 1) * RECORD_BYTES;
-        if rec_offset + RECORD_BYTES > data.len() {
+        let rec_offset = ((record_num - 1) * RECORD_BYTES) as u64;
+        if rec_offset + RECORD_BYTES as u64 > size {
             return Err(KernelError::BadSummaryRecord(format!(
                 "summary record {record_num} extends past end of file"
             )));
         }
+        read_record(rec_offset, &mut record)?;
+
+        // The name record immediately follows its summary record. Tolerate
+        // a truncated trailing name record by leaving names empty.
+        let names_offset = rec_offset + RECORD_BYTES as u64;
+        let have_names = names_offset + RECORD_BYTES as u64 <= size;
+        if have_names {
+            read_record(names_offset, &mut names)?;
+        }
 
-        let next = read_f64(data, rec_offset, endian);
-        let nsum = read_f64(data, rec_offset + 16, endian) as usize;
+        let next = read_f64(&record, 0, endian);
+        let nsum = read_f64(&record, 16, endian) as usize;
 
         // Summaries start at double index 3 within the record (byte offset 24).
         for i in 0..nsum {
-            let sum_offset = rec_offset + 24 + i * ss * 8;
-            if sum_offset + ss * 8 > rec_offset + RECORD_BYTES {
+            let sum_offset = 24 + i * ss * 8;
+            if sum_offset + ss * 8 > RECORD_BYTES {
                 return Err(KernelError::BadSummaryRecord(format!(
                     "summary {i} in record {record_num} overflows record boundary"
                 )));
@@ -154,17 +205,31 @@ pub fn read_summaries(
             // Read ND doubles.
             let mut doubles = Vec::with_capacity(nd);
             for d in 0..nd {
-                doubles.push(read_f64(data, sum_offset + d * 8, endian));
+                doubles.push(read_f64(&record, sum_offset + d * 8, endian));
             }
 
             // Read NI integers — packed into the bytes following the doubles.
             let int_base = sum_offset + nd * 8;
             let mut integers = Vec::with_capacity(ni);
             for j in 0..ni {
-                integers.push(read_i32(data, int_base + j * 4, endian));
+                integers.push(read_i32(&record, int_base + j * 4, endian));
             }
 
-            summaries.push(DafSummary { doubles, integers });
+            let name_len = ss * 8;
+            let name = if have_names {
+                let bytes = &names[i * name_len..(i + 1) * name_len];
+                String::from_utf8_lossy(bytes)
+                    .trim_end_matches([' ', '\0'])
+                    .to_string()
+            } else {
+                String::new()
+            };
+
+            summaries.push(DafSummary {
+                doubles,
+                integers,
+                name,
+            });
         }
 
         // Follow linked list. NEXT == 0.0 means end.
@@ -174,6 +239,60 @@ pub fn read_summaries(
     Ok(summaries)
 }
 
+// ---------------------------------------------------------------------------
+// Comment area
+// ---------------------------------------------------------------------------
+
+/// Characters of comment text stored per comment record.
+const COMMENT_CHARS: usize = 1000;
+
+/// Read the comment area (records 2 through FWARD - 1) as text.
+///
+/// Lines are NUL-terminated in the file and the text ends at an EOT byte;
+/// both are decoded here, so the result uses `\n` line endings.
+pub fn read_comments(data: &[u8], file_record: &FileRecord) -> Result<String, KernelError> {
+    collect_comments(file_record, data.len() as u64, |offset, record| {
+        record.copy_from_slice(&data[offset as usize..offset as usize + RECORD_BYTES]);
+        Ok(())
+    })
+}
+
+/// Read the comment area from an on-demand byte source.
+pub fn read_comments_from(
+    source: &dyn ByteSource,
+    file_record: &FileRecord,
+) -> Result<String, KernelError> {
+    collect_comments(file_record, source.size(), |offset, record| {
+        source.read_exact_at(offset, record)
+    })
+}
+
+fn collect_comments(
+    file_record: &FileRecord,
+    size: u64,
+    mut read_record: impl FnMut(u64, &mut [u8; RECORD_BYTES]) -> Result<(), KernelError>,
+) -> Result<String, KernelError> {
+    let mut text = Vec::new();
+    let mut record = [0u8; RECORD_BYTES];
+    'records: for record_num in 2..file_record.fward.max(2) as usize {
+        let offset = ((record_num - 1) * RECORD_BYTES) as u64;
+        if offset + RECORD_BYTES as u64 > size {
+            return Err(KernelError::BadSummaryRecord(format!(
+                "comment record {record_num} extends past end of file"
+            )));
+        }
+        read_record(offset, &mut record)?;
+        for &byte in &record[..COMMENT_CHARS] {
+            match byte {
+                0x04 => break 'records,
+                0x00 => text.push(b'\n'),
+                b => text.push(b),
+            }
+        }
+    }
+    Ok(String::from_utf8_lossy(&text).into_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +312,30 @@ mod tests {
         assert!(matches!(result, Err(KernelError::BadEndianness(_))));
     }
 
+    #[test]
+    fn comments_decode_nul_lines_and_stop_at_eot() {
+        let mut data = vec![0u8; 3 * RECORD_BYTES];
+        data[0..8].copy_from_slice(b"DAF/SPK ");
+        data[88..96].copy_from_slice(LTL_IEEE);
+        data[76..80].copy_from_slice(&3i32.to_le_bytes()); // FWARD
+        let body = b"DE TEST\0line two\0";
+        let second = RECORD_BYTES;
+        data[second..second + COMMENT_CHARS].fill(b' ');
+        data[second..second + body.len()].copy_from_slice(body);
+        // Bytes past the 1000-character area are not comment text.
+        data[second + COMMENT_CHARS] = b'X';
+        let record = parse_file_record(&data).unwrap();
+        let mut with_eot = data.clone();
+        with_eot[second + body.len()] = 0x04;
+        assert_eq!(
+            read_comments(&with_eot, &record).unwrap(),
+            "DE TEST\nline two\n"
+        );
+        let full = read_comments(&data, &record).unwrap();
+        assert_eq!(full.len(), COMMENT_CHARS);
+        assert!(!full.contains('X'));
+    }
+
     #[test]
     fn bad_file_id_rejected() {
         let mut data = vec![0u8; RECORD_BYTES];
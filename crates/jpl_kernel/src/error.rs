@@ -27,6 +27,10 @@ pub enum KernelError {
     },
     /// A segment's internal metadata is inconsistent.
     BadSegmentData(String),
+    /// A frame needed for an orientation transform is not supported.
+    UnsupportedFrame(i32),
+    /// A text kernel could not be parsed, or a variable has the wrong shape.
+    BadTextKernel(String),
     /// I/O error message (we store the string, not the io::Error, to keep Clone + PartialEq).
     Io(String),
 }
@@ -55,6 +59,8 @@ impl Display for KernelError {
                 )
             }
             Self::BadSegmentData(msg) => write!(f, "bad segment data: {msg}"),
+            Self::UnsupportedFrame(id) => write!(f, "unsupported reference frame: {id}"),
+            Self::BadTextKernel(msg) => write!(f, "bad text kernel: {msg}"),
             Self::Io(msg) => write!(f, "I/O error: {msg}"),
         }
     }
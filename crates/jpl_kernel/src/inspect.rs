@@ -0,0 +1,137 @@
+//! Kernel metadata inspection: coverage windows and gaps.
+//!
+//! Coverage is reported per (target, center) pair by merging the time
+//! ranges of all segments for that pair. Segments that touch or overlap
+//! form one window; any space between windows is a gap.
+
+use std::collections::BTreeMap;
+
+use crate::spk::SpkSegment;
+
+/// A closed interval of TDB seconds past J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoverageWindow {
+    pub start_epoch: f64,
+    pub end_epoch: f64,
+}
+
+/// Merged coverage of one (target, center) pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    pub target: i32,
+    pub center: i32,
+    /// Number of segments contributing to this pair.
+    pub segment_count: usize,
+    /// Disjoint covered intervals, in time order.
+    pub windows: Vec<CoverageWindow>,
+    /// Uncovered intervals between consecutive windows.
+    pub gaps: Vec<CoverageWindow>,
+}
+
+impl Coverage {
+    /// Whether the pair is covered without interruption.
+    pub fn is_contiguous(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+/// Coverage report for a set of segments, sorted by (target, center).
+pub fn coverage_report(segments: &[SpkSegment]) -> Vec<Coverage> {
+    let mut by_pair: BTreeMap<(i32, i32), Vec<CoverageWindow>> = BTreeMap::new();
+    for seg in segments {
+        by_pair
+            .entry((seg.target, seg.center))
+            .or_default()
+            .push(CoverageWindow {
+                start_epoch: seg.start_epoch,
+                end_epoch: seg.end_epoch,
+            });
+    }
+
+    by_pair
+        .into_iter()
+        .map(|((target, center), mut ranges)| {
+            let segment_count = ranges.len();
+            ranges.sort_by(|a, b| a.start_epoch.total_cmp(&b.start_epoch));
+            let mut windows: Vec<CoverageWindow> = Vec::with_capacity(ranges.len());
+            for range in ranges {
+                match windows.last_mut() {
+                    Some(last) if range.start_epoch <= last.end_epoch => {
+                        last.end_epoch = last.end_epoch.max(range.end_epoch);
+                    }
+                    _ => windows.push(range),
+                }
+            }
+            let gaps = windows
+                .windows(2)
+                .map(|pair| CoverageWindow {
+                    start_epoch: pair[0].end_epoch,
+                    end_epoch: pair[1].start_epoch,
+                })
+                .collect();
+            Coverage {
+                target,
+                center,
+                segment_count,
+                windows,
+                gaps,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(target: i32, start_epoch: f64, end_epoch: f64) -> SpkSegment {
+        SpkSegment {
+            start_epoch,
+            end_epoch,
+            target,
+            center: 0,
+            frame: 1,
+            data_type: 2,
+            start_addr: 1,
+            end_addr: 2,
+            name: String::new(),
+        }
+    }
+
+    #[test]
+    fn merges_touching_and_overlapping_segments() {
+        let report = coverage_report(&[
+            segment(5, 100.0, 200.0),
+            segment(5, 0.0, 100.0),
+            segment(5, 150.0, 250.0),
+            segment(5, 400.0, 500.0),
+            segment(3, 0.0, 10.0),
+        ]);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].target, 3);
+        assert!(report[0].is_contiguous());
+
+        let jupiter = &report[1];
+        assert_eq!(jupiter.segment_count, 4);
+        assert_eq!(
+            jupiter.windows,
+            vec![
+                CoverageWindow {
+                    start_epoch: 0.0,
+                    end_epoch: 250.0
+                },
+                CoverageWindow {
+                    start_epoch: 400.0,
+                    end_epoch: 500.0
+                },
+            ]
+        );
+        assert_eq!(
+            jupiter.gaps,
+            vec![CoverageWindow {
+                start_epoch: 250.0,
+                end_epoch: 400.0
+            }]
+        );
+    }
+}
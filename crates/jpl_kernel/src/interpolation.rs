@@ -0,0 +1,131 @@
+//! Lagrange and Hermite polynomial interpolation over discrete samples.
+//!
+//! Used by the discrete-state SPK segment types (8, 9, 13). Algorithms
+//! from the standard Neville and Newton divided-difference formulations.
+//! Implementation is original.
+
+/// Maximum number of samples supported by the stack-allocated tables.
+///
+/// SPK Types 8/9 allow at most 28 states per window and Type 13 at most
+/// 14, so 32 covers every valid segment without heap allocation.
+pub const MAX_SAMPLES: usize = 32;
+
+/// Evaluate the Lagrange interpolating polynomial through `(xs[i], ys[i])`
+/// at `x` using Neville's algorithm.
+///
+/// `xs` and `ys` must have the same length, at most [`MAX_SAMPLES`].
+/// Returns 0.0 for empty input.
+pub fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let n = xs.len().min(ys.len()).min(MAX_SAMPLES);
+    if n == 0 {
+        return 0.0;
+    }
+
+    let mut p = [0.0f64; MAX_SAMPLES];
+    p[..n].copy_from_slice(&ys[..n]);
+
+    // After pass `j`, p[i] holds the interpolant through xs[i..=i+j].
+    for j in 1..n {
+        for i in 0..n - j {
+            let denom = xs[i] - xs[i + j];
+            p[i] = ((x - xs[i + j]) * p[i] + (xs[i] - x) * p[i + 1]) / denom;
+        }
+    }
+
+    p[0]
+}
+
+/// Evaluate the Hermite interpolating polynomial matching values `ys` and
+/// first derivatives `dys` at nodes `xs`, returning `(value, derivative)`
+/// at `x`.
+///
+/// Builds the Newton divided-difference table over doubled nodes, then
+/// evaluates the Newton form and its derivative with a Horner-style pass.
+/// All slices must have the same length, at most `MAX_SAMPLES / 2`.
+pub fn hermite(xs: &[f64], ys: &[f64], dys: &[f64], x: f64) -> (f64, f64) {
+    let n = xs.len().min(ys.len()).min(dys.len()).min(MAX_SAMPLES / 2);
+    if n == 0 {
+        return (0.0, 0.0);
+    }
+
+    let m = 2 * n;
+    let mut z = [0.0f64; MAX_SAMPLES];
+    let mut c = [0.0f64; MAX_SAMPLES];
+    for i in 0..n {
+        z[2 * i] = xs[i];
+        z[2 * i + 1] = xs[i];
+        c[2 * i] = ys[i];
+        c[2 * i + 1] = ys[i];
+    }
+
+    // In-place divided differences, bottom-up so c[k - 1] still holds
+    // the previous column when c[k] is updated.
+    for j in 1..m {
+        for k in (j..m).rev() {
+            if j == 1 && k % 2 == 1 {
+                // Repeated node: first divided difference is the derivative.
+                c[k] = dys[k / 2];
+            } else {
+                c[k] = (c[k] - c[k - 1]) / (z[k] - z[k - j]);
+            }
+        }
+    }
+
+    let mut value = c[m - 1];
+    let mut derivative = 0.0;
+    for k in (0..m - 1).rev() {
+        let dx = x - z[k];
+        derivative = derivative * dx + value;
+        value = value * dx + c[k];
+    }
+
+    (value, derivative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-12;
+
+    #[test]
+    fn lagrange_reproduces_cubic() {
+        // Four nodes reproduce any cubic exactly.
+        let f = |x: f64| 2.0 * x * x * x - 3.0 * x * x + x - 7.0;
+        let xs = [-1.0, 0.5, 2.0, 3.5];
+        let ys = xs.map(f);
+        for x in [-0.7, 0.0, 1.3, 3.0] {
+            assert!((lagrange(&xs, &ys, x) - f(x)).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn lagrange_passes_through_nodes() {
+        let xs = [0.0, 1.0, 4.0];
+        let ys = [5.0, -2.0, 9.0];
+        for (x, y) in xs.iter().zip(ys) {
+            assert!((lagrange(&xs, &ys, *x) - y).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn hermite_reproduces_quintic_and_derivative() {
+        // Three nodes with derivatives determine a quintic.
+        let f = |x: f64| x.powi(5) - 2.0 * x.powi(3) + 0.5 * x;
+        let df = |x: f64| 5.0 * x.powi(4) - 6.0 * x * x + 0.5;
+        let xs = [-1.0, 0.25, 1.5];
+        let ys = xs.map(f);
+        let dys = xs.map(df);
+        for x in [-0.8, 0.0, 0.9, 1.4] {
+            let (v, d) = hermite(&xs, &ys, &dys, x);
+            assert!((v - f(x)).abs() < 1e-10, "value at {x}: {v} vs {}", f(x));
+            assert!((d - df(x)).abs() < 1e-10, "deriv at {x}: {d} vs {}", df(x));
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(lagrange(&[], &[], 1.0), 0.0);
+        assert_eq!(hermite(&[], &[], &[], 1.0), (0.0, 0.0));
+    }
+}
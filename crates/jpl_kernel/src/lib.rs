@@ -1,8 +1,20 @@
 //! JPL kernel parsing and interpolation primitives.
 //!
 //! This crate reads NAIF DAF/SPK binary kernel files and evaluates
-//! Chebyshev polynomial segments to produce position and velocity
-//! state vectors.
+//! segments to produce position and velocity state vectors. Supported
+//! segment data types: 1 and 21 (modified difference arrays), 2 and 3
+//! (Chebyshev), 8 and 9 (Lagrange) and 13 (Hermite).
+//!
+//! Kernels are read through a [`ByteSource`]: either fully in memory
+//! ([`SpkKernel::load`]) or on demand from a file handle or custom reader
+//! ([`SpkKernel::open`], [`SpkKernel::from_source`]).
+//!
+//! [`SpkWriter`] writes new kernels: subsets of existing ones clipped to a
+//! time window, and Type 2/3 segments fitted to a state function.
+//!
+//! Binary PCK kernels ([`PckKernel`]) provide body orientation as Euler
+//! angles, and text kernels ([`TextKernel`]) provide body constants such as
+//! radii and GM values plus fixed-offset frame definitions.
 //!
 //! Reference: NAIF DAF/SPK Required Reading documents (public domain,
 //! US Government work product). Implementation is original, written
@@ -11,12 +23,26 @@
 pub mod chebyshev;
 pub mod daf;
 pub mod error;
+pub mod inspect;
+pub mod interpolation;
+pub mod pck;
+pub mod source;
 pub mod spk;
+pub mod text_kernel;
+pub mod writer;
 
 use std::path::Path;
+use std::sync::Arc;
 
 pub use error::KernelError;
-pub use spk::{SpkEvaluation, SpkSegment};
+pub use inspect::{Coverage, CoverageWindow};
+pub use pck::{BodyOrientation, FrameRotation, PckEvaluation, PckKernel, PckSegment};
+pub use source::{ByteSource, FileSource, ReaderSource};
+pub use spk::{ChebyshevRecordData, SpkEvaluation, SpkSegment};
+pub use text_kernel::{TextKernel, TextValue, TkFrame};
+pub use writer::{ChebyshevFit, SegmentData, SpkWriter, fit_chebyshev_segment};
+
+use spk::KernelBytes;
 
 /// Map a planet body code (x99) to its parent barycenter (x).
 ///
@@ -37,8 +63,8 @@ pub fn planet_body_to_barycenter(code: i32) -> i32 {
 /// A loaded SPK kernel, ready for evaluation.
 #[derive(Debug, Clone)]
 pub struct SpkKernel {
-    data: Vec<u8>,
-    endianness: daf::Endianness,
+    source: Arc<dyn ByteSource>,
+    file_record: daf::FileRecord,
     segments: Vec<SpkSegment>,
 }
 
@@ -54,7 +80,21 @@ impl SpkKernel {
 
     /// Load an SPK kernel from raw bytes (useful for testing).
     pub fn from_bytes(data: Vec<u8>) -> Result<Self, KernelError> {
-        let file_record = daf::parse_file_record(&data)?;
+        Self::from_source(Arc::new(data))
+    }
+
+    /// Open an SPK kernel for on-demand reading.
+    ///
+    /// Only the file record and summary records are read up front; segment
+    /// data is fetched from the file as queries need it, so opening a
+    /// multi-gigabyte kernel is cheap.
+    pub fn open(path: &Path) -> Result<Self, KernelError> {
+        Self::from_source(Arc::new(FileSource::open(path)?))
+    }
+
+    /// Load an SPK kernel from any byte source.
+    pub fn from_source(source: Arc<dyn ByteSource>) -> Result<Self, KernelError> {
+        let file_record = daf::read_file_record_from(&*source)?;
 
         if file_record.nd != 2 || file_record.ni != 6 {
             return Err(KernelError::BadFileId(format!(
@@ -63,35 +103,67 @@ impl SpkKernel {
             )));
         }
 
-        let summaries = daf::read_summaries(&data, &file_record)?;
+        let summaries = daf::read_summaries_from(&*source, &file_record)?;
         let mut segments = Vec::with_capacity(summaries.len());
         for summary in &summaries {
             segments.push(spk::segment_from_summary(summary)?);
         }
 
         Ok(Self {
-            data,
-            endianness: file_record.endianness,
+            source,
+            file_record,
             segments,
         })
     }
 
+    /// Whether the whole kernel is resident in memory.
+    pub fn is_resident(&self) -> bool {
+        self.source.as_slice().is_some()
+    }
+
+    fn bytes(&self) -> KernelBytes<'_> {
+        KernelBytes::new(&*self.source)
+    }
+
+    /// DAF file ID word, e.g. `DAF/SPK`.
+    pub fn file_id(&self) -> &str {
+        &self.file_record.file_id
+    }
+
+    /// Internal file name from the DAF file record.
+    pub fn internal_name(&self) -> &str {
+        &self.file_record.internal_name
+    }
+
+    /// Text of the DAF comment area (empty if the kernel has none).
+    ///
+    /// Read from the source on each call; on-demand kernels do not keep
+    /// the comments in memory.
+    pub fn comments(&self) -> Result<String, KernelError> {
+        daf::read_comments_from(&*self.source, &self.file_record)
+    }
+
     /// All segments in this kernel.
     pub fn segments(&self) -> &[SpkSegment] {
         &self.segments
     }
 
-    /// Find the first segment matching `target` whose center field matches,
-    /// and whose time range covers `epoch_tdb_s`.
-    fn find_segment(
+    /// Coverage windows and gaps for every (target, center) pair.
+    pub fn coverage(&self) -> Vec<Coverage> {
+        inspect::coverage_report(&self.segments)
+    }
+
+    /// Index of the first segment matching `target` and `center` whose time
+    /// range covers `epoch_tdb_s`.
+    pub fn find_segment_index(
         &self,
         target: i32,
         center: i32,
         epoch_tdb_s: f64,
-    ) -> Result<&SpkSegment, KernelError> {
+    ) -> Result<usize, KernelError> {
         self.segments
             .iter()
-            .find(|seg| {
+            .position(|seg| {
                 seg.target == target
                     && seg.center == center
                     && epoch_tdb_s >= seg.start_epoch
@@ -104,6 +176,12 @@ impl SpkKernel {
             })
     }
 
+    fn segment(&self, segment_index: usize) -> Result<&SpkSegment, KernelError> {
+        self.segments.get(segment_index).ok_or_else(|| {
+            KernelError::BadSegmentData(format!("segment index {segment_index} out of range"))
+        })
+    }
+
     /// Evaluate a segment for the given (target, center) pair at the epoch.
     ///
     /// `epoch_tdb_s` is TDB seconds past J2000.0.
@@ -116,12 +194,94 @@ impl SpkKernel {
         center: i32,
         epoch_tdb_s: f64,
     ) -> Result<SpkEvaluation, KernelError> {
-        let segment = self.find_segment(target, center, epoch_tdb_s)?;
+        let index = self.find_segment_index(target, center, epoch_tdb_s)?;
+        self.evaluate_segment(index, epoch_tdb_s)
+    }
+
+    /// Evaluate segment `segment_index` at the epoch.
+    pub fn evaluate_segment(
+        &self,
+        segment_index: usize,
+        epoch_tdb_s: f64,
+    ) -> Result<SpkEvaluation, KernelError> {
+        let segment = self.segment(segment_index)?;
+        spk::evaluate_segment_in(
+            self.bytes(),
+            segment,
+            epoch_tdb_s,
+            self.file_record.endianness,
+        )
+    }
 
-        match segment.data_type {
-            2 => spk::evaluate_type2(&self.data, segment, epoch_tdb_s, self.endianness),
-            other => Err(KernelError::UnsupportedDataType(other)),
+    /// Index of the Chebyshev record of segment `segment_index` covering
+    /// the epoch, or `None` if the segment is not Type 2 or 3.
+    pub fn chebyshev_record_index(
+        &self,
+        segment_index: usize,
+        epoch_tdb_s: f64,
+    ) -> Result<Option<usize>, KernelError> {
+        let segment = self.segment(segment_index)?;
+        spk::chebyshev_record_index_in(
+            self.bytes(),
+            segment,
+            epoch_tdb_s,
+            self.file_record.endianness,
+        )
+    }
+
+    /// Decode Chebyshev record `record_index` of segment `segment_index`.
+    pub fn read_chebyshev_record(
+        &self,
+        segment_index: usize,
+        record_index: usize,
+    ) -> Result<ChebyshevRecordData, KernelError> {
+        let segment = self.segment(segment_index)?;
+        spk::read_chebyshev_record_in(
+            self.bytes(),
+            segment,
+            record_index,
+            self.file_record.endianness,
+        )
+    }
+
+    /// Copy segment `segment_index` clipped to `[start_tdb_s, end_tdb_s]`,
+    /// ready for [`SpkWriter::add_segment`].
+    ///
+    /// Every supported type keeps only the records or states needed to
+    /// evaluate inside the window, so the copy evaluates identically there.
+    pub fn segment_subset(
+        &self,
+        segment_index: usize,
+        start_tdb_s: f64,
+        end_tdb_s: f64,
+    ) -> Result<SegmentData, KernelError> {
+        let segment = self.segment(segment_index)?;
+        let start_epoch = segment.start_epoch.max(start_tdb_s);
+        let end_epoch = segment.end_epoch.min(end_tdb_s);
+        if start_epoch > end_epoch {
+            return Err(KernelError::EpochOutOfRange {
+                target: segment.target,
+                center: segment.center,
+                epoch_tdb_s: start_tdb_s,
+            });
         }
+        let words = spk::subset_words_in(
+            self.bytes(),
+            segment,
+            start_epoch,
+            end_epoch,
+            self.file_record.endianness,
+        )?;
+        Ok(SegmentData {
+            target: segment.target,
+            center: segment.center,
+            frame: segment.frame,
+            data_type: segment.data_type,
+            start_epoch,
+            end_epoch,
+            name: segment.name.clone(),
+            words,
+        })
     }
 
     /// Look up the center body for a given target by inspecting segments.
@@ -0,0 +1,551 @@
+//! Binary PCK (Planetary Constants Kernel) segments and body orientation.
+//!
+//! Binary PCKs are DAF files with ND=2, NI=5 whose segments hold Euler
+//! angles (φ, δ, w) of a body-fixed frame relative to an inertial reference
+//! frame. Types 2 and 3 share the SPK Type 2/3 Chebyshev record layout, so
+//! evaluation reuses the SPK readers with angles in place of positions.
+//!
+//! The rotation from the reference frame to the body-fixed frame is
+//! `R = [w]₃ [δ]₁ [φ]₃`, where `[θ]ᵢ` rotates the coordinate axes by θ
+//! about axis i.
+//!
+//! Reference: NAIF PCK and DAF Required Reading (public domain, US
+//! Government work product). Implementation is original.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::daf::{self, DafSummary, FileRecord};
+use crate::error::KernelError;
+use crate::source::{ByteSource, FileSource};
+use crate::spk::{self, KernelBytes, SpkSegment};
+
+/// NAIF frame ID of the J2000 (ICRF-aligned) inertial frame.
+pub const FRAME_J2000: i32 = 1;
+
+/// NAIF frame ID of the mean ecliptic and equinox of J2000.
+pub const FRAME_ECLIPJ2000: i32 = 17;
+
+/// Obliquity relating J2000 and ECLIPJ2000 (IAU 1976, 84381.448″), radians.
+const ECLIPJ2000_OBLIQUITY_RAD: f64 = 84381.448 / 3600.0 * std::f64::consts::PI / 180.0;
+
+/// Metadata for a single binary PCK segment, extracted from a DAF summary.
+#[derive(Debug, Clone)]
+pub struct PckSegment {
+    pub start_epoch: f64,
+    pub end_epoch: f64,
+    /// NAIF ID of the body-fixed frame (e.g. 3000 ITRF93, 31006 MOON_PA).
+    pub body_frame: i32,
+    /// NAIF ID of the inertial frame the angles are referred to.
+    pub reference_frame: i32,
+    pub data_type: i32,
+    /// First word address (1-based, 8 bytes per word).
+    pub start_addr: i32,
+    /// Last word address (1-based, 8 bytes per word).
+    pub end_addr: i32,
+    /// Segment name from the DAF name record (may be empty).
+    pub name: String,
+}
+
+impl PckSegment {
+    /// The segment viewed through the SPK Chebyshev reader.
+    fn as_spk(&self) -> SpkSegment {
+        SpkSegment {
+            start_epoch: self.start_epoch,
+            end_epoch: self.end_epoch,
+            target: self.body_frame,
+            center: self.reference_frame,
+            frame: self.reference_frame,
+            data_type: self.data_type,
+            start_addr: self.start_addr,
+            end_addr: self.end_addr,
+            name: String::new(),
+        }
+    }
+}
+
+/// Convert a DAF summary (with ND=2, NI=5) into a PCK segment descriptor.
+pub fn pck_segment_from_summary(summary: &DafSummary) -> Result<PckSegment, KernelError> {
+    if summary.doubles.len() < 2 || summary.integers.len() < 5 {
+        return Err(KernelError::BadSummaryRecord(
+            "PCK summary requires ND>=2, NI>=5".into(),
+        ));
+    }
+
+    Ok(PckSegment {
+        start_epoch: summary.doubles[0],
+        end_epoch: summary.doubles[1],
+        body_frame: summary.integers[0],
+        reference_frame: summary.integers[1],
+        data_type: summary.integers[2],
+        start_addr: summary.integers[3],
+        end_addr: summary.integers[4],
+        name: summary.name.clone(),
+    })
+}
+
+/// Euler angles of a body-fixed frame and their rates at one epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PckEvaluation {
+    /// (φ, δ, w) in radians.
+    pub angles_rad: [f64; 3],
+    /// (dφ/dt, dδ/dt, dw/dt) in radians per second.
+    pub rates_rad_s: [f64; 3],
+}
+
+impl PckEvaluation {
+    /// Rotation from the segment's reference frame to the body-fixed frame.
+    pub fn rotation(&self) -> [[f64; 3]; 3] {
+        let [phi, delta, w] = self.angles_rad;
+        mat_mul(
+            &mat_mul(&axis_rotation(3, w), &axis_rotation(1, delta)),
+            &axis_rotation(3, phi),
+        )
+    }
+
+    /// Time derivative of [`Self::rotation`], per second.
+    pub fn rotation_rate(&self) -> [[f64; 3]; 3] {
+        let [phi, delta, w] = self.angles_rad;
+        let [dphi, ddelta, dw] = self.rates_rad_s;
+        let (rw, rd, rp) = (
+            axis_rotation(3, w),
+            axis_rotation(1, delta),
+            axis_rotation(3, phi),
+        );
+        let (dw_m, dd_m, dp_m) = (
+            scale(&axis_rotation_derivative(3, w), dw),
+            scale(&axis_rotation_derivative(1, delta), ddelta),
+            scale(&axis_rotation_derivative(3, phi), dphi),
+        );
+        add(
+            &add(
+                &mat_mul(&mat_mul(&dw_m, &rd), &rp),
+                &mat_mul(&mat_mul(&rw, &dd_m), &rp),
+            ),
+            &mat_mul(&mat_mul(&rw, &rd), &dp_m),
+        )
+    }
+
+    /// The evaluation as a [`FrameRotation`] from its reference frame.
+    pub fn frame_rotation(&self) -> FrameRotation {
+        FrameRotation {
+            matrix: self.rotation(),
+            rate: self.rotation_rate(),
+        }
+    }
+}
+
+/// A time-dependent rotation and its derivative (row-major, per second).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameRotation {
+    pub matrix: [[f64; 3]; 3],
+    pub rate: [[f64; 3]; 3],
+}
+
+impl FrameRotation {
+    /// Rotate a position vector.
+    pub fn apply(&self, v: &[f64; 3]) -> [f64; 3] {
+        mat_vec(&self.matrix, v)
+    }
+
+    /// Rotate a state: `r' = R·r`, `v' = R·v + (dR/dt)·r`.
+    pub fn apply_state(
+        &self,
+        position_km: &[f64; 3],
+        velocity_km_s: &[f64; 3],
+    ) -> ([f64; 3], [f64; 3]) {
+        let position = mat_vec(&self.matrix, position_km);
+        let rotated = mat_vec(&self.matrix, velocity_km_s);
+        let drift = mat_vec(&self.rate, position_km);
+        (
+            position,
+            [
+                rotated[0] + drift[0],
+                rotated[1] + drift[1],
+                rotated[2] + drift[2],
+            ],
+        )
+    }
+
+    /// Compose with an earlier rotation: `self ∘ first`.
+    pub fn after(&self, first: &FrameRotation) -> FrameRotation {
+        FrameRotation {
+            matrix: mat_mul(&self.matrix, &first.matrix),
+            rate: add(
+                &mat_mul(&self.rate, &first.matrix),
+                &mat_mul(&self.matrix, &first.rate),
+            ),
+        }
+    }
+
+    /// A constant rotation (zero rate), e.g. a TK frame offset.
+    pub fn fixed(matrix: [[f64; 3]; 3]) -> FrameRotation {
+        FrameRotation {
+            matrix,
+            rate: [[0.0; 3]; 3],
+        }
+    }
+
+    /// Pole and prime meridian of the target frame, for a rotation from
+    /// J2000: the target +Z axis is the pole, and W is measured from the
+    /// ascending node of the body equator on the J2000 equator.
+    pub fn orientation(&self) -> BodyOrientation {
+        let m = &self.matrix;
+        BodyOrientation {
+            pole_ra_rad: m[2][1].atan2(m[2][0]),
+            pole_dec_rad: m[2][2].clamp(-1.0, 1.0).asin(),
+            prime_meridian_rad: m[0][2].atan2(m[1][2]).rem_euclid(std::f64::consts::TAU),
+        }
+    }
+
+    /// Planetocentric east longitude in (−π, π] and latitude, in radians,
+    /// of the direction `v` expressed in the target frame.
+    pub fn lon_lat(&self, v: &[f64; 3]) -> [f64; 2] {
+        let [x, y, z] = mat_vec(&self.matrix, v);
+        [y.atan2(x), z.atan2(x.hypot(y))]
+    }
+}
+
+/// IAU-style orientation of a body-fixed frame relative to J2000.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyOrientation {
+    /// Right ascension of the body's north pole, radians.
+    pub pole_ra_rad: f64,
+    /// Declination of the body's north pole, radians.
+    pub pole_dec_rad: f64,
+    /// Prime meridian angle W in [0, 2π), radians.
+    pub prime_meridian_rad: f64,
+}
+
+/// Constant rotation from J2000 to `frame`, for the inertial frames PCK
+/// segments are commonly referred to.
+pub fn inertial_rotation_from_j2000(frame: i32) -> Result<[[f64; 3]; 3], KernelError> {
+    match frame {
+        FRAME_J2000 => Ok(identity()),
+        FRAME_ECLIPJ2000 => Ok(axis_rotation(1, ECLIPJ2000_OBLIQUITY_RAD)),
+        other => Err(KernelError::UnsupportedFrame(other)),
+    }
+}
+
+/// A loaded binary PCK kernel.
+#[derive(Debug, Clone)]
+pub struct PckKernel {
+    source: Arc<dyn ByteSource>,
+    file_record: FileRecord,
+    segments: Vec<PckSegment>,
+}
+
+impl PckKernel {
+    /// Load a binary PCK from a file path, reading it fully into memory.
+    pub fn load(path: &Path) -> Result<Self, KernelError> {
+        let data = std::fs::read(path)?;
+        Self::from_bytes(data)
+    }
+
+    /// Load a binary PCK from raw bytes.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, KernelError> {
+        Self::from_source(Arc::new(data))
+    }
+
+    /// Open a binary PCK for on-demand reading.
+    pub fn open(path: &Path) -> Result<Self, KernelError> {
+        Self::from_source(Arc::new(FileSource::open(path)?))
+    }
+
+    /// Load a binary PCK from any byte source.
+    pub fn from_source(source: Arc<dyn ByteSource>) -> Result<Self, KernelError> {
+        let file_record = daf::read_file_record_from(&*source)?;
+
+        if file_record.nd != 2 || file_record.ni != 5 {
+            return Err(KernelError::BadFileId(format!(
+                "expected binary PCK (ND=2, NI=5), got ND={}, NI={}",
+                file_record.nd, file_record.ni
+            )));
+        }
+
+        let summaries = daf::read_summaries_from(&*source, &file_record)?;
+        let mut segments = Vec::with_capacity(summaries.len());
+        for summary in &summaries {
+            segments.push(pck_segment_from_summary(summary)?);
+        }
+
+        Ok(Self {
+            source,
+            file_record,
+            segments,
+        })
+    }
+
+    /// Whether the whole kernel is resident in memory.
+    pub fn is_resident(&self) -> bool {
+        self.source.as_slice().is_some()
+    }
+
+    /// Internal file name from the DAF file record.
+    pub fn internal_name(&self) -> &str {
+        &self.file_record.internal_name
+    }
+
+    /// Text of the DAF comment area (empty if the kernel has none).
+    pub fn comments(&self) -> Result<String, KernelError> {
+        daf::read_comments_from(&*self.source, &self.file_record)
+    }
+
+    /// All segments in this kernel.
+    pub fn segments(&self) -> &[PckSegment] {
+        &self.segments
+    }
+
+    /// Index of the first segment for `body_frame` covering `epoch_tdb_s`.
+    pub fn find_segment_index(
+        &self,
+        body_frame: i32,
+        epoch_tdb_s: f64,
+    ) -> Result<usize, KernelError> {
+        self.segments
+            .iter()
+            .position(|seg| {
+                seg.body_frame == body_frame
+                    && epoch_tdb_s >= seg.start_epoch
+                    && epoch_tdb_s <= seg.end_epoch
+            })
+            .ok_or(KernelError::EpochOutOfRange {
+                target: body_frame,
+                center: -1,
+                epoch_tdb_s,
+            })
+    }
+
+    /// Euler angles of `body_frame` at `epoch_tdb_s` (TDB seconds past
+    /// J2000.0), relative to the covering segment's reference frame.
+    pub fn evaluate(
+        &self,
+        body_frame: i32,
+        epoch_tdb_s: f64,
+    ) -> Result<PckEvaluation, KernelError> {
+        let index = self.find_segment_index(body_frame, epoch_tdb_s)?;
+        self.evaluate_segment(index, epoch_tdb_s)
+    }
+
+    /// Evaluate segment `segment_index` at the epoch.
+    pub fn evaluate_segment(
+        &self,
+        segment_index: usize,
+        epoch_tdb_s: f64,
+    ) -> Result<PckEvaluation, KernelError> {
+        let segment = self.segments.get(segment_index).ok_or_else(|| {
+            KernelError::BadSegmentData(format!("segment index {segment_index} out of range"))
+        })?;
+        if !matches!(segment.data_type, 2 | 3) {
+            return Err(KernelError::UnsupportedDataType(segment.data_type));
+        }
+        let eval = spk::evaluate_segment_in(
+            KernelBytes::new(&*self.source),
+            &segment.as_spk(),
+            epoch_tdb_s,
+            self.file_record.endianness,
+        )?;
+        Ok(PckEvaluation {
+            angles_rad: eval.position_km,
+            rates_rad_s: eval.velocity_km_s,
+        })
+    }
+
+    /// Rotation from J2000 to `body_frame` at `epoch_tdb_s`.
+    ///
+    /// Segments referred to ECLIPJ2000 (as in the Earth high-precision
+    /// PCKs) are composed with the constant J2000 → ECLIPJ2000 rotation.
+    pub fn rotation_from_j2000(
+        &self,
+        body_frame: i32,
+        epoch_tdb_s: f64,
+    ) -> Result<FrameRotation, KernelError> {
+        let index = self.find_segment_index(body_frame, epoch_tdb_s)?;
+        let reference = self.segments[index].reference_frame;
+        let body = self.evaluate_segment(index, epoch_tdb_s)?.frame_rotation();
+        let inertial = FrameRotation {
+            matrix: inertial_rotation_from_j2000(reference)?,
+            rate: [[0.0; 3]; 3],
+        };
+        Ok(body.after(&inertial))
+    }
+
+    /// Pole right ascension, declination and prime meridian of
+    /// `body_frame` at `epoch_tdb_s`.
+    pub fn orientation(
+        &self,
+        body_frame: i32,
+        epoch_tdb_s: f64,
+    ) -> Result<BodyOrientation, KernelError> {
+        Ok(self
+            .rotation_from_j2000(body_frame, epoch_tdb_s)?
+            .orientation())
+    }
+
+    /// Body-fixed longitude and latitude of the J2000 direction `toward`.
+    ///
+    /// With the Moon's PA frame and the Moon→Earth vector this is the
+    /// sub-Earth point, i.e. the optical libration in longitude and
+    /// latitude (plus physical libration, which the PCK angles include).
+    pub fn sub_point(
+        &self,
+        body_frame: i32,
+        epoch_tdb_s: f64,
+        toward: &[f64; 3],
+    ) -> Result<[f64; 2], KernelError> {
+        Ok(self
+            .rotation_from_j2000(body_frame, epoch_tdb_s)?
+            .lon_lat(toward))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Matrix helpers
+// ---------------------------------------------------------------------------
+
+fn identity() -> [[f64; 3]; 3] {
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+}
+
+/// Frame rotation `[θ]ᵢ` about axis 1, 2 or 3.
+pub(crate) fn axis_rotation(axis: usize, angle: f64) -> [[f64; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    match axis {
+        1 => [[1.0, 0.0, 0.0], [0.0, c, s], [0.0, -s, c]],
+        2 => [[c, 0.0, -s], [0.0, 1.0, 0.0], [s, 0.0, c]],
+        _ => [[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, 1.0]],
+    }
+}
+
+/// d[θ]ᵢ/dθ.
+fn axis_rotation_derivative(axis: usize, angle: f64) -> [[f64; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    match axis {
+        1 => [[0.0, 0.0, 0.0], [0.0, -s, c], [0.0, -c, -s]],
+        2 => [[-s, 0.0, -c], [0.0, 0.0, 0.0], [c, 0.0, -s]],
+        _ => [[-s, c, 0.0], [-c, -s, 0.0], [0.0, 0.0, 0.0]],
+    }
+}
+
+pub(crate) fn mat_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = [[0.0f64; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+fn mat_vec(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+fn add(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = *a;
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell += b[i][j];
+        }
+    }
+    m
+}
+
+fn scale(a: &[[f64; 3]; 3], k: f64) -> [[f64; 3]; 3] {
+    a.map(|row| row.map(|x| x * k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3], tol: f64) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (a[i][j] - b[i][j]).abs() < tol,
+                    "[{i}][{j}]: {a:?} vs {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn summary_roundtrip() {
+        let summary = DafSummary {
+            doubles: vec![-1e9, 1e9],
+            integers: vec![3000, 17, 2, 641, 9000],
+            name: String::new(),
+        };
+        let seg = pck_segment_from_summary(&summary).unwrap();
+        assert_eq!(seg.body_frame, 3000);
+        assert_eq!(seg.reference_frame, 17);
+        assert_eq!(seg.data_type, 2);
+        assert_eq!(seg.end_addr, 9000);
+        assert!(
+            pck_segment_from_summary(&DafSummary {
+                doubles: vec![0.0, 1.0],
+                integers: vec![1, 2, 3],
+                name: String::new(),
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn pole_along_z_is_pure_spin() {
+        // δ = 90°: body pole coincides with the reference pole, so the
+        // rotation is about z by φ + w.
+        let eval = PckEvaluation {
+            angles_rad: [0.3, std::f64::consts::FRAC_PI_2, 1.1],
+            rates_rad_s: [0.0; 3],
+        };
+        let r = eval.rotation();
+        let expected = mat_mul(
+            &axis_rotation(3, 1.1),
+            &axis_rotation(1, std::f64::consts::FRAC_PI_2),
+        );
+        assert_close(&r, &mat_mul(&expected, &axis_rotation(3, 0.3)), 1e-15);
+        let det = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+            - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+            + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+        assert!((det - 1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn rotation_rate_matches_finite_difference() {
+        let eval = PckEvaluation {
+            angles_rad: [0.2, 1.1, 4.0],
+            rates_rad_s: [1e-9, -3e-10, 7.29e-5],
+        };
+        let h = 1.0;
+        let step = |sign: f64| PckEvaluation {
+            angles_rad: [
+                eval.angles_rad[0] + sign * h * eval.rates_rad_s[0],
+                eval.angles_rad[1] + sign * h * eval.rates_rad_s[1],
+                eval.angles_rad[2] + sign * h * eval.rates_rad_s[2],
+            ],
+            rates_rad_s: eval.rates_rad_s,
+        };
+        let (ahead, behind) = (step(1.0).rotation(), step(-1.0).rotation());
+        let numeric = scale(&add(&ahead, &scale(&behind, -1.0)), 0.5 / h);
+        assert_close(&eval.rotation_rate(), &numeric, 1e-12);
+    }
+
+    #[test]
+    fn eclipj2000_tilts_pole_by_obliquity() {
+        let m = inertial_rotation_from_j2000(FRAME_ECLIPJ2000).unwrap();
+        // The ecliptic pole expressed in J2000 maps to +z.
+        let eps = ECLIPJ2000_OBLIQUITY_RAD;
+        let pole = mat_vec(&m, &[0.0, -eps.sin(), eps.cos()]);
+        assert!((pole[2] - 1.0).abs() < 1e-15);
+        assert_eq!(
+            inertial_rotation_from_j2000(99),
+            Err(KernelError::UnsupportedFrame(99))
+        );
+    }
+}
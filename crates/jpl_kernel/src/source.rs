@@ -0,0 +1,221 @@
+//! Byte sources backing a loaded kernel.
+//!
+//! [`crate::SpkKernel`] reads segment data through the [`ByteSource`] trait,
+//! so a kernel can live fully in memory or be read on demand from a file
+//! handle or any user-supplied reader. On-demand sources only touch the
+//! bytes a query needs, which keeps multi-gigabyte kernels (DE441) cheap to
+//! open. Everything here is safe code: file access uses positional reads
+//! where the platform offers them rather than memory mapping.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::error::KernelError;
+
+/// Random-access, read-only byte storage for a kernel file.
+///
+/// Implementations must be safe to share across threads; concurrent
+/// `read_exact_at` calls with different buffers must not interfere.
+pub trait ByteSource: Send + Sync + fmt::Debug {
+    /// Total size of the source in bytes.
+    fn size(&self) -> u64;
+
+    /// Fill `buf` with the bytes starting at `offset`.
+    ///
+    /// Fails rather than returning a short read.
+    fn read_exact_at(&self, offset: u64, buf: &mut [u8]) -> Result<(), KernelError>;
+
+    /// The whole contents, when they are resident in memory.
+    ///
+    /// Sources that return `Some` are sliced directly instead of copied.
+    fn as_slice(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+/// Check that `offset..offset + len` lies inside a source of `size` bytes.
+fn check_range(offset: u64, len: usize, size: u64) -> Result<(), KernelError> {
+    match offset.checked_add(len as u64) {
+        Some(end) if end <= size => Ok(()),
+        _ => Err(KernelError::Io(format!(
+            "read of {len} bytes at offset {offset} past end of {size}-byte source"
+        ))),
+    }
+}
+
+impl ByteSource for Vec<u8> {
+    fn size(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn read_exact_at(&self, offset: u64, buf: &mut [u8]) -> Result<(), KernelError> {
+        check_range(offset, buf.len(), self.size())?;
+        let start = offset as usize;
+        buf.copy_from_slice(&self[start..start + buf.len()]);
+        Ok(())
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+/// A kernel file read on demand through a std file handle.
+///
+/// Uses positional reads (`pread` on Unix, `seek_read` on Windows), so
+/// concurrent queries never contend on a shared file cursor.
+#[derive(Debug)]
+pub struct FileSource {
+    file: File,
+    size: u64,
+    /// Serialises seek + read on platforms without positional reads.
+    #[cfg(not(any(unix, windows)))]
+    cursor: Mutex<()>,
+}
+
+impl FileSource {
+    /// Open `path` for on-demand reading.
+    pub fn open(path: &Path) -> Result<Self, KernelError> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            file,
+            size,
+            #[cfg(not(any(unix, windows)))]
+            cursor: Mutex::new(()),
+        })
+    }
+
+    #[cfg(unix)]
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<()> {
+        std::os::unix::fs::FileExt::read_exact_at(&self.file, buf, offset)
+    }
+
+    #[cfg(windows)]
+    fn read_at(&self, mut offset: u64, mut buf: &mut [u8]) -> std::io::Result<()> {
+        use std::os::windows::fs::FileExt;
+        while !buf.is_empty() {
+            match self.file.seek_read(buf, offset) {
+                Ok(0) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => {
+                    buf = &mut buf[n..];
+                    offset += n as u64;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<()> {
+        let _guard = self.cursor.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(buf)
+    }
+}
+
+impl ByteSource for FileSource {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn read_exact_at(&self, offset: u64, buf: &mut [u8]) -> Result<(), KernelError> {
+        check_range(offset, buf.len(), self.size)?;
+        self.read_at(offset, buf)?;
+        Ok(())
+    }
+}
+
+/// Adapter exposing any seekable reader as a [`ByteSource`].
+///
+/// Reads are serialised through a mutex, so this suits custom storage
+/// (archives, network-backed readers) more than hot local files, for
+/// which [`FileSource`] is preferable.
+pub struct ReaderSource<R> {
+    reader: Mutex<R>,
+    size: u64,
+}
+
+impl<R: Read + Seek> ReaderSource<R> {
+    /// Wrap `reader`, measuring its size by seeking to the end.
+    pub fn new(mut reader: R) -> Result<Self, KernelError> {
+        let size = reader.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            reader: Mutex::new(reader),
+            size,
+        })
+    }
+}
+
+impl<R> fmt::Debug for ReaderSource<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderSource")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read + Seek + Send> ByteSource for ReaderSource<R> {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn read_exact_at(&self, offset: u64, buf: &mut [u8]) -> Result<(), KernelError> {
+        check_range(offset, buf.len(), self.size)?;
+        let mut reader = self
+            .reader
+            .lock()
+            .map_err(|_| KernelError::Io("reader lock poisoned".into()))?;
+        reader.seek(SeekFrom::Start(offset))?;
+        reader.read_exact(buf)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn vec_source_reads_and_bounds_checks() {
+        let source: Vec<u8> = (0..16).collect();
+        let mut buf = [0u8; 4];
+        source.read_exact_at(6, &mut buf).unwrap();
+        assert_eq!(buf, [6, 7, 8, 9]);
+        assert!(source.read_exact_at(13, &mut buf).is_err());
+        assert!(ByteSource::as_slice(&source).is_some());
+    }
+
+    #[test]
+    fn reader_source_matches_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let source = ReaderSource::new(Cursor::new(bytes.clone())).unwrap();
+        assert_eq!(source.size(), 256);
+        let mut buf = [0u8; 8];
+        source.read_exact_at(100, &mut buf).unwrap();
+        assert_eq!(&buf, &bytes[100..108]);
+        assert!(source.read_exact_at(250, &mut buf).is_err());
+        assert!(source.as_slice().is_none());
+    }
+
+    #[test]
+    fn file_source_reads_at_offsets() {
+        let path =
+            std::env::temp_dir().join(format!("jpl_kernel_file_source_{}.bin", std::process::id()));
+        let bytes: Vec<u8> = (0..64).collect();
+        std::fs::write(&path, &bytes).unwrap();
+        let source = FileSource::open(&path).unwrap();
+        let mut buf = [0u8; 5];
+        source.read_exact_at(40, &mut buf).unwrap();
+        assert_eq!(&buf, &bytes[40..45]);
+        assert!(source.read_exact_at(60, &mut buf).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
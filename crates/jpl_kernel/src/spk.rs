@@ -3,9 +3,13 @@
 //! Reference: NAIF SPK Required Reading (public domain, US Government work product).
 //! Implementation is original, written from the public specification.
 
+use std::cell::RefCell;
+
 use crate::chebyshev;
 use crate::daf::{DafSummary, Endianness};
 use crate::error::KernelError;
+use crate::interpolation::{self, MAX_SAMPLES};
+use crate::source::ByteSource;
 
 /// Metadata for a single SPK segment, extracted from a DAF summary.
 #[derive(Debug, Clone)]
@@ -20,6 +24,8 @@ pub struct SpkSegment {
     pub start_addr: i32,
     /// Last word address (1-based, 8 bytes per word).
     pub end_addr: i32,
+    /// Segment name from the DAF name record (may be empty).
+    pub name: String,
 }
 
 /// Result of evaluating an SPK segment at a single epoch.
@@ -29,15 +35,28 @@ pub struct SpkEvaluation {
     pub velocity_km_s: [f64; 3],
 }
 
-/// SPK Type 2 segment descriptor (stored at the end of segment data).
+/// Chebyshev segment descriptor (Types 2 and 3), stored at the end of
+/// segment data.
 #[derive(Debug, Clone, Copy)]
-struct Type2Descriptor {
+struct ChebyshevDescriptor {
     init: f64,
     intlen: f64,
     rsize: f64,
     n: f64,
 }
 
+/// Largest Chebyshev degree + 1 handled without heap allocation.
+///
+/// DE442s uses 14 coefficients; 32 covers all foreseeable Type 2/3
+/// segments.
+pub(crate) const MAX_COEFFS: usize = 32;
+
+/// Largest difference-line dimension accepted for Type 21 records.
+///
+/// Type 1 fixes this at [`TYPE1_MAXDIM`]; Horizons small-body Type 21 files
+/// use up to 25. 64 leaves generous headroom while keeping work arrays on the stack.
+const MAX_DIFF_DIM: usize = 64;
+
 // ---------------------------------------------------------------------------
 // Segment extraction from DAF summary
 // ---------------------------------------------------------------------------
@@ -59,6 +78,7 @@ pub fn segment_from_summary(summary: &DafSummary) -> Result<SpkSegment, KernelEr
         data_type: summary.integers[3],
         start_addr: summary.integers[4],
         end_addr: summary.integers[5],
+        name: summary.name.clone(),
     })
 }
 
@@ -74,30 +94,407 @@ fn read_f64(data: &[u8], offset: usize, endian: Endianness) -> f64 {
     }
 }
 
+/// Bytes of a kernel file: resident in memory or read on demand.
+#[derive(Clone, Copy)]
+pub(crate) enum KernelBytes<'a> {
+    Slice(&'a [u8]),
+    Source(&'a dyn ByteSource),
+}
+
+impl<'a> KernelBytes<'a> {
+    /// View a source, slicing it directly when it is memory-resident.
+    pub(crate) fn new(source: &'a dyn ByteSource) -> Self {
+        match source.as_slice() {
+            Some(data) => Self::Slice(data),
+            None => Self::Source(source),
+        }
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            Self::Slice(data) => data.len() as u64,
+            Self::Source(source) => source.size(),
+        }
+    }
+}
+
+/// Bytes fetched per read from an on-demand source.
+///
+/// A multiple of 8 so words never straddle blocks; large enough to hold a
+/// full DE-series Chebyshev record.
+const BLOCK_BYTES: usize = 4096;
+
+/// Word-addressed view of one segment's data array.
+///
+/// Word `0` is the segment's first double (DAF address `start_addr`).
+/// On-demand sources are read one block at a time; the most recent block
+/// is kept for the lifetime of the view.
+struct SegmentWords<'a> {
+    bytes: KernelBytes<'a>,
+    base: usize,
+    len: usize,
+    endian: Endianness,
+    /// Byte offset and contents of the last block read from a source.
+    block: RefCell<(usize, Vec<u8>)>,
+}
+
+impl<'a> SegmentWords<'a> {
+    fn new(
+        bytes: KernelBytes<'a>,
+        segment: &SpkSegment,
+        endian: Endianness,
+    ) -> Result<Self, KernelError> {
+        if segment.start_addr < 1 || segment.end_addr < segment.start_addr {
+            return Err(KernelError::BadSegmentData(format!(
+                "invalid segment address range {}..{}",
+                segment.start_addr, segment.end_addr
+            )));
+        }
+        let end_byte = segment.end_addr as usize * 8;
+        if end_byte as u64 > bytes.size() {
+            return Err(KernelError::BadSegmentData(
+                "segment end_addr extends past file".into(),
+            ));
+        }
+        let base = (segment.start_addr as usize - 1) * 8;
+        Ok(Self {
+            bytes,
+            base,
+            len: (end_byte - base) / 8,
+            endian,
+            block: RefCell::new((0, Vec::new())),
+        })
+    }
+
+    /// Read word `index` (0-based) of the segment.
+    fn get(&self, index: usize) -> Result<f64, KernelError> {
+        if index >= self.len {
+            return Err(KernelError::BadSegmentData(format!(
+                "word {index} past end of {}-word segment",
+                self.len
+            )));
+        }
+        let offset = self.base + index * 8;
+        match self.bytes {
+            KernelBytes::Slice(data) => Ok(read_f64(data, offset, self.endian)),
+            KernelBytes::Source(source) => {
+                let mut block = self.block.borrow_mut();
+                let (start, buf) = &mut *block;
+                if offset < *start || offset + 8 > *start + buf.len() {
+                    let block_start = self.base + (offset - self.base) / BLOCK_BYTES * BLOCK_BYTES;
+                    let block_end = (block_start + BLOCK_BYTES).min(self.base + self.len * 8);
+                    buf.resize(block_end - block_start, 0);
+                    source.read_exact_at(block_start as u64, buf)?;
+                    *start = block_start;
+                }
+                Ok(read_f64(buf, offset - *start, self.endian))
+            }
+        }
+    }
+
+    /// Read the `k`-th word from the end of the segment (`k = 1` is last).
+    fn word_from_end(&self, k: usize) -> Result<f64, KernelError> {
+        let index = self.len.checked_sub(k).ok_or_else(|| {
+            KernelError::BadSegmentData(format!("word {k} from end of {}-word segment", self.len))
+        })?;
+        self.get(index)
+    }
+
+    /// Ensure the segment holds at least `words` doubles.
+    fn require(&self, words: usize, what: &str) -> Result<(), KernelError> {
+        if words > self.len {
+            return Err(KernelError::BadSegmentData(format!(
+                "{what} needs {words} words, segment has {}",
+                self.len
+            )));
+        }
+        Ok(())
+    }
+
+    /// Number of entries in the ascending table at `base..base + n` for
+    /// which `pred` holds (binary search; `pred` must be monotone).
+    fn partition_point(
+        &self,
+        base: usize,
+        n: usize,
+        pred: impl Fn(f64) -> bool,
+    ) -> Result<usize, KernelError> {
+        let (mut lo, mut hi) = (0usize, n);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.get(base + mid)?) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(lo)
+    }
+}
+
+/// Decode a count/size word stored as a double.
+fn word_to_count(value: f64, what: &str) -> Result<usize, KernelError> {
+    if !value.is_finite() || value < 0.0 || value.fract() != 0.0 {
+        return Err(KernelError::BadSegmentData(format!(
+            "invalid {what} {value}"
+        )));
+    }
+    Ok(value as usize)
+}
+
 // ---------------------------------------------------------------------------
-// Type 2 evaluation
+// Type 2 / Type 3 evaluation (Chebyshev)
 // ---------------------------------------------------------------------------
 
-/// Read the Type 2 descriptor from the last 4 doubles of the segment data.
-fn read_type2_descriptor(
+/// Read the Chebyshev descriptor from the last 4 doubles of the segment data.
+fn read_chebyshev_descriptor(words: &SegmentWords<'_>) -> Result<ChebyshevDescriptor, KernelError> {
+    if words.len < 4 {
+        return Err(KernelError::BadSegmentData(
+            "segment too short for Chebyshev descriptor".into(),
+        ));
+    }
+    Ok(ChebyshevDescriptor {
+        init: words.word_from_end(4)?,
+        intlen: words.word_from_end(3)?,
+        rsize: words.word_from_end(2)?,
+        n: words.word_from_end(1)?,
+    })
+}
+
+/// Record layout of a Chebyshev segment, validated against its descriptor.
+struct ChebyshevLayout {
+    desc: ChebyshevDescriptor,
+    rsize: usize,
+    n_records: usize,
+    n_coeffs: usize,
+}
+
+impl ChebyshevLayout {
+    /// Read and validate the layout of a segment whose records carry
+    /// `components` coefficient sets (3 for Type 2, 6 for Type 3).
+    fn read(words: &SegmentWords<'_>, components: usize) -> Result<Self, KernelError> {
+        let desc = read_chebyshev_descriptor(words)?;
+
+        let n = desc.n as usize;
+        let rsize = desc.rsize as usize;
+
+        if rsize < 2 + components || !(rsize - 2).is_multiple_of(components) {
+            return Err(KernelError::BadSegmentData(format!(
+                "invalid RSIZE {rsize}: must satisfy (RSIZE-2) mod {components} == 0"
+            )));
+        }
+        let n_coeffs = (rsize - 2) / components;
+        if n_coeffs > MAX_COEFFS {
+            return Err(KernelError::BadSegmentData(format!(
+                "n_coeffs {n_coeffs} exceeds MAX_COEFFS {MAX_COEFFS}"
+            )));
+        }
+
+        Ok(Self {
+            desc,
+            rsize,
+            n_records: n,
+            n_coeffs,
+        })
+    }
+
+    /// Index of the record covering `epoch_tdb_s`, clamped to the last record.
+    fn record_index(&self, epoch_tdb_s: f64) -> usize {
+        let index = ((epoch_tdb_s - self.desc.init) / self.desc.intlen).floor() as usize;
+        index.min(self.n_records.saturating_sub(1))
+    }
+
+    /// Index of the last record needed to cover up to `epoch_tdb_s`; an
+    /// epoch on a record boundary does not pull in the following record.
+    fn last_record_index(&self, epoch_tdb_s: f64) -> usize {
+        let index = ((epoch_tdb_s - self.desc.init) / self.desc.intlen).ceil() as usize;
+        index
+            .saturating_sub(1)
+            .min(self.n_records.saturating_sub(1))
+    }
+
+    /// First word of record `index`, checked against the segment length.
+    fn record_word(&self, words: &SegmentWords<'_>, index: usize) -> Result<usize, KernelError> {
+        let record_word = index * self.rsize;
+        if index >= self.n_records.max(1) || record_word + self.rsize > words.len {
+            return Err(KernelError::BadSegmentData(
+                "record extends past end of file".into(),
+            ));
+        }
+        Ok(record_word)
+    }
+}
+
+/// Number of coefficient sets per record for a Chebyshev data type.
+fn chebyshev_components(data_type: i32) -> Option<usize> {
+    match data_type {
+        2 => Some(3),
+        3 => Some(6),
+        _ => None,
+    }
+}
+
+/// One located Chebyshev record, ready for component evaluation.
+struct ChebyshevRecord {
+    /// Word index of the first coefficient (after MID and RADIUS).
+    coeff_word: usize,
+    n_coeffs: usize,
+    radius: f64,
+    /// Normalised time in [-1, 1].
+    s: f64,
+}
+
+impl ChebyshevRecord {
+    /// Locate the record covering `epoch_tdb_s` in a segment whose records
+    /// carry `components` coefficient sets (3 for Type 2, 6 for Type 3).
+    fn locate(
+        words: &SegmentWords<'_>,
+        epoch_tdb_s: f64,
+        components: usize,
+    ) -> Result<Self, KernelError> {
+        let layout = ChebyshevLayout::read(words, components)?;
+        let record_word = layout.record_word(words, layout.record_index(epoch_tdb_s))?;
+
+        // Read MID and RADIUS.
+        let mid = words.get(record_word)?;
+        let radius = words.get(record_word + 1)?;
+
+        if radius == 0.0 {
+            return Err(KernelError::BadSegmentData("RADIUS is zero".into()));
+        }
+
+        Ok(Self {
+            coeff_word: record_word + 2,
+            n_coeffs: layout.n_coeffs,
+            radius,
+            s: (epoch_tdb_s - mid) / radius,
+        })
+    }
+
+    /// Copy the coefficients of `component` into `buf`, returning the
+    /// filled prefix.
+    fn coefficients<'b>(
+        &self,
+        words: &SegmentWords<'_>,
+        component: usize,
+        buf: &'b mut [f64; MAX_COEFFS],
+    ) -> Result<&'b [f64], KernelError> {
+        let base = self.coeff_word + component * self.n_coeffs;
+        for (c, slot) in buf[..self.n_coeffs].iter_mut().enumerate() {
+            *slot = words.get(base + c)?;
+        }
+        Ok(&buf[..self.n_coeffs])
+    }
+}
+
+/// A decoded Chebyshev record (Types 2 and 3), detached from the file so
+/// it can be cached and evaluated repeatedly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebyshevRecordData {
+    /// Record midpoint, TDB seconds past J2000.0.
+    pub mid: f64,
+    /// Record half-length in seconds.
+    pub radius: f64,
+    /// Coefficient sets per record: 3 (Type 2) or 6 (Type 3).
+    pub components: usize,
+    /// Coefficients per component.
+    pub n_coeffs: usize,
+    /// `components * n_coeffs` coefficients, component-major.
+    pub coefficients: Vec<f64>,
+}
+
+impl ChebyshevRecordData {
+    fn component(&self, index: usize) -> &[f64] {
+        &self.coefficients[index * self.n_coeffs..(index + 1) * self.n_coeffs]
+    }
+
+    /// Evaluate the record at `epoch_tdb_s`.
+    ///
+    /// Matches [`evaluate_type2`] / [`evaluate_type3`] bit for bit.
+    pub fn evaluate(&self, epoch_tdb_s: f64) -> SpkEvaluation {
+        let s = (epoch_tdb_s - self.mid) / self.radius;
+        let mut position_km = [0.0f64; 3];
+        let mut velocity_km_s = [0.0f64; 3];
+        for axis in 0..3 {
+            let coeffs = self.component(axis);
+            position_km[axis] = chebyshev::clenshaw(coeffs, s);
+            velocity_km_s[axis] = if self.components == 6 {
+                chebyshev::clenshaw(self.component(axis + 3), s)
+            } else {
+                chebyshev::clenshaw_derivative(coeffs, s) / self.radius
+            };
+        }
+        SpkEvaluation {
+            position_km,
+            velocity_km_s,
+        }
+    }
+}
+
+/// Index of the Chebyshev record covering `epoch_tdb_s`.
+///
+/// Returns `Ok(None)` for segments that are not Type 2 or 3.
+pub fn chebyshev_record_index(
     data: &[u8],
     segment: &SpkSegment,
+    epoch_tdb_s: f64,
     endian: Endianness,
-) -> Result<Type2Descriptor, KernelError> {
-    // The descriptor occupies the last 4 doubles (32 bytes) of the segment.
-    let end_byte = segment.end_addr as usize * 8;
-    if end_byte > data.len() || end_byte < 32 {
-        return Err(KernelError::BadSegmentData(
-            "segment end_addr extends past file".into(),
-        ));
+) -> Result<Option<usize>, KernelError> {
+    chebyshev_record_index_in(KernelBytes::Slice(data), segment, epoch_tdb_s, endian)
+}
+
+pub(crate) fn chebyshev_record_index_in(
+    bytes: KernelBytes<'_>,
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<Option<usize>, KernelError> {
+    let Some(components) = chebyshev_components(segment.data_type) else {
+        return Ok(None);
+    };
+    let words = SegmentWords::new(bytes, segment, endian)?;
+    let layout = ChebyshevLayout::read(&words, components)?;
+    Ok(Some(layout.record_index(epoch_tdb_s)))
+}
+
+/// Decode Chebyshev record `record_index` of a Type 2 or 3 segment.
+pub fn read_chebyshev_record(
+    data: &[u8],
+    segment: &SpkSegment,
+    record_index: usize,
+    endian: Endianness,
+) -> Result<ChebyshevRecordData, KernelError> {
+    read_chebyshev_record_in(KernelBytes::Slice(data), segment, record_index, endian)
+}
+
+pub(crate) fn read_chebyshev_record_in(
+    bytes: KernelBytes<'_>,
+    segment: &SpkSegment,
+    record_index: usize,
+    endian: Endianness,
+) -> Result<ChebyshevRecordData, KernelError> {
+    let components = chebyshev_components(segment.data_type)
+        .ok_or(KernelError::UnsupportedDataType(segment.data_type))?;
+    let words = SegmentWords::new(bytes, segment, endian)?;
+    let layout = ChebyshevLayout::read(&words, components)?;
+    let record_word = layout.record_word(&words, record_index)?;
+
+    let mid = words.get(record_word)?;
+    let radius = words.get(record_word + 1)?;
+    if radius == 0.0 {
+        return Err(KernelError::BadSegmentData("RADIUS is zero".into()));
     }
-    let desc_offset = end_byte - 32; // 4 doubles * 8 bytes
 
-    Ok(Type2Descriptor {
-        init: read_f64(data, desc_offset, endian),
-        intlen: read_f64(data, desc_offset + 8, endian),
-        rsize: read_f64(data, desc_offset + 16, endian),
-        n: read_f64(data, desc_offset + 24, endian),
+    let count = components * layout.n_coeffs;
+    let coefficients = (0..count)
+        .map(|i| words.get(record_word + 2 + i))
+        .collect::<Result<_, _>>()?;
+    Ok(ChebyshevRecordData {
+        mid,
+        radius,
+        components,
+        n_coeffs: layout.n_coeffs,
+        coefficients,
     })
 }
 
@@ -110,69 +507,585 @@ pub fn evaluate_type2(
     epoch_tdb_s: f64,
     endian: Endianness,
 ) -> Result<SpkEvaluation, KernelError> {
-    let desc = read_type2_descriptor(data, segment, endian)?;
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type2_words(&words, epoch_tdb_s)
+}
 
-    let n = desc.n as usize;
-    let rsize = desc.rsize as usize;
-    let intlen = desc.intlen;
+fn evaluate_type2_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let record = ChebyshevRecord::locate(words, epoch_tdb_s, 3)?;
 
-    if rsize < 3 || !(rsize - 2).is_multiple_of(3) {
-        return Err(KernelError::BadSegmentData(format!(
-            "invalid RSIZE {rsize}: must satisfy (RSIZE-2) mod 3 == 0"
-        )));
+    let mut position_km = [0.0f64; 3];
+    let mut velocity_km_s = [0.0f64; 3];
+    let mut buf = [0.0f64; MAX_COEFFS];
+
+    for axis in 0..3 {
+        let coeffs = record.coefficients(words, axis, &mut buf)?;
+        position_km[axis] = chebyshev::clenshaw(coeffs, record.s);
+        velocity_km_s[axis] = chebyshev::clenshaw_derivative(coeffs, record.s) / record.radius;
     }
-    let n_coeffs = (rsize - 2) / 3;
 
-    // Find the record index.
-    let record_index = ((epoch_tdb_s - desc.init) / intlen).floor() as usize;
-    let record_index = record_index.min(n.saturating_sub(1));
+    Ok(SpkEvaluation {
+        position_km,
+        velocity_km_s,
+    })
+}
 
-    // Byte offset of this record within the file.
-    let seg_start_byte = (segment.start_addr as usize - 1) * 8;
-    let record_byte = seg_start_byte + record_index * rsize * 8;
+/// Evaluate an SPK Type 3 (Chebyshev position and velocity) segment.
+///
+/// Same record layout as Type 2 but with six coefficient sets per record;
+/// velocity comes from its own expansion rather than the position
+/// derivative.
+pub fn evaluate_type3(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type3_words(&words, epoch_tdb_s)
+}
 
-    if record_byte + rsize * 8 > data.len() {
-        return Err(KernelError::BadSegmentData(
-            "record extends past end of file".into(),
-        ));
-    }
+fn evaluate_type3_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let record = ChebyshevRecord::locate(words, epoch_tdb_s, 6)?;
 
-    // Read MID and RADIUS.
-    let mid = read_f64(data, record_byte, endian);
-    let radius = read_f64(data, record_byte + 8, endian);
+    let mut position_km = [0.0f64; 3];
+    let mut velocity_km_s = [0.0f64; 3];
+    let mut buf = [0.0f64; MAX_COEFFS];
 
-    if radius == 0.0 {
-        return Err(KernelError::BadSegmentData("RADIUS is zero".into()));
+    for axis in 0..3 {
+        let coeffs = record.coefficients(words, axis, &mut buf)?;
+        position_km[axis] = chebyshev::clenshaw(coeffs, record.s);
+        let coeffs = record.coefficients(words, axis + 3, &mut buf)?;
+        velocity_km_s[axis] = chebyshev::clenshaw(coeffs, record.s);
     }
 
-    // Normalised time in [-1, 1].
-    let s = (epoch_tdb_s - mid) / radius;
+    Ok(SpkEvaluation {
+        position_km,
+        velocity_km_s,
+    })
+}
 
-    // Read coefficients for X, Y, Z and evaluate.
-    let coeff_base = record_byte + 16; // skip MID + RADIUS
+// ---------------------------------------------------------------------------
+// Types 8, 9, 13 evaluation (discrete states)
+// ---------------------------------------------------------------------------
 
-    // Stack-allocate coefficient buffer. DE442s uses 14 coefficients;
-    // 32 covers all foreseeable Type 2 segments without heap allocation.
-    const MAX_COEFFS: usize = 32;
-    if n_coeffs > MAX_COEFFS {
-        return Err(KernelError::BadSegmentData(format!(
-            "n_coeffs {n_coeffs} exceeds MAX_COEFFS {MAX_COEFFS}"
-        )));
+/// First sample index of a `window`-sample interpolation window in a series
+/// of `n` samples, given the `anchor` sample nearest the request.
+///
+/// Even windows straddle the request (anchor = last sample at or before
+/// it); odd windows centre on the nearest sample. The window is clamped
+/// to the ends of the series.
+fn window_start(anchor: i64, window: usize, n: usize) -> usize {
+    let first = anchor - ((window - 1) / 2) as i64;
+    first.clamp(0, (n - window) as i64) as usize
+}
+
+/// Interpolate a window of discrete states.
+///
+/// `epoch_of(k)` and `state_word(k)` give the epoch and first state word
+/// of window sample `k`. With `hermite`, position is Hermite-interpolated
+/// from positions and velocities and velocity is its derivative; otherwise
+/// each of the six components is Lagrange-interpolated independently.
+fn interpolate_window(
+    words: &SegmentWords<'_>,
+    window: usize,
+    epoch_tdb_s: f64,
+    hermite: bool,
+    epoch_of: impl Fn(usize) -> Result<f64, KernelError>,
+    state_word: impl Fn(usize) -> usize,
+) -> Result<SpkEvaluation, KernelError> {
+    let mut xs = [0.0f64; MAX_SAMPLES];
+    let mut states = [[0.0f64; 6]; MAX_SAMPLES];
+    for k in 0..window {
+        xs[k] = epoch_of(k)?;
+        let base = state_word(k);
+        for (c, slot) in states[k].iter_mut().enumerate() {
+            *slot = words.get(base + c)?;
+        }
     }
+    let xs = &xs[..window];
 
     let mut position_km = [0.0f64; 3];
     let mut velocity_km_s = [0.0f64; 3];
+    let mut ys = [0.0f64; MAX_SAMPLES];
+    let mut dys = [0.0f64; MAX_SAMPLES];
 
     for axis in 0..3 {
-        let axis_offset = coeff_base + axis * n_coeffs * 8;
-        let mut buf = [0.0f64; MAX_COEFFS];
-        for (c, slot) in buf[..n_coeffs].iter_mut().enumerate() {
-            *slot = read_f64(data, axis_offset + c * 8, endian);
+        for k in 0..window {
+            ys[k] = states[k][axis];
+            dys[k] = states[k][axis + 3];
+        }
+        if hermite {
+            let (p, v) = interpolation::hermite(xs, &ys[..window], &dys[..window], epoch_tdb_s);
+            position_km[axis] = p;
+            velocity_km_s[axis] = v;
+        } else {
+            position_km[axis] = interpolation::lagrange(xs, &ys[..window], epoch_tdb_s);
+            velocity_km_s[axis] = interpolation::lagrange(xs, &dys[..window], epoch_tdb_s);
         }
-        let coeffs = &buf[..n_coeffs];
+    }
 
-        position_km[axis] = chebyshev::clenshaw(coeffs, s);
-        velocity_km_s[axis] = chebyshev::clenshaw_derivative(coeffs, s) / radius;
+    Ok(SpkEvaluation {
+        position_km,
+        velocity_km_s,
+    })
+}
+
+/// Evaluate an SPK Type 8 (Lagrange, equally spaced states) segment.
+///
+/// Layout: `N` six-word states, then start epoch, step size, polynomial
+/// degree and `N`.
+pub fn evaluate_type8(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type8_words(&words, epoch_tdb_s)
+}
+
+/// Validated Type 8 layout: `n` states every `step` seconds from `begin`.
+struct Type8Layout {
+    begin: f64,
+    step: f64,
+    degree: f64,
+    n: usize,
+    window: usize,
+}
+
+impl Type8Layout {
+    fn read(words: &SegmentWords<'_>) -> Result<Self, KernelError> {
+        words.require(4, "Type 8 descriptor")?;
+
+        let begin = words.word_from_end(4)?;
+        let step = words.word_from_end(3)?;
+        let degree = words.word_from_end(2)?;
+        let n = word_to_count(words.word_from_end(1)?, "Type 8 state count")?;
+
+        if n == 0 {
+            return Err(KernelError::BadSegmentData(
+                "Type 8 segment has no states".into(),
+            ));
+        }
+        words.require(6 * n + 4, "Type 8 states")?;
+        if n > 1 && (step.is_nan() || step <= 0.0) {
+            return Err(KernelError::BadSegmentData(format!(
+                "Type 8 step size {step} must be positive"
+            )));
+        }
+
+        let window = (word_to_count(degree, "Type 8 degree")? + 1).min(n);
+        if window > MAX_SAMPLES {
+            return Err(KernelError::BadSegmentData(format!(
+                "Type 8 window {window} exceeds {MAX_SAMPLES}"
+            )));
+        }
+        Ok(Self {
+            begin,
+            step,
+            degree,
+            n,
+            window,
+        })
+    }
+
+    /// Position of `epoch_tdb_s` in units of `step` from the first state.
+    fn position(&self, epoch_tdb_s: f64) -> f64 {
+        if self.n > 1 {
+            (epoch_tdb_s - self.begin) / self.step
+        } else {
+            0.0
+        }
+    }
+
+    /// Number of states at or before `epoch_tdb_s`.
+    fn states_at_or_before(&self, epoch_tdb_s: f64) -> usize {
+        (self.position(epoch_tdb_s).floor() + 1.0).clamp(0.0, self.n as f64) as usize
+    }
+}
+
+fn evaluate_type8_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let layout = Type8Layout::read(words)?;
+    let position = layout.position(epoch_tdb_s);
+    let anchor = if layout.window.is_multiple_of(2) {
+        position.floor()
+    } else {
+        position.round()
+    };
+    let first = window_start(anchor as i64, layout.window, layout.n);
+
+    interpolate_window(
+        words,
+        layout.window,
+        epoch_tdb_s,
+        false,
+        |k| Ok(layout.begin + (first + k) as f64 * layout.step),
+        |k| 6 * (first + k),
+    )
+}
+
+/// Shared evaluation for Types 9 and 13 (unequally spaced states).
+///
+/// Layout: `N` six-word states, `N` epochs, `(N - 1) / 100` directory
+/// epochs, then a window control word and `N`.
+fn evaluate_unequal_states(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+    layout: &UnequalStatesLayout,
+) -> Result<SpkEvaluation, KernelError> {
+    let UnequalStatesLayout {
+        n, window, hermite, ..
+    } = *layout;
+    let epoch_base = 6 * n;
+    let anchor = {
+        let at_or_before = layout.states_at_or_before(words, epoch_tdb_s)?;
+        let last = at_or_before as i64 - 1;
+        if window.is_multiple_of(2) || at_or_before == 0 || at_or_before == n {
+            last
+        } else {
+            // Odd windows centre on the nearest state.
+            let before = words.get(epoch_base + at_or_before - 1)?;
+            let after = words.get(epoch_base + at_or_before)?;
+            if after - epoch_tdb_s < epoch_tdb_s - before {
+                last + 1
+            } else {
+                last
+            }
+        }
+    };
+    let first = window_start(anchor, window, n);
+
+    interpolate_window(
+        words,
+        window,
+        epoch_tdb_s,
+        hermite,
+        |k| words.get(epoch_base + first + k),
+        |k| 6 * (first + k),
+    )
+}
+
+/// Validated Type 9/13 layout.
+struct UnequalStatesLayout {
+    /// Window control word: degree (Type 9) or window size minus one
+    /// (Type 13).
+    control: f64,
+    n: usize,
+    window: usize,
+    hermite: bool,
+}
+
+impl UnequalStatesLayout {
+    fn read(words: &SegmentWords<'_>, data_type: i32) -> Result<Self, KernelError> {
+        let (type_name, hermite) = if data_type == 13 {
+            ("Type 13", true)
+        } else {
+            ("Type 9", false)
+        };
+        words.require(2, type_name)?;
+        let n = word_to_count(words.word_from_end(1)?, "state count")?;
+        if n == 0 {
+            return Err(KernelError::BadSegmentData(format!(
+                "{type_name} segment has no states"
+            )));
+        }
+        words.require(7 * n + (n - 1) / 100 + 2, type_name)?;
+
+        // Hermite samples carry position and velocity, so each counts twice.
+        let control = words.word_from_end(2)?;
+        let (what, max) = if hermite {
+            ("Type 13 window size", MAX_SAMPLES / 2)
+        } else {
+            ("Type 9 degree", MAX_SAMPLES)
+        };
+        let window = (word_to_count(control, what)? + 1).min(n);
+        if window > max {
+            return Err(KernelError::BadSegmentData(format!(
+                "{type_name} window {window} exceeds {max}"
+            )));
+        }
+        Ok(Self {
+            control,
+            n,
+            window,
+            hermite,
+        })
+    }
+
+    /// Number of states at or before `epoch_tdb_s`.
+    fn states_at_or_before(
+        &self,
+        words: &SegmentWords<'_>,
+        epoch_tdb_s: f64,
+    ) -> Result<usize, KernelError> {
+        words.partition_point(6 * self.n, self.n, |e| e <= epoch_tdb_s)
+    }
+}
+
+/// Evaluate an SPK Type 9 (Lagrange, unequally spaced states) segment.
+pub fn evaluate_type9(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type9_words(&words, epoch_tdb_s)
+}
+
+fn evaluate_type9_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let layout = UnequalStatesLayout::read(words, 9)?;
+    evaluate_unequal_states(words, epoch_tdb_s, &layout)
+}
+
+/// Evaluate an SPK Type 13 (Hermite, unequally spaced states) segment.
+///
+/// The control word stores the window size minus one; each window sample
+/// contributes both position and velocity to the Hermite fit.
+pub fn evaluate_type13(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type13_words(&words, epoch_tdb_s)
+}
+
+fn evaluate_type13_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let layout = UnequalStatesLayout::read(words, 13)?;
+    evaluate_unequal_states(words, epoch_tdb_s, &layout)
+}
+
+// ---------------------------------------------------------------------------
+// Types 1 and 21 evaluation (modified difference arrays)
+// ---------------------------------------------------------------------------
+
+/// Difference-line dimension fixed by SPK Type 1.
+const TYPE1_MAXDIM: usize = 15;
+
+/// Evaluate an SPK Type 1 (modified difference array) segment.
+///
+/// Layout: `N` difference-line records of 71 words (`MAXDIM = 15`), `N`
+/// record end epochs, `N / 100` directory epochs, then `N`. Records are
+/// selected as for Type 21.
+pub fn evaluate_type1(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type1_words(&words, epoch_tdb_s)
+}
+
+fn evaluate_type1_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let layout = DifferenceLineLayout::read(words, 1)?;
+    evaluate_difference_lines(words, &layout, epoch_tdb_s)
+}
+
+/// Evaluate an SPK Type 21 (extended modified difference array) segment.
+///
+/// Layout: `N` difference-line records of `4 * MAXDIM + 11` words, `N`
+/// record end epochs, `N / 100` directory epochs, then `MAXDIM` and `N`.
+/// The record used is the first whose end epoch is at or after the request.
+pub fn evaluate_type21(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let words = SegmentWords::new(KernelBytes::Slice(data), segment, endian)?;
+    evaluate_type21_words(&words, epoch_tdb_s)
+}
+
+fn evaluate_type21_words(
+    words: &SegmentWords<'_>,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let layout = DifferenceLineLayout::read(words, 21)?;
+    evaluate_difference_lines(words, &layout, epoch_tdb_s)
+}
+
+/// Validated Type 1/21 layout: `n` records of `dlsize` words followed by
+/// their end epochs, the directory and `trailer` control words.
+struct DifferenceLineLayout {
+    maxdim: usize,
+    n: usize,
+    dlsize: usize,
+    /// 1 for Type 1 (`N`), 2 for Type 21 (`MAXDIM`, `N`).
+    trailer: usize,
+}
+
+impl DifferenceLineLayout {
+    fn read(words: &SegmentWords<'_>, data_type: i32) -> Result<Self, KernelError> {
+        let (maxdim, n, trailer, type_name) = if data_type == 1 {
+            words.require(1, "Type 1 descriptor")?;
+            let n = word_to_count(words.word_from_end(1)?, "Type 1 record count")?;
+            (TYPE1_MAXDIM, n, 1, "Type 1")
+        } else {
+            words.require(2, "Type 21 descriptor")?;
+            let maxdim = word_to_count(words.word_from_end(2)?, "Type 21 MAXDIM")?;
+            let n = word_to_count(words.word_from_end(1)?, "Type 21 record count")?;
+            if maxdim == 0 || maxdim > MAX_DIFF_DIM {
+                return Err(KernelError::BadSegmentData(format!(
+                    "Type 21 MAXDIM {maxdim} outside 1..={MAX_DIFF_DIM}"
+                )));
+            }
+            (maxdim, n, 2, "Type 21")
+        };
+        if n == 0 {
+            return Err(KernelError::BadSegmentData(format!(
+                "{type_name} segment has no records"
+            )));
+        }
+        let dlsize = 4 * maxdim + 11;
+        words.require(n * dlsize + n + n / 100 + trailer, type_name)?;
+        Ok(Self {
+            maxdim,
+            n,
+            dlsize,
+            trailer,
+        })
+    }
+
+    /// Index of the record used for `epoch_tdb_s`: the first whose end
+    /// epoch is at or after it, clamped to the last record.
+    fn record_index(
+        &self,
+        words: &SegmentWords<'_>,
+        epoch_tdb_s: f64,
+    ) -> Result<usize, KernelError> {
+        let index = words.partition_point(self.n * self.dlsize, self.n, |e| e < epoch_tdb_s)?;
+        Ok(index.min(self.n - 1))
+    }
+}
+
+/// Select and evaluate the difference-line record covering `epoch_tdb_s`.
+fn evaluate_difference_lines(
+    words: &SegmentWords<'_>,
+    layout: &DifferenceLineLayout,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let dlsize = layout.dlsize;
+    let index = layout.record_index(words, epoch_tdb_s)?;
+
+    let mut record = [0.0f64; 4 * MAX_DIFF_DIM + 11];
+    let record_word = index * dlsize;
+    for (i, slot) in record[..dlsize].iter_mut().enumerate() {
+        *slot = words.get(record_word + i)?;
+    }
+
+    evaluate_difference_line(&record[..dlsize], layout.maxdim, epoch_tdb_s)
+}
+
+/// Evaluate one modified-difference-array record at `epoch_tdb_s`.
+///
+/// Record layout (0-based words): `TL`, step sizes `G[MAXDIM]`, reference
+/// position/velocity interleaved `(x, vx, y, vy, z, vz)`, difference table
+/// `DT[3][MAXDIM]`, `KQMAX1`, `KQ[3]`. Work arrays are 1-based to mirror
+/// the published recurrence.
+fn evaluate_difference_line(
+    record: &[f64],
+    maxdim: usize,
+    epoch_tdb_s: f64,
+) -> Result<SpkEvaluation, KernelError> {
+    let tl = record[0];
+    let g = |j: usize| record[j]; // G(j), 1-based
+    let refpos = |i: usize| record[maxdim + 1 + 2 * i];
+    let refvel = |i: usize| record[maxdim + 2 + 2 * i];
+    let dt = |j: usize, i: usize| record[maxdim + 7 + i * maxdim + (j - 1)]; // DT(j, i), j 1-based
+
+    let kqmax1 = word_to_count(record[4 * maxdim + 7], "difference line KQMAX1")?;
+    if kqmax1 < 2 || kqmax1 > maxdim + 1 {
+        return Err(KernelError::BadSegmentData(format!(
+            "difference line KQMAX1 {kqmax1} outside 2..={}",
+            maxdim + 1
+        )));
+    }
+    let mut kq = [0usize; 3];
+    for (i, slot) in kq.iter_mut().enumerate() {
+        *slot = word_to_count(record[4 * maxdim + 8 + i], "difference line KQ")?;
+        if *slot > maxdim {
+            return Err(KernelError::BadSegmentData(format!(
+                "difference line KQ {} exceeds MAXDIM {maxdim}",
+                *slot
+            )));
+        }
+    }
+
+    let delta = epoch_tdb_s - tl;
+    let mut tp = delta;
+    let mq2 = kqmax1 - 2;
+    let mut ks = kqmax1 - 1;
+
+    let mut fc = [0.0f64; MAX_DIFF_DIM + 2];
+    let mut wc = [0.0f64; MAX_DIFF_DIM + 2];
+    let mut w = [0.0f64; MAX_DIFF_DIM + 3];
+
+    fc[1] = 1.0;
+    for j in 1..=mq2 {
+        fc[j + 1] = tp / g(j);
+        wc[j] = delta / g(j);
+        tp = delta + g(j);
+    }
+    for (j, slot) in w.iter_mut().enumerate().take(kqmax1 + 1).skip(1) {
+        *slot = 1.0 / j as f64;
+    }
+
+    // Integration coefficients for position.
+    let mut jx = 0usize;
+    let mut ks1 = ks - 1;
+    while ks >= 2 {
+        jx += 1;
+        for j in 1..=jx {
+            w[j + ks] = fc[j + 1] * w[j + ks1] - wc[j] * w[j + ks];
+        }
+        ks = ks1;
+        ks1 = ks1.saturating_sub(1);
+    }
+
+    let mut position_km = [0.0f64; 3];
+    for (i, pos) in position_km.iter_mut().enumerate() {
+        let mut sum = 0.0;
+        for j in (1..=kq[i]).rev() {
+            sum += dt(j, i) * w[j + ks];
+        }
+        *pos = refpos(i) + delta * (refvel(i) + delta * sum);
+    }
+
+    // One more pass yields the coefficients for velocity.
+    for j in 1..=jx {
+        w[j + ks] = fc[j + 1] * w[j + ks1] - wc[j] * w[j + ks];
+    }
+    ks -= 1;
+
+    let mut velocity_km_s = [0.0f64; 3];
+    for (i, vel) in velocity_km_s.iter_mut().enumerate() {
+        let mut sum = 0.0;
+        for j in (1..=kq[i]).rev() {
+            sum += dt(j, i) * w[j + ks];
+        }
+        *vel = refvel(i) + delta * sum;
     }
 
     Ok(SpkEvaluation {
@@ -181,6 +1094,190 @@ pub fn evaluate_type2(
     })
 }
 
+// ---------------------------------------------------------------------------
+// Subsetting
+// ---------------------------------------------------------------------------
+
+/// Data words of `segment` restricted to `[start_tdb_s, end_tdb_s]`.
+///
+/// Every supported type keeps only the data that evaluation inside the
+/// window reads, so the subset evaluates identically there:
+///
+/// - Types 2/3: the records overlapping the window, with the descriptor
+///   rewritten for the new first record and count.
+/// - Types 1/21: the difference-line records used in the window, their end
+///   epochs and a rebuilt directory.
+/// - Types 8/9/13: the states any interpolation window in the window can
+///   reach; Type 8 moves its start epoch to the first kept state.
+///
+/// Unsupported types are returned whole.
+pub(crate) fn subset_words_in(
+    bytes: KernelBytes<'_>,
+    segment: &SpkSegment,
+    start_tdb_s: f64,
+    end_tdb_s: f64,
+    endian: Endianness,
+) -> Result<Vec<f64>, KernelError> {
+    let words = SegmentWords::new(bytes, segment, endian)?;
+    let components = match segment.data_type {
+        1 | 21 => {
+            return subset_difference_lines(&words, segment.data_type, start_tdb_s, end_tdb_s);
+        }
+        8 => return subset_type8(&words, start_tdb_s, end_tdb_s),
+        9 | 13 => {
+            return subset_unequal_states(&words, segment.data_type, start_tdb_s, end_tdb_s);
+        }
+        other => match chebyshev_components(other) {
+            Some(components) => components,
+            None => return (0..words.len).map(|i| words.get(i)).collect(),
+        },
+    };
+    let layout = ChebyshevLayout::read(&words, components)?;
+    let first = layout.record_index(start_tdb_s);
+    let last = layout.last_record_index(end_tdb_s).max(first);
+    let begin = layout.record_word(&words, first)?;
+    let end = layout.record_word(&words, last)? + layout.rsize;
+
+    let mut out = (begin..end)
+        .map(|i| words.get(i))
+        .collect::<Result<Vec<_>, _>>()?;
+    out.extend([
+        layout.desc.init + first as f64 * layout.desc.intlen,
+        layout.desc.intlen,
+        layout.desc.rsize,
+        (last - first + 1) as f64,
+    ]);
+    Ok(out)
+}
+
+/// Sample range `lo..hi` of a series of `n` that every `window`-sample
+/// interpolation window for an epoch in a range can draw from.
+///
+/// `before_start` and `before_end` count the samples at or before the
+/// range ends. The range always holds at least `window` samples, so
+/// [`window_start`] picks the same samples from the subset as from the
+/// full series.
+fn window_sample_range(
+    before_start: usize,
+    before_end: usize,
+    window: usize,
+    n: usize,
+) -> (usize, usize) {
+    let lead = (window - 1) / 2;
+    let lo = before_start.saturating_sub(1 + lead);
+    let hi = (before_end + window - lead)
+        .min(n)
+        .max((lo + window).min(n));
+    (lo.min(hi - window), hi)
+}
+
+/// Read `count` consecutive words starting at `first`.
+fn read_words(
+    words: &SegmentWords<'_>,
+    first: usize,
+    count: usize,
+) -> Result<Vec<f64>, KernelError> {
+    (first..first + count).map(|i| words.get(i)).collect()
+}
+
+/// Every 100th epoch of `epochs`, `count` entries, as stored in segment
+/// directories.
+fn epoch_directory(epochs: &[f64], count: usize) -> impl Iterator<Item = f64> + '_ {
+    (1..=count).map(|k| epochs[100 * k - 1])
+}
+
+fn subset_type8(
+    words: &SegmentWords<'_>,
+    start_tdb_s: f64,
+    end_tdb_s: f64,
+) -> Result<Vec<f64>, KernelError> {
+    let layout = Type8Layout::read(words)?;
+    let (lo, hi) = window_sample_range(
+        layout.states_at_or_before(start_tdb_s),
+        layout.states_at_or_before(end_tdb_s),
+        layout.window,
+        layout.n,
+    );
+    let mut out = read_words(words, 6 * lo, 6 * (hi - lo))?;
+    out.extend([
+        layout.begin + lo as f64 * layout.step,
+        layout.step,
+        layout.degree,
+        (hi - lo) as f64,
+    ]);
+    Ok(out)
+}
+
+fn subset_unequal_states(
+    words: &SegmentWords<'_>,
+    data_type: i32,
+    start_tdb_s: f64,
+    end_tdb_s: f64,
+) -> Result<Vec<f64>, KernelError> {
+    let layout = UnequalStatesLayout::read(words, data_type)?;
+    let (lo, hi) = window_sample_range(
+        layout.states_at_or_before(words, start_tdb_s)?,
+        layout.states_at_or_before(words, end_tdb_s)?,
+        layout.window,
+        layout.n,
+    );
+    let n = hi - lo;
+    let mut out = read_words(words, 6 * lo, 6 * n)?;
+    let epochs = read_words(words, 6 * layout.n + lo, n)?;
+    out.extend(&epochs);
+    out.extend(epoch_directory(&epochs, (n - 1) / 100));
+    out.extend([layout.control, n as f64]);
+    Ok(out)
+}
+
+fn subset_difference_lines(
+    words: &SegmentWords<'_>,
+    data_type: i32,
+    start_tdb_s: f64,
+    end_tdb_s: f64,
+) -> Result<Vec<f64>, KernelError> {
+    let layout = DifferenceLineLayout::read(words, data_type)?;
+    let first = layout.record_index(words, start_tdb_s)?;
+    let last = layout.record_index(words, end_tdb_s)?.max(first);
+    let n = last - first + 1;
+
+    let mut out = read_words(words, first * layout.dlsize, n * layout.dlsize)?;
+    let ends = read_words(words, layout.n * layout.dlsize + first, n)?;
+    out.extend(&ends);
+    out.extend(epoch_directory(&ends, n / 100));
+    if layout.trailer == 2 {
+        out.push(layout.maxdim as f64);
+    }
+    out.push(n as f64);
+    Ok(out)
+}
+
+// ---------------------------------------------------------------------------
+// Dispatch
+// ---------------------------------------------------------------------------
+
+/// Evaluate a segment of any supported type from kernel bytes.
+pub(crate) fn evaluate_segment_in(
+    bytes: KernelBytes<'_>,
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let evaluate: fn(&SegmentWords<'_>, f64) -> Result<SpkEvaluation, KernelError> =
+        match segment.data_type {
+            1 => evaluate_type1_words,
+            2 => evaluate_type2_words,
+            3 => evaluate_type3_words,
+            8 => evaluate_type8_words,
+            9 => evaluate_type9_words,
+            13 => evaluate_type13_words,
+            21 => evaluate_type21_words,
+            other => return Err(KernelError::UnsupportedDataType(other)),
+        };
+    let words = SegmentWords::new(bytes, segment, endian)?;
+    evaluate(&words, epoch_tdb_s)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +1287,7 @@ mod tests {
         let summary = DafSummary {
             doubles: vec![0.0],
             integers: vec![1, 2, 3],
+            name: String::new(),
         };
         assert!(segment_from_summary(&summary).is_err());
     }
@@ -199,6 +1297,7 @@ mod tests {
         let summary = DafSummary {
             doubles: vec![-1e9, 1e9],
             integers: vec![499, 4, 1, 2, 100, 200],
+            name: String::new(),
         };
         let seg = segment_from_summary(&summary).unwrap();
         assert_eq!(seg.target, 499);
@@ -207,4 +1306,379 @@ mod tests {
         assert_eq!(seg.start_addr, 100);
         assert_eq!(seg.end_addr, 200);
     }
+
+    /// Lay out `words` as a little-endian segment occupying the whole buffer.
+    fn segment_data(words: &[f64], data_type: i32) -> (Vec<u8>, SpkSegment) {
+        let data = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        let segment = SpkSegment {
+            start_epoch: -1e12,
+            end_epoch: 1e12,
+            target: 1,
+            center: 0,
+            frame: 1,
+            data_type,
+            start_addr: 1,
+            end_addr: words.len() as i32,
+            name: String::new(),
+        };
+        (data, segment)
+    }
+
+    /// Cubic reference motion per axis, with its derivative.
+    fn cubic_state(t: f64) -> [f64; 6] {
+        let mut state = [0.0; 6];
+        for axis in 0..3 {
+            let a = axis as f64 + 1.0;
+            state[axis] = 1.0e6 * a + 12.0 * a * t - 3.0e-4 * t * t + 2.0e-9 * a * t * t * t;
+            state[axis + 3] = 12.0 * a - 6.0e-4 * t + 6.0e-9 * a * t * t;
+        }
+        state
+    }
+
+    fn assert_state(eval: SpkEvaluation, expected: [f64; 6], tol: f64) {
+        for axis in 0..3 {
+            assert!(
+                (eval.position_km[axis] - expected[axis]).abs() < tol,
+                "pos[{axis}] {} vs {}",
+                eval.position_km[axis],
+                expected[axis]
+            );
+            assert!(
+                (eval.velocity_km_s[axis] - expected[axis + 3]).abs() < tol,
+                "vel[{axis}] {} vs {}",
+                eval.velocity_km_s[axis],
+                expected[axis + 3]
+            );
+        }
+    }
+
+    fn assert_close(a: f64, b: f64, tol: f64) {
+        assert!((a - b).abs() < tol, "{a} vs {b}");
+    }
+
+    #[test]
+    fn type3_uses_velocity_coefficients() {
+        // One record: MID = 100, RADIUS = 50, 2 coefficients per component.
+        let mut words = vec![100.0, 50.0];
+        for component in 0..6 {
+            words.extend([10.0 * component as f64, 1.0 + component as f64]);
+        }
+        words.extend([50.0, 100.0, 14.0, 1.0]); // INIT, INTLEN, RSIZE, N
+        let (data, seg) = segment_data(&words, 3);
+
+        let eval = evaluate_type3(&data, &seg, 125.0, Endianness::Little).unwrap();
+        let s = 0.5;
+        for axis in 0..3 {
+            assert_close(
+                eval.position_km[axis],
+                10.0 * axis as f64 + (1.0 + axis as f64) * s,
+                1e-12,
+            );
+            let c = axis + 3;
+            assert_close(
+                eval.velocity_km_s[axis],
+                10.0 * c as f64 + (1.0 + c as f64) * s,
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn type3_rejects_bad_rsize() {
+        // RSIZE 11 is valid for Type 2 ((11-2) mod 3 == 0) but not Type 3.
+        let mut words = vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        words.extend([0.0, 2.0, 11.0, 1.0]);
+        let (data, seg) = segment_data(&words, 3);
+        assert!(matches!(
+            evaluate_type3(&data, &seg, 0.5, Endianness::Little),
+            Err(KernelError::BadSegmentData(_))
+        ));
+        assert!(evaluate_type2(&data, &seg, 0.5, Endianness::Little).is_ok());
+    }
+
+    #[test]
+    fn trailer_reads_reject_short_segments() {
+        // Fewer words than the 4-word Type 2/8 trailer.
+        let (data, seg) = segment_data(&[1.0, 2.0], 2);
+        assert!(matches!(
+            evaluate_type2(&data, &seg, 0.0, Endianness::Little),
+            Err(KernelError::BadSegmentData(_))
+        ));
+        assert!(matches!(
+            evaluate_type8(&data, &seg, 0.0, Endianness::Little),
+            Err(KernelError::BadSegmentData(_))
+        ));
+    }
+
+    #[test]
+    fn decoded_records_match_direct_evaluation() {
+        // Two records of 3 coefficients per component, Type 2 and Type 3.
+        for (data_type, components) in [(2, 3usize), (3, 6usize)] {
+            let rsize = 2 + 3 * components;
+            let mut words = Vec::new();
+            for record in 0..2 {
+                words.extend([50.0 + 100.0 * record as f64, 50.0]);
+                for c in 0..3 * components {
+                    words.push(0.5 * c as f64 + record as f64 - 0.01 * (c * c) as f64);
+                }
+            }
+            words.extend([0.0, 100.0, rsize as f64, 2.0]);
+            let (data, seg) = segment_data(&words, data_type);
+
+            for t in [0.0, 37.5, 99.9, 100.0, 163.2, 200.0] {
+                let index = chebyshev_record_index(&data, &seg, t, Endianness::Little)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(index, if t < 100.0 { 0 } else { 1 });
+                let record = read_chebyshev_record(&data, &seg, index, Endianness::Little).unwrap();
+                assert_eq!(record.components, components);
+                let direct = if data_type == 2 {
+                    evaluate_type2(&data, &seg, t, Endianness::Little)
+                } else {
+                    evaluate_type3(&data, &seg, t, Endianness::Little)
+                }
+                .unwrap();
+                assert_eq!(record.evaluate(t), direct);
+            }
+            assert!(read_chebyshev_record(&data, &seg, 2, Endianness::Little).is_err());
+        }
+
+        let (data, seg) = segment_data(&[0.0; 8], 9);
+        assert_eq!(
+            chebyshev_record_index(&data, &seg, 0.0, Endianness::Little).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn type8_reproduces_cubic_motion() {
+        let (begin, step, n) = (-500.0, 60.0, 20usize);
+        let mut words = Vec::new();
+        for i in 0..n {
+            words.extend(cubic_state(begin + i as f64 * step));
+        }
+        words.extend([begin, step, 3.0, n as f64]);
+        let (data, seg) = segment_data(&words, 8);
+
+        for t in [-500.0, -431.7, 0.0, 222.2, 639.9, begin + 19.0 * step] {
+            let eval = evaluate_type8(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, cubic_state(t), 1e-6);
+        }
+    }
+
+    #[test]
+    fn type8_odd_window_reproduces_quadratic() {
+        // Degree 2 (window 3) is exact for the quadratic part alone.
+        let f = |t: f64| {
+            [
+                5.0 + 2.0 * t + 0.25 * t * t,
+                0.0,
+                0.0,
+                2.0 + 0.5 * t,
+                0.0,
+                0.0,
+            ]
+        };
+        let mut words = Vec::new();
+        for i in 0..6 {
+            words.extend(f(i as f64 * 10.0));
+        }
+        words.extend([0.0, 10.0, 2.0, 6.0]);
+        let (data, seg) = segment_data(&words, 8);
+        for t in [0.0, 4.0, 14.9, 33.3, 50.0] {
+            let eval = evaluate_type8(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, f(t), 1e-9);
+        }
+    }
+
+    #[test]
+    fn type9_reproduces_cubic_motion() {
+        let epochs = [0.0, 35.0, 80.0, 100.0, 170.0, 260.0, 275.0, 400.0];
+        let mut words = Vec::new();
+        for &t in &epochs {
+            words.extend(cubic_state(t));
+        }
+        words.extend(epochs);
+        words.extend([3.0, epochs.len() as f64]);
+        let (data, seg) = segment_data(&words, 9);
+
+        for t in [0.0, 12.5, 99.0, 100.0, 201.0, 399.0, 400.0] {
+            let eval = evaluate_type9(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, cubic_state(t), 1e-6);
+        }
+    }
+
+    #[test]
+    fn type13_reproduces_cubic_with_two_point_window() {
+        // Hermite over 2 states is exact for cubics.
+        let epochs = [0.0, 50.0, 75.0, 200.0, 260.0];
+        let mut words = Vec::new();
+        for &t in &epochs {
+            words.extend(cubic_state(t));
+        }
+        words.extend(epochs);
+        words.extend([1.0, epochs.len() as f64]); // window size - 1
+        let (data, seg) = segment_data(&words, 13);
+
+        for t in [0.0, 20.0, 74.0, 130.0, 259.0, 260.0] {
+            let eval = evaluate_type13(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, cubic_state(t), 1e-6);
+        }
+    }
+
+    #[test]
+    fn type13_window_clamped_to_state_count() {
+        let epochs = [0.0, 100.0];
+        let mut words = Vec::new();
+        for &t in &epochs {
+            words.extend(cubic_state(t));
+        }
+        words.extend(epochs);
+        words.extend([7.0, 2.0]);
+        let (data, seg) = segment_data(&words, 13);
+        let eval = evaluate_type13(&data, &seg, 42.0, Endianness::Little).unwrap();
+        assert_state(eval, cubic_state(42.0), 1e-6);
+    }
+
+    /// Build a Type 21 record with MAXDIM = `maxdim` describing
+    /// `x = r + v·Δ + a·Δ²/2 + j·Δ³/6` about `tl` on every axis.
+    fn difference_line(
+        maxdim: usize,
+        tl: f64,
+        g1: f64,
+        r: f64,
+        v: f64,
+        a: f64,
+        j: f64,
+    ) -> Vec<f64> {
+        let mut rec = vec![0.0; 4 * maxdim + 11];
+        rec[0] = tl;
+        rec[1] = g1;
+        for i in 0..3 {
+            rec[maxdim + 1 + 2 * i] = r * (i + 1) as f64;
+            rec[maxdim + 2 + 2 * i] = v;
+            rec[maxdim + 7 + i * maxdim] = a;
+            rec[maxdim + 7 + i * maxdim + 1] = j * g1;
+            rec[4 * maxdim + 8 + i] = 2.0;
+        }
+        rec[4 * maxdim + 7] = 3.0;
+        rec
+    }
+
+    #[test]
+    fn type21_reproduces_cubic_motion() {
+        let (r, v, a, j) = (7.0e5, 3.5, -2.0e-4, 4.0e-9);
+        let maxdim = 25;
+        let mut words = difference_line(maxdim, 0.0, 3600.0, r, v, a, j);
+        words.extend(difference_line(maxdim, 1000.0, 7200.0, r, v, a, j));
+        words.extend([500.0, 2000.0]); // record end epochs
+        words.extend([maxdim as f64, 2.0]);
+        let (data, seg) = segment_data(&words, 21);
+
+        let expected = |tl: f64, t: f64| {
+            let d = t - tl;
+            let mut st = [0.0; 6];
+            for i in 0..3 {
+                st[i] = r * (i + 1) as f64 + v * d + a * d * d / 2.0 + j * d * d * d / 6.0;
+                st[i + 3] = v + a * d + j * d * d / 2.0;
+            }
+            st
+        };
+
+        // Before and at the first end epoch → first record.
+        for t in [-200.0, 0.0, 499.0, 500.0] {
+            let eval = evaluate_type21(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, expected(0.0, t), 1e-6);
+        }
+        // After it → second record.
+        for t in [500.5, 1200.0, 2000.0] {
+            let eval = evaluate_type21(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, expected(1000.0, t), 1e-6);
+        }
+    }
+
+    #[test]
+    fn type1_reproduces_cubic_motion() {
+        let (r, v, a, j) = (-4.0e6, 12.0, 3.0e-5, -6.0e-10);
+        let mut words = difference_line(TYPE1_MAXDIM, 0.0, 1800.0, r, v, a, j);
+        words.extend(difference_line(TYPE1_MAXDIM, 1000.0, 900.0, r, v, a, j));
+        words.extend([500.0, 2000.0, 2.0]); // end epochs, N
+        let (data, seg) = segment_data(&words, 1);
+
+        for (tl, t) in [
+            (0.0, -50.0),
+            (0.0, 500.0),
+            (1000.0, 750.0),
+            (1000.0, 2000.0),
+        ] {
+            let d = t - tl;
+            let mut expected = [0.0; 6];
+            for i in 0..3 {
+                expected[i] = r * (i + 1) as f64 + v * d + a * d * d / 2.0 + j * d * d * d / 6.0;
+                expected[i + 3] = v + a * d + j * d * d / 2.0;
+            }
+            let eval = evaluate_type1(&data, &seg, t, Endianness::Little).unwrap();
+            assert_state(eval, expected, 1e-6);
+        }
+    }
+
+    #[test]
+    fn type1_rejects_truncated_segment() {
+        let mut words = vec![0.0; 4 * TYPE1_MAXDIM + 11];
+        words.push(2.0); // claims two records
+        let (data, seg) = segment_data(&words, 1);
+        assert!(matches!(
+            evaluate_type1(&data, &seg, 0.0, Endianness::Little),
+            Err(KernelError::BadSegmentData(_))
+        ));
+    }
+
+    #[test]
+    fn type9_subset_rebuilds_directory() {
+        let epochs: Vec<f64> = (0..450).map(|k| 10.0 * k as f64 + (k % 3) as f64).collect();
+        let mut words = Vec::new();
+        for &t in &epochs {
+            words.extend(cubic_state(t));
+        }
+        words.extend(&epochs);
+        words.extend((1..=4).map(|k| epochs[100 * k - 1]));
+        words.extend([3.0, epochs.len() as f64]);
+        let (data, seg) = segment_data(&words, 9);
+
+        let (start, end) = (1234.5, 3456.7);
+        let sub = subset_words_in(
+            KernelBytes::Slice(&data),
+            &seg,
+            start,
+            end,
+            Endianness::Little,
+        )
+        .unwrap();
+        let n = *sub.last().unwrap() as usize;
+        assert!(n > 200 && n < 250, "{n} states kept");
+        assert_eq!(sub.len(), 7 * n + (n - 1) / 100 + 2);
+        let kept = &sub[6 * n..7 * n];
+        assert!(kept[0] < start && kept[n - 1] > end);
+        assert_eq!(&sub[7 * n..7 * n + 2], [kept[99], kept[199]]);
+        assert_eq!(sub[sub.len() - 2], 3.0);
+
+        let (sub_data, sub_seg) = segment_data(&sub, 9);
+        for t in [start, 2000.0, 2999.9, end] {
+            assert_eq!(
+                evaluate_type9(&sub_data, &sub_seg, t, Endianness::Little).unwrap(),
+                evaluate_type9(&data, &seg, t, Endianness::Little).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn type21_rejects_bad_maxdim() {
+        let mut words = vec![0.0; 4 * 15 + 11];
+        words.extend([0.0, 0.0, 1.0]);
+        let (data, seg) = segment_data(&words, 21);
+        assert!(matches!(
+            evaluate_type21(&data, &seg, 0.0, Endianness::Little),
+            Err(KernelError::BadSegmentData(_))
+        ));
+    }
 }
@@ -0,0 +1,667 @@
+//! Text kernels (text PCK, FK) and the kernel variable pool.
+//!
+//! A text kernel alternates free-form comment blocks (after `\begintext`)
+//! with data blocks (after `\begindata`) of assignments:
+//!
+//! ```text
+//! BODY399_RADII = ( 6378.1366 6378.1366 6356.7519 )
+//! BODY399_GM    = 3.9860043543609598D+05
+//! FRAME_MOON_ME = 31007
+//! TKFRAME_31007_RELATIVE = 'MOON_PA'
+//! ```
+//!
+//! Values are numbers (Fortran `D` exponents allowed), single-quoted strings
+//! (`''` escapes a quote) or `@` calendar dates, alone or in a parenthesised
+//! list. `=` replaces a variable and `+=` appends to it, across files loaded
+//! into the same pool.
+//!
+//! Reference: NAIF Kernel, PCK and Frames Required Reading (public domain,
+//! US Government work product). Implementation is original.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::KernelError;
+use crate::pck::{axis_rotation, mat_mul};
+
+/// A single value of a kernel pool variable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextValue {
+    /// Numeric value; `@` dates are stored as seconds past J2000.
+    Number(f64),
+    Text(String),
+}
+
+/// Variables parsed from one or more text kernels.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextKernel {
+    variables: BTreeMap<String, Vec<TextValue>>,
+}
+
+/// A fixed-offset (TK) frame defined in a frame kernel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TkFrame {
+    /// Name of the frame the offset is relative to.
+    pub relative: String,
+    /// Constant rotation from the relative frame to the TK frame (row-major).
+    pub rotation: [[f64; 3]; 3],
+}
+
+impl TextKernel {
+    /// An empty variable pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a single text kernel.
+    pub fn parse(text: &str) -> Result<Self, KernelError> {
+        let mut kernel = Self::new();
+        kernel.add_text(text)?;
+        Ok(kernel)
+    }
+
+    /// Load a single text kernel from a file path.
+    pub fn load(path: &Path) -> Result<Self, KernelError> {
+        let mut kernel = Self::new();
+        kernel.add_file(path)?;
+        Ok(kernel)
+    }
+
+    /// Parse another text kernel into this pool.
+    ///
+    /// Later assignments replace earlier ones; `+=` appends to them.
+    pub fn add_text(&mut self, text: &str) -> Result<(), KernelError> {
+        let mut data = String::new();
+        let mut in_data = false;
+        for line in text.lines() {
+            match line.trim() {
+                "\\begindata" => in_data = true,
+                "\\begintext" => in_data = false,
+                _ if in_data => {
+                    data.push_str(line);
+                    data.push('\n');
+                }
+                _ => {}
+            }
+        }
+        self.assign_all(&tokenize(&data)?)
+    }
+
+    /// Load another text kernel file into this pool.
+    pub fn add_file(&mut self, path: &Path) -> Result<(), KernelError> {
+        let text = std::fs::read_to_string(path)?;
+        self.add_text(&text)
+    }
+
+    fn assign_all(&mut self, tokens: &[Token]) -> Result<(), KernelError> {
+        let mut iter = tokens.iter();
+        while let Some(token) = iter.next() {
+            let Token::Word(name) = token else {
+                return Err(bad(format!("expected variable name, found {token:?}")));
+            };
+            let append = match iter.next() {
+                Some(Token::Assign) => false,
+                Some(Token::Append) => true,
+                other => {
+                    return Err(bad(format!(
+                        "expected '=' or '+=' after {name}, found {other:?}"
+                    )));
+                }
+            };
+
+            let mut values = Vec::new();
+            match iter.next() {
+                Some(Token::Open) => loop {
+                    match iter.next() {
+                        Some(Token::Close) => break,
+                        Some(value) => values.push(parse_value(value, name)?),
+                        None => return Err(bad(format!("unterminated list for {name}"))),
+                    }
+                },
+                Some(value) => values.push(parse_value(value, name)?),
+                None => return Err(bad(format!("missing value for {name}"))),
+            }
+
+            let slot = self.variables.entry(name.clone()).or_default();
+            if !append {
+                slot.clear();
+            }
+            slot.extend(values);
+            let numeric = slot
+                .iter()
+                .filter(|v| matches!(v, TextValue::Number(_)))
+                .count();
+            if numeric != 0 && numeric != slot.len() {
+                return Err(bad(format!("{name} mixes numeric and string values")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Values of a variable, if defined.
+    pub fn get(&self, name: &str) -> Option<&[TextValue]> {
+        self.variables.get(name).map(Vec::as_slice)
+    }
+
+    /// Names of all defined variables, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.variables.keys().map(String::as_str)
+    }
+
+    /// Numeric values of a variable, or `None` if undefined or textual.
+    pub fn numbers(&self, name: &str) -> Option<Vec<f64>> {
+        self.get(name)?
+            .iter()
+            .map(|v| match v {
+                TextValue::Number(x) => Some(*x),
+                TextValue::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// The value of a scalar numeric variable.
+    pub fn number(&self, name: &str) -> Option<f64> {
+        match self.get(name)? {
+            [TextValue::Number(x)] => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// The value of a scalar string variable.
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            [TextValue::Text(s)] => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Tri-axial radii of a body in km (`BODYnnn_RADII`).
+    pub fn body_radii_km(&self, naif_id: i32) -> Option<[f64; 3]> {
+        self.numbers(&format!("BODY{naif_id}_RADII"))?
+            .try_into()
+            .ok()
+    }
+
+    /// Gravitational parameter of a body in km³/s² (`BODYnnn_GM`).
+    pub fn body_gm_km3_s2(&self, naif_id: i32) -> Option<f64> {
+        self.number(&format!("BODY{naif_id}_GM"))
+    }
+
+    /// NAIF ID assigned to a body name by `NAIF_BODY_NAME` /
+    /// `NAIF_BODY_CODE`.
+    ///
+    /// Names compare case-insensitively with runs of blanks collapsed; when
+    /// a name is listed more than once the last assignment wins.
+    pub fn body_code(&self, name: &str) -> Option<i32> {
+        let wanted = normalize_body_name(name);
+        self.body_mappings()
+            .rev()
+            .find(|(n, _)| normalize_body_name(n) == wanted)
+            .map(|(_, code)| code)
+    }
+
+    /// Last body name assigned to a NAIF ID by `NAIF_BODY_NAME` /
+    /// `NAIF_BODY_CODE`.
+    pub fn body_name(&self, naif_id: i32) -> Option<&str> {
+        self.body_mappings()
+            .rev()
+            .find(|&(_, code)| code == naif_id)
+            .map(|(name, _)| name)
+    }
+
+    fn body_mappings(&self) -> impl DoubleEndedIterator<Item = (&str, i32)> {
+        let names = self.get("NAIF_BODY_NAME").unwrap_or(&[]);
+        let codes = self.get("NAIF_BODY_CODE").unwrap_or(&[]);
+        names.iter().zip(codes).filter_map(|pair| match pair {
+            (TextValue::Text(name), TextValue::Number(code)) if code.fract() == 0.0 => {
+                Some((name.as_str(), *code as i32))
+            }
+            _ => None,
+        })
+    }
+
+    /// Frame ID assigned to a frame name (`FRAME_<NAME>`).
+    pub fn frame_id(&self, name: &str) -> Option<i32> {
+        let id = self.number(&format!("FRAME_{}", name.trim().to_ascii_uppercase()))?;
+        (id.fract() == 0.0).then_some(id as i32)
+    }
+
+    /// Name assigned to a frame ID (`FRAME_<id>_NAME`).
+    pub fn frame_name(&self, frame_id: i32) -> Option<&str> {
+        self.string(&format!("FRAME_{frame_id}_NAME"))
+    }
+
+    /// The fixed-offset definition of frame `frame_id`, if it is a TK frame.
+    ///
+    /// Keywords may be keyed by frame ID or by frame name. Supports the
+    /// `ANGLES`, `MATRIX` and `QUATERNION` specifications; the angle, matrix
+    /// and quaternion forms all describe the rotation from the TK frame to
+    /// its relative frame, which is inverted here.
+    pub fn tk_frame(&self, frame_id: i32) -> Result<Option<TkFrame>, KernelError> {
+        let key = if self.get(&format!("TKFRAME_{frame_id}_SPEC")).is_some() {
+            frame_id.to_string()
+        } else {
+            match self.frame_name(frame_id) {
+                Some(name) if self.get(&format!("TKFRAME_{name}_SPEC")).is_some() => {
+                    name.to_string()
+                }
+                _ => return Ok(None),
+            }
+        };
+        let var = |suffix: &str| format!("TKFRAME_{key}_{suffix}");
+        let relative = self
+            .string(&var("RELATIVE"))
+            .ok_or_else(|| bad(format!("{} missing", var("RELATIVE"))))?
+            .to_string();
+        let spec = self
+            .string(&var("SPEC"))
+            .ok_or_else(|| bad(format!("{} is not a string", var("SPEC"))))?
+            .to_ascii_uppercase();
+
+        let to_relative = match spec.as_str() {
+            "ANGLES" => {
+                let angles = self.fixed::<3>(&var("ANGLES"))?;
+                let axes = self.fixed::<3>(&var("AXES"))?;
+                let units = self
+                    .string(&var("UNITS"))
+                    .ok_or_else(|| bad(format!("{} missing", var("UNITS"))))?;
+                let to_rad = angle_unit_rad(units)?;
+                let mut m = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+                for (angle, axis) in angles.iter().zip(axes) {
+                    if ![1.0, 2.0, 3.0].contains(&axis) {
+                        return Err(bad(format!("{} must be 1, 2 or 3", var("AXES"))));
+                    }
+                    m = mat_mul(&m, &axis_rotation(axis as usize, angle * to_rad));
+                }
+                m
+            }
+            "MATRIX" => {
+                let v = self.fixed::<9>(&var("MATRIX"))?;
+                // Column-major: the first three values are the first column.
+                [[v[0], v[3], v[6]], [v[1], v[4], v[7]], [v[2], v[5], v[8]]]
+            }
+            "QUATERNION" => quaternion_to_matrix(self.fixed::<4>(&var("Q"))?)?,
+            other => return Err(bad(format!("unsupported TK frame spec '{other}'"))),
+        };
+
+        Ok(Some(TkFrame {
+            relative,
+            rotation: transpose(&to_relative),
+        }))
+    }
+
+    /// Exactly `N` numeric values of a variable.
+    fn fixed<const N: usize>(&self, name: &str) -> Result<[f64; N], KernelError> {
+        self.numbers(name)
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| bad(format!("{name} must hold {N} numbers")))
+    }
+}
+
+fn bad(msg: String) -> KernelError {
+    KernelError::BadTextKernel(msg)
+}
+
+fn angle_unit_rad(units: &str) -> Result<f64, KernelError> {
+    match units.to_ascii_uppercase().as_str() {
+        "RADIANS" => Ok(1.0),
+        "DEGREES" => Ok(1f64.to_radians()),
+        "ARCMINUTES" => Ok((1.0 / 60.0f64).to_radians()),
+        "ARCSECONDS" => Ok((1.0 / 3600.0f64).to_radians()),
+        other => Err(bad(format!("unsupported angle units '{other}'"))),
+    }
+}
+
+/// Rotation matrix of a unit quaternion `(cos, sin·axis)`.
+fn quaternion_to_matrix(q: [f64; 4]) -> Result<[[f64; 3]; 3], KernelError> {
+    let norm = q.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return Err(bad("zero quaternion".into()));
+    }
+    let [w, x, y, z] = q.map(|c| c / norm);
+    Ok([
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - w * z),
+            2.0 * (x * z + w * y),
+        ],
+        [
+            2.0 * (x * y + w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - w * x),
+        ],
+        [
+            2.0 * (x * z - w * y),
+            2.0 * (y * z + w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ])
+}
+
+fn transpose(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    [
+        [m[0][0], m[1][0], m[2][0]],
+        [m[0][1], m[1][1], m[2][1]],
+        [m[0][2], m[1][2], m[2][2]],
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizer
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Text(String),
+    Assign,
+    Append,
+    Open,
+    Close,
+}
+
+fn tokenize(data: &str) -> Result<Vec<Token>, KernelError> {
+    let mut tokens = Vec::new();
+    let mut chars = data.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() || c == ',' => {
+                chars.next();
+            }
+            '=' => {
+                chars.next();
+                tokens.push(Token::Assign);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '\'' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            text.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some('\n') | None => return Err(bad("unterminated string".into())),
+                        Some(ch) => text.push(ch),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || matches!(ch, ',' | '=' | '(' | ')' | '\'') {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                // `NAME +=` or `NAME+=`: the '+' belongs to the operator.
+                if chars.peek() == Some(&'=') && word.ends_with('+') {
+                    chars.next();
+                    word.pop();
+                    if !word.is_empty() {
+                        tokens.push(Token::Word(word));
+                    }
+                    tokens.push(Token::Append);
+                } else {
+                    tokens.push(Token::Word(word));
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_value(token: &Token, name: &str) -> Result<TextValue, KernelError> {
+    match token {
+        Token::Text(s) => Ok(TextValue::Text(s.clone())),
+        Token::Word(w) => {
+            let parsed = match w.strip_prefix('@') {
+                Some(date) => parse_date_seconds(date),
+                None => w.replace(['D', 'd'], "E").parse().ok(),
+            };
+            parsed
+                .map(TextValue::Number)
+                .ok_or_else(|| bad(format!("invalid value '{w}' for {name}")))
+        }
+        other => Err(bad(format!("unexpected {other:?} in value of {name}"))),
+    }
+}
+
+/// Seconds past J2000 (2000-01-01 12:00:00) of an `@` date such as
+/// `2000-JAN-01`, `2000-01-01/12:00:00` or `2000-01-01T12:00:00.5`.
+fn parse_date_seconds(date: &str) -> Option<f64> {
+    const MONTHS: [&str; 12] = [
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ];
+    // A 'T' separator follows a digit; month names such as OCT contain one.
+    let split = date.char_indices().find(|&(i, c)| {
+        c == '/' || (c == 'T' && date[..i].ends_with(|p: char| p.is_ascii_digit()))
+    });
+    let (day_part, time_part) = match split.map(|(i, _)| i) {
+        Some(i) => (&date[..i], Some(&date[i + 1..])),
+        None => (date, None),
+    };
+    let mut fields = day_part.split('-');
+    let year: i64 = fields.next()?.parse().ok()?;
+    let month_field = fields.next()?;
+    let month = match month_field.parse::<i64>() {
+        Ok(m) => m,
+        Err(_) => {
+            let upper = month_field.to_ascii_uppercase();
+            MONTHS.iter().position(|m| upper.starts_with(m))? as i64 + 1
+        }
+    };
+    let day: i64 = fields.next()?.parse().ok()?;
+    if fields.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut seconds_of_day = 0.0;
+    if let Some(time) = time_part {
+        let mut scale = 3600.0;
+        for field in time.split(':') {
+            if scale < 1.0 {
+                return None;
+            }
+            seconds_of_day += field.parse::<f64>().ok()? * scale;
+            scale /= 60.0;
+        }
+    }
+
+    // Days from 1970-01-01 of a proleptic Gregorian date.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days_since_1970 = era * 146_097 + doe - 719_468;
+    // 2000-01-01 is day 10957 since 1970.
+    Some((days_since_1970 - 10_957) as f64 * 86_400.0 + seconds_of_day - 43_200.0)
+}
+
+/// Upper-case `name` with surrounding blanks dropped and inner runs of
+/// blanks collapsed to one space.
+fn normalize_body_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"KPL/PCK
+
+Comments are ignored, even ones that look like BODY10_GM = 1.
+
+\begindata
+
+BODY399_RADII = ( 6378.1366   6378.1366   6356.7519 )
+BODY399_GM    = 3.9860043543609598D+05
+BODY301_RADII = ( 1737.4, 1737.4, 1737.4 )
+NAMES         = ( 'EARTH' 'MOON' )
+NAMES        += 'O''HARE'
+EPOCH         = @2000-JAN-01/12:00:00
+
+\begintext
+
+More comments.
+
+\begindata
+BODY399_GM = 398600.0
+";
+
+    #[test]
+    fn body_name_code_mappings() {
+        let k = TextKernel::parse(
+            r"\begindata
+NAIF_BODY_NAME += ( 'CHIRON', 'MY  PROBE' )
+NAIF_BODY_CODE += ( 2002060, -555 )
+NAIF_BODY_NAME += 'PROBE'
+NAIF_BODY_CODE += -555
+",
+        )
+        .unwrap();
+        assert_eq!(k.body_code("chiron"), Some(2_002_060));
+        assert_eq!(k.body_code(" my probe "), Some(-555));
+        assert_eq!(k.body_code("CERES"), None);
+        assert_eq!(k.body_name(-555), Some("PROBE"));
+        assert_eq!(k.body_name(10), None);
+    }
+
+    #[test]
+    fn parses_numbers_strings_and_dates() {
+        let k = TextKernel::parse(SAMPLE).unwrap();
+        assert_eq!(
+            k.body_radii_km(399),
+            Some([6378.1366, 6378.1366, 6356.7519])
+        );
+        assert_eq!(k.body_radii_km(301), Some([1737.4; 3]));
+        // Later assignment replaces the earlier one.
+        assert_eq!(k.body_gm_km3_s2(399), Some(398600.0));
+        assert_eq!(k.body_gm_km3_s2(10), None);
+        assert_eq!(
+            k.get("NAMES").unwrap(),
+            &[
+                TextValue::Text("EARTH".into()),
+                TextValue::Text("MOON".into()),
+                TextValue::Text("O'HARE".into()),
+            ]
+        );
+        assert_eq!(k.number("EPOCH"), Some(0.0));
+        assert_eq!(k.names().count(), 5);
+    }
+
+    #[test]
+    fn append_continues_across_kernels() {
+        let mut k = TextKernel::parse("\\begindata\nLIST = ( 1 2 )\n").unwrap();
+        k.add_text("\\begindata\nLIST += 3\nLIST+=(4)\n").unwrap();
+        assert_eq!(k.numbers("LIST"), Some(vec![1.0, 2.0, 3.0, 4.0]));
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        for text in [
+            "\\begindata\nX = ( 1 2\n",
+            "\\begindata\nX 1\n",
+            "\\begindata\nX = 'open\n",
+            "\\begindata\nX = 1.0Q5\n",
+            "\\begindata\nX = ( 1 'a' )\n",
+        ] {
+            assert!(
+                matches!(TextKernel::parse(text), Err(KernelError::BadTextKernel(_))),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn date_values() {
+        assert_eq!(parse_date_seconds("2000-01-02"), Some(43_200.0));
+        assert_eq!(parse_date_seconds("2000-JAN-01T12:00:30.5"), Some(30.5));
+        assert_eq!(parse_date_seconds("1999-DEC-31/12:00"), Some(-86_400.0));
+        assert_eq!(parse_date_seconds("2000-OCT-01"), Some(23_630_400.0));
+        assert_eq!(parse_date_seconds("2000-13-01"), None);
+    }
+
+    fn frame_kernel(spec: &str) -> TextKernel {
+        TextKernel::parse(&format!(
+            "\\begindata\nFRAME_TEST_TK = 1400001\nFRAME_1400001_NAME = 'TEST_TK'\n{spec}\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn tk_frame_specs_agree() {
+        // 90° about z, written three ways.
+        let angles = frame_kernel(
+            "TKFRAME_1400001_RELATIVE = 'J2000'\nTKFRAME_1400001_SPEC = 'ANGLES'\n\
+             TKFRAME_1400001_ANGLES = ( 0 0 -90 )\nTKFRAME_1400001_AXES = ( 1 2 3 )\n\
+             TKFRAME_1400001_UNITS = 'DEGREES'",
+        );
+        let matrix = frame_kernel(
+            "TKFRAME_TEST_TK_RELATIVE = 'J2000'\nTKFRAME_TEST_TK_SPEC = 'MATRIX'\n\
+             TKFRAME_TEST_TK_MATRIX = ( 0 1 0  -1 0 0  0 0 1 )",
+        );
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        let quaternion = frame_kernel(&format!(
+            "TKFRAME_1400001_RELATIVE = 'J2000'\nTKFRAME_1400001_SPEC = 'QUATERNION'\n\
+             TKFRAME_1400001_Q = ( {half} 0 0 {half} )"
+        ));
+
+        assert_eq!(angles.frame_id("test_tk"), Some(1_400_001));
+        let expected = [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        for kernel in [&angles, &matrix, &quaternion] {
+            let frame = kernel.tk_frame(1_400_001).unwrap().unwrap();
+            assert_eq!(frame.relative, "J2000");
+            for (row, expected_row) in frame.rotation.iter().zip(&expected) {
+                for (x, e) in row.iter().zip(expected_row) {
+                    assert!((x - e).abs() < 1e-15);
+                }
+            }
+        }
+        assert_eq!(angles.tk_frame(7).unwrap(), None);
+    }
+
+    #[test]
+    fn tk_frame_angles_compose_in_order() {
+        // [a1]_3 [a2]_1 (TK → relative), inverted to relative → TK.
+        let k = frame_kernel(
+            "TKFRAME_1400001_RELATIVE = 'J2000'\nTKFRAME_1400001_SPEC = 'ANGLES'\n\
+             TKFRAME_1400001_ANGLES = ( 3600 7200 0 )\nTKFRAME_1400001_AXES = ( 3 1 2 )\n\
+             TKFRAME_1400001_UNITS = 'ARCSECONDS'",
+        );
+        let frame = k.tk_frame(1_400_001).unwrap().unwrap();
+        let to_relative = mat_mul(
+            &axis_rotation(3, 1f64.to_radians()),
+            &axis_rotation(1, 2f64.to_radians()),
+        );
+        let product = mat_mul(&frame.rotation, &to_relative);
+        for (i, row) in product.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((x - expected).abs() < 1e-15);
+            }
+        }
+        let bad_units = frame_kernel(
+            "TKFRAME_1400001_RELATIVE = 'J2000'\nTKFRAME_1400001_SPEC = 'ANGLES'\n\
+             TKFRAME_1400001_ANGLES = ( 0 0 0 )\nTKFRAME_1400001_AXES = ( 1 2 3 )\n\
+             TKFRAME_1400001_UNITS = 'FURLONGS'",
+        );
+        assert!(bad_units.tk_frame(1_400_001).is_err());
+    }
+}
@@ -0,0 +1,414 @@
+//! DAF/SPK writer: kernel subsets and fitted Chebyshev segments.
+//!
+//! [`SpkWriter`] assembles a little-endian (`LTL-IEEE`) DAF/SPK file from
+//! [`SegmentData`] values. Segments come either from an existing kernel,
+//! clipped to a time window ([`crate::SpkKernel::segment_subset`]), or
+//! from a Chebyshev fit to a state function ([`fit_chebyshev_segment`]).
+//!
+//! File layout follows the DAF specification: the file record, the
+//! comment records, then for every group of up to 25 segments a summary
+//! record, its name record and the segments' data.
+//!
+//! Reference: NAIF DAF and SPK Required Reading (public domain, US
+//! Government work product). Implementation is original.
+
+use std::f64::consts::PI;
+use std::path::Path;
+
+use crate::error::KernelError;
+use crate::spk::MAX_COEFFS;
+
+/// Size of every DAF record in bytes.
+const RECORD_BYTES: usize = 1024;
+
+/// Doubles per DAF record.
+const RECORD_WORDS: usize = RECORD_BYTES / 8;
+
+/// SPK summary size in doubles: ND + (NI + 1) / 2 with ND = 2, NI = 6.
+const SUMMARY_WORDS: usize = 5;
+
+/// Summaries per summary record, after the NEXT, PREV and NSUM words.
+const SUMMARIES_PER_RECORD: usize = (RECORD_WORDS - 3) / SUMMARY_WORDS;
+
+/// Longest segment name: one summary's worth of characters.
+const NAME_CHARS: usize = SUMMARY_WORDS * 8;
+
+/// Longest internal file name.
+const IFNAME_CHARS: usize = 60;
+
+/// Characters of comment text stored per comment record.
+const COMMENT_CHARS: usize = 1000;
+
+/// FTP validation string stored in the file record to detect transfers
+/// that mangle line endings or high-bit bytes.
+const FTP_STRING: &[u8; 28] = b"FTPSTR:\r:\n:\r\n:\r\x00:\x81:\x10\xce:ENDFTP";
+
+/// Byte offset of the FTP validation string in the file record.
+const FTP_OFFSET: usize = 699;
+
+/// One segment ready to be written: summary fields plus its data words.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentData {
+    pub target: i32,
+    pub center: i32,
+    pub frame: i32,
+    pub data_type: i32,
+    /// Coverage start, TDB seconds past J2000.0.
+    pub start_epoch: f64,
+    /// Coverage end, TDB seconds past J2000.0.
+    pub end_epoch: f64,
+    /// Segment name (at most 40 ASCII characters).
+    pub name: String,
+    /// Segment data array, including any trailing descriptor.
+    pub words: Vec<f64>,
+}
+
+/// Builder for a DAF/SPK file.
+#[derive(Debug, Clone, Default)]
+pub struct SpkWriter {
+    internal_name: String,
+    comments: String,
+    segments: Vec<SegmentData>,
+}
+
+impl SpkWriter {
+    /// Start a kernel with the given internal file name (at most 60 ASCII
+    /// characters).
+    pub fn new(internal_name: &str) -> Self {
+        Self {
+            internal_name: internal_name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the comment area text. Lines are separated by `\n`.
+    pub fn set_comments(&mut self, text: &str) {
+        self.comments = text.to_string();
+    }
+
+    /// Segments added so far, in file order.
+    pub fn segments(&self) -> &[SegmentData] {
+        &self.segments
+    }
+
+    /// Append a segment.
+    pub fn add_segment(&mut self, segment: SegmentData) -> Result<(), KernelError> {
+        if !segment.name.is_ascii() || segment.name.len() > NAME_CHARS {
+            return Err(KernelError::BadSegmentData(format!(
+                "segment name {:?} must be ASCII and at most {NAME_CHARS} characters",
+                segment.name
+            )));
+        }
+        if segment.start_epoch.is_nan()
+            || segment.end_epoch.is_nan()
+            || segment.start_epoch > segment.end_epoch
+        {
+            return Err(KernelError::BadSegmentData(format!(
+                "segment window {}..{} is empty",
+                segment.start_epoch, segment.end_epoch
+            )));
+        }
+        if segment.words.is_empty() {
+            return Err(KernelError::BadSegmentData("segment has no data".into()));
+        }
+        self.segments.push(segment);
+        Ok(())
+    }
+
+    /// Append every segment of `kernel` accepted by `select` that overlaps
+    /// `[start_tdb_s, end_tdb_s]`, clipped to that window.
+    ///
+    /// Returns the number of segments added.
+    pub fn add_kernel_subset(
+        &mut self,
+        kernel: &crate::SpkKernel,
+        start_tdb_s: f64,
+        end_tdb_s: f64,
+        mut select: impl FnMut(&crate::SpkSegment) -> bool,
+    ) -> Result<usize, KernelError> {
+        let mut added = 0;
+        for (index, segment) in kernel.segments().iter().enumerate() {
+            if !select(segment)
+                || segment.end_epoch < start_tdb_s
+                || segment.start_epoch > end_tdb_s
+            {
+                continue;
+            }
+            self.add_segment(kernel.segment_subset(index, start_tdb_s, end_tdb_s)?)?;
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Encode the kernel as DAF/SPK bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, KernelError> {
+        if !self.internal_name.is_ascii() || self.internal_name.len() > IFNAME_CHARS {
+            return Err(KernelError::BadFileId(format!(
+                "internal name {:?} must be ASCII and at most {IFNAME_CHARS} characters",
+                self.internal_name
+            )));
+        }
+        let comments = encode_comments(&self.comments)?;
+
+        let mut out = vec![0u8; RECORD_BYTES];
+        for chunk in comments.chunks(COMMENT_CHARS) {
+            let start = out.len();
+            out.resize(start + RECORD_BYTES, 0);
+            out[start..start + chunk.len()].copy_from_slice(chunk);
+        }
+        let fward = out.len() / RECORD_BYTES + 1;
+
+        let mut prev = 0usize;
+        let mut free = 0usize;
+        let groups: Vec<&[SegmentData]> = if self.segments.is_empty() {
+            vec![&[]]
+        } else {
+            self.segments.chunks(SUMMARIES_PER_RECORD).collect()
+        };
+        for group in groups {
+            let record_num = out.len() / RECORD_BYTES + 1;
+            let summary_base = out.len();
+            let names_base = summary_base + RECORD_BYTES;
+            out.resize(summary_base + 2 * RECORD_BYTES, 0);
+            out[names_base..names_base + RECORD_BYTES].fill(b' ');
+
+            if prev != 0 {
+                let prev_base = (prev - 1) * RECORD_BYTES;
+                put_f64(&mut out, prev_base, record_num as f64);
+            }
+            put_f64(&mut out, summary_base + 8, prev as f64);
+            put_f64(&mut out, summary_base + 16, group.len() as f64);
+
+            for (i, segment) in group.iter().enumerate() {
+                let start_addr = out.len() / 8 + 1;
+                for &w in &segment.words {
+                    out.extend_from_slice(&w.to_le_bytes());
+                }
+                let end_addr = out.len() / 8;
+
+                let off = summary_base + 24 + i * SUMMARY_WORDS * 8;
+                put_f64(&mut out, off, segment.start_epoch);
+                put_f64(&mut out, off + 8, segment.end_epoch);
+                let ints = [
+                    segment.target,
+                    segment.center,
+                    segment.frame,
+                    segment.data_type,
+                    address(start_addr)?,
+                    address(end_addr)?,
+                ];
+                for (j, v) in ints.iter().enumerate() {
+                    let o = off + 16 + j * 4;
+                    out[o..o + 4].copy_from_slice(&v.to_le_bytes());
+                }
+
+                let name = names_base + i * NAME_CHARS;
+                out[name..name + segment.name.len()].copy_from_slice(segment.name.as_bytes());
+            }
+
+            free = out.len() / 8 + 1;
+            out.resize(out.len().div_ceil(RECORD_BYTES) * RECORD_BYTES, 0);
+            prev = record_num;
+        }
+
+        out[0..8].copy_from_slice(b"DAF/SPK ");
+        out[8..12].copy_from_slice(&2i32.to_le_bytes());
+        out[12..16].copy_from_slice(&6i32.to_le_bytes());
+        out[16..16 + IFNAME_CHARS].fill(b' ');
+        out[16..16 + self.internal_name.len()].copy_from_slice(self.internal_name.as_bytes());
+        out[76..80].copy_from_slice(&address(fward)?.to_le_bytes());
+        out[80..84].copy_from_slice(&address(prev)?.to_le_bytes());
+        out[84..88].copy_from_slice(&address(free)?.to_le_bytes());
+        out[88..96].copy_from_slice(b"LTL-IEEE");
+        out[FTP_OFFSET..FTP_OFFSET + FTP_STRING.len()].copy_from_slice(FTP_STRING);
+        Ok(out)
+    }
+
+    /// Write the kernel to `path`.
+    pub fn write(&self, path: &Path) -> Result<(), KernelError> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+}
+
+fn put_f64(out: &mut [u8], offset: usize, value: f64) {
+    out[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Convert a record number or word address to the DAF's 32-bit integer.
+fn address(value: usize) -> Result<i32, KernelError> {
+    i32::try_from(value)
+        .map_err(|_| KernelError::BadSegmentData("kernel exceeds DAF address range".into()))
+}
+
+/// Comment text as DAF bytes: every line NUL-terminated, then EOT.
+fn encode_comments(text: &str) -> Result<Vec<u8>, KernelError> {
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    if !text.is_ascii() || text.bytes().any(|b| b == 0x00 || b == 0x04) {
+        return Err(KernelError::BadSegmentData(
+            "comments must be ASCII without NUL or EOT bytes".into(),
+        ));
+    }
+    let mut bytes: Vec<u8> = text
+        .bytes()
+        .map(|b| if b == b'\n' { 0x00 } else { b })
+        .collect();
+    if !text.ends_with('\n') {
+        bytes.push(0x00);
+    }
+    bytes.push(0x04);
+    Ok(bytes)
+}
+
+// ---------------------------------------------------------------------------
+// Chebyshev fitting
+// ---------------------------------------------------------------------------
+
+/// Parameters for fitting a Type 2 or Type 3 segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebyshevFit {
+    pub target: i32,
+    pub center: i32,
+    pub frame: i32,
+    /// 2 (position; velocity from the derivative) or 3 (position and
+    /// velocity fitted separately).
+    pub data_type: i32,
+    /// Coverage start, TDB seconds past J2000.0.
+    pub start_epoch: f64,
+    /// Coverage end, TDB seconds past J2000.0.
+    pub end_epoch: f64,
+    /// Record length in seconds. The last record may extend past
+    /// `end_epoch`; the summary window still ends there.
+    pub interval_s: f64,
+    /// Polynomial degree of every component (at most 31).
+    pub degree: usize,
+    /// Segment name.
+    pub name: String,
+}
+
+/// Fit a Chebyshev segment to `state_at`, which returns
+/// `[x, y, z, vx, vy, vz]` (km, km/s) at a TDB epoch.
+///
+/// Each record is interpolated at the `degree + 1` Chebyshev nodes of its
+/// interval, so the fit is exact for polynomial motion up to `degree` and
+/// near-minimax for smooth motion.
+pub fn fit_chebyshev_segment(
+    fit: &ChebyshevFit,
+    mut state_at: impl FnMut(f64) -> [f64; 6],
+) -> Result<SegmentData, KernelError> {
+    let components = match fit.data_type {
+        2 => 3,
+        3 => 6,
+        other => return Err(KernelError::UnsupportedDataType(other)),
+    };
+    let n_coeffs = fit.degree + 1;
+    if n_coeffs > MAX_COEFFS {
+        return Err(KernelError::BadSegmentData(format!(
+            "degree {} exceeds {}",
+            fit.degree,
+            MAX_COEFFS - 1
+        )));
+    }
+    let span = fit.end_epoch - fit.start_epoch;
+    if !span.is_finite() || span <= 0.0 || !fit.interval_s.is_finite() || fit.interval_s <= 0.0 {
+        return Err(KernelError::BadSegmentData(format!(
+            "invalid fit window {}..{} with interval {}",
+            fit.start_epoch, fit.end_epoch, fit.interval_s
+        )));
+    }
+
+    let n_records = ((span / fit.interval_s).ceil() as usize).max(1);
+    let radius = fit.interval_s / 2.0;
+    let rsize = 2 + components * n_coeffs;
+    let nodes: Vec<f64> = (0..n_coeffs)
+        .map(|k| (PI * (k as f64 + 0.5) / n_coeffs as f64).cos())
+        .collect();
+
+    let mut words = Vec::with_capacity(n_records * rsize + 4);
+    let mut samples = vec![[0.0f64; 6]; n_coeffs];
+    for record in 0..n_records {
+        let mid = fit.start_epoch + (record as f64 + 0.5) * fit.interval_s;
+        for (sample, &x) in samples.iter_mut().zip(&nodes) {
+            *sample = state_at(mid + radius * x);
+        }
+        words.extend([mid, radius]);
+        for component in 0..components {
+            for j in 0..n_coeffs {
+                let sum: f64 = samples
+                    .iter()
+                    .enumerate()
+                    .map(|(k, s)| {
+                        s[component] * (PI * j as f64 * (k as f64 + 0.5) / n_coeffs as f64).cos()
+                    })
+                    .sum();
+                let scale = if j == 0 { 1.0 } else { 2.0 };
+                words.push(scale * sum / n_coeffs as f64);
+            }
+        }
+    }
+    words.extend([
+        fit.start_epoch,
+        fit.interval_s,
+        rsize as f64,
+        n_records as f64,
+    ]);
+
+    Ok(SegmentData {
+        target: fit.target,
+        center: fit.center,
+        frame: fit.frame,
+        data_type: fit.data_type,
+        start_epoch: fit.start_epoch,
+        end_epoch: fit.end_epoch,
+        name: fit.name.clone(),
+        words,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chebyshev::clenshaw;
+
+    #[test]
+    fn ftp_string_fits_file_record() {
+        assert_eq!(FTP_OFFSET + FTP_STRING.len() + 297, RECORD_BYTES);
+        assert_eq!(SUMMARIES_PER_RECORD, 25);
+    }
+
+    #[test]
+    fn comments_are_nul_terminated_lines() {
+        assert_eq!(encode_comments("a\nb").unwrap(), b"a\0b\0\x04");
+        assert_eq!(encode_comments("a\n").unwrap(), b"a\0\x04");
+        assert!(encode_comments("").unwrap().is_empty());
+        assert!(encode_comments("caf\u{e9}").is_err());
+    }
+
+    #[test]
+    fn fit_reproduces_polynomial_exactly() {
+        let fit = ChebyshevFit {
+            target: 1,
+            center: 0,
+            frame: 1,
+            data_type: 2,
+            start_epoch: -100.0,
+            end_epoch: 100.0,
+            interval_s: 200.0,
+            degree: 3,
+            name: String::new(),
+        };
+        let cubic = |t: f64| [t * t * t, 2.0 * t, 5.0, 0.0, 0.0, 0.0];
+        let segment = fit_chebyshev_segment(&fit, cubic).unwrap();
+        // MID, RADIUS, 3 x 4 coefficients, 4 descriptor words.
+        assert_eq!(segment.words.len(), 2 + 12 + 4);
+        for t in [-100.0, -37.5, 0.0, 80.0] {
+            let s = t / 100.0;
+            let x = clenshaw(&segment.words[2..6], s);
+            let y = clenshaw(&segment.words[6..10], s);
+            assert!((x - t * t * t).abs() < 1e-6);
+            assert!((y - 2.0 * t).abs() < 1e-9);
+        }
+    }
+}
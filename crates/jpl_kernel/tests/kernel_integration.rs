@@ -97,3 +97,71 @@ fn resolve_mars_to_ssb() {
         "Mars(499) SSB distance {r:.0} km out of range"
     );
 }
+
+#[test]
+fn open_on_demand_matches_load() {
+    let kernel = match load_kernel() {
+        Some(k) => k,
+        None => return,
+    };
+    let lazy = SpkKernel::open(&kernel_path()).expect("should open de442s.bsp");
+    assert!(!lazy.is_resident());
+    assert_eq!(lazy.segments().len(), kernel.segments().len());
+
+    for body in [301, 399, 4, 5, 10] {
+        for epoch in [-1.0e8, 0.0, 3.3e8] {
+            assert_eq!(
+                lazy.resolve_to_ssb(body, epoch).unwrap(),
+                kernel.resolve_to_ssb(body, epoch).unwrap(),
+                "body {body} at {epoch}"
+            );
+        }
+    }
+}
+
+#[test]
+fn de442s_metadata_is_readable() {
+    let Some(kernel) = load_kernel() else { return };
+    assert!(kernel.file_id().starts_with("DAF/SPK"));
+    assert!(!kernel.internal_name().is_empty());
+    // Comments are free text; DE kernels describe themselves there.
+    let comments = kernel.comments().unwrap();
+    assert!(!comments.trim().is_empty());
+    // Every major body in DE442s is covered without gaps.
+    for coverage in kernel.coverage() {
+        assert!(
+            coverage.is_contiguous(),
+            "gap for {}/{}",
+            coverage.target,
+            coverage.center
+        );
+    }
+}
+
+#[test]
+fn de442s_subset_round_trips() {
+    let Some(kernel) = load_kernel() else { return };
+    // Calendar year 2025 (TDB seconds past J2000, approximately).
+    let (start, end) = (788_961_600.0, 820_497_600.0);
+    let mut writer = jpl_kernel::SpkWriter::new("DE442S 2025 SUBSET");
+    let added = writer
+        .add_kernel_subset(&kernel, start, end, |seg| {
+            matches!(seg.target, 3 | 301 | 399 | 10)
+        })
+        .unwrap();
+    assert_eq!(added, 4);
+    let bytes = writer.to_bytes().unwrap();
+    let subset = SpkKernel::from_bytes(bytes).unwrap();
+    assert_eq!(subset.segments().len(), 4);
+
+    for i in 0..=50 {
+        let t = start + (end - start) * i as f64 / 50.0;
+        for (target, center) in [(3, 0), (301, 3), (399, 3), (10, 0)] {
+            assert_eq!(
+                subset.evaluate(target, center, t).unwrap(),
+                kernel.evaluate(target, center, t).unwrap(),
+                "target {target} at {t}"
+            );
+        }
+    }
+}
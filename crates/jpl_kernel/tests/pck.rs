@@ -0,0 +1,179 @@
+//! Integration tests for binary PCK kernels, using a synthetic DAF/PCK file
+//! whose Euler angles follow analytic reference motion.
+
+use std::f64::consts::{FRAC_PI_2, TAU};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use jpl_kernel::pck::{FRAME_ECLIPJ2000, FRAME_J2000};
+use jpl_kernel::{KernelError, PckKernel, ReaderSource, SpkKernel};
+
+const RECORD_WORDS: usize = 128;
+
+/// Spin rate of the synthetic body (Earth-like), rad/s.
+const SPIN_RAD_S: f64 = 7.292_115e-5;
+
+/// Reference angles: fixed pole at (φ, δ) with w advancing uniformly.
+fn angles(t: f64) -> [f64; 3] {
+    [0.1, FRAC_PI_2 - 0.2, 1.0 + SPIN_RAD_S * t]
+}
+
+/// One Type 2 segment covering [-1e5, 1e5] s as a single Chebyshev record.
+fn segment_words() -> Vec<f64> {
+    let radius = 1.0e5;
+    let [phi, delta, w0] = angles(0.0);
+    let mut words = vec![0.0, radius];
+    words.extend([phi, 0.0]);
+    words.extend([delta, 0.0]);
+    words.extend([w0, SPIN_RAD_S * radius]);
+    words.extend([-radius, 2.0 * radius, 8.0, 1.0]);
+    words
+}
+
+/// Assemble a little-endian DAF/PCK file with one segment per
+/// (body frame, reference frame) pair.
+fn build_pck(frames: &[(i32, i32)]) -> Vec<u8> {
+    let mut file = vec![0u8; 3 * 1024];
+    file[0..8].copy_from_slice(b"DAF/PCK ");
+    file[8..12].copy_from_slice(&2i32.to_le_bytes());
+    file[12..16].copy_from_slice(&5i32.to_le_bytes());
+    file[16..76].copy_from_slice(&[b' '; 60]);
+    file[76..80].copy_from_slice(&2i32.to_le_bytes()); // FWARD
+    file[80..84].copy_from_slice(&2i32.to_le_bytes()); // BWARD
+    file[88..96].copy_from_slice(b"LTL-IEEE");
+
+    let summary_base = 1024;
+    file[summary_base + 16..summary_base + 24]
+        .copy_from_slice(&(frames.len() as f64).to_le_bytes());
+
+    let words = segment_words();
+    let mut next_addr = 3 * RECORD_WORDS + 1;
+    for (i, &(body_frame, reference_frame)) in frames.iter().enumerate() {
+        let start_addr = next_addr as i32;
+        let end_addr = (next_addr + words.len() - 1) as i32;
+        next_addr += words.len();
+
+        // ND + (NI + 1) / 2 = 5 words per summary.
+        let off = summary_base + 24 + i * 5 * 8;
+        file[off..off + 8].copy_from_slice(&(-1.0e5f64).to_le_bytes());
+        file[off + 8..off + 16].copy_from_slice(&1.0e5f64.to_le_bytes());
+        let ints = [body_frame, reference_frame, 2, start_addr, end_addr];
+        for (j, v) in ints.iter().enumerate() {
+            let o = off + 16 + j * 4;
+            file[o..o + 4].copy_from_slice(&v.to_le_bytes());
+        }
+
+        for w in &words {
+            file.extend_from_slice(&w.to_le_bytes());
+        }
+    }
+    file
+}
+
+#[test]
+fn evaluates_angles_and_rates() {
+    let kernel = PckKernel::from_bytes(build_pck(&[(3000, FRAME_ECLIPJ2000)])).unwrap();
+    assert_eq!(kernel.segments().len(), 1);
+    assert_eq!(kernel.segments()[0].body_frame, 3000);
+
+    for t in [-9.0e4, 0.0, 3.3e4] {
+        let eval = kernel.evaluate(3000, t).unwrap();
+        let expected = angles(t);
+        for (angle, reference) in eval.angles_rad.iter().zip(expected) {
+            assert!((angle - reference).abs() < 1e-12);
+        }
+        assert!(eval.rates_rad_s[0].abs() < 1e-18);
+        assert!((eval.rates_rad_s[2] - SPIN_RAD_S).abs() < 1e-15);
+    }
+    assert!(matches!(
+        kernel.evaluate(3000, 2.0e5),
+        Err(KernelError::EpochOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn rotation_from_j2000_composes_reference_frame() {
+    let kernel =
+        PckKernel::from_bytes(build_pck(&[(3000, FRAME_ECLIPJ2000), (31006, FRAME_J2000)]))
+            .unwrap();
+    let t = 1234.5;
+
+    // A J2000-referred segment is used as is.
+    let moon = kernel.rotation_from_j2000(31006, t).unwrap();
+    assert_eq!(moon.matrix, kernel.evaluate(31006, t).unwrap().rotation());
+
+    // For the ecliptic-referred segment, δ is the angle between the
+    // ecliptic pole and the body pole.
+    let earth = kernel.rotation_from_j2000(3000, t).unwrap();
+    let eps = (84381.448f64 / 3600.0).to_radians();
+    let ecliptic_pole = [0.0, -eps.sin(), eps.cos()];
+    let in_body = earth.apply(&ecliptic_pole);
+    assert!((in_body[2] - angles(t)[1].cos()).abs() < 1e-12);
+
+    // A fixed inertial point rotates backwards at the spin rate about the
+    // body pole; check the state transform against a finite difference.
+    let r = [7000.0, 0.0, 0.0];
+    let (_, v) = earth.apply_state(&r, &[0.0; 3]);
+    let ahead = kernel.rotation_from_j2000(3000, t + 1.0).unwrap().apply(&r);
+    let behind = kernel.rotation_from_j2000(3000, t - 1.0).unwrap().apply(&r);
+    for axis in 0..3 {
+        let numeric = (ahead[axis] - behind[axis]) / 2.0;
+        assert!((v[axis] - numeric).abs() < 1e-9, "axis {axis}");
+    }
+}
+
+#[test]
+fn on_demand_matches_in_memory() {
+    let bytes = build_pck(&[(3000, FRAME_ECLIPJ2000)]);
+    let resident = PckKernel::from_bytes(bytes.clone()).unwrap();
+    let reader =
+        PckKernel::from_source(Arc::new(ReaderSource::new(Cursor::new(bytes)).unwrap())).unwrap();
+    assert!(resident.is_resident());
+    assert!(!reader.is_resident());
+    assert_eq!(
+        resident.evaluate(3000, 500.0).unwrap(),
+        reader.evaluate(3000, 500.0).unwrap()
+    );
+}
+
+#[test]
+fn rejects_mismatched_daf_layout() {
+    let bytes = build_pck(&[(3000, FRAME_J2000)]);
+    assert!(matches!(
+        SpkKernel::from_bytes(bytes),
+        Err(KernelError::BadFileId(_))
+    ));
+
+    let kernel = PckKernel::from_bytes(build_pck(&[(3000, 42)])).unwrap();
+    assert_eq!(
+        kernel.rotation_from_j2000(3000, 0.0),
+        Err(KernelError::UnsupportedFrame(42))
+    );
+}
+
+#[test]
+fn orientation_and_sub_point_recover_euler_angles() {
+    let kernel = PckKernel::from_bytes(build_pck(&[(31006, FRAME_J2000)])).unwrap();
+    let t = -2.5e4;
+    let [phi, delta, w] = angles(t);
+
+    let orientation = kernel.orientation(31006, t).unwrap();
+    assert!((orientation.pole_ra_rad - (phi - FRAC_PI_2)).abs() < 1e-12);
+    assert!((orientation.pole_dec_rad - (FRAC_PI_2 - delta)).abs() < 1e-12);
+    assert!((orientation.prime_meridian_rad - w.rem_euclid(TAU)).abs() < 1e-12);
+
+    // The pole sits at latitude +90°, and the body-fixed +X axis (prime
+    // meridian on the equator) at longitude and latitude zero.
+    let pole = [
+        orientation.pole_dec_rad.cos() * orientation.pole_ra_rad.cos(),
+        orientation.pole_dec_rad.cos() * orientation.pole_ra_rad.sin(),
+        orientation.pole_dec_rad.sin(),
+    ];
+    let [_, lat] = kernel.sub_point(31006, t, &pole).unwrap();
+    assert!((lat - FRAC_PI_2).abs() < 1e-9);
+
+    let m = kernel.rotation_from_j2000(31006, t).unwrap().matrix;
+    let x_axis = [m[0][0], m[0][1], m[0][2]];
+    let [lon, lat] = kernel.sub_point(31006, t, &x_axis).unwrap();
+    assert!(lon.abs() < 1e-12 && lat.abs() < 1e-12);
+}
@@ -0,0 +1,321 @@
+//! Integration tests for non-Type-2 SPK segments, using synthetic DAF files
+//! whose states follow analytic reference motion.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use jpl_kernel::{KernelError, ReaderSource, SpkKernel, SpkWriter};
+
+const RECORD_WORDS: usize = 128;
+
+/// One synthetic segment: summary fields plus its data words.
+struct Segment {
+    target: i32,
+    center: i32,
+    data_type: i32,
+    start: f64,
+    end: f64,
+    words: Vec<f64>,
+}
+
+/// Assemble a little-endian DAF/SPK file: file record, one summary record,
+/// one name record, then segment data.
+fn build_spk(segments: &[Segment]) -> Vec<u8> {
+    let mut file = vec![0u8; 3 * 1024];
+    file[0..8].copy_from_slice(b"DAF/SPK ");
+    file[8..12].copy_from_slice(&2i32.to_le_bytes());
+    file[12..16].copy_from_slice(&6i32.to_le_bytes());
+    file[16..76].copy_from_slice(&[b' '; 60]);
+    file[76..80].copy_from_slice(&2i32.to_le_bytes()); // FWARD
+    file[80..84].copy_from_slice(&2i32.to_le_bytes()); // BWARD
+    file[88..96].copy_from_slice(b"LTL-IEEE");
+
+    let summary_base = 1024;
+    file[summary_base + 16..summary_base + 24]
+        .copy_from_slice(&(segments.len() as f64).to_le_bytes());
+
+    let mut next_addr = 3 * RECORD_WORDS + 1;
+    for (i, seg) in segments.iter().enumerate() {
+        let start_addr = next_addr as i32;
+        let end_addr = (next_addr + seg.words.len() - 1) as i32;
+        next_addr += seg.words.len();
+
+        let off = summary_base + 24 + i * 5 * 8;
+        file[off..off + 8].copy_from_slice(&seg.start.to_le_bytes());
+        file[off + 8..off + 16].copy_from_slice(&seg.end.to_le_bytes());
+        let ints = [
+            seg.target,
+            seg.center,
+            1,
+            seg.data_type,
+            start_addr,
+            end_addr,
+        ];
+        for (j, v) in ints.iter().enumerate() {
+            let o = off + 16 + j * 4;
+            file[o..o + 4].copy_from_slice(&v.to_le_bytes());
+        }
+
+        for w in &seg.words {
+            file.extend_from_slice(&w.to_le_bytes());
+        }
+    }
+    file
+}
+
+/// Heliocentric "asteroid" motion: uniform acceleration per axis.
+fn asteroid_state(t: f64) -> [f64; 6] {
+    let a = [1.0e-6, -2.0e-6, 5.0e-7];
+    let v = [15.0, -3.0, 1.0];
+    let r = [3.0e8, 1.0e8, -2.0e7];
+    let mut s = [0.0; 6];
+    for i in 0..3 {
+        s[i] = r[i] + v[i] * t + 0.5 * a[i] * t * t;
+        s[i + 3] = v[i] + a[i] * t;
+    }
+    s
+}
+
+/// Sun relative to SSB as a Type 3 segment: linear in time.
+fn sun_type3() -> Segment {
+    // One record over [-1e6, 1e6]; MID=0, RADIUS=1e6.
+    let mut words = vec![0.0, 1.0e6];
+    words.extend([1000.0, 1.0e6 * 1.0e-3]); // x = 1000 + 1e-3 t
+    words.extend([-500.0, 1.0e6 * 2.0e-3]); // y
+    words.extend([20.0, 0.0]); // z
+    words.extend([1.0e-3, 0.0]); // vx
+    words.extend([2.0e-3, 0.0]); // vy
+    words.extend([0.0, 0.0]); // vz
+    words.extend([-1.0e6, 2.0e6, 14.0, 1.0]);
+    Segment {
+        target: 10,
+        center: 0,
+        data_type: 3,
+        start: -1.0e6,
+        end: 1.0e6,
+        words,
+    }
+}
+
+fn asteroid_type21(target: i32) -> Segment {
+    // A single record with constant acceleration: KQMAX1 = 2, KQ = 1.
+    let maxdim = 15;
+    let s0 = asteroid_state(0.0);
+    let acc = [1.0e-6, -2.0e-6, 5.0e-7];
+    let mut rec = vec![0.0; 4 * maxdim + 11];
+    rec[1] = 86_400.0;
+    for i in 0..3 {
+        rec[maxdim + 1 + 2 * i] = s0[i];
+        rec[maxdim + 2 + 2 * i] = s0[i + 3];
+        rec[maxdim + 7 + i * maxdim] = acc[i];
+        rec[4 * maxdim + 8 + i] = 1.0;
+    }
+    rec[4 * maxdim + 7] = 2.0;
+    let mut words = rec;
+    words.extend([1.0e6, maxdim as f64, 1.0]);
+    Segment {
+        target,
+        center: 10,
+        data_type: 21,
+        start: -1.0e6,
+        end: 1.0e6,
+        words,
+    }
+}
+
+fn asteroid_type13(target: i32) -> Segment {
+    let epochs: Vec<f64> = (0..9).map(|i| -1.0e6 + i as f64 * 2.5e5).collect();
+    let mut words = Vec::new();
+    for &t in &epochs {
+        words.extend(asteroid_state(t));
+    }
+    words.extend(&epochs);
+    words.extend([3.0, epochs.len() as f64]);
+    Segment {
+        target,
+        center: 10,
+        data_type: 13,
+        start: -1.0e6,
+        end: 1.0e6,
+        words,
+    }
+}
+
+fn asteroid_type8(target: i32) -> Segment {
+    let mut words = Vec::new();
+    for i in 0..11 {
+        words.extend(asteroid_state(-1.0e6 + i as f64 * 2.0e5));
+    }
+    words.extend([-1.0e6, 2.0e5, 4.0, 11.0]);
+    Segment {
+        target,
+        center: 10,
+        data_type: 8,
+        start: -1.0e6,
+        end: 1.0e6,
+        words,
+    }
+}
+
+fn assert_matches_reference(kernel: &SpkKernel, target: i32, t: f64) {
+    let eval = kernel.evaluate(target, 10, t).unwrap();
+    let expected = asteroid_state(t);
+    for i in 0..3 {
+        let dp = (eval.position_km[i] - expected[i]).abs();
+        let dv = (eval.velocity_km_s[i] - expected[i + 3]).abs();
+        assert!(dp < 1e-3, "target {target} pos[{i}] off by {dp} km at {t}");
+        assert!(
+            dv < 1e-9,
+            "target {target} vel[{i}] off by {dv} km/s at {t}"
+        );
+    }
+}
+
+#[test]
+fn mixed_type_kernel_evaluates_every_segment() {
+    let data = build_spk(&[
+        sun_type3(),
+        asteroid_type21(2_000_001),
+        asteroid_type13(2_002_060),
+        asteroid_type8(2_005_145),
+    ]);
+    let kernel = SpkKernel::from_bytes(data).expect("synthetic kernel should load");
+
+    let types: Vec<i32> = kernel.segments().iter().map(|s| s.data_type).collect();
+    assert_eq!(types, vec![3, 21, 13, 8]);
+
+    for t in [-9.0e5, -1234.5, 0.0, 4.2e5, 9.99e5] {
+        assert_matches_reference(&kernel, 2_000_001, t);
+        assert_matches_reference(&kernel, 2_002_060, t);
+        assert_matches_reference(&kernel, 2_005_145, t);
+    }
+}
+
+#[test]
+fn resolve_to_ssb_chains_through_type3_sun() {
+    let data = build_spk(&[sun_type3(), asteroid_type21(2_000_001)]);
+    let kernel = SpkKernel::from_bytes(data).unwrap();
+
+    let t = 5.0e5;
+    let state = kernel.resolve_to_ssb(2_000_001, t).unwrap();
+    let helio = asteroid_state(t);
+    let sun = [1000.0 + 1.0e-3 * t, -500.0 + 2.0e-3 * t, 20.0];
+    for i in 0..3 {
+        assert!((state[i] - (helio[i] + sun[i])).abs() < 1e-3);
+    }
+    assert!((state[3] - (helio[3] + 1.0e-3)).abs() < 1e-9);
+    assert!((state[4] - (helio[4] + 2.0e-3)).abs() < 1e-9);
+}
+
+#[test]
+fn unknown_type_still_rejected() {
+    let mut seg = asteroid_type8(2_000_001);
+    seg.data_type = 17;
+    let kernel = SpkKernel::from_bytes(build_spk(&[seg])).unwrap();
+    assert_eq!(
+        kernel.evaluate(2_000_001, 10, 0.0),
+        Err(KernelError::UnsupportedDataType(17))
+    );
+}
+
+#[test]
+fn on_demand_sources_match_in_memory() {
+    let data = build_spk(&[
+        sun_type3(),
+        asteroid_type21(2_000_001),
+        asteroid_type13(2_002_060),
+        asteroid_type8(2_005_145),
+    ]);
+    let path =
+        std::env::temp_dir().join(format!("jpl_kernel_on_demand_{}.bsp", std::process::id()));
+    std::fs::write(&path, &data).unwrap();
+
+    let resident = SpkKernel::from_bytes(data.clone()).unwrap();
+    let from_file = SpkKernel::open(&path).unwrap();
+    let from_reader =
+        SpkKernel::from_source(Arc::new(ReaderSource::new(Cursor::new(data)).unwrap())).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(resident.is_resident());
+    assert!(!from_file.is_resident());
+    assert!(!from_reader.is_resident());
+    assert_eq!(from_file.segments().len(), resident.segments().len());
+
+    for target in [10, 2_000_001, 2_002_060, 2_005_145] {
+        let center = resident.center_for(target).unwrap();
+        for t in [-9.0e5, -1234.5, 0.0, 4.2e5, 9.99e5] {
+            let expected = resident.evaluate(target, center, t).unwrap();
+            assert_eq!(from_file.evaluate(target, center, t).unwrap(), expected);
+            assert_eq!(from_reader.evaluate(target, center, t).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn metadata_reports_names_and_coverage_gaps() {
+    let mut late_sun = sun_type3();
+    late_sun.start = 2.0e6;
+    late_sun.end = 3.0e6;
+    let mut data = build_spk(&[sun_type3(), late_sun, asteroid_type8(2_005_145)]);
+    data[16..30].copy_from_slice(b"SYNTHETIC TEST");
+    // Name record (record 3): one 40-character name per summary.
+    for (i, name) in ["SUN PART 1", "SUN PART 2"].iter().enumerate() {
+        let off = 2 * 1024 + i * 40;
+        data[off..off + 40].fill(b' ');
+        data[off..off + name.len()].copy_from_slice(name.as_bytes());
+    }
+    let kernel = SpkKernel::from_bytes(data).unwrap();
+
+    assert_eq!(kernel.file_id(), "DAF/SPK");
+    assert_eq!(kernel.internal_name(), "SYNTHETIC TEST");
+    assert_eq!(kernel.comments().unwrap(), "");
+    let names: Vec<&str> = kernel.segments().iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["SUN PART 1", "SUN PART 2", ""]);
+
+    let coverage = kernel.coverage();
+    assert_eq!(coverage.len(), 2);
+    let sun = coverage.iter().find(|c| c.target == 10).unwrap();
+    assert_eq!(sun.segment_count, 2);
+    assert_eq!(sun.windows.len(), 2);
+    assert_eq!(sun.gaps.len(), 1);
+    assert_eq!(
+        (sun.gaps[0].start_epoch, sun.gaps[0].end_epoch),
+        (1.0e6, 2.0e6)
+    );
+    assert!(
+        coverage
+            .iter()
+            .find(|c| c.target == 2_005_145)
+            .unwrap()
+            .is_contiguous()
+    );
+}
+
+#[test]
+fn subset_of_interpolated_types_narrows_window_and_matches_source() {
+    let data = build_spk(&[
+        asteroid_type8(2_000_001),
+        asteroid_type13(2_000_002),
+        asteroid_type21(2_000_003),
+    ]);
+    let source = SpkKernel::from_bytes(data).unwrap();
+    let (start, end) = (-2.5e5, 4.0e5);
+
+    let mut writer = SpkWriter::new("ASTEROID SUBSET");
+    let added = writer
+        .add_kernel_subset(&source, start, end, |_| true)
+        .unwrap();
+    assert_eq!(added, 3);
+    let kernel = SpkKernel::from_bytes(writer.to_bytes().unwrap()).unwrap();
+
+    for (index, seg) in kernel.segments().iter().enumerate() {
+        assert_eq!((seg.start_epoch, seg.end_epoch), (start, end));
+        for t in [start, 1.2345e5, end] {
+            assert_eq!(
+                kernel.evaluate_segment(index, t).unwrap(),
+                source.evaluate_segment(index, t).unwrap()
+            );
+        }
+        assert!(kernel.evaluate(seg.target, seg.center, end + 1.0).is_err());
+    }
+}
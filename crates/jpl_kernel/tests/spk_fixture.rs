@@ -0,0 +1,193 @@
+//! Golden-state tests against a committed fixture kernel.
+//!
+//! `tests/data/spk_types_fixture.bsp` holds one segment of each supported
+//! SPK data type, written by `scripts/kernels/generate_spk_type_fixture.py`
+//! from exact polynomial motion. `spk_types_fixture.csv` lists the true
+//! states at record boundaries, segment ends and interior epochs.
+//!
+//! The Type 1 and Type 21 bodies move on polynomials of up to degree 8,
+//! so their records carry difference tables with `KQ` from 2 to 7 over
+//! unequal step sizes. The generator builds those tables as Newton divided
+//! differences of the true acceleration, independently of the reader's
+//! integration recurrence.
+//!
+//! `spk_types_fixture_spice.csv`, when present, holds the same epochs
+//! evaluated by CSPICE (`scripts/kernels/spice_reference_states.py`).
+
+use std::path::{Path, PathBuf};
+
+use jpl_kernel::{SpkKernel, SpkWriter};
+
+/// Round-off budget for positions of order 1e8 km.
+const POSITION_TOL_KM: f64 = 1e-6;
+/// Type 13 differentiates its Hermite fit, losing a few digits to position
+/// magnitude over node spacing; the other types stay near 1e-14 km/s.
+const VELOCITY_TOL_KM_S: f64 = 1e-9;
+/// First epoch of every fixture segment (TDB seconds past J2000).
+const T0: f64 = 6.0e8;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(name)
+}
+
+struct Golden {
+    target: i32,
+    data_type: i32,
+    epoch_tdb_s: f64,
+    state: [f64; 6],
+}
+
+fn golden_states() -> Vec<Golden> {
+    let text = std::fs::read_to_string(fixture("spk_types_fixture.csv")).expect("fixture csv");
+    parse_states(&text)
+}
+
+fn parse_states(text: &str) -> Vec<Golden> {
+    text.lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            assert_eq!(fields.len(), 9, "bad fixture row: {line}");
+            let mut state = [0.0; 6];
+            for (slot, field) in state.iter_mut().zip(&fields[3..]) {
+                *slot = field.parse().unwrap();
+            }
+            Golden {
+                target: fields[0].parse().unwrap(),
+                data_type: fields[1].parse().unwrap(),
+                epoch_tdb_s: fields[2].parse().unwrap(),
+                state,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn fixture_lists_one_segment_per_type() {
+    let kernel = SpkKernel::load(&fixture("spk_types_fixture.bsp")).unwrap();
+    let types: Vec<i32> = kernel.segments().iter().map(|s| s.data_type).collect();
+    assert_eq!(types, vec![1, 2, 3, 8, 9, 13, 21]);
+    for seg in kernel.segments() {
+        assert_eq!(seg.center, 0);
+        assert_eq!(seg.frame, 1);
+    }
+}
+
+fn assert_matches(kernel: &SpkKernel, golden: &[Golden]) {
+    for g in golden {
+        let eval = kernel
+            .evaluate(g.target, 0, g.epoch_tdb_s)
+            .unwrap_or_else(|e| panic!("type {} at {}: {e}", g.data_type, g.epoch_tdb_s));
+        for i in 0..3 {
+            let dp = (eval.position_km[i] - g.state[i]).abs();
+            let dv = (eval.velocity_km_s[i] - g.state[i + 3]).abs();
+            assert!(
+                dp < POSITION_TOL_KM,
+                "type {} at {} axis {i}: position off by {dp} km",
+                g.data_type,
+                g.epoch_tdb_s
+            );
+            assert!(
+                dv < VELOCITY_TOL_KM_S,
+                "type {} at {} axis {i}: velocity off by {dv} km/s",
+                g.data_type,
+                g.epoch_tdb_s
+            );
+        }
+    }
+}
+
+#[test]
+fn every_segment_type_matches_golden_states() {
+    let kernel = SpkKernel::load(&fixture("spk_types_fixture.bsp")).unwrap();
+    let golden = golden_states();
+    assert_eq!(golden.len(), 7 * 12);
+    assert_matches(&kernel, &golden);
+}
+
+#[test]
+fn every_segment_type_matches_cspice_states() {
+    let Ok(text) = std::fs::read_to_string(fixture("spk_types_fixture_spice.csv")) else {
+        eprintln!("Skipping: spk_types_fixture_spice.csv not generated");
+        return;
+    };
+    let kernel = SpkKernel::load(&fixture("spk_types_fixture.bsp")).unwrap();
+    let spice = parse_states(&text);
+    let golden = golden_states();
+    assert_eq!(spice.len(), golden.len());
+    for (s, g) in spice.iter().zip(&golden) {
+        assert_eq!((s.target, s.epoch_tdb_s), (g.target, g.epoch_tdb_s));
+    }
+    assert_matches(&kernel, &spice);
+}
+
+#[test]
+fn on_demand_fixture_matches_resident() {
+    let resident = SpkKernel::load(&fixture("spk_types_fixture.bsp")).unwrap();
+    let on_demand = SpkKernel::open(&fixture("spk_types_fixture.bsp")).unwrap();
+    assert!(!on_demand.is_resident());
+    for g in golden_states() {
+        let a = resident.evaluate(g.target, 0, g.epoch_tdb_s).unwrap();
+        let b = on_demand.evaluate(g.target, 0, g.epoch_tdb_s).unwrap();
+        assert_eq!(a.position_km, b.position_km);
+        assert_eq!(a.velocity_km_s, b.velocity_km_s);
+    }
+}
+
+#[test]
+fn subsets_shrink_and_match_source_in_window() {
+    let source = SpkKernel::load(&fixture("spk_types_fixture.bsp")).unwrap();
+    // Interior windows (the second ends nearer the next Type 13 state, so
+    // its odd window rounds up), leading and trailing windows (the leading
+    // one ends on a record boundary) and a single instant.
+    for (from, to) in [
+        (1000.5, 2468.125),
+        (1000.5, 2690.0),
+        (0.0, 1000.0),
+        (3001.0, 4000.0),
+        (1777.0, 1777.0),
+    ] {
+        let (start, end) = (T0 + from, T0 + to);
+        let mut writer = SpkWriter::new("FIXTURE SUBSET");
+        let added = writer
+            .add_kernel_subset(&source, start, end, |_| true)
+            .unwrap();
+        assert_eq!(added, 7);
+
+        for (clipped, seg) in writer.segments().iter().zip(source.segments()) {
+            let source_words = (seg.end_addr - seg.start_addr + 1) as usize;
+            assert!(
+                clipped.words.len() < source_words,
+                "type {} over [{from}, {to}]: {} of {source_words} words kept",
+                seg.data_type,
+                clipped.words.len()
+            );
+        }
+
+        let subset = SpkKernel::from_bytes(writer.to_bytes().unwrap()).unwrap();
+        for (index, seg) in subset.segments().iter().enumerate() {
+            assert_eq!((seg.start_epoch, seg.end_epoch), (start, end));
+            for i in 1..50 {
+                let t = start + (end - start) * f64::from(i) / 50.0;
+                assert_eq!(
+                    subset.evaluate_segment(index, t).unwrap(),
+                    source.evaluate_segment(index, t).unwrap(),
+                    "type {} at {t}",
+                    seg.data_type
+                );
+            }
+            // A window end on a Chebyshev record boundary is served by the
+            // record before it, which agrees to round-off.
+            for t in [start, end] {
+                let a = subset.evaluate_segment(index, t).unwrap();
+                let b = source.evaluate_segment(index, t).unwrap();
+                for k in 0..3 {
+                    assert!((a.position_km[k] - b.position_km[k]).abs() < POSITION_TOL_KM);
+                    assert!((a.velocity_km_s[k] - b.velocity_km_s[k]).abs() < VELOCITY_TOL_KM_S);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Round-trip tests for the SPK writer: fitted segments and kernel subsets
+//! are written with `SpkWriter` and read back with `SpkKernel`.
+
+use jpl_kernel::{
+    ChebyshevFit, KernelError, SegmentData, SpkKernel, SpkWriter, fit_chebyshev_segment,
+};
+
+/// Circular orbit of 7000 km radius with a 6000 s period, inclined by
+/// 30 degrees about the x axis.
+fn orbit(t: f64) -> [f64; 6] {
+    let r = 7000.0;
+    let n = 2.0 * std::f64::consts::PI / 6000.0;
+    let (s, c) = (n * t).sin_cos();
+    let (si, ci) = 30f64.to_radians().sin_cos();
+    let (x, y) = (r * c, r * s);
+    let (vx, vy) = (-r * n * s, r * n * c);
+    [x, y * ci, y * si, vx, vy * ci, vy * si]
+}
+
+fn orbit_fit(data_type: i32, name: &str) -> ChebyshevFit {
+    ChebyshevFit {
+        target: -1000,
+        center: 399,
+        frame: 1,
+        data_type,
+        start_epoch: 0.0,
+        end_epoch: 86_400.0,
+        interval_s: 1500.0,
+        degree: 15,
+        name: name.to_string(),
+    }
+}
+
+fn assert_matches_orbit(kernel: &SpkKernel, target: i32, t: f64) {
+    let eval = kernel.evaluate(target, 399, t).unwrap();
+    let expected = orbit(t);
+    for axis in 0..3 {
+        let dp = (eval.position_km[axis] - expected[axis]).abs();
+        let dv = (eval.velocity_km_s[axis] - expected[axis + 3]).abs();
+        assert!(dp < 1e-6, "t={t} axis {axis}: position off by {dp} km");
+        assert!(dv < 1e-9, "t={t} axis {axis}: velocity off by {dv} km/s");
+    }
+}
+
+#[test]
+fn fitted_segments_round_trip() {
+    let mut writer = SpkWriter::new("FITTED ORBIT");
+    writer.set_comments("Synthetic orbit\nfitted by the writer tests");
+    for (data_type, name) in [(2, "ORBIT TYPE 2"), (3, "ORBIT TYPE 3")] {
+        let mut fit = orbit_fit(data_type, name);
+        if data_type == 3 {
+            fit.target = -1001;
+        }
+        writer
+            .add_segment(fit_chebyshev_segment(&fit, orbit).unwrap())
+            .unwrap();
+    }
+    let kernel = SpkKernel::from_bytes(writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(kernel.internal_name(), "FITTED ORBIT");
+    assert_eq!(
+        kernel.comments().unwrap(),
+        "Synthetic orbit\nfitted by the writer tests\n"
+    );
+    let names: Vec<&str> = kernel.segments().iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["ORBIT TYPE 2", "ORBIT TYPE 3"]);
+    assert_eq!(kernel.segments()[1].data_type, 3);
+
+    for i in 0..=96 {
+        let t = i as f64 * 900.0;
+        assert_matches_orbit(&kernel, -1000, t);
+        assert_matches_orbit(&kernel, -1001, t);
+    }
+}
+
+#[test]
+fn subset_clips_records_and_matches_source() {
+    let mut writer = SpkWriter::new("SOURCE");
+    writer
+        .add_segment(fit_chebyshev_segment(&orbit_fit(2, "FULL DAY"), orbit).unwrap())
+        .unwrap();
+    let source = SpkKernel::from_bytes(writer.to_bytes().unwrap()).unwrap();
+
+    // 03:00 to 06:00 exactly spans records 7..14 (1500 s each).
+    let (start, end) = (10_800.0, 21_600.0);
+    let mut subset = SpkWriter::new("SUBSET");
+    let added = subset
+        .add_kernel_subset(&source, start, end, |seg| seg.target == -1000)
+        .unwrap();
+    assert_eq!(added, 1);
+    let clipped = &subset.segments()[0];
+    assert_eq!((clipped.start_epoch, clipped.end_epoch), (start, end));
+    // 8 records of 2 + 3 * 16 words, plus the descriptor.
+    assert_eq!(clipped.words.len(), 8 * 50 + 4);
+    assert_eq!(
+        &clipped.words[clipped.words.len() - 4..],
+        [10_500.0, 1500.0, 50.0, 8.0]
+    );
+
+    let kernel = SpkKernel::from_bytes(subset.to_bytes().unwrap()).unwrap();
+    assert_eq!(kernel.segments()[0].name, "FULL DAY");
+    for i in 0..=40 {
+        let t = start + i as f64 * 270.0;
+        assert_eq!(
+            kernel.evaluate(-1000, 399, t).unwrap(),
+            source.evaluate(-1000, 399, t).unwrap()
+        );
+    }
+    assert!(matches!(
+        kernel.evaluate(-1000, 399, end + 1.0),
+        Err(KernelError::EpochOutOfRange { .. })
+    ));
+    assert!(matches!(
+        source.segment_subset(0, 1.0e6, 2.0e6),
+        Err(KernelError::EpochOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn many_segments_span_summary_records() {
+    let mut writer = SpkWriter::new("MANY");
+    for i in 0..60 {
+        let start = i as f64 * 100.0;
+        writer
+            .add_segment(SegmentData {
+                target: 1000 + i,
+                center: 0,
+                frame: 1,
+                data_type: 2,
+                start_epoch: start,
+                end_epoch: start + 100.0,
+                name: format!("SEGMENT {i}"),
+                // One constant-position record: MID, RADIUS, x, y, z.
+                words: vec![
+                    start + 50.0,
+                    50.0,
+                    i as f64,
+                    0.0,
+                    0.0,
+                    start,
+                    100.0,
+                    5.0,
+                    1.0,
+                ],
+            })
+            .unwrap();
+    }
+    let kernel = SpkKernel::from_bytes(writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(kernel.segments().len(), 60);
+    for (i, seg) in kernel.segments().iter().enumerate() {
+        assert_eq!(seg.target, 1000 + i as i32);
+        assert_eq!(seg.name, format!("SEGMENT {i}"));
+        let eval = kernel.evaluate_segment(i, seg.start_epoch + 10.0).unwrap();
+        assert_eq!(eval.position_km, [i as f64, 0.0, 0.0]);
+    }
+}
+
+#[test]
+fn writes_to_disk_and_rejects_bad_input() {
+    let mut writer = SpkWriter::new("ON DISK");
+    writer
+        .add_segment(fit_chebyshev_segment(&orbit_fit(3, "DISK"), orbit).unwrap())
+        .unwrap();
+    let path = std::env::temp_dir().join(format!("jpl_kernel_writer_{}.bsp", std::process::id()));
+    writer.write(&path).unwrap();
+    let kernel = SpkKernel::open(&path).unwrap();
+    assert_matches_orbit(&kernel, -1000, 4321.0);
+    drop(kernel);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut fit = orbit_fit(5, "");
+    assert_eq!(
+        fit_chebyshev_segment(&fit, orbit),
+        Err(KernelError::UnsupportedDataType(5))
+    );
+    fit.data_type = 2;
+    fit.degree = 40;
+    assert!(fit_chebyshev_segment(&fit, orbit).is_err());
+
+    let long_name = SegmentData {
+        name: "X".repeat(41),
+        ..fit_chebyshev_segment(&orbit_fit(2, ""), orbit).unwrap()
+    };
+    assert!(writer.add_segment(long_name).is_err());
+    assert!(SpkWriter::new(&"N".repeat(61)).to_bytes().is_err());
+}
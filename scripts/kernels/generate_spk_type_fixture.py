@@ -0,0 +1,278 @@
+#!/usr/bin/env python3
+"""Generate the SPK segment-type fixture kernel and its expected states.
+
+Writes one little-endian DAF/SPK file with a segment of each supported data
+type (1, 2, 3, 8, 9, 13, 21) plus a CSV of states sampled from the same
+analytic motion. Every body follows an exact polynomial per axis, which
+each segment type represents without truncation error, so the CSV holds the
+true states rather than the output of any reader.
+
+Segment encodings follow the NAIF SPK Required Reading layouts directly and
+share no code with the Rust reader.
+"""
+
+from __future__ import annotations
+
+import argparse
+import math
+import struct
+from pathlib import Path
+
+RECORD_BYTES = 1024
+RECORD_WORDS = 128
+ND, NI = 2, 6
+FTPSTR = b"FTPSTR:\r:\n:\r\n:\r\x00:\x81:\x10\xce:ENDFTP"
+
+T0 = 6.0e8  # 2019-01-05 TDB, seconds past J2000
+SPAN = 4000.0
+CENTER = 0
+FRAME = 1  # J2000
+
+# (position, velocity, acceleration, jerk) at T0 per axis, km and seconds.
+MOTION = {
+    1001: [(1.2e8, 21.0, 3.0e-6, -4.0e-11), (-7.5e7, 14.0, -2.0e-6, 6.0e-11), (3.1e6, -1.5, 5.0e-7, 1.0e-11)],
+    1002: [(-4.4e8, -8.0, 1.1e-6, 2.0e-11), (2.2e8, 11.5, -9.0e-7, -3.0e-11), (9.0e7, 4.25, 2.5e-7, 5.0e-12)],
+    1003: [(6.8e7, 30.5, -5.0e-6, 7.0e-11), (1.4e8, -12.0, 4.0e-6, -2.0e-11), (-2.6e7, 0.75, 1.0e-6, 3.0e-11)],
+    1008: [(2.9e8, 5.0, 2.0e-6, 1.5e-11), (-1.1e8, -17.0, -3.5e-6, -5.0e-11), (4.7e7, 2.0, 6.0e-7, 8.0e-12)],
+    1009: [(-3.3e8, 9.5, -1.5e-6, 4.5e-11), (5.6e7, 22.0, 2.5e-6, -1.0e-11), (-8.1e6, -3.25, -4.0e-7, 2.0e-12)],
+    1013: [(1.9e8, -26.0, 4.5e-6, -6.0e-11), (2.4e8, 7.0, -1.0e-6, 3.5e-11), (1.2e7, 1.25, 8.0e-7, -4.0e-12)],
+    1021: [(-5.2e8, 13.0, 3.5e-6, 2.5e-11), (-1.8e8, -9.5, 1.5e-6, -4.5e-11), (6.6e7, -0.5, -2.0e-7, 6.0e-12)],
+}
+
+# Higher powers for the difference-line bodies, so their records need more
+# than two difference-table entries. Entry k is the km contributed by the
+# (k + 4)-th power of (t - T0) one TAU after T0.
+TAU = 1000.0
+HIGHER = {
+    1001: [[0.8, -0.3], [0.5, 0.2, -0.1], []],
+    1021: [[0.6, -0.25, 0.1, 0.05, -0.02], [0.4, 0.3], [0.7, -0.2, 0.1, -0.05]],
+}
+
+
+def taylor(target: int, axis: int) -> list[float]:
+    """Derivatives of the axis position at T0, lowest order first."""
+    r, v, a, j = MOTION[target][axis]
+    coeffs = [r, v, a, j]
+    for k, km in enumerate(HIGHER.get(target, [[], [], []])[axis], start=4):
+        coeffs.append(km * math.factorial(k) / TAU**k)
+    return coeffs
+
+
+def derivative(target: int, axis: int, order: int, t: float) -> float:
+    """`order`-th time derivative of the axis position at `t`."""
+    d = t - T0
+    coeffs = taylor(target, axis)
+    return sum(c * d ** (k - order) / math.factorial(k - order) for k, c in enumerate(coeffs) if k >= order)
+
+
+def state(target: int, t: float) -> list[float]:
+    """Exact state (x, y, z, vx, vy, vz) of `target` at TDB seconds `t`."""
+    return [derivative(target, i, order, t) for order in (0, 1) for i in range(3)]
+
+
+# ---------------------------------------------------------------------------
+# Segment encoders
+# ---------------------------------------------------------------------------
+
+
+def power_to_chebyshev(c: list[float]) -> list[float]:
+    """Chebyshev coefficients of the cubic c0 + c1 s + c2 s^2 + c3 s^3."""
+    c0, c1, c2, c3 = c
+    # s^2 = (T0 + T2) / 2, s^3 = (3 T1 + T3) / 4
+    return [c0 + c2 / 2.0, c1 + 3.0 * c3 / 4.0, c2 / 2.0, c3 / 4.0]
+
+
+def axis_in_s(target: int, axis: int, mid: float, radius: float) -> list[float]:
+    """Axis position as a cubic in the normalised time s = (t - mid) / radius."""
+    r, v, a, j = MOTION[target][axis]
+    d = mid - T0
+    # Taylor-expand about mid, then scale by radius per power.
+    p0 = r + v * d + a * d * d / 2.0 + j * d ** 3 / 6.0
+    p1 = v + a * d + j * d * d / 2.0
+    p2 = (a + j * d) / 2.0
+    p3 = j / 6.0
+    return [p0, p1 * radius, p2 * radius ** 2, p3 * radius ** 3]
+
+
+def chebyshev_segment(target: int, with_velocity: bool) -> list[float]:
+    """Type 2 (position) or Type 3 (position + velocity) records of 1000 s."""
+    intlen = 1000.0
+    radius = intlen / 2.0
+    n = int(SPAN / intlen)
+    words: list[float] = []
+    for k in range(n):
+        mid = T0 + (k + 0.5) * intlen
+        record = [mid, radius]
+        polys = [axis_in_s(target, i, mid, radius) for i in range(3)]
+        for p in polys:
+            record += power_to_chebyshev(p)
+        if with_velocity:
+            for p in polys:
+                # d/dt = (d/ds) / radius; derivative of a cubic is quadratic.
+                dp = [p[1] / radius, 2.0 * p[2] / radius, 3.0 * p[3] / radius, 0.0]
+                record += power_to_chebyshev(dp)
+        words += record
+    rsize = len(words) // n
+    return words + [T0, intlen, float(rsize), float(n)]
+
+
+def type8_segment(target: int) -> list[float]:
+    step = 200.0
+    n = int(SPAN / step) + 1
+    words: list[float] = []
+    for k in range(n):
+        words += state(target, T0 + k * step)
+    return words + [T0, step, 3.0, float(n)]
+
+
+def unequal_epochs() -> list[float]:
+    epochs, t, k = [], T0, 0
+    while t < T0 + SPAN:
+        epochs.append(t)
+        t += 150.0 + 70.0 * (k % 4)
+        k += 1
+    epochs.append(T0 + SPAN)
+    return epochs
+
+
+def unequal_segment(target: int, control: float) -> list[float]:
+    """Type 9/13 body: states, epochs, directory, control word, N."""
+    epochs = unequal_epochs()
+    words: list[float] = []
+    for t in epochs:
+        words += state(target, t)
+    words += epochs
+    words += [epochs[100 * (i + 1) - 1] for i in range(len(epochs) // 100)]
+    return words + [control, float(len(epochs))]
+
+
+def divided_differences(xs: list[float], ys: list[float]) -> list[float]:
+    """Newton coefficients f[x0], f[x0, x1], ... of the points (xs, ys)."""
+    table = list(ys)
+    coeffs = [table[0]]
+    for order in range(1, len(xs)):
+        table = [(table[i + 1] - table[i]) / (xs[i + order] - xs[i]) for i in range(len(table) - 1)]
+        coeffs.append(table[0])
+    return coeffs
+
+
+def difference_line_segment(target: int, maxdim: int, extended: bool) -> list[float]:
+    """Type 1 (`extended` false, MAXDIM 15) or Type 21 difference lines.
+
+    Each record is referenced to its midpoint TL with G(j) = TL - t_j for
+    unequally spaced past epochs t_j (t_0 = TL). The acceleration on an axis
+    is a polynomial of degree KQ - 1 written in Newton form over those
+    epochs, DT(j) = f[t_0 .. t_{j-1}] * G(1) * .. * G(j-1), which the
+    reader's integration recurrence must reproduce exactly. KQ differs per
+    axis, from 2 (cubic motion) up to the body's polynomial degree - 1.
+    """
+    intlen = 1000.0
+    n = int(SPAN / intlen)
+    kq = [len(taylor(target, i)) - 2 for i in range(3)]
+    records: list[float] = []
+    ends: list[float] = []
+    for k in range(n):
+        tl = T0 + (k + 0.5) * intlen
+        g = [0.0]
+        for j in range(1, maxdim + 1):
+            g.append(g[-1] + 120.0 + 35.0 * j + 50.0 * k)
+        record = [0.0] * (4 * maxdim + 11)
+        record[0] = tl
+        record[1 : maxdim + 1] = g[1:]
+        st = state(target, tl)
+        for i in range(3):
+            record[maxdim + 1 + 2 * i] = st[i]
+            record[maxdim + 2 + 2 * i] = st[i + 3]
+            nodes = [tl - g[j] for j in range(kq[i])]
+            newton = divided_differences(nodes, [derivative(target, i, 2, x) for x in nodes])
+            scale = 1.0
+            for j in range(kq[i]):
+                record[maxdim + 7 + i * maxdim + j] = newton[j] * scale
+                scale *= g[j + 1]
+            record[4 * maxdim + 8 + i] = float(kq[i])
+        record[4 * maxdim + 7] = float(max(kq) + 1)
+        records += record
+        ends.append(T0 + (k + 1) * intlen)
+    trailer = [float(maxdim), float(n)] if extended else [float(n)]
+    directory = [ends[100 * (i + 1) - 1] for i in range(n // 100)]
+    return records + ends + directory + trailer
+
+
+SEGMENTS = [
+    (1001, 1, lambda: difference_line_segment(1001, 15, False)),
+    (1002, 2, lambda: chebyshev_segment(1002, False)),
+    (1003, 3, lambda: chebyshev_segment(1003, True)),
+    (1008, 8, lambda: type8_segment(1008)),
+    (1009, 9, lambda: unequal_segment(1009, 5.0)),
+    (1013, 13, lambda: unequal_segment(1013, 2.0)),
+    (1021, 21, lambda: difference_line_segment(1021, 25, True)),
+]
+
+
+# ---------------------------------------------------------------------------
+# DAF assembly
+# ---------------------------------------------------------------------------
+
+
+def pack_words(words: list[float]) -> bytes:
+    return struct.pack(f"<{len(words)}d", *words)
+
+
+def build_daf() -> bytes:
+    data = b""
+    summaries = b""
+    names = b""
+    address = 3 * RECORD_WORDS + 1
+    for target, data_type, encode in SEGMENTS:
+        words = encode()
+        start, end = address, address + len(words) - 1
+        address += len(words)
+        summaries += struct.pack("<2d", T0, T0 + SPAN)
+        summaries += struct.pack("<6i", target, CENTER, FRAME, data_type, start, end)
+        names += f"SPK TYPE {data_type} FIXTURE".ljust(40).encode()
+        data += pack_words(words)
+
+    file_record = bytearray(RECORD_BYTES)
+    file_record[0:8] = b"DAF/SPK "
+    struct.pack_into("<2i", file_record, 8, ND, NI)
+    file_record[16:76] = b"SPK SEGMENT TYPE FIXTURE".ljust(60)
+    struct.pack_into("<3i", file_record, 76, 2, 2, address)
+    file_record[88:96] = b"LTL-IEEE"
+    file_record[699 : 699 + len(FTPSTR)] = FTPSTR
+
+    summary_record = struct.pack("<3d", 0.0, 0.0, float(len(SEGMENTS))) + summaries
+    name_record = names
+    out = bytes(file_record)
+    out += summary_record.ljust(RECORD_BYTES, b"\0")
+    out += name_record.ljust(RECORD_BYTES, b" ")
+    return out + data
+
+
+def sample_epochs() -> list[float]:
+    # Segment ends, record boundaries, and interior points off any grid.
+    offsets = [0.0, 1.0, 333.25, 999.5, 1000.0, 1000.5, 1777.0, 2000.0, 2468.125, 3001.0, 3999.0, 4000.0]
+    return [T0 + o for o in offsets]
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser()
+    parser.add_argument(
+        "--out-dir",
+        type=Path,
+        default=Path("crates/jpl_kernel/tests/data"),
+    )
+    args = parser.parse_args()
+    args.out_dir.mkdir(parents=True, exist_ok=True)
+
+    (args.out_dir / "spk_types_fixture.bsp").write_bytes(build_daf())
+    lines = ["# target,data_type,epoch_tdb_s,x_km,y_km,z_km,vx_km_s,vy_km_s,vz_km_s"]
+    for target, data_type, _ in SEGMENTS:
+        for t in sample_epochs():
+            values = [repr(v) for v in state(target, t)]
+            lines.append(",".join([str(target), str(data_type), repr(t)] + values))
+    (args.out_dir / "spk_types_fixture.csv").write_text("\n".join(lines) + "\n")
+    return 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
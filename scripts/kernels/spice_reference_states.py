@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+"""Evaluate the SPK segment-type fixture with CSPICE.
+
+Reads `spk_types_fixture.bsp` and the epochs of `spk_types_fixture.csv`,
+evaluates every row with CSPICE `spkgeo_c` (through SpiceyPy) and writes
+`spk_types_fixture_spice.csv` in the same layout. The Rust test
+`every_segment_type_matches_cspice_states` checks the reader against that
+file when it is present.
+
+Requires SpiceyPy (`pip install spiceypy`); no LSK is needed because the
+fixture epochs are already TDB seconds past J2000.
+"""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+import spiceypy as spice
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser()
+    parser.add_argument(
+        "--data-dir",
+        type=Path,
+        default=Path("crates/jpl_kernel/tests/data"),
+    )
+    args = parser.parse_args()
+
+    spice.furnsh(str(args.data_dir / "spk_types_fixture.bsp"))
+    lines = [f"# CSPICE {spice.tkvrsn('TOOLKIT')} spkgeo_c, frame J2000, observer 0"]
+    lines.append("# target,data_type,epoch_tdb_s,x_km,y_km,z_km,vx_km_s,vy_km_s,vz_km_s")
+    for row in (args.data_dir / "spk_types_fixture.csv").read_text().splitlines():
+        if not row or row.startswith("#"):
+            continue
+        target, data_type, epoch = row.split(",")[:3]
+        state, _ = spice.spkgeo(int(target), float(epoch), "J2000", 0)
+        values = [repr(float(v)) for v in state]
+        lines.append(",".join([target, data_type, epoch] + values))
+    spice.kclear()
+
+    (args.data_dir / "spk_types_fixture_spice.csv").write_text("\n".join(lines) + "\n")
+    return 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())